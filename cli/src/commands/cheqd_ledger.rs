@@ -59,7 +59,9 @@ pub mod create_nym_command {
                 .add_required_param("denom", "Denom is currency for transaction")
                 .add_optional_param("role", "Role of identity.")
                 .add_optional_param("memo", "Memo is optional param. It has any arbitrary memo to be added to the transaction")
+                .add_optional_param("fee_granter", "Address of an account which pays the fee using an allowance granted to the key")
                 .add_example("cheqd-ledger create-nym did=my_did verkey=my_verkey key_alias=my_key max_coin=500 max_gas=10000000 denom=cheq role=role memo=memo")
+                .add_example("cheqd-ledger create-nym did=my_did verkey=my_verkey key_alias=my_key max_coin=500 max_gas=10000000 denom=cheq fee_granter=granter_address")
                 .finalize()
     );
 
@@ -75,6 +77,7 @@ pub mod create_nym_command {
         let denom = get_str_param("denom", params).map_err(error_err!())?;
         let role = get_opt_str_param("role", params).map_err(error_err!())?.unwrap_or("");
         let memo = get_opt_str_param("memo", params).map_err(error_err!())?.unwrap_or("");
+        let fee_granter = get_opt_str_param("fee_granter", params).map_err(error_err!())?;
 
         let pool_alias = ensure_cheqd_connected_pool(ctx)?;
         let wallet_handle = ensure_opened_wallet_handle(&ctx)?;
@@ -102,7 +105,8 @@ pub mod create_nym_command {
             max_coin,
             denom,
             timeout_height,
-            memo
+            memo,
+            fee_granter
         ).map_err(|err| handle_indy_error(err, None, None, None))?;
 
         let signed_tx = CheqdKeys::sign(wallet_handle, key_alias, &tx)
@@ -184,7 +188,7 @@ pub mod bank_send_command {
         let request = CheqdLedger::build_msg_send(from, to, amount, denom)
             .map_err(|err| handle_indy_error(err, None, None, None))?;
 
-        let response = build_and_sign_and_broadcast_tx(ctx, &pool_alias, &request, key_alias, denom, max_gas, max_coin, memo)?;
+        let response = build_and_sign_and_broadcast_tx(ctx, &pool_alias, &request, key_alias, denom, max_gas, max_coin, memo, None)?;
         let parsed_response = CheqdLedger::parse_msg_send_resp(&response)
             .map_err(|err| handle_indy_error(err, None, None, None))?;
 
@@ -277,6 +281,825 @@ pub mod get_all_nym_command {
     }
 }
 
+pub mod delegate_command {
+    use super::*;
+
+    command!(CommandMetadata::build("delegate", "Delegate coins to a validator.")
+                .add_required_param("delegator", "Address of the delegator")
+                .add_required_param("validator", "Address of the validator")
+                .add_required_param("amount", "Amount of coins to delegate")
+                .add_required_param("denom", "Denom of coins")
+                .add_required_param("key_alias", "Key alias")
+                .add_required_param("max_coin", "Max amount coins for transaction")
+                .add_required_param("max_gas", "Max amount gas for transaction")
+                .add_optional_param("memo", "Memo is optional param. It has any arbitrary memo to be added to the transaction")
+                .add_example("cheqd-ledger delegate delegator=delegator_address validator=validator_address amount=100 denom=cheq key_alias=my_key max_coin=100 max_gas=10000000")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let delegator = get_str_param("delegator", params).map_err(error_err!())?;
+        let validator = get_str_param("validator", params).map_err(error_err!())?;
+        let amount = get_str_param("amount", params).map_err(error_err!())?;
+        let denom = get_str_param("denom", params).map_err(error_err!())?;
+        let key_alias = get_str_param("key_alias", params).map_err(error_err!())?;
+        let max_coin = get_str_param("max_coin", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_coin must be integer"))?;
+        let max_gas = get_str_param("max_gas", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_gas must be integer"))?;
+        let memo = get_opt_str_param("memo", params).map_err(error_err!())?.unwrap_or("");
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let request = CheqdLedger::build_msg_delegate(delegator, validator, amount, denom)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let response = build_and_sign_and_broadcast_tx(ctx, &pool_alias, &request, key_alias, denom, max_gas, max_coin, memo, None)?;
+        let parsed_response = CheqdLedger::parse_msg_delegate_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Coins have been delegated");
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod undelegate_command {
+    use super::*;
+
+    command!(CommandMetadata::build("undelegate", "Undelegate coins from a validator.")
+                .add_required_param("delegator", "Address of the delegator")
+                .add_required_param("validator", "Address of the validator")
+                .add_required_param("amount", "Amount of coins to undelegate")
+                .add_required_param("denom", "Denom of coins")
+                .add_required_param("key_alias", "Key alias")
+                .add_required_param("max_coin", "Max amount coins for transaction")
+                .add_required_param("max_gas", "Max amount gas for transaction")
+                .add_optional_param("memo", "Memo is optional param. It has any arbitrary memo to be added to the transaction")
+                .add_example("cheqd-ledger undelegate delegator=delegator_address validator=validator_address amount=100 denom=cheq key_alias=my_key max_coin=100 max_gas=10000000")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let delegator = get_str_param("delegator", params).map_err(error_err!())?;
+        let validator = get_str_param("validator", params).map_err(error_err!())?;
+        let amount = get_str_param("amount", params).map_err(error_err!())?;
+        let denom = get_str_param("denom", params).map_err(error_err!())?;
+        let key_alias = get_str_param("key_alias", params).map_err(error_err!())?;
+        let max_coin = get_str_param("max_coin", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_coin must be integer"))?;
+        let max_gas = get_str_param("max_gas", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_gas must be integer"))?;
+        let memo = get_opt_str_param("memo", params).map_err(error_err!())?.unwrap_or("");
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let request = CheqdLedger::build_msg_undelegate(delegator, validator, amount, denom)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let response = build_and_sign_and_broadcast_tx(ctx, &pool_alias, &request, key_alias, denom, max_gas, max_coin, memo, None)?;
+        let parsed_response = CheqdLedger::parse_msg_undelegate_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Coins have been undelegated: {}", parsed_response);
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod redelegate_command {
+    use super::*;
+
+    command!(CommandMetadata::build("redelegate", "Redelegate coins from one validator to another.")
+                .add_required_param("delegator", "Address of the delegator")
+                .add_required_param("src_validator", "Address of the validator to redelegate from")
+                .add_required_param("dst_validator", "Address of the validator to redelegate to")
+                .add_required_param("amount", "Amount of coins to redelegate")
+                .add_required_param("denom", "Denom of coins")
+                .add_required_param("key_alias", "Key alias")
+                .add_required_param("max_coin", "Max amount coins for transaction")
+                .add_required_param("max_gas", "Max amount gas for transaction")
+                .add_optional_param("memo", "Memo is optional param. It has any arbitrary memo to be added to the transaction")
+                .add_example("cheqd-ledger redelegate delegator=delegator_address src_validator=validator_address dst_validator=validator_address amount=100 denom=cheq key_alias=my_key max_coin=100 max_gas=10000000")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let delegator = get_str_param("delegator", params).map_err(error_err!())?;
+        let src_validator = get_str_param("src_validator", params).map_err(error_err!())?;
+        let dst_validator = get_str_param("dst_validator", params).map_err(error_err!())?;
+        let amount = get_str_param("amount", params).map_err(error_err!())?;
+        let denom = get_str_param("denom", params).map_err(error_err!())?;
+        let key_alias = get_str_param("key_alias", params).map_err(error_err!())?;
+        let max_coin = get_str_param("max_coin", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_coin must be integer"))?;
+        let max_gas = get_str_param("max_gas", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_gas must be integer"))?;
+        let memo = get_opt_str_param("memo", params).map_err(error_err!())?.unwrap_or("");
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let request = CheqdLedger::build_msg_begin_redelegate(delegator, src_validator, dst_validator, amount, denom)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let response = build_and_sign_and_broadcast_tx(ctx, &pool_alias, &request, key_alias, denom, max_gas, max_coin, memo, None)?;
+        let parsed_response = CheqdLedger::parse_msg_begin_redelegate_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Coins have been redelegated: {}", parsed_response);
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod withdraw_rewards_command {
+    use super::*;
+
+    command!(CommandMetadata::build("withdraw-rewards", "Withdraw delegation rewards from a validator.")
+                .add_required_param("delegator", "Address of the delegator")
+                .add_required_param("validator", "Address of the validator")
+                .add_required_param("denom", "Denom of coins used to pay the fee")
+                .add_required_param("key_alias", "Key alias")
+                .add_required_param("max_coin", "Max amount coins for transaction")
+                .add_required_param("max_gas", "Max amount gas for transaction")
+                .add_optional_param("memo", "Memo is optional param. It has any arbitrary memo to be added to the transaction")
+                .add_example("cheqd-ledger withdraw-rewards delegator=delegator_address validator=validator_address denom=cheq key_alias=my_key max_coin=100 max_gas=10000000")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let delegator = get_str_param("delegator", params).map_err(error_err!())?;
+        let validator = get_str_param("validator", params).map_err(error_err!())?;
+        let denom = get_str_param("denom", params).map_err(error_err!())?;
+        let key_alias = get_str_param("key_alias", params).map_err(error_err!())?;
+        let max_coin = get_str_param("max_coin", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_coin must be integer"))?;
+        let max_gas = get_str_param("max_gas", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_gas must be integer"))?;
+        let memo = get_opt_str_param("memo", params).map_err(error_err!())?.unwrap_or("");
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let request = CheqdLedger::build_msg_withdraw_delegator_reward(delegator, validator)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let response = build_and_sign_and_broadcast_tx(ctx, &pool_alias, &request, key_alias, denom, max_gas, max_coin, memo, None)?;
+        let parsed_response = CheqdLedger::parse_msg_withdraw_delegator_reward_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Rewards have been withdrawn");
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod get_delegation_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get-delegation", "Get delegation of a delegator to a validator.")
+                .add_required_param("delegator", "Address of the delegator")
+                .add_required_param("validator", "Address of the validator")
+                .add_example("cheqd-ledger get-delegation delegator=delegator_address validator=validator_address")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let delegator = get_str_param("delegator", params).map_err(error_err!())?;
+        let validator = get_str_param("validator", params).map_err(error_err!())?;
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let query = CheqdLedger::build_query_delegation(delegator, validator)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+        let response = CheqdPool::abci_query(&pool_alias, &query)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+        let parsed_response = CheqdLedger::parse_query_delegation_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Delegation info: {}", parsed_response);
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod get_delegations_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get-delegations", "Get all delegations of a delegator.")
+                .add_required_param("delegator", "Address of the delegator")
+                .add_example("cheqd-ledger get-delegations delegator=delegator_address")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let delegator = get_str_param("delegator", params).map_err(error_err!())?;
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let query = CheqdLedger::build_query_delegator_delegations(delegator, None)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+        let response = CheqdPool::abci_query(&pool_alias, &query)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+        let parsed_response = CheqdLedger::parse_query_delegator_delegations_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Delegations info: {}", parsed_response);
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod get_validators_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get-validators", "Get validators of the network.")
+                .add_optional_param("status", "Validator status to filter by: BOND_STATUS_BONDED, BOND_STATUS_UNBONDING or BOND_STATUS_UNBONDED")
+                .add_example("cheqd-ledger get-validators")
+                .add_example("cheqd-ledger get-validators status=BOND_STATUS_BONDED")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let status = get_opt_str_param("status", params).map_err(error_err!())?;
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let query = CheqdLedger::build_query_validators(status, None)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+        let response = CheqdPool::abci_query(&pool_alias, &query)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+        let parsed_response = CheqdLedger::parse_query_validators_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Validators info: {}", parsed_response);
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod get_rewards_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get-rewards", "Get pending rewards of a delegator.")
+                .add_required_param("delegator", "Address of the delegator")
+                .add_example("cheqd-ledger get-rewards delegator=delegator_address")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let delegator = get_str_param("delegator", params).map_err(error_err!())?;
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let query = CheqdLedger::build_query_delegation_total_rewards(delegator)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+        let response = CheqdPool::abci_query(&pool_alias, &query)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+        let parsed_response = CheqdLedger::parse_query_delegation_total_rewards_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Rewards info: {}", parsed_response);
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod submit_text_proposal_command {
+    use super::*;
+
+    command!(CommandMetadata::build("submit-text-proposal", "Submit a text governance proposal.")
+                .add_required_param("title", "Title of the proposal")
+                .add_required_param("description", "Description of the proposal")
+                .add_required_param("proposer", "Address of the proposer")
+                .add_required_param("amount", "Amount of coins for the initial deposit")
+                .add_required_param("denom", "Denom of coins")
+                .add_required_param("key_alias", "Key alias")
+                .add_required_param("max_coin", "Max amount coins for transaction")
+                .add_required_param("max_gas", "Max amount gas for transaction")
+                .add_optional_param("memo", "Memo is optional param. It has any arbitrary memo to be added to the transaction")
+                .add_example(r#"cheqd-ledger submit-text-proposal title="Title" description="Description" proposer=proposer_address amount=100 denom=cheq key_alias=my_key max_coin=100 max_gas=10000000"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let title = get_str_param("title", params).map_err(error_err!())?;
+        let description = get_str_param("description", params).map_err(error_err!())?;
+        let proposer = get_str_param("proposer", params).map_err(error_err!())?;
+        let amount = get_str_param("amount", params).map_err(error_err!())?;
+        let denom = get_str_param("denom", params).map_err(error_err!())?;
+        let key_alias = get_str_param("key_alias", params).map_err(error_err!())?;
+        let max_coin = get_str_param("max_coin", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_coin must be integer"))?;
+        let max_gas = get_str_param("max_gas", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_gas must be integer"))?;
+        let memo = get_opt_str_param("memo", params).map_err(error_err!())?.unwrap_or("");
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let request = CheqdLedger::build_msg_submit_text_proposal(title, description, proposer, amount, denom)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let response = build_and_sign_and_broadcast_tx(ctx, &pool_alias, &request, key_alias, denom, max_gas, max_coin, memo, None)?;
+        let parsed_response = CheqdLedger::parse_msg_submit_proposal_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Proposal has been submitted: {}", parsed_response);
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod submit_param_change_proposal_command {
+    use super::*;
+
+    command!(CommandMetadata::build("submit-param-change-proposal", "Submit a governance proposal to change network parameters.")
+                .add_required_param("title", "Title of the proposal")
+                .add_required_param("description", "Description of the proposal")
+                .add_required_param("changes", "List of parameter changes in json format [{\"subspace\": string, \"key\": string, \"value\": string}]")
+                .add_required_param("proposer", "Address of the proposer")
+                .add_required_param("amount", "Amount of coins for the initial deposit")
+                .add_required_param("denom", "Denom of coins")
+                .add_required_param("key_alias", "Key alias")
+                .add_required_param("max_coin", "Max amount coins for transaction")
+                .add_required_param("max_gas", "Max amount gas for transaction")
+                .add_optional_param("memo", "Memo is optional param. It has any arbitrary memo to be added to the transaction")
+                .add_example(r#"cheqd-ledger submit-param-change-proposal title="Title" description="Description" changes=[{"subspace":"staking","key":"MaxValidators","value":"105"}] proposer=proposer_address amount=100 denom=cheq key_alias=my_key max_coin=100 max_gas=10000000"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let title = get_str_param("title", params).map_err(error_err!())?;
+        let description = get_str_param("description", params).map_err(error_err!())?;
+        let changes = get_str_param("changes", params).map_err(error_err!())?;
+        let proposer = get_str_param("proposer", params).map_err(error_err!())?;
+        let amount = get_str_param("amount", params).map_err(error_err!())?;
+        let denom = get_str_param("denom", params).map_err(error_err!())?;
+        let key_alias = get_str_param("key_alias", params).map_err(error_err!())?;
+        let max_coin = get_str_param("max_coin", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_coin must be integer"))?;
+        let max_gas = get_str_param("max_gas", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_gas must be integer"))?;
+        let memo = get_opt_str_param("memo", params).map_err(error_err!())?.unwrap_or("");
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let request = CheqdLedger::build_msg_submit_param_change_proposal(title, description, changes, proposer, amount, denom)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let response = build_and_sign_and_broadcast_tx(ctx, &pool_alias, &request, key_alias, denom, max_gas, max_coin, memo, None)?;
+        let parsed_response = CheqdLedger::parse_msg_submit_proposal_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Proposal has been submitted: {}", parsed_response);
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod deposit_command {
+    use super::*;
+
+    command!(CommandMetadata::build("deposit", "Add a deposit to a governance proposal.")
+                .add_required_param("proposal_id", "Identifier of the proposal")
+                .add_required_param("depositor", "Address of the depositor")
+                .add_required_param("amount", "Amount of coins to deposit")
+                .add_required_param("denom", "Denom of coins")
+                .add_required_param("key_alias", "Key alias")
+                .add_required_param("max_coin", "Max amount coins for transaction")
+                .add_required_param("max_gas", "Max amount gas for transaction")
+                .add_optional_param("memo", "Memo is optional param. It has any arbitrary memo to be added to the transaction")
+                .add_example("cheqd-ledger deposit proposal_id=1 depositor=depositor_address amount=100 denom=cheq key_alias=my_key max_coin=100 max_gas=10000000")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let proposal_id = get_str_param("proposal_id", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: proposal_id must be integer"))?;
+        let depositor = get_str_param("depositor", params).map_err(error_err!())?;
+        let amount = get_str_param("amount", params).map_err(error_err!())?;
+        let denom = get_str_param("denom", params).map_err(error_err!())?;
+        let key_alias = get_str_param("key_alias", params).map_err(error_err!())?;
+        let max_coin = get_str_param("max_coin", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_coin must be integer"))?;
+        let max_gas = get_str_param("max_gas", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_gas must be integer"))?;
+        let memo = get_opt_str_param("memo", params).map_err(error_err!())?.unwrap_or("");
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let request = CheqdLedger::build_msg_deposit(proposal_id, depositor, amount, denom)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let response = build_and_sign_and_broadcast_tx(ctx, &pool_alias, &request, key_alias, denom, max_gas, max_coin, memo, None)?;
+        let parsed_response = CheqdLedger::parse_msg_deposit_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Deposit has been added");
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod vote_command {
+    use super::*;
+
+    command!(CommandMetadata::build("vote", "Vote on a governance proposal.")
+                .add_required_param("proposal_id", "Identifier of the proposal")
+                .add_required_param("voter", "Address of the voter")
+                .add_required_param("option", "Vote option: yes, abstain, no or no_with_veto")
+                .add_required_param("denom", "Denom of coins used to pay the fee")
+                .add_required_param("key_alias", "Key alias")
+                .add_required_param("max_coin", "Max amount coins for transaction")
+                .add_required_param("max_gas", "Max amount gas for transaction")
+                .add_optional_param("memo", "Memo is optional param. It has any arbitrary memo to be added to the transaction")
+                .add_example("cheqd-ledger vote proposal_id=1 voter=voter_address option=yes denom=cheq key_alias=my_key max_coin=100 max_gas=10000000")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let proposal_id = get_str_param("proposal_id", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: proposal_id must be integer"))?;
+        let voter = get_str_param("voter", params).map_err(error_err!())?;
+        let option = get_str_param("option", params).map_err(error_err!())?;
+        let denom = get_str_param("denom", params).map_err(error_err!())?;
+        let key_alias = get_str_param("key_alias", params).map_err(error_err!())?;
+        let max_coin = get_str_param("max_coin", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_coin must be integer"))?;
+        let max_gas = get_str_param("max_gas", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_gas must be integer"))?;
+        let memo = get_opt_str_param("memo", params).map_err(error_err!())?.unwrap_or("");
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let request = CheqdLedger::build_msg_vote(proposal_id, voter, option)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let response = build_and_sign_and_broadcast_tx(ctx, &pool_alias, &request, key_alias, denom, max_gas, max_coin, memo, None)?;
+        let parsed_response = CheqdLedger::parse_msg_vote_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Vote has been cast");
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod vote_weighted_command {
+    use super::*;
+
+    command!(CommandMetadata::build("vote-weighted", "Split a vote on a governance proposal between several options.")
+                .add_required_param("proposal_id", "Identifier of the proposal")
+                .add_required_param("voter", "Address of the voter")
+                .add_required_param("options", "List of weighted options in json format [{\"option\": string, \"weight\": string}]. Weights must sum up to 1")
+                .add_required_param("denom", "Denom of coins used to pay the fee")
+                .add_required_param("key_alias", "Key alias")
+                .add_required_param("max_coin", "Max amount coins for transaction")
+                .add_required_param("max_gas", "Max amount gas for transaction")
+                .add_optional_param("memo", "Memo is optional param. It has any arbitrary memo to be added to the transaction")
+                .add_example(r#"cheqd-ledger vote-weighted proposal_id=1 voter=voter_address options=[{"option":"yes","weight":"0.7"},{"option":"abstain","weight":"0.3"}] denom=cheq key_alias=my_key max_coin=100 max_gas=10000000"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let proposal_id = get_str_param("proposal_id", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: proposal_id must be integer"))?;
+        let voter = get_str_param("voter", params).map_err(error_err!())?;
+        let options = get_str_param("options", params).map_err(error_err!())?;
+        let denom = get_str_param("denom", params).map_err(error_err!())?;
+        let key_alias = get_str_param("key_alias", params).map_err(error_err!())?;
+        let max_coin = get_str_param("max_coin", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_coin must be integer"))?;
+        let max_gas = get_str_param("max_gas", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_gas must be integer"))?;
+        let memo = get_opt_str_param("memo", params).map_err(error_err!())?.unwrap_or("");
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let request = CheqdLedger::build_msg_vote_weighted(proposal_id, voter, options)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let response = build_and_sign_and_broadcast_tx(ctx, &pool_alias, &request, key_alias, denom, max_gas, max_coin, memo, None)?;
+        let parsed_response = CheqdLedger::parse_msg_vote_weighted_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Weighted vote has been cast");
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod get_proposal_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get-proposal", "Get a governance proposal.")
+                .add_required_param("proposal_id", "Identifier of the proposal")
+                .add_example("cheqd-ledger get-proposal proposal_id=1")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let proposal_id = get_str_param("proposal_id", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: proposal_id must be integer"))?;
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let query = CheqdLedger::build_query_proposal(proposal_id)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+        let response = CheqdPool::abci_query(&pool_alias, &query)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+        let parsed_response = CheqdLedger::parse_query_proposal_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Proposal info: {}", parsed_response);
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod get_proposals_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get-proposals", "Get governance proposals.")
+                .add_optional_param("status", "Status of proposals to filter by: deposit_period, voting_period, passed, rejected or failed")
+                .add_example("cheqd-ledger get-proposals")
+                .add_example("cheqd-ledger get-proposals status=voting_period")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let status = get_opt_str_param("status", params).map_err(error_err!())?;
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let query = CheqdLedger::build_query_proposals(status, None)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+        let response = CheqdPool::abci_query(&pool_alias, &query)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+        let parsed_response = CheqdLedger::parse_query_proposals_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Proposals info: {}", parsed_response);
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod get_tally_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get-tally", "Get current tally of a governance proposal.")
+                .add_required_param("proposal_id", "Identifier of the proposal")
+                .add_example("cheqd-ledger get-tally proposal_id=1")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let proposal_id = get_str_param("proposal_id", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: proposal_id must be integer"))?;
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let query = CheqdLedger::build_query_tally_result(proposal_id)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+        let response = CheqdPool::abci_query(&pool_alias, &query)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+        let parsed_response = CheqdLedger::parse_query_tally_result_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Tally info: {}", parsed_response);
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod get_vote_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get-vote", "Get a vote on a governance proposal.")
+                .add_required_param("proposal_id", "Identifier of the proposal")
+                .add_required_param("voter", "Address of the voter")
+                .add_example("cheqd-ledger get-vote proposal_id=1 voter=voter_address")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let proposal_id = get_str_param("proposal_id", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: proposal_id must be integer"))?;
+        let voter = get_str_param("voter", params).map_err(error_err!())?;
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let query = CheqdLedger::build_query_vote(proposal_id, voter)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+        let response = CheqdPool::abci_query(&pool_alias, &query)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+        let parsed_response = CheqdLedger::parse_query_vote_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Vote info: {}", parsed_response);
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod grant_fee_allowance_command {
+    use super::*;
+
+    command!(CommandMetadata::build("grant-fee-allowance", "Allow an account to pay transaction fees from the granter account.")
+                .add_required_param("granter", "Address of the account which pays fees")
+                .add_required_param("grantee", "Address of the account which is allowed to use the granter coins for fees")
+                .add_required_param("denom", "Denom of coins")
+                .add_optional_param("spend_limit", "Max amount of coins the grantee can spend on fees. Unlimited if not set")
+                .add_optional_param("expiration", "Unix timestamp in seconds when the allowance expires")
+                .add_required_param("key_alias", "Key alias")
+                .add_required_param("max_coin", "Max amount coins for transaction")
+                .add_required_param("max_gas", "Max amount gas for transaction")
+                .add_optional_param("memo", "Memo is optional param. It has any arbitrary memo to be added to the transaction")
+                .add_example("cheqd-ledger grant-fee-allowance granter=granter_address grantee=grantee_address spend_limit=1000 denom=cheq key_alias=my_key max_coin=100 max_gas=10000000")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let granter = get_str_param("granter", params).map_err(error_err!())?;
+        let grantee = get_str_param("grantee", params).map_err(error_err!())?;
+        let denom = get_str_param("denom", params).map_err(error_err!())?;
+        let spend_limit = get_opt_str_param("spend_limit", params).map_err(error_err!())?;
+        let expiration = get_opt_str_param("expiration", params).map_err(error_err!())?
+            .map(|expiration| expiration.parse::<i64>()).transpose()
+            .map_err(|_| println_err!("Invalid format of input data: expiration must be integer"))?;
+        let key_alias = get_str_param("key_alias", params).map_err(error_err!())?;
+        let max_coin = get_str_param("max_coin", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_coin must be integer"))?;
+        let max_gas = get_str_param("max_gas", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_gas must be integer"))?;
+        let memo = get_opt_str_param("memo", params).map_err(error_err!())?.unwrap_or("");
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let request = CheqdLedger::build_msg_grant_allowance(granter, grantee, spend_limit, Some(denom), expiration.unwrap_or(-1))
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let response = build_and_sign_and_broadcast_tx(ctx, &pool_alias, &request, key_alias, denom, max_gas, max_coin, memo, None)?;
+        let parsed_response = CheqdLedger::parse_msg_grant_allowance_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Fee allowance has been granted");
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod revoke_fee_allowance_command {
+    use super::*;
+
+    command!(CommandMetadata::build("revoke-fee-allowance", "Remove the fee allowance granted to an account.")
+                .add_required_param("granter", "Address of the account which pays fees")
+                .add_required_param("grantee", "Address of the account which is allowed to use the granter coins for fees")
+                .add_required_param("denom", "Denom of coins used to pay the fee")
+                .add_required_param("key_alias", "Key alias")
+                .add_required_param("max_coin", "Max amount coins for transaction")
+                .add_required_param("max_gas", "Max amount gas for transaction")
+                .add_optional_param("memo", "Memo is optional param. It has any arbitrary memo to be added to the transaction")
+                .add_example("cheqd-ledger revoke-fee-allowance granter=granter_address grantee=grantee_address denom=cheq key_alias=my_key max_coin=100 max_gas=10000000")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let granter = get_str_param("granter", params).map_err(error_err!())?;
+        let grantee = get_str_param("grantee", params).map_err(error_err!())?;
+        let denom = get_str_param("denom", params).map_err(error_err!())?;
+        let key_alias = get_str_param("key_alias", params).map_err(error_err!())?;
+        let max_coin = get_str_param("max_coin", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_coin must be integer"))?;
+        let max_gas = get_str_param("max_gas", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_gas must be integer"))?;
+        let memo = get_opt_str_param("memo", params).map_err(error_err!())?.unwrap_or("");
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let request = CheqdLedger::build_msg_revoke_allowance(granter, grantee)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let response = build_and_sign_and_broadcast_tx(ctx, &pool_alias, &request, key_alias, denom, max_gas, max_coin, memo, None)?;
+        let parsed_response = CheqdLedger::parse_msg_revoke_allowance_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Fee allowance has been revoked");
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod grant_authz_command {
+    use super::*;
+
+    command!(CommandMetadata::build("grant-authz", "Authorise an account to submit messages of the given type on behalf of the granter.")
+                .add_required_param("granter", "Address of the account which gives the authorization")
+                .add_required_param("grantee", "Address of the account which receives the authorization")
+                .add_required_param("msg_type_url", "Type url of the authorized message")
+                .add_required_param("expiration", "Unix timestamp in seconds when the authorization expires")
+                .add_required_param("denom", "Denom of coins used to pay the fee")
+                .add_required_param("key_alias", "Key alias")
+                .add_required_param("max_coin", "Max amount coins for transaction")
+                .add_required_param("max_gas", "Max amount gas for transaction")
+                .add_optional_param("memo", "Memo is optional param. It has any arbitrary memo to be added to the transaction")
+                .add_example("cheqd-ledger grant-authz granter=granter_address grantee=grantee_address msg_type_url=/cheqdid.cheqdnode.cheqd.MsgCreateNym expiration=1893456000 denom=cheq key_alias=my_key max_coin=100 max_gas=10000000")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let granter = get_str_param("granter", params).map_err(error_err!())?;
+        let grantee = get_str_param("grantee", params).map_err(error_err!())?;
+        let msg_type_url = get_str_param("msg_type_url", params).map_err(error_err!())?;
+        let expiration = get_str_param("expiration", params).map_err(error_err!())?
+            .parse::<i64>().map_err(|_| println_err!("Invalid format of input data: expiration must be integer"))?;
+        let denom = get_str_param("denom", params).map_err(error_err!())?;
+        let key_alias = get_str_param("key_alias", params).map_err(error_err!())?;
+        let max_coin = get_str_param("max_coin", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_coin must be integer"))?;
+        let max_gas = get_str_param("max_gas", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_gas must be integer"))?;
+        let memo = get_opt_str_param("memo", params).map_err(error_err!())?.unwrap_or("");
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let request = CheqdLedger::build_msg_grant(granter, grantee, msg_type_url, expiration)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let response = build_and_sign_and_broadcast_tx(ctx, &pool_alias, &request, key_alias, denom, max_gas, max_coin, memo, None)?;
+        let parsed_response = CheqdLedger::parse_msg_grant_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Authorization has been granted");
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
+pub mod revoke_authz_command {
+    use super::*;
+
+    command!(CommandMetadata::build("revoke-authz", "Revoke the authorization given to an account.")
+                .add_required_param("granter", "Address of the account which gave the authorization")
+                .add_required_param("grantee", "Address of the account which received the authorization")
+                .add_required_param("msg_type_url", "Type url of the authorized message")
+                .add_required_param("denom", "Denom of coins used to pay the fee")
+                .add_required_param("key_alias", "Key alias")
+                .add_required_param("max_coin", "Max amount coins for transaction")
+                .add_required_param("max_gas", "Max amount gas for transaction")
+                .add_optional_param("memo", "Memo is optional param. It has any arbitrary memo to be added to the transaction")
+                .add_example("cheqd-ledger revoke-authz granter=granter_address grantee=grantee_address msg_type_url=/cheqdid.cheqdnode.cheqd.MsgCreateNym denom=cheq key_alias=my_key max_coin=100 max_gas=10000000")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let granter = get_str_param("granter", params).map_err(error_err!())?;
+        let grantee = get_str_param("grantee", params).map_err(error_err!())?;
+        let msg_type_url = get_str_param("msg_type_url", params).map_err(error_err!())?;
+        let denom = get_str_param("denom", params).map_err(error_err!())?;
+        let key_alias = get_str_param("key_alias", params).map_err(error_err!())?;
+        let max_coin = get_str_param("max_coin", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_coin must be integer"))?;
+        let max_gas = get_str_param("max_gas", params).map_err(error_err!())?
+            .parse::<u64>().map_err(|_| println_err!("Invalid format of input data: max_gas must be integer"))?;
+        let memo = get_opt_str_param("memo", params).map_err(error_err!())?.unwrap_or("");
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let request = CheqdLedger::build_msg_revoke(granter, grantee, msg_type_url)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let response = build_and_sign_and_broadcast_tx(ctx, &pool_alias, &request, key_alias, denom, max_gas, max_coin, memo, None)?;
+        let parsed_response = CheqdLedger::parse_msg_revoke_resp(&response)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Authorization has been revoked");
+        trace!("execute << {:?}", parsed_response);
+
+        Ok(())
+    }
+}
+
 pub fn build_and_sign_and_broadcast_tx(ctx: &CommandContext,
                                        pool_alias: &str,
                                        request: &[u8],
@@ -284,7 +1107,8 @@ pub fn build_and_sign_and_broadcast_tx(ctx: &CommandContext,
                                        denom: &str,
                                        max_gas: u64,
                                        max_coin: u64,
-                                       memo: &str) -> Result<String, ()> {
+                                       memo: &str,
+                                       fee_granter: Option<&str>) -> Result<String, ()> {
     let wallet_handle = ensure_opened_wallet_handle(&ctx)?;
     let timeout_height = get_timeout_height(pool_alias)?;
 
@@ -307,7 +1131,8 @@ pub fn build_and_sign_and_broadcast_tx(ctx: &CommandContext,
         max_coin,
         denom,
         timeout_height,
-        memo
+        memo,
+        fee_granter
     ).map_err(|err| handle_indy_error(err, None, None, None))?;
 
     let signed  = CheqdKeys::sign(wallet_handle, key_alias, &tx)
@@ -498,6 +1323,42 @@ pub mod tests {
             }
             tear_down_with_wallet(&ctx);
         }
+
+        #[test]
+        pub fn get_validators() {
+            let ctx = setup_with_wallet_and_cheqd_pool();
+            {
+                let cmd = get_validators_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn get_proposals() {
+            let ctx = setup_with_wallet_and_cheqd_pool();
+            {
+                let cmd = get_proposals_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn get_rewards() {
+            let ctx = setup_with_wallet_and_cheqd_pool();
+            let key_info = get_key(&ctx);
+            let account_id = key_info["account_id"].as_str().unwrap().to_string();
+            {
+                let cmd = get_rewards_command::new();
+                let mut params = CommandParams::new();
+                params.insert("delegator", account_id);
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
     }
 
     pub fn create_new_nym(ctx: &CommandContext) {
@@ -207,6 +207,156 @@ pub mod abci_info_command {
     }
 }
 
+pub mod status_command {
+    use super::*;
+
+    command!(CommandMetadata::build("status", "The request returns node information, the latest block and sync state of the node.")
+                .add_example("cheqd-pool status")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let res = match CheqdPoolLibindy::status(&pool_alias) {
+            Ok(resp) => {
+                println_succ!("Status request result \"{}\"", resp);
+                Ok(())
+            },
+            Err(err) => {
+                handle_indy_error(err, None, Some(&pool_alias), None);
+                Err(())
+            },
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod block_command {
+    use super::*;
+
+    command!(CommandMetadata::build("block", "The request returns the block at the given height.")
+                .add_optional_param("height", "Height of the block. The latest block if not set")
+                .add_example("cheqd-pool block height=10")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let height = get_opt_number_param::<i64>("height", params).map_err(error_err!())?.unwrap_or(-1);
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let res = match CheqdPoolLibindy::block(&pool_alias, height) {
+            Ok(resp) => {
+                println_succ!("Block request result \"{}\"", resp);
+                Ok(())
+            },
+            Err(err) => {
+                handle_indy_error(err, None, Some(&pool_alias), None);
+                Err(())
+            },
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod block_results_command {
+    use super::*;
+
+    command!(CommandMetadata::build("block-results", "The request returns results of transactions executed in the block at the given height.")
+                .add_optional_param("height", "Height of the block. The latest block if not set")
+                .add_example("cheqd-pool block-results height=10")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let height = get_opt_number_param::<i64>("height", params).map_err(error_err!())?.unwrap_or(-1);
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let res = match CheqdPoolLibindy::block_results(&pool_alias, height) {
+            Ok(resp) => {
+                println_succ!("Block-results request result \"{}\"", resp);
+                Ok(())
+            },
+            Err(err) => {
+                handle_indy_error(err, None, Some(&pool_alias), None);
+                Err(())
+            },
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod validators_command {
+    use super::*;
+
+    command!(CommandMetadata::build("validators", "The request returns the validator set at the given height.")
+                .add_optional_param("height", "Height of the block. The latest block if not set")
+                .add_optional_param("page", "Number of the page. The first page if not set")
+                .add_optional_param("per_page", "Number of validators per page. The node default if not set")
+                .add_example("cheqd-pool validators height=10 page=1 per_page=30")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let height = get_opt_number_param::<i64>("height", params).map_err(error_err!())?.unwrap_or(-1);
+        let page = get_opt_number_param::<i64>("page", params).map_err(error_err!())?.unwrap_or(-1);
+        let per_page = get_opt_number_param::<i64>("per_page", params).map_err(error_err!())?.unwrap_or(-1);
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let res = match CheqdPoolLibindy::validators(&pool_alias, height, page, per_page) {
+            Ok(resp) => {
+                println_succ!("Validators request result \"{}\"", resp);
+                Ok(())
+            },
+            Err(err) => {
+                handle_indy_error(err, None, Some(&pool_alias), None);
+                Err(())
+            },
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod net_info_command {
+    use super::*;
+
+    command!(CommandMetadata::build("net-info", "The request returns network information of the node: listeners and connected peers.")
+                .add_example("cheqd-pool net-info")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+        let pool_alias = ensure_cheqd_connected_pool(ctx)?;
+
+        let res = match CheqdPoolLibindy::net_info(&pool_alias) {
+            Ok(resp) => {
+                println_succ!("Net-info request result \"{}\"", resp);
+                Ok(())
+            },
+            Err(err) => {
+                handle_indy_error(err, None, Some(&pool_alias), None);
+                Err(())
+            },
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -289,6 +439,61 @@ pub mod tests {
             }
             tear_down_with_wallet(&ctx);
         }
+
+        #[test]
+        pub fn status() {
+            let ctx = setup_with_wallet_and_cheqd_pool();
+            {
+                let cmd = status_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn block() {
+            let ctx = setup_with_wallet_and_cheqd_pool();
+            {
+                let cmd = block_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn block_results() {
+            let ctx = setup_with_wallet_and_cheqd_pool();
+            {
+                let cmd = block_results_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn validators() {
+            let ctx = setup_with_wallet_and_cheqd_pool();
+            {
+                let cmd = validators_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn net_info() {
+            let ctx = setup_with_wallet_and_cheqd_pool();
+            {
+                let cmd = net_info_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
     }
 
     pub fn create_pool(ctx: &CommandContext) {
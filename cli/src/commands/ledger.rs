@@ -464,10 +464,11 @@ pub mod get_validator_info_command {
     command!(CommandMetadata::build("get-validator-info", "Get validator info from all nodes.")
                 .add_optional_param("nodes","The list of node names to send the request")
                 .add_optional_param("timeout"," Time to wait respond from nodes")
-                .add_optional_param("timeout"," Time to wait respond from nodes")
+                .add_optional_param("raw","Print raw replies of the nodes instead of the table (false by default)")
                 .add_example(r#"ledger get-validator-info"#)
                 .add_example(r#"ledger get-validator-info nodes=Node1,Node2"#)
                 .add_example(r#"ledger get-validator-info nodes=Node1,Node2 timeout=150"#)
+                .add_example(r#"ledger get-validator-info raw=true"#)
                 .finalize()
     );
 
@@ -480,6 +481,7 @@ pub mod get_validator_info_command {
 
         let nodes = get_opt_str_array_param("nodes", params).map_err(error_err!())?;
         let timeout = get_opt_number_param::<i32>("timeout", params).map_err(error_err!())?;
+        let raw = get_opt_bool_param("raw", params).map_err(error_err!())?.unwrap_or(false);
 
         let request = Ledger::build_get_validator_info_request(&submitter_did)
             .map_err(|err| handle_indy_error(err, None, None, None))?;
@@ -501,6 +503,10 @@ pub mod get_validator_info_command {
             }
         };
 
+        if !raw {
+            return print_validator_info(&response);
+        }
+
         println_succ!("Validator Info:");
 
         let mut lines: Vec<String> = Vec::new();
@@ -533,6 +539,55 @@ pub mod get_validator_info_command {
     }
 }
 
+fn print_validator_info(response: &str) -> Result<(), ()> {
+    let validator_info = Ledger::parse_get_validator_info_response(response)
+        .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+    let validator_info = serde_json::from_str::<JSONValue>(&validator_info)
+        .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+
+    println_succ!("Validator Info:");
+
+    let nodes = validator_info["nodes"].as_array().cloned().unwrap_or_default();
+
+    let rows = nodes.iter()
+        .map(|node| {
+            let mut row = node.clone();
+            row["status"] = match node["error"].as_str() {
+                Some(error) => json!(format!("{}: {}", node["status"].as_str().unwrap_or(""), error)),
+                None => node["status"].clone(),
+            };
+            row
+        })
+        .collect::<Vec<JSONValue>>();
+
+    print_list_table(&rows,
+                     &[("node", "Node"),
+                         ("status", "Status"),
+                         ("mode", "Mode"),
+                         ("indy_node_version", "Indy Node"),
+                         ("uptime", "Uptime (sec)"),
+                         ("view_no", "View No"),
+                         ("view_change_in_progress", "View Change"),
+                         ("catchup_status", "Catchup Status"),
+                         ("ledger_sizes", "Ledger Sizes"),
+                         ("unreachable_nodes", "Unreachable Nodes")],
+                     "There are no replies from the nodes");
+
+    let inconsistencies = validator_info["summary"]["inconsistencies"].as_array().cloned().unwrap_or_default();
+
+    if inconsistencies.is_empty() {
+        println_succ!("No inconsistencies between the nodes have been found");
+    } else {
+        println_warn!("Inconsistencies:");
+        for inconsistency in inconsistencies {
+            println_warn!("\t{}", inconsistency.as_str().unwrap_or(""));
+        }
+    }
+
+    Ok(())
+}
+
 pub mod get_schema_command {
     use super::*;
 
@@ -2042,6 +2097,103 @@ pub mod get_acceptance_mechanisms_command {
     }
 }
 
+pub mod ledgers_freeze_command {
+    use super::*;
+
+    command!(CommandMetadata::build("ledgers-freeze", r#"Freeze list of ledgers.
+        Frozen ledgers can not be written anymore but can still be read."#)
+                .add_required_param("ledgers_ids","List of ledgers IDs for freezing. Example: 1,2,28")
+                .add_optional_param("sign","Sign the request (True by default)")
+                .add_optional_param("send","Send the request to the Ledger (True by default). If false then created request will be printed and stored into CLI context.")
+                .add_example("ledger ledgers-freeze ledgers_ids=1,2,28")
+                .add_example("ledger ledgers-freeze ledgers_ids=28 send=false")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+        let submitter_did = ensure_active_did(&ctx)?;
+
+        let ledgers_ids = get_str_array_param("ledgers_ids", params).map_err(error_err!())?;
+        let ledgers_ids = parse_ledgers_ids(&ledgers_ids)?;
+
+        let request = Ledger::build_ledgers_freeze_request(&submitter_did, &ledgers_ids)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let (_, response): (String, Response<serde_json::Value>) =
+            send_write_request!(ctx, params, &request, wallet_handle, &wallet_name, &submitter_did);
+
+        let res = handle_transaction_response(response)
+            .map(|result| print_transaction_response(result,
+                                                     "LedgersFreeze request has been sent to Ledger.",
+                                                     None,
+                                                     &[("ledgers_ids", "Ledgers IDs")],
+                                                     true));
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod get_frozen_ledgers_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get-frozen-ledgers", r#"Get a list of frozen ledgers"#)
+                .add_optional_param("send","Send the request to the Ledger (True by default). If false then created request will be printed and stored into CLI context.")
+                .add_example("ledger get-frozen-ledgers")
+                .add_example("ledger get-frozen-ledgers send=false")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let submitter_did = get_active_did(&ctx);
+
+        let request = Ledger::build_get_frozen_ledgers_request(submitter_did.as_ref().map(String::as_str))
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let (response_json, _) = send_read_request!(&ctx, params, &request, submitter_did.as_ref().map(String::as_str));
+
+        let frozen_ledgers = Ledger::parse_get_frozen_ledgers_response(&response_json)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let frozen_ledgers: BTreeMap<String, serde_json::Value> = serde_json::from_str(&frozen_ledgers)
+            .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+
+        let frozen_ledgers = frozen_ledgers
+            .into_iter()
+            .map(|(ledger_id, frozen_ledger)| json!({
+                "ledger_id": ledger_id,
+                "ledger": frozen_ledger["ledger"],
+                "state": frozen_ledger["state"],
+                "seq_no": frozen_ledger["seq_no"],
+            }))
+            .collect::<Vec<serde_json::Value>>();
+
+        print_list_table(&frozen_ledgers,
+                         &[("ledger_id", "Ledger ID"),
+                             ("ledger", "Ledger Root Hash"),
+                             ("state", "State Root Hash"),
+                             ("seq_no", "Last Seq No")],
+                         "There are no frozen ledgers");
+
+        trace!("execute <<");
+        Ok(())
+    }
+}
+
+fn parse_ledgers_ids(ledgers_ids: &[&str]) -> Result<String, ()> {
+    let ledgers_ids = ledgers_ids
+        .iter()
+        .map(|ledger_id| ledger_id.trim().parse::<u64>()
+            .map_err(|_| println_err!("Invalid format of Ledgers IDs: {} is not a number", ledger_id)))
+        .collect::<Result<Vec<u64>, ()>>()?;
+
+    serialize(&ledgers_ids)
+}
+
 pub fn set_author_agreement(ctx: &CommandContext, request: &mut String) -> Result<(), ()> {
     if let Some((text, version, acc_mech_type, time_of_acceptance)) = get_transaction_author_info(&ctx) {
         if acc_mech_type.is_empty() {
@@ -3390,6 +3542,19 @@ pub mod tests {
             tear_down_with_wallet_and_pool(&ctx);
         }
 
+        #[test]
+        pub fn get_validator_info_works_for_raw() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = get_validator_info_command::new();
+                let mut params = CommandParams::new();
+                params.insert("raw", "true".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
         #[test]
         pub fn get_validator_info_works_for_unknown_node() {
             let ctx = setup_with_wallet_and_pool();
@@ -5079,6 +5244,55 @@ pub mod tests {
         }
     }
 
+    mod ledgers_freeze {
+        use super::*;
+
+        #[test]
+        pub fn ledgers_freeze_works_for_send_false() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = ledgers_freeze_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledgers_ids", "28,29".to_string());
+                params.insert("send", "false".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let transaction: serde_json::Value = serde_json::from_str(&get_transaction(&ctx).unwrap()).unwrap();
+            assert_eq!(json!([28, 29]), transaction["operation"]["ledgers_ids"]);
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn ledgers_freeze_works_for_invalid_ledgers_ids() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = ledgers_freeze_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledgers_ids", "pool,config".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
+    mod get_frozen_ledgers {
+        use super::*;
+
+        #[test]
+        pub fn get_frozen_ledgers_works() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = get_frozen_ledgers_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
     fn _path() -> (::std::path::PathBuf, String) {
         let mut path = crate::utils::environment::EnvironmentUtils::indy_home_path();
         path.push("transaction");
@@ -22,8 +22,12 @@ impl CheqdLedger {
                     max_coin_amount: u64,
                     max_coin_denom: &str,
                     timeout_height: u64,
-                    memo: &str) -> Result<Vec<u8>, IndyError> {
-        cheqd_ledger::auth::build_tx(pool_alias, sender_public_key, msg, account_number, sequence_number, max_gas, max_coin_amount, max_coin_denom, timeout_height, memo).wait()
+                    memo: &str,
+                    fee_granter: Option<&str>) -> Result<Vec<u8>, IndyError> {
+        match fee_granter {
+            Some(fee_granter) => cheqd_ledger::auth::build_tx_with_fee_granter(pool_alias, sender_public_key, msg, account_number, sequence_number, max_gas, max_coin_amount, max_coin_denom, timeout_height, memo, fee_granter).wait(),
+            None => cheqd_ledger::auth::build_tx(pool_alias, sender_public_key, msg, account_number, sequence_number, max_gas, max_coin_amount, max_coin_denom, timeout_height, memo).wait(),
+        }
     }
 
     pub fn build_msg_create_nym(did: &str,
@@ -73,4 +77,175 @@ impl CheqdLedger {
     pub fn parse_query_all_nym_resp(query_resp: &str) -> Result<String, IndyError> {
         cheqd_ledger::cheqd::parse_query_all_nym_resp(query_resp).wait()
     }
-}
\ No newline at end of file
+
+    pub fn build_msg_delegate(delegator_address: &str, validator_address: &str, amount: &str, denom: &str) -> Result<Vec<u8>, IndyError> {
+        cheqd_ledger::staking::build_msg_delegate(delegator_address, validator_address, amount, denom).wait()
+    }
+
+    pub fn parse_msg_delegate_resp(commit_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::staking::parse_msg_delegate_resp(commit_resp).wait()
+    }
+
+    pub fn build_msg_undelegate(delegator_address: &str, validator_address: &str, amount: &str, denom: &str) -> Result<Vec<u8>, IndyError> {
+        cheqd_ledger::staking::build_msg_undelegate(delegator_address, validator_address, amount, denom).wait()
+    }
+
+    pub fn parse_msg_undelegate_resp(commit_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::staking::parse_msg_undelegate_resp(commit_resp).wait()
+    }
+
+    pub fn build_msg_begin_redelegate(delegator_address: &str, validator_src_address: &str, validator_dst_address: &str, amount: &str, denom: &str) -> Result<Vec<u8>, IndyError> {
+        cheqd_ledger::staking::build_msg_begin_redelegate(delegator_address, validator_src_address, validator_dst_address, amount, denom).wait()
+    }
+
+    pub fn parse_msg_begin_redelegate_resp(commit_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::staking::parse_msg_begin_redelegate_resp(commit_resp).wait()
+    }
+
+    pub fn build_query_delegation(delegator_address: &str, validator_address: &str) -> Result<String, IndyError> {
+        cheqd_ledger::staking::build_query_delegation(delegator_address, validator_address).wait()
+    }
+
+    pub fn parse_query_delegation_resp(query_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::staking::parse_query_delegation_resp(query_resp).wait()
+    }
+
+    pub fn build_query_delegator_delegations(delegator_address: &str, pagination: Option<&str>) -> Result<String, IndyError> {
+        cheqd_ledger::staking::build_query_delegator_delegations(delegator_address, pagination).wait()
+    }
+
+    pub fn parse_query_delegator_delegations_resp(query_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::staking::parse_query_delegator_delegations_resp(query_resp).wait()
+    }
+
+    pub fn build_query_validators(status: Option<&str>, pagination: Option<&str>) -> Result<String, IndyError> {
+        cheqd_ledger::staking::build_query_validators(status, pagination).wait()
+    }
+
+    pub fn parse_query_validators_resp(query_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::staking::parse_query_validators_resp(query_resp).wait()
+    }
+
+    pub fn build_msg_withdraw_delegator_reward(delegator_address: &str, validator_address: &str) -> Result<Vec<u8>, IndyError> {
+        cheqd_ledger::distribution::build_msg_withdraw_delegator_reward(delegator_address, validator_address).wait()
+    }
+
+    pub fn parse_msg_withdraw_delegator_reward_resp(commit_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::distribution::parse_msg_withdraw_delegator_reward_resp(commit_resp).wait()
+    }
+
+    pub fn build_query_delegation_total_rewards(delegator_address: &str) -> Result<String, IndyError> {
+        cheqd_ledger::distribution::build_query_delegation_total_rewards(delegator_address).wait()
+    }
+
+    pub fn parse_query_delegation_total_rewards_resp(query_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::distribution::parse_query_delegation_total_rewards_resp(query_resp).wait()
+    }
+
+    pub fn build_msg_submit_text_proposal(title: &str, description: &str, proposer: &str, deposit_amount: &str, deposit_denom: &str) -> Result<Vec<u8>, IndyError> {
+        cheqd_ledger::gov::build_msg_submit_text_proposal(title, description, proposer, deposit_amount, deposit_denom).wait()
+    }
+
+    pub fn build_msg_submit_param_change_proposal(title: &str, description: &str, changes: &str, proposer: &str, deposit_amount: &str, deposit_denom: &str) -> Result<Vec<u8>, IndyError> {
+        cheqd_ledger::gov::build_msg_submit_param_change_proposal(title, description, changes, proposer, deposit_amount, deposit_denom).wait()
+    }
+
+    pub fn parse_msg_submit_proposal_resp(commit_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::gov::parse_msg_submit_proposal_resp(commit_resp).wait()
+    }
+
+    pub fn build_msg_deposit(proposal_id: u64, depositor: &str, amount: &str, denom: &str) -> Result<Vec<u8>, IndyError> {
+        cheqd_ledger::gov::build_msg_deposit(proposal_id, depositor, amount, denom).wait()
+    }
+
+    pub fn parse_msg_deposit_resp(commit_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::gov::parse_msg_deposit_resp(commit_resp).wait()
+    }
+
+    pub fn build_msg_vote(proposal_id: u64, voter: &str, option: &str) -> Result<Vec<u8>, IndyError> {
+        cheqd_ledger::gov::build_msg_vote(proposal_id, voter, option).wait()
+    }
+
+    pub fn parse_msg_vote_resp(commit_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::gov::parse_msg_vote_resp(commit_resp).wait()
+    }
+
+    pub fn build_msg_vote_weighted(proposal_id: u64, voter: &str, options: &str) -> Result<Vec<u8>, IndyError> {
+        cheqd_ledger::gov::build_msg_vote_weighted(proposal_id, voter, options).wait()
+    }
+
+    pub fn parse_msg_vote_weighted_resp(commit_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::gov::parse_msg_vote_weighted_resp(commit_resp).wait()
+    }
+
+    pub fn build_query_proposal(proposal_id: u64) -> Result<String, IndyError> {
+        cheqd_ledger::gov::build_query_proposal(proposal_id).wait()
+    }
+
+    pub fn parse_query_proposal_resp(query_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::gov::parse_query_proposal_resp(query_resp).wait()
+    }
+
+    pub fn build_query_proposals(status: Option<&str>, pagination: Option<&str>) -> Result<String, IndyError> {
+        cheqd_ledger::gov::build_query_proposals(status, pagination).wait()
+    }
+
+    pub fn parse_query_proposals_resp(query_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::gov::parse_query_proposals_resp(query_resp).wait()
+    }
+
+    pub fn build_query_tally_result(proposal_id: u64) -> Result<String, IndyError> {
+        cheqd_ledger::gov::build_query_tally_result(proposal_id).wait()
+    }
+
+    pub fn parse_query_tally_result_resp(query_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::gov::parse_query_tally_result_resp(query_resp).wait()
+    }
+
+    pub fn build_query_vote(proposal_id: u64, voter: &str) -> Result<String, IndyError> {
+        cheqd_ledger::gov::build_query_vote(proposal_id, voter).wait()
+    }
+
+    pub fn parse_query_vote_resp(query_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::gov::parse_query_vote_resp(query_resp).wait()
+    }
+
+    pub fn build_msg_grant_allowance(granter: &str, grantee: &str, spend_limit_amount: Option<&str>, spend_limit_denom: Option<&str>, expiration: i64) -> Result<Vec<u8>, IndyError> {
+        cheqd_ledger::feegrant::build_msg_grant_allowance(granter, grantee, spend_limit_amount, spend_limit_denom, expiration).wait()
+    }
+
+    pub fn parse_msg_grant_allowance_resp(commit_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::feegrant::parse_msg_grant_allowance_resp(commit_resp).wait()
+    }
+
+    pub fn build_msg_revoke_allowance(granter: &str, grantee: &str) -> Result<Vec<u8>, IndyError> {
+        cheqd_ledger::feegrant::build_msg_revoke_allowance(granter, grantee).wait()
+    }
+
+    pub fn parse_msg_revoke_allowance_resp(commit_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::feegrant::parse_msg_revoke_allowance_resp(commit_resp).wait()
+    }
+    pub fn build_msg_grant(granter: &str, grantee: &str, msg_type_url: &str, expiration: i64) -> Result<Vec<u8>, IndyError> {
+        cheqd_ledger::authz::build_msg_grant(granter, grantee, msg_type_url, expiration).wait()
+    }
+
+    pub fn parse_msg_grant_resp(commit_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::authz::parse_msg_grant_resp(commit_resp).wait()
+    }
+
+    pub fn build_msg_exec(grantee: &str, msg: &[u8]) -> Result<Vec<u8>, IndyError> {
+        cheqd_ledger::authz::build_msg_exec(grantee, msg).wait()
+    }
+
+    pub fn parse_msg_exec_resp(commit_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::authz::parse_msg_exec_resp(commit_resp).wait()
+    }
+
+    pub fn build_msg_revoke(granter: &str, grantee: &str, msg_type_url: &str) -> Result<Vec<u8>, IndyError> {
+        cheqd_ledger::authz::build_msg_revoke(granter, grantee, msg_type_url).wait()
+    }
+
+    pub fn parse_msg_revoke_resp(commit_resp: &str) -> Result<String, IndyError> {
+        cheqd_ledger::authz::parse_msg_revoke_resp(commit_resp).wait()
+    }
+}
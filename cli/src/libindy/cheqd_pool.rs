@@ -28,4 +28,24 @@ impl CheqdPool {
     pub fn abci_info(pool_alias: &str) -> Result<String, IndyError> {
         cheqd_pool::abci_info(pool_alias).wait()
     }
-}
\ No newline at end of file
+
+    pub fn status(pool_alias: &str) -> Result<String, IndyError> {
+        cheqd_pool::status(pool_alias).wait()
+    }
+
+    pub fn block(pool_alias: &str, height: i64) -> Result<String, IndyError> {
+        cheqd_pool::block(pool_alias, height).wait()
+    }
+
+    pub fn block_results(pool_alias: &str, height: i64) -> Result<String, IndyError> {
+        cheqd_pool::block_results(pool_alias, height).wait()
+    }
+
+    pub fn validators(pool_alias: &str, height: i64, page: i64, per_page: i64) -> Result<String, IndyError> {
+        cheqd_pool::validators(pool_alias, height, page, per_page).wait()
+    }
+
+    pub fn net_info(pool_alias: &str) -> Result<String, IndyError> {
+        cheqd_pool::net_info(pool_alias).wait()
+    }
+}
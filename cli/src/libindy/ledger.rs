@@ -59,6 +59,10 @@ impl Ledger {
         ledger::build_get_validator_info_request(submitter_did).wait()
     }
 
+    pub fn parse_get_validator_info_response(get_validator_info_response: &str) -> Result<String, IndyError> {
+        ledger::parse_get_validator_info_response(get_validator_info_response).wait()
+    }
+
     pub fn build_get_cred_def_request(submitter_did: Option<&str>, id: &str) -> Result<String, IndyError> {
         ledger::build_get_cred_def_request(submitter_did, id).wait()
     }
@@ -116,6 +120,18 @@ impl Ledger {
         ledger::build_disable_all_txn_author_agreements_request(submitter_did).wait()
     }
 
+    pub fn build_ledgers_freeze_request(submitter_did: &str, ledgers_ids: &str) -> Result<String, IndyError> {
+        ledger::build_ledgers_freeze_request(submitter_did, ledgers_ids).wait()
+    }
+
+    pub fn build_get_frozen_ledgers_request(submitter_did: Option<&str>) -> Result<String, IndyError> {
+        ledger::build_get_frozen_ledgers_request(submitter_did).wait()
+    }
+
+    pub fn parse_get_frozen_ledgers_response(get_frozen_ledgers_response: &str) -> Result<String, IndyError> {
+        ledger::parse_get_frozen_ledgers_response(get_frozen_ledgers_response).wait()
+    }
+
     pub fn build_acceptance_mechanisms_request(submitter_did: &str, aml: &str, version: &str, aml_context: Option<&str>) -> Result<String, IndyError> {
         ledger::build_acceptance_mechanisms_request(submitter_did, aml, version, aml_context).wait()
     }
@@ -192,6 +192,8 @@ cfg_if::cfg_if! {
         .add_command(ledger::get_acceptance_mechanisms_command::new())
         .add_command(ledger::endorse_transaction_command::new())
         .add_command(ledger::taa_disable_all_command::new())
+        .add_command(ledger::ledgers_freeze_command::new())
+        .add_command(ledger::get_frozen_ledgers_command::new())
         .finalize_group()
         .add_group(payment_address::group::new())
         .add_command(payment_address::new_command::new())
@@ -207,6 +209,27 @@ cfg_if::cfg_if! {
         .add_command(cheqd_ledger::get_all_nym_command::new())
         .add_command(cheqd_ledger::bank_send_command::new())
         .add_command(cheqd_ledger::get_balance_command::new())
+        .add_command(cheqd_ledger::delegate_command::new())
+        .add_command(cheqd_ledger::undelegate_command::new())
+        .add_command(cheqd_ledger::redelegate_command::new())
+        .add_command(cheqd_ledger::withdraw_rewards_command::new())
+        .add_command(cheqd_ledger::get_delegation_command::new())
+        .add_command(cheqd_ledger::get_delegations_command::new())
+        .add_command(cheqd_ledger::get_validators_command::new())
+        .add_command(cheqd_ledger::get_rewards_command::new())
+        .add_command(cheqd_ledger::submit_text_proposal_command::new())
+        .add_command(cheqd_ledger::submit_param_change_proposal_command::new())
+        .add_command(cheqd_ledger::deposit_command::new())
+        .add_command(cheqd_ledger::vote_command::new())
+        .add_command(cheqd_ledger::vote_weighted_command::new())
+        .add_command(cheqd_ledger::get_proposal_command::new())
+        .add_command(cheqd_ledger::get_proposals_command::new())
+        .add_command(cheqd_ledger::get_tally_command::new())
+        .add_command(cheqd_ledger::get_vote_command::new())
+        .add_command(cheqd_ledger::grant_fee_allowance_command::new())
+        .add_command(cheqd_ledger::revoke_fee_allowance_command::new())
+        .add_command(cheqd_ledger::grant_authz_command::new())
+        .add_command(cheqd_ledger::revoke_authz_command::new())
         .finalize_group()
         .add_group(cheqd_pool::group::new())
         .add_command(cheqd_pool::add_command::new())
@@ -215,6 +238,11 @@ cfg_if::cfg_if! {
         .add_command(cheqd_pool::get_config_command::new())
         .add_command(cheqd_pool::get_all_config_command::new())
         .add_command(cheqd_pool::abci_info_command::new())
+        .add_command(cheqd_pool::status_command::new())
+        .add_command(cheqd_pool::block_command::new())
+        .add_command(cheqd_pool::block_results_command::new())
+        .add_command(cheqd_pool::validators_command::new())
+        .add_command(cheqd_pool::net_info_command::new())
         .finalize_group()
         .add_group(cheqd_keys::group::new())
         .add_command(cheqd_keys::add_command::new())
@@ -291,6 +319,8 @@ cfg_if::cfg_if! {
             .add_command(ledger::get_acceptance_mechanisms_command::new())
             .add_command(ledger::endorse_transaction_command::new())
             .add_command(ledger::taa_disable_all_command::new())
+            .add_command(ledger::ledgers_freeze_command::new())
+            .add_command(ledger::get_frozen_ledgers_command::new())
             .finalize_group()
             .add_group(payment_address::group::new())
             .add_command(payment_address::new_command::new())
@@ -289,6 +289,38 @@ extern "C" {
                                                                           const char *const transport_vk)
                                                  );
 
+    /// Resolves W3C DID document for the given Indy DID.
+    ///
+    /// DID document is built from NYM verkey and diddocContent (if present),
+    /// otherwise services are built from endpoint ATTRIB.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// pool_handle: Pool handle (created by open_pool).
+    ///     Pass 0 to use the pool opened for the namespace of did:indy DID.
+    /// did - The DID to resolve.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - resolution_result - DID resolution result json with DID document and ledger metadata.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_resolve_did(indy_handle_t     command_handle,
+                                         indy_handle_t     pool_handle,
+                                         const char *const did,
+
+                                         void              (*cb)(indy_handle_t     command_handle,
+                                                                 indy_error_t      err,
+                                                                 const char *const resolution_result)
+                                        );
+
     /// Saves/replaces the meta information for the giving DID in the wallet.
     ///
     /// #Params
@@ -68,6 +68,45 @@ extern "C" {
                                                                  const char*   request_result_json)
                                            );
 
+    /// Publishes a batch of request messages to validator pool (no signing, like submit_request).
+    ///
+    /// Requests are pipelined: up to `max_concurrency` requests are sent to the pool at the same time.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// requests_json: array of prepared request jsons.
+    /// options_json: (Optional) submit options json:
+    /// {
+    ///     "max_concurrency": int (optional, 10 by default) - the number of requests sent at the same time,
+    ///     "stop_on_failure": bool (optional, false by default) - don't send the rest of requests after an error or
+    ///         rejected request. Requests sent before the failure are still awaited.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Array of results in the order of requests:
+    /// [
+    ///     {"status": "reply", "reply": <reply json>} - the request is replied by the pool,
+    ///     {"status": "rejected", "reply": <reply json>} - the request is rejected by the pool (REJECT or REQNACK),
+    ///     {"status": "error", "error_code": <int>, "message": <string>} - the request failed with libindy error,
+    ///     {"status": "skipped"} - the request wasn't sent as a previous one failed
+    /// ]
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_submit_requests(indy_handle_t command_handle,
+                                             indy_handle_t pool_handle,
+                                             const char *  requests_json,
+                                             const char *  options_json,
+
+                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                  indy_error_t  err,
+                                                                  const char*   results_json)
+                                            );
+
     /// Send action to particular nodes of validator pool.
     ///
     /// The list of requests can be send:
@@ -104,6 +143,55 @@ extern "C" {
                                                                 const char*   request_result_json)
                                            );
 
+    /// Downloads transactions of the ledger into the local copy kept for the pool.
+    ///
+    /// Transactions are requested in batches by GET_TXN requests starting right after the last
+    /// locally stored one, so repeated calls resume the download incrementally.
+    /// Every transaction is checked against the audit path and the multi-signed ledger root hash
+    /// returned by the pool, and the local copy extended with each batch is checked against
+    /// the ledger merkle root before the batch is stored.
+    ///
+    /// Transactions are stored one json per line in `<pool directory>/ledgers/<ledger id>.txn`:
+    /// {"txn": {..}, "txnMetadata": {..}, "ver": "..", "reqSignature": {..}}
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// ledger_type: type of the ledger to download:
+    ///     DOMAIN - used default,
+    ///     POOL,
+    ///     CONFIG
+    ///     any number
+    /// config: (Optional) download config json:
+    /// {
+    ///     "batch_size": <int> (Optional) number of transactions requested at once (100 by default),
+    ///     "to": <int> (Optional) seq_no of the last transaction to download (the whole ledger by default)
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Download status json:
+    /// {
+    ///     "ledgerId": <int> id of the ledger,
+    ///     "ledgerSize": <int> size of the ledger reported by the pool,
+    ///     "txnsCount": <int> number of transactions in the local copy,
+    ///     "rootHash": <string> base58 merkle root of the local copy,
+    ///     "path": <string> path to the local copy
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_download_ledger(indy_handle_t command_handle,
+                                             indy_handle_t pool_handle,
+                                             const char *  ledger_type,
+                                             const char *  config,
+
+                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                  indy_error_t  err,
+                                                                  const char*   status_json)
+                                             );
+
     /// Signs request message.
     ///
     /// Adds submitter information to passed request json, signs it with submitter
@@ -567,6 +655,61 @@ extern "C" {
                                                                             const char*   request_json)
                                                        );
 
+        /// Parses replies of the nodes to GET_VALIDATOR_INFO request into typed validator info
+        /// and summarizes it for the whole pool.
+        ///
+        /// #Params
+        /// command_handle: command handle to map callback to caller context.
+        /// get_validator_info_response: response of GET_VALIDATOR_INFO request: json object mapping node aliases
+        ///     to their replies (as returned by indy_sign_and_submit_request and indy_submit_action) or a single node reply.
+        /// cb: Callback that takes command result as parameter.
+        ///
+        /// #Returns
+        /// Validator info json:
+        /// {
+        ///     "nodes": [
+        ///         {
+        ///             "node": string - node alias,
+        ///             "status": string - "ok", "timeout" or "error",
+        ///             "error": string (optional) - why the reply can't be parsed,
+        ///             "mode": string (optional) - node mode (e.g. "participating"),
+        ///             "indy_node_version": string (optional),
+        ///             "sovrin_version": string (optional),
+        ///             "uptime": int (optional) - node uptime in seconds,
+        ///             "timestamp": int (optional) - time the info was collected,
+        ///             "view_no": int (optional),
+        ///             "view_change_in_progress": bool (optional),
+        ///             "catchup_status": {"<ledger>": string} - catch-up status per ledger (e.g. "synced"),
+        ///             "ledger_sizes": {"<ledger>": int} - number of transactions per ledger,
+        ///             "freshness": {"<ledger>": {"last_updated_time": string (optional), "has_write_consensus": bool (optional)}},
+        ///             "unreachable_nodes": [string] - nodes the node can't reach
+        ///         }
+        ///     ],
+        ///     "summary": {
+        ///         "nodes_count": int,
+        ///         "replied_nodes": [string],
+        ///         "timeout_nodes": [string],
+        ///         "failed_nodes": [string],
+        ///         "indy_node_versions": {"<version>": [string]} - nodes grouped by indy-node version,
+        ///         "view_numbers": {"<view_no>": [string]} - nodes grouped by view number,
+        ///         "ledger_sizes": {"<ledger>": {"<size>": [string]}} - nodes grouped by ledger size,
+        ///         "unreachable_nodes": {"<node>": [string]} - nodes reported unreachable and the nodes reported them,
+        ///         "inconsistencies": [string] - human readable descriptions of the problems found
+        ///     }
+        /// }
+        /// Ledgers are named "pool", "domain", "config" and "audit".
+        ///
+        /// #Errors
+        /// Common*
+
+        extern indy_error_t indy_parse_get_validator_info_response(indy_handle_t command_handle,
+                                                                   const char *  get_validator_info_response,
+
+                                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                                        indy_error_t  err,
+                                                                                        const char*   validator_info_json)
+                                                                   );
+
 
     /// Builds a GET_TXN request. Request to get any transaction by its seq_no.
     ///
@@ -1117,6 +1260,56 @@ extern "C" {
                                                                               const char*   request_json)
                                                         );
 
+    /// Evaluates authentication rules locally to predict whether the ledger would accept a prepared write request.
+    ///
+    /// Signatures are not verified, only their presence in the request is taken into account.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_auth_rule_response: response of GET_AUTH_RULE request (see `indy_build_get_auth_rule_request`).
+    /// action_json: the action to evaluate rules for:
+    ///     {
+    ///         auth_type: ledger transaction alias or associated value,
+    ///         auth_action: type of an action. Can be either "ADD" or "EDIT",
+    ///         field: transaction field,
+    ///         old_value: (Optional) old value of a field (mandatory for EDIT action),
+    ///         new_value: (Optional) new value of a field,
+    ///         owner: (Optional) DID of the owner of the changed object (the request author by default),
+    ///     }
+    /// request_json: the prepared (and signed as far as possible) request.
+    /// signers_json: NYM data of the request signers and endorser as returned by `indy_parse_get_nym_response`:
+    ///     [{"did": string, "verkey": string, "role": string}]
+    ///     Signers without NYM data are treated as unknown for the ledger.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// evaluation_json: {
+    ///     satisfied: bool - whether the request meets the rule,
+    ///     forbidden: bool - whether the action is forbidden,
+    ///     missing_signatures: [{
+    ///         role: (Optional) role which signers must have ("*" means any role),
+    ///         sig_count: number of missing signatures,
+    ///         need_to_be_owner: whether a signer must be an owner of the object,
+    ///         off_ledger_signature: whether signatures of DIDs unknown for the ledger are accepted,
+    ///     }] - for OR constraints the alternative requiring the least signatures is reported,
+    ///     missing_endorsement: bool - whether the request names an Endorser which hasn't signed it
+    ///                                 or has no role allowing to endorse,
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_evaluate_auth_rule(indy_handle_t command_handle,
+                                                const char *  get_auth_rule_response,
+                                                const char *  action_json,
+                                                const char *  request_json,
+                                                const char *  signers_json,
+
+                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                     indy_error_t  err,
+                                                                     const char*   evaluation_json)
+                                               );
+
     /// Builds a TXN_AUTHR_AGRMT request. Request to add a new version of Transaction Author Agreement to the ledger.
     ///
     /// EXPERIMENTAL
@@ -1326,6 +1519,51 @@ extern "C" {
                                                                                                     const char*   request_with_meta_json)
                                                                                );
 
+    /// Sets transaction author agreement acceptance for the opened pool.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// indy_sign_and_submit_request appends the acceptance to domain ledger write requests
+    /// sent to this pool (unless they already contain acceptance data).
+    /// Before that the agreement in force is read from the ledger (and re-read every 10 minutes
+    /// or when the ledger rejects the acceptance). If it differs from the accepted one,
+    /// LedgerTxnAuthorAgreementChanged error is returned and the new agreement must be accepted
+    /// by calling this function again. If the ledger doesn't require the agreement, nothing is appended.
+    ///
+    /// The acceptance is stored with the pool ledger configuration and set again on every opening of the pool,
+    /// until another acceptance is set or the configuration is deleted.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// text and version - (optional) raw data about TAA from ledger.
+    ///     These parameters should be passed together.
+    ///     These parameters are required if taa_digest parameter is omitted.
+    /// taa_digest - (optional) digest on text and version.
+    ///     Digest is sha256 hash calculated on concatenated strings: version || text.
+    ///     This parameter is required if text and version parameters are omitted.
+    /// mechanism - mechanism how user has accepted the TAA
+    /// time - UTC timestamp when user has accepted the TAA. Note that the time portion will be discarded to avoid a privacy risk.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_set_pool_txn_author_agreement_acceptance(indy_handle_t command_handle,
+                                                                      indy_handle_t pool_handle,
+                                                                      const char *  text,
+                                                                      const char *  version,
+                                                                      const char *  taa_digest,
+                                                                      const char *  mechanism,
+                                                                      indy_u64_t    time,
+
+                                                                      void           (*cb)(indy_handle_t command_handle_,
+                                                                                           indy_error_t  err)
+                                                                      );
+
     /// Append Endorser to an existing request.
     ///
     /// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
@@ -1354,6 +1592,376 @@ extern "C" {
                                                                           const char*   out_request_json)
                                                      );
 
+    /// Builds an endorsement envelope: a request prepared for the Endorser together with the list
+    /// of DIDs which must sign it. The envelope is stored in the wallet as a pending endorsement
+    /// and can be passed to other parties (e.g. the Endorser) to collect their signatures.
+    ///
+    /// Note: Signatures present in the request are removed as appending of the Endorser changes signed data.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// request_json: original request built by the transaction author.
+    /// endorser_did: DID of the Endorser that will submit the transaction.
+    /// options_json: (Optional) envelope options:
+    /// {
+    ///     "required_signers": [string] - (Optional) DIDs which must sign the request in addition to the author and Endorser,
+    ///                                     or in addition to the ones required by `auth_rule`,
+    ///     "auth_rule": (Optional) auth rule the request must meet (see `indy_evaluate_auth_rule`):
+    ///     {
+    ///         "get_auth_rule_response": string - response of GET_AUTH_RULE request,
+    ///         "action": json - the action to evaluate rules for,
+    ///         "signers": [json] - NYM data of the signers as returned by `indy_parse_get_nym_response`,
+    ///     }
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// envelope_json: {
+    ///     "id": string - identifier of the pending endorsement,
+    ///     "request": json - the request with Endorser field appended and signatures collected so far,
+    ///     "endorser": string - DID of the Endorser,
+    ///     "required_signers": [string] or null - DIDs which must sign the request. Derived from `auth_rule` if it is passed,
+    ///                           null if it isn't and no explicit signers are passed or the rule can't be met,
+    ///     "auth_rule": (Optional) json - auth rule the request must meet,
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_build_endorsement_envelope(indy_handle_t command_handle,
+                                                        indy_handle_t wallet_handle,
+                                                        const char *  request_json,
+                                                        const char *  endorser_did,
+                                                        const char *  options_json,
+
+                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err,
+                                                                             const char*   envelope_json)
+                                                       );
+
+    /// Adds a signature to the request of an endorsement envelope (see `indy_build_endorsement_envelope`).
+    ///
+    /// The signed envelope is stored (or updated) in the wallet as a pending endorsement.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// envelope_json: endorsement envelope.
+    /// signer_did: DID of the signer. The key of the DID must be in the wallet.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Endorsement envelope json with the signature added.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+
+    extern indy_error_t indy_sign_endorsement_envelope(indy_handle_t command_handle,
+                                                       indy_handle_t wallet_handle,
+                                                       const char *  envelope_json,
+                                                       const char *  signer_did,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   envelope_json)
+                                                      );
+
+    /// Checks whether an endorsement envelope collected all required signatures.
+    ///
+    /// If the envelope contains an auth rule, the request is also evaluated against it (see `indy_evaluate_auth_rule`).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// envelope_json: endorsement envelope.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// status_json: {
+    ///     "complete": bool - whether the request can be submitted,
+    ///     "required_signers_determined": bool - false if the envelope doesn't know its required signers,
+    ///     "missing_signers": [string] - required DIDs which haven't signed the request yet,
+    ///     "auth_rule_evaluation": (Optional) json - result of the auth rule evaluation,
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_check_endorsement_envelope(indy_handle_t command_handle,
+                                                        const char *  envelope_json,
+
+                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err,
+                                                                             const char*   status_json)
+                                                       );
+
+    /// Submits the request of a complete endorsement envelope to validator pool.
+    ///
+    /// The pending endorsement is removed from the wallet once the request is written.
+    /// Envelopes with undetermined required signers are rejected.
+    /// Rejected requests are kept in the wallet.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// envelope_json: endorsement envelope.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+
+    extern indy_error_t indy_submit_endorsement_envelope(indy_handle_t command_handle,
+                                                         indy_handle_t pool_handle,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  envelope_json,
+
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err,
+                                                                              const char*   request_result_json)
+                                                        );
+
+    /// Lists endorsement envelopes stored in the wallet which are not submitted yet.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Array of endorsement envelopes (see `indy_build_endorsement_envelope`).
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_list_pending_endorsements(indy_handle_t command_handle,
+                                                       indy_handle_t wallet_handle,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   envelopes_json)
+                                                      );
+
+    /// Deletes a pending endorsement from the wallet.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// id: identifier of the pending endorsement (`id` field of the envelope).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_delete_pending_endorsement(indy_handle_t command_handle,
+                                                        indy_handle_t wallet_handle,
+                                                        const char *  id,
+
+                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err)
+                                                       );
+
+    /// Builds a LEDGERS_FREEZE request. Request to freeze list of ledgers.
+    /// Frozen ledgers can not be written anymore but can still be read.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+    ///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+    /// ledgers_ids: list of ledgers IDs for freezing ledgers (json format).
+    ///     [0, 1, 28, ...]
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_ledgers_freeze_request(indy_handle_t command_handle,
+                                                          const char *  submitter_did,
+                                                          const char *  ledgers_ids,
+
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err,
+                                                                               const char*   request_json)
+                                                          );
+
+    /// Builds a GET_FROZEN_LEDGERS request. Request to get list of frozen ledgers.
+    /// Frozen ledgers are defined by LEDGERS_FREEZE request.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_get_frozen_ledgers_request(indy_handle_t command_handle,
+                                                              const char *  submitter_did,
+
+                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   request_json)
+                                                              );
+
+    /// Parse a GET_FROZEN_LEDGERS response to get list of frozen ledgers.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_frozen_ledgers_response: response of GET_FROZEN_LEDGERS request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Frozen ledgers json (empty object if no ledger has been frozen):
+    /// {
+    ///     "<ledger_id>": {
+    ///         ledger: root hash of the ledger at the moment of freezing,
+    ///         state: state root hash of the ledger at the moment of freezing,
+    ///         seq_no: last sequence number of the ledger at the moment of freezing
+    ///     },
+    ///     ...
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_parse_get_frozen_ledgers_response(indy_handle_t command_handle,
+                                                               const char *  get_frozen_ledgers_response,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   frozen_ledgers_json)
+                                                               );
+
+    /// Builds a request to store a rich schema object on the ledger.
+    /// The transaction type is selected by `rs_type`:
+    /// ctx -> SET_JSON_LD_CONTEXT, sch -> SET_RICH_SCHEMA, enc -> SET_RICH_SCHEMA_ENCODING,
+    /// map -> SET_RICH_SCHEMA_MAPPING, cdf -> SET_RICH_SCHEMA_CRED_DEF, pdf -> SET_RICH_SCHEMA_PRES_DEF.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+    ///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+    /// rs_id: identifier of the rich schema object (DID string).
+    /// rs_content: JSON-LD content of the rich schema object (json string).
+    /// rs_name: rich schema object name.
+    /// rs_version: rich schema object version.
+    /// rs_type: rich schema object type. One of: ctx, sch, enc, map, cdf, pdf.
+    /// ver: version of the rich schema object format (currently "1").
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_rich_schema_request(indy_handle_t command_handle,
+                                                       const char *  submitter_did,
+                                                       const char *  rs_id,
+                                                       const char *  rs_content,
+                                                       const char *  rs_name,
+                                                       const char *  rs_version,
+                                                       const char *  rs_type,
+                                                       const char *  ver,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   request_json)
+                                                       );
+
+    /// Builds a GET_RICH_SCHEMA_OBJECT_BY_ID request. Request to get a rich schema object by its id.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// rs_id: identifier of the rich schema object.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_get_rich_schema_object_by_id_request(indy_handle_t command_handle,
+                                                                        const char *  submitter_did,
+                                                                        const char *  rs_id,
+
+                                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                                             indy_error_t  err,
+                                                                                             const char*   request_json)
+                                                                        );
+
+    /// Builds a GET_RICH_SCHEMA_OBJECT_BY_METADATA request.
+    /// Request to get a rich schema object by its type, name and version.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// rs_type: rich schema object type. One of: ctx, sch, enc, map, cdf, pdf.
+    /// rs_name: rich schema object name.
+    /// rs_version: rich schema object version.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_get_rich_schema_object_by_metadata_request(indy_handle_t command_handle,
+                                                                              const char *  submitter_did,
+                                                                              const char *  rs_type,
+                                                                              const char *  rs_name,
+                                                                              const char *  rs_version,
+
+                                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                                   indy_error_t  err,
+                                                                                                   const char*   request_json)
+                                                                              );
+
+    /// Parse a GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA response.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_rich_schema_object_response: response of GET_RICH_SCHEMA_OBJECT_BY_* request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Rich schema object json:
+    /// {
+    ///     id: identifier of the rich schema object,
+    ///     content: JSON-LD content of the object,
+    ///     rsName: object name,
+    ///     rsVersion: object version,
+    ///     rsType: object type,
+    ///     ver: version of the object format,
+    ///     from: DID of the object author,
+    ///     endorser: (Optional) DID of the endorser
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_parse_get_rich_schema_object_response(indy_handle_t command_handle,
+                                                                   const char *  get_rich_schema_object_response,
+
+                                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                                        indy_error_t  err,
+                                                                                        const char*   rich_schema_object_json)
+                                                                   );
+
 #ifdef __cplusplus
 }
 #endif
@@ -132,6 +132,10 @@ typedef enum
     // Item not found on ledger.
     LedgerNotFound = 309,
 
+    // Transaction author agreement on the ledger differs from the one accepted for the pool.
+    // Accept the new agreement with indy_set_pool_txn_author_agreement_acceptance.
+    LedgerTxnAuthorAgreementChanged = 310,
+
     // Revocation registry is full and creation of new registry is necessary
     AnoncredsRevocationRegistryFullError = 400,
 
@@ -144,6 +144,33 @@ extern "C" {
                                                                        indy_error_t err)
                                                  );
 
+    /// Apply a list of non-secret record operations to the wallet atomically:
+    /// either all operations are applied or, if any of them fails, none of them.
+    ///
+    /// Supported by the default (SQLite) and MySQL wallet storages. Plugged storages
+    /// have no transactions and return WalletStorageError without applying anything.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context
+    /// wallet_handle: wallet handle (created by open_wallet)
+    /// operations_json: the list of operations applied in the given order as json:
+    ///   [
+    ///     {"op": "add", "type": <str>, "id": <str>, "value": <str>, "tags": <tags_json>}, // tags are optional
+    ///     {"op": "update_value", "type": <str>, "id": <str>, "value": <str>},
+    ///     {"op": "add_tags", "type": <str>, "id": <str>, "tags": <tags_json>},
+    ///     {"op": "update_tags", "type": <str>, "id": <str>, "tags": <tags_json>},
+    ///     {"op": "delete_tags", "type": <str>, "id": <str>, "tag_names": [<str>]},
+    ///     {"op": "delete", "type": <str>, "id": <str>}
+    ///   ]
+    ///   where tags_json has the same format as in indy_add_wallet_record
+
+    extern indy_error_t indy_wallet_batch(indy_handle_t  command_handle,
+                                          indy_handle_t  wallet_handle,
+                                          const char*    operations_json,
+                                          void           (*fn)(indy_handle_t command_handle_,
+                                                               indy_error_t err)
+                                         );
+
     /// Get an wallet record by id
     ///
     /// #Params
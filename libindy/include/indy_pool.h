@@ -14,6 +14,12 @@ extern "C" {
                                                        void          (*cb)(indy_handle_t command_handle_, indy_error_t err)
                                                        );
     
+    extern indy_error_t indy_create_simulated_pool(indy_handle_t command_handle,
+                                                   const char *  config_name,
+                                                   const char *  config,
+                                                   void          (*cb)(indy_handle_t command_handle_, indy_error_t err, const char *const genesis_txns)
+                                                   );
+
     extern indy_error_t indy_open_pool_ledger(indy_handle_t command_handle,
                                               const char *  config_name,
                                               const char *  config,
@@ -25,6 +31,19 @@ extern "C" {
                                                  void          (*cb)(indy_handle_t command_handle_, indy_error_t err)
                                                  );
 
+    extern indy_error_t indy_get_pool_status(indy_handle_t command_handle,
+                                             indy_handle_t handle,
+                                             void          (*cb)(indy_handle_t command_handle_, indy_error_t err, const char *const status_json)
+                                             );
+
+    extern indy_error_t indy_verify_ledger_reply(indy_handle_t command_handle,
+                                                 const char *  pool_name,
+                                                 const char *  genesis_txns,
+                                                 const char *  request_json,
+                                                 const char *  reply_json,
+                                                 void          (*cb)(indy_handle_t command_handle_, indy_error_t err, const char *const verification_json)
+                                                 );
+
     extern indy_error_t indy_list_pools(indy_handle_t command_handle,
                                         void          (*fn)(indy_handle_t command_handle_, indy_error_t err, const char *const pools)
                                         );
@@ -39,10 +58,28 @@ extern "C" {
                                                        void          (*cb)(indy_handle_t command_handle_, indy_error_t err)
                                                        );
 
+    extern indy_error_t indy_update_pool_ledger_genesis(indy_handle_t command_handle,
+                                                        const char *  config_name,
+                                                        const char *  config,
+                                                        void          (*cb)(indy_handle_t command_handle_, indy_error_t err)
+                                                        );
+
+    extern indy_error_t indy_export_pool_snapshot(indy_handle_t command_handle,
+                                                  indy_handle_t wallet_handle,
+                                                  const char *  config_name,
+                                                  const char *  export_config,
+                                                  void          (*cb)(indy_handle_t command_handle_, indy_error_t err, const char *const snapshot_json)
+                                                  );
+
     extern indy_error_t indy_set_protocol_version(indy_handle_t command_handle,
                                                   indy_u64_t    protocol_version,
                                                   void          (*cb)(indy_handle_t command_handle_, indy_error_t err)
                                                   );
+
+    extern indy_error_t indy_get_pool_handle_for_namespace(indy_handle_t command_handle,
+                                                           const char *  namespace_,
+                                                           void          (*cb)(indy_handle_t command_handle_, indy_error_t err, indy_handle_t pool_handle)
+                                                           );
 #ifdef __cplusplus
 }
 #endif
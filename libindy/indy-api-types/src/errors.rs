@@ -60,6 +60,8 @@ pub enum IndyErrorKind {
     InvalidTransaction,
     #[fail(display = "Item not found on ledger")]
     LedgerItemNotFound,
+    #[fail(display = "Transaction author agreement on the ledger has been changed")]
+    TxnAuthorAgreementChanged,
     // Pool errors
     #[fail(display = "Pool not created")]
     PoolNotCreated,
@@ -472,6 +474,7 @@ impl From<IndyErrorKind> for ErrorCode {
             IndyErrorKind::NoConsensus => ErrorCode::LedgerNoConsensusError,
             IndyErrorKind::InvalidTransaction => ErrorCode::LedgerInvalidTransaction,
             IndyErrorKind::LedgerItemNotFound => ErrorCode::LedgerNotFound,
+            IndyErrorKind::TxnAuthorAgreementChanged => ErrorCode::LedgerTxnAuthorAgreementChanged,
             IndyErrorKind::PoolNotCreated => ErrorCode::PoolLedgerNotCreatedError,
             IndyErrorKind::InvalidPoolHandle => ErrorCode::PoolLedgerInvalidPoolHandle,
             IndyErrorKind::PoolTerminated => ErrorCode::PoolLedgerTerminated,
@@ -573,6 +576,7 @@ impl From<ErrorCode> for IndyErrorKind {
             ErrorCode::LedgerNoConsensusError => IndyErrorKind::NoConsensus,
             ErrorCode::LedgerInvalidTransaction => IndyErrorKind::InvalidTransaction,
             ErrorCode::LedgerNotFound => IndyErrorKind::LedgerItemNotFound,
+            ErrorCode::LedgerTxnAuthorAgreementChanged => IndyErrorKind::TxnAuthorAgreementChanged,
             ErrorCode::PoolLedgerNotCreatedError => IndyErrorKind::PoolNotCreated,
             ErrorCode::PoolLedgerInvalidPoolHandle => IndyErrorKind::InvalidPoolHandle,
             ErrorCode::PoolLedgerTerminated => IndyErrorKind::PoolTerminated,
@@ -561,6 +561,47 @@ impl WalletService {
         Ok(())
     }
 
+    pub async fn batch(
+        &self,
+        wallet_handle: WalletHandle,
+        operations: &[WalletOperation],
+    ) -> IndyResult<()> {
+        let wallet = self.get_wallet(wallet_handle).await?;
+        wallet.batch(operations).await
+    }
+
+    pub fn update_indy_object_operation<T>(
+        &self,
+        name: &str,
+        object: &T,
+    ) -> IndyResult<WalletOperation>
+    where
+        T: ::serde::Serialize + Sized,
+    {
+        let type_ = short_type_name::<T>();
+
+        let object_json = serde_json::to_string(object).to_indy(
+            IndyErrorKind::InvalidState,
+            format!("Cannot serialize {:?}", type_),
+        )?;
+
+        Ok(WalletOperation::UpdateValue {
+            type_: self.add_prefix(type_),
+            id: name.to_string(),
+            value: object_json,
+        })
+    }
+
+    pub fn delete_indy_record_operation<T>(&self, name: &str) -> WalletOperation
+    where
+        T: Sized,
+    {
+        WalletOperation::Delete {
+            type_: self.add_prefix(short_type_name::<T>()),
+            id: name.to_string(),
+        }
+    }
+
     pub async fn get_record(
         &self,
         wallet_handle: WalletHandle,
@@ -1105,6 +1146,62 @@ impl WalletRecord {
     }
 }
 
+/// Record operation applied as a part of an atomic wallet batch.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum WalletOperation {
+    Add {
+        #[serde(rename = "type")]
+        type_: String,
+        id: String,
+        value: String,
+        #[serde(default)]
+        tags: Tags,
+    },
+    UpdateValue {
+        #[serde(rename = "type")]
+        type_: String,
+        id: String,
+        value: String,
+    },
+    AddTags {
+        #[serde(rename = "type")]
+        type_: String,
+        id: String,
+        tags: Tags,
+    },
+    UpdateTags {
+        #[serde(rename = "type")]
+        type_: String,
+        id: String,
+        tags: Tags,
+    },
+    DeleteTags {
+        #[serde(rename = "type")]
+        type_: String,
+        id: String,
+        tag_names: Vec<String>,
+    },
+    Delete {
+        #[serde(rename = "type")]
+        type_: String,
+        id: String,
+    },
+}
+
+impl WalletOperation {
+    pub fn get_type(&self) -> &str {
+        match *self {
+            WalletOperation::Add { ref type_, .. }
+            | WalletOperation::UpdateValue { ref type_, .. }
+            | WalletOperation::AddTags { ref type_, .. }
+            | WalletOperation::UpdateTags { ref type_, .. }
+            | WalletOperation::DeleteTags { ref type_, .. }
+            | WalletOperation::Delete { ref type_, .. } => type_,
+        }
+    }
+}
+
 fn default_true() -> bool {
     true
 }
@@ -3331,6 +3428,174 @@ mod tests {
         assert_kind!(IndyErrorKind::WalletItemNotFound, res);
     }
 
+    /**
+     * Batch tests
+     */
+    #[async_std::test]
+    async fn wallet_service_batch_works() {
+        test::cleanup_wallet("wallet_service_batch_works");
+        {
+            let wallet_service = WalletService::new();
+
+            wallet_service
+                .create_wallet(
+                    &_config("wallet_service_batch_works"),
+                    &RAW_CREDENTIAL,
+                    (&RAW_KDD, &RAW_MASTER_KEY),
+                )
+                .await
+                .unwrap();
+
+            let wallet_handle = wallet_service
+                .open_wallet(&_config_cached("wallet_service_batch_works"), &RAW_CREDENTIAL)
+                .await
+                .unwrap();
+
+            wallet_service
+                .add_record(wallet_handle, "type", "name", "value", &HashMap::new())
+                .await
+                .unwrap();
+
+            let operations = vec![
+                WalletOperation::UpdateValue {
+                    type_: "type".to_string(),
+                    id: "name".to_string(),
+                    value: "new_value".to_string(),
+                },
+                WalletOperation::AddTags {
+                    type_: "type".to_string(),
+                    id: "name".to_string(),
+                    tags: serde_json::from_str(r#"{"tag_name": "tag_value"}"#).unwrap(),
+                },
+                WalletOperation::Add {
+                    type_: "type".to_string(),
+                    id: "name2".to_string(),
+                    value: "value2".to_string(),
+                    tags: HashMap::new(),
+                },
+            ];
+
+            wallet_service
+                .batch(wallet_handle, &operations)
+                .await
+                .unwrap();
+
+            let record = wallet_service
+                .get_record(
+                    wallet_handle,
+                    "type",
+                    "name",
+                    &_fetch_options(false, true, true),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!("new_value", record.get_value().unwrap());
+            assert_eq!("tag_value", record.get_tags().unwrap()["tag_name"]);
+
+            let record = wallet_service
+                .get_record(
+                    wallet_handle,
+                    "type",
+                    "name2",
+                    &_fetch_options(false, true, false),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!("value2", record.get_value().unwrap());
+        }
+
+        test::cleanup_wallet("wallet_service_batch_works");
+    }
+
+    #[async_std::test]
+    async fn wallet_service_batch_works_for_rollback() {
+        test::cleanup_wallet("wallet_service_batch_works_for_rollback");
+        {
+            let wallet_service = WalletService::new();
+
+            wallet_service
+                .create_wallet(
+                    &_config("wallet_service_batch_works_for_rollback"),
+                    &RAW_CREDENTIAL,
+                    (&RAW_KDD, &RAW_MASTER_KEY),
+                )
+                .await
+                .unwrap();
+
+            let wallet_handle = wallet_service
+                .open_wallet(
+                    &_config_cached("wallet_service_batch_works_for_rollback"),
+                    &RAW_CREDENTIAL,
+                )
+                .await
+                .unwrap();
+
+            wallet_service
+                .add_record(wallet_handle, "type", "name", "value", &HashMap::new())
+                .await
+                .unwrap();
+
+            let operations = vec![
+                WalletOperation::UpdateValue {
+                    type_: "type".to_string(),
+                    id: "name".to_string(),
+                    value: "new_value".to_string(),
+                },
+                WalletOperation::Delete {
+                    type_: "type".to_string(),
+                    id: "unknown_name".to_string(),
+                },
+            ];
+
+            let res = wallet_service.batch(wallet_handle, &operations).await;
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+
+            let record = wallet_service
+                .get_record(
+                    wallet_handle,
+                    "type",
+                    "name",
+                    &_fetch_options(false, true, false),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!("value", record.get_value().unwrap());
+        }
+
+        test::cleanup_wallet("wallet_service_batch_works_for_rollback");
+    }
+
+    #[test]
+    fn wallet_operation_deserialize_works() {
+        let operations: Vec<WalletOperation> = serde_json::from_str(
+            r#"[
+                {"op": "add", "type": "type", "id": "name", "value": "value"},
+                {"op": "delete_tags", "type": "type", "id": "name", "tag_names": ["tag_name"]}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                WalletOperation::Add {
+                    type_: "type".to_string(),
+                    id: "name".to_string(),
+                    value: "value".to_string(),
+                    tags: HashMap::new(),
+                },
+                WalletOperation::DeleteTags {
+                    type_: "type".to_string(),
+                    id: "name".to_string(),
+                    tag_names: vec!["tag_name".to_string()],
+                },
+            ],
+            operations
+        );
+    }
+
     /**
      * Add tags tests
      */
@@ -8,14 +8,17 @@ use indy_utils::environment;
 use serde::Deserialize;
 use sqlx::{
     sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions},
-    ConnectOptions, Done, SqlitePool,
+    ConnectOptions, Done, Sqlite, SqlitePool, Transaction,
 };
 
 use async_trait::async_trait;
 
 use crate::{
     language,
-    storage::{StorageIterator, StorageRecord, Tag, TagName, WalletStorage, WalletStorageType},
+    storage::{
+        StorageIterator, StorageOperation, StorageRecord, Tag, TagName, WalletStorage,
+        WalletStorageType,
+    },
     wallet::EncryptedValue,
     RecordOptions, SearchOptions,
 };
@@ -87,140 +90,20 @@ impl SQLiteStorageType {
     }
 }
 
-#[async_trait]
-impl WalletStorage for SQLiteStorage {
-    ///
-    /// Tries to fetch values and/or tags from the storage.
-    /// Returns Result with StorageEntity object which holds requested data in case of success or
-    /// Result with IndyError in case of failure.
-    ///
-    ///
-    /// # Arguments
-    ///
-    ///  * `type_` - type_ of the item in storage
-    ///  * `id` - id of the item in storage
-    ///  * `options` - JSon containing what needs to be fetched.
-    ///  Example: {"retrieveValue": true, "retrieveTags": true}
-    ///
-    /// # Returns
-    ///
-    /// Result that can be either:
-    ///
-    ///  * `StorageEntity` - Contains name, optional value and optional tags
-    ///  * `IndyError`
-    ///
-    /// # Errors
-    ///
-    /// Any of the following `IndyError` type_ of errors can be throw by this method:
-    ///
-    ///  * `IndyError::Closed` - Storage is closed
-    ///  * `IndyError::ItemNotFound` - Item is not found in database
-    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
-    ///
-    async fn get(&self, type_: &[u8], id: &[u8], options: &str) -> IndyResult<StorageRecord> {
-        let options: RecordOptions = serde_json::from_str(options).to_indy(
-            IndyErrorKind::InvalidStructure,
-            "RecordOptions is malformed json",
-        )?;
-
-        let mut conn = self.pool.acquire().await?;
-
-        let (item_id, value, key): (i64, Vec<u8>, Vec<u8>) =
-            sqlx::query_as("SELECT id, value, key FROM items where type = ?1 AND name = ?2")
-                .bind(type_)
-                .bind(id)
-                .fetch_one(&mut conn)
-                .await?;
-
-        let value = if options.retrieve_value {
-            Some(EncryptedValue::new(value, key))
-        } else {
-            None
-        };
-
-        let type_ = if options.retrieve_type {
-            Some(type_.to_vec())
-        } else {
-            None
-        };
-
-        let tags = if options.retrieve_tags {
-            let mut tags = Vec::new();
-
-            tags.extend(
-                sqlx::query_as::<_, (Vec<u8>, String)>(
-                    "SELECT name, value from tags_plaintext where item_id = ?",
-                )
-                .bind(item_id)
-                .fetch_all(&mut conn)
-                .await?
-                .drain(..)
-                .map(|r| Tag::PlainText(r.0, r.1)),
-            );
-
-            tags.extend(
-                sqlx::query_as::<_, (Vec<u8>, Vec<u8>)>(
-                    "SELECT name, value from tags_encrypted where item_id = ?",
-                )
-                .bind(item_id)
-                .fetch_all(&mut conn)
-                .await?
-                .drain(..)
-                .map(|r| Tag::Encrypted(r.0, r.1)),
-            );
-
-            Some(tags)
-        } else {
-            None
-        };
-
-        Ok(StorageRecord::new(id.to_vec(), value, type_, tags))
-    }
-
-    ///
-    /// inserts value and tags into storage.
-    /// Returns Result with () on success or
-    /// Result with IndyError in case of failure.
-    ///
-    ///
-    /// # Arguments
-    ///
-    ///  * `type_` - type of the item in storage
-    ///  * `id` - id of the item in storage
-    ///  * `value` - value of the item in storage
-    ///  * `value_key` - key used to encrypt the value
-    ///  * `tags` - tags assigned to the value
-    ///
-    /// # Returns
-    ///
-    /// Result that can be either:
-    ///
-    ///  * `()`
-    ///  * `IndyError`
-    ///
-    /// # Errors
-    ///
-    /// Any of the following `IndyError` class of errors can be throw by this method:
-    ///
-    ///  * `IndyError::Closed` - Storage is closed
-    ///  * `IndyError::ItemAlreadyExists` - Item is already present in database
-    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
-    ///
-    async fn add(
-        &self,
+impl SQLiteStorage {
+    async fn _add(
+        tx: &mut Transaction<'_, Sqlite>,
         type_: &[u8],
         id: &[u8],
         value: &EncryptedValue,
         tags: &[Tag],
     ) -> IndyResult<()> {
-        let mut tx = self.pool.begin().await?;
-
         let id = sqlx::query("INSERT INTO items (type, name, value, key) VALUES (?1, ?2, ?3, ?4)")
             .bind(type_)
             .bind(id)
             .bind(&value.data)
             .bind(&value.key)
-            .execute(&mut tx)
+            .execute(&mut *tx)
             .await?
             .last_insert_rowid();
 
@@ -233,7 +116,7 @@ impl WalletStorage for SQLiteStorage {
                     .bind(id)
                     .bind(tag_name)
                     .bind(tag_data)
-                    .execute(&mut tx)
+                    .execute(&mut *tx)
                     .await?
                 }
                 Tag::PlainText(ref tag_name, ref tag_data) => {
@@ -243,34 +126,33 @@ impl WalletStorage for SQLiteStorage {
                     .bind(id)
                     .bind(tag_name)
                     .bind(tag_data)
-                    .execute(&mut tx)
+                    .execute(&mut *tx)
                     .await?
                 }
             };
         }
 
-        tx.commit().await?;
         Ok(())
     }
 
-    async fn update(&self, type_: &[u8], id: &[u8], value: &EncryptedValue) -> IndyResult<()> {
-        let mut tx = self.pool.begin().await?;
-
+    async fn _update(
+        tx: &mut Transaction<'_, Sqlite>,
+        type_: &[u8],
+        id: &[u8],
+        value: &EncryptedValue,
+    ) -> IndyResult<()> {
         let row_updated =
             sqlx::query("UPDATE items SET value = ?1, key = ?2 WHERE type = ?3 AND name = ?4")
                 .bind(&value.data)
                 .bind(&value.key)
                 .bind(&type_)
                 .bind(&id)
-                .execute(&mut tx)
+                .execute(&mut *tx)
                 .await?
                 .rows_affected();
 
         match row_updated {
-            1 => {
-                tx.commit().await?;
-                Ok(())
-            }
+            1 => Ok(()),
             0 => Err(err_msg(
                 IndyErrorKind::WalletItemNotFound,
                 "Item to update not found",
@@ -282,14 +164,17 @@ impl WalletStorage for SQLiteStorage {
         }
     }
 
-    async fn add_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
-        let mut tx = self.pool.begin().await?;
-
+    async fn _add_tags(
+        tx: &mut Transaction<'_, Sqlite>,
+        type_: &[u8],
+        id: &[u8],
+        tags: &[Tag],
+    ) -> IndyResult<()> {
         let (item_id,): (i64,) =
             sqlx::query_as("SELECT id FROM items WHERE type = ?1 AND name = ?2")
                 .bind(type_)
                 .bind(id)
-                .fetch_one(&mut tx)
+                .fetch_one(&mut *tx)
                 .await?;
 
         for tag in tags {
@@ -301,7 +186,7 @@ impl WalletStorage for SQLiteStorage {
                     .bind(item_id)
                     .bind(tag_name)
                     .bind(tag_data)
-                    .execute(&mut tx)
+                    .execute(&mut *tx)
                     .await?
                 }
                 Tag::PlainText(ref tag_name, ref tag_data) => {
@@ -311,34 +196,36 @@ impl WalletStorage for SQLiteStorage {
                     .bind(item_id)
                     .bind(tag_name)
                     .bind(tag_data)
-                    .execute(&mut tx)
+                    .execute(&mut *tx)
                     .await?
                 }
             };
         }
 
-        tx.commit().await?;
         Ok(())
     }
 
-    async fn update_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
-        let mut tx = self.pool.begin().await?;
-
+    async fn _update_tags(
+        tx: &mut Transaction<'_, Sqlite>,
+        type_: &[u8],
+        id: &[u8],
+        tags: &[Tag],
+    ) -> IndyResult<()> {
         let (item_id,): (i64,) =
             sqlx::query_as("SELECT id FROM items WHERE type = ?1 AND name = ?2")
                 .bind(type_)
                 .bind(&id)
-                .fetch_one(&mut tx)
+                .fetch_one(&mut *tx)
                 .await?;
 
         sqlx::query("DELETE FROM tags_encrypted WHERE item_id = ?1")
             .bind(item_id)
-            .execute(&mut tx)
+            .execute(&mut *tx)
             .await?;
 
         sqlx::query("DELETE FROM tags_plaintext WHERE item_id = ?1")
             .bind(item_id)
-            .execute(&mut tx)
+            .execute(&mut *tx)
             .await?;
 
         for tag in tags {
@@ -350,7 +237,7 @@ impl WalletStorage for SQLiteStorage {
                     .bind(item_id)
                     .bind(tag_name)
                     .bind(tag_data)
-                    .execute(&mut tx)
+                    .execute(&mut *tx)
                     .await?
                 }
                 Tag::PlainText(ref tag_name, ref tag_data) => {
@@ -360,25 +247,26 @@ impl WalletStorage for SQLiteStorage {
                     .bind(item_id)
                     .bind(tag_name)
                     .bind(tag_data)
-                    .execute(&mut tx)
+                    .execute(&mut *tx)
                     .await?
                 }
             };
         }
 
-        tx.commit().await?;
-
         Ok(())
     }
 
-    async fn delete_tags(&self, type_: &[u8], id: &[u8], tag_names: &[TagName]) -> IndyResult<()> {
-        let mut tx = self.pool.begin().await?;
-
+    async fn _delete_tags(
+        tx: &mut Transaction<'_, Sqlite>,
+        type_: &[u8],
+        id: &[u8],
+        tag_names: &[TagName],
+    ) -> IndyResult<()> {
         let (item_id,): (i64,) =
             sqlx::query_as("SELECT id FROM items WHERE type = ?1 AND name = ?2")
                 .bind(type_)
                 .bind(id)
-                .fetch_one(&mut tx)
+                .fetch_one(&mut *tx)
                 .await?;
 
         for tag_name in tag_names {
@@ -387,19 +275,200 @@ impl WalletStorage for SQLiteStorage {
                     sqlx::query("DELETE FROM tags_encrypted WHERE item_id = ?1 AND name = ?2")
                         .bind(item_id)
                         .bind(tag_name)
-                        .execute(&mut tx)
+                        .execute(&mut *tx)
                         .await?
                 }
                 TagName::OfPlain(ref tag_name) => {
                     sqlx::query("DELETE FROM tags_plaintext WHERE item_id = ?1 AND name = ?2")
                         .bind(item_id)
                         .bind(tag_name)
-                        .execute(&mut tx)
+                        .execute(&mut *tx)
                         .await?
                 }
             };
         }
 
+        Ok(())
+    }
+
+    async fn _delete(tx: &mut Transaction<'_, Sqlite>, type_: &[u8], id: &[u8]) -> IndyResult<()> {
+        let rows_affected = sqlx::query("DELETE FROM items where type = ?1 AND name = ?2")
+            .bind(type_)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+
+        match rows_affected {
+            1 => Ok(()),
+            0 => Err(err_msg(
+                IndyErrorKind::WalletItemNotFound,
+                "Item to delete not found",
+            )),
+            _ => Err(err_msg(
+                IndyErrorKind::InvalidState,
+                "More than one row deleted. Seems wallet structure is inconsistent",
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl WalletStorage for SQLiteStorage {
+    ///
+    /// Tries to fetch values and/or tags from the storage.
+    /// Returns Result with StorageEntity object which holds requested data in case of success or
+    /// Result with IndyError in case of failure.
+    ///
+    ///
+    /// # Arguments
+    ///
+    ///  * `type_` - type_ of the item in storage
+    ///  * `id` - id of the item in storage
+    ///  * `options` - JSon containing what needs to be fetched.
+    ///  Example: {"retrieveValue": true, "retrieveTags": true}
+    ///
+    /// # Returns
+    ///
+    /// Result that can be either:
+    ///
+    ///  * `StorageEntity` - Contains name, optional value and optional tags
+    ///  * `IndyError`
+    ///
+    /// # Errors
+    ///
+    /// Any of the following `IndyError` type_ of errors can be throw by this method:
+    ///
+    ///  * `IndyError::Closed` - Storage is closed
+    ///  * `IndyError::ItemNotFound` - Item is not found in database
+    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
+    ///
+    async fn get(&self, type_: &[u8], id: &[u8], options: &str) -> IndyResult<StorageRecord> {
+        let options: RecordOptions = serde_json::from_str(options).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "RecordOptions is malformed json",
+        )?;
+
+        let mut conn = self.pool.acquire().await?;
+
+        let (item_id, value, key): (i64, Vec<u8>, Vec<u8>) =
+            sqlx::query_as("SELECT id, value, key FROM items where type = ?1 AND name = ?2")
+                .bind(type_)
+                .bind(id)
+                .fetch_one(&mut conn)
+                .await?;
+
+        let value = if options.retrieve_value {
+            Some(EncryptedValue::new(value, key))
+        } else {
+            None
+        };
+
+        let type_ = if options.retrieve_type {
+            Some(type_.to_vec())
+        } else {
+            None
+        };
+
+        let tags = if options.retrieve_tags {
+            let mut tags = Vec::new();
+
+            tags.extend(
+                sqlx::query_as::<_, (Vec<u8>, String)>(
+                    "SELECT name, value from tags_plaintext where item_id = ?",
+                )
+                .bind(item_id)
+                .fetch_all(&mut conn)
+                .await?
+                .drain(..)
+                .map(|r| Tag::PlainText(r.0, r.1)),
+            );
+
+            tags.extend(
+                sqlx::query_as::<_, (Vec<u8>, Vec<u8>)>(
+                    "SELECT name, value from tags_encrypted where item_id = ?",
+                )
+                .bind(item_id)
+                .fetch_all(&mut conn)
+                .await?
+                .drain(..)
+                .map(|r| Tag::Encrypted(r.0, r.1)),
+            );
+
+            Some(tags)
+        } else {
+            None
+        };
+
+        Ok(StorageRecord::new(id.to_vec(), value, type_, tags))
+    }
+
+    ///
+    /// inserts value and tags into storage.
+    /// Returns Result with () on success or
+    /// Result with IndyError in case of failure.
+    ///
+    ///
+    /// # Arguments
+    ///
+    ///  * `type_` - type of the item in storage
+    ///  * `id` - id of the item in storage
+    ///  * `value` - value of the item in storage
+    ///  * `value_key` - key used to encrypt the value
+    ///  * `tags` - tags assigned to the value
+    ///
+    /// # Returns
+    ///
+    /// Result that can be either:
+    ///
+    ///  * `()`
+    ///  * `IndyError`
+    ///
+    /// # Errors
+    ///
+    /// Any of the following `IndyError` class of errors can be throw by this method:
+    ///
+    ///  * `IndyError::Closed` - Storage is closed
+    ///  * `IndyError::ItemAlreadyExists` - Item is already present in database
+    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
+    ///
+    async fn add(
+        &self,
+        type_: &[u8],
+        id: &[u8],
+        value: &EncryptedValue,
+        tags: &[Tag],
+    ) -> IndyResult<()> {
+        let mut tx = self.pool.begin().await?;
+        Self::_add(&mut tx, type_, id, value, tags).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn update(&self, type_: &[u8], id: &[u8], value: &EncryptedValue) -> IndyResult<()> {
+        let mut tx = self.pool.begin().await?;
+        Self::_update(&mut tx, type_, id, value).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn add_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
+        let mut tx = self.pool.begin().await?;
+        Self::_add_tags(&mut tx, type_, id, tags).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn update_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
+        let mut tx = self.pool.begin().await?;
+        Self::_update_tags(&mut tx, type_, id, tags).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn delete_tags(&self, type_: &[u8], id: &[u8], tag_names: &[TagName]) -> IndyResult<()> {
+        let mut tx = self.pool.begin().await?;
+        Self::_delete_tags(&mut tx, type_, id, tag_names).await?;
         tx.commit().await?;
         Ok(())
     }
@@ -432,28 +501,62 @@ impl WalletStorage for SQLiteStorage {
     ///
     async fn delete(&self, type_: &[u8], id: &[u8]) -> IndyResult<()> {
         let mut tx = self.pool.begin().await?;
+        Self::_delete(&mut tx, type_, id).await?;
+        tx.commit().await?;
+        Ok(())
+    }
 
-        let rows_affected = sqlx::query("DELETE FROM items where type = ?1 AND name = ?2")
-            .bind(type_)
-            .bind(id)
-            .execute(&mut tx)
-            .await?
-            .rows_affected();
+    ///
+    /// applies the list of operations to the storage within a single transaction.
+    /// Either all operations are applied or, if any of them fails, none of them.
+    ///
+    /// # Arguments
+    ///
+    ///  * `operations` - operations to apply in the given order
+    ///
+    /// # Errors
+    ///
+    /// The error of the first failed operation is returned and the transaction is rolled back.
+    ///
+    async fn batch(&self, operations: &[StorageOperation]) -> IndyResult<()> {
+        let mut tx = self.pool.begin().await?;
 
-        match rows_affected {
-            1 => {
-                tx.commit().await?;
-                Ok(())
+        for operation in operations {
+            match *operation {
+                StorageOperation::Add {
+                    ref type_,
+                    ref id,
+                    ref value,
+                    ref tags,
+                } => Self::_add(&mut tx, type_, id, value, tags).await?,
+                StorageOperation::Update {
+                    ref type_,
+                    ref id,
+                    ref value,
+                } => Self::_update(&mut tx, type_, id, value).await?,
+                StorageOperation::AddTags {
+                    ref type_,
+                    ref id,
+                    ref tags,
+                } => Self::_add_tags(&mut tx, type_, id, tags).await?,
+                StorageOperation::UpdateTags {
+                    ref type_,
+                    ref id,
+                    ref tags,
+                } => Self::_update_tags(&mut tx, type_, id, tags).await?,
+                StorageOperation::DeleteTags {
+                    ref type_,
+                    ref id,
+                    ref tag_names,
+                } => Self::_delete_tags(&mut tx, type_, id, tag_names).await?,
+                StorageOperation::Delete { ref type_, ref id } => {
+                    Self::_delete(&mut tx, type_, id).await?
+                }
             }
-            0 => Err(err_msg(
-                IndyErrorKind::WalletItemNotFound,
-                "Item to delete not found",
-            )),
-            _ => Err(err_msg(
-                IndyErrorKind::InvalidState,
-                "More than one row deleted. Seems wallet structure is inconsistent",
-            )),
         }
+
+        tx.commit().await?;
+        Ok(())
     }
 
     async fn get_storage_metadata(&self) -> IndyResult<Vec<u8>> {
@@ -569,7 +672,10 @@ impl WalletStorage for SQLiteStorage {
             let mut mtags = if options.retrieve_tags && records.len() > 0 {
                 let mut tags: Vec<(i64, Tag)> = Vec::new();
 
-                let in_binings = std::iter::repeat("?").take(records.len()).collect::<Vec<_>>().join(",");
+                let in_binings = std::iter::repeat("?")
+                    .take(records.len())
+                    .collect::<Vec<_>>()
+                    .join(",");
 
                 let query = format!(
                     r#"
@@ -1873,6 +1979,126 @@ mod tests {
         _cleanup("sqlite_storage_delete_tags_works_for_non_existing_id");
     }
 
+    #[async_std::test]
+    async fn sqlite_storage_batch_works() {
+        _cleanup("sqlite_storage_batch_works");
+
+        {
+            let storage = _storage("sqlite_storage_batch_works").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            let operations = vec![
+                StorageOperation::Add {
+                    type_: _type2(),
+                    id: _id2(),
+                    value: _value2(),
+                    tags: _new_tags(),
+                },
+                StorageOperation::Update {
+                    type_: _type1(),
+                    id: _id1(),
+                    value: _value2(),
+                },
+                StorageOperation::UpdateTags {
+                    type_: _type1(),
+                    id: _id1(),
+                    tags: _new_tags(),
+                },
+            ];
+
+            storage.batch(&operations).await.unwrap();
+
+            let record = storage
+                .get(
+                    &_type1(),
+                    &_id1(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(record.value.unwrap(), _value2());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_new_tags()));
+
+            let record = storage
+                .get(
+                    &_type2(),
+                    &_id2(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(record.value.unwrap(), _value2());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_new_tags()));
+        }
+
+        _cleanup("sqlite_storage_batch_works");
+    }
+
+    #[async_std::test]
+    async fn sqlite_storage_batch_works_for_rollback() {
+        _cleanup("sqlite_storage_batch_works_for_rollback");
+
+        {
+            let storage = _storage("sqlite_storage_batch_works_for_rollback").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            let operations = vec![
+                StorageOperation::Add {
+                    type_: _type2(),
+                    id: _id2(),
+                    value: _value2(),
+                    tags: _new_tags(),
+                },
+                StorageOperation::Delete {
+                    type_: _type1(),
+                    id: _id1(),
+                },
+                StorageOperation::Update {
+                    type_: _type1(),
+                    id: _id2(),
+                    value: _value2(),
+                },
+            ];
+
+            let res = storage.batch(&operations).await;
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+
+            let res = storage
+                .get(
+                    &_type2(),
+                    &_id2(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await;
+
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+
+            let record = storage
+                .get(
+                    &_type1(),
+                    &_id1(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(record.value.unwrap(), _value1());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_tags()));
+        }
+
+        _cleanup("sqlite_storage_batch_works_for_rollback");
+    }
+
     fn _cleanup(name: &str) {
         test::cleanup_storage(name)
     }
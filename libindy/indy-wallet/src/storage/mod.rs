@@ -13,7 +13,7 @@ pub enum Tag {
     PlainText(Vec<u8>, String),
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum TagName {
     OfEncrypted(Vec<u8>),
     OfPlain(Vec<u8>),
@@ -43,6 +43,41 @@ impl StorageRecord {
     }
 }
 
+/// Single record modification applied as a part of `WalletStorage::batch`.
+#[derive(Clone, Debug)]
+pub enum StorageOperation {
+    Add {
+        type_: Vec<u8>,
+        id: Vec<u8>,
+        value: EncryptedValue,
+        tags: Vec<Tag>,
+    },
+    Update {
+        type_: Vec<u8>,
+        id: Vec<u8>,
+        value: EncryptedValue,
+    },
+    AddTags {
+        type_: Vec<u8>,
+        id: Vec<u8>,
+        tags: Vec<Tag>,
+    },
+    UpdateTags {
+        type_: Vec<u8>,
+        id: Vec<u8>,
+        tags: Vec<Tag>,
+    },
+    DeleteTags {
+        type_: Vec<u8>,
+        id: Vec<u8>,
+        tag_names: Vec<TagName>,
+    },
+    Delete {
+        type_: Vec<u8>,
+        id: Vec<u8>,
+    },
+}
+
 #[async_trait]
 pub trait StorageIterator: Send + Sync {
     async fn next(&mut self) -> Result<Option<StorageRecord>, IndyError>;
@@ -75,6 +110,8 @@ pub trait WalletStorage: Send + Sync {
         tag_names: &[TagName],
     ) -> Result<(), IndyError>;
     async fn delete(&self, type_: &[u8], id: &[u8]) -> Result<(), IndyError>;
+    // Applies all operations or none of them. Storages that can't guarantee it return an error.
+    async fn batch(&self, operations: &[StorageOperation]) -> Result<(), IndyError>;
     async fn get_storage_metadata(&self) -> Result<Vec<u8>, IndyError>;
     async fn set_storage_metadata(&self, metadata: &[u8]) -> Result<(), IndyError>;
     async fn get_all(&self) -> Result<Box<dyn StorageIterator>, IndyError>;
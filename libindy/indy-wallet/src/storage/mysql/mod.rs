@@ -10,13 +10,13 @@ use indy_utils::crypto::base64;
 use log::LevelFilter;
 use query::{wql_to_sql, wql_to_sql_count};
 use serde::Deserialize;
-use sqlx::{ConnectOptions, Done, mysql::{MySqlConnectOptions, MySqlPoolOptions, MySqlRow}, MySqlPool, Row};
+use sqlx::{ConnectOptions, Done, mysql::{MySqlConnectOptions, MySqlPoolOptions, MySqlRow}, MySql, MySqlPool, Row, Transaction};
 
 use crate::{
     language,
     RecordOptions,
     SearchOptions,
-    storage::{StorageIterator, StorageRecord, Tag, TagName, WalletStorage, WalletStorageType},
+    storage::{StorageIterator, StorageOperation, StorageRecord, Tag, TagName, WalletStorage, WalletStorageType},
     wallet::EncryptedValue,
 };
 
@@ -161,129 +161,15 @@ impl MySqlStorageType {
     }
 }
 
-#[async_trait]
-impl WalletStorage for MySqlStorage {
-    ///
-    /// Tries to fetch values and/or tags from the storage.
-    /// Returns Result with StorageEntity object which holds requested data in case of success or
-    /// Result with IndyError in case of failure.
-    ///
-    ///
-    /// # Arguments
-    ///
-    ///  * `type_` - type_ of the item in storage
-    ///  * `id` - id of the item in storage
-    ///  * `options` - JSon containing what needs to be fetched.
-    ///  Example: {"retrieveValue": true, "retrieveTags": true}
-    ///
-    /// # Returns
-    ///
-    /// Result that can be either:
-    ///
-    ///  * `StorageEntity` - Contains name, optional value and optional tags
-    ///  * `IndyError`
-    ///
-    /// # Errors
-    ///
-    /// Any of the following `IndyError` type_ of errors can be throw by this method:
-    ///
-    ///  * `IndyError::Closed` - Storage is closed
-    ///  * `IndyError::ItemNotFound` - Item is not found in database
-    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
-    ///
-    async fn get(&self, type_: &[u8], id: &[u8], options: &str) -> IndyResult<StorageRecord> {
-        let options: RecordOptions = serde_json::from_str(options).to_indy(
-            IndyErrorKind::InvalidStructure,
-            "RecordOptions is malformed json",
-        )?;
-
-        let mut conn = self.read_pool.acquire().await?;
-
-        let (value, tags): (Option<Vec<u8>>, Option<serde_json::Value>) = sqlx::query_as(&format!(
-            r#"
-            SELECT {}, {}
-            FROM items
-            WHERE
-                wallet_id = ?
-                    AND type = ?
-                    AND name = ?
-            "#,
-            if options.retrieve_value {
-                "value"
-            } else {
-                "NULL"
-            },
-            if options.retrieve_tags {
-                "tags"
-            } else {
-                "NULL"
-            },
-        ))
-        .bind(self.wallet_id)
-        .bind(&base64::encode(type_))
-        .bind(&base64::encode(id))
-        .fetch_one(&mut conn)
-        .await?;
-
-        let value = if let Some(value) = value {
-            Some(EncryptedValue::from_bytes(&value)?)
-        } else {
-            None
-        };
-
-        let type_ = if options.retrieve_type {
-            Some(type_.to_vec())
-        } else {
-            None
-        };
-
-        let tags = if let Some(tags) = tags {
-            Some(_tags_from_json(tags)?)
-        } else {
-            None
-        };
-
-        Ok(StorageRecord::new(id.to_vec(), value, type_, tags))
-    }
-
-    ///
-    /// inserts value and tags into storage.
-    /// Returns Result with () on success or
-    /// Result with IndyError in case of failure.
-    ///
-    ///
-    /// # Arguments
-    ///
-    ///  * `type_` - type of the item in storage
-    ///  * `id` - id of the item in storage
-    ///  * `value` - value of the item in storage
-    ///  * `value_key` - key used to encrypt the value
-    ///  * `tags` - tags assigned to the value
-    ///
-    /// # Returns
-    ///
-    /// Result that can be either:
-    ///
-    ///  * `()`
-    ///  * `IndyError`
-    ///
-    /// # Errors
-    ///
-    /// Any of the following `IndyError` class of errors can be throw by this method:
-    ///
-    ///  * `IndyError::Closed` - Storage is closed
-    ///  * `IndyError::ItemAlreadyExists` - Item is already present in database
-    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
-    ///
-    async fn add(
+impl MySqlStorage {
+    async fn _add(
         &self,
+        tx: &mut Transaction<'_, MySql>,
         type_: &[u8],
         id: &[u8],
         value: &EncryptedValue,
         tags: &[Tag],
     ) -> IndyResult<()> {
-        let mut tx = self.write_pool.begin().await?;
-
         sqlx::query(
             r#"
             INSERT INTO items (type, name, value, tags, wallet_id)
@@ -295,16 +181,19 @@ impl WalletStorage for MySqlStorage {
         .bind(&value.to_bytes())
         .bind(&_tags_to_json(tags)?)
         .bind(&self.wallet_id)
-        .execute(&mut tx)
+        .execute(&mut *tx)
         .await?;
 
-        tx.commit().await?;
         Ok(())
     }
 
-    async fn update(&self, type_: &[u8], id: &[u8], value: &EncryptedValue) -> IndyResult<()> {
-        let mut tx = self.write_pool.begin().await?;
-
+    async fn _update(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        type_: &[u8],
+        id: &[u8],
+        value: &EncryptedValue,
+    ) -> IndyResult<()> {
         let row_updated = sqlx::query(
             r#"
             UPDATE items
@@ -318,15 +207,12 @@ impl WalletStorage for MySqlStorage {
         .bind(&base64::encode(type_))
         .bind(&base64::encode(id))
         .bind(&self.wallet_id)
-        .execute(&mut tx)
+        .execute(&mut *tx)
         .await?
         .rows_affected();
 
         match row_updated {
-            1 => {
-                tx.commit().await?;
-                Ok(())
-            }
+            1 => Ok(()),
             0 => Err(err_msg(
                 IndyErrorKind::WalletItemNotFound,
                 "Item to update not found",
@@ -338,7 +224,13 @@ impl WalletStorage for MySqlStorage {
         }
     }
 
-    async fn add_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
+    async fn _add_tags(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        type_: &[u8],
+        id: &[u8],
+        tags: &[Tag],
+    ) -> IndyResult<()> {
         if tags.is_empty() {
             // FIXME: Think about checking item exists
             return Ok(());
@@ -350,8 +242,6 @@ impl WalletStorage for MySqlStorage {
             .collect::<Vec<_>>()
             .join(",");
 
-        let mut tx = self.write_pool.begin().await?;
-
         let row_updated = sqlx::query(&format!(
             r#"
             UPDATE items
@@ -365,15 +255,12 @@ impl WalletStorage for MySqlStorage {
         .bind(&base64::encode(type_))
         .bind(&base64::encode(id))
         .bind(&self.wallet_id)
-        .execute(&mut tx)
+        .execute(&mut *tx)
         .await?
         .rows_affected();
 
         match row_updated {
-            1 => {
-                tx.commit().await?;
-                Ok(())
-            }
+            1 => Ok(()),
             0 => Err(err_msg(
                 IndyErrorKind::WalletItemNotFound,
                 "Item to update not found",
@@ -385,9 +272,13 @@ impl WalletStorage for MySqlStorage {
         }
     }
 
-    async fn update_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
-        let mut tx = self.write_pool.begin().await?;
-
+    async fn _update_tags(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        type_: &[u8],
+        id: &[u8],
+        tags: &[Tag],
+    ) -> IndyResult<()> {
         let row_updated = sqlx::query(
             r#"
             UPDATE items
@@ -401,15 +292,12 @@ impl WalletStorage for MySqlStorage {
         .bind(&base64::encode(type_))
         .bind(&base64::encode(id))
         .bind(&self.wallet_id)
-        .execute(&mut tx)
+        .execute(&mut *tx)
         .await?
         .rows_affected();
 
         match row_updated {
-            1 => {
-                tx.commit().await?;
-                Ok(())
-            }
+            1 => Ok(()),
             0 => Err(err_msg(
                 IndyErrorKind::WalletItemNotFound,
                 "Item to update not found",
@@ -421,14 +309,18 @@ impl WalletStorage for MySqlStorage {
         }
     }
 
-    async fn delete_tags(&self, type_: &[u8], id: &[u8], tag_names: &[TagName]) -> IndyResult<()> {
+    async fn _delete_tags(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        type_: &[u8],
+        id: &[u8],
+        tag_names: &[TagName],
+    ) -> IndyResult<()> {
         if tag_names.is_empty() {
             // FIXME: Think about checking item exists
             return Ok(());
         }
 
-        let mut tx = self.write_pool.begin().await?;
-
         let tag_name_paths = _tag_names_to_plain(&tag_names)
             .into_iter()
             .map(|tag_name| format!(r#"'$."{}"'"#, tag_name))
@@ -448,15 +340,12 @@ impl WalletStorage for MySqlStorage {
         .bind(&base64::encode(type_))
         .bind(&base64::encode(id))
         .bind(&self.wallet_id)
-        .execute(&mut tx)
+        .execute(&mut *tx)
         .await?
         .rows_affected();
 
         match row_updated {
-            1 => {
-                tx.commit().await?;
-                Ok(())
-            }
+            1 => Ok(()),
             0 => Err(err_msg(
                 IndyErrorKind::WalletItemNotFound,
                 "Item to update not found",
@@ -468,6 +357,195 @@ impl WalletStorage for MySqlStorage {
         }
     }
 
+    async fn _delete(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        type_: &[u8],
+        id: &[u8],
+    ) -> IndyResult<()> {
+        let rows_affected = sqlx::query(
+            r#"
+            DELETE FROM items
+            WHERE type = ?
+                AND name = ?
+                AND wallet_id = ?"#,
+        )
+        .bind(&base64::encode(type_))
+        .bind(&base64::encode(id))
+        .bind(&self.wallet_id)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        match rows_affected {
+            1 => Ok(()),
+            0 => Err(err_msg(
+                IndyErrorKind::WalletItemNotFound,
+                "Item to delete not found",
+            )),
+            _ => Err(err_msg(
+                IndyErrorKind::InvalidState,
+                "More than one row deleted. Seems wallet structure is inconsistent",
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl WalletStorage for MySqlStorage {
+    ///
+    /// Tries to fetch values and/or tags from the storage.
+    /// Returns Result with StorageEntity object which holds requested data in case of success or
+    /// Result with IndyError in case of failure.
+    ///
+    ///
+    /// # Arguments
+    ///
+    ///  * `type_` - type_ of the item in storage
+    ///  * `id` - id of the item in storage
+    ///  * `options` - JSon containing what needs to be fetched.
+    ///  Example: {"retrieveValue": true, "retrieveTags": true}
+    ///
+    /// # Returns
+    ///
+    /// Result that can be either:
+    ///
+    ///  * `StorageEntity` - Contains name, optional value and optional tags
+    ///  * `IndyError`
+    ///
+    /// # Errors
+    ///
+    /// Any of the following `IndyError` type_ of errors can be throw by this method:
+    ///
+    ///  * `IndyError::Closed` - Storage is closed
+    ///  * `IndyError::ItemNotFound` - Item is not found in database
+    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
+    ///
+    async fn get(&self, type_: &[u8], id: &[u8], options: &str) -> IndyResult<StorageRecord> {
+        let options: RecordOptions = serde_json::from_str(options).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "RecordOptions is malformed json",
+        )?;
+
+        let mut conn = self.read_pool.acquire().await?;
+
+        let (value, tags): (Option<Vec<u8>>, Option<serde_json::Value>) = sqlx::query_as(&format!(
+            r#"
+            SELECT {}, {}
+            FROM items
+            WHERE
+                wallet_id = ?
+                    AND type = ?
+                    AND name = ?
+            "#,
+            if options.retrieve_value {
+                "value"
+            } else {
+                "NULL"
+            },
+            if options.retrieve_tags {
+                "tags"
+            } else {
+                "NULL"
+            },
+        ))
+        .bind(self.wallet_id)
+        .bind(&base64::encode(type_))
+        .bind(&base64::encode(id))
+        .fetch_one(&mut conn)
+        .await?;
+
+        let value = if let Some(value) = value {
+            Some(EncryptedValue::from_bytes(&value)?)
+        } else {
+            None
+        };
+
+        let type_ = if options.retrieve_type {
+            Some(type_.to_vec())
+        } else {
+            None
+        };
+
+        let tags = if let Some(tags) = tags {
+            Some(_tags_from_json(tags)?)
+        } else {
+            None
+        };
+
+        Ok(StorageRecord::new(id.to_vec(), value, type_, tags))
+    }
+
+    ///
+    /// inserts value and tags into storage.
+    /// Returns Result with () on success or
+    /// Result with IndyError in case of failure.
+    ///
+    ///
+    /// # Arguments
+    ///
+    ///  * `type_` - type of the item in storage
+    ///  * `id` - id of the item in storage
+    ///  * `value` - value of the item in storage
+    ///  * `value_key` - key used to encrypt the value
+    ///  * `tags` - tags assigned to the value
+    ///
+    /// # Returns
+    ///
+    /// Result that can be either:
+    ///
+    ///  * `()`
+    ///  * `IndyError`
+    ///
+    /// # Errors
+    ///
+    /// Any of the following `IndyError` class of errors can be throw by this method:
+    ///
+    ///  * `IndyError::Closed` - Storage is closed
+    ///  * `IndyError::ItemAlreadyExists` - Item is already present in database
+    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
+    ///
+    async fn add(
+        &self,
+        type_: &[u8],
+        id: &[u8],
+        value: &EncryptedValue,
+        tags: &[Tag],
+    ) -> IndyResult<()> {
+        let mut tx = self.write_pool.begin().await?;
+        self._add(&mut tx, type_, id, value, tags).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn update(&self, type_: &[u8], id: &[u8], value: &EncryptedValue) -> IndyResult<()> {
+        let mut tx = self.write_pool.begin().await?;
+        self._update(&mut tx, type_, id, value).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn add_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
+        let mut tx = self.write_pool.begin().await?;
+        self._add_tags(&mut tx, type_, id, tags).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn update_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
+        let mut tx = self.write_pool.begin().await?;
+        self._update_tags(&mut tx, type_, id, tags).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn delete_tags(&self, type_: &[u8], id: &[u8], tag_names: &[TagName]) -> IndyResult<()> {
+        let mut tx = self.write_pool.begin().await?;
+        self._delete_tags(&mut tx, type_, id, tag_names).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
     ///
     /// deletes value and tags into storage.
     /// Returns Result with () on success or
@@ -496,35 +574,62 @@ impl WalletStorage for MySqlStorage {
     ///
     async fn delete(&self, type_: &[u8], id: &[u8]) -> IndyResult<()> {
         let mut tx = self.write_pool.begin().await?;
+        self._delete(&mut tx, type_, id).await?;
+        tx.commit().await?;
+        Ok(())
+    }
 
-        let rows_affected = sqlx::query(
-            r#"
-            DELETE FROM items
-            WHERE type = ?
-                AND name = ?
-                AND wallet_id = ?"#,
-        )
-        .bind(&base64::encode(type_))
-        .bind(&base64::encode(id))
-        .bind(&self.wallet_id)
-        .execute(&mut tx)
-        .await?
-        .rows_affected();
+    ///
+    /// applies the list of operations to the storage within a single transaction.
+    /// Either all operations are applied or, if any of them fails, none of them.
+    ///
+    /// # Arguments
+    ///
+    ///  * `operations` - operations to apply in the given order
+    ///
+    /// # Errors
+    ///
+    /// The error of the first failed operation is returned and the transaction is rolled back.
+    ///
+    async fn batch(&self, operations: &[StorageOperation]) -> IndyResult<()> {
+        let mut tx = self.write_pool.begin().await?;
 
-        match rows_affected {
-            1 => {
-                tx.commit().await?;
-                Ok(())
+        for operation in operations {
+            match *operation {
+                StorageOperation::Add {
+                    ref type_,
+                    ref id,
+                    ref value,
+                    ref tags,
+                } => self._add(&mut tx, type_, id, value, tags).await?,
+                StorageOperation::Update {
+                    ref type_,
+                    ref id,
+                    ref value,
+                } => self._update(&mut tx, type_, id, value).await?,
+                StorageOperation::AddTags {
+                    ref type_,
+                    ref id,
+                    ref tags,
+                } => self._add_tags(&mut tx, type_, id, tags).await?,
+                StorageOperation::UpdateTags {
+                    ref type_,
+                    ref id,
+                    ref tags,
+                } => self._update_tags(&mut tx, type_, id, tags).await?,
+                StorageOperation::DeleteTags {
+                    ref type_,
+                    ref id,
+                    ref tag_names,
+                } => self._delete_tags(&mut tx, type_, id, tag_names).await?,
+                StorageOperation::Delete { ref type_, ref id } => {
+                    self._delete(&mut tx, type_, id).await?
+                }
             }
-            0 => Err(err_msg(
-                IndyErrorKind::WalletItemNotFound,
-                "Item to delete not found",
-            )),
-            _ => Err(err_msg(
-                IndyErrorKind::InvalidState,
-                "More than one row deleted. Seems wallet structure is inconsistent",
-            )),
         }
+
+        tx.commit().await?;
+        Ok(())
     }
 
     async fn get_storage_metadata(&self) -> IndyResult<Vec<u8>> {
@@ -1888,6 +1993,128 @@ mod tests {
         _cleanup("mysql_storage_delete_tags_works_for_non_existing_id").await;
     }
 
+    #[async_std::test]
+    #[cfg(feature = "mysql")]
+    async fn mysql_storage_batch_works() {
+        _cleanup("mysql_storage_batch_works").await;
+
+        {
+            let storage = _storage("mysql_storage_batch_works").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            let operations = vec![
+                StorageOperation::Add {
+                    type_: _type2(),
+                    id: _id2(),
+                    value: _value2(),
+                    tags: _new_tags(),
+                },
+                StorageOperation::Update {
+                    type_: _type1(),
+                    id: _id1(),
+                    value: _value2(),
+                },
+                StorageOperation::UpdateTags {
+                    type_: _type1(),
+                    id: _id1(),
+                    tags: _new_tags(),
+                },
+            ];
+
+            storage.batch(&operations).await.unwrap();
+
+            let record = storage
+                .get(
+                    &_type1(),
+                    &_id1(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(record.value.unwrap(), _value2());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_new_tags()));
+
+            let record = storage
+                .get(
+                    &_type2(),
+                    &_id2(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(record.value.unwrap(), _value2());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_new_tags()));
+        }
+
+        _cleanup("mysql_storage_batch_works").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "mysql")]
+    async fn mysql_storage_batch_works_for_rollback() {
+        _cleanup("mysql_storage_batch_works_for_rollback").await;
+
+        {
+            let storage = _storage("mysql_storage_batch_works_for_rollback").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            let operations = vec![
+                StorageOperation::Add {
+                    type_: _type2(),
+                    id: _id2(),
+                    value: _value2(),
+                    tags: _new_tags(),
+                },
+                StorageOperation::Delete {
+                    type_: _type1(),
+                    id: _id1(),
+                },
+                StorageOperation::Update {
+                    type_: _type1(),
+                    id: _id2(),
+                    value: _value2(),
+                },
+            ];
+
+            let res = storage.batch(&operations).await;
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+
+            let res = storage
+                .get(
+                    &_type2(),
+                    &_id2(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await;
+
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+
+            let record = storage
+                .get(
+                    &_type1(),
+                    &_id1(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(record.value.unwrap(), _value1());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_tags()));
+        }
+
+        _cleanup("mysql_storage_batch_works_for_rollback").await;
+    }
+
     fn _config() -> Option<&'static str> {
         Some(
             r#"
@@ -12,7 +12,7 @@ use indy_api_types::errors::prelude::*;
 use crate::language;
 use indy_utils::crypto::base64;
 
-use super::{EncryptedValue, StorageIterator, StorageRecord, Tag, TagName, WalletStorage, WalletStorageType};
+use super::{EncryptedValue, StorageIterator, StorageOperation, StorageRecord, Tag, TagName, WalletStorage, WalletStorageType};
 use super::super::{RecordOptions, SearchOptions};
 
 #[derive(Debug, Deserialize)]
@@ -518,6 +518,11 @@ impl WalletStorage for PluggedStorage {
         Ok(())
     }
 
+    // Storage plugins have no notion of transactions, so all-or-nothing batches can't be provided.
+    fn batch(&self, _operations: &[StorageOperation]) -> IndyResult<()> {
+        Err(err_msg(IndyErrorKind::WalletStorageError, "Batch operations are not supported by plugged wallet storages"))
+    }
+
     fn get_storage_metadata(&self) -> IndyResult<Vec<u8>> {
         let mut metadata_ptr: *const c_char = ptr::null_mut();
         let mut metadata_handle = -1;
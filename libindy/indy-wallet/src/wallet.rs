@@ -10,8 +10,8 @@ use indy_utils::{
 use serde::{Deserialize, Serialize};
 use zeroize::Zeroize;
 
-use crate::{encryption::*, iterator::WalletIterator, query_encryption::encrypt_query, storage, WalletRecord, cache::wallet_cache::WalletCache, RecordOptions};
-use crate::storage::StorageRecord;
+use crate::{encryption::*, iterator::WalletIterator, query_encryption::encrypt_query, storage, WalletOperation, WalletRecord, cache::wallet_cache::WalletCache, RecordOptions};
+use crate::storage::{StorageOperation, StorageRecord, Tag};
 use crate::cache::wallet_cache::WalletCacheHitMetrics;
 use futures::future::join;
 
@@ -392,6 +392,143 @@ impl Wallet {
         Ok(())
     }
 
+    pub async fn batch(&self, operations: &[WalletOperation]) -> IndyResult<()> {
+        let mut encrypted_operations = Vec::with_capacity(operations.len());
+
+        for operation in operations {
+            let encrypted_operation = match *operation {
+                WalletOperation::Add {
+                    ref type_,
+                    ref id,
+                    ref value,
+                    ref tags,
+                } => StorageOperation::Add {
+                    type_: self._encrypt_type(type_),
+                    id: self._encrypt_name(id),
+                    value: EncryptedValue::encrypt(value, &self.keys.value_key),
+                    tags: self._encrypt_tags(tags),
+                },
+                WalletOperation::UpdateValue {
+                    ref type_,
+                    ref id,
+                    ref value,
+                } => StorageOperation::Update {
+                    type_: self._encrypt_type(type_),
+                    id: self._encrypt_name(id),
+                    value: EncryptedValue::encrypt(value, &self.keys.value_key),
+                },
+                WalletOperation::AddTags {
+                    ref type_,
+                    ref id,
+                    ref tags,
+                } => StorageOperation::AddTags {
+                    type_: self._encrypt_type(type_),
+                    id: self._encrypt_name(id),
+                    tags: self._encrypt_tags(tags),
+                },
+                WalletOperation::UpdateTags {
+                    ref type_,
+                    ref id,
+                    ref tags,
+                } => StorageOperation::UpdateTags {
+                    type_: self._encrypt_type(type_),
+                    id: self._encrypt_name(id),
+                    tags: self._encrypt_tags(tags),
+                },
+                WalletOperation::DeleteTags {
+                    ref type_,
+                    ref id,
+                    ref tag_names,
+                } => {
+                    let tag_names = tag_names.iter().map(String::as_str).collect::<Vec<&str>>();
+
+                    StorageOperation::DeleteTags {
+                        type_: self._encrypt_type(type_),
+                        id: self._encrypt_name(id),
+                        tag_names: encrypt_tag_names(
+                            &tag_names,
+                            &self.keys.tag_name_key,
+                            &self.keys.tags_hmac_key,
+                        ),
+                    }
+                }
+                WalletOperation::Delete { ref type_, ref id } => StorageOperation::Delete {
+                    type_: self._encrypt_type(type_),
+                    id: self._encrypt_name(id),
+                },
+            };
+
+            encrypted_operations.push(encrypted_operation);
+        }
+
+        self.storage.batch(&encrypted_operations).await?;
+
+        // Cache is updated only after the whole batch is committed to the storage.
+        for (operation, encrypted_operation) in operations.iter().zip(encrypted_operations.iter()) {
+            let type_ = operation.get_type();
+
+            match *encrypted_operation {
+                StorageOperation::Add {
+                    type_: ref etype,
+                    id: ref ename,
+                    value: ref evalue,
+                    tags: ref etags,
+                } => self.cache.add(type_, etype, ename, evalue, etags).await,
+                StorageOperation::Update {
+                    type_: ref etype,
+                    id: ref ename,
+                    value: ref evalue,
+                } => self.cache.update(type_, etype, ename, evalue).await,
+                StorageOperation::AddTags {
+                    type_: ref etype,
+                    id: ref ename,
+                    tags: ref etags,
+                } => self.cache.add_tags(type_, etype, ename, etags).await,
+                StorageOperation::UpdateTags {
+                    type_: ref etype,
+                    id: ref ename,
+                    tags: ref etags,
+                } => self.cache.update_tags(type_, etype, ename, etags).await,
+                StorageOperation::DeleteTags {
+                    type_: ref etype,
+                    id: ref ename,
+                    tag_names: ref etag_names,
+                } => self.cache.delete_tags(type_, etype, ename, etag_names).await,
+                StorageOperation::Delete {
+                    type_: ref etype,
+                    id: ref ename,
+                } => self.cache.delete(type_, etype, ename).await,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn _encrypt_type(&self, type_: &str) -> Vec<u8> {
+        encrypt_as_searchable(
+            type_.as_bytes(),
+            &self.keys.type_key,
+            &self.keys.item_hmac_key,
+        )
+    }
+
+    fn _encrypt_name(&self, name: &str) -> Vec<u8> {
+        encrypt_as_searchable(
+            name.as_bytes(),
+            &self.keys.name_key,
+            &self.keys.item_hmac_key,
+        )
+    }
+
+    fn _encrypt_tags(&self, tags: &HashMap<String, String>) -> Vec<Tag> {
+        encrypt_tags(
+            tags,
+            &self.keys.tag_name_key,
+            &self.keys.tag_value_key,
+            &self.keys.tags_hmac_key,
+        )
+    }
+
     pub async fn search<'a>(
         &'a self,
         type_: &str,
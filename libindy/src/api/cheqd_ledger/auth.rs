@@ -109,6 +109,7 @@ pub extern "C" fn indy_cheqd_ledger_auth_build_tx(
                 &max_coin_denom,
                 timeout_height,
                 &memo,
+                None,
             )
             .await;
         res
@@ -132,6 +133,138 @@ pub extern "C" fn indy_cheqd_ledger_auth_build_tx(
 }
 
 
+/// Build txn before sending with the fee paid by a fee granter
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_alias: string alias of a pool
+/// sender_public_key: public key of sender
+/// msg_raw: message in raw format,
+/// msg_len: length of message,
+/// account_number: number of accounts,
+/// sequence_number: how many txns are already written,
+/// max_gas: how much gas user is ready to pay.,
+/// max_coin_amount: how many coins user can pay,
+/// max_coin_denom: which kink of coins user is ready to pay,
+/// timeout_height: block height until which the transaction is valid,
+/// memo: a note or comment to send with the transaction,
+/// fee_granter: address of an account which pays the fee
+///     using an allowance granted to the sender by MsgGrantAllowance,
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_auth_build_tx_with_fee_granter(
+    command_handle: CommandHandle,
+    pool_alias: *const c_char,
+    sender_public_key: *const c_char,
+    msg_raw: *const u8,
+    msg_len: u32,
+    account_number: u64,
+    sequence_number: u64,
+    max_gas: u64,
+    max_coin_amount: u64,
+    max_coin_denom: *const c_char,
+    timeout_height: u64,
+    memo: *const c_char,
+    fee_granter: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            tx_raw: *const u8,
+            tx_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_auth_build_tx_with_fee_granter > pool_alias {:?} sender_public_key {:?} msg_raw {:?} \
+        msg_len {:?} account_number {:?} sequence_number {:?} max_gas {:?} max_coin_amount \
+        {:?} max_coin_denom {:?} timeout_height {:?} memo {:?} fee_granter {:?}",
+        pool_alias,
+        sender_public_key,
+        msg_raw,
+        msg_len,
+        account_number,
+        sequence_number,
+        max_gas,
+        max_coin_amount,
+        max_coin_denom,
+        timeout_height,
+        memo,
+        fee_granter
+    );
+
+    check_useful_c_str!(pool_alias, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(sender_public_key, ErrorCode::CommonInvalidParam3);
+    check_useful_c_byte_array!(
+        msg_raw,
+        msg_len,
+        ErrorCode::CommonInvalidParam4,
+        ErrorCode::CommonInvalidParam5
+    );
+    check_useful_c_str!(max_coin_denom, ErrorCode::CommonInvalidParam10);
+    check_useful_c_str!(memo, ErrorCode::CommonInvalidParam12);
+    check_useful_c_str!(fee_granter, ErrorCode::CommonInvalidParam13);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam14);
+
+    debug!(
+        "indy_cheqd_ledger_auth_build_tx_with_fee_granter > pool_alias {:?} sender_public_key {:?} msg_raw {:?} \
+        account_number {:?} sequence_number {:?} max_gas {:?} max_coin_amount \
+        {:?} max_coin_denom {:?} timeout_height {:?} memo {:?} fee_granter {:?}",
+        pool_alias,
+        sender_public_key,
+        msg_raw,
+        account_number,
+        sequence_number,
+        max_gas,
+        max_coin_amount,
+        max_coin_denom,
+        timeout_height,
+        memo,
+        fee_granter
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .auth_build_tx(
+                &pool_alias,
+                &sender_public_key,
+                &msg_raw,
+                account_number,
+                sequence_number,
+                max_gas,
+                max_coin_amount,
+                &max_coin_denom,
+                timeout_height,
+                &memo,
+                Some(&fee_granter),
+            )
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, tx) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_auth_build_tx_with_fee_granter ? err {:?} tx {:?}", err, tx);
+
+        let (tx_raw, tx_len) = ctypes::vec_to_pointer(&tx);
+        cb(command_handle, err, tx_raw, tx_len)
+    };
+
+    locator
+        .executor
+        .spawn_ok_instrumented(CommandMetric::CheqdLedgerCommandBuildTxWithFeeGranter, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_auth_build_tx_with_fee_granter < {:?}", res);
+    res
+}
+
 /// Build query for getting info about account.
 ///
 /// #Params
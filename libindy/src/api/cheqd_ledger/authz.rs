@@ -0,0 +1,386 @@
+use indy_api_types::{CommandHandle, ErrorCode, errors::prelude::*};
+use indy_utils::ctypes;
+use libc::c_char;
+
+use crate::Locator;
+use crate::services::CommandMetric;
+
+/// Build MsgGrant to authorise the grantee to execute messages of the given type on behalf of the granter.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// granter: address of the account which gives the authorization
+/// grantee: address of the account which receives the authorization
+/// msg_type_url: type url of the authorized message, e.g. "/cheqdid.cheqdnode.cheqd.MsgCreateNym"
+/// expiration: unix timestamp in seconds when the authorization expires
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_authz_build_msg_grant(
+    command_handle: CommandHandle,
+    granter: *const c_char,
+    grantee: *const c_char,
+    msg_type_url: *const c_char,
+    expiration: i64,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            msg_raw: *const u8,
+            msg_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_authz_build_msg_grant > granter {:?} grantee {:?} msg_type_url {:?} expiration {:?}",
+        granter, grantee, msg_type_url, expiration
+    );
+
+    check_useful_c_str!(granter, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(grantee, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(msg_type_url, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    debug!(
+        "indy_cheqd_ledger_authz_build_msg_grant > granter {:?} grantee {:?} msg_type_url {:?} expiration {:?}",
+        granter, grantee, msg_type_url, expiration
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .authz_build_msg_grant(&granter, &grantee, &msg_type_url, expiration);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_authz_build_msg_grant: msg: {:?}", msg);
+        let (msg_raw, msg_len) = ctypes::vec_to_pointer(&msg);
+        cb(command_handle, err, msg_raw, msg_len)
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildMsgGrant,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_authz_build_msg_grant < {:?}", res);
+    res
+}
+
+/// Parse response for MsgGrant transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// commit_resp: response for MsgGrant transaction
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_authz_parse_msg_grant_resp(
+    command_handle: CommandHandle,
+    commit_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, msg_resp: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_authz_parse_msg_grant_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    check_useful_c_str!(commit_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_authz_parse_msg_grant_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .authz_parse_msg_grant_resp(&commit_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg_resp) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_authz_parse_msg_grant_resp: msg_resp: {:?}", msg_resp);
+        let msg_resp = ctypes::string_to_cstring(msg_resp);
+        cb(command_handle, err, msg_resp.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseMsgGrantResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_authz_parse_msg_grant_resp < {:?}", res);
+    res
+}
+
+/// Build MsgExec to submit a message on behalf of the granter which has authorised the grantee by MsgGrant.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// grantee: address of the account which submits the message
+/// msg: message built by one of the cheqd ledger builders
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_authz_build_msg_exec(
+    command_handle: CommandHandle,
+    grantee: *const c_char,
+    msg_raw: *const u8,
+    msg_len: u32,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            msg_raw: *const u8,
+            msg_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_authz_build_msg_exec > grantee {:?} msg_raw {:?}",
+        grantee, msg_raw
+    );
+
+    check_useful_c_str!(grantee, ErrorCode::CommonInvalidParam2);
+    check_useful_c_byte_array!(
+        msg_raw,
+        msg_len,
+        ErrorCode::CommonInvalidParam3,
+        ErrorCode::CommonInvalidParam4
+    );
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_cheqd_ledger_authz_build_msg_exec > grantee {:?} msg_raw {:?}",
+        grantee, msg_raw
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .authz_build_msg_exec(&grantee, &msg_raw);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_authz_build_msg_exec: msg: {:?}", msg);
+        let (msg_raw, msg_len) = ctypes::vec_to_pointer(&msg);
+        cb(command_handle, err, msg_raw, msg_len)
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildMsgExec,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_authz_build_msg_exec < {:?}", res);
+    res
+}
+
+/// Parse response for MsgExec transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// commit_resp: response for MsgExec transaction
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_authz_parse_msg_exec_resp(
+    command_handle: CommandHandle,
+    commit_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, msg_resp: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_authz_parse_msg_exec_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    check_useful_c_str!(commit_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_authz_parse_msg_exec_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .authz_parse_msg_exec_resp(&commit_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg_resp) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_authz_parse_msg_exec_resp: msg_resp: {:?}", msg_resp);
+        let msg_resp = ctypes::string_to_cstring(msg_resp);
+        cb(command_handle, err, msg_resp.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseMsgExecResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_authz_parse_msg_exec_resp < {:?}", res);
+    res
+}
+
+/// Build MsgRevoke to revoke the authorization given to the grantee by MsgGrant.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// granter: address of the account which gave the authorization
+/// grantee: address of the account which received the authorization
+/// msg_type_url: type url of the authorized message
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_authz_build_msg_revoke(
+    command_handle: CommandHandle,
+    granter: *const c_char,
+    grantee: *const c_char,
+    msg_type_url: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            msg_raw: *const u8,
+            msg_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_authz_build_msg_revoke > granter {:?} grantee {:?} msg_type_url {:?}",
+        granter, grantee, msg_type_url
+    );
+
+    check_useful_c_str!(granter, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(grantee, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(msg_type_url, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_cheqd_ledger_authz_build_msg_revoke > granter {:?} grantee {:?} msg_type_url {:?}",
+        granter, grantee, msg_type_url
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .authz_build_msg_revoke(&granter, &grantee, &msg_type_url);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_authz_build_msg_revoke: msg: {:?}", msg);
+        let (msg_raw, msg_len) = ctypes::vec_to_pointer(&msg);
+        cb(command_handle, err, msg_raw, msg_len)
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildMsgRevoke,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_authz_build_msg_revoke < {:?}", res);
+    res
+}
+
+/// Parse response for MsgRevoke transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// commit_resp: response for MsgRevoke transaction
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_authz_parse_msg_revoke_resp(
+    command_handle: CommandHandle,
+    commit_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, msg_resp: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_authz_parse_msg_revoke_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    check_useful_c_str!(commit_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_authz_parse_msg_revoke_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .authz_parse_msg_revoke_resp(&commit_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg_resp) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_authz_parse_msg_revoke_resp: msg_resp: {:?}", msg_resp);
+        let msg_resp = ctypes::string_to_cstring(msg_resp);
+        cb(command_handle, err, msg_resp.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseMsgRevokeResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_authz_parse_msg_revoke_resp < {:?}", res);
+    res
+}
@@ -0,0 +1,242 @@
+use indy_api_types::{CommandHandle, ErrorCode, errors::prelude::*};
+use indy_utils::ctypes;
+use libc::c_char;
+
+use crate::Locator;
+use crate::services::CommandMetric;
+
+/// Build MsgWithdrawDelegatorReward to withdraw rewards of a delegator from a validator.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// delegator_address: address of the delegator
+/// validator_address: address of the validator
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_distribution_build_msg_withdraw_delegator_reward(
+    command_handle: CommandHandle,
+    delegator_address: *const c_char,
+    validator_address: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            msg_raw: *const u8,
+            msg_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_distribution_build_msg_withdraw_delegator_reward > delegator_address {:?} validator_address {:?}",
+        delegator_address, validator_address
+    );
+
+    check_useful_c_str!(delegator_address, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(validator_address, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_cheqd_ledger_distribution_build_msg_withdraw_delegator_reward > delegator_address {:?} validator_address {:?}",
+        delegator_address, validator_address
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .distribution_build_msg_withdraw_delegator_reward(&delegator_address, &validator_address);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_distribution_build_msg_withdraw_delegator_reward: msg: {:?}", msg);
+        let (msg_raw, msg_len) = ctypes::vec_to_pointer(&msg);
+        cb(command_handle, err, msg_raw, msg_len)
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildMsgWithdrawDelegatorReward,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_distribution_build_msg_withdraw_delegator_reward < {:?}", res);
+    res
+}
+
+/// Parse response for MsgWithdrawDelegatorReward transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// commit_resp: response for MsgWithdrawDelegatorReward transaction
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_distribution_parse_msg_withdraw_delegator_reward_resp(
+    command_handle: CommandHandle,
+    commit_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, msg_resp: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_distribution_parse_msg_withdraw_delegator_reward_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    check_useful_c_str!(commit_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_distribution_parse_msg_withdraw_delegator_reward_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .distribution_parse_msg_withdraw_delegator_reward_resp(&commit_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg_resp) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_distribution_parse_msg_withdraw_delegator_reward_resp: msg_resp: {:?}", msg_resp);
+        let msg_resp = ctypes::string_to_cstring(msg_resp);
+        cb(command_handle, err, msg_resp.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseMsgWithdrawDelegatorRewardResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_distribution_parse_msg_withdraw_delegator_reward_resp < {:?}", res);
+    res
+}
+
+/// Build query for getting pending rewards of a delegator from all validators.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// delegator_address: address of the delegator
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_distribution_build_query_delegation_total_rewards(
+    command_handle: CommandHandle,
+    delegator_address: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, query: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_distribution_build_query_delegation_total_rewards > delegator_address {:?}",
+        delegator_address
+    );
+
+    check_useful_c_str!(delegator_address, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_distribution_build_query_delegation_total_rewards > delegator_address {:?}",
+        delegator_address
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .distribution_build_query_delegation_total_rewards(&delegator_address);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, query) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_distribution_build_query_delegation_total_rewards: query: {:?}", query);
+        let query = ctypes::string_to_cstring(query);
+        cb(command_handle, err, query.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildQueryDelegationTotalRewards,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_distribution_build_query_delegation_total_rewards < {:?}", res);
+    res
+}
+
+/// Parse response for QueryDelegationTotalRewards.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// query_resp: response for QueryDelegationTotalRewards query
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_distribution_parse_query_delegation_total_rewards_resp(
+    command_handle: CommandHandle,
+    query_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, rewards: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_distribution_parse_query_delegation_total_rewards_resp > query_resp {:?}",
+        query_resp
+    );
+
+    check_useful_c_str!(query_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_distribution_parse_query_delegation_total_rewards_resp > query_resp {:?}",
+        query_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .distribution_parse_query_delegation_total_rewards_resp(&query_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, rewards) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_distribution_parse_query_delegation_total_rewards_resp: rewards: {:?}", rewards);
+        let rewards = ctypes::string_to_cstring(rewards);
+        cb(command_handle, err, rewards.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseQueryDelegationTotalRewardsResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_distribution_parse_query_delegation_total_rewards_resp < {:?}", res);
+    res
+}
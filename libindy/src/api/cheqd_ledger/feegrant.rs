@@ -0,0 +1,260 @@
+use indy_api_types::{CommandHandle, ErrorCode, errors::prelude::*};
+use indy_utils::ctypes;
+use libc::c_char;
+
+use crate::Locator;
+use crate::services::CommandMetric;
+
+/// Build MsgGrantAllowance to allow the grantee to pay transaction fees from the granter account.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// granter: address of the account which pays fees
+/// grantee: address of the account which is allowed to use the granter coins for fees
+/// spend_limit_amount: (Optional) max amount of coins the grantee can spend on fees. Unlimited if not set
+/// spend_limit_denom: (Optional) denomination of the spend limit coins
+/// expiration: unix timestamp in seconds when the allowance expires (-1 to omit)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_feegrant_build_msg_grant_allowance(
+    command_handle: CommandHandle,
+    granter: *const c_char,
+    grantee: *const c_char,
+    spend_limit_amount: *const c_char,
+    spend_limit_denom: *const c_char,
+    expiration: i64,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            msg_raw: *const u8,
+            msg_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_feegrant_build_msg_grant_allowance > granter {:?} grantee {:?} spend_limit_amount {:?} spend_limit_denom {:?} expiration {:?}",
+        granter, grantee, spend_limit_amount, spend_limit_denom, expiration
+    );
+
+    check_useful_c_str!(granter, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(grantee, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_c_str!(spend_limit_amount, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(spend_limit_denom, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    debug!(
+        "indy_cheqd_ledger_feegrant_build_msg_grant_allowance > granter {:?} grantee {:?} spend_limit_amount {:?} spend_limit_denom {:?} expiration {:?}",
+        granter, grantee, spend_limit_amount, spend_limit_denom, expiration
+    );
+
+    let expiration = if expiration != -1 { Some(expiration) } else { None };
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .feegrant_build_msg_grant_allowance(&granter, &grantee, spend_limit_amount.as_deref(), spend_limit_denom.as_deref(), expiration);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_feegrant_build_msg_grant_allowance: msg: {:?}", msg);
+        let (msg_raw, msg_len) = ctypes::vec_to_pointer(&msg);
+        cb(command_handle, err, msg_raw, msg_len)
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildMsgGrantAllowance,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_feegrant_build_msg_grant_allowance < {:?}", res);
+    res
+}
+
+/// Parse response for MsgGrantAllowance transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// commit_resp: response for MsgGrantAllowance transaction
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_feegrant_parse_msg_grant_allowance_resp(
+    command_handle: CommandHandle,
+    commit_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, msg_resp: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_feegrant_parse_msg_grant_allowance_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    check_useful_c_str!(commit_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_feegrant_parse_msg_grant_allowance_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .feegrant_parse_msg_grant_allowance_resp(&commit_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg_resp) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_feegrant_parse_msg_grant_allowance_resp: msg_resp: {:?}", msg_resp);
+        let msg_resp = ctypes::string_to_cstring(msg_resp);
+        cb(command_handle, err, msg_resp.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseMsgGrantAllowanceResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_feegrant_parse_msg_grant_allowance_resp < {:?}", res);
+    res
+}
+
+/// Build MsgRevokeAllowance to remove the fee allowance granted to the grantee.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// granter: address of the account which pays fees
+/// grantee: address of the account which is allowed to use the granter coins for fees
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_feegrant_build_msg_revoke_allowance(
+    command_handle: CommandHandle,
+    granter: *const c_char,
+    grantee: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            msg_raw: *const u8,
+            msg_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_feegrant_build_msg_revoke_allowance > granter {:?} grantee {:?}",
+        granter, grantee
+    );
+
+    check_useful_c_str!(granter, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(grantee, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_cheqd_ledger_feegrant_build_msg_revoke_allowance > granter {:?} grantee {:?}",
+        granter, grantee
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .feegrant_build_msg_revoke_allowance(&granter, &grantee);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_feegrant_build_msg_revoke_allowance: msg: {:?}", msg);
+        let (msg_raw, msg_len) = ctypes::vec_to_pointer(&msg);
+        cb(command_handle, err, msg_raw, msg_len)
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildMsgRevokeAllowance,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_feegrant_build_msg_revoke_allowance < {:?}", res);
+    res
+}
+
+/// Parse response for MsgRevokeAllowance transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// commit_resp: response for MsgRevokeAllowance transaction
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_feegrant_parse_msg_revoke_allowance_resp(
+    command_handle: CommandHandle,
+    commit_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, msg_resp: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_feegrant_parse_msg_revoke_allowance_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    check_useful_c_str!(commit_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_feegrant_parse_msg_revoke_allowance_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .feegrant_parse_msg_revoke_allowance_resp(&commit_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg_resp) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_feegrant_parse_msg_revoke_allowance_resp: msg_resp: {:?}", msg_resp);
+        let msg_resp = ctypes::string_to_cstring(msg_resp);
+        cb(command_handle, err, msg_resp.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseMsgRevokeAllowanceResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_feegrant_parse_msg_revoke_allowance_resp < {:?}", res);
+    res
+}
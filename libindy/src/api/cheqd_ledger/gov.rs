@@ -0,0 +1,1048 @@
+use indy_api_types::{CommandHandle, ErrorCode, errors::prelude::*};
+use indy_utils::ctypes;
+use libc::c_char;
+
+use crate::Locator;
+use crate::services::CommandMetric;
+
+/// Build MsgSubmitProposal with a text proposal.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// title: title of the proposal
+/// description: description of the proposal
+/// proposer: address of the proposer
+/// deposit_amount: amount of coins for the initial deposit
+/// deposit_denom: denomination of coins for the initial deposit
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_build_msg_submit_text_proposal(
+    command_handle: CommandHandle,
+    title: *const c_char,
+    description: *const c_char,
+    proposer: *const c_char,
+    deposit_amount: *const c_char,
+    deposit_denom: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            msg_raw: *const u8,
+            msg_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_build_msg_submit_text_proposal > title {:?} description {:?} proposer {:?} deposit_amount {:?} deposit_denom {:?}",
+        title, description, proposer, deposit_amount, deposit_denom
+    );
+
+    check_useful_c_str!(title, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(description, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(proposer, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(deposit_amount, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(deposit_denom, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    debug!(
+        "indy_cheqd_ledger_gov_build_msg_submit_text_proposal > title {:?} description {:?} proposer {:?} deposit_amount {:?} deposit_denom {:?}",
+        title, description, proposer, deposit_amount, deposit_denom
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_build_msg_submit_text_proposal(&title, &description, &proposer, &deposit_amount, &deposit_denom);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_gov_build_msg_submit_text_proposal: msg: {:?}", msg);
+        let (msg_raw, msg_len) = ctypes::vec_to_pointer(&msg);
+        cb(command_handle, err, msg_raw, msg_len)
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildMsgSubmitTextProposal,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_build_msg_submit_text_proposal < {:?}", res);
+    res
+}
+
+/// Build MsgSubmitProposal with a parameter change proposal.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// title: title of the proposal
+/// description: description of the proposal
+/// changes: list of parameter changes json [{"subspace": string, "key": string, "value": string}]
+/// proposer: address of the proposer
+/// deposit_amount: amount of coins for the initial deposit
+/// deposit_denom: denomination of coins for the initial deposit
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_build_msg_submit_param_change_proposal(
+    command_handle: CommandHandle,
+    title: *const c_char,
+    description: *const c_char,
+    changes: *const c_char,
+    proposer: *const c_char,
+    deposit_amount: *const c_char,
+    deposit_denom: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            msg_raw: *const u8,
+            msg_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_build_msg_submit_param_change_proposal > title {:?} description {:?} changes {:?} proposer {:?} deposit_amount {:?} deposit_denom {:?}",
+        title, description, changes, proposer, deposit_amount, deposit_denom
+    );
+
+    check_useful_c_str!(title, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(description, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(changes, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(proposer, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(deposit_amount, ErrorCode::CommonInvalidParam6);
+    check_useful_c_str!(deposit_denom, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    debug!(
+        "indy_cheqd_ledger_gov_build_msg_submit_param_change_proposal > title {:?} description {:?} changes {:?} proposer {:?} deposit_amount {:?} deposit_denom {:?}",
+        title, description, changes, proposer, deposit_amount, deposit_denom
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_build_msg_submit_param_change_proposal(&title, &description, &changes, &proposer, &deposit_amount, &deposit_denom);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_gov_build_msg_submit_param_change_proposal: msg: {:?}", msg);
+        let (msg_raw, msg_len) = ctypes::vec_to_pointer(&msg);
+        cb(command_handle, err, msg_raw, msg_len)
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildMsgSubmitParamChangeProposal,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_build_msg_submit_param_change_proposal < {:?}", res);
+    res
+}
+
+/// Parse response for MsgSubmitProposal transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// commit_resp: response for MsgSubmitProposal transaction
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_parse_msg_submit_proposal_resp(
+    command_handle: CommandHandle,
+    commit_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, msg_resp: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_parse_msg_submit_proposal_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    check_useful_c_str!(commit_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_gov_parse_msg_submit_proposal_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_parse_msg_submit_proposal_resp(&commit_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg_resp) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_gov_parse_msg_submit_proposal_resp: msg_resp: {:?}", msg_resp);
+        let msg_resp = ctypes::string_to_cstring(msg_resp);
+        cb(command_handle, err, msg_resp.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseMsgSubmitProposalResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_parse_msg_submit_proposal_resp < {:?}", res);
+    res
+}
+
+/// Build MsgDeposit to add a deposit to a proposal.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// proposal_id: identifier of the proposal
+/// depositor: address of the depositor
+/// amount: amount of coins to deposit
+/// denom: denomination of coins
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_build_msg_deposit(
+    command_handle: CommandHandle,
+    proposal_id: u64,
+    depositor: *const c_char,
+    amount: *const c_char,
+    denom: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            msg_raw: *const u8,
+            msg_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_build_msg_deposit > proposal_id {:?} depositor {:?} amount {:?} denom {:?}",
+        proposal_id, depositor, amount, denom
+    );
+
+    check_useful_c_str!(depositor, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(amount, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(denom, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    debug!(
+        "indy_cheqd_ledger_gov_build_msg_deposit > proposal_id {:?} depositor {:?} amount {:?} denom {:?}",
+        proposal_id, depositor, amount, denom
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_build_msg_deposit(proposal_id, &depositor, &amount, &denom);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_gov_build_msg_deposit: msg: {:?}", msg);
+        let (msg_raw, msg_len) = ctypes::vec_to_pointer(&msg);
+        cb(command_handle, err, msg_raw, msg_len)
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildMsgDeposit,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_build_msg_deposit < {:?}", res);
+    res
+}
+
+/// Parse response for MsgDeposit transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// commit_resp: response for MsgDeposit transaction
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_parse_msg_deposit_resp(
+    command_handle: CommandHandle,
+    commit_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, msg_resp: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_parse_msg_deposit_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    check_useful_c_str!(commit_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_gov_parse_msg_deposit_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_parse_msg_deposit_resp(&commit_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg_resp) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_gov_parse_msg_deposit_resp: msg_resp: {:?}", msg_resp);
+        let msg_resp = ctypes::string_to_cstring(msg_resp);
+        cb(command_handle, err, msg_resp.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseMsgDepositResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_parse_msg_deposit_resp < {:?}", res);
+    res
+}
+
+/// Build MsgVote to vote on a proposal.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// proposal_id: identifier of the proposal
+/// voter: address of the voter
+/// option: vote option: "yes", "abstain", "no" or "no_with_veto"
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_build_msg_vote(
+    command_handle: CommandHandle,
+    proposal_id: u64,
+    voter: *const c_char,
+    option: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            msg_raw: *const u8,
+            msg_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_build_msg_vote > proposal_id {:?} voter {:?} option {:?}",
+        proposal_id, voter, option
+    );
+
+    check_useful_c_str!(voter, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(option, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_cheqd_ledger_gov_build_msg_vote > proposal_id {:?} voter {:?} option {:?}",
+        proposal_id, voter, option
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_build_msg_vote(proposal_id, &voter, &option);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_gov_build_msg_vote: msg: {:?}", msg);
+        let (msg_raw, msg_len) = ctypes::vec_to_pointer(&msg);
+        cb(command_handle, err, msg_raw, msg_len)
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildMsgVote,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_build_msg_vote < {:?}", res);
+    res
+}
+
+/// Parse response for MsgVote transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// commit_resp: response for MsgVote transaction
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_parse_msg_vote_resp(
+    command_handle: CommandHandle,
+    commit_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, msg_resp: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_parse_msg_vote_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    check_useful_c_str!(commit_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_gov_parse_msg_vote_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_parse_msg_vote_resp(&commit_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg_resp) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_gov_parse_msg_vote_resp: msg_resp: {:?}", msg_resp);
+        let msg_resp = ctypes::string_to_cstring(msg_resp);
+        cb(command_handle, err, msg_resp.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseMsgVoteResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_parse_msg_vote_resp < {:?}", res);
+    res
+}
+
+/// Build MsgVoteWeighted to split a vote on a proposal between several options.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// proposal_id: identifier of the proposal
+/// voter: address of the voter
+/// options: list of weighted options json [{"option": "yes", "weight": "0.7"}]. Weights must sum up to 1
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_build_msg_vote_weighted(
+    command_handle: CommandHandle,
+    proposal_id: u64,
+    voter: *const c_char,
+    options: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            msg_raw: *const u8,
+            msg_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_build_msg_vote_weighted > proposal_id {:?} voter {:?} options {:?}",
+        proposal_id, voter, options
+    );
+
+    check_useful_c_str!(voter, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(options, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_cheqd_ledger_gov_build_msg_vote_weighted > proposal_id {:?} voter {:?} options {:?}",
+        proposal_id, voter, options
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_build_msg_vote_weighted(proposal_id, &voter, &options);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_gov_build_msg_vote_weighted: msg: {:?}", msg);
+        let (msg_raw, msg_len) = ctypes::vec_to_pointer(&msg);
+        cb(command_handle, err, msg_raw, msg_len)
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildMsgVoteWeighted,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_build_msg_vote_weighted < {:?}", res);
+    res
+}
+
+/// Parse response for MsgVoteWeighted transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// commit_resp: response for MsgVoteWeighted transaction
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_parse_msg_vote_weighted_resp(
+    command_handle: CommandHandle,
+    commit_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, msg_resp: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_parse_msg_vote_weighted_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    check_useful_c_str!(commit_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_gov_parse_msg_vote_weighted_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_parse_msg_vote_weighted_resp(&commit_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg_resp) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_gov_parse_msg_vote_weighted_resp: msg_resp: {:?}", msg_resp);
+        let msg_resp = ctypes::string_to_cstring(msg_resp);
+        cb(command_handle, err, msg_resp.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseMsgVoteWeightedResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_parse_msg_vote_weighted_resp < {:?}", res);
+    res
+}
+
+/// Build query for getting a proposal. The response is checked by a state proof.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// proposal_id: identifier of the proposal
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_build_query_proposal(
+    command_handle: CommandHandle,
+    proposal_id: u64,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, query: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_build_query_proposal > proposal_id {:?}",
+        proposal_id
+    );
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_gov_build_query_proposal > proposal_id {:?}",
+        proposal_id
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_build_query_proposal(proposal_id);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, query) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_gov_build_query_proposal: query: {:?}", query);
+        let query = ctypes::string_to_cstring(query);
+        cb(command_handle, err, query.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildQueryProposal,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_build_query_proposal < {:?}", res);
+    res
+}
+
+/// Parse response for QueryProposal and check its state proof.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// query_resp: response for QueryProposal query
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_parse_query_proposal_resp(
+    command_handle: CommandHandle,
+    query_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, proposal: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_parse_query_proposal_resp > query_resp {:?}",
+        query_resp
+    );
+
+    check_useful_c_str!(query_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_gov_parse_query_proposal_resp > query_resp {:?}",
+        query_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_parse_query_proposal_resp(&query_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, proposal) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_gov_parse_query_proposal_resp: proposal: {:?}", proposal);
+        let proposal = ctypes::string_to_cstring(proposal);
+        cb(command_handle, err, proposal.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseQueryProposalResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_parse_query_proposal_resp < {:?}", res);
+    res
+}
+
+/// Build query for getting a list of proposals.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// status: (Optional) status of proposals to filter by: "deposit_period", "voting_period", "passed", "rejected" or "failed"
+/// pagination: (Optional) pagination json {"key": [u8], "offset": u64, "limit": u64, "count_total": bool}
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_build_query_proposals(
+    command_handle: CommandHandle,
+    status: *const c_char,
+    pagination: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, query: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_build_query_proposals > status {:?} pagination {:?}",
+        status, pagination
+    );
+
+    check_useful_opt_c_str!(status, ErrorCode::CommonInvalidParam2);
+    check_useful_opt_c_str!(pagination, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_cheqd_ledger_gov_build_query_proposals > status {:?} pagination {:?}",
+        status, pagination
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_build_query_proposals(status.as_deref(), pagination.as_deref());
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, query) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_gov_build_query_proposals: query: {:?}", query);
+        let query = ctypes::string_to_cstring(query);
+        cb(command_handle, err, query.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildQueryProposals,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_build_query_proposals < {:?}", res);
+    res
+}
+
+/// Parse response for QueryProposals.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// query_resp: response for QueryProposals query
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_parse_query_proposals_resp(
+    command_handle: CommandHandle,
+    query_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, proposals: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_parse_query_proposals_resp > query_resp {:?}",
+        query_resp
+    );
+
+    check_useful_c_str!(query_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_gov_parse_query_proposals_resp > query_resp {:?}",
+        query_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_parse_query_proposals_resp(&query_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, proposals) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_gov_parse_query_proposals_resp: proposals: {:?}", proposals);
+        let proposals = ctypes::string_to_cstring(proposals);
+        cb(command_handle, err, proposals.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseQueryProposalsResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_parse_query_proposals_resp < {:?}", res);
+    res
+}
+
+/// Build query for getting the current tally of a proposal.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// proposal_id: identifier of the proposal
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_build_query_tally_result(
+    command_handle: CommandHandle,
+    proposal_id: u64,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, query: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_build_query_tally_result > proposal_id {:?}",
+        proposal_id
+    );
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_gov_build_query_tally_result > proposal_id {:?}",
+        proposal_id
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_build_query_tally_result(proposal_id);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, query) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_gov_build_query_tally_result: query: {:?}", query);
+        let query = ctypes::string_to_cstring(query);
+        cb(command_handle, err, query.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildQueryTallyResult,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_build_query_tally_result < {:?}", res);
+    res
+}
+
+/// Parse response for QueryTallyResult.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// query_resp: response for QueryTallyResult query
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_parse_query_tally_result_resp(
+    command_handle: CommandHandle,
+    query_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, tally: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_parse_query_tally_result_resp > query_resp {:?}",
+        query_resp
+    );
+
+    check_useful_c_str!(query_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_gov_parse_query_tally_result_resp > query_resp {:?}",
+        query_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_parse_query_tally_result_resp(&query_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, tally) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_gov_parse_query_tally_result_resp: tally: {:?}", tally);
+        let tally = ctypes::string_to_cstring(tally);
+        cb(command_handle, err, tally.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseQueryTallyResultResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_parse_query_tally_result_resp < {:?}", res);
+    res
+}
+
+/// Build query for getting a vote of a voter on a proposal. The response is checked by a state proof.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// proposal_id: identifier of the proposal
+/// voter: address of the voter
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_build_query_vote(
+    command_handle: CommandHandle,
+    proposal_id: u64,
+    voter: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, query: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_build_query_vote > proposal_id {:?} voter {:?}",
+        proposal_id, voter
+    );
+
+    check_useful_c_str!(voter, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_cheqd_ledger_gov_build_query_vote > proposal_id {:?} voter {:?}",
+        proposal_id, voter
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_build_query_vote(proposal_id, &voter);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, query) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_gov_build_query_vote: query: {:?}", query);
+        let query = ctypes::string_to_cstring(query);
+        cb(command_handle, err, query.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildQueryVote,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_build_query_vote < {:?}", res);
+    res
+}
+
+/// Parse response for QueryVote and check its state proof.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// query_resp: response for QueryVote query
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_gov_parse_query_vote_resp(
+    command_handle: CommandHandle,
+    query_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, vote: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_gov_parse_query_vote_resp > query_resp {:?}",
+        query_resp
+    );
+
+    check_useful_c_str!(query_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_gov_parse_query_vote_resp > query_resp {:?}",
+        query_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .gov_parse_query_vote_resp(&query_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, vote) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_gov_parse_query_vote_resp: vote: {:?}", vote);
+        let vote = ctypes::string_to_cstring(vote);
+        cb(command_handle, err, vote.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseQueryVoteResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_gov_parse_query_vote_resp < {:?}", res);
+    res
+}
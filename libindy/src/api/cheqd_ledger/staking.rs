@@ -0,0 +1,744 @@
+use indy_api_types::{CommandHandle, ErrorCode, errors::prelude::*};
+use indy_utils::ctypes;
+use libc::c_char;
+
+use crate::Locator;
+use crate::services::CommandMetric;
+
+/// Build MsgDelegate to delegate coins from a delegator to a validator.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// delegator_address: address of the delegator
+/// validator_address: address of the validator
+/// amount: amount of coins to delegate
+/// denom: denomination of coins
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_staking_build_msg_delegate(
+    command_handle: CommandHandle,
+    delegator_address: *const c_char,
+    validator_address: *const c_char,
+    amount: *const c_char,
+    denom: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            msg_raw: *const u8,
+            msg_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_staking_build_msg_delegate > delegator_address {:?} validator_address {:?} amount {:?} denom {:?}",
+        delegator_address, validator_address, amount, denom
+    );
+
+    check_useful_c_str!(delegator_address, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(validator_address, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(amount, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(denom, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    debug!(
+        "indy_cheqd_ledger_staking_build_msg_delegate > delegator_address {:?} validator_address {:?} amount {:?} denom {:?}",
+        delegator_address, validator_address, amount, denom
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .staking_build_msg_delegate(&delegator_address, &validator_address, &amount, &denom);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_staking_build_msg_delegate: msg: {:?}", msg);
+        let (msg_raw, msg_len) = ctypes::vec_to_pointer(&msg);
+        cb(command_handle, err, msg_raw, msg_len)
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildMsgDelegate,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_staking_build_msg_delegate < {:?}", res);
+    res
+}
+
+/// Parse response for MsgDelegate transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// commit_resp: response for MsgDelegate transaction
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_staking_parse_msg_delegate_resp(
+    command_handle: CommandHandle,
+    commit_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, msg_resp: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_staking_parse_msg_delegate_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    check_useful_c_str!(commit_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_staking_parse_msg_delegate_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .staking_parse_msg_delegate_resp(&commit_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg_resp) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_staking_parse_msg_delegate_resp: msg_resp: {:?}", msg_resp);
+        let msg_resp = ctypes::string_to_cstring(msg_resp);
+        cb(command_handle, err, msg_resp.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseMsgDelegateResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_staking_parse_msg_delegate_resp < {:?}", res);
+    res
+}
+
+/// Build MsgUndelegate to undelegate coins from a validator.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// delegator_address: address of the delegator
+/// validator_address: address of the validator
+/// amount: amount of coins to undelegate
+/// denom: denomination of coins
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_staking_build_msg_undelegate(
+    command_handle: CommandHandle,
+    delegator_address: *const c_char,
+    validator_address: *const c_char,
+    amount: *const c_char,
+    denom: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            msg_raw: *const u8,
+            msg_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_staking_build_msg_undelegate > delegator_address {:?} validator_address {:?} amount {:?} denom {:?}",
+        delegator_address, validator_address, amount, denom
+    );
+
+    check_useful_c_str!(delegator_address, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(validator_address, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(amount, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(denom, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    debug!(
+        "indy_cheqd_ledger_staking_build_msg_undelegate > delegator_address {:?} validator_address {:?} amount {:?} denom {:?}",
+        delegator_address, validator_address, amount, denom
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .staking_build_msg_undelegate(&delegator_address, &validator_address, &amount, &denom);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_staking_build_msg_undelegate: msg: {:?}", msg);
+        let (msg_raw, msg_len) = ctypes::vec_to_pointer(&msg);
+        cb(command_handle, err, msg_raw, msg_len)
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildMsgUndelegate,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_staking_build_msg_undelegate < {:?}", res);
+    res
+}
+
+/// Parse response for MsgUndelegate transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// commit_resp: response for MsgUndelegate transaction
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_staking_parse_msg_undelegate_resp(
+    command_handle: CommandHandle,
+    commit_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, msg_resp: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_staking_parse_msg_undelegate_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    check_useful_c_str!(commit_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_staking_parse_msg_undelegate_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .staking_parse_msg_undelegate_resp(&commit_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg_resp) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_staking_parse_msg_undelegate_resp: msg_resp: {:?}", msg_resp);
+        let msg_resp = ctypes::string_to_cstring(msg_resp);
+        cb(command_handle, err, msg_resp.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseMsgUndelegateResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_staking_parse_msg_undelegate_resp < {:?}", res);
+    res
+}
+
+/// Build MsgBeginRedelegate to move delegated coins from one validator to another.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// delegator_address: address of the delegator
+/// validator_src_address: address of the validator to redelegate from
+/// validator_dst_address: address of the validator to redelegate to
+/// amount: amount of coins to redelegate
+/// denom: denomination of coins
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_staking_build_msg_begin_redelegate(
+    command_handle: CommandHandle,
+    delegator_address: *const c_char,
+    validator_src_address: *const c_char,
+    validator_dst_address: *const c_char,
+    amount: *const c_char,
+    denom: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            msg_raw: *const u8,
+            msg_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_staking_build_msg_begin_redelegate > delegator_address {:?} validator_src_address {:?} validator_dst_address {:?} amount {:?} denom {:?}",
+        delegator_address, validator_src_address, validator_dst_address, amount, denom
+    );
+
+    check_useful_c_str!(delegator_address, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(validator_src_address, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(validator_dst_address, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(amount, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(denom, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    debug!(
+        "indy_cheqd_ledger_staking_build_msg_begin_redelegate > delegator_address {:?} validator_src_address {:?} validator_dst_address {:?} amount {:?} denom {:?}",
+        delegator_address, validator_src_address, validator_dst_address, amount, denom
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .staking_build_msg_begin_redelegate(&delegator_address, &validator_src_address, &validator_dst_address, &amount, &denom);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_staking_build_msg_begin_redelegate: msg: {:?}", msg);
+        let (msg_raw, msg_len) = ctypes::vec_to_pointer(&msg);
+        cb(command_handle, err, msg_raw, msg_len)
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildMsgBeginRedelegate,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_staking_build_msg_begin_redelegate < {:?}", res);
+    res
+}
+
+/// Parse response for MsgBeginRedelegate transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// commit_resp: response for MsgBeginRedelegate transaction
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_staking_parse_msg_begin_redelegate_resp(
+    command_handle: CommandHandle,
+    commit_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, msg_resp: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_staking_parse_msg_begin_redelegate_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    check_useful_c_str!(commit_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_staking_parse_msg_begin_redelegate_resp > commit_resp {:?}",
+        commit_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .staking_parse_msg_begin_redelegate_resp(&commit_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, msg_resp) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_staking_parse_msg_begin_redelegate_resp: msg_resp: {:?}", msg_resp);
+        let msg_resp = ctypes::string_to_cstring(msg_resp);
+        cb(command_handle, err, msg_resp.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseMsgBeginRedelegateResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_staking_parse_msg_begin_redelegate_resp < {:?}", res);
+    res
+}
+
+/// Build query for getting delegation of a delegator to a validator.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// delegator_address: address of the delegator
+/// validator_address: address of the validator
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_staking_build_query_delegation(
+    command_handle: CommandHandle,
+    delegator_address: *const c_char,
+    validator_address: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, query: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_staking_build_query_delegation > delegator_address {:?} validator_address {:?}",
+        delegator_address, validator_address
+    );
+
+    check_useful_c_str!(delegator_address, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(validator_address, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_cheqd_ledger_staking_build_query_delegation > delegator_address {:?} validator_address {:?}",
+        delegator_address, validator_address
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .staking_build_query_delegation(&delegator_address, &validator_address);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, query) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_staking_build_query_delegation: query: {:?}", query);
+        let query = ctypes::string_to_cstring(query);
+        cb(command_handle, err, query.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildQueryDelegation,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_staking_build_query_delegation < {:?}", res);
+    res
+}
+
+/// Parse response for QueryDelegation.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// query_resp: response for QueryDelegation query
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_staking_parse_query_delegation_resp(
+    command_handle: CommandHandle,
+    query_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, delegation: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_staking_parse_query_delegation_resp > query_resp {:?}",
+        query_resp
+    );
+
+    check_useful_c_str!(query_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_staking_parse_query_delegation_resp > query_resp {:?}",
+        query_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .staking_parse_query_delegation_resp(&query_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, delegation) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_staking_parse_query_delegation_resp: delegation: {:?}", delegation);
+        let delegation = ctypes::string_to_cstring(delegation);
+        cb(command_handle, err, delegation.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseQueryDelegationResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_staking_parse_query_delegation_resp < {:?}", res);
+    res
+}
+
+/// Build query for getting all delegations of a delegator.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// delegator_address: address of the delegator
+/// pagination: (Optional) pagination json {"key": [u8], "offset": u64, "limit": u64, "count_total": bool}
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_staking_build_query_delegator_delegations(
+    command_handle: CommandHandle,
+    delegator_address: *const c_char,
+    pagination: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, query: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_staking_build_query_delegator_delegations > delegator_address {:?} pagination {:?}",
+        delegator_address, pagination
+    );
+
+    check_useful_c_str!(delegator_address, ErrorCode::CommonInvalidParam2);
+    check_useful_opt_c_str!(pagination, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_cheqd_ledger_staking_build_query_delegator_delegations > delegator_address {:?} pagination {:?}",
+        delegator_address, pagination
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .staking_build_query_delegator_delegations(&delegator_address, pagination.as_deref());
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, query) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_staking_build_query_delegator_delegations: query: {:?}", query);
+        let query = ctypes::string_to_cstring(query);
+        cb(command_handle, err, query.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildQueryDelegatorDelegations,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_staking_build_query_delegator_delegations < {:?}", res);
+    res
+}
+
+/// Parse response for QueryDelegatorDelegations.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// query_resp: response for QueryDelegatorDelegations query
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_staking_parse_query_delegator_delegations_resp(
+    command_handle: CommandHandle,
+    query_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, delegations: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_staking_parse_query_delegator_delegations_resp > query_resp {:?}",
+        query_resp
+    );
+
+    check_useful_c_str!(query_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_staking_parse_query_delegator_delegations_resp > query_resp {:?}",
+        query_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .staking_parse_query_delegator_delegations_resp(&query_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, delegations) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_staking_parse_query_delegator_delegations_resp: delegations: {:?}", delegations);
+        let delegations = ctypes::string_to_cstring(delegations);
+        cb(command_handle, err, delegations.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseQueryDelegatorDelegationsResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_staking_parse_query_delegator_delegations_resp < {:?}", res);
+    res
+}
+
+/// Build query for getting validators.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// status: (Optional) validator status to filter by, e.g. "BOND_STATUS_BONDED"
+/// pagination: (Optional) pagination json {"key": [u8], "offset": u64, "limit": u64, "count_total": bool}
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_staking_build_query_validators(
+    command_handle: CommandHandle,
+    status: *const c_char,
+    pagination: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, query: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_staking_build_query_validators > status {:?} pagination {:?}",
+        status, pagination
+    );
+
+    check_useful_opt_c_str!(status, ErrorCode::CommonInvalidParam2);
+    check_useful_opt_c_str!(pagination, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_cheqd_ledger_staking_build_query_validators > status {:?} pagination {:?}",
+        status, pagination
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .staking_build_query_validators(status.as_deref(), pagination.as_deref());
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, query) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_staking_build_query_validators: query: {:?}", query);
+        let query = ctypes::string_to_cstring(query);
+        cb(command_handle, err, query.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildQueryValidators,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_staking_build_query_validators < {:?}", res);
+    res
+}
+
+/// Parse response for QueryValidators.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// query_resp: response for QueryValidators query
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_staking_parse_query_validators_resp(
+    command_handle: CommandHandle,
+    query_resp: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, validators: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_staking_parse_query_validators_resp > query_resp {:?}",
+        query_resp
+    );
+
+    check_useful_c_str!(query_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_staking_parse_query_validators_resp > query_resp {:?}",
+        query_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .staking_parse_query_validators_resp(&query_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, validators) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_staking_parse_query_validators_resp: validators: {:?}", validators);
+        let validators = ctypes::string_to_cstring(validators);
+        cb(command_handle, err, validators.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseQueryValidatorsResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_staking_parse_query_validators_resp < {:?}", res);
+    res
+}
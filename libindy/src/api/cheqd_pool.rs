@@ -385,4 +385,327 @@ pub extern "C" fn indy_cheqd_pool_abci_info(
     let res = ErrorCode::Success;
     debug!("indy_cheqd_pool_abci_info < {:?}", res);
     res
-}
\ No newline at end of file
+}
+
+/// Request node status: node info, latest block and sync state
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_alias: name of a pool
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+///   Response with status of the node
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_cheqd_pool_status(
+    command_handle: CommandHandle,
+    pool_alias: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, resp: *const c_char)>,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_pool_status > pool_alias {:?}",
+        pool_alias
+    );
+
+    check_useful_c_str!(pool_alias, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_pool_status > pool_alias {:?}",
+        pool_alias
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_pool_controller
+            .status(&pool_alias)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_pool_status ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator
+        .executor
+        .spawn_ok_instrumented(CommandMetric::CheqdPoolCommandStatus, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_pool_status < {:?}", res);
+    res
+}
+
+/// Request block at the given height
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_alias: name of a pool
+/// height: (optional, -1 for the latest block) height of the block
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+///   Response with the block
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_cheqd_pool_block(
+    command_handle: CommandHandle,
+    pool_alias: *const c_char,
+    height: i64,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, resp: *const c_char)>,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_pool_block > pool_alias {:?}, height {:?}",
+        pool_alias, height
+    );
+
+    check_useful_c_str!(pool_alias, ErrorCode::CommonInvalidParam2);
+    if height < -1 { return ErrorCode::CommonInvalidParam3; }
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_cheqd_pool_block > pool_alias {:?}, height {:?}",
+        pool_alias, height
+    );
+
+    let height = if height != -1 { Some(height as u64) } else { None };
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_pool_controller
+            .block(&pool_alias, height)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_pool_block ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator
+        .executor
+        .spawn_ok_instrumented(CommandMetric::CheqdPoolCommandBlock, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_pool_block < {:?}", res);
+    res
+}
+
+/// Request results of transactions executed in the block at the given height
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_alias: name of a pool
+/// height: (optional, -1 for the latest block) height of the block
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+///   Response with results of the block
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_cheqd_pool_block_results(
+    command_handle: CommandHandle,
+    pool_alias: *const c_char,
+    height: i64,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, resp: *const c_char)>,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_pool_block_results > pool_alias {:?}, height {:?}",
+        pool_alias, height
+    );
+
+    check_useful_c_str!(pool_alias, ErrorCode::CommonInvalidParam2);
+    if height < -1 { return ErrorCode::CommonInvalidParam3; }
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_cheqd_pool_block_results > pool_alias {:?}, height {:?}",
+        pool_alias, height
+    );
+
+    let height = if height != -1 { Some(height as u64) } else { None };
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_pool_controller
+            .block_results(&pool_alias, height)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_pool_block_results ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator
+        .executor
+        .spawn_ok_instrumented(CommandMetric::CheqdPoolCommandBlockResults, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_pool_block_results < {:?}", res);
+    res
+}
+
+/// Request validator set at the given height
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_alias: name of a pool
+/// height: (optional, -1 for the latest block) height of the block
+/// page: (optional, -1 for the first page) number of the page
+/// per_page: (optional, -1 for the node default) number of validators per page
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+///   Response with the validator set
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_cheqd_pool_validators(
+    command_handle: CommandHandle,
+    pool_alias: *const c_char,
+    height: i64,
+    page: i64,
+    per_page: i64,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, resp: *const c_char)>,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_pool_validators > pool_alias {:?}, height {:?}, page {:?}, per_page {:?}",
+        pool_alias, height, page, per_page
+    );
+
+    check_useful_c_str!(pool_alias, ErrorCode::CommonInvalidParam2);
+    if height < -1 { return ErrorCode::CommonInvalidParam3; }
+    if page < -1 { return ErrorCode::CommonInvalidParam4; }
+    if per_page < -1 { return ErrorCode::CommonInvalidParam5; }
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    debug!(
+        "indy_cheqd_pool_validators > pool_alias {:?}, height {:?}, page {:?}, per_page {:?}",
+        pool_alias, height, page, per_page
+    );
+
+    let height = if height != -1 { Some(height as u64) } else { None };
+    let page = if page != -1 { Some(page as u64) } else { None };
+    let per_page = if per_page != -1 { Some(per_page as u64) } else { None };
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_pool_controller
+            .validators(&pool_alias, height, page, per_page)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_pool_validators ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator
+        .executor
+        .spawn_ok_instrumented(CommandMetric::CheqdPoolCommandValidators, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_pool_validators < {:?}", res);
+    res
+}
+
+/// Request network information of the node: listeners and connected peers
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_alias: name of a pool
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+///   Response with network information
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_cheqd_pool_net_info(
+    command_handle: CommandHandle,
+    pool_alias: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, resp: *const c_char)>,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_pool_net_info > pool_alias {:?}",
+        pool_alias
+    );
+
+    check_useful_c_str!(pool_alias, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_pool_net_info > pool_alias {:?}",
+        pool_alias
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_pool_controller
+            .net_info(&pool_alias)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_pool_net_info ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator
+        .executor
+        .spawn_ok_instrumented(CommandMetric::CheqdPoolCommandNetInfo, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_pool_net_info < {:?}", res);
+    res
+}
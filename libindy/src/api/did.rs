@@ -637,6 +637,91 @@ pub extern "C" fn indy_get_endpoint_for_did(
     res
 }
 
+/// Resolves W3C DID document for the given Indy DID.
+///
+/// DID document is built from the ledger data:
+///     - verkey of the NYM becomes Ed25519VerificationKey2018 verification method used for authentication.
+///     - if the NYM has `diddocContent` it is merged into the DID document.
+///     - otherwise services are built from `endpoint` ATTRIB:
+///         {"endpoint": {"endpoint": string, "types": ["endpoint", "did-communication", "DIDComm"], "routingKeys": [string]}}
+///         or legacy {"endpoint": {"ha": string, "verkey": string}} (becomes did-communication service).
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// pool_handle: Pool handle (created by open_pool).
+///     Pass 0 to use the pool opened for the namespace of did:indy DID (see indy_get_pool_handle_for_namespace).
+/// did - The DID to resolve: unqualified, did:sov or did:indy.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - resolution_result - DID resolution result json:
+/// {
+///     "@context": "https://w3id.org/did-resolution/v1",
+///     "didDocument": DID document json,
+///     "didResolutionMetadata": {"contentType": "application/did+ld+json"},
+///     "didDocumentMetadata": {
+///         "nym": {
+///             "seqNo": int, "txnTime": int, ... - metadata of GET_NYM reply (see indy_get_response_metadata),
+///             "stateProof": (optional) {"rootHash": string, "multiSignature": json} - state proof the reply was verified against
+///         },
+///         "endpoint": (optional) the same metadata of GET_ATTRIB reply for endpoint
+///     }
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_resolve_did(
+    command_handle: CommandHandle,
+    pool_handle: PoolHandle,
+    did: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, resolution_result: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_resolve_did > pool_handle {:?} did {:?}",
+        pool_handle, did
+    );
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_resolve_did ? pool_handle {:?} did {:?}",
+        pool_handle, did
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .did_controller
+            .resolve_did(pool_handle, did)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_resolve_did ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::DidCommandResolveDid, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_resolve_did < {:?}", res);
+    res
+}
+
 /// Saves/replaces the meta information for the giving DID in the wallet.
 ///
 /// #Params
@@ -981,6 +1066,7 @@ pub extern "C" fn indy_abbreviate_verkey(
 /// wallet_handle: Wallet handle (created by open_wallet).
 /// did: target DID stored in the wallet.
 /// method: method to apply to the DID.
+///     Use `indy:<namespace>` (e.g. `indy:sovrin:staging`) to get did:indy identifier of the Indy network.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
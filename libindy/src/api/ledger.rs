@@ -16,10 +16,14 @@ use crate::{
         },
         crypto::did::DidValue,
         ledger::{
-            auth_rule::{AuthRules, Constraint},
+            auth_rule::{AuthRuleAction, AuthRules, Constraint},
             author_agreement::{AcceptanceMechanisms, GetTxnAuthorAgreementData},
+            endorsement::{EndorsementEnvelopeOptions, PendingEndorsement},
             node::NodeOperationData,
+            nym::NymData,
             pool::Schedule,
+            request::SubmitRequestsOptions,
+            txn::DownloadLedgerConfig,
         },
     },
     Locator,
@@ -32,6 +36,9 @@ use crate::services::CommandMetric;
 /// sign key (see wallet_sign), and sends signed request message
 /// to validator pool (see write_request).
 ///
+/// If transaction author agreement acceptance is set for the pool
+/// (see indy_set_pool_txn_author_agreement_acceptance), it is appended to domain ledger write requests.
+///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
 /// pool_handle: pool handle (created by open_pool_ledger).
@@ -171,6 +178,87 @@ pub extern "C" fn indy_submit_request(
     res
 }
 
+/// Publishes a batch of request messages to validator pool (no signing, like submit_request).
+///
+/// Requests are pipelined: up to `max_concurrency` requests are sent to the pool at the same time.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// requests_json: array of prepared request jsons.
+/// options_json: (Optional) submit options json:
+/// {
+///     "max_concurrency": int (optional, 10 by default) - the number of requests sent at the same time,
+///     "stop_on_failure": bool (optional, false by default) - don't send the rest of requests after an error or
+///         rejected request. Requests sent before the failure are still awaited.
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Array of results in the order of requests:
+/// [
+///     {"status": "reply", "reply": <reply json>} - the request is replied by the pool,
+///     {"status": "rejected", "reply": <reply json>} - the request is rejected by the pool (REJECT or REQNACK),
+///     {"status": "error", "error_code": <int>, "message": <string>} - the request failed with libindy error,
+///     {"status": "skipped"} - the request wasn't sent as a previous one failed
+/// ]
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_submit_requests(
+    command_handle: CommandHandle,
+    pool_handle: PoolHandle,
+    requests_json: *const c_char,
+    options_json: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            results_json: *const c_char,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_submit_requests > pool_handle {:?} requests_json {:?} options_json {:?}",
+        pool_handle, requests_json, options_json
+    );
+
+    check_useful_json!(requests_json, ErrorCode::CommonInvalidParam3, Vec<serde_json::Value>);
+    check_useful_opt_validatable_json!(options_json, ErrorCode::CommonInvalidParam4, SubmitRequestsOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_submit_requests ? pool_handle {:?} requests_json {:?} options_json {:?}",
+        pool_handle, requests_json, options_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .submit_requests(pool_handle, requests_json, options_json)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_submit_requests ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandSubmitRequests, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_submit_requests < {:?}", res);
+    res
+}
+
 /// Send action to particular nodes of validator pool.
 ///
 /// The list of requests can be send:
@@ -253,6 +341,94 @@ pub extern "C" fn indy_submit_action(
     res
 }
 
+/// Downloads transactions of the ledger into the local copy kept for the pool.
+///
+/// Transactions are requested in batches by GET_TXN requests starting right after the last
+/// locally stored one, so repeated calls resume the download incrementally.
+/// Every transaction is checked against the audit path and the multi-signed ledger root hash
+/// returned by the pool, and the local copy extended with each batch is checked against
+/// the ledger merkle root before the batch is stored.
+///
+/// Transactions are stored one json per line in `<pool directory>/ledgers/<ledger id>.txn`:
+/// {"txn": {..}, "txnMetadata": {..}, "ver": "..", "reqSignature": {..}}
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// ledger_type: type of the ledger to download:
+///     DOMAIN - used default,
+///     POOL,
+///     CONFIG
+///     any number
+/// config: (Optional) download config json:
+/// {
+///     "batch_size": <int> (Optional) number of transactions requested at once (100 by default),
+///     "to": <int> (Optional) seq_no of the last transaction to download (the whole ledger by default)
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Download status json:
+/// {
+///     "ledgerId": <int> id of the ledger,
+///     "ledgerSize": <int> size of the ledger reported by the pool,
+///     "txnsCount": <int> number of transactions in the local copy,
+///     "rootHash": <string> base58 merkle root of the local copy,
+///     "path": <string> path to the local copy
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_download_ledger(
+    command_handle: CommandHandle,
+    pool_handle: PoolHandle,
+    ledger_type: *const c_char,
+    config: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, status_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_download_ledger > pool_handle {:?} ledger_type {:?} config {:?}",
+        pool_handle, ledger_type, config
+    );
+
+    check_useful_c_str!(ledger_type, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_validatable_json!(config, ErrorCode::CommonInvalidParam4, DownloadLedgerConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_download_ledger? pool_handle {:?} ledger_type {:?} config {:?}",
+        pool_handle, ledger_type, config
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .download_ledger(pool_handle, ledger_type, config)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_download_ledger ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandDownloadLedger, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_download_ledger < {:?}", res);
+    res
+}
+
 /// Signs request message.
 ///
 /// Adds submitter information to passed request json, signs it with submitter
@@ -402,6 +578,9 @@ pub extern "C" fn indy_multi_sign_request(
 
 /// Builds a request to get a DDO.
 ///
+/// Indy ledger has no separate DDO transaction: DID document is built from NYM,
+/// so this call builds GET_NYM request. Use indy_resolve_did to get resolved DID document.
+///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
 /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
@@ -1404,6 +1583,104 @@ pub extern "C" fn indy_build_get_validator_info_request(
     res
 }
 
+/// Parses replies of the nodes to GET_VALIDATOR_INFO request into typed validator info
+/// and summarizes it for the whole pool.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_validator_info_response: response of GET_VALIDATOR_INFO request: json object mapping node aliases
+///     to their replies (as returned by indy_sign_and_submit_request and indy_submit_action) or a single node reply.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Validator info json:
+/// {
+///     "nodes": [
+///         {
+///             "node": string - node alias,
+///             "status": string - "ok", "timeout" or "error",
+///             "error": string (optional) - why the reply can't be parsed,
+///             "mode": string (optional) - node mode (e.g. "participating"),
+///             "indy_node_version": string (optional),
+///             "sovrin_version": string (optional),
+///             "uptime": int (optional) - node uptime in seconds,
+///             "timestamp": int (optional) - time the info was collected,
+///             "view_no": int (optional),
+///             "view_change_in_progress": bool (optional),
+///             "catchup_status": {"<ledger>": string} - catch-up status per ledger (e.g. "synced"),
+///             "ledger_sizes": {"<ledger>": int} - number of transactions per ledger,
+///             "freshness": {"<ledger>": {"last_updated_time": string (optional), "has_write_consensus": bool (optional)}},
+///             "unreachable_nodes": [string] - nodes the node can't reach
+///         }
+///     ],
+///     "summary": {
+///         "nodes_count": int,
+///         "replied_nodes": [string],
+///         "timeout_nodes": [string],
+///         "failed_nodes": [string],
+///         "indy_node_versions": {"<version>": [string]} - nodes grouped by indy-node version,
+///         "view_numbers": {"<view_no>": [string]} - nodes grouped by view number,
+///         "ledger_sizes": {"<ledger>": {"<size>": [string]}} - nodes grouped by ledger size,
+///         "unreachable_nodes": {"<node>": [string]} - nodes reported unreachable and the nodes reported them,
+///         "inconsistencies": [string] - human readable descriptions of the problems found
+///     }
+/// }
+/// Ledgers are named "pool", "domain", "config" and "audit".
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_parse_get_validator_info_response(
+    command_handle: CommandHandle,
+    get_validator_info_response: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            validator_info_json: *const c_char,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_parse_get_validator_info_response > get_validator_info_response {:?}",
+        get_validator_info_response
+    );
+
+    check_useful_c_str!(get_validator_info_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_parse_get_validator_info_response ? get_validator_info_response {:?}",
+        get_validator_info_response
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .parse_get_validator_info_response(get_validator_info_response);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!(
+            "indy_parse_get_validator_info_response ? err {:?} res {:?}",
+            err, res
+        );
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandParseGetValidatorInfoResponse, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_parse_get_validator_info_response < {:?}", res);
+    res
+}
+
 /// Builds a GET_TXN request. Request to get any transaction by its seq_no.
 ///
 /// #Params
@@ -2457,9 +2734,11 @@ pub type CustomTransactionParser =
 /// Callback type to deallocate result buffer `parsed_sp` from `CustomTransactionParser`
 pub type CustomFree = extern "C" fn(data: *const c_char) -> ErrorCode;
 
-// FIXME:
 /// Register callbacks (see type description for `CustomTransactionParser` and `CustomFree`
 ///
+/// Replies to requests of the registered type will be verified with state proofs returned by `parse`
+/// instead of waiting for f+1 equal replies from nodes.
+///
 /// # params
 /// command_handle: command handle to map callback to caller context.
 /// txn_type: type of transaction to apply `parse` callback.
@@ -2472,41 +2751,51 @@ pub type CustomFree = extern "C" fn(data: *const c_char) -> ErrorCode;
 ///
 /// # errors
 /// Common*
-// #[no_mangle]
-// pub extern fn indy_register_transaction_parser_for_sp(command_handle: CommandHandle,
-//                                                       txn_type: *const c_char,
-//                                                       parser: Option<CustomTransactionParser>,
-//                                                       free: Option<CustomFree>,
-//                                                       cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
-//     debug!("indy_register_transaction_parser_for_sp > txn_type {:?} parser {:?} free {:?}",
-//            txn_type, parser, free);
-
-//     check_useful_c_str!(txn_type, ErrorCode::CommonInvalidParam2);
-//     check_useful_c_callback!(parser, ErrorCode::CommonInvalidParam3);
-//     check_useful_c_callback!(free, ErrorCode::CommonInvalidParam4);
-//     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
-
-//     debug!("indy_register_transaction_parser_for_sp: entities: txn_type {}, parser {:?} free {:?}",
-//            txn_type, parser, free);
-
-//     let res = CommandExecutor::instance()
-//         .send(Command::Ledger(LedgerCommand::RegisterSPParser(
-//             txn_type,
-//             parser,
-//             free,
-//             Box::new(move |res| {
-//                 let res = prepare_result!(res);
-//                 debug!("indy_register_transaction_parser_for_sp: res {:?}", res);
-//                 cb(command_handle, res)
-//             }),
-//         )));
-
-//     let res = prepare_result!(res);
-
-//     debug!("indy_register_transaction_parser_for_sp < {:?}", res);
-
-//     res
-// }
+#[no_mangle]
+pub extern "C" fn indy_register_transaction_parser_for_sp(
+    command_handle: CommandHandle,
+    txn_type: *const c_char,
+    parser: Option<CustomTransactionParser>,
+    free: Option<CustomFree>,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>,
+) -> ErrorCode {
+    debug!(
+        "indy_register_transaction_parser_for_sp > txn_type {:?} parser {:?} free {:?}",
+        txn_type, parser, free
+    );
+
+    check_useful_c_str!(txn_type, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(parser, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(free, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_register_transaction_parser_for_sp ? txn_type {:?} parser {:?} free {:?}",
+        txn_type, parser, free
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .register_sp_parser(txn_type, parser, free);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let err = prepare_result!(res);
+        debug!("indy_register_transaction_parser_for_sp ? err {:?}", err);
+
+        cb(command_handle, err);
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandRegisterSPParser, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_register_transaction_parser_for_sp < {:?}", res);
+    res
+}
 
 /// Parse transaction response to fetch metadata.
 /// The important use case for this method is validation of Node's response freshens.
@@ -2867,6 +3156,102 @@ pub extern "C" fn indy_build_get_auth_rule_request(
     res
 }
 
+/// Evaluates authentication rules locally to predict whether the ledger would accept a prepared write request.
+///
+/// Signatures are not verified, only their presence in the request is taken into account.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_auth_rule_response: response of GET_AUTH_RULE request (see `indy_build_get_auth_rule_request`).
+/// action_json: the action to evaluate rules for:
+///     {
+///         auth_type: ledger transaction alias or associated value,
+///         auth_action: type of an action. Can be either "ADD" or "EDIT",
+///         field: transaction field,
+///         old_value: (Optional) old value of a field (mandatory for EDIT action),
+///         new_value: (Optional) new value of a field,
+///         owner: (Optional) DID of the owner of the changed object (the request author by default),
+///     }
+/// request_json: the prepared (and signed as far as possible) request.
+/// signers_json: NYM data of the request signers and endorser as returned by `indy_parse_get_nym_response`:
+///     [{"did": string, "verkey": string, "role": string}]
+///     Signers without NYM data are treated as unknown for the ledger.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// evaluation_json: {
+///     satisfied: bool - whether the request meets the rule,
+///     forbidden: bool - whether the action is forbidden,
+///     missing_signatures: [{
+///         role: (Optional) role which signers must have ("*" means any role),
+///         sig_count: number of missing signatures,
+///         need_to_be_owner: whether a signer must be an owner of the object,
+///         off_ledger_signature: whether signatures of DIDs unknown for the ledger are accepted,
+///     }] - for OR constraints the alternative requiring the least signatures is reported,
+///     missing_endorsement: bool - whether the request names an Endorser which hasn't signed it
+///                                 or has no role allowing to endorse,
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_evaluate_auth_rule(
+    command_handle: CommandHandle,
+    get_auth_rule_response: *const c_char,
+    action_json: *const c_char,
+    request_json: *const c_char,
+    signers_json: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, evaluation_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_evaluate_auth_rule > get_auth_rule_response {:?} \
+            action_json {:?} request_json {:?} signers_json {:?}",
+        get_auth_rule_response, action_json, request_json, signers_json
+    );
+
+    check_useful_c_str!(get_auth_rule_response, ErrorCode::CommonInvalidParam2);
+    check_useful_validatable_json!(action_json, ErrorCode::CommonInvalidParam3, AuthRuleAction);
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam4);
+    check_useful_json!(signers_json, ErrorCode::CommonInvalidParam5, Vec<NymData>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    debug!(
+        "indy_evaluate_auth_rule ? get_auth_rule_response {:?} \
+            action_json {:?} request_json {:?} signers_json {:?}",
+        get_auth_rule_response, action_json, request_json, signers_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator.ledger_controller.evaluate_auth_rule(
+            get_auth_rule_response,
+            action_json,
+            request_json,
+            signers_json,
+        );
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+
+        debug!("indy_evaluate_auth_rule ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandEvaluateAuthRule, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_evaluate_auth_rule < {:?}", res);
+    res
+}
+
 /// Builds a TXN_AUTHR_AGRMT request. Request to add a new version of Transaction Author Agreement to the ledger.
 ///
 /// EXPERIMENTAL
@@ -3377,23 +3762,118 @@ pub extern "C" fn indy_append_txn_author_agreement_acceptance_to_request(
     res
 }
 
-/// Append Endorser to an existing request.
+/// Sets transaction author agreement acceptance for the opened pool.
 ///
-/// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
-/// But it is expecting that the transaction will be sent by the specified Endorser.
+/// EXPERIMENTAL
 ///
-/// Note: Both Transaction Author and Endorser must sign output request after that.
+/// indy_sign_and_submit_request appends the acceptance to domain ledger write requests
+/// sent to this pool (unless they already contain acceptance data).
+/// Before that the agreement in force is read from the ledger (and re-read every 10 minutes
+/// or when the ledger rejects the acceptance). If it differs from the accepted one,
+/// LedgerTxnAuthorAgreementChanged error is returned and the new agreement must be accepted
+/// by calling this function again. If the ledger doesn't require the agreement, nothing is appended.
 ///
-/// More about Transaction Endorser: https://github.com/hyperledger/indy-node/blob/master/design/transaction_endorser.md
-///                                  https://github.com/hyperledger/indy-sdk/blob/master/docs/configuration.md
+/// The acceptance is stored with the pool ledger configuration and set again on every opening of the pool,
+/// until another acceptance is set or the configuration is deleted.
 ///
 /// #Params
-/// request_json: original request
-/// endorser_did: DID of the Endorser that will submit the transaction.
-///               The Endorser's DID must be present on the ledger.
-/// cb: Callback that takes command result as parameter.
-///     The command result is a request JSON with Endorser field appended.
-///
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// text and version - (optional) raw data about TAA from ledger.
+///     These parameters should be passed together.
+///     These parameters are required if taa_digest parameter is omitted.
+/// taa_digest - (optional) digest on text and version.
+///     Digest is sha256 hash calculated on concatenated strings: version || text.
+///     This parameter is required if text and version parameters are omitted.
+/// mechanism - mechanism how user has accepted the TAA
+/// time - UTC timestamp when user has accepted the TAA. Note that the time portion will be discarded to avoid a privacy risk.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_set_pool_txn_author_agreement_acceptance(
+    command_handle: CommandHandle,
+    pool_handle: PoolHandle,
+    text: *const c_char,
+    version: *const c_char,
+    taa_digest: *const c_char,
+    mechanism: *const c_char,
+    time: u64,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>,
+) -> ErrorCode {
+    debug!(
+        "indy_set_pool_txn_author_agreement_acceptance > pool_handle {:?} \
+            text {:?} version {:?} taa_digest {:?} \
+            mechanism {:?} time {:?}",
+        pool_handle, text, version, taa_digest, mechanism, time
+    );
+
+    check_useful_opt_c_str!(text, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_c_str!(version, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(taa_digest, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(mechanism, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    debug!(
+        "indy_set_pool_txn_author_agreement_acceptance ? pool_handle {:?} \
+            text {:?} version {:?} taa_digest {:?} \
+            mechanism {:?} time {:?}",
+        pool_handle, text, version, taa_digest, mechanism, time
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .set_pool_txn_author_agreement_acceptance(
+                pool_handle,
+                text,
+                version,
+                taa_digest,
+                mechanism,
+                time,
+            )
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let err = prepare_result!(res);
+        debug!("indy_set_pool_txn_author_agreement_acceptance ? err {:?}", err);
+
+        cb(command_handle, err)
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandSetPoolTxnAuthorAgreementAcceptance, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_set_pool_txn_author_agreement_acceptance < {:?}", res);
+    res
+}
+
+/// Append Endorser to an existing request.
+///
+/// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
+/// But it is expecting that the transaction will be sent by the specified Endorser.
+///
+/// Note: Both Transaction Author and Endorser must sign output request after that.
+///
+/// More about Transaction Endorser: https://github.com/hyperledger/indy-node/blob/master/design/transaction_endorser.md
+///                                  https://github.com/hyperledger/indy-sdk/blob/master/docs/configuration.md
+///
+/// #Params
+/// request_json: original request
+/// endorser_did: DID of the Endorser that will submit the transaction.
+///               The Endorser's DID must be present on the ledger.
+/// cb: Callback that takes command result as parameter.
+///     The command result is a request JSON with Endorser field appended.
+///
 /// #Errors
 /// Common*
 #[no_mangle]
@@ -3446,3 +3926,905 @@ pub extern "C" fn indy_append_request_endorser(
     debug!("indy_append_request_endorser < {:?}", res);
     res
 }
+
+/// Builds an endorsement envelope: a request prepared for the Endorser together with the list
+/// of DIDs which must sign it. The envelope is stored in the wallet as a pending endorsement
+/// and can be passed to other parties (e.g. the Endorser) to collect their signatures.
+///
+/// Note: Signatures present in the request are removed as appending of the Endorser changes signed data.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// request_json: original request built by the transaction author.
+/// endorser_did: DID of the Endorser that will submit the transaction.
+/// options_json: (Optional) envelope options:
+/// {
+///     "required_signers": [string] - (Optional) DIDs which must sign the request in addition to the author and Endorser,
+///                                     or in addition to the ones required by `auth_rule`,
+///     "auth_rule": (Optional) auth rule the request must meet (see `indy_evaluate_auth_rule`):
+///     {
+///         "get_auth_rule_response": string - response of GET_AUTH_RULE request,
+///         "action": json - the action to evaluate rules for,
+///         "signers": [json] - NYM data of the signers as returned by `indy_parse_get_nym_response`,
+///     }
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// envelope_json: {
+///     "id": string - identifier of the pending endorsement,
+///     "request": json - the request with Endorser field appended and signatures collected so far,
+///     "endorser": string - DID of the Endorser,
+///     "required_signers": [string] or null - DIDs which must sign the request. Derived from `auth_rule` if it is passed,
+///                           null if it isn't and no explicit signers are passed or the rule can't be met,
+///     "auth_rule": (Optional) json - auth rule the request must meet,
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_build_endorsement_envelope(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    request_json: *const c_char,
+    endorser_did: *const c_char,
+    options_json: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, envelope_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_build_endorsement_envelope > wallet_handle {:?} request_json {:?} \
+            endorser_did {:?} options_json {:?}",
+        wallet_handle, request_json, endorser_did, options_json
+    );
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
+    check_useful_validatable_string!(endorser_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_opt_validatable_json!(options_json, ErrorCode::CommonInvalidParam5, EndorsementEnvelopeOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    debug!(
+        "indy_build_endorsement_envelope ? wallet_handle {:?} request_json {:?} \
+            endorser_did {:?} options_json {:?}",
+        wallet_handle, request_json, endorser_did, options_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .build_endorsement_envelope(wallet_handle, request_json, endorser_did, options_json)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_build_endorsement_envelope ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandBuildEndorsementEnvelope, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_build_endorsement_envelope < {:?}", res);
+    res
+}
+
+/// Adds a signature to the request of an endorsement envelope (see `indy_build_endorsement_envelope`).
+///
+/// The signed envelope is stored (or updated) in the wallet as a pending endorsement.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// envelope_json: endorsement envelope.
+/// signer_did: DID of the signer. The key of the DID must be in the wallet.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Endorsement envelope json with the signature added.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern "C" fn indy_sign_endorsement_envelope(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    envelope_json: *const c_char,
+    signer_did: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, envelope_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_sign_endorsement_envelope > wallet_handle {:?} envelope_json {:?} signer_did {:?}",
+        wallet_handle, envelope_json, signer_did
+    );
+
+    check_useful_validatable_json!(envelope_json, ErrorCode::CommonInvalidParam3, PendingEndorsement);
+    check_useful_validatable_string!(signer_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_sign_endorsement_envelope ? wallet_handle {:?} envelope_json {:?} signer_did {:?}",
+        wallet_handle, envelope_json, signer_did
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .sign_endorsement_envelope(wallet_handle, envelope_json, signer_did)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_sign_endorsement_envelope ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandSignEndorsementEnvelope, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_sign_endorsement_envelope < {:?}", res);
+    res
+}
+
+/// Checks whether an endorsement envelope collected all required signatures.
+///
+/// If the envelope contains an auth rule, the request is also evaluated against it (see `indy_evaluate_auth_rule`).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// envelope_json: endorsement envelope.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// status_json: {
+///     "complete": bool - whether the request can be submitted,
+///     "required_signers_determined": bool - false if the envelope doesn't know its required signers,
+///     "missing_signers": [string] - required DIDs which haven't signed the request yet,
+///     "auth_rule_evaluation": (Optional) json - result of the auth rule evaluation,
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_check_endorsement_envelope(
+    command_handle: CommandHandle,
+    envelope_json: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, status_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_check_endorsement_envelope > envelope_json {:?}",
+        envelope_json
+    );
+
+    check_useful_validatable_json!(envelope_json, ErrorCode::CommonInvalidParam2, PendingEndorsement);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_check_endorsement_envelope ? envelope_json {:?}",
+        envelope_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .check_endorsement_envelope(envelope_json);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_check_endorsement_envelope ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandCheckEndorsementEnvelope, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_check_endorsement_envelope < {:?}", res);
+    res
+}
+
+/// Submits the request of a complete endorsement envelope to validator pool.
+///
+/// The pending endorsement is removed from the wallet once the request is written.
+/// Envelopes with undetermined required signers are rejected.
+/// Rejected requests are kept in the wallet.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// envelope_json: endorsement envelope.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_submit_endorsement_envelope(
+    command_handle: CommandHandle,
+    pool_handle: PoolHandle,
+    wallet_handle: WalletHandle,
+    envelope_json: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            request_result_json: *const c_char,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_submit_endorsement_envelope > pool_handle {:?} wallet_handle {:?} envelope_json {:?}",
+        pool_handle, wallet_handle, envelope_json
+    );
+
+    check_useful_validatable_json!(envelope_json, ErrorCode::CommonInvalidParam4, PendingEndorsement);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_submit_endorsement_envelope ? pool_handle {:?} wallet_handle {:?} envelope_json {:?}",
+        pool_handle, wallet_handle, envelope_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .submit_endorsement_envelope(pool_handle, wallet_handle, envelope_json)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_submit_endorsement_envelope ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandSubmitEndorsementEnvelope, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_submit_endorsement_envelope < {:?}", res);
+    res
+}
+
+/// Lists endorsement envelopes stored in the wallet which are not submitted yet.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Array of endorsement envelopes (see `indy_build_endorsement_envelope`).
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_list_pending_endorsements(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, envelopes_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_list_pending_endorsements > wallet_handle {:?}",
+        wallet_handle
+    );
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_list_pending_endorsements ? wallet_handle {:?}",
+        wallet_handle
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .list_pending_endorsements(wallet_handle)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_list_pending_endorsements ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandListPendingEndorsements, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_list_pending_endorsements < {:?}", res);
+    res
+}
+
+/// Deletes a pending endorsement from the wallet.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// id: identifier of the pending endorsement (`id` field of the envelope).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_delete_pending_endorsement(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    id: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>,
+) -> ErrorCode {
+    debug!(
+        "indy_delete_pending_endorsement > wallet_handle {:?} id {:?}",
+        wallet_handle, id
+    );
+
+    check_useful_c_str!(id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_delete_pending_endorsement ? wallet_handle {:?} id {:?}",
+        wallet_handle, id
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .delete_pending_endorsement(wallet_handle, id)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let err = prepare_result!(res);
+        debug!("indy_delete_pending_endorsement ? err {:?}", err);
+
+        cb(command_handle, err)
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandDeletePendingEndorsement, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_delete_pending_endorsement < {:?}", res);
+    res
+}
+
+/// Builds a LEDGERS_FREEZE request. Request to freeze list of ledgers.
+/// Frozen ledgers can not be written anymore but can still be read.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+/// ledgers_ids: list of ledgers IDs for freezing ledgers (json format).
+///     [0, 1, 28, ...]
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_build_ledgers_freeze_request(
+    command_handle: CommandHandle,
+    submitter_did: *const c_char,
+    ledgers_ids: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, request_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_build_ledgers_freeze_request > submitter_did {:?} ledgers_ids {:?}",
+        submitter_did, ledgers_ids
+    );
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_json!(ledgers_ids, ErrorCode::CommonInvalidParam3, Vec<u64>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_build_ledgers_freeze_request? submitter_did {:?} ledgers_ids {:?}",
+        submitter_did, ledgers_ids
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .build_ledgers_freeze_request(submitter_did, ledgers_ids);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_build_ledgers_freeze_request ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandBuildLedgersFreezeRequest, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_build_ledgers_freeze_request < {:?}", res);
+    res
+}
+
+/// Builds a GET_FROZEN_LEDGERS request. Request to get list of frozen ledgers.
+/// Frozen ledgers are defined by LEDGERS_FREEZE request.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_build_get_frozen_ledgers_request(
+    command_handle: CommandHandle,
+    submitter_did: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, request_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_build_get_frozen_ledgers_request > submitter_did {:?}",
+        submitter_did
+    );
+
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_build_get_frozen_ledgers_request? submitter_did {:?}",
+        submitter_did
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .build_get_frozen_ledgers_request(submitter_did);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_build_get_frozen_ledgers_request ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandBuildGetFrozenLedgersRequest, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_build_get_frozen_ledgers_request < {:?}", res);
+    res
+}
+
+/// Parse a GET_FROZEN_LEDGERS response to get list of frozen ledgers.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_frozen_ledgers_response: response of GET_FROZEN_LEDGERS request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Frozen ledgers json (empty object if no ledger has been frozen):
+/// {
+///     "<ledger_id>": {
+///         ledger: root hash of the ledger at the moment of freezing,
+///         state: state root hash of the ledger at the moment of freezing,
+///         seq_no: last sequence number of the ledger at the moment of freezing
+///     },
+///     ...
+/// }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_parse_get_frozen_ledgers_response(
+    command_handle: CommandHandle,
+    get_frozen_ledgers_response: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            frozen_ledgers_json: *const c_char,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_parse_get_frozen_ledgers_response > get_frozen_ledgers_response {:?}",
+        get_frozen_ledgers_response
+    );
+
+    check_useful_c_str!(get_frozen_ledgers_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_parse_get_frozen_ledgers_response? get_frozen_ledgers_response {:?}",
+        get_frozen_ledgers_response
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .parse_get_frozen_ledgers_response(get_frozen_ledgers_response);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!(
+            "indy_parse_get_frozen_ledgers_response ? err {:?} res {:?}",
+            err, res
+        );
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandParseGetFrozenLedgersResponse, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_parse_get_frozen_ledgers_response < {:?}", res);
+    res
+}
+
+/// Builds a request to store a rich schema object on the ledger.
+/// The transaction type is selected by `rs_type`:
+/// ctx -> SET_JSON_LD_CONTEXT, sch -> SET_RICH_SCHEMA, enc -> SET_RICH_SCHEMA_ENCODING,
+/// map -> SET_RICH_SCHEMA_MAPPING, cdf -> SET_RICH_SCHEMA_CRED_DEF, pdf -> SET_RICH_SCHEMA_PRES_DEF.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+/// rs_id: identifier of the rich schema object (DID string).
+/// rs_content: JSON-LD content of the rich schema object (json string).
+/// rs_name: rich schema object name.
+/// rs_version: rich schema object version.
+/// rs_type: rich schema object type. One of: ctx, sch, enc, map, cdf, pdf.
+/// ver: version of the rich schema object format (currently "1").
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_build_rich_schema_request(
+    command_handle: CommandHandle,
+    submitter_did: *const c_char,
+    rs_id: *const c_char,
+    rs_content: *const c_char,
+    rs_name: *const c_char,
+    rs_version: *const c_char,
+    rs_type: *const c_char,
+    ver: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, request_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_build_rich_schema_request > submitter_did {:?} rs_id {:?} rs_content {:?} \
+            rs_name {:?} rs_version {:?} rs_type {:?} ver {:?}",
+        submitter_did, rs_id, rs_content, rs_name, rs_version, rs_type, ver
+    );
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_c_str!(rs_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(rs_content, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(rs_name, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(rs_version, ErrorCode::CommonInvalidParam6);
+    check_useful_c_str!(rs_type, ErrorCode::CommonInvalidParam7);
+    check_useful_c_str!(ver, ErrorCode::CommonInvalidParam8);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    debug!(
+        "indy_build_rich_schema_request? submitter_did {:?} rs_id {:?} rs_content {:?} \
+            rs_name {:?} rs_version {:?} rs_type {:?} ver {:?}",
+        submitter_did, rs_id, rs_content, rs_name, rs_version, rs_type, ver
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator.ledger_controller.build_rich_schema_request(
+            submitter_did,
+            rs_id,
+            rs_content,
+            rs_name,
+            rs_version,
+            rs_type,
+            ver,
+        );
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_build_rich_schema_request ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandBuildRichSchemaRequest, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_build_rich_schema_request < {:?}", res);
+    res
+}
+
+/// Builds a GET_RICH_SCHEMA_OBJECT_BY_ID request. Request to get a rich schema object by its id.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// rs_id: identifier of the rich schema object.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_build_get_rich_schema_object_by_id_request(
+    command_handle: CommandHandle,
+    submitter_did: *const c_char,
+    rs_id: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, request_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_build_get_rich_schema_object_by_id_request > submitter_did {:?} rs_id {:?}",
+        submitter_did, rs_id
+    );
+
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_c_str!(rs_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_build_get_rich_schema_object_by_id_request? submitter_did {:?} rs_id {:?}",
+        submitter_did, rs_id
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .build_get_rich_schema_object_by_id_request(submitter_did, rs_id);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!(
+            "indy_build_get_rich_schema_object_by_id_request ? err {:?} res {:?}",
+            err, res
+        );
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandBuildGetRichSchemaObjectByIdRequest, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_build_get_rich_schema_object_by_id_request < {:?}", res);
+    res
+}
+
+/// Builds a GET_RICH_SCHEMA_OBJECT_BY_METADATA request.
+/// Request to get a rich schema object by its type, name and version.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// rs_type: rich schema object type. One of: ctx, sch, enc, map, cdf, pdf.
+/// rs_name: rich schema object name.
+/// rs_version: rich schema object version.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_build_get_rich_schema_object_by_metadata_request(
+    command_handle: CommandHandle,
+    submitter_did: *const c_char,
+    rs_type: *const c_char,
+    rs_name: *const c_char,
+    rs_version: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, request_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_build_get_rich_schema_object_by_metadata_request > submitter_did {:?} \
+            rs_type {:?} rs_name {:?} rs_version {:?}",
+        submitter_did, rs_type, rs_name, rs_version
+    );
+
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_c_str!(rs_type, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(rs_name, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(rs_version, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    debug!(
+        "indy_build_get_rich_schema_object_by_metadata_request? submitter_did {:?} \
+            rs_type {:?} rs_name {:?} rs_version {:?}",
+        submitter_did, rs_type, rs_name, rs_version
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .build_get_rich_schema_object_by_metadata_request(
+                submitter_did,
+                rs_type,
+                rs_name,
+                rs_version,
+            );
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!(
+            "indy_build_get_rich_schema_object_by_metadata_request ? err {:?} res {:?}",
+            err, res
+        );
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandBuildGetRichSchemaObjectByMetadataRequest, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_build_get_rich_schema_object_by_metadata_request < {:?}", res);
+    res
+}
+
+/// Parse a GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA response.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_rich_schema_object_response: response of GET_RICH_SCHEMA_OBJECT_BY_* request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Rich schema object json:
+/// {
+///     id: identifier of the rich schema object,
+///     content: JSON-LD content of the object,
+///     rsName: object name,
+///     rsVersion: object version,
+///     rsType: object type,
+///     ver: version of the object format,
+///     from: DID of the object author,
+///     endorser: (Optional) DID of the endorser
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_parse_get_rich_schema_object_response(
+    command_handle: CommandHandle,
+    get_rich_schema_object_response: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            rich_schema_object_json: *const c_char,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_parse_get_rich_schema_object_response > get_rich_schema_object_response {:?}",
+        get_rich_schema_object_response
+    );
+
+    check_useful_c_str!(get_rich_schema_object_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_parse_get_rich_schema_object_response? get_rich_schema_object_response {:?}",
+        get_rich_schema_object_response
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .parse_get_rich_schema_object_response(get_rich_schema_object_response);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!(
+            "indy_parse_get_rich_schema_object_response ? err {:?} res {:?}",
+            err, res
+        );
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandParseGetRichSchemaObjectResponse, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_parse_get_rich_schema_object_response < {:?}", res);
+    res
+}
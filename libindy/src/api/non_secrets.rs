@@ -382,6 +382,69 @@ pub extern "C" fn indy_delete_wallet_record(
     res
 }
 
+/// Apply a list of non-secret record operations to the wallet atomically:
+/// either all operations are applied or, if any of them fails, none of them.
+///
+/// Supported by the default (SQLite) and MySQL wallet storages. Plugged storages
+/// have no transactions and return WalletStorageError without applying anything.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context
+/// wallet_handle: wallet handle (created by open_wallet)
+/// operations_json: the list of operations applied in the given order as json:
+///   [
+///     {"op": "add", "type": <str>, "id": <str>, "value": <str>, "tags": <tags_json>}, // tags are optional
+///     {"op": "update_value", "type": <str>, "id": <str>, "value": <str>},
+///     {"op": "add_tags", "type": <str>, "id": <str>, "tags": <tags_json>},
+///     {"op": "update_tags", "type": <str>, "id": <str>, "tags": <tags_json>},
+///     {"op": "delete_tags", "type": <str>, "id": <str>, "tag_names": [<str>]},
+///     {"op": "delete", "type": <str>, "id": <str>}
+///   ]
+///   where tags_json has the same format as in indy_add_wallet_record
+#[no_mangle]
+pub extern "C" fn indy_wallet_batch(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    operations_json: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>,
+) -> ErrorCode {
+    debug!(
+        "indy_wallet_batch > wallet_handle {:?} operations_json {:?}",
+        wallet_handle, operations_json
+    );
+
+    check_useful_c_str!(operations_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_wallet_batch ? wallet_handle {:?} operations_json {:?}",
+        wallet_handle, operations_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .non_secret_controller
+            .batch(wallet_handle, operations_json)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let err = prepare_result!(res);
+        debug!("indy_wallet_batch ? err {:?}", err);
+
+        cb(command_handle, err);
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::NonSecretsCommandBatch, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_wallet_batch < {:?}", res);
+    res
+}
+
 /// Get an wallet record by id
 ///
 /// #Params
@@ -475,6 +475,12 @@ pub extern fn indy_register_payment_method(command_handle: CommandHandle,
 ///     seed: <str>, // allows deterministic creation of payment address
 ///   }
 ///
+/// The built-in 'cheqd' payment method (available with `cheqd` feature) creates
+/// a secp256k1 key stored in the wallet and accepts the following config:
+///   {
+///     mnemonic: <str>, // optional, allows deterministic creation of the key
+///   }
+///
 /// #Returns
 /// payment_address - public identifier of payment address in fully resolvable payment address format
 #[no_mangle]
@@ -699,6 +705,9 @@ pub extern fn indy_parse_response_with_fees(command_handle: CommandHandle,
 /// submitter_did: (Optional) DID of request sender
 /// payment_address: target payment address
 ///
+/// For the built-in 'cheqd' payment method the request is a balance query
+/// to be sent with `indy_cheqd_pool_abci_query`.
+///
 /// #Returns
 /// get_sources_txn_json - Indy request for getting sources list for payment address
 /// payment_method - used payment method
@@ -752,6 +761,9 @@ pub extern fn indy_build_get_payment_sources_request(command_handle: CommandHand
 /// payment_method: payment method to use.
 /// resp_json: response for Indy request for getting sources list
 ///
+/// For the built-in 'cheqd' payment method the state proof of the response is always checked,
+/// an empty sources list is returned only if the proof shows that the account has no balance.
+///
 /// #Returns
 /// sources_json - parsed (payment method and node version agnostic) sources info as json:
 ///   [{
@@ -821,6 +833,22 @@ pub extern fn indy_parse_get_payment_sources_response(command_handle: CommandHan
 ///   }]
 /// extra: // optional information for payment operation
 ///
+/// The built-in 'cheqd' payment method (available with `cheqd` feature) expects exactly one input,
+/// signs the transaction with the key of that payment address and requires extra:
+///   {
+///     pool_alias: <str>, // cheqd pool the transaction is built for
+///     account_number: <int>, // sender account number (see `indy_cheqd_ledger_auth_build_query_account`)
+///     sequence_number: <int>, // sender account sequence
+///     max_gas: <int>,
+///     max_coin_amount: <int>, // fee amount
+///     denom: <str>, // optional, denom of the transferred coins and the fee
+///     timeout_height: <int>, // optional
+///     memo: <str>, // optional
+///   }
+/// The returned request is `{"pool_alias": <str>, "tx": <hex encoded signed tx>}`;
+/// the decoded tx must be sent with `indy_cheqd_pool_broadcast_tx_commit` and the response
+/// parsed with `indy_parse_payment_response`.
+///
 /// #Returns
 /// payment_req_json - Indy request for doing payment
 /// payment_method - used payment method
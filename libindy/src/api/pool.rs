@@ -1,18 +1,24 @@
 use indy_api_types::{
     errors::prelude::*, validation::Validatable, CommandHandle, ErrorCode, PoolHandle,
-    INVALID_POOL_HANDLE,
+    WalletHandle, INVALID_POOL_HANDLE,
 };
 
 use indy_utils::ctypes;
 use libc::c_char;
 use serde_json;
+use std::sync::Arc;
 
 use crate::{
-    domain::pool::{PoolConfig, PoolOpenConfig},
+    domain::pool::{PoolConfig, PoolOpenConfig, PoolSnapshotExportConfig, SimulatedPoolConfig},
     Locator,
 };
 use crate::services::CommandMetric;
 
+pub use crate::services::{
+    KeyValueSimpleData, KeyValueSimpleDataVerificationType, KeyValuesInSP, KeyValuesSubTrieData,
+    NumericalSuffixAscendingNoGapsData, ParsedSP, StateProofParser,
+};
+
 /// Creates a new local pool ledger configuration that can be used later to connect pool nodes.
 ///
 /// #Params
@@ -21,6 +27,23 @@ use crate::services::CommandMetric;
 /// {
 ///     "genesis_txn": string (optional), A path to genesis transaction file. If NULL, then a default one will be used.
 ///                    If file doesn't exists default one will be created.
+///     "genesis_txn_data": string (optional), Genesis transactions itself (content of genesis transaction file:
+///                    one transaction json per line). Can be used instead of `genesis_txn`.
+///     "genesis_url": string (optional), URL to fetch genesis transaction file from. Can be used instead of `genesis_txn`.
+///                    `genesis_hash` is required for this source.
+///     "genesis_hash": string (optional), hex encoded SHA-256 digest of genesis transaction file content.
+///                    If set, genesis transactions with another digest are rejected.
+///     Only one of `genesis_txn`, `genesis_txn_data` and `genesis_url` can be specified.
+///     "namespace": string (optional), did:indy namespace of the network (e.g. "sovrin" or "sovrin:staging").
+///                  Opened pool is registered for this namespace so did:indy identifiers can be resolved
+///                  against it (see indy_get_pool_handle_for_namespace).
+///     "snapshot": object (optional), pool ledger snapshot exported by indy_export_pool_snapshot.
+///                 Snapshot must start with the genesis transactions and match its `root_hash`.
+///                 The pool is caught up from the snapshot instead of genesis transactions on opening;
+///                 if the nodes' consistency proofs don't match it, catch-up restarts from genesis.
+///                 Signature of a signed snapshot is checked.
+///     "snapshot_verkey": string (optional), verkey the snapshot must be signed with.
+///                        Unsigned snapshots or ones signed with other keys are rejected.
 /// }
 ///
 /// #Returns
@@ -42,7 +65,7 @@ pub extern "C" fn indy_create_pool_ledger_config(
     );
 
     check_useful_c_str!(config_name, ErrorCode::CommonInvalidParam2);
-    check_useful_opt_json!(config, ErrorCode::CommonInvalidParam3, PoolConfig);
+    check_useful_opt_validatable_json!(config, ErrorCode::CommonInvalidParam3, PoolConfig);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     debug!(
@@ -53,7 +76,7 @@ pub extern "C" fn indy_create_pool_ledger_config(
     let locator = Locator::instance();
 
     let action = async move {
-        let res = locator.pool_controller.create(config_name, config);
+        let res = locator.pool_controller.create(config_name, config).await;
         res
     };
 
@@ -71,12 +94,93 @@ pub extern "C" fn indy_create_pool_ledger_config(
     res
 }
 
+/// Starts in-process simulated pool of nodes and creates a local pool ledger configuration for it.
+///
+/// Simulated nodes answer pool ledger catch-up, NYM, ATTRIB, SCHEMA and CRED_DEF writes
+/// and reads of these transactions (including GET_TXN) with valid state proofs and BLS multi-signatures.
+/// Writes are accepted only if they are signed correctly and permitted by the default auth rules.
+/// Pools opened with this configuration talk to the simulated nodes within the process instead of the network,
+/// so the whole pool stack can be used offline and deterministically (e.g. in application tests).
+/// Simulated pool is stopped and its ledgers are dropped when the configuration is deleted
+/// with indy_delete_pool_ledger_config.
+///
+/// #Params
+/// config_name: Name of the pool ledger configuration.
+/// config (optional): Simulated pool configuration json. if NULL, then default config will be used. Example:
+/// {
+///     "nodes_count": int (optional), number of simulated nodes (4 by default, 25 at most).
+///     "nyms": array (optional), NYMs written to the domain ledger before the pool starts:
+///         [{
+///             "did": string, DID of the NYM,
+///             "verkey": string, full or abbreviated verkey of the NYM,
+///             "role": string (optional), one of "TRUSTEE", "STEWARD", "ENDORSER" ("TRUST_ANCHOR"), "NETWORK_MONITOR"
+///         }]
+///         At least one TRUSTEE, STEWARD or ENDORSER is needed to write anything else.
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Genesis transactions of the simulated pool (one transaction json per line).
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_create_simulated_pool(
+    command_handle: CommandHandle,
+    config_name: *const c_char,
+    config: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, genesis_txns: *const c_char)>,
+) -> ErrorCode {
+    debug!(
+        "indy_create_simulated_pool > config_name {:?}, config {:?}",
+        config_name, config
+    );
+
+    check_useful_c_str!(config_name, ErrorCode::CommonInvalidParam2);
+    check_useful_opt_validatable_json!(config, ErrorCode::CommonInvalidParam3, SimulatedPoolConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_create_simulated_pool ? config_name {:?}, config: {:?}",
+        config_name, config
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .pool_controller
+            .create_simulated_pool(config_name, config.unwrap_or_default())
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, genesis_txns) = prepare_result!(res, String::new());
+        debug!("indy_create_simulated_pool ? err {:?} genesis_txns {:?}", err, genesis_txns);
+
+        let genesis_txns = ctypes::string_to_cstring(genesis_txns);
+        cb(command_handle, err, genesis_txns.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::PoolCommandCreateSimulated, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_create_simulated_pool < {:?}", res);
+    res
+}
+
 /// Opens pool ledger and performs connecting to pool nodes.
 ///
 /// Pool ledger configuration with corresponded name must be previously created
 /// with indy_create_pool_ledger_config method.
 /// It is impossible to open pool with the same name more than once.
 ///
+/// Protocol version of the pool is detected while connecting: from the format of pool ledger transactions
+/// and from the versions nodes report. Requests sent to the pool are adjusted to the detected version
+/// (see indy_set_protocol_version), so pools of different versions can be used at the same time.
+///
 /// config_name: Name of the pool ledger configuration.
 /// config (optional): Runtime pool configuration json.
 ///                         if NULL, then default config will be used. Example:
@@ -92,6 +196,13 @@ pub extern "C" fn indy_create_pool_ledger_config(
 ///     "number_read_nodes": int (optional) - the number of nodes to send read requests (2 by default)
 ///         By default Libindy sends a read requests to 2 nodes in the pool.
 ///         If response isn't received or `state proof` is invalid Libindy sends the request again but to 2 (`number_read_nodes`) * 2 = 4 nodes and so far until completion.
+///     "read_nodes_selection": string (optional) - how nodes to send read requests are chosen:
+///         "random" (default) - nodes are shuffled,
+///         "adaptive" - nodes are ranked by latency and success rate observed for the pool (see indy_get_pool_status)
+///             and read requests go to the best performing nodes first.
+///             If response isn't received or `state proof` is missing or invalid Libindy sends the request
+///             to all other nodes at once and waits for consensus.
+///         Nodes passed in `preordered_nodes` go first in both modes.
 /// }
 ///
 /// #Returns
@@ -192,6 +303,149 @@ pub extern "C" fn indy_refresh_pool_ledger(
     res
 }
 
+/// Returns health of the nodes of an opened pool ledger as observed by libindy.
+///
+/// #Params
+/// handle: pool handle returned by indy_open_pool_ledger
+///
+/// #Returns
+/// Pool status json:
+/// {
+///     "ledger_size": <int> - number of transactions in the local copy of the pool ledger,
+///     "merkle_root": <string> - base58 encoded merkle tree root of the local copy of the pool ledger,
+///     "protocol_version": <int> - protocol version detected for the pool (1 for Indy Node 1.3, 2 for Indy Node 1.4 and greater),
+///     "nodes": [
+///         {
+///             "alias": <string> - node alias,
+///             "address": <string> - node client address,
+///             "last_response_time": <int, optional> - unix timestamp (in seconds) of the last message received from the node,
+///             "latency": <float, optional> - rolling average time (in milliseconds) between sending a request to the node and its first reply,
+///             "failures": <int> - number of requests the node didn't reply to in time,
+///             "in_read_set": <bool> - whether read requests are currently sent to the node
+///         }
+///     ]
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_get_pool_status(
+    command_handle: CommandHandle,
+    handle: PoolHandle,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, status_json: *const c_char)>,
+) -> ErrorCode {
+    debug!("indy_get_pool_status > handle {:?}", handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!("indy_get_pool_status ? handle {:?}", handle);
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator.pool_controller.get_status(handle).await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_get_pool_status ? err {:?} res {:?}", err, res);
+
+        let status_json = ctypes::string_to_cstring(res);
+        cb(command_handle, err, status_json.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::PoolCommandGetStatus, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_get_pool_status < {:?}", res);
+    res
+}
+
+/// Verifies state proof and BLS multi-signature of a saved ledger reply without connecting to the pool.
+///
+/// Node BLS keys are taken either from the local copy of the pool ledger of a created pool
+/// or from the passed genesis transactions. Freshness of the reply is not checked,
+/// the time the multi-signature was made is returned instead.
+///
+/// #Params
+/// pool_name (optional): name of the created pool ledger configuration.
+/// genesis_txns (optional): pool genesis transactions (one per line). Exactly one of `pool_name` and `genesis_txns` must be passed.
+/// request_json: the request the reply was received for.
+/// reply_json: the reply to verify.
+///
+/// #Returns
+/// Verification report json:
+/// {
+///     "verified": <bool> - whether the reply contains state proofs and all of them are valid,
+///     "stateProofFound": <bool> - whether state proofs were found in the reply,
+///     "stateProofs": [
+///         {
+///             "rootHash": <string> - root hash the proof is made against,
+///             "participants": [<string>] - aliases of the nodes signed the root hash,
+///             "timestamp": <int, optional> - time the root hash was signed,
+///             "multiSignatureValid": <bool> - whether the multi-signature is valid and made by enough nodes,
+///             "proofValid": <bool> - whether the reply data matches the proof
+///         }
+///     ],
+///     "lastSignedTime": <int, optional> - last signed time of the reply,
+///     "nodesCount": <int> - number of pool nodes,
+///     "f": <int> - number of faulty nodes the pool tolerates
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_verify_ledger_reply(
+    command_handle: CommandHandle,
+    pool_name: *const c_char,
+    genesis_txns: *const c_char,
+    request_json: *const c_char,
+    reply_json: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, verification_json: *const c_char)>,
+) -> ErrorCode {
+    debug!(
+        "indy_verify_ledger_reply > pool_name {:?} genesis_txns {:?} request_json {:?} reply_json {:?}",
+        pool_name, genesis_txns, request_json, reply_json
+    );
+
+    check_useful_opt_c_str!(pool_name, ErrorCode::CommonInvalidParam2);
+    check_useful_opt_c_str!(genesis_txns, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(reply_json, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    debug!(
+        "indy_verify_ledger_reply ? pool_name {:?} genesis_txns {:?} request_json {:?} reply_json {:?}",
+        pool_name, genesis_txns, request_json, reply_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .pool_controller
+            .verify_ledger_reply(pool_name, genesis_txns, request_json, reply_json);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_verify_ledger_reply ? err {:?} res {:?}", err, res);
+
+        let verification_json = ctypes::string_to_cstring(res);
+        cb(command_handle, err, verification_json.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::PoolCommandVerifyLedgerReply, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_verify_ledger_reply < {:?}", res);
+    res
+}
+
 /// Lists names of created pool ledgers
 ///
 /// #Params
@@ -328,6 +582,159 @@ pub extern "C" fn indy_delete_pool_ledger_config(
     res
 }
 
+/// Replaces genesis transactions of created pool ledger configuration.
+///
+/// Can be used when the network publishes a new genesis transaction file.
+/// Pool ledger transactions received from the nodes before are dropped,
+/// so the pool is synchronized from the new genesis transactions on the next opening.
+/// Pool must not be opened.
+///
+/// #Params
+/// config_name: Name of the pool ledger configuration.
+/// config: Genesis transactions source json. Example:
+/// {
+///     "genesis_txn": string (optional), A path to genesis transaction file.
+///     "genesis_txn_data": string (optional), Genesis transactions itself (content of genesis transaction file).
+///     "genesis_url": string (optional), URL to fetch genesis transaction file from. `genesis_hash` is required for this source.
+///     "genesis_hash": string (optional), hex encoded SHA-256 digest of genesis transaction file content.
+///                     If NULL, then the hash pinned before (if any) is checked and kept.
+///     "namespace": string (optional), did:indy namespace of the network. If NULL, then stored one will be kept.
+///     "snapshot": object (optional), pool ledger snapshot exported by indy_export_pool_snapshot
+///                 to catch up from instead of the new genesis transactions.
+///     "snapshot_verkey": string (optional), verkey the snapshot must be signed with.
+///     Exactly one of `genesis_txn`, `genesis_txn_data` and `genesis_url` must be specified.
+/// }
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_update_pool_ledger_genesis(
+    command_handle: CommandHandle,
+    config_name: *const c_char,
+    config: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>,
+) -> ErrorCode {
+    debug!(
+        "indy_update_pool_ledger_genesis > config_name {:?}, config {:?}",
+        config_name, config
+    );
+
+    check_useful_c_str!(config_name, ErrorCode::CommonInvalidParam2);
+    check_useful_validatable_json!(config, ErrorCode::CommonInvalidParam3, PoolConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_update_pool_ledger_genesis ? config_name {:?}, config: {:?}",
+        config_name, config
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator.pool_controller.update_genesis(config_name, config).await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let err = prepare_result!(res);
+        debug!("indy_update_pool_ledger_genesis ? err {:?}", err);
+
+        cb(command_handle, err)
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::PoolCommandUpdateGenesis, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_update_pool_ledger_genesis < {:?}", res);
+    res
+}
+
+/// Exports transactions of the local copy of the pool ledger as a snapshot.
+///
+/// Snapshot contains pool ledger transactions received from the nodes on the last opening or refresh
+/// (genesis transactions if the pool was never opened) and the merkle tree root hash over them.
+/// Pass it as `snapshot` of indy_create_pool_ledger_config on other machines
+/// to skip catch-up from genesis transactions on the first opening.
+///
+/// #Params
+/// wallet_handle: wallet handle (created by open_wallet) holding the signer key.
+///                Not used if `signer_verkey` isn't set.
+/// config_name: Name of the pool ledger configuration.
+/// export_config (optional): export configuration json:
+/// {
+///     "signer_verkey": string (optional), verkey of the wallet key to sign the snapshot with.
+///                      Importers can require the snapshot to be signed with it (see `snapshot_verkey`
+///                      of indy_create_pool_ledger_config).
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Snapshot json:
+/// {
+///     "ledger_size": <int> - number of transactions in the snapshot,
+///     "root_hash": <string> - base58 encoded merkle tree root hash of the transactions,
+///     "txns": [<object>] - pool ledger transactions ordered by sequence number,
+///     "signature": (optional) {
+///         "verkey": <string> - verkey of the signer,
+///         "signature": <string> - base58 encoded signature of "<ledger_size>:<root_hash>"
+///     }
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_export_pool_snapshot(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    config_name: *const c_char,
+    export_config: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, snapshot_json: *const c_char)>,
+) -> ErrorCode {
+    debug!(
+        "indy_export_pool_snapshot > wallet_handle {:?} config_name {:?} export_config {:?}",
+        wallet_handle, config_name, export_config
+    );
+
+    check_useful_c_str!(config_name, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_json!(export_config, ErrorCode::CommonInvalidParam4, PoolSnapshotExportConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_export_pool_snapshot ? wallet_handle {:?} config_name {:?} export_config {:?}",
+        wallet_handle, config_name, export_config
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .pool_controller
+            .export_snapshot(wallet_handle, config_name, export_config)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_export_pool_snapshot ? err {:?} res {:?}", err, res);
+
+        let snapshot_json = ctypes::string_to_cstring(res);
+        cb(command_handle, err, snapshot_json.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::PoolCommandExportSnapshot, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_export_pool_snapshot < {:?}", res);
+    res
+}
+
 /// Set PROTOCOL_VERSION to specific version.
 ///
 /// There is a global property PROTOCOL_VERSION that used in every request to the pool and
@@ -335,6 +742,12 @@ pub extern "C" fn indy_delete_pool_ledger_config(
 ///
 /// By default PROTOCOL_VERSION=1.
 ///
+/// Opened pools use protocol version detected for them instead:
+/// not signed requests submitted to a pool get its `protocolVersion`.
+/// Requests signed before submission are sent as is,
+/// so build them after opening the pool or with the matching PROTOCOL_VERSION.
+/// PROTOCOL_VERSION is still used by request builders and for pools the version can't be detected for.
+///
 /// #Params
 /// protocol_version: Protocol version will be used:
 ///     1 - for Indy Node 1.3
@@ -385,3 +798,91 @@ pub extern "C" fn indy_set_protocol_version(
     debug!("indy_set_protocol_version < {:?}", res);
     res
 }
+
+/// Returns handle of the opened pool ledger which serves given did:indy namespace.
+///
+/// Namespace is assigned to the pool ledger configuration on creation
+/// (see `namespace` field of indy_create_pool_ledger_config config).
+/// Only one opened pool can be registered for a namespace.
+///
+/// #Params
+/// namespace: did:indy namespace (e.g. "sovrin" or "sovrin:staging").
+///            The namespace of a did:indy identifier (did:indy:<namespace>:<id>).
+///
+/// #Returns
+/// Handle to opened pool registered for the namespace.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_get_pool_handle_for_namespace(
+    command_handle: CommandHandle,
+    namespace: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, pool_handle: PoolHandle),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_get_pool_handle_for_namespace > namespace {:?}",
+        namespace
+    );
+
+    check_useful_c_str!(namespace, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_get_pool_handle_for_namespace ? namespace {:?}",
+        namespace
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .pool_controller
+            .get_pool_handle_for_namespace(namespace)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, pool_handle) = prepare_result!(res, INVALID_POOL_HANDLE);
+
+        debug!(
+            "indy_get_pool_handle_for_namespace ? err {:?} pool_handle {:?}",
+            err, pool_handle
+        );
+
+        cb(command_handle, err, pool_handle)
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::PoolCommandGetPoolHandleForNamespace, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_get_pool_handle_for_namespace < {:?}", res);
+    res
+}
+
+/// Registers a state proof parser implemented in Rust for a custom transaction type.
+/// It is the safe counterpart of `indy_register_transaction_parser_for_sp`.
+///
+/// Replies to requests of the registered type will be verified with state proofs returned by
+/// `parser` instead of waiting for f+1 equal replies from nodes.
+///
+/// #Params
+/// txn_type: type of transaction to apply `parser` to.
+/// parser: state proof parser.
+///
+/// #Errors
+/// Common*
+pub fn register_state_proof_parser(txn_type: &str, parser: Arc<dyn StateProofParser>) -> IndyResult<()> {
+    debug!("register_state_proof_parser > txn_type {:?}", txn_type);
+
+    let res = Locator::instance()
+        .ledger_controller
+        .register_state_proof_parser(txn_type, parser);
+
+    debug!("register_state_proof_parser < {:?}", res);
+    res
+}
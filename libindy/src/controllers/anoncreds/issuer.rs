@@ -34,6 +34,7 @@ use crate::{
             schema::{AttributeNames, Schema, SchemaId, SchemaV1},
         },
         crypto::did::DidValue,
+        ledger::request::ProtocolVersion,
     },
     services::{AnoncredsHelpers, BlobStorageService, CryptoService, IssuerService, PoolService},
 };
@@ -152,8 +153,14 @@ impl IssuerController {
             .map(|n| SchemaId(n.to_string()))
             .unwrap_or_else(|| schema.id.clone());
 
-        let cred_def_id =
-            CredentialDefinitionId::new(&issuer_did, &schema_id, signature_type.to_str(), &tag);
+        // There is no pool here, so the protocol version set for the library is used
+        let cred_def_id = CredentialDefinitionId::for_protocol_version(
+            &issuer_did,
+            &schema_id,
+            signature_type.to_str(),
+            &tag,
+            ProtocolVersion::get(),
+        );
 
         let cred_def = self
             .wallet_service
@@ -8,8 +8,13 @@ use indy_wallet::{WalletRecord, WalletService};
 
 use crate::{
     domain::{
-        anoncreds::{credential_definition::CredentialDefinitionId, schema::SchemaId},
-        cache::{GetCacheOptions, PurgeOptions},
+        anoncreds::{
+            credential_definition::CredentialDefinitionId,
+            revocation_registry_definition::RevocationRegistryId,
+            revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1},
+            schema::SchemaId,
+        },
+        cache::{CachedRevocRegDelta, GetCacheOptions, PurgeOptions},
         crypto::did::DidValue,
     },
     services::{CryptoService, LedgerService, PoolService},
@@ -17,6 +22,9 @@ use crate::{
 
 const CRED_DEF_CACHE: &str = "cred_def_cache";
 const SCHEMA_CACHE: &str = "schema_cache";
+const NYM_CACHE: &str = "nym_cache";
+const REVOC_REG_DEF_CACHE: &str = "revoc_reg_def_cache";
+const REVOC_REG_DELTA_CACHE: &str = "revoc_reg_delta_cache";
 
 pub(crate) struct CacheController {
     crypto_service: Arc<CryptoService>,
@@ -28,24 +36,7 @@ pub(crate) struct CacheController {
 macro_rules! check_cache {
     ($cache: ident, $options: ident) => {
         if let Some(cache) = $cache {
-            let min_fresh = $options.min_fresh.unwrap_or(-1);
-            if min_fresh >= 0 {
-                let ts = match CacheController::_get_seconds_since_epoch() {
-                    Ok(ts) => ts,
-                    Err(err) => return Err(err),
-                };
-                if ts - min_fresh
-                    <= cache
-                        .get_tags()
-                        .unwrap_or(&Tags::new())
-                        .get("timestamp")
-                        .unwrap_or(&"-1".to_string())
-                        .parse()
-                        .unwrap_or(-1)
-                {
-                    return Ok(cache.get_value().unwrap_or("").to_string());
-                }
-            } else {
+            if CacheController::_is_fresh(&cache, &$options)? {
                 return Ok(cache.get_value().unwrap_or("").to_string());
             }
         }
@@ -180,40 +171,268 @@ impl CacheController {
         return res;
     }
 
-    pub(crate) async fn purge_schema_cache(
+    pub(crate) async fn get_nym(
         &self,
+        pool_handle: PoolHandle,
         wallet_handle: WalletHandle,
-        options: PurgeOptions,
-    ) -> IndyResult<()> {
+        submitter_did: DidValue,
+        did: DidValue,
+        options: GetCacheOptions,
+    ) -> IndyResult<String> {
         trace!(
-            "purge_schema_cache > wallet_handle {:?} options {:?}",
+            "get_nym > pool_handle {:?} wallet_handle {:?} \
+                submitter_did {:?} did {:?} options {:?}",
+            pool_handle,
             wallet_handle,
+            submitter_did,
+            did,
             options
         );
 
-        let query_json = Self::_build_query_json(options.max_age.unwrap_or(-1))?;
+        let cache = self
+            ._get_record_from_cache(wallet_handle, &did.0, &options, NYM_CACHE)
+            .await?;
 
-        let mut search = self
-            .wallet_service
-            .search_records(
-                wallet_handle,
-                SCHEMA_CACHE,
-                &query_json,
-                &json!({
-                    "retrieveType": false,
-                    "retrieveValue": false,
-                    "retrieveTags": false,
-                })
-                .to_string(),
-            )
+        check_cache!(cache, options);
+
+        if options.no_update.unwrap_or(false) {
+            let res = Err(IndyError::from(IndyErrorKind::LedgerItemNotFound));
+            trace!("get_nym < not found {:?}", res);
+            return res;
+        }
+
+        let nym_json = {
+            self.crypto_service.validate_opt_did(Some(&submitter_did))?;
+
+            let request_json = self
+                .ledger_service
+                .build_get_nym_request(Some(&submitter_did), &did)?;
+
+            let pool_response = self
+                .pool_service
+                .send_tx(pool_handle, &request_json)
+                .await?;
+
+            self.ledger_service.parse_get_nym_response(&pool_response)?
+        };
+
+        self._delete_and_add_record(wallet_handle, options, &did.0, &nym_json, NYM_CACHE)
+            .await
+            .to_indy(IndyErrorKind::InvalidState, "Can't update cache.")?;
+
+        let res = Ok(nym_json);
+        trace!("get_nym < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn get_revoc_reg_def(
+        &self,
+        pool_handle: PoolHandle,
+        wallet_handle: WalletHandle,
+        submitter_did: DidValue,
+        id: RevocationRegistryId,
+        options: GetCacheOptions,
+    ) -> IndyResult<String> {
+        trace!(
+            "get_revoc_reg_def > pool_handle {:?} wallet_handle {:?} \
+                submitter_did {:?} id {:?} options {:?}",
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            id,
+            options
+        );
+
+        let cache = self
+            ._get_record_from_cache(wallet_handle, &id.0, &options, REVOC_REG_DEF_CACHE)
             .await?;
 
-        while let Some(record) = search.fetch_next_record().await? {
-            self.wallet_service
-                .delete_record(wallet_handle, SCHEMA_CACHE, record.get_id())
+        check_cache!(cache, options);
+
+        if options.no_update.unwrap_or(false) {
+            let res = Err(IndyError::from(IndyErrorKind::LedgerItemNotFound));
+            trace!("get_revoc_reg_def < not found {:?}", res);
+            return res;
+        }
+
+        let (revoc_reg_def_id, revoc_reg_def_json) = {
+            self.crypto_service.validate_opt_did(Some(&submitter_did))?;
+
+            let request_json = self
+                .ledger_service
+                .build_get_revoc_reg_def_request(Some(&submitter_did), &id)?;
+
+            let pool_response = self
+                .pool_service
+                .send_tx(pool_handle, &request_json)
                 .await?;
+
+            self.ledger_service
+                .parse_get_revoc_reg_def_response(&pool_response)?
+        };
+
+        self._delete_and_add_record(
+            wallet_handle,
+            options,
+            &revoc_reg_def_id,
+            &revoc_reg_def_json,
+            REVOC_REG_DEF_CACHE,
+        )
+        .await
+        .to_indy(IndyErrorKind::InvalidState, "Can't update cache.")?;
+
+        let res = Ok(revoc_reg_def_json);
+        trace!("get_revoc_reg_def < {:?}", res);
+        res
+    }
+
+    /// Cached delta is stored per registry and `from`.
+    /// If cached delta ends before requested `to` only the missing interval is fetched
+    /// from the ledger and merged into the cached one.
+    pub(crate) async fn get_revoc_reg_delta(
+        &self,
+        pool_handle: PoolHandle,
+        wallet_handle: WalletHandle,
+        submitter_did: DidValue,
+        revoc_reg_def_id: RevocationRegistryId,
+        from: Option<i64>,
+        to: i64,
+        options: GetCacheOptions,
+    ) -> IndyResult<(String, String, u64)> {
+        trace!(
+            "get_revoc_reg_delta > pool_handle {:?} wallet_handle {:?} \
+                submitter_did {:?} revoc_reg_def_id {:?} from {:?} to {:?} options {:?}",
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            revoc_reg_def_id,
+            from,
+            to,
+            options
+        );
+
+        let cache_id = format!("{}::{}", revoc_reg_def_id.0, from.unwrap_or(0));
+
+        let cache = self
+            ._get_record_from_cache(wallet_handle, &cache_id, &options, REVOC_REG_DELTA_CACHE)
+            .await?;
+
+        let cached = match cache {
+            Some(ref record) => {
+                let cached: CachedRevocRegDelta =
+                    serde_json::from_str(record.get_value().unwrap_or(""))
+                        .to_indy(IndyErrorKind::InvalidState, "Invalid cached revocation registry delta")?;
+
+                if cached.to == to && CacheController::_is_fresh(record, &options)? {
+                    let res = CacheController::_cached_revoc_reg_delta_to_result(cached);
+                    trace!("get_revoc_reg_delta < cached {:?}", res);
+                    return res;
+                }
+
+                Some(cached)
+            }
+            None => None,
+        };
+
+        if options.no_update.unwrap_or(false) {
+            let res = Err(IndyError::from(IndyErrorKind::LedgerItemNotFound));
+            trace!("get_revoc_reg_delta < not found {:?}", res);
+            return res;
         }
 
+        self.crypto_service.validate_opt_did(Some(&submitter_did))?;
+
+        let cached_to = cached.as_ref().map(|cached| cached.to);
+
+        let merged = match cached {
+            Some(cached) if cached.to < to => {
+                let (_, missing_delta_json, timestamp) = self
+                    ._ledger_get_revoc_reg_delta(
+                        pool_handle,
+                        &submitter_did,
+                        &revoc_reg_def_id,
+                        Some(cached.to),
+                        to,
+                    )
+                    .await?;
+
+                let missing_delta: RevocationRegistryDelta =
+                    serde_json::from_str(&missing_delta_json).to_indy(
+                        IndyErrorKind::InvalidState,
+                        "Invalid revocation registry delta json",
+                    )?;
+
+                CacheController::_merge_revoc_reg_deltas(cached.delta, missing_delta)?
+                    .map(|delta| (delta, timestamp))
+            }
+            _ => None,
+        };
+
+        let (delta, timestamp) = match merged {
+            Some(merged) => merged,
+            None => {
+                let (_, delta_json, timestamp) = self
+                    ._ledger_get_revoc_reg_delta(
+                        pool_handle,
+                        &submitter_did,
+                        &revoc_reg_def_id,
+                        from,
+                        to,
+                    )
+                    .await?;
+
+                let delta: RevocationRegistryDelta = serde_json::from_str(&delta_json).to_indy(
+                    IndyErrorKind::InvalidState,
+                    "Invalid revocation registry delta json",
+                )?;
+
+                (delta, timestamp)
+            }
+        };
+
+        let cached = CachedRevocRegDelta {
+            revoc_reg_def_id: revoc_reg_def_id.0.clone(),
+            delta,
+            timestamp,
+            to,
+        };
+
+        // Cached delta covering later interval is not replaced with an older one
+        if cached_to.map(|cached_to| cached_to <= to).unwrap_or(true) {
+            let cached_json = serde_json::to_string(&cached).to_indy(
+                IndyErrorKind::InvalidState,
+                "Cannot serialize cached revocation registry delta",
+            )?;
+
+            self._delete_and_add_record(
+                wallet_handle,
+                options,
+                &cache_id,
+                &cached_json,
+                REVOC_REG_DELTA_CACHE,
+            )
+            .await
+            .to_indy(IndyErrorKind::InvalidState, "Can't update cache.")?;
+        }
+
+        let res = CacheController::_cached_revoc_reg_delta_to_result(cached);
+        trace!("get_revoc_reg_delta < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn purge_schema_cache(
+        &self,
+        wallet_handle: WalletHandle,
+        options: PurgeOptions,
+    ) -> IndyResult<()> {
+        trace!(
+            "purge_schema_cache > wallet_handle {:?} options {:?}",
+            wallet_handle,
+            options
+        );
+
+        self._purge_cache(wallet_handle, options, SCHEMA_CACHE).await?;
+
         let res = Ok(());
         trace!("purge_schema_cache < {:?}", res);
         res
@@ -230,13 +449,80 @@ impl CacheController {
             options
         );
 
+        self._purge_cache(wallet_handle, options, CRED_DEF_CACHE).await?;
+
+        let res = Ok(());
+        trace!("purge_cred_def_cache < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn purge_nym_cache(
+        &self,
+        wallet_handle: WalletHandle,
+        options: PurgeOptions,
+    ) -> IndyResult<()> {
+        trace!(
+            "purge_nym_cache > wallet_handle {:?} options {:?}",
+            wallet_handle,
+            options
+        );
+
+        self._purge_cache(wallet_handle, options, NYM_CACHE).await?;
+
+        let res = Ok(());
+        trace!("purge_nym_cache < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn purge_revoc_reg_def_cache(
+        &self,
+        wallet_handle: WalletHandle,
+        options: PurgeOptions,
+    ) -> IndyResult<()> {
+        trace!(
+            "purge_revoc_reg_def_cache > wallet_handle {:?} options {:?}",
+            wallet_handle,
+            options
+        );
+
+        self._purge_cache(wallet_handle, options, REVOC_REG_DEF_CACHE).await?;
+
+        let res = Ok(());
+        trace!("purge_revoc_reg_def_cache < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn purge_revoc_reg_delta_cache(
+        &self,
+        wallet_handle: WalletHandle,
+        options: PurgeOptions,
+    ) -> IndyResult<()> {
+        trace!(
+            "purge_revoc_reg_delta_cache > wallet_handle {:?} options {:?}",
+            wallet_handle,
+            options
+        );
+
+        self._purge_cache(wallet_handle, options, REVOC_REG_DELTA_CACHE).await?;
+
+        let res = Ok(());
+        trace!("purge_revoc_reg_delta_cache < {:?}", res);
+        res
+    }
+
+    async fn _purge_cache(
+        &self,
+        wallet_handle: WalletHandle,
+        options: PurgeOptions,
+        which_cache: &str,
+    ) -> IndyResult<()> {
         let query_json = Self::_build_query_json(options.max_age.unwrap_or(-1))?;
 
         let mut search = self
             .wallet_service
             .search_records(
                 wallet_handle,
-                CRED_DEF_CACHE,
+                which_cache,
                 &query_json,
                 &json!({
                     "retrieveType": false,
@@ -249,13 +535,105 @@ impl CacheController {
 
         while let Some(record) = search.fetch_next_record().await? {
             self.wallet_service
-                .delete_record(wallet_handle, CRED_DEF_CACHE, record.get_id())
+                .delete_record(wallet_handle, which_cache, record.get_id())
                 .await?;
         }
 
-        let res = Ok(());
-        trace!("purge_cred_def_cache <<< res: ()");
-        res
+        Ok(())
+    }
+
+    async fn _ledger_get_revoc_reg_delta(
+        &self,
+        pool_handle: PoolHandle,
+        submitter_did: &DidValue,
+        revoc_reg_def_id: &RevocationRegistryId,
+        from: Option<i64>,
+        to: i64,
+    ) -> IndyResult<(String, String, u64)> {
+        let request_json = self.ledger_service.build_get_revoc_reg_delta_request(
+            Some(submitter_did),
+            revoc_reg_def_id,
+            from,
+            to,
+        )?;
+
+        let pool_response = self
+            .pool_service
+            .send_tx(pool_handle, &request_json)
+            .await?;
+
+        self.ledger_service
+            .parse_get_revoc_reg_delta_response(&pool_response)
+    }
+
+    /// Returns None if deltas can't be merged and the whole interval has to be requested.
+    fn _merge_revoc_reg_deltas(
+        cached_delta: RevocationRegistryDelta,
+        missing_delta: RevocationRegistryDelta,
+    ) -> IndyResult<Option<RevocationRegistryDelta>> {
+        let mut cached_delta = RevocationRegistryDeltaV1::from(cached_delta);
+        let missing_delta = RevocationRegistryDeltaV1::from(missing_delta);
+
+        if cached_delta.value.merge(&missing_delta.value).is_ok() {
+            return Ok(Some(RevocationRegistryDelta::RevocationRegistryDeltaV1(
+                cached_delta,
+            )));
+        }
+
+        // Ledger doesn't return previous accumulator if there were no entries in the interval
+        let cached_value = serde_json::to_value(&cached_delta.value).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize RevocationRegistryDelta",
+        )?;
+
+        let missing_value = serde_json::to_value(&missing_delta.value).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize RevocationRegistryDelta",
+        )?;
+
+        let unchanged = cached_value["accum"] == missing_value["accum"]
+            && missing_value["issued"].as_array().map(Vec::is_empty).unwrap_or(true)
+            && missing_value["revoked"].as_array().map(Vec::is_empty).unwrap_or(true);
+
+        if unchanged {
+            Ok(Some(RevocationRegistryDelta::RevocationRegistryDeltaV1(
+                cached_delta,
+            )))
+        } else {
+            warn!("Cached revocation registry delta can't be merged with the missing interval");
+            Ok(None)
+        }
+    }
+
+    fn _cached_revoc_reg_delta_to_result(
+        cached: CachedRevocRegDelta,
+    ) -> IndyResult<(String, String, u64)> {
+        let delta_json = serde_json::to_string(&cached.delta).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize RevocationRegistryDelta",
+        )?;
+
+        Ok((cached.revoc_reg_def_id, delta_json, cached.timestamp))
+    }
+
+    fn _is_fresh(cache: &WalletRecord, options: &GetCacheOptions) -> IndyResult<bool> {
+        let min_fresh = options.min_fresh.unwrap_or(-1);
+
+        if min_fresh < 0 {
+            return Ok(true);
+        }
+
+        let ts = CacheController::_get_seconds_since_epoch()?;
+
+        let cached_ts = cache
+            .get_tags()
+            .unwrap_or(&Tags::new())
+            .get("timestamp")
+            .unwrap_or(&"-1".to_string())
+            .parse()
+            .unwrap_or(-1);
+
+        Ok(ts - min_fresh <= cached_ts)
     }
 
     async fn _delete_and_add_record(
@@ -359,3 +737,101 @@ impl CacheController {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{BTreeSet, HashSet},
+        iter::FromIterator,
+    };
+
+    use ursa::cl::{
+        issuer::Issuer as UrsaIssuer, RevocationRegistryDelta as UrsaRevocationRegistryDelta,
+        SimpleTailsAccessor,
+    };
+
+    use super::*;
+
+    const MAX_CRED_NUM: u32 = 5;
+
+    fn _delta(value: UrsaRevocationRegistryDelta) -> RevocationRegistryDelta {
+        RevocationRegistryDelta::RevocationRegistryDeltaV1(RevocationRegistryDeltaV1 { value })
+    }
+
+    fn _to_json(delta: &RevocationRegistryDelta) -> serde_json::Value {
+        serde_json::to_value(delta).unwrap()
+    }
+
+    /// Deltas of two subsequent revocations and the ledger delta for the interval
+    /// without entries after the first one (with no previous accumulator).
+    fn _deltas() -> (
+        UrsaRevocationRegistryDelta,
+        UrsaRevocationRegistryDelta,
+        UrsaRevocationRegistryDelta,
+    ) {
+        let mut schema_builder = UrsaIssuer::new_credential_schema_builder().unwrap();
+        schema_builder.add_attr("name").unwrap();
+        let schema = schema_builder.finalize().unwrap();
+
+        let mut non_schema_builder = UrsaIssuer::new_non_credential_schema_builder().unwrap();
+        non_schema_builder.add_attr("master_secret").unwrap();
+        let non_schema = non_schema_builder.finalize().unwrap();
+
+        let (cred_pub_key, _, _) = UrsaIssuer::new_credential_def(&schema, &non_schema, true).unwrap();
+
+        let (_, _, mut rev_reg, mut tails_generator) =
+            UrsaIssuer::new_revocation_registry_def(&cred_pub_key, MAX_CRED_NUM, true).unwrap();
+
+        let tails = SimpleTailsAccessor::new(&mut tails_generator).unwrap();
+
+        let first = UrsaIssuer::revoke_credential(&mut rev_reg, MAX_CRED_NUM, 1, &tails).unwrap();
+        let unchanged = UrsaRevocationRegistryDelta::from_parts(None, &rev_reg, &HashSet::new(), &HashSet::new());
+        let second = UrsaIssuer::revoke_credential(&mut rev_reg, MAX_CRED_NUM, 2, &tails).unwrap();
+
+        (first, second, unchanged)
+    }
+
+    #[test]
+    fn merge_revoc_reg_deltas_works() {
+        let (first, second, _) = _deltas();
+
+        let second_json = _to_json(&_delta(second.clone()));
+
+        let merged = CacheController::_merge_revoc_reg_deltas(_delta(first), _delta(second))
+            .unwrap()
+            .unwrap();
+        let merged = _to_json(&merged);
+
+        let revoked: BTreeSet<u64> = merged["value"]["revoked"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|idx| idx.as_u64().unwrap())
+            .collect();
+
+        assert_eq!(second_json["value"]["accum"], merged["value"]["accum"]);
+        assert_eq!(BTreeSet::from_iter(vec![1, 2]), revoked);
+    }
+
+    #[test]
+    fn merge_revoc_reg_deltas_works_for_cached_delta_covering_interval() {
+        let (first, _, unchanged) = _deltas();
+
+        let first_json = _to_json(&_delta(first.clone()));
+
+        let merged = CacheController::_merge_revoc_reg_deltas(_delta(first), _delta(unchanged))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(first_json, _to_json(&merged));
+    }
+
+    #[test]
+    fn merge_revoc_reg_deltas_works_for_not_mergeable_deltas() {
+        let (first, second, _) = _deltas();
+
+        let res = CacheController::_merge_revoc_reg_deltas(_delta(second), _delta(first)).unwrap();
+
+        assert!(res.is_none());
+    }
+}
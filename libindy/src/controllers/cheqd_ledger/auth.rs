@@ -17,8 +17,9 @@ impl CheqdLedgerController {
         max_coin_denom: &str,
         timeout_height: u64,
         memo: &str,
+        fee_granter: Option<&str>,
     ) -> IndyResult<Vec<u8>> {
-        trace!("auth_build_tx > pool_alias {:?}, sender_public_key {:?}, msg {:?}, account_number {:?}, sequence_number {:?}, max_gas {:?}, max_coin_amount {:?}, max_coin_denom {:?}, timeout_height {:?}, memo {:?}", pool_alias, sender_public_key, msg, account_number, sequence_number, max_gas, max_coin_amount, max_coin_denom, timeout_height, memo);
+        trace!("auth_build_tx > pool_alias {:?}, sender_public_key {:?}, msg {:?}, account_number {:?}, sequence_number {:?}, max_gas {:?}, max_coin_amount {:?}, max_coin_denom {:?}, timeout_height {:?}, memo {:?}, fee_granter {:?}", pool_alias, sender_public_key, msg, account_number, sequence_number, max_gas, max_coin_amount, max_coin_denom, timeout_height, memo, fee_granter);
 
         let pool = self.cheqd_pool_service.get_config(pool_alias).await?;
         let msg = Msg::from_bytes(&msg)?;
@@ -28,7 +29,7 @@ impl CheqdLedgerController {
             .auth_build_tx(
                 &pool.chain_id,
                 sender_public_key,
-                msg,
+                vec![msg],
                 account_number,
                 sequence_number,
                 max_gas,
@@ -36,6 +37,7 @@ impl CheqdLedgerController {
                 max_coin_denom,
                 timeout_height,
                 memo,
+                fee_granter,
             )
             .await?;
 
@@ -0,0 +1,100 @@
+use crate::controllers::CheqdLedgerController;
+use indy_api_types::errors::{IndyResult, IndyErrorKind, IndyResultExt};
+use crate::domain::cheqd_ledger::cosmos_ext::CosmosMsgExt;
+use cosmrs::rpc::endpoint::broadcast::tx_commit::Response;
+use cosmrs::tx::Msg;
+
+impl CheqdLedgerController {
+    pub(crate) fn authz_build_msg_grant(
+        &self,
+        granter: &str,
+        grantee: &str,
+        msg_type_url: &str,
+        expiration: i64,
+    ) -> IndyResult<Vec<u8>> {
+        trace!(
+            "authz_build_msg_grant > granter {:?} grantee {:?} msg_type_url {:?} expiration {:?}",
+            granter, grantee, msg_type_url, expiration
+        );
+        let msg = self
+            .cheqd_ledger_service
+            .authz_build_msg_grant(granter, grantee, msg_type_url, expiration)?;
+        trace!("authz_build_msg_grant < {:?}", msg);
+
+        Ok(msg.to_bytes()?)
+    }
+
+    pub(crate) fn authz_parse_msg_grant_resp(&self, resp: &str) -> IndyResult<String> {
+        trace!("authz_parse_msg_grant_resp > resp {:?}", resp);
+        let resp: Response = serde_json::from_str(&resp).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after sending MsgGrant request"
+        )?;
+        let res = self.cheqd_ledger_service.authz_parse_msg_grant_resp(&resp)?;
+        let res = serde_json::to_string(&res).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize structure for MsgGrant Response"
+        )?;
+        trace!("authz_parse_msg_grant_resp < {:?}", res);
+        Ok(res)
+    }
+
+    pub(crate) fn authz_build_msg_exec(&self, grantee: &str, msg: &[u8]) -> IndyResult<Vec<u8>> {
+        trace!("authz_build_msg_exec > grantee {:?} msg {:?}", grantee, msg);
+        let msg = Msg::from_bytes(&msg)?;
+        let msg = self
+            .cheqd_ledger_service
+            .authz_build_msg_exec(grantee, vec![msg])?;
+        trace!("authz_build_msg_exec < {:?}", msg);
+
+        Ok(msg.to_bytes()?)
+    }
+
+    pub(crate) fn authz_parse_msg_exec_resp(&self, resp: &str) -> IndyResult<String> {
+        trace!("authz_parse_msg_exec_resp > resp {:?}", resp);
+        let resp: Response = serde_json::from_str(&resp).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after sending MsgExec request"
+        )?;
+        let res = self.cheqd_ledger_service.authz_parse_msg_exec_resp(&resp)?;
+        let res = serde_json::to_string(&res).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize structure for MsgExec Response"
+        )?;
+        trace!("authz_parse_msg_exec_resp < {:?}", res);
+        Ok(res)
+    }
+
+    pub(crate) fn authz_build_msg_revoke(
+        &self,
+        granter: &str,
+        grantee: &str,
+        msg_type_url: &str,
+    ) -> IndyResult<Vec<u8>> {
+        trace!(
+            "authz_build_msg_revoke > granter {:?} grantee {:?} msg_type_url {:?}",
+            granter, grantee, msg_type_url
+        );
+        let msg = self
+            .cheqd_ledger_service
+            .authz_build_msg_revoke(granter, grantee, msg_type_url)?;
+        trace!("authz_build_msg_revoke < {:?}", msg);
+
+        Ok(msg.to_bytes()?)
+    }
+
+    pub(crate) fn authz_parse_msg_revoke_resp(&self, resp: &str) -> IndyResult<String> {
+        trace!("authz_parse_msg_revoke_resp > resp {:?}", resp);
+        let resp: Response = serde_json::from_str(&resp).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after sending MsgRevoke request"
+        )?;
+        let res = self.cheqd_ledger_service.authz_parse_msg_revoke_resp(&resp)?;
+        let res = serde_json::to_string(&res).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize structure for MsgRevoke Response"
+        )?;
+        trace!("authz_parse_msg_revoke_resp < {:?}", res);
+        Ok(res)
+    }
+}
@@ -0,0 +1,77 @@
+use crate::controllers::CheqdLedgerController;
+use indy_api_types::errors::{IndyResult, IndyErrorKind, IndyResultExt};
+use crate::domain::cheqd_ledger::cosmos_ext::CosmosMsgExt;
+use cosmrs::rpc::endpoint::abci_query::Response as QueryResponse;
+use cosmrs::rpc::endpoint::broadcast::tx_commit::Response;
+
+impl CheqdLedgerController {
+    pub(crate) fn distribution_build_msg_withdraw_delegator_reward(
+        &self,
+        delegator_address: &str,
+        validator_address: &str,
+    ) -> IndyResult<Vec<u8>> {
+        trace!(
+            "distribution_build_msg_withdraw_delegator_reward > delegator_address {:?} validator_address {:?}",
+            delegator_address, validator_address
+        );
+        let msg = self
+            .cheqd_ledger_service
+            .distribution_build_msg_withdraw_delegator_reward(delegator_address, validator_address)?;
+        trace!("distribution_build_msg_withdraw_delegator_reward < {:?}", msg);
+
+        Ok(msg.to_bytes()?)
+    }
+
+    pub(crate) fn distribution_parse_msg_withdraw_delegator_reward_resp(&self, resp: &str) -> IndyResult<String> {
+        trace!("distribution_parse_msg_withdraw_delegator_reward_resp > resp {:?}", resp);
+        let resp: Response = serde_json::from_str(&resp).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after sending MsgWithdrawDelegatorReward request"
+        )?;
+        let res = self
+            .cheqd_ledger_service
+            .distribution_parse_msg_withdraw_delegator_reward_resp(&resp)?;
+        let res = serde_json::to_string(&res).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize structure for MsgWithdrawDelegatorReward Response"
+        )?;
+        trace!("distribution_parse_msg_withdraw_delegator_reward_resp < {:?}", res);
+        Ok(res)
+    }
+
+    pub(crate) fn distribution_build_query_delegation_total_rewards(
+        &self,
+        delegator_address: &str,
+    ) -> IndyResult<String> {
+        trace!(
+            "distribution_build_query_delegation_total_rewards > delegator_address {:?}",
+            delegator_address
+        );
+        let query = self
+            .cheqd_ledger_service
+            .distribution_build_query_delegation_total_rewards(delegator_address.to_string())?;
+        let json = serde_json::to_string(&query).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize request for QueryDelegationTotalRewards object"
+        )?;
+        trace!("distribution_build_query_delegation_total_rewards < {:?}", query);
+        Ok(json)
+    }
+
+    pub(crate) fn distribution_parse_query_delegation_total_rewards_resp(&self, resp_json: &str) -> IndyResult<String> {
+        trace!("distribution_parse_query_delegation_total_rewards_resp > resp {:?}", resp_json);
+        let resp: QueryResponse = serde_json::from_str(resp_json).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after QueryDelegationTotalRewards into internal object"
+        )?;
+        let result = self
+            .cheqd_ledger_service
+            .distribution_parse_query_delegation_total_rewards_resp(&resp)?;
+        let json_result = serde_json::to_string(&result).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize QueryDelegationTotalRewardsResponse object"
+        )?;
+        trace!("distribution_parse_query_delegation_total_rewards_resp < {:?}", json_result);
+        Ok(json_result)
+    }
+}
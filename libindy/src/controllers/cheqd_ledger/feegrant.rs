@@ -0,0 +1,83 @@
+use crate::controllers::CheqdLedgerController;
+use indy_api_types::errors::{IndyResult, IndyErrorKind, IndyResultExt};
+use crate::domain::cheqd_ledger::cosmos_ext::CosmosMsgExt;
+use crate::utils::environment;
+use cosmrs::rpc::endpoint::broadcast::tx_commit::Response;
+
+impl CheqdLedgerController {
+    pub(crate) fn feegrant_build_msg_grant_allowance(
+        &self,
+        granter: &str,
+        grantee: &str,
+        spend_limit_amount: Option<&str>,
+        spend_limit_denom: Option<&str>,
+        expiration: Option<i64>,
+    ) -> IndyResult<Vec<u8>> {
+        trace!(
+            "feegrant_build_msg_grant_allowance > granter {:?} grantee {:?} spend_limit_amount {:?} \
+            spend_limit_denom {:?} expiration {:?}",
+            granter, grantee, spend_limit_amount, spend_limit_denom, expiration
+        );
+        let denom = spend_limit_denom
+            .map(String::from)
+            .unwrap_or_else(environment::cheqd_denom);
+        let spend_limit = spend_limit_amount.map(|amount| (amount, denom.as_str()));
+
+        let msg = self.cheqd_ledger_service.feegrant_build_msg_grant_allowance(
+            granter,
+            grantee,
+            spend_limit,
+            expiration,
+        )?;
+        trace!("feegrant_build_msg_grant_allowance < {:?}", msg);
+
+        Ok(msg.to_bytes()?)
+    }
+
+    pub(crate) fn feegrant_parse_msg_grant_allowance_resp(&self, resp: &str) -> IndyResult<String> {
+        trace!("feegrant_parse_msg_grant_allowance_resp > resp {:?}", resp);
+        let resp: Response = serde_json::from_str(&resp).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after sending MsgGrantAllowance request"
+        )?;
+        let res = self.cheqd_ledger_service.feegrant_parse_msg_grant_allowance_resp(&resp)?;
+        let res = serde_json::to_string(&res).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize structure for MsgGrantAllowance Response"
+        )?;
+        trace!("feegrant_parse_msg_grant_allowance_resp < {:?}", res);
+        Ok(res)
+    }
+
+    pub(crate) fn feegrant_build_msg_revoke_allowance(
+        &self,
+        granter: &str,
+        grantee: &str,
+    ) -> IndyResult<Vec<u8>> {
+        trace!(
+            "feegrant_build_msg_revoke_allowance > granter {:?} grantee {:?}",
+            granter, grantee
+        );
+        let msg = self
+            .cheqd_ledger_service
+            .feegrant_build_msg_revoke_allowance(granter, grantee)?;
+        trace!("feegrant_build_msg_revoke_allowance < {:?}", msg);
+
+        Ok(msg.to_bytes()?)
+    }
+
+    pub(crate) fn feegrant_parse_msg_revoke_allowance_resp(&self, resp: &str) -> IndyResult<String> {
+        trace!("feegrant_parse_msg_revoke_allowance_resp > resp {:?}", resp);
+        let resp: Response = serde_json::from_str(&resp).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after sending MsgRevokeAllowance request"
+        )?;
+        let res = self.cheqd_ledger_service.feegrant_parse_msg_revoke_allowance_resp(&resp)?;
+        let res = serde_json::to_string(&res).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize structure for MsgRevokeAllowance Response"
+        )?;
+        trace!("feegrant_parse_msg_revoke_allowance_resp < {:?}", res);
+        Ok(res)
+    }
+}
@@ -0,0 +1,297 @@
+use crate::controllers::CheqdLedgerController;
+use indy_api_types::errors::{IndyResult, IndyErrorKind, IndyResultExt};
+use crate::domain::cheqd_ledger::cosmos_ext::CosmosMsgExt;
+use crate::domain::cheqd_ledger::gov::{ParamChange, WeightedVote};
+use cosmrs::rpc::endpoint::abci_query::Response as QueryResponse;
+use cosmrs::rpc::endpoint::broadcast::tx_commit::Response;
+
+impl CheqdLedgerController {
+    pub(crate) fn gov_build_msg_submit_text_proposal(
+        &self,
+        title: &str,
+        description: &str,
+        proposer: &str,
+        deposit_amount: &str,
+        deposit_denom: &str,
+    ) -> IndyResult<Vec<u8>> {
+        trace!(
+            "gov_build_msg_submit_text_proposal > title {:?} description {:?} proposer {:?} \
+            deposit_amount {:?} deposit_denom {:?}",
+            title, description, proposer, deposit_amount, deposit_denom
+        );
+        let msg = self.cheqd_ledger_service.gov_build_msg_submit_text_proposal(
+            title,
+            description,
+            proposer,
+            deposit_amount,
+            deposit_denom,
+        )?;
+        trace!("gov_build_msg_submit_text_proposal < {:?}", msg);
+
+        Ok(msg.to_bytes()?)
+    }
+
+    pub(crate) fn gov_build_msg_submit_param_change_proposal(
+        &self,
+        title: &str,
+        description: &str,
+        changes_json: &str,
+        proposer: &str,
+        deposit_amount: &str,
+        deposit_denom: &str,
+    ) -> IndyResult<Vec<u8>> {
+        trace!(
+            "gov_build_msg_submit_param_change_proposal > title {:?} description {:?} changes_json {:?} \
+            proposer {:?} deposit_amount {:?} deposit_denom {:?}",
+            title, description, changes_json, proposer, deposit_amount, deposit_denom
+        );
+        let changes: Vec<ParamChange> = serde_json::from_str(changes_json).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize list of parameter changes"
+        )?;
+        let msg = self.cheqd_ledger_service.gov_build_msg_submit_param_change_proposal(
+            title,
+            description,
+            changes,
+            proposer,
+            deposit_amount,
+            deposit_denom,
+        )?;
+        trace!("gov_build_msg_submit_param_change_proposal < {:?}", msg);
+
+        Ok(msg.to_bytes()?)
+    }
+
+    pub(crate) fn gov_parse_msg_submit_proposal_resp(&self, resp: &str) -> IndyResult<String> {
+        trace!("gov_parse_msg_submit_proposal_resp > resp {:?}", resp);
+        let resp: Response = serde_json::from_str(&resp).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after sending MsgSubmitProposal request"
+        )?;
+        let res = self.cheqd_ledger_service.gov_parse_msg_submit_proposal_resp(&resp)?;
+        let res = serde_json::to_string(&res).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize structure for MsgSubmitProposal Response"
+        )?;
+        trace!("gov_parse_msg_submit_proposal_resp < {:?}", res);
+        Ok(res)
+    }
+
+    pub(crate) fn gov_build_msg_deposit(
+        &self,
+        proposal_id: u64,
+        depositor: &str,
+        amount: &str,
+        denom: &str,
+    ) -> IndyResult<Vec<u8>> {
+        trace!(
+            "gov_build_msg_deposit > proposal_id {:?} depositor {:?} amount {:?} denom {:?}",
+            proposal_id, depositor, amount, denom
+        );
+        let msg = self
+            .cheqd_ledger_service
+            .gov_build_msg_deposit(proposal_id, depositor, amount, denom)?;
+        trace!("gov_build_msg_deposit < {:?}", msg);
+
+        Ok(msg.to_bytes()?)
+    }
+
+    pub(crate) fn gov_parse_msg_deposit_resp(&self, resp: &str) -> IndyResult<String> {
+        trace!("gov_parse_msg_deposit_resp > resp {:?}", resp);
+        let resp: Response = serde_json::from_str(&resp).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after sending MsgDeposit request"
+        )?;
+        let res = self.cheqd_ledger_service.gov_parse_msg_deposit_resp(&resp)?;
+        let res = serde_json::to_string(&res).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize structure for MsgDeposit Response"
+        )?;
+        trace!("gov_parse_msg_deposit_resp < {:?}", res);
+        Ok(res)
+    }
+
+    pub(crate) fn gov_build_msg_vote(
+        &self,
+        proposal_id: u64,
+        voter: &str,
+        option: &str,
+    ) -> IndyResult<Vec<u8>> {
+        trace!(
+            "gov_build_msg_vote > proposal_id {:?} voter {:?} option {:?}",
+            proposal_id, voter, option
+        );
+        let msg = self
+            .cheqd_ledger_service
+            .gov_build_msg_vote(proposal_id, voter, option)?;
+        trace!("gov_build_msg_vote < {:?}", msg);
+
+        Ok(msg.to_bytes()?)
+    }
+
+    pub(crate) fn gov_parse_msg_vote_resp(&self, resp: &str) -> IndyResult<String> {
+        trace!("gov_parse_msg_vote_resp > resp {:?}", resp);
+        let resp: Response = serde_json::from_str(&resp).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after sending MsgVote request"
+        )?;
+        let res = self.cheqd_ledger_service.gov_parse_msg_vote_resp(&resp)?;
+        let res = serde_json::to_string(&res).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize structure for MsgVote Response"
+        )?;
+        trace!("gov_parse_msg_vote_resp < {:?}", res);
+        Ok(res)
+    }
+
+    pub(crate) fn gov_build_msg_vote_weighted(
+        &self,
+        proposal_id: u64,
+        voter: &str,
+        options_json: &str,
+    ) -> IndyResult<Vec<u8>> {
+        trace!(
+            "gov_build_msg_vote_weighted > proposal_id {:?} voter {:?} options_json {:?}",
+            proposal_id, voter, options_json
+        );
+        let options: Vec<WeightedVote> = serde_json::from_str(options_json).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize list of weighted vote options"
+        )?;
+        let msg = self
+            .cheqd_ledger_service
+            .gov_build_msg_vote_weighted(proposal_id, voter, options)?;
+        trace!("gov_build_msg_vote_weighted < {:?}", msg);
+
+        Ok(msg.to_bytes()?)
+    }
+
+    pub(crate) fn gov_parse_msg_vote_weighted_resp(&self, resp: &str) -> IndyResult<String> {
+        trace!("gov_parse_msg_vote_weighted_resp > resp {:?}", resp);
+        let resp: Response = serde_json::from_str(&resp).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after sending MsgVoteWeighted request"
+        )?;
+        let res = self.cheqd_ledger_service.gov_parse_msg_vote_weighted_resp(&resp)?;
+        let res = serde_json::to_string(&res).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize structure for MsgVoteWeighted Response"
+        )?;
+        trace!("gov_parse_msg_vote_weighted_resp < {:?}", res);
+        Ok(res)
+    }
+
+    pub(crate) fn gov_build_query_proposal(&self, proposal_id: u64) -> IndyResult<String> {
+        trace!("gov_build_query_proposal > proposal_id {:?}", proposal_id);
+        let query = self.cheqd_ledger_service.gov_build_query_proposal(proposal_id)?;
+        let json = serde_json::to_string(&query).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize request for QueryProposal object"
+        )?;
+        trace!("gov_build_query_proposal < {:?}", query);
+        Ok(json)
+    }
+
+    pub(crate) fn gov_parse_query_proposal_resp(&self, resp_json: &str) -> IndyResult<String> {
+        trace!("gov_parse_query_proposal_resp > resp {:?}", resp_json);
+        let resp: QueryResponse = serde_json::from_str(resp_json).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after QueryProposal into internal object"
+        )?;
+        let result = self.cheqd_ledger_service.gov_parse_query_proposal_resp(&resp)?;
+        let json_result = serde_json::to_string(&result).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize QueryProposalResponse object"
+        )?;
+        trace!("gov_parse_query_proposal_resp < {:?}", json_result);
+        Ok(json_result)
+    }
+
+    pub(crate) fn gov_build_query_proposals(
+        &self,
+        status: Option<&str>,
+        pagination_json: Option<&str>,
+    ) -> IndyResult<String> {
+        trace!(
+            "gov_build_query_proposals > status {:?} pagination_json {:?}",
+            status, pagination_json
+        );
+        let pagination = Self::parse_pagination(pagination_json)?;
+        let query = self
+            .cheqd_ledger_service
+            .gov_build_query_proposals(status, pagination)?;
+        let json = serde_json::to_string(&query).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize request for QueryProposals object"
+        )?;
+        trace!("gov_build_query_proposals < {:?}", query);
+        Ok(json)
+    }
+
+    pub(crate) fn gov_parse_query_proposals_resp(&self, resp_json: &str) -> IndyResult<String> {
+        trace!("gov_parse_query_proposals_resp > resp {:?}", resp_json);
+        let resp: QueryResponse = serde_json::from_str(resp_json).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after QueryProposals into internal object"
+        )?;
+        let result = self.cheqd_ledger_service.gov_parse_query_proposals_resp(&resp)?;
+        let json_result = serde_json::to_string(&result).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize QueryProposalsResponse object"
+        )?;
+        trace!("gov_parse_query_proposals_resp < {:?}", json_result);
+        Ok(json_result)
+    }
+
+    pub(crate) fn gov_build_query_tally_result(&self, proposal_id: u64) -> IndyResult<String> {
+        trace!("gov_build_query_tally_result > proposal_id {:?}", proposal_id);
+        let query = self.cheqd_ledger_service.gov_build_query_tally_result(proposal_id)?;
+        let json = serde_json::to_string(&query).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize request for QueryTallyResult object"
+        )?;
+        trace!("gov_build_query_tally_result < {:?}", query);
+        Ok(json)
+    }
+
+    pub(crate) fn gov_parse_query_tally_result_resp(&self, resp_json: &str) -> IndyResult<String> {
+        trace!("gov_parse_query_tally_result_resp > resp {:?}", resp_json);
+        let resp: QueryResponse = serde_json::from_str(resp_json).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after QueryTallyResult into internal object"
+        )?;
+        let result = self.cheqd_ledger_service.gov_parse_query_tally_result_resp(&resp)?;
+        let json_result = serde_json::to_string(&result).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize QueryTallyResultResponse object"
+        )?;
+        trace!("gov_parse_query_tally_result_resp < {:?}", json_result);
+        Ok(json_result)
+    }
+
+    pub(crate) fn gov_build_query_vote(&self, proposal_id: u64, voter: &str) -> IndyResult<String> {
+        trace!("gov_build_query_vote > proposal_id {:?} voter {:?}", proposal_id, voter);
+        let query = self.cheqd_ledger_service.gov_build_query_vote(proposal_id, voter)?;
+        let json = serde_json::to_string(&query).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize request for QueryVote object"
+        )?;
+        trace!("gov_build_query_vote < {:?}", query);
+        Ok(json)
+    }
+
+    pub(crate) fn gov_parse_query_vote_resp(&self, resp_json: &str) -> IndyResult<String> {
+        trace!("gov_parse_query_vote_resp > resp {:?}", resp_json);
+        let resp: QueryResponse = serde_json::from_str(resp_json).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after QueryVote into internal object"
+        )?;
+        let result = self.cheqd_ledger_service.gov_parse_query_vote_resp(&resp)?;
+        let json_result = serde_json::to_string(&result).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize QueryVoteResponse object"
+        )?;
+        trace!("gov_parse_query_vote_resp < {:?}", json_result);
+        Ok(json_result)
+    }
+}
@@ -7,6 +7,11 @@ use crate::services::{CheqdLedgerService, CheqdPoolService};
 mod cheqd;
 mod auth;
 mod bank;
+mod staking;
+mod distribution;
+mod gov;
+mod feegrant;
+mod authz;
 
 pub(crate) struct CheqdLedgerController {
     cheqd_ledger_service: Arc<CheqdLedgerService>,
@@ -0,0 +1,240 @@
+use crate::controllers::CheqdLedgerController;
+use indy_api_types::errors::{IndyResult, IndyErrorKind, IndyResultExt};
+use crate::domain::cheqd_ledger::base::query::PageRequest;
+use crate::domain::cheqd_ledger::cosmos_ext::CosmosMsgExt;
+use cosmrs::rpc::endpoint::abci_query::Response as QueryResponse;
+use cosmrs::rpc::endpoint::broadcast::tx_commit::Response;
+
+impl CheqdLedgerController {
+    pub(crate) fn staking_build_msg_delegate(
+        &self,
+        delegator_address: &str,
+        validator_address: &str,
+        amount: &str,
+        denom: &str,
+    ) -> IndyResult<Vec<u8>> {
+        trace!(
+            "staking_build_msg_delegate > delegator_address {:?} validator_address {:?} amount {:?} denom {:?}",
+            delegator_address, validator_address, amount, denom
+        );
+        let msg = self
+            .cheqd_ledger_service
+            .staking_build_msg_delegate(delegator_address, validator_address, amount, denom)?;
+        trace!("staking_build_msg_delegate < {:?}", msg);
+
+        Ok(msg.to_bytes()?)
+    }
+
+    pub(crate) fn staking_parse_msg_delegate_resp(&self, resp: &str) -> IndyResult<String> {
+        trace!("staking_parse_msg_delegate_resp > resp {:?}", resp);
+        let resp: Response = serde_json::from_str(&resp).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after sending MsgDelegate request"
+        )?;
+        let res = self.cheqd_ledger_service.staking_parse_msg_delegate_resp(&resp)?;
+        let res = serde_json::to_string(&res).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize structure for MsgDelegate Response"
+        )?;
+        trace!("staking_parse_msg_delegate_resp < {:?}", res);
+        Ok(res)
+    }
+
+    pub(crate) fn staking_build_msg_undelegate(
+        &self,
+        delegator_address: &str,
+        validator_address: &str,
+        amount: &str,
+        denom: &str,
+    ) -> IndyResult<Vec<u8>> {
+        trace!(
+            "staking_build_msg_undelegate > delegator_address {:?} validator_address {:?} amount {:?} denom {:?}",
+            delegator_address, validator_address, amount, denom
+        );
+        let msg = self
+            .cheqd_ledger_service
+            .staking_build_msg_undelegate(delegator_address, validator_address, amount, denom)?;
+        trace!("staking_build_msg_undelegate < {:?}", msg);
+
+        Ok(msg.to_bytes()?)
+    }
+
+    pub(crate) fn staking_parse_msg_undelegate_resp(&self, resp: &str) -> IndyResult<String> {
+        trace!("staking_parse_msg_undelegate_resp > resp {:?}", resp);
+        let resp: Response = serde_json::from_str(&resp).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after sending MsgUndelegate request"
+        )?;
+        let res = self.cheqd_ledger_service.staking_parse_msg_undelegate_resp(&resp)?;
+        let res = serde_json::to_string(&res).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize structure for MsgUndelegate Response"
+        )?;
+        trace!("staking_parse_msg_undelegate_resp < {:?}", res);
+        Ok(res)
+    }
+
+    pub(crate) fn staking_build_msg_begin_redelegate(
+        &self,
+        delegator_address: &str,
+        validator_src_address: &str,
+        validator_dst_address: &str,
+        amount: &str,
+        denom: &str,
+    ) -> IndyResult<Vec<u8>> {
+        trace!(
+            "staking_build_msg_begin_redelegate > delegator_address {:?} validator_src_address {:?} \
+            validator_dst_address {:?} amount {:?} denom {:?}",
+            delegator_address, validator_src_address, validator_dst_address, amount, denom
+        );
+        let msg = self
+            .cheqd_ledger_service
+            .staking_build_msg_begin_redelegate(
+                delegator_address,
+                validator_src_address,
+                validator_dst_address,
+                amount,
+                denom,
+            )?;
+        trace!("staking_build_msg_begin_redelegate < {:?}", msg);
+
+        Ok(msg.to_bytes()?)
+    }
+
+    pub(crate) fn staking_parse_msg_begin_redelegate_resp(&self, resp: &str) -> IndyResult<String> {
+        trace!("staking_parse_msg_begin_redelegate_resp > resp {:?}", resp);
+        let resp: Response = serde_json::from_str(&resp).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after sending MsgBeginRedelegate request"
+        )?;
+        let res = self.cheqd_ledger_service.staking_parse_msg_begin_redelegate_resp(&resp)?;
+        let res = serde_json::to_string(&res).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize structure for MsgBeginRedelegate Response"
+        )?;
+        trace!("staking_parse_msg_begin_redelegate_resp < {:?}", res);
+        Ok(res)
+    }
+
+    pub(crate) fn staking_build_query_delegation(
+        &self,
+        delegator_address: &str,
+        validator_address: &str,
+    ) -> IndyResult<String> {
+        trace!(
+            "staking_build_query_delegation > delegator_address {:?} validator_address {:?}",
+            delegator_address, validator_address
+        );
+        let query = self.cheqd_ledger_service.staking_build_query_delegation(
+            delegator_address.to_string(),
+            validator_address.to_string(),
+        )?;
+        let json = serde_json::to_string(&query).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize request for QueryDelegation object"
+        )?;
+        trace!("staking_build_query_delegation < {:?}", query);
+        Ok(json)
+    }
+
+    pub(crate) fn staking_parse_query_delegation_resp(&self, resp_json: &str) -> IndyResult<String> {
+        trace!("staking_parse_query_delegation_resp > resp {:?}", resp_json);
+        let resp: QueryResponse = serde_json::from_str(resp_json).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after QueryDelegation into internal object"
+        )?;
+        let result = self.cheqd_ledger_service.staking_parse_query_delegation_resp(&resp)?;
+        let json_result = serde_json::to_string(&result).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize QueryDelegationResponse object"
+        )?;
+        trace!("staking_parse_query_delegation_resp < {:?}", json_result);
+        Ok(json_result)
+    }
+
+    pub(crate) fn staking_build_query_delegator_delegations(
+        &self,
+        delegator_address: &str,
+        pagination_json: Option<&str>,
+    ) -> IndyResult<String> {
+        trace!(
+            "staking_build_query_delegator_delegations > delegator_address {:?} pagination_json {:?}",
+            delegator_address, pagination_json
+        );
+        let pagination = Self::parse_pagination(pagination_json)?;
+        let query = self
+            .cheqd_ledger_service
+            .staking_build_query_delegator_delegations(delegator_address.to_string(), pagination)?;
+        let json = serde_json::to_string(&query).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize request for QueryDelegatorDelegations object"
+        )?;
+        trace!("staking_build_query_delegator_delegations < {:?}", query);
+        Ok(json)
+    }
+
+    pub(crate) fn staking_parse_query_delegator_delegations_resp(&self, resp_json: &str) -> IndyResult<String> {
+        trace!("staking_parse_query_delegator_delegations_resp > resp {:?}", resp_json);
+        let resp: QueryResponse = serde_json::from_str(resp_json).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after QueryDelegatorDelegations into internal object"
+        )?;
+        let result = self
+            .cheqd_ledger_service
+            .staking_parse_query_delegator_delegations_resp(&resp)?;
+        let json_result = serde_json::to_string(&result).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize QueryDelegatorDelegationsResponse object"
+        )?;
+        trace!("staking_parse_query_delegator_delegations_resp < {:?}", json_result);
+        Ok(json_result)
+    }
+
+    pub(crate) fn staking_build_query_validators(
+        &self,
+        status: Option<&str>,
+        pagination_json: Option<&str>,
+    ) -> IndyResult<String> {
+        trace!(
+            "staking_build_query_validators > status {:?} pagination_json {:?}",
+            status, pagination_json
+        );
+        let pagination = Self::parse_pagination(pagination_json)?;
+        let query = self.cheqd_ledger_service.staking_build_query_validators(
+            status.unwrap_or_default().to_string(),
+            pagination,
+        )?;
+        let json = serde_json::to_string(&query).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize request for QueryValidators object"
+        )?;
+        trace!("staking_build_query_validators < {:?}", query);
+        Ok(json)
+    }
+
+    pub(crate) fn staking_parse_query_validators_resp(&self, resp_json: &str) -> IndyResult<String> {
+        trace!("staking_parse_query_validators_resp > resp {:?}", resp_json);
+        let resp: QueryResponse = serde_json::from_str(resp_json).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after QueryValidators into internal object"
+        )?;
+        let result = self.cheqd_ledger_service.staking_parse_query_validators_resp(&resp)?;
+        let json_result = serde_json::to_string(&result).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize QueryValidatorsResponse object"
+        )?;
+        trace!("staking_parse_query_validators_resp < {:?}", json_result);
+        Ok(json_result)
+    }
+
+    pub(crate) fn parse_pagination(pagination_json: Option<&str>) -> IndyResult<Option<PageRequest>> {
+        pagination_json
+            .map(|pagination| {
+                serde_json::from_str::<PageRequest>(pagination).to_indy(
+                    IndyErrorKind::InvalidStructure,
+                    "Cannot deserialize PageRequest object"
+                )
+            })
+            .transpose()
+    }
+}
@@ -113,4 +113,49 @@ impl CheqdPoolController {
         )?;
         Ok(json_resp)
     }
+
+    pub(crate) async fn status(&self, pool_alias: &str) -> IndyResult<String> {
+        let resp = self.cheqd_pool_service.status(pool_alias).await?;
+        let json_resp = serde_json::to_string(&resp).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize Response after sending status request"
+        )?;
+        Ok(json_resp)
+    }
+
+    pub(crate) async fn block(&self, pool_alias: &str, height: Option<u64>) -> IndyResult<String> {
+        let resp = self.cheqd_pool_service.block(pool_alias, height).await?;
+        let json_resp = serde_json::to_string(&resp).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize Response after sending block request"
+        )?;
+        Ok(json_resp)
+    }
+
+    pub(crate) async fn block_results(&self, pool_alias: &str, height: Option<u64>) -> IndyResult<String> {
+        let resp = self.cheqd_pool_service.block_results(pool_alias, height).await?;
+        let json_resp = serde_json::to_string(&resp).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize Response after sending block results request"
+        )?;
+        Ok(json_resp)
+    }
+
+    pub(crate) async fn validators(&self, pool_alias: &str, height: Option<u64>, page: Option<u64>, per_page: Option<u64>) -> IndyResult<String> {
+        let resp = self.cheqd_pool_service.validators(pool_alias, height, page, per_page).await?;
+        let json_resp = serde_json::to_string(&resp).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize Response after sending validators request"
+        )?;
+        Ok(json_resp)
+    }
+
+    pub(crate) async fn net_info(&self, pool_alias: &str) -> IndyResult<String> {
+        let resp = self.cheqd_pool_service.net_info(pool_alias).await?;
+        let json_resp = serde_json::to_string(&resp).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize Response after sending net info request"
+        )?;
+        Ok(json_resp)
+    }
 }
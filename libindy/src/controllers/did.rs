@@ -1,6 +1,6 @@
 use std::{collections::HashMap, sync::Arc};
 
-use indy_api_types::{errors::prelude::*, PoolHandle, WalletHandle};
+use indy_api_types::{errors::prelude::*, PoolHandle, WalletHandle, INVALID_POOL_HANDLE};
 use indy_wallet::{RecordOptions, SearchOptions, WalletService};
 use rust_base58::{FromBase58, ToBase58};
 
@@ -12,6 +12,7 @@ use crate::{
     domain::crypto::key::KeyInfo,
     domain::{
         ledger::attrib::{AttribData, Endpoint, GetAttrReplyResult},
+        ledger::ddo::{DidDocumentMetadata, DidResolutionResult, LedgerObjectMetadata},
         ledger::nym::{GetNymReplyResult, GetNymResultDataV0},
         ledger::response::Reply,
         pairwise::Pairwise,
@@ -154,13 +155,13 @@ impl DidController {
 
         let my_did = Did::from(my_temporary_did);
 
-        self.wallet_service
-            .update_indy_object(wallet_handle, &my_did.did.0, &my_did)
-            .await?;
+        // Key replacement and temporary DID removal must not be applied partially
+        let operations = vec![
+            self.wallet_service.update_indy_object_operation(&my_did.did.0, &my_did)?,
+            self.wallet_service.delete_indy_record_operation::<TemporaryDid>(&my_did.did.0),
+        ];
 
-        self.wallet_service
-            .delete_indy_record::<TemporaryDid>(wallet_handle, &my_did.did.0)
-            .await?;
+        self.wallet_service.batch(wallet_handle, &operations).await?;
 
         let res = Ok(());
         trace!("replace_keys_apply < {:?}", res);
@@ -470,6 +471,92 @@ impl DidController {
         res
     }
 
+    pub(crate) async fn resolve_did(
+        &self,
+        pool_handle: PoolHandle,
+        did: DidValue,
+    ) -> IndyResult<String> {
+        trace!(
+            "resolve_did > pool_handle {:?} did {:?}",
+            pool_handle, did
+        );
+
+        self.crypto_service.validate_did(&did)?;
+
+        let pool_handle = match did.indy_namespace() {
+            Some(namespace) if pool_handle == INVALID_POOL_HANDLE => {
+                self.pool_service
+                    .get_pool_handle_for_namespace(&namespace)
+                    .await?
+            }
+            _ => pool_handle,
+        };
+
+        let get_nym_request = self.ledger_service.build_get_nym_request(None, &did)?;
+
+        let get_nym_response = self
+            .pool_service
+            .send_tx(pool_handle, &get_nym_request)
+            .await?;
+
+        let (mut did_doc, diddoc_content) = self
+            .ledger_service
+            .parse_get_nym_response_to_did_document(&did, &get_nym_response)?;
+
+        let nym_metadata = self._ledger_object_metadata(&get_nym_response)?;
+
+        // Endpoint ATTRIB is used only for DIDs without DID document content on NYM
+        let (did_doc, endpoint_metadata) = match diddoc_content {
+            Some(diddoc_content) => (
+                self.ledger_service
+                    .merge_diddoc_content(&did_doc, diddoc_content)?,
+                None,
+            ),
+            None => {
+                let get_attrib_request = self.ledger_service.build_get_attrib_request(
+                    None,
+                    &did,
+                    Some("endpoint"),
+                    None,
+                    None,
+                )?;
+
+                let get_attrib_response = self
+                    .pool_service
+                    .send_tx(pool_handle, &get_attrib_request)
+                    .await?;
+
+                let endpoint_metadata = if self
+                    .ledger_service
+                    .add_endpoint_services(&mut did_doc, &get_attrib_response)?
+                {
+                    Some(self._ledger_object_metadata(&get_attrib_response)?)
+                } else {
+                    None
+                };
+
+                let did_doc = serde_json::to_value(&did_doc)
+                    .to_indy(IndyErrorKind::InvalidState, "Can't serialize DID document")?;
+
+                (did_doc, endpoint_metadata)
+            }
+        };
+
+        let resolution_result = DidResolutionResult::new(
+            did_doc,
+            DidDocumentMetadata {
+                nym: nym_metadata,
+                endpoint: endpoint_metadata,
+            },
+        );
+
+        let res = serde_json::to_string(&resolution_result)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize DID resolution result")?;
+
+        trace!("resolve_did < {:?}", res);
+        Ok(res)
+    }
+
     pub(crate) async fn set_did_metadata(
         &self,
         wallet_handle: WalletHandle,
@@ -814,6 +901,13 @@ impl DidController {
         .await
     }
 
+    fn _ledger_object_metadata(&self, response: &str) -> IndyResult<LedgerObjectMetadata> {
+        Ok(LedgerObjectMetadata {
+            response_metadata: PoolService::parse_response_metadata(response)?,
+            state_proof: self.ledger_service.parse_state_proof_metadata(response)?,
+        })
+    }
+
     async fn _wallet_get_my_did(
         &self,
         wallet_handle: WalletHandle,
@@ -1,7 +1,11 @@
-use std::{string::ToString, sync::Arc};
+use std::{cmp, string::ToString, sync::Arc};
 
-use indy_api_types::{errors::prelude::*, PoolHandle, WalletHandle};
-use indy_wallet::{RecordOptions, WalletService};
+use futures::{future::join_all, stream::FuturesOrdered, StreamExt};
+
+use indy_api_types::{
+    errors::prelude::*, validation::Validatable, ErrorCode, PoolHandle, WalletHandle,
+};
+use indy_wallet::{RecordOptions, SearchOptions, WalletService};
 use rust_base58::ToBase58;
 use serde_json::{self, Value};
 
@@ -23,17 +27,32 @@ use crate::{
             key::Key,
         },
         ledger::{
-            auth_rule::{AuthRules, Constraint},
-            author_agreement::{AcceptanceMechanisms, GetTxnAuthorAgreementData},
+            auth_rule::{AuthRuleAction, AuthRules, Constraint},
+            author_agreement::{
+                AcceptanceMechanisms, GetTxnAuthorAgreementData, GetTxnAuthorAgreementResult,
+                PoolTxnAuthorAgreement,
+            },
+            constants::DOMAIN_LEDGER_WRITE_REQUESTS,
+            cred_def,
+            endorsement::{EndorsementEnvelopeOptions, EndorsementStatus, PendingEndorsement},
             node::NodeOperationData,
+            nym::NymData,
             pool::Schedule,
-            request::Request,
+            request::{ProtocolVersion, Request, SubmitRequestResult, SubmitRequestsOptions},
+            rich_schema::{RichSchema, RichSchemaType},
+            txn::{DownloadLedgerConfig, LedgerDownloadStatus},
         },
     },
-    services::{CryptoService, LedgerService, PoolService},
+    services::{
+        parse_get_txn_data, CryptoService, CustomStateProofParser, LedgerMirror, LedgerService,
+        PoolService, StateProofParser,
+    },
     utils::crypto::signature_serializer::serialize_signature,
 };
 
+/// How long (in sec) transaction author agreement read from the ledger is considered to be in force.
+const TXN_AUTHR_AGRMT_CHECK_INTERVAL: i64 = 600;
+
 enum SignatureType {
     Single,
     Multi,
@@ -61,7 +80,6 @@ impl LedgerController {
         }
     }
 
-    #[allow(dead_code)] // FIXME [async] TODO implement external SP parsers
     pub(crate) fn register_sp_parser(
         &self,
         txn_type: String,
@@ -73,10 +91,24 @@ impl LedgerController {
             txn_type, parser, free
         );
 
-        unimplemented!();
-        // FIXME: !!!
-        // PoolService::register_sp_parser(txn_type, parser, free)
-        //     .map_err(IndyError::from)
+        let parser = Arc::new(CustomStateProofParser::new(parser, free));
+        let res = self.register_state_proof_parser(&txn_type, parser);
+
+        debug!("register_sp_parser < {:?}", res);
+        res
+    }
+
+    pub(crate) fn register_state_proof_parser(
+        &self,
+        txn_type: &str,
+        parser: Arc<dyn StateProofParser>,
+    ) -> IndyResult<()> {
+        debug!("register_state_proof_parser > txn_type {:?}", txn_type);
+
+        let res = PoolService::register_sp_parser(txn_type, parser);
+
+        debug!("register_state_proof_parser < {:?}", res);
+        res
     }
 
     pub(crate) async fn sign_and_submit_request(
@@ -92,6 +124,14 @@ impl LedgerController {
             pool_handle, wallet_handle, submitter_did, request_json
         );
 
+        let request_json = self
+            ._apply_pool_protocol_version(pool_handle, request_json)
+            .await?;
+
+        let (request_json, taa_appended) = self
+            ._append_pool_taa_acceptance(pool_handle, request_json)
+            .await?;
+
         let signed_request = self
             ._sign_request(
                 wallet_handle,
@@ -105,6 +145,15 @@ impl LedgerController {
             ._submit_request(pool_handle, signed_request.as_str())
             .await?;
 
+        if taa_appended && Self::_is_taa_rejection(&res) {
+            // agreement could be changed on the ledger after it was read last time
+            self.pool_service.set_ledger_taa_digest(pool_handle, None).await;
+
+            if let Some(taa) = self.pool_service.get_taa_acceptance(pool_handle).await {
+                self._check_ledger_taa(pool_handle, &taa).await?;
+            }
+        }
+
         let res = Ok(res);
         debug!("sign_and_submit_request < {:?}", res);
         res
@@ -127,6 +176,55 @@ impl LedgerController {
         res
     }
 
+    pub(crate) async fn submit_requests(
+        &self,
+        handle: PoolHandle,
+        requests: Vec<Value>,
+        options: Option<SubmitRequestsOptions>,
+    ) -> IndyResult<String> {
+        debug!(
+            "submit_requests > handle {:?} requests {:?} options {:?}",
+            handle, requests, options
+        );
+
+        let options = options.unwrap_or_default();
+        let requests: Vec<String> = requests.iter().map(Value::to_string).collect();
+
+        let mut pending = requests.iter();
+        let mut in_flight = FuturesOrdered::new();
+        let mut results = Vec::with_capacity(requests.len());
+        let mut stopped = false;
+
+        loop {
+            while !stopped && in_flight.len() < options.max_concurrency {
+                match pending.next() {
+                    Some(request) => in_flight.push(self._submit_request(handle, request)),
+                    None => break,
+                }
+            }
+
+            // Requests already sent are awaited even after a failure as they can still be written.
+            let result = match in_flight.next().await {
+                Some(res) => Self::_submit_request_result(res),
+                None => break,
+            };
+
+            stopped |= options.stop_on_failure && result.is_failure();
+            results.push(result);
+        }
+
+        results.resize_with(requests.len(), || SubmitRequestResult::Skipped);
+
+        let res = serde_json::to_string(&results).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize submit results",
+        )?;
+
+        let res = Ok(res);
+        debug!("submit_requests < {:?}", res);
+        res
+    }
+
     pub(crate) async fn submit_action(
         &self,
         handle: PoolHandle,
@@ -151,6 +249,96 @@ impl LedgerController {
         res
     }
 
+    pub(crate) async fn download_ledger(
+        &self,
+        handle: PoolHandle,
+        ledger_type: String,
+        config: Option<DownloadLedgerConfig>,
+    ) -> IndyResult<String> {
+        debug!(
+            "download_ledger > handle {:?} ledger_type {:?} config {:?}",
+            handle, ledger_type, config
+        );
+
+        let config = config.unwrap_or_default();
+
+        let ledger_id = self.ledger_service.parse_ledger_id(Some(&ledger_type))?;
+        let pool_name = self.pool_service.get_pool_name(handle).await?;
+
+        let mut mirror = LedgerMirror::open(&pool_name, ledger_id)?;
+
+        // Unknown until the first reply: the first batch consists of a single request.
+        let mut ledger_size: Option<u64> = None;
+
+        loop {
+            let from = mirror.count() + 1;
+
+            let mut to = match ledger_size {
+                Some(ledger_size) => cmp::min(ledger_size, from + config.batch_size as u64 - 1),
+                None => from,
+            };
+
+            if let Some(limit) = config.to {
+                to = cmp::min(to, limit);
+            }
+
+            if from > to {
+                break;
+            }
+
+            let requests = (from..=to)
+                .map(|seq_no| {
+                    self.ledger_service
+                        .build_get_txn_request(None, Some(&ledger_type), seq_no as i32)
+                })
+                .collect::<IndyResult<Vec<String>>>()?;
+
+            let replies = join_all(
+                requests
+                    .iter()
+                    .map(|request| self.pool_service.send_tx(handle, request)),
+            )
+            .await;
+
+            let mut batch = Vec::with_capacity(replies.len());
+
+            for reply in replies {
+                match parse_get_txn_data(&reply?)? {
+                    Some(data) => batch.push(data),
+                    // the ledger is shorter than requested
+                    None => break,
+                }
+            }
+
+            let reached_end = batch.len() as u64 != to - from + 1;
+
+            if let Some(size) = batch.last().and_then(|data| data["ledgerSize"].as_u64()) {
+                ledger_size = Some(size);
+            }
+
+            mirror.append_batch(&batch)?;
+
+            if reached_end {
+                break;
+            }
+        }
+
+        let status = LedgerDownloadStatus {
+            ledger_id,
+            ledger_size: ledger_size.unwrap_or_else(|| mirror.count()),
+            txns_count: mirror.count(),
+            root_hash: mirror.root_hash(),
+            path: mirror.path(),
+        };
+
+        let res = serde_json::to_string(&status)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ledger download status")?;
+
+        let res = Ok(res);
+        debug!("download_ledger < {:?}", res);
+        res
+    }
+
     pub(crate) async fn sign_request(
         &self,
         wallet_handle: WalletHandle,
@@ -429,7 +617,7 @@ impl LedgerController {
 
         let res = self
             .ledger_service
-            .build_cred_def_request(&submitter_did, cred_def)?;
+            .build_cred_def_request(&submitter_did, cred_def, ProtocolVersion::get())?;
 
         let res = Ok(res);
         debug!("build_cred_def_request < {:?}", res);
@@ -517,6 +705,24 @@ impl LedgerController {
         res
     }
 
+    pub(crate) fn parse_get_validator_info_response(
+        &self,
+        get_validator_info_response: String,
+    ) -> IndyResult<String> {
+        debug!(
+            "parse_get_validator_info_response > get_validator_info_response {:?}",
+            get_validator_info_response
+        );
+
+        let res = self
+            .ledger_service
+            .parse_get_validator_info_response(&get_validator_info_response)?;
+
+        let res = Ok(res);
+        debug!("parse_get_validator_info_response < {:?}", res);
+        res
+    }
+
     pub(crate) fn build_get_txn_request(
         &self,
         submitter_did: Option<DidValue>,
@@ -915,6 +1121,36 @@ impl LedgerController {
         res
     }
 
+    pub(crate) fn evaluate_auth_rule(
+        &self,
+        get_auth_rule_response: String,
+        action: AuthRuleAction,
+        request_json: String,
+        signers: Vec<NymData>,
+    ) -> IndyResult<String> {
+        debug!(
+            "evaluate_auth_rule > get_auth_rule_response {:?} action {:?} \
+            request_json {:?} signers {:?}",
+            get_auth_rule_response, action, request_json, signers
+        );
+
+        let evaluation = self.ledger_service.evaluate_auth_rule(
+            &get_auth_rule_response,
+            &action,
+            &request_json,
+            &signers,
+        )?;
+
+        let res = serde_json::to_string(&evaluation).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize auth rule evaluation",
+        )?;
+
+        let res = Ok(res);
+        debug!("evaluate_auth_rule < {:?}", res);
+        res
+    }
+
     pub(crate) fn build_txn_author_agreement_request(
         &self,
         submitter_did: DidValue,
@@ -1045,6 +1281,38 @@ impl LedgerController {
         res
     }
 
+    pub(crate) async fn set_pool_txn_author_agreement_acceptance(
+        &self,
+        pool_handle: PoolHandle,
+        text: Option<String>,
+        version: Option<String>,
+        taa_digest: Option<String>,
+        acc_mech_type: String,
+        time: u64,
+    ) -> IndyResult<()> {
+        debug!(
+            "set_pool_txn_author_agreement_acceptance > pool_handle {:?} \
+                text {:?} version {:?} taa_digest {:?} acc_mech_type {:?} time {:?}",
+            pool_handle, text, version, taa_digest, acc_mech_type, time
+        );
+
+        let acceptance = self.ledger_service.prepare_acceptance_data(
+            text.as_deref(),
+            version.as_deref(),
+            taa_digest.as_deref(),
+            &acc_mech_type,
+            time,
+        )?;
+
+        self.pool_service
+            .set_taa_acceptance(pool_handle, acceptance)
+            .await?;
+
+        let res = Ok(());
+        debug!("set_pool_txn_author_agreement_acceptance < {:?}", res);
+        res
+    }
+
     pub(crate) fn append_txn_author_agreement_acceptance_to_request(
         &self,
         request_json: String,
@@ -1119,6 +1387,423 @@ impl LedgerController {
         res
     }
 
+    pub(crate) async fn build_endorsement_envelope(
+        &self,
+        wallet_handle: WalletHandle,
+        request_json: String,
+        endorser_did: DidValue,
+        options: Option<EndorsementEnvelopeOptions>,
+    ) -> IndyResult<String> {
+        debug!(
+            "build_endorsement_envelope > wallet_handle {:?} request_json {:?} \
+                endorser_did {:?} options {:?}",
+            wallet_handle, request_json, endorser_did, options
+        );
+
+        self.crypto_service.validate_did(&endorser_did)?;
+
+        let options = options.unwrap_or_default();
+        let endorser = endorser_did.to_short().0;
+
+        let mut request: Value = serde_json::from_str(&request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize request")?;
+
+        let (identifier, req_id) = match (request["identifier"].as_str(), request["reqId"].as_u64()) {
+            (Some(identifier), Some(req_id)) => (identifier.to_string(), req_id),
+            _ => {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidStructure,
+                    "Request must contain `identifier` and `reqId`",
+                ))
+            }
+        };
+
+        // Appending of the endorser changes signed data so previous signatures are invalid.
+        request["endorser"] = json!(endorser);
+        if let Some(request) = request.as_object_mut() {
+            request.remove("signature");
+            request.remove("signatures");
+        }
+
+        // Without an auth rule only the caller knows who else has to sign the request.
+        let mut required_signers = match options.auth_rule {
+            Some(ref auth_rule) => self.ledger_service.auth_rule_required_signers(
+                &auth_rule.get_auth_rule_response,
+                &auth_rule.action,
+                &request,
+                &auth_rule.signers,
+            )?,
+            None if !options.required_signers.is_empty() => {
+                Some(vec![identifier.clone(), endorser.clone()])
+            }
+            None => None,
+        };
+
+        if let Some(ref mut required_signers) = required_signers {
+            for did in options.required_signers {
+                let did = did.to_short().0;
+
+                if !required_signers.contains(&did) {
+                    required_signers.push(did);
+                }
+            }
+        }
+
+        let envelope = PendingEndorsement {
+            id: PendingEndorsement::id(&identifier, req_id),
+            request,
+            endorser,
+            required_signers,
+            auth_rule: options.auth_rule,
+        };
+
+        self.wallet_service
+            .upsert_indy_object(wallet_handle, &envelope.id, &envelope)
+            .await?;
+
+        let res = serde_json::to_string(&envelope).to_indy(
+            IndyErrorKind::InvalidState,
+            "Can't serialize endorsement envelope",
+        )?;
+
+        let res = Ok(res);
+        debug!("build_endorsement_envelope < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn sign_endorsement_envelope(
+        &self,
+        wallet_handle: WalletHandle,
+        mut envelope: PendingEndorsement,
+        signer_did: DidValue,
+    ) -> IndyResult<String> {
+        debug!(
+            "sign_endorsement_envelope > wallet_handle {:?} envelope {:?} signer_did {:?}",
+            wallet_handle, envelope, signer_did
+        );
+
+        self.crypto_service.validate_did(&signer_did)?;
+
+        let request = self
+            ._sign_request(
+                wallet_handle,
+                &signer_did,
+                &envelope.request.to_string(),
+                SignatureType::Multi,
+            )
+            .await?;
+
+        envelope.request = serde_json::from_str(&request)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize signed request")?;
+
+        self.wallet_service
+            .upsert_indy_object(wallet_handle, &envelope.id, &envelope)
+            .await?;
+
+        let res = serde_json::to_string(&envelope).to_indy(
+            IndyErrorKind::InvalidState,
+            "Can't serialize endorsement envelope",
+        )?;
+
+        let res = Ok(res);
+        debug!("sign_endorsement_envelope < {:?}", res);
+        res
+    }
+
+    pub(crate) fn check_endorsement_envelope(
+        &self,
+        envelope: PendingEndorsement,
+    ) -> IndyResult<String> {
+        debug!("check_endorsement_envelope > envelope {:?}", envelope);
+
+        let status = self._check_endorsement_envelope(&envelope)?;
+
+        let res = serde_json::to_string(&status).to_indy(
+            IndyErrorKind::InvalidState,
+            "Can't serialize endorsement status",
+        )?;
+
+        let res = Ok(res);
+        debug!("check_endorsement_envelope < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn submit_endorsement_envelope(
+        &self,
+        pool_handle: PoolHandle,
+        wallet_handle: WalletHandle,
+        envelope: PendingEndorsement,
+    ) -> IndyResult<String> {
+        debug!(
+            "submit_endorsement_envelope > pool_handle {:?} wallet_handle {:?} envelope {:?}",
+            pool_handle, wallet_handle, envelope
+        );
+
+        let status = self._check_endorsement_envelope(&envelope)?;
+
+        if !status.required_signers_determined {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                "Required signers of the endorsement envelope are undetermined, \
+                    build it with an auth rule or explicit required signers",
+            ));
+        }
+
+        if !status.complete {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!(
+                    "Endorsement envelope is incomplete, missing signers: {:?}",
+                    status.missing_signers
+                ),
+            ));
+        }
+
+        let res = self
+            ._submit_request(pool_handle, &envelope.request.to_string())
+            .await?;
+
+        let response: Value = serde_json::from_str(&res).unwrap_or(Value::Null);
+
+        // Rejected requests are kept to be inspected or fixed.
+        if response["op"] == json!("REPLY") {
+            self._delete_pending_endorsement(wallet_handle, &envelope.id)
+                .await?;
+        }
+
+        let res = Ok(res);
+        debug!("submit_endorsement_envelope < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn list_pending_endorsements(
+        &self,
+        wallet_handle: WalletHandle,
+    ) -> IndyResult<String> {
+        debug!(
+            "list_pending_endorsements > wallet_handle {:?}",
+            wallet_handle
+        );
+
+        let mut search = self
+            .wallet_service
+            .search_indy_records::<PendingEndorsement>(
+                wallet_handle,
+                "{}",
+                &SearchOptions::id_value(),
+            )
+            .await?;
+
+        let mut envelopes: Vec<PendingEndorsement> = Vec::new();
+
+        while let Some(record) = search.fetch_next_record().await? {
+            let envelope = record
+                .get_value()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "No value for record"))?;
+
+            let envelope: PendingEndorsement = serde_json::from_str(envelope).to_indy(
+                IndyErrorKind::InvalidState,
+                "Cannot deserialize pending endorsement",
+            )?;
+
+            envelopes.push(envelope);
+        }
+
+        let res = serde_json::to_string(&envelopes).to_indy(
+            IndyErrorKind::InvalidState,
+            "Can't serialize pending endorsements",
+        )?;
+
+        let res = Ok(res);
+        debug!("list_pending_endorsements < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn delete_pending_endorsement(
+        &self,
+        wallet_handle: WalletHandle,
+        id: String,
+    ) -> IndyResult<()> {
+        debug!(
+            "delete_pending_endorsement > wallet_handle {:?} id {:?}",
+            wallet_handle, id
+        );
+
+        self.wallet_service
+            .delete_indy_record::<PendingEndorsement>(wallet_handle, &id)
+            .await?;
+
+        let res = Ok(());
+        debug!("delete_pending_endorsement < {:?}", res);
+        res
+    }
+
+    pub(crate) fn build_ledgers_freeze_request(
+        &self,
+        submitter_did: DidValue,
+        ledgers_ids: Vec<u64>,
+    ) -> IndyResult<String> {
+        debug!(
+            "build_ledgers_freeze_request > submitter_did {:?} ledgers_ids {:?}",
+            submitter_did, ledgers_ids
+        );
+
+        self.crypto_service.validate_did(&submitter_did)?;
+
+        let res = self
+            .ledger_service
+            .build_ledgers_freeze_request(&submitter_did, ledgers_ids)?;
+
+        let res = Ok(res);
+        debug!("build_ledgers_freeze_request < {:?}", res);
+        res
+    }
+
+    pub(crate) fn build_get_frozen_ledgers_request(
+        &self,
+        submitter_did: Option<DidValue>,
+    ) -> IndyResult<String> {
+        debug!(
+            "build_get_frozen_ledgers_request > submitter_did {:?}",
+            submitter_did
+        );
+
+        self._validate_opt_did(submitter_did.as_ref())?;
+
+        let res = self
+            .ledger_service
+            .build_get_frozen_ledgers_request(submitter_did.as_ref())?;
+
+        let res = Ok(res);
+        debug!("build_get_frozen_ledgers_request < {:?}", res);
+        res
+    }
+
+    pub(crate) fn parse_get_frozen_ledgers_response(
+        &self,
+        get_frozen_ledgers_response: String,
+    ) -> IndyResult<String> {
+        debug!(
+            "parse_get_frozen_ledgers_response > get_frozen_ledgers_response {:?}",
+            get_frozen_ledgers_response
+        );
+
+        let res = self
+            .ledger_service
+            .parse_get_frozen_ledgers_response(&get_frozen_ledgers_response)?;
+
+        let res = Ok(res);
+        debug!("parse_get_frozen_ledgers_response < {:?}", res);
+        res
+    }
+
+    pub(crate) fn build_rich_schema_request(
+        &self,
+        submitter_did: DidValue,
+        rs_id: String,
+        rs_content: String,
+        rs_name: String,
+        rs_version: String,
+        rs_type: String,
+        ver: String,
+    ) -> IndyResult<String> {
+        debug!(
+            "build_rich_schema_request > submitter_did {:?} rs_id {:?} rs_content {:?} \
+                rs_name {:?} rs_version {:?} rs_type {:?} ver {:?}",
+            submitter_did, rs_id, rs_content, rs_name, rs_version, rs_type, ver
+        );
+
+        self.crypto_service.validate_did(&submitter_did)?;
+
+        let rs_type = RichSchemaType::from_str(&rs_type)
+            .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, err))?;
+
+        let rich_schema = RichSchema::new(rs_id, rs_content, rs_name, rs_version, rs_type, ver);
+
+        rich_schema
+            .validate()
+            .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, err))?;
+
+        let res = self
+            .ledger_service
+            .build_rich_schema_request(&submitter_did, rich_schema)?;
+
+        let res = Ok(res);
+        debug!("build_rich_schema_request < {:?}", res);
+        res
+    }
+
+    pub(crate) fn build_get_rich_schema_object_by_id_request(
+        &self,
+        submitter_did: Option<DidValue>,
+        rs_id: String,
+    ) -> IndyResult<String> {
+        debug!(
+            "build_get_rich_schema_object_by_id_request > submitter_did {:?} rs_id {:?}",
+            submitter_did, rs_id
+        );
+
+        self._validate_opt_did(submitter_did.as_ref())?;
+
+        let res = self
+            .ledger_service
+            .build_get_rich_schema_object_by_id_request(submitter_did.as_ref(), &rs_id)?;
+
+        let res = Ok(res);
+        debug!("build_get_rich_schema_object_by_id_request < {:?}", res);
+        res
+    }
+
+    pub(crate) fn build_get_rich_schema_object_by_metadata_request(
+        &self,
+        submitter_did: Option<DidValue>,
+        rs_type: String,
+        rs_name: String,
+        rs_version: String,
+    ) -> IndyResult<String> {
+        debug!(
+            "build_get_rich_schema_object_by_metadata_request > submitter_did {:?} \
+                rs_type {:?} rs_name {:?} rs_version {:?}",
+            submitter_did, rs_type, rs_name, rs_version
+        );
+
+        self._validate_opt_did(submitter_did.as_ref())?;
+
+        let rs_type = RichSchemaType::from_str(&rs_type)
+            .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, err))?;
+
+        let res = self
+            .ledger_service
+            .build_get_rich_schema_object_by_metadata_request(
+                submitter_did.as_ref(),
+                rs_type,
+                &rs_name,
+                &rs_version,
+            )?;
+
+        let res = Ok(res);
+        debug!("build_get_rich_schema_object_by_metadata_request < {:?}", res);
+        res
+    }
+
+    pub(crate) fn parse_get_rich_schema_object_response(
+        &self,
+        get_rich_schema_object_response: String,
+    ) -> IndyResult<String> {
+        debug!(
+            "parse_get_rich_schema_object_response > get_rich_schema_object_response {:?}",
+            get_rich_schema_object_response
+        );
+
+        let res = self
+            .ledger_service
+            .parse_get_rich_schema_object_response(&get_rich_schema_object_response)?;
+
+        let res = Ok(res);
+        debug!("parse_get_rich_schema_object_response < {:?}", res);
+        res
+    }
+
     fn _validate_opt_did(&self, did: Option<&DidValue>) -> IndyResult<()> {
         match did {
             Some(did) => Ok(self.crypto_service.validate_did(did)?),
@@ -1213,6 +1898,204 @@ impl LedgerController {
         res
     }
 
+    /// Appends transaction author agreement acceptance set for the pool to domain ledger write request.
+    /// Returns the request and whether acceptance has been appended.
+    async fn _append_pool_taa_acceptance(
+        &self,
+        pool_handle: PoolHandle,
+        request_json: String,
+    ) -> IndyResult<(String, bool)> {
+        let taa = match self.pool_service.get_taa_acceptance(pool_handle).await {
+            Some(taa) => taa,
+            None => return Ok((request_json, false)),
+        };
+
+        let mut request: Value = serde_json::from_str(&request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        let txn_type = request["operation"]["type"].as_str().unwrap_or_default();
+
+        if !request["taaAcceptance"].is_null() || !DOMAIN_LEDGER_WRITE_REQUESTS.contains(&txn_type) {
+            return Ok((request_json, false));
+        }
+
+        if !self._check_ledger_taa(pool_handle, &taa).await? {
+            return Ok((request_json, false));
+        }
+
+        request["taaAcceptance"] = json!(taa.acceptance);
+
+        let request_json = serde_json::to_string(&request).to_indy(
+            IndyErrorKind::InvalidState,
+            "Can't serialize request after adding author agreement acceptance data",
+        )?;
+
+        Ok((request_json, true))
+    }
+
+    /// Sets `protocolVersion` of the request to the one detected for the pool.
+    /// Signed requests are sent as is as the version is a part of the signed data.
+    async fn _apply_pool_protocol_version(
+        &self,
+        pool_handle: PoolHandle,
+        request_json: String,
+    ) -> IndyResult<String> {
+        let mut request: Value = serde_json::from_str(&request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        if request.get("protocolVersion").is_none()
+            || request.get("signature").is_some()
+            || request.get("signatures").is_some()
+        {
+            return Ok(request_json);
+        }
+
+        let protocol_version = self.pool_service.get_protocol_version(pool_handle).await?;
+
+        if request["protocolVersion"].as_u64() == Some(protocol_version as u64) {
+            return Ok(request_json);
+        }
+
+        request["protocolVersion"] = json!(protocol_version);
+        cred_def::apply_protocol_version(&mut request["operation"], protocol_version);
+
+        serde_json::to_string(&request).to_indy(
+            IndyErrorKind::InvalidState,
+            "Can't serialize request after setting protocol version",
+        )
+    }
+
+    /// Checks that the accepted agreement is the one in force on the ledger.
+    /// Returns `false` if the ledger doesn't require agreement acceptance.
+    async fn _check_ledger_taa(
+        &self,
+        pool_handle: PoolHandle,
+        taa: &PoolTxnAuthorAgreement,
+    ) -> IndyResult<bool> {
+        let ledger_digest = match taa.ledger_digest {
+            Some((ref digest, read_time))
+                if time::get_time().sec - read_time < TXN_AUTHR_AGRMT_CHECK_INTERVAL =>
+            {
+                digest.clone()
+            }
+            _ => {
+                let digest = self._get_ledger_taa_digest(pool_handle).await?;
+
+                self.pool_service
+                    .set_ledger_taa_digest(pool_handle, Some(digest.clone()))
+                    .await;
+
+                digest
+            }
+        };
+
+        match ledger_digest {
+            None => Ok(false),
+            Some(ref digest) if digest.eq_ignore_ascii_case(&taa.acceptance.taa_digest) => Ok(true),
+            Some(digest) => Err(err_msg(
+                IndyErrorKind::TxnAuthorAgreementChanged,
+                format!(
+                    "Transaction author agreement on the ledger {} differs from the accepted one {}",
+                    digest, taa.acceptance.taa_digest
+                ),
+            )),
+        }
+    }
+
+    async fn _get_ledger_taa_digest(&self, pool_handle: PoolHandle) -> IndyResult<Option<String>> {
+        let request = self
+            .ledger_service
+            .build_get_txn_author_agreement_request(None, None)?;
+
+        let response = self.pool_service.send_tx(pool_handle, &request).await?;
+
+        let result = LedgerService::parse_response::<GetTxnAuthorAgreementResult>(&response)?.result();
+
+        let now = time::get_time().sec as u64;
+
+        Ok(result
+            .data
+            .filter(|data| data.retirement_ts.map(|ts| ts > now).unwrap_or(true))
+            .map(|data| data.digest))
+    }
+
+    fn _is_taa_rejection(response: &str) -> bool {
+        let response: Value = match serde_json::from_str(response) {
+            Ok(response) => response,
+            Err(_) => return false,
+        };
+
+        (response["op"] == json!("REJECT") || response["op"] == json!("REQNACK"))
+            && response["reason"]
+                .as_str()
+                .map(|reason| reason.contains("Author Agreement"))
+                .unwrap_or(false)
+    }
+
+    fn _check_endorsement_envelope(
+        &self,
+        envelope: &PendingEndorsement,
+    ) -> IndyResult<EndorsementStatus> {
+        let missing_signers = envelope.missing_signers();
+
+        let auth_rule_evaluation = match envelope.auth_rule {
+            Some(ref auth_rule) => Some(self.ledger_service.evaluate_auth_rule(
+                &auth_rule.get_auth_rule_response,
+                &auth_rule.action,
+                &envelope.request.to_string(),
+                &auth_rule.signers,
+            )?),
+            None => None,
+        };
+
+        let required_signers_determined = envelope.required_signers.is_some();
+
+        Ok(EndorsementStatus {
+            complete: required_signers_determined
+                && missing_signers.is_empty()
+                && auth_rule_evaluation
+                    .as_ref()
+                    .map(|evaluation| evaluation.satisfied)
+                    .unwrap_or(true),
+            required_signers_determined,
+            missing_signers,
+            auth_rule_evaluation,
+        })
+    }
+
+    async fn _delete_pending_endorsement(
+        &self,
+        wallet_handle: WalletHandle,
+        id: &str,
+    ) -> IndyResult<()> {
+        match self
+            .wallet_service
+            .delete_indy_record::<PendingEndorsement>(wallet_handle, id)
+            .await
+        {
+            Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => Ok(()),
+            res => res,
+        }
+    }
+
+    fn _submit_request_result(res: IndyResult<String>) -> SubmitRequestResult {
+        match res {
+            Ok(reply) => {
+                let reply: Value = serde_json::from_str(&reply).unwrap_or(Value::String(reply));
+
+                if reply["op"] == json!("REJECT") || reply["op"] == json!("REQNACK") {
+                    SubmitRequestResult::Rejected { reply }
+                } else {
+                    SubmitRequestResult::Reply { reply }
+                }
+            }
+            Err(err) => SubmitRequestResult::Error {
+                error_code: ErrorCode::from(err.kind()) as i32,
+                message: err.to_string(),
+            },
+        }
+    }
+
     async fn _submit_request<'a>(
         &self,
         handle: PoolHandle,
@@ -1226,7 +2109,11 @@ impl LedgerController {
         serde_json::from_str::<Request<serde_json::Value>>(&request_json)
             .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
 
-        let res = self.pool_service.send_tx(handle, request_json).await?;
+        let request_json = self
+            ._apply_pool_protocol_version(handle, request_json.to_string())
+            .await?;
+
+        let res = self.pool_service.send_tx(handle, &request_json).await?;
 
         let res = Ok(res);
         debug!("_submit_request < {:?}", res);
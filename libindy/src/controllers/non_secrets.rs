@@ -3,7 +3,9 @@ use std::{collections::HashMap, sync::Arc};
 use futures::lock::Mutex;
 use indy_api_types::{domain::wallet::Tags, errors::prelude::*, SearchHandle, WalletHandle};
 use indy_utils::next_search_handle;
-use indy_wallet::{RecordOptions, SearchOptions, WalletRecord, WalletSearch, WalletService};
+use indy_wallet::{
+    RecordOptions, SearchOptions, WalletOperation, WalletRecord, WalletSearch, WalletService,
+};
 
 pub(crate) struct NonSecretsController {
     wallet_service: Arc<WalletService>,
@@ -190,6 +192,35 @@ impl NonSecretsController {
         res
     }
 
+    pub(crate) async fn batch(
+        &self,
+        wallet_handle: WalletHandle,
+        operations_json: String,
+    ) -> IndyResult<()> {
+        trace!(
+            "batch > wallet_handle {:?} operations_json {:?}",
+            wallet_handle,
+            operations_json
+        );
+
+        let operations: Vec<WalletOperation> = serde_json::from_str(&operations_json).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize wallet operations",
+        )?;
+
+        for operation in &operations {
+            self._check_type(operation.get_type())?;
+        }
+
+        self.wallet_service
+            .batch(wallet_handle, &operations)
+            .await?;
+
+        let res = Ok(());
+        trace!("batch < {:?}", res);
+        res
+    }
+
     pub(crate) async fn get_record(
         &self,
         wallet_handle: WalletHandle,
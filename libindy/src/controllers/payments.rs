@@ -23,14 +23,30 @@ use crate::{
     },
 };
 
+#[cfg(feature = "cheqd")]
+use crate::{
+    domain::cheqd_keys::Key,
+    domain::cheqd_ledger::cosmos_ext::CosmosRawExt,
+    domain::cheqd_ledger::payments::{CheqdPaymentAddressConfig, CheqdPaymentExtra, CheqdPaymentOutput, CheqdPaymentRequest},
+    services::{CheqdKeysService, CheqdLedgerService, CheqdPoolService, CHEQD_PAYMENT_METHOD},
+    utils::environment,
+};
+
 pub struct PaymentsController {
     payments_service:Arc<PaymentsService>,
     wallet_service:Arc<WalletService>,
     crypto_service:Arc<CryptoService>,
     ledger_service:Arc<LedgerService>,
+    #[cfg(feature = "cheqd")]
+    cheqd_ledger_service:Arc<CheqdLedgerService>,
+    #[cfg(feature = "cheqd")]
+    cheqd_keys_service:Arc<CheqdKeysService>,
+    #[cfg(feature = "cheqd")]
+    cheqd_pool_service:Arc<CheqdPoolService>,
 }
 
 impl PaymentsController {
+    #[cfg(not(feature = "cheqd"))]
     pub(crate) fn new(payments_service:Arc<PaymentsService>, wallet_service:Arc<WalletService>, crypto_service:Arc<CryptoService>, ledger_service:Arc<LedgerService>) -> PaymentsController {
         PaymentsController {
             payments_service,
@@ -40,6 +56,21 @@ impl PaymentsController {
         }
     }
 
+    #[cfg(feature = "cheqd")]
+    pub(crate) fn new(payments_service:Arc<PaymentsService>, wallet_service:Arc<WalletService>, crypto_service:Arc<CryptoService>, ledger_service:Arc<LedgerService>,
+                      cheqd_ledger_service:Arc<CheqdLedgerService>, cheqd_keys_service:Arc<CheqdKeysService>,
+                      cheqd_pool_service:Arc<CheqdPoolService>) -> PaymentsController {
+        PaymentsController {
+            payments_service,
+            wallet_service,
+            crypto_service,
+            ledger_service,
+            cheqd_ledger_service,
+            cheqd_keys_service,
+            cheqd_pool_service,
+        }
+    }
+
     pub(crate) async fn register_method(&self, type_: String, methods: PaymentsMethodCBs) -> IndyResult<()> {
         trace!("register_method > type_ {:?} methods {:?}", type_, methods);
 
@@ -61,6 +92,16 @@ impl PaymentsController {
 
         self.wallet_service.check(wallet_handle).await.map_err(map_err_err!())?;
 
+        #[cfg(feature = "cheqd")]
+        let res = if type_ == CHEQD_PAYMENT_METHOD {
+            self._cheqd_create_address(wallet_handle, &config).await?
+        } else {
+            self.payments_service
+                .create_address(wallet_handle, &type_, &config)
+                .await?
+        };
+
+        #[cfg(not(feature = "cheqd"))]
         let res = self
             .payments_service
             .create_address(wallet_handle, &type_, &config)
@@ -166,6 +207,16 @@ impl PaymentsController {
         let method = self
             .payments_service
             .parse_method_from_payment_address(&payment_address)?;
+
+        #[cfg(feature = "cheqd")]
+        {
+            if method == CHEQD_PAYMENT_METHOD {
+                let req = self._cheqd_build_get_payment_sources_request(&payment_address)?;
+                trace!("build_get_payment_sources_request < req {} method {}", req, method);
+                return Ok((req, method));
+            }
+        }
+
         let req = self
             .payments_service
             .build_get_payment_sources_request(
@@ -188,6 +239,15 @@ impl PaymentsController {
     ) -> IndyResult<(String, i64)> {
         trace!("parse_get_payment_sources_response > response {:?}", response);
 
+        #[cfg(feature = "cheqd")]
+        {
+            if type_ == CHEQD_PAYMENT_METHOD {
+                let res = self._cheqd_parse_get_payment_sources_response(&response);
+                trace!("parse_get_payment_sources_response < {:?}", res);
+                return res;
+            }
+        }
+
         let res = self
             .payments_service
             .parse_get_payment_sources_response(&type_, &response)
@@ -220,6 +280,15 @@ impl PaymentsController {
         let method =
             PaymentsController::_merge_parse_result(method_from_inputs, method_from_outputs)?;
 
+        #[cfg(feature = "cheqd")]
+        {
+            if method == CHEQD_PAYMENT_METHOD {
+                let req = self._cheqd_build_payment_req(wallet_handle, &inputs, &outputs, extra.as_deref()).await?;
+                trace!("build_payment_req <");
+                return Ok((req, method));
+            }
+        }
+
         let req = self
             .payments_service
             .build_payment_req(
@@ -276,6 +345,15 @@ impl PaymentsController {
     ) -> IndyResult<String> {
         trace!("parse_payment_response > response {:?}", response);
 
+        #[cfg(feature = "cheqd")]
+        {
+            if payment_method == CHEQD_PAYMENT_METHOD {
+                let res = self._cheqd_parse_payment_response(&response);
+                trace!("parse_payment_response < {:?}", res);
+                return res;
+            }
+        }
+
         let res = self
             .payments_service
             .parse_payment_response(&payment_method, &response)
@@ -466,6 +544,115 @@ impl PaymentsController {
     }
 
 
+    // CHEQD PAYMENT METHOD
+
+    #[cfg(feature = "cheqd")]
+    async fn _cheqd_create_address(&self, wallet_handle: WalletHandle, config: &str) -> IndyResult<String> {
+        let config: CheqdPaymentAddressConfig = serde_json::from_str(config)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize cheqd payment address config")?;
+
+        let key = match config.mnemonic {
+            Some(mnemonic) => self.cheqd_keys_service.new_from_mnemonic("", &mnemonic)?,
+            None => self.cheqd_keys_service.new_random("")?,
+        };
+
+        // Keys backing payment addresses are stored under their account id so they can be found by address
+        let account_id = self.cheqd_keys_service.get_info(&key)?.account_id;
+        let key = Key::new(account_id.clone(), key.priv_key.clone());
+
+        self.wallet_service
+            .add_indy_object(wallet_handle, &key.alias, &key, &HashMap::new())
+            .await
+            .map_err(|err| match err.kind() {
+                IndyErrorKind::WalletItemAlreadyExists =>
+                    err.extend(format!("Payment address for account {} already exists", account_id)),
+                _ => err,
+            })?;
+
+        Ok(self.cheqd_ledger_service.payment_address_from_account_id(&account_id))
+    }
+
+    #[cfg(feature = "cheqd")]
+    fn _cheqd_build_get_payment_sources_request(&self, payment_address: &str) -> IndyResult<String> {
+        let req = self.cheqd_ledger_service.payment_build_get_sources_request(payment_address, None)?;
+
+        serde_json::to_string(&req)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize request for cheqd balance query")
+    }
+
+    #[cfg(feature = "cheqd")]
+    fn _cheqd_parse_get_payment_sources_response(&self, response: &str) -> IndyResult<(String, i64)> {
+        let resp: cosmrs::rpc::endpoint::abci_query::Response = serde_json::from_str(response)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize response for cheqd balance query")?;
+
+        let sources = self.cheqd_ledger_service.payment_parse_get_sources_response(&resp)?;
+
+        let sources = serde_json::to_string(&sources)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize cheqd payment sources")?;
+
+        Ok((sources, -1))
+    }
+
+    /// Builds the transfer transaction and signs it with the key stored for the sender payment address.
+    #[cfg(feature = "cheqd")]
+    async fn _cheqd_build_payment_req(&self, wallet_handle: WalletHandle, inputs: &str, outputs: &str, extra: Option<&str>) -> IndyResult<String> {
+        let inputs: Vec<String> = serde_json::from_str(inputs)
+            .to_indy(IndyErrorKind::InvalidStructure, "Unable to parse inputs")?;
+
+        let outputs: Vec<CheqdPaymentOutput> = serde_json::from_str(outputs)
+            .to_indy(IndyErrorKind::InvalidStructure, "Unable to parse outputs")?;
+
+        let extra = extra.ok_or_else(|| err_msg(
+            IndyErrorKind::InvalidStructure,
+            "Cheqd payment request requires extra with pool alias and sender account state",
+        ))?;
+        let extra: CheqdPaymentExtra = serde_json::from_str(extra)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize cheqd payment extra")?;
+        let denom = extra.denom.clone().unwrap_or_else(environment::cheqd_denom);
+
+        let (sender, msgs) = self.cheqd_ledger_service.payment_build_transfer_msgs(&inputs, &outputs, &denom)?;
+
+        let key: Key = self.wallet_service
+            .get_indy_object(wallet_handle, &sender, &RecordOptions::id_value())
+            .await?;
+        let sender_public_key = self.cheqd_keys_service.get_info(&key)?.pub_key;
+
+        let pool = self.cheqd_pool_service.get_config(&extra.pool_alias).await?;
+
+        let sign_doc = self.cheqd_ledger_service
+            .auth_build_tx(
+                &pool.chain_id,
+                &sender_public_key,
+                msgs,
+                extra.account_number,
+                extra.sequence_number,
+                extra.max_gas,
+                extra.max_coin_amount,
+                &denom,
+                extra.timeout_height,
+                &extra.memo,
+                None,
+            )
+            .await?;
+
+        let signed = self.cheqd_keys_service.sign(&key, sign_doc).await?;
+        let req = CheqdPaymentRequest::new(extra.pool_alias, hex::encode(signed.to_bytes()?));
+
+        serde_json::to_string(&req)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize cheqd payment request")
+    }
+
+    #[cfg(feature = "cheqd")]
+    fn _cheqd_parse_payment_response(&self, response: &str) -> IndyResult<String> {
+        let resp: cosmrs::rpc::endpoint::broadcast::tx_commit::Response = serde_json::from_str(response)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize response for cheqd payment")?;
+
+        let receipts = self.cheqd_ledger_service.payment_parse_payment_response(&resp)?;
+
+        serde_json::to_string(&receipts)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize cheqd payment receipts")
+    }
+
     // HELPERS
 
     fn _merge_parse_result(method_from_inputs: IndyResult<String>, method_from_outputs: IndyResult<String>) -> IndyResult<String> {
@@ -510,3 +697,147 @@ impl PaymentsController {
         Ok(res)
     }
 }
+
+#[cfg(all(test, feature = "cheqd"))]
+mod tests {
+    use cosmrs::tx::Raw;
+    use indy_api_types::domain::wallet::{Config, Credentials, KeyDerivationMethod};
+    use indy_api_types::INVALID_WALLET_HANDLE;
+    use rand::{distributions::Alphanumeric, Rng};
+
+    use crate::controllers::WalletController;
+    use crate::services::{CheqdPoolService, CryptoService, LedgerService, PaymentsService};
+
+    use super::*;
+
+    const MNEMONIC: &str = "secret phrase";
+
+    fn random_name() -> String {
+        rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(7)
+            .map(char::from)
+            .collect()
+    }
+
+    async fn setup() -> (PaymentsController, Arc<CheqdPoolService>, WalletHandle) {
+        let wallet_service = Arc::new(WalletService::new());
+        let crypto_service = Arc::new(CryptoService::new());
+        let cheqd_pool_service = Arc::new(CheqdPoolService::new());
+
+        let controller = PaymentsController::new(
+            Arc::new(PaymentsService::new()),
+            wallet_service.clone(),
+            crypto_service.clone(),
+            Arc::new(LedgerService::new()),
+            Arc::new(CheqdLedgerService::new()),
+            Arc::new(CheqdKeysService::new()),
+            cheqd_pool_service.clone(),
+        );
+
+        let wallet_controller = WalletController::new(wallet_service, crypto_service);
+
+        let config = Config {
+            id: random_name(),
+            storage_type: None,
+            storage_config: None,
+            cache: None,
+        };
+        let credentials = Credentials {
+            key: "6nxtSiXFvBd593Y2DCed2dYvRY1PGK9WMtxCBjLzKgbw".to_string(),
+            rekey: None,
+            storage_credentials: None,
+            key_derivation_method: KeyDerivationMethod::RAW,
+            rekey_derivation_method: KeyDerivationMethod::RAW,
+        };
+
+        wallet_controller.create(config.clone(), credentials.clone()).await.unwrap();
+        let wallet_handle = wallet_controller.open(config, credentials).await.unwrap();
+
+        (controller, cheqd_pool_service, wallet_handle)
+    }
+
+    fn mnemonic_config() -> String {
+        json!({ "mnemonic": MNEMONIC }).to_string()
+    }
+
+    #[async_std::test]
+    async fn cheqd_create_address_works() {
+        let (controller, _, wallet_handle) = setup().await;
+
+        let address = controller._cheqd_create_address(wallet_handle, &mnemonic_config()).await.unwrap();
+        let account_id = controller.cheqd_ledger_service.payment_account_id(&address).unwrap();
+
+        let key: Key = controller.wallet_service
+            .get_indy_object(wallet_handle, &account_id, &RecordOptions::id_value())
+            .await
+            .unwrap();
+        assert_eq!(account_id, key.alias);
+    }
+
+    #[async_std::test]
+    async fn cheqd_create_address_fails_for_duplicate() {
+        let (controller, _, wallet_handle) = setup().await;
+
+        controller._cheqd_create_address(wallet_handle, &mnemonic_config()).await.unwrap();
+
+        let err = controller._cheqd_create_address(wallet_handle, &mnemonic_config()).await.unwrap_err();
+        assert_eq!(IndyErrorKind::WalletItemAlreadyExists, err.kind());
+    }
+
+    #[async_std::test]
+    async fn cheqd_create_address_passes_other_wallet_errors_through() {
+        let (controller, _, _) = setup().await;
+
+        let err = controller._cheqd_create_address(INVALID_WALLET_HANDLE, "{}").await.unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidWalletHandle, err.kind());
+    }
+
+    #[async_std::test]
+    async fn cheqd_build_payment_req_works() {
+        let (controller, cheqd_pool_service, wallet_handle) = setup().await;
+
+        let sender = controller._cheqd_create_address(wallet_handle, &mnemonic_config()).await.unwrap();
+        let recipient = controller.cheqd_ledger_service.payment_address_from_account_id("cosmos1ma0t2kx2m7v4rdcgeaye4jgyv0p9q8fpd8gvyc");
+        let source = controller.cheqd_ledger_service.payment_source_from_account_id(
+            &controller.cheqd_ledger_service.payment_account_id(&sender).unwrap());
+
+        let pool_alias = random_name();
+        cheqd_pool_service.add(&pool_alias, "http://127.0.0.1:26657", "cheqdnode").await.unwrap();
+
+        let inputs = json!([source]).to_string();
+        let outputs = json!([
+            { "recipient": recipient, "amount": 10 },
+            { "recipient": sender, "amount": 90 },
+        ]).to_string();
+        let extra = json!({
+            "pool_alias": pool_alias,
+            "account_number": 0,
+            "sequence_number": 0,
+            "max_gas": 300000,
+            "max_coin_amount": 0,
+        }).to_string();
+
+        let req = controller._cheqd_build_payment_req(wallet_handle, &inputs, &outputs, Some(&extra)).await.unwrap();
+        let req: CheqdPaymentRequest = serde_json::from_str(&req).unwrap();
+
+        assert_eq!(pool_alias, req.pool_alias);
+        Raw::from_bytes(&hex::decode(&req.tx).unwrap()).unwrap();
+    }
+
+    #[async_std::test]
+    async fn cheqd_build_payment_req_fails_without_extra() {
+        let (controller, _, wallet_handle) = setup().await;
+
+        let sender = controller._cheqd_create_address(wallet_handle, &mnemonic_config()).await.unwrap();
+        let source = controller.cheqd_ledger_service.payment_source_from_account_id(
+            &controller.cheqd_ledger_service.payment_account_id(&sender).unwrap());
+        let recipient = controller.cheqd_ledger_service.payment_address_from_account_id("cosmos1ma0t2kx2m7v4rdcgeaye4jgyv0p9q8fpd8gvyc");
+
+        let inputs = json!([source]).to_string();
+        let outputs = json!([{ "recipient": recipient, "amount": 10 }]).to_string();
+
+        let err = controller._cheqd_build_payment_req(wallet_handle, &inputs, &outputs, None).await.unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+}
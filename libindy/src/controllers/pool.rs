@@ -1,34 +1,72 @@
 use std::sync::Arc;
 
-use indy_api_types::{errors::prelude::*, PoolHandle};
+use indy_api_types::{errors::prelude::*, PoolHandle, WalletHandle};
+use indy_wallet::RecordOptions;
+use rust_base58::ToBase58;
 
 use crate::{
     domain::{
+        crypto::key::Key,
         ledger::request::ProtocolVersion,
-        pool::{PoolConfig, PoolOpenConfig},
+        pool::{
+            PoolConfig, PoolOpenConfig, PoolSnapshotExportConfig, PoolSnapshotSignature,
+            SimulatedPoolConfig,
+        },
     },
-    services::PoolService,
+    services::{CryptoService, PoolService, WalletService},
 };
 
 pub(crate) struct PoolController {
     pool_service: Arc<PoolService>,
+    wallet_service: Arc<WalletService>,
+    crypto_service: Arc<CryptoService>,
 }
 
 impl PoolController {
-    pub fn new(pool_service: Arc<PoolService>) -> PoolController {
-        PoolController { pool_service }
+    pub fn new(
+        pool_service: Arc<PoolService>,
+        wallet_service: Arc<WalletService>,
+        crypto_service: Arc<CryptoService>,
+    ) -> PoolController {
+        PoolController {
+            pool_service,
+            wallet_service,
+            crypto_service,
+        }
     }
 
-    pub(crate) fn create(&self, name: String, config: Option<PoolConfig>) -> IndyResult<()> {
+    pub(crate) async fn create(&self, name: String, config: Option<PoolConfig>) -> IndyResult<()> {
         trace!("create > name {:?} config {:?}", name, config);
 
-        self.pool_service.create(&name, config)?;
+        self.pool_service.create(&name, config).await?;
 
         let res = Ok(());
         trace!("create < {:?}", res);
         res
     }
 
+    pub(crate) async fn create_simulated_pool(
+        &self,
+        name: String,
+        config: SimulatedPoolConfig,
+    ) -> IndyResult<String> {
+        trace!("create_simulated_pool > name {:?} config {:?}", name, config);
+
+        let res = self.pool_service.create_simulated(&name, config).await;
+        trace!("create_simulated_pool < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn update_genesis(&self, name: String, config: PoolConfig) -> IndyResult<()> {
+        trace!("update_genesis > name {:?} config {:?}", name, config);
+
+        self.pool_service.update_genesis(&name, config).await?;
+
+        let res = Ok(());
+        trace!("update_genesis < {:?}", res);
+        res
+    }
+
     pub(crate) async fn delete(&self, name: String) -> IndyResult<()> {
         trace!("delete > name {:?}", name);
 
@@ -86,6 +124,102 @@ impl PoolController {
         res
     }
 
+    pub(crate) async fn get_status(&self, handle: PoolHandle) -> IndyResult<String> {
+        trace!("get_status > handle {:?}", handle);
+
+        let status = self.pool_service.get_status(handle).await?;
+
+        let status = serde_json::to_string(&status)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize pool status")?;
+
+        let res = Ok(status);
+        trace!("get_status < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn export_snapshot(
+        &self,
+        wallet_handle: WalletHandle,
+        name: String,
+        config: Option<PoolSnapshotExportConfig>,
+    ) -> IndyResult<String> {
+        trace!(
+            "export_snapshot > wallet_handle {:?} name {:?} config {:?}",
+            wallet_handle,
+            name,
+            config
+        );
+
+        let config = config.unwrap_or_default();
+        let mut snapshot = self.pool_service.export_snapshot(&name).await?;
+
+        if let Some(signer_verkey) = config.signer_verkey {
+            self.crypto_service.validate_key(&signer_verkey).await?;
+
+            let key: Key = self
+                .wallet_service
+                .get_indy_object(wallet_handle, &signer_verkey, &RecordOptions::id_value())
+                .await?;
+
+            let signature = self
+                .crypto_service
+                .sign(&key, &snapshot.signed_data())
+                .await?;
+
+            snapshot.signature = Some(PoolSnapshotSignature {
+                verkey: key.verkey,
+                signature: signature.to_base58(),
+            });
+        }
+
+        let snapshot = serde_json::to_string(&snapshot)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize pool snapshot")?;
+
+        let res = Ok(snapshot);
+        trace!("export_snapshot < {:?}", res);
+        res
+    }
+
+    pub(crate) fn verify_ledger_reply(
+        &self,
+        pool_name: Option<String>,
+        genesis_txns: Option<String>,
+        request: String,
+        reply: String,
+    ) -> IndyResult<String> {
+        trace!(
+            "verify_ledger_reply > pool_name {:?} genesis_txns {:?} request {:?} reply {:?}",
+            pool_name, genesis_txns, request, reply
+        );
+
+        let verification = self.pool_service.verify_ledger_reply(
+            pool_name.as_deref(),
+            genesis_txns.as_deref(),
+            &request,
+            &reply,
+        )?;
+
+        let verification = serde_json::to_string(&verification)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize reply verification")?;
+
+        let res = Ok(verification);
+        trace!("verify_ledger_reply < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn get_pool_handle_for_namespace(&self, namespace: String) -> IndyResult<PoolHandle> {
+        trace!("get_pool_handle_for_namespace > namespace {:?}", namespace);
+
+        let handle = self
+            .pool_service
+            .get_pool_handle_for_namespace(&namespace)
+            .await?;
+
+        let res = Ok(handle);
+        trace!("get_pool_handle_for_namespace < {:?}", res);
+        res
+    }
+
     pub(crate) fn set_protocol_version(&self, version: usize) -> IndyResult<()> {
         trace!("set_protocol_version > version {:?}", version);
 
@@ -10,9 +10,8 @@ use ursa::cl::{
 use crate::utils::qualifier;
 
 use super::super::{
-    anoncreds::{schema::SchemaId, DELIMITER},
+    anoncreds::{indy_object_id, indy_object_parts, schema::SchemaId, DELIMITER},
     crypto::did::DidValue,
-    ledger::request::ProtocolVersion,
 };
 
 pub const CL_SIGNATURE_TYPE: &str = "CL";
@@ -138,6 +137,7 @@ qualifiable_type!(CredentialDefinitionId);
 impl CredentialDefinitionId {
     pub const PREFIX: &'static str = "creddef";
     pub const MARKER: &'static str = "3";
+    pub const INDY_OBJECT_TYPE: &'static str = "CLAIM_DEF";
 
     pub fn new(
         did: &DidValue,
@@ -145,42 +145,55 @@ impl CredentialDefinitionId {
         signature_type: &str,
         tag: &str,
     ) -> CredentialDefinitionId {
-        let id = if ProtocolVersion::is_node_1_3() {
-            CredentialDefinitionId(format!(
-                "{}{}{}{}{}{}{}",
-                did.0,
-                DELIMITER,
-                Self::MARKER,
-                DELIMITER,
-                signature_type,
-                DELIMITER,
-                schema_id.0
-            ))
+        if did.indy_namespace().is_some() {
+            // did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e/anoncreds/v0/CLAIM_DEF/1/tag
+            // Signature type is always CL, Schema is referenced by its sequence number
+            return CredentialDefinitionId(indy_object_id(did, Self::INDY_OBJECT_TYPE, &[&schema_id.0, tag]));
+        }
+
+        let tag = if tag.is_empty() {
+            format!("")
         } else {
-            let tag = if tag.is_empty() {
-                format!("")
-            } else {
-                format!("{}{}", DELIMITER, tag)
-            };
-            CredentialDefinitionId(format!(
-                "{}{}{}{}{}{}{}{}",
-                did.0,
-                DELIMITER,
-                Self::MARKER,
-                DELIMITER,
-                signature_type,
-                DELIMITER,
-                schema_id.0,
-                tag
-            ))
+            format!("{}{}", DELIMITER, tag)
         };
+        let id = CredentialDefinitionId(format!(
+            "{}{}{}{}{}{}{}{}",
+            did.0,
+            DELIMITER,
+            Self::MARKER,
+            DELIMITER,
+            signature_type,
+            DELIMITER,
+            schema_id.0,
+            tag
+        ));
         match did.get_method() {
             Some(method) => id.set_method(&method),
             None => id,
         }
     }
 
+    /// Id of a new credential definition to be published on a pool of the given protocol version.
+    /// Node 1.3 pools (protocol version 1) don't support tags in legacy credential definition ids.
+    pub fn for_protocol_version(
+        did: &DidValue,
+        schema_id: &SchemaId,
+        signature_type: &str,
+        tag: &str,
+        protocol_version: usize,
+    ) -> CredentialDefinitionId {
+        let tag = if protocol_version == 1 && did.indy_namespace().is_none() { "" } else { tag };
+        CredentialDefinitionId::new(did, schema_id, signature_type, tag)
+    }
+
     pub fn parts(&self) -> Option<(DidValue, String, SchemaId, String)> {
+        if let Some((did, parts)) = indy_object_parts(&self.0, Self::INDY_OBJECT_TYPE) {
+            return match parts.as_slice() {
+                [schema_id, tag] => Some((did, CL_SIGNATURE_TYPE.to_string(), SchemaId(schema_id.to_string()), tag.to_string())),
+                _ => None,
+            };
+        }
+
         let parts = self.0.split_terminator(DELIMITER).collect::<Vec<&str>>();
 
         if parts.len() == 4 {
@@ -341,6 +354,79 @@ mod tests {
         CredentialDefinitionId("creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag".to_string())
     }
 
+    fn _did_indy() -> DidValue {
+        DidValue("did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e".to_string())
+    }
+
+    fn _cred_def_id_indy() -> CredentialDefinitionId {
+        CredentialDefinitionId("did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e/anoncreds/v0/CLAIM_DEF/1/tag".to_string())
+    }
+
+    mod indy {
+        use super::*;
+
+        #[test]
+        fn test_cred_def_id_new_for_did_indy() {
+            assert_eq!(
+                _cred_def_id_indy(),
+                CredentialDefinitionId::new(&_did_indy(), &_schema_id_seq_no(), &_signature_type(), &_tag())
+            );
+        }
+
+        #[test]
+        fn test_cred_def_id_parts_for_did_indy() {
+            let (did, signature_type, schema_id, tag) = _cred_def_id_indy().parts().unwrap();
+            assert_eq!(_did_indy(), did);
+            assert_eq!(_signature_type(), signature_type);
+            assert_eq!(_schema_id_seq_no(), schema_id);
+            assert_eq!(_tag(), tag);
+        }
+
+        #[test]
+        fn test_cred_def_id_to_unqualified_for_did_indy() {
+            assert_eq!(
+                _cred_def_id_unqualified_with_schema_as_seq_no(),
+                _cred_def_id_indy().to_unqualified()
+            );
+        }
+
+        #[test]
+        fn test_cred_def_id_qualify_for_did_indy_method() {
+            assert_eq!(
+                _cred_def_id_indy(),
+                _cred_def_id_unqualified_with_schema_as_seq_no().qualify("indy:sovrin")
+            );
+        }
+    }
+
+    mod for_protocol_version {
+        use super::*;
+
+        #[test]
+        fn test_cred_def_id_for_protocol_version_2_keeps_tag() {
+            assert_eq!(
+                _cred_def_id_unqualified_with_schema_as_seq_no(),
+                CredentialDefinitionId::for_protocol_version(&_did(), &_schema_id_seq_no(), &_signature_type(), &_tag(), 2)
+            );
+        }
+
+        #[test]
+        fn test_cred_def_id_for_protocol_version_1_drops_tag() {
+            assert_eq!(
+                _cred_def_id_unqualified_with_schema_as_seq_no_without_tag(),
+                CredentialDefinitionId::for_protocol_version(&_did(), &_schema_id_seq_no(), &_signature_type(), &_tag(), 1)
+            );
+        }
+
+        #[test]
+        fn test_cred_def_id_for_protocol_version_1_keeps_tag_for_did_indy() {
+            assert_eq!(
+                _cred_def_id_indy(),
+                CredentialDefinitionId::for_protocol_version(&_did_indy(), &_schema_id_seq_no(), &_signature_type(), &_tag(), 1)
+            );
+        }
+    }
+
     mod to_unqualified {
         use super::*;
 
@@ -1,3 +1,6 @@
+use super::crypto::did::DidValue;
+use crate::utils::qualifier;
+
 pub mod credential;
 pub mod credential_attr_tag_policy;
 pub mod credential_definition;
@@ -15,3 +18,25 @@ pub mod schema;
 pub mod master_secret;
 
 pub const DELIMITER: &str = ":";
+
+pub const INDY_OBJECT_PATH: &str = "/anoncreds/v0/";
+pub const INDY_OBJECT_DELIMITER: &str = "/";
+
+/// Builds did:indy identifier of anoncreds object:
+/// did:indy:<namespace>:<did>/anoncreds/v0/<object_type>/<parts>
+pub fn indy_object_id(did: &DidValue, object_type: &str, parts: &[&str]) -> String {
+    format!("{}{}{}{}{}", did.0, INDY_OBJECT_PATH, object_type, INDY_OBJECT_DELIMITER, parts.join(INDY_OBJECT_DELIMITER))
+}
+
+/// Splits did:indy identifier of anoncreds object of the given type into the DID and path parts.
+pub fn indy_object_parts<'a>(id: &'a str, object_type: &str) -> Option<(DidValue, Vec<&'a str>)> {
+    if !qualifier::is_indy_qualified(id) {
+        return None;
+    }
+
+    let marker = format!("{}{}{}", INDY_OBJECT_PATH, object_type, INDY_OBJECT_DELIMITER);
+    let pos = id.find(&marker)?;
+    let parts = id[pos + marker.len()..].split(INDY_OBJECT_DELIMITER).collect();
+
+    Some((DidValue(id[..pos].to_string()), parts))
+}
@@ -6,7 +6,12 @@ use regex::Regex;
 use ursa::cl::{RevocationKeyPrivate, RevocationKeyPublic};
 
 use super::super::{
-        anoncreds::{credential_definition::CredentialDefinitionId, DELIMITER},
+        anoncreds::{
+            credential_definition::{CredentialDefinitionId, CL_SIGNATURE_TYPE},
+            indy_object_id, indy_object_parts,
+            schema::SchemaId,
+            DELIMITER,
+        },
         crypto::did::DidValue,
 };
 
@@ -146,6 +151,7 @@ qualifiable_type!(RevocationRegistryId);
 
 impl RevocationRegistryId {
     pub const PREFIX: &'static str = "revreg";
+    pub const INDY_OBJECT_TYPE: &'static str = "REV_REG_DEF";
 
     pub fn new(
         did: &DidValue,
@@ -153,6 +159,18 @@ impl RevocationRegistryId {
         rev_reg_type: &str,
         tag: &str,
     ) -> RevocationRegistryId {
+        if did.indy_namespace().is_some() {
+            if let Some((_, _, schema_id, cred_def_tag)) = cred_def_id.parts() {
+                // did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e/anoncreds/v0/REV_REG_DEF/1/tag/TAG_1
+                // Revocation registry type is always CL_ACCUM
+                return RevocationRegistryId(indy_object_id(
+                    did,
+                    Self::INDY_OBJECT_TYPE,
+                    &[&schema_id.0, &cred_def_tag, tag],
+                ));
+            }
+        }
+
         let id = RevocationRegistryId(format!(
             "{}{}{}{}{}{}{}{}{}",
             did.0,
@@ -172,6 +190,21 @@ impl RevocationRegistryId {
     }
 
     pub fn parts(&self) -> Option<(DidValue, CredentialDefinitionId, String, String)> {
+        if let Some((did, parts)) = indy_object_parts(&self.0, Self::INDY_OBJECT_TYPE) {
+            return match parts.as_slice() {
+                [schema_id, cred_def_tag, tag] => {
+                    let cred_def_id = CredentialDefinitionId::new(
+                        &did,
+                        &SchemaId(schema_id.to_string()),
+                        CL_SIGNATURE_TYPE,
+                        cred_def_tag,
+                    );
+                    Some((did, cred_def_id, CL_ACCUM.to_string(), tag.to_string()))
+                }
+                _ => None,
+            };
+        }
+
         match QUALIFIED_REV_REG_ID.captures(&self.0) {
             Some(caps) => Some((
                 DidValue(caps["did"].to_string()),
@@ -266,6 +299,47 @@ mod tests {
         RevocationRegistryId("revreg:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:4:creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag:CL_ACCUM:TAG_1".to_string())
     }
 
+    fn _did_indy() -> DidValue {
+        DidValue("did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e".to_string())
+    }
+
+    fn _cred_def_id_indy() -> CredentialDefinitionId {
+        CredentialDefinitionId("did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e/anoncreds/v0/CLAIM_DEF/1/tag".to_string())
+    }
+
+    fn _rev_reg_id_indy() -> RevocationRegistryId {
+        RevocationRegistryId("did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e/anoncreds/v0/REV_REG_DEF/1/tag/TAG_1".to_string())
+    }
+
+    mod indy {
+        use super::*;
+
+        #[test]
+        fn test_rev_reg_id_new_for_did_indy() {
+            assert_eq!(
+                _rev_reg_id_indy(),
+                RevocationRegistryId::new(&_did_indy(), &_cred_def_id_indy(), &_rev_reg_type(), &_tag())
+            );
+        }
+
+        #[test]
+        fn test_rev_reg_id_parts_for_did_indy() {
+            let (did, cred_def_id, rev_reg_type, tag) = _rev_reg_id_indy().parts().unwrap();
+            assert_eq!(_did_indy(), did);
+            assert_eq!(_cred_def_id_indy(), cred_def_id);
+            assert_eq!(_rev_reg_type(), rev_reg_type);
+            assert_eq!(_tag(), tag);
+        }
+
+        #[test]
+        fn test_rev_reg_id_to_unqualified_for_did_indy() {
+            assert_eq!(
+                RevocationRegistryId("NcYxiDXkpYi6ov5FcYDi1e:4:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag:CL_ACCUM:TAG_1".to_string()),
+                _rev_reg_id_indy().to_unqualified()
+            );
+        }
+    }
+
     mod to_unqualified {
         use super::*;
 
@@ -1,4 +1,4 @@
-use super::DELIMITER;
+use super::{indy_object_id, indy_object_parts, DELIMITER};
 
 use super::super::crypto::did::DidValue;
 
@@ -117,8 +117,14 @@ qualifiable_type!(SchemaId);
 impl SchemaId {
     pub const PREFIX: &'static str = "schema";
     pub const MARKER: &'static str = "2";
+    pub const INDY_OBJECT_TYPE: &'static str = "SCHEMA";
 
     pub fn new(did: &DidValue, name: &str, version: &str) -> SchemaId {
+        if did.indy_namespace().is_some() {
+            // did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e/anoncreds/v0/SCHEMA/gvt/1.0
+            return SchemaId(indy_object_id(did, Self::INDY_OBJECT_TYPE, &[name, version]));
+        }
+
         let id = SchemaId(format!("{}{}{}{}{}{}{}", did.0, DELIMITER, Self::MARKER, DELIMITER, name, DELIMITER, version));
         match did.get_method() {
             Some(method) => id.set_method(&method),
@@ -127,6 +133,13 @@ impl SchemaId {
     }
 
     pub fn parts(&self) -> Option<(DidValue, String, String)> {
+        if let Some((did, parts)) = indy_object_parts(&self.0, Self::INDY_OBJECT_TYPE) {
+            return match parts.as_slice() {
+                [name, version] => Some((did, name.to_string(), version.to_string())),
+                _ => None,
+            };
+        }
+
         let parts = self.0.split_terminator(DELIMITER).collect::<Vec<&str>>();
 
         if parts.len() == 1 {
@@ -208,6 +221,14 @@ mod tests {
         SchemaId("schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0".to_string())
     }
 
+    fn _did_indy() -> DidValue {
+        DidValue("did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e".to_string())
+    }
+
+    fn _schema_id_indy() -> SchemaId {
+        SchemaId("did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e/anoncreds/v0/SCHEMA/gvt/1.0".to_string())
+    }
+
     fn _schema_id_invalid() -> SchemaId {
         SchemaId("NcYxiDXkpYi6ov5FcYDi1e:2".to_string())
     }
@@ -234,6 +255,25 @@ mod tests {
         fn test_schema_id_parts_for_invalid_unqualified() {
             assert_eq!(_schema_id_invalid(), _schema_id_invalid().to_unqualified());
         }
+
+        #[test]
+        fn test_schema_id_unqualify_for_id_as_did_indy() {
+            assert_eq!(_schema_id_unqualified(), _schema_id_indy().to_unqualified());
+        }
+    }
+
+    mod new {
+        use super::*;
+
+        #[test]
+        fn test_schema_id_new_for_did_indy() {
+            assert_eq!(_schema_id_indy(), SchemaId::new(&_did_indy(), "gvt", "1.0"));
+        }
+
+        #[test]
+        fn test_schema_id_qualify_for_did_indy_method() {
+            assert_eq!(_schema_id_indy(), _schema_id_unqualified().qualify("indy:sovrin"));
+        }
     }
 
     mod parts {
@@ -260,6 +300,16 @@ mod tests {
         fn test_schema_id_parts_for_invalid_unqualified() {
             assert!(_schema_id_invalid().parts().is_none());
         }
+
+        #[test]
+        fn test_schema_id_parts_for_id_as_did_indy() {
+            let (did, name, version) = _schema_id_indy().parts().unwrap();
+            assert_eq!(_did_indy(), did);
+            assert_eq!("gvt", name);
+            assert_eq!("1.0", version);
+            assert!(_schema_id_indy().is_fully_qualified());
+            assert_eq!(Some("indy:sovrin".to_string()), _schema_id_indy().get_method());
+        }
     }
 
     mod validate {
@@ -280,6 +330,11 @@ mod tests {
             _schema_id_qualified().validate().unwrap();
         }
 
+        #[test]
+        fn test_validate_schema_id_as_did_indy() {
+            _schema_id_indy().validate().unwrap();
+        }
+
         #[test]
         fn test_validate_schema_id_for_invalid_unqualified() {
             _schema_id_invalid().validate().unwrap_err();
@@ -1,3 +1,5 @@
+use super::anoncreds::revocation_registry_delta::RevocationRegistryDelta;
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PurgeOptions {
@@ -11,4 +13,14 @@ pub struct GetCacheOptions {
     pub no_update: Option<bool>,    // Use only cached data, do not try to update.
     pub no_store: Option<bool>,     // Skip storing fresh data if updated
     pub min_fresh: Option<i32>,     // Return cached data if not older than this many seconds. -1 means do not check age.
-}
\ No newline at end of file
+}
+
+/// Revocation registry delta stored in the cache for the interval from requested `from` to `to`.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedRevocRegDelta {
+    pub revoc_reg_def_id: String,
+    pub delta: RevocationRegistryDelta,
+    pub timestamp: u64,             // Time of the accumulator returned by the ledger
+    pub to: i64,
+}
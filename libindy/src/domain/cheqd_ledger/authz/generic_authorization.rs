@@ -0,0 +1,54 @@
+use cosmrs::proto::cosmos::authz::v1beta1::GenericAuthorization as ProtoGenericAuthorization;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// GenericAuthorization gives the grantee unrestricted permissions to execute
+/// the provided method on behalf of the granter's account.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct GenericAuthorization {
+    pub msg: String,
+}
+
+impl GenericAuthorization {
+    pub fn new(
+        msg: String,
+    ) -> Self {
+        GenericAuthorization {
+            msg,
+        }
+    }
+}
+
+impl CheqdProto for GenericAuthorization {
+    type Proto = ProtoGenericAuthorization;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            msg: self.msg.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.msg.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_generic_authorization() {
+        let msg = GenericAuthorization::new(
+            "msg".to_string(),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = GenericAuthorization::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
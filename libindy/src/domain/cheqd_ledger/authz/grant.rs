@@ -0,0 +1,88 @@
+use cosmrs::proto::cosmos::authz::v1beta1::Grant as ProtoGrant;
+use indy_api_types::errors::{IndyErrorKind, IndyResult, IndyError};
+
+use super::super::CheqdProto;
+use super::super::prost_ext::ProstMessageExt;
+use super::super::authz::GenericAuthorization;
+use super::super::base::Timestamp;
+
+const GENERIC_AUTHORIZATION_TYPE_URL: &str = "/cosmos.authz.v1beta1.GenericAuthorization";
+
+/// Grant gives permissions to execute the provide method with expiration time.
+///
+/// Only `GenericAuthorization` is supported.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Grant {
+    pub authorization: Option<GenericAuthorization>,
+    pub expiration: Option<Timestamp>,
+}
+
+impl Grant {
+    pub fn new(
+        authorization: Option<GenericAuthorization>,
+        expiration: Option<Timestamp>,
+    ) -> Self {
+        Grant {
+            authorization,
+            expiration,
+        }
+    }
+}
+
+impl CheqdProto for Grant {
+    type Proto = ProtoGrant;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            authorization: self
+                .authorization
+                .as_ref()
+                .map(|p| p.to_proto().to_any(GENERIC_AUTHORIZATION_TYPE_URL)),
+            expiration: self.expiration.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let authorization = proto
+            .authorization
+            .as_ref()
+            .map(|p| {
+                if p.type_url != GENERIC_AUTHORIZATION_TYPE_URL {
+                    return Err(IndyError::from_msg(
+                        IndyErrorKind::InvalidStructure,
+                        format!("Unsupported authorization type: {}", p.type_url),
+                    ));
+                }
+                GenericAuthorization::from_proto_bytes(&p.value)
+            })
+            .transpose()?;
+        let expiration = proto
+            .expiration
+            .as_ref()
+            .map(|p| Timestamp::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            authorization,
+            expiration,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_grant() {
+        let msg = Grant::new(
+            Some(GenericAuthorization::new("/cheqdid.cheqdnode.cheqd.MsgCreateNym".to_string())),
+            Some(Timestamp::new(1, 0)),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = Grant::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
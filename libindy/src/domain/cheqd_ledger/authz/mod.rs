@@ -0,0 +1,19 @@
+//! Authz module related models
+
+pub use generic_authorization::GenericAuthorization;
+pub use grant::Grant;
+pub use msg_grant::MsgGrant;
+pub use msg_grant_response::MsgGrantResponse;
+pub use msg_exec::MsgExec;
+pub use msg_exec_response::MsgExecResponse;
+pub use msg_revoke::MsgRevoke;
+pub use msg_revoke_response::MsgRevokeResponse;
+
+mod generic_authorization;
+mod grant;
+mod msg_grant;
+mod msg_grant_response;
+mod msg_exec;
+mod msg_exec_response;
+mod msg_revoke;
+mod msg_revoke_response;
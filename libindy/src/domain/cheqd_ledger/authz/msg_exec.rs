@@ -0,0 +1,72 @@
+use cosmrs::proto::cosmos::authz::v1beta1::MsgExec as ProtoMsgExec;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::CheqdProtoMsg;
+use super::super::base::Any;
+
+/// MsgExec attempts to execute the provided messages using
+/// authorizations granted to the grantee.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgExec {
+    pub grantee: String,
+    pub msgs: Vec<Any>,
+}
+
+impl MsgExec {
+    pub fn new(
+        grantee: String,
+        msgs: Vec<Any>,
+    ) -> Self {
+        MsgExec {
+            grantee,
+            msgs,
+        }
+    }
+}
+
+impl CheqdProto for MsgExec {
+    type Proto = ProtoMsgExec;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            grantee: self.grantee.clone(),
+            msgs: self.msgs.iter().map(|p| p.to_proto()).collect(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let msgs = proto
+            .msgs
+            .iter()
+            .map(|p| Any::from_proto(p))
+            .collect::<IndyResult<Vec<Any>>>()?;
+
+        Ok(Self::new(
+            proto.grantee.clone(),
+            msgs,
+        ))
+    }
+}
+
+impl CheqdProtoMsg for MsgExec {
+    const TYPE_URL: &'static str = "/cosmos.authz.v1beta1.MsgExec";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_exec() {
+        let msg = MsgExec::new(
+            "grantee".to_string(),
+            vec![],
+        );
+
+        let proto = msg.to_proto();
+        let decoded = MsgExec::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
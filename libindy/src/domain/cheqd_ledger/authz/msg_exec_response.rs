@@ -0,0 +1,53 @@
+use cosmrs::proto::cosmos::authz::v1beta1::MsgExecResponse as ProtoMsgExecResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// MsgExecResponse defines the Msg/MsgExecResponse response type.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgExecResponse {
+    pub results: Vec<Vec<u8>>,
+}
+
+impl MsgExecResponse {
+    pub fn new(
+        results: Vec<Vec<u8>>,
+    ) -> Self {
+        MsgExecResponse {
+            results,
+        }
+    }
+}
+
+impl CheqdProto for MsgExecResponse {
+    type Proto = ProtoMsgExecResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            results: self.results.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.results.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_exec_response() {
+        let msg = MsgExecResponse::new(
+            vec![vec![1, 2, 3]],
+        );
+
+        let proto = msg.to_proto();
+        let decoded = MsgExecResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,78 @@
+use cosmrs::proto::cosmos::authz::v1beta1::MsgGrant as ProtoMsgGrant;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::CheqdProtoMsg;
+use super::super::authz::Grant;
+
+/// MsgGrant is a request type for Grant method. It declares authorization to the grantee
+/// on behalf of the granter with the provided expiration time.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgGrant {
+    pub granter: String,
+    pub grantee: String,
+    pub grant: Option<Grant>,
+}
+
+impl MsgGrant {
+    pub fn new(
+        granter: String,
+        grantee: String,
+        grant: Option<Grant>,
+    ) -> Self {
+        MsgGrant {
+            granter,
+            grantee,
+            grant,
+        }
+    }
+}
+
+impl CheqdProto for MsgGrant {
+    type Proto = ProtoMsgGrant;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            granter: self.granter.clone(),
+            grantee: self.grantee.clone(),
+            grant: self.grant.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let grant = proto
+            .grant
+            .as_ref()
+            .map(|p| Grant::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            proto.granter.clone(),
+            proto.grantee.clone(),
+            grant,
+        ))
+    }
+}
+
+impl CheqdProtoMsg for MsgGrant {
+    const TYPE_URL: &'static str = "/cosmos.authz.v1beta1.MsgGrant";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_grant() {
+        let msg = MsgGrant::new(
+            "granter".to_string(),
+            "grantee".to_string(),
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = MsgGrant::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
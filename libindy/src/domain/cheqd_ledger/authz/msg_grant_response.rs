@@ -0,0 +1,42 @@
+use cosmrs::proto::cosmos::authz::v1beta1::MsgGrantResponse as ProtoMsgGrantResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// MsgGrantResponse defines the Msg/MsgGrant response type.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgGrantResponse {
+}
+
+impl MsgGrantResponse {
+    pub fn new() -> Self {
+        MsgGrantResponse {}
+    }
+}
+
+impl CheqdProto for MsgGrantResponse {
+    type Proto = ProtoMsgGrantResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {}
+    }
+
+    fn from_proto(_proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_grant_response() {
+        let msg = MsgGrantResponse::new();
+
+        let proto = msg.to_proto();
+        let decoded = MsgGrantResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,71 @@
+use cosmrs::proto::cosmos::authz::v1beta1::MsgRevoke as ProtoMsgRevoke;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::CheqdProtoMsg;
+
+/// MsgRevoke revokes any authorization with the provided sdk.Msg type on the
+/// granter's account with that has been granted to the grantee.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgRevoke {
+    pub granter: String,
+    pub grantee: String,
+    pub msg_type_url: String,
+}
+
+impl MsgRevoke {
+    pub fn new(
+        granter: String,
+        grantee: String,
+        msg_type_url: String,
+    ) -> Self {
+        MsgRevoke {
+            granter,
+            grantee,
+            msg_type_url,
+        }
+    }
+}
+
+impl CheqdProto for MsgRevoke {
+    type Proto = ProtoMsgRevoke;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            granter: self.granter.clone(),
+            grantee: self.grantee.clone(),
+            msg_type_url: self.msg_type_url.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.granter.clone(),
+            proto.grantee.clone(),
+            proto.msg_type_url.clone(),
+        ))
+    }
+}
+
+impl CheqdProtoMsg for MsgRevoke {
+    const TYPE_URL: &'static str = "/cosmos.authz.v1beta1.MsgRevoke";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_revoke() {
+        let msg = MsgRevoke::new(
+            "granter".to_string(),
+            "grantee".to_string(),
+            "msg_type_url".to_string(),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = MsgRevoke::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,42 @@
+use cosmrs::proto::cosmos::authz::v1beta1::MsgRevokeResponse as ProtoMsgRevokeResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// MsgRevokeResponse defines the Msg/MsgRevokeResponse response type.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgRevokeResponse {
+}
+
+impl MsgRevokeResponse {
+    pub fn new() -> Self {
+        MsgRevokeResponse {}
+    }
+}
+
+impl CheqdProto for MsgRevokeResponse {
+    type Proto = ProtoMsgRevokeResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {}
+    }
+
+    fn from_proto(_proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_revoke_response() {
+        let msg = MsgRevokeResponse::new();
+
+        let proto = msg.to_proto();
+        let decoded = MsgRevokeResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,59 @@
+use prost_types::Any as ProtoAny;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// Arbitrary serialized protobuf message along with a URL that describes its type.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Any {
+    pub type_url: String,
+    pub value: Vec<u8>,
+}
+
+impl Any {
+    pub fn new(
+        type_url: String,
+        value: Vec<u8>,
+    ) -> Self {
+        Any {
+            type_url,
+            value,
+        }
+    }
+}
+
+impl CheqdProto for Any {
+    type Proto = ProtoAny;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            type_url: self.type_url.clone(),
+            value: self.value.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.type_url.clone(),
+            proto.value.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_any() {
+        let msg = Any::new(
+            "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            vec![1, 2, 3],
+        );
+
+        let proto = msg.to_proto();
+        let decoded = Any::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -1 +1,7 @@
 pub mod query;
+
+pub use any::Any;
+pub use timestamp::Timestamp;
+
+mod any;
+mod timestamp;
@@ -0,0 +1,59 @@
+use prost_types::Timestamp as ProtoTimestamp;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// Point in time independent of any time zone, used by cosmos messages and queries.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Timestamp {
+    pub seconds: i64,
+    pub nanos: i32,
+}
+
+impl Timestamp {
+    pub fn new(
+        seconds: i64,
+        nanos: i32,
+    ) -> Self {
+        Timestamp {
+            seconds,
+            nanos,
+        }
+    }
+}
+
+impl CheqdProto for Timestamp {
+    type Proto = ProtoTimestamp;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            seconds: self.seconds,
+            nanos: self.nanos,
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.seconds,
+            proto.nanos,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_timestamp() {
+        let msg = Timestamp::new(
+            1,
+            1,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = Timestamp::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,59 @@
+use cosmrs::proto::cosmos::base::v1beta1::DecCoin as ProtoDecCoin;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// DecCoin defines a token with a denomination and a decimal amount.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct DecCoin {
+    pub denom: String,
+    pub amount: String,
+}
+
+impl DecCoin {
+    pub fn new(
+        denom: String,
+        amount: String,
+    ) -> Self {
+        DecCoin {
+            denom,
+            amount,
+        }
+    }
+}
+
+impl CheqdProto for DecCoin {
+    type Proto = ProtoDecCoin;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            denom: self.denom.clone(),
+            amount: self.amount.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.denom.clone(),
+            proto.amount.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dec_coin() {
+        let msg = DecCoin::new(
+            "denom".to_string(),
+            "amount".to_string(),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = DecCoin::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,67 @@
+use cosmrs::proto::cosmos::distribution::v1beta1::DelegationDelegatorReward as ProtoDelegationDelegatorReward;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::distribution::DecCoin;
+
+/// DelegationDelegatorReward represents the properties
+/// of a delegator's delegation reward.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DelegationDelegatorReward {
+    pub validator_address: String,
+    pub reward: Vec<DecCoin>,
+}
+
+impl DelegationDelegatorReward {
+    pub fn new(
+        validator_address: String,
+        reward: Vec<DecCoin>,
+    ) -> Self {
+        DelegationDelegatorReward {
+            validator_address,
+            reward,
+        }
+    }
+}
+
+impl CheqdProto for DelegationDelegatorReward {
+    type Proto = ProtoDelegationDelegatorReward;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            validator_address: self.validator_address.clone(),
+            reward: self.reward.iter().map(|p| p.to_proto()).collect(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let reward = proto
+            .reward
+            .iter()
+            .map(|p| DecCoin::from_proto(p))
+            .collect::<IndyResult<Vec<DecCoin>>>()?;
+
+        Ok(Self::new(
+            proto.validator_address.clone(),
+            reward,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_delegation_delegator_reward() {
+        let msg = DelegationDelegatorReward::new(
+            "validator_address".to_string(),
+            vec![],
+        );
+
+        let proto = msg.to_proto();
+        let decoded = DelegationDelegatorReward::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,15 @@
+//! Distribution module related models
+
+pub use msg_withdraw_delegator_reward::MsgWithdrawDelegatorReward;
+pub use msg_withdraw_delegator_reward_response::MsgWithdrawDelegatorRewardResponse;
+pub use delegation_delegator_reward::DelegationDelegatorReward;
+pub use query_delegation_total_rewards_request::QueryDelegationTotalRewardsRequest;
+pub use query_delegation_total_rewards_response::QueryDelegationTotalRewardsResponse;
+pub use dec_coin::DecCoin;
+
+mod msg_withdraw_delegator_reward;
+mod msg_withdraw_delegator_reward_response;
+mod delegation_delegator_reward;
+mod query_delegation_total_rewards_request;
+mod query_delegation_total_rewards_response;
+mod dec_coin;
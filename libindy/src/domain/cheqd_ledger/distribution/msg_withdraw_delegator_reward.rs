@@ -0,0 +1,65 @@
+use cosmrs::proto::cosmos::distribution::v1beta1::MsgWithdrawDelegatorReward as ProtoMsgWithdrawDelegatorReward;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::CheqdProtoMsg;
+
+/// MsgWithdrawDelegatorReward represents delegation withdrawal to a delegator
+/// from a single validator.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgWithdrawDelegatorReward {
+    pub delegator_address: String,
+    pub validator_address: String,
+}
+
+impl MsgWithdrawDelegatorReward {
+    pub fn new(
+        delegator_address: String,
+        validator_address: String,
+    ) -> Self {
+        MsgWithdrawDelegatorReward {
+            delegator_address,
+            validator_address,
+        }
+    }
+}
+
+impl CheqdProto for MsgWithdrawDelegatorReward {
+    type Proto = ProtoMsgWithdrawDelegatorReward;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            delegator_address: self.delegator_address.clone(),
+            validator_address: self.validator_address.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.delegator_address.clone(),
+            proto.validator_address.clone(),
+        ))
+    }
+}
+
+impl CheqdProtoMsg for MsgWithdrawDelegatorReward {
+    const TYPE_URL: &'static str = "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_withdraw_delegator_reward() {
+        let msg = MsgWithdrawDelegatorReward::new(
+            "delegator_address".to_string(),
+            "validator_address".to_string(),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = MsgWithdrawDelegatorReward::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
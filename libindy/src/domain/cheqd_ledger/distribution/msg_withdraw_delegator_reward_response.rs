@@ -0,0 +1,42 @@
+use cosmrs::proto::cosmos::distribution::v1beta1::MsgWithdrawDelegatorRewardResponse as ProtoMsgWithdrawDelegatorRewardResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// MsgWithdrawDelegatorRewardResponse defines the Msg/WithdrawDelegatorReward response type.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgWithdrawDelegatorRewardResponse {
+}
+
+impl MsgWithdrawDelegatorRewardResponse {
+    pub fn new() -> Self {
+        MsgWithdrawDelegatorRewardResponse {}
+    }
+}
+
+impl CheqdProto for MsgWithdrawDelegatorRewardResponse {
+    type Proto = ProtoMsgWithdrawDelegatorRewardResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {}
+    }
+
+    fn from_proto(_proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_withdraw_delegator_reward_response() {
+        let msg = MsgWithdrawDelegatorRewardResponse::new();
+
+        let proto = msg.to_proto();
+        let decoded = MsgWithdrawDelegatorRewardResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,54 @@
+use cosmrs::proto::cosmos::distribution::v1beta1::QueryDelegationTotalRewardsRequest as ProtoQueryDelegationTotalRewardsRequest;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// QueryDelegationTotalRewardsRequest is the request type for the
+/// Query/DelegationTotalRewards RPC method.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QueryDelegationTotalRewardsRequest {
+    pub delegator_address: String,
+}
+
+impl QueryDelegationTotalRewardsRequest {
+    pub fn new(
+        delegator_address: String,
+    ) -> Self {
+        QueryDelegationTotalRewardsRequest {
+            delegator_address,
+        }
+    }
+}
+
+impl CheqdProto for QueryDelegationTotalRewardsRequest {
+    type Proto = ProtoQueryDelegationTotalRewardsRequest;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            delegator_address: self.delegator_address.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.delegator_address.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query_delegation_total_rewards_request() {
+        let msg = QueryDelegationTotalRewardsRequest::new(
+            "delegator_address".to_string(),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = QueryDelegationTotalRewardsRequest::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
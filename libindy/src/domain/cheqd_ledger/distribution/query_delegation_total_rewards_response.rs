@@ -0,0 +1,73 @@
+use cosmrs::proto::cosmos::distribution::v1beta1::QueryDelegationTotalRewardsResponse as ProtoQueryDelegationTotalRewardsResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::distribution::DecCoin;
+use super::super::distribution::DelegationDelegatorReward;
+
+/// QueryDelegationTotalRewardsResponse is the response type for the
+/// Query/DelegationTotalRewards RPC method.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QueryDelegationTotalRewardsResponse {
+    pub rewards: Vec<DelegationDelegatorReward>,
+    pub total: Vec<DecCoin>,
+}
+
+impl QueryDelegationTotalRewardsResponse {
+    pub fn new(
+        rewards: Vec<DelegationDelegatorReward>,
+        total: Vec<DecCoin>,
+    ) -> Self {
+        QueryDelegationTotalRewardsResponse {
+            rewards,
+            total,
+        }
+    }
+}
+
+impl CheqdProto for QueryDelegationTotalRewardsResponse {
+    type Proto = ProtoQueryDelegationTotalRewardsResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            rewards: self.rewards.iter().map(|p| p.to_proto()).collect(),
+            total: self.total.iter().map(|p| p.to_proto()).collect(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let rewards = proto
+            .rewards
+            .iter()
+            .map(|p| DelegationDelegatorReward::from_proto(p))
+            .collect::<IndyResult<Vec<DelegationDelegatorReward>>>()?;
+        let total = proto
+            .total
+            .iter()
+            .map(|p| DecCoin::from_proto(p))
+            .collect::<IndyResult<Vec<DecCoin>>>()?;
+
+        Ok(Self::new(
+            rewards,
+            total,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query_delegation_total_rewards_response() {
+        let msg = QueryDelegationTotalRewardsResponse::new(
+            vec![],
+            vec![],
+        );
+
+        let proto = msg.to_proto();
+        let decoded = QueryDelegationTotalRewardsResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,73 @@
+use cosmrs::proto::cosmos::feegrant::v1beta1::BasicAllowance as ProtoBasicAllowance;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::bank::Coin;
+use super::super::base::Timestamp;
+
+/// BasicAllowance implements Allowance with a one-time grant of coins
+/// that optionally expires. The grantee can use up to SpendLimit to cover fees.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct BasicAllowance {
+    pub spend_limit: Vec<Coin>,
+    pub expiration: Option<Timestamp>,
+}
+
+impl BasicAllowance {
+    pub fn new(
+        spend_limit: Vec<Coin>,
+        expiration: Option<Timestamp>,
+    ) -> Self {
+        BasicAllowance {
+            spend_limit,
+            expiration,
+        }
+    }
+}
+
+impl CheqdProto for BasicAllowance {
+    type Proto = ProtoBasicAllowance;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            spend_limit: self.spend_limit.iter().map(|p| p.to_proto()).collect(),
+            expiration: self.expiration.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let spend_limit = proto
+            .spend_limit
+            .iter()
+            .map(|p| Coin::from_proto(p))
+            .collect::<IndyResult<Vec<Coin>>>()?;
+        let expiration = proto
+            .expiration
+            .as_ref()
+            .map(|p| Timestamp::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            spend_limit,
+            expiration,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_basic_allowance() {
+        let msg = BasicAllowance::new(
+            vec![],
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = BasicAllowance::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
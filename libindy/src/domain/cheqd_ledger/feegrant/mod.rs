@@ -0,0 +1,13 @@
+//! Feegrant module related models
+
+pub use basic_allowance::BasicAllowance;
+pub use msg_grant_allowance::MsgGrantAllowance;
+pub use msg_grant_allowance_response::MsgGrantAllowanceResponse;
+pub use msg_revoke_allowance::MsgRevokeAllowance;
+pub use msg_revoke_allowance_response::MsgRevokeAllowanceResponse;
+
+mod basic_allowance;
+mod msg_grant_allowance;
+mod msg_grant_allowance_response;
+mod msg_revoke_allowance;
+mod msg_revoke_allowance_response;
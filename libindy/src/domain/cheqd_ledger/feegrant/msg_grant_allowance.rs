@@ -0,0 +1,99 @@
+use cosmrs::proto::cosmos::feegrant::v1beta1::MsgGrantAllowance as ProtoMsgGrantAllowance;
+use indy_api_types::errors::{IndyErrorKind, IndyResult, IndyError};
+
+use super::super::CheqdProto;
+use super::super::CheqdProtoMsg;
+use super::super::prost_ext::ProstMessageExt;
+use super::super::feegrant::BasicAllowance;
+
+const BASIC_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.BasicAllowance";
+
+/// MsgGrantAllowance adds permission for Grantee to spend up to Allowance
+/// of fees from the account of Granter.
+///
+/// Only `BasicAllowance` is supported.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgGrantAllowance {
+    pub granter: String,
+    pub grantee: String,
+    pub allowance: Option<BasicAllowance>,
+}
+
+impl MsgGrantAllowance {
+    pub fn new(
+        granter: String,
+        grantee: String,
+        allowance: Option<BasicAllowance>,
+    ) -> Self {
+        MsgGrantAllowance {
+            granter,
+            grantee,
+            allowance,
+        }
+    }
+}
+
+impl CheqdProto for MsgGrantAllowance {
+    type Proto = ProtoMsgGrantAllowance;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            granter: self.granter.clone(),
+            grantee: self.grantee.clone(),
+            allowance: self
+                .allowance
+                .as_ref()
+                .map(|p| p.to_proto().to_any(BASIC_ALLOWANCE_TYPE_URL)),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let allowance = proto
+            .allowance
+            .as_ref()
+            .map(|p| {
+                if p.type_url != BASIC_ALLOWANCE_TYPE_URL {
+                    return Err(IndyError::from_msg(
+                        IndyErrorKind::InvalidStructure,
+                        format!("Unsupported fee allowance type: {}", p.type_url),
+                    ));
+                }
+                BasicAllowance::from_proto_bytes(&p.value)
+            })
+            .transpose()?;
+
+        Ok(Self::new(
+            proto.granter.clone(),
+            proto.grantee.clone(),
+            allowance,
+        ))
+    }
+}
+
+impl CheqdProtoMsg for MsgGrantAllowance {
+    const TYPE_URL: &'static str = "/cosmos.feegrant.v1beta1.MsgGrantAllowance";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::super::bank::Coin;
+    use super::super::super::base::Timestamp;
+
+    #[test]
+    fn test_msg_grant_allowance() {
+        let msg = MsgGrantAllowance::new(
+            "granter".to_string(),
+            "grantee".to_string(),
+            Some(BasicAllowance::new(
+                vec![Coin::new("cheq".to_string(), "100".to_string())],
+                Some(Timestamp::new(1, 0)),
+            )),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = MsgGrantAllowance::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,42 @@
+use cosmrs::proto::cosmos::feegrant::v1beta1::MsgGrantAllowanceResponse as ProtoMsgGrantAllowanceResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// MsgGrantAllowanceResponse defines the Msg/GrantAllowanceResponse response type.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgGrantAllowanceResponse {
+}
+
+impl MsgGrantAllowanceResponse {
+    pub fn new() -> Self {
+        MsgGrantAllowanceResponse {}
+    }
+}
+
+impl CheqdProto for MsgGrantAllowanceResponse {
+    type Proto = ProtoMsgGrantAllowanceResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {}
+    }
+
+    fn from_proto(_proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_grant_allowance_response() {
+        let msg = MsgGrantAllowanceResponse::new();
+
+        let proto = msg.to_proto();
+        let decoded = MsgGrantAllowanceResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
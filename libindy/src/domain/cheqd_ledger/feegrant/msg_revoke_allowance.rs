@@ -0,0 +1,64 @@
+use cosmrs::proto::cosmos::feegrant::v1beta1::MsgRevokeAllowance as ProtoMsgRevokeAllowance;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::CheqdProtoMsg;
+
+/// MsgRevokeAllowance removes any existing Allowance from Granter to Grantee.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgRevokeAllowance {
+    pub granter: String,
+    pub grantee: String,
+}
+
+impl MsgRevokeAllowance {
+    pub fn new(
+        granter: String,
+        grantee: String,
+    ) -> Self {
+        MsgRevokeAllowance {
+            granter,
+            grantee,
+        }
+    }
+}
+
+impl CheqdProto for MsgRevokeAllowance {
+    type Proto = ProtoMsgRevokeAllowance;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            granter: self.granter.clone(),
+            grantee: self.grantee.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.granter.clone(),
+            proto.grantee.clone(),
+        ))
+    }
+}
+
+impl CheqdProtoMsg for MsgRevokeAllowance {
+    const TYPE_URL: &'static str = "/cosmos.feegrant.v1beta1.MsgRevokeAllowance";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_revoke_allowance() {
+        let msg = MsgRevokeAllowance::new(
+            "granter".to_string(),
+            "grantee".to_string(),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = MsgRevokeAllowance::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,42 @@
+use cosmrs::proto::cosmos::feegrant::v1beta1::MsgRevokeAllowanceResponse as ProtoMsgRevokeAllowanceResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// MsgRevokeAllowanceResponse defines the Msg/RevokeAllowanceResponse response type.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgRevokeAllowanceResponse {
+}
+
+impl MsgRevokeAllowanceResponse {
+    pub fn new() -> Self {
+        MsgRevokeAllowanceResponse {}
+    }
+}
+
+impl CheqdProto for MsgRevokeAllowanceResponse {
+    type Proto = ProtoMsgRevokeAllowanceResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {}
+    }
+
+    fn from_proto(_proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_revoke_allowance_response() {
+        let msg = MsgRevokeAllowanceResponse::new();
+
+        let proto = msg.to_proto();
+        let decoded = MsgRevokeAllowanceResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
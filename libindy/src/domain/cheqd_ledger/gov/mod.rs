@@ -0,0 +1,49 @@
+//! Gov module related models
+
+pub use text_proposal::TextProposal;
+pub use param_change::ParamChange;
+pub use parameter_change_proposal::ParameterChangeProposal;
+pub use proposal_content::ProposalContent;
+pub use msg_submit_proposal::MsgSubmitProposal;
+pub use msg_submit_proposal_response::MsgSubmitProposalResponse;
+pub use msg_deposit::MsgDeposit;
+pub use msg_deposit_response::MsgDepositResponse;
+pub use msg_vote::MsgVote;
+pub use msg_vote_response::MsgVoteResponse;
+pub use weighted_vote_option::WeightedVoteOption;
+pub use weighted_vote::WeightedVote;
+pub use msg_vote_weighted::MsgVoteWeighted;
+pub use msg_vote_weighted_response::MsgVoteWeightedResponse;
+pub use tally_result::TallyResult;
+pub use proposal::Proposal;
+pub use vote::Vote;
+pub use query_proposal_response::QueryProposalResponse;
+pub use query_proposals_request::QueryProposalsRequest;
+pub use query_proposals_response::QueryProposalsResponse;
+pub use query_tally_result_request::QueryTallyResultRequest;
+pub use query_tally_result_response::QueryTallyResultResponse;
+pub use query_vote_response::QueryVoteResponse;
+
+mod text_proposal;
+mod param_change;
+mod parameter_change_proposal;
+mod proposal_content;
+mod msg_submit_proposal;
+mod msg_submit_proposal_response;
+mod msg_deposit;
+mod msg_deposit_response;
+mod msg_vote;
+mod msg_vote_response;
+mod weighted_vote_option;
+mod weighted_vote;
+mod msg_vote_weighted;
+mod msg_vote_weighted_response;
+mod tally_result;
+mod proposal;
+mod vote;
+mod query_proposal_response;
+mod query_proposals_request;
+mod query_proposals_response;
+mod query_tally_result_request;
+mod query_tally_result_response;
+mod query_vote_response;
@@ -0,0 +1,77 @@
+use cosmrs::proto::cosmos::gov::v1beta1::MsgDeposit as ProtoMsgDeposit;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::CheqdProtoMsg;
+use super::super::bank::Coin;
+
+/// MsgDeposit defines a message to submit a deposit to an existing proposal.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgDeposit {
+    pub proposal_id: u64,
+    pub depositor: String,
+    pub amount: Vec<Coin>,
+}
+
+impl MsgDeposit {
+    pub fn new(
+        proposal_id: u64,
+        depositor: String,
+        amount: Vec<Coin>,
+    ) -> Self {
+        MsgDeposit {
+            proposal_id,
+            depositor,
+            amount,
+        }
+    }
+}
+
+impl CheqdProto for MsgDeposit {
+    type Proto = ProtoMsgDeposit;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            proposal_id: self.proposal_id,
+            depositor: self.depositor.clone(),
+            amount: self.amount.iter().map(|p| p.to_proto()).collect(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let amount = proto
+            .amount
+            .iter()
+            .map(|p| Coin::from_proto(p))
+            .collect::<IndyResult<Vec<Coin>>>()?;
+
+        Ok(Self::new(
+            proto.proposal_id,
+            proto.depositor.clone(),
+            amount,
+        ))
+    }
+}
+
+impl CheqdProtoMsg for MsgDeposit {
+    const TYPE_URL: &'static str = "/cosmos.gov.v1beta1.MsgDeposit";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_deposit() {
+        let msg = MsgDeposit::new(
+            1,
+            "depositor".to_string(),
+            vec![],
+        );
+
+        let proto = msg.to_proto();
+        let decoded = MsgDeposit::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
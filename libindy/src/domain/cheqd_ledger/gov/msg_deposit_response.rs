@@ -0,0 +1,42 @@
+use cosmrs::proto::cosmos::gov::v1beta1::MsgDepositResponse as ProtoMsgDepositResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// MsgDepositResponse defines the Msg/Deposit response type.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgDepositResponse {
+}
+
+impl MsgDepositResponse {
+    pub fn new() -> Self {
+        MsgDepositResponse {}
+    }
+}
+
+impl CheqdProto for MsgDepositResponse {
+    type Proto = ProtoMsgDepositResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {}
+    }
+
+    fn from_proto(_proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_deposit_response() {
+        let msg = MsgDepositResponse::new();
+
+        let proto = msg.to_proto();
+        let decoded = MsgDepositResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
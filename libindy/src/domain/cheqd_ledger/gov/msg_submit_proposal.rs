@@ -0,0 +1,88 @@
+use cosmrs::proto::cosmos::gov::v1beta1::MsgSubmitProposal as ProtoMsgSubmitProposal;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::CheqdProtoMsg;
+use super::super::bank::Coin;
+use super::super::gov::ProposalContent;
+
+/// MsgSubmitProposal defines an sdk.Msg type that supports submitting arbitrary
+/// proposal Content.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgSubmitProposal {
+    pub content: Option<ProposalContent>,
+    pub initial_deposit: Vec<Coin>,
+    pub proposer: String,
+}
+
+impl MsgSubmitProposal {
+    pub fn new(
+        content: Option<ProposalContent>,
+        initial_deposit: Vec<Coin>,
+        proposer: String,
+    ) -> Self {
+        MsgSubmitProposal {
+            content,
+            initial_deposit,
+            proposer,
+        }
+    }
+}
+
+impl CheqdProto for MsgSubmitProposal {
+    type Proto = ProtoMsgSubmitProposal;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            content: self.content.as_ref().map(|p| p.to_proto()),
+            initial_deposit: self.initial_deposit.iter().map(|p| p.to_proto()).collect(),
+            proposer: self.proposer.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let content = proto
+            .content
+            .as_ref()
+            .map(|p| ProposalContent::from_proto(p))
+            .transpose()?;
+        let initial_deposit = proto
+            .initial_deposit
+            .iter()
+            .map(|p| Coin::from_proto(p))
+            .collect::<IndyResult<Vec<Coin>>>()?;
+
+        Ok(Self::new(
+            content,
+            initial_deposit,
+            proto.proposer.clone(),
+        ))
+    }
+}
+
+impl CheqdProtoMsg for MsgSubmitProposal {
+    const TYPE_URL: &'static str = "/cosmos.gov.v1beta1.MsgSubmitProposal";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::TextProposal;
+
+    #[test]
+    fn test_msg_submit_proposal() {
+        let msg = MsgSubmitProposal::new(
+            Some(ProposalContent::Text(TextProposal::new(
+                "title".to_string(),
+                "description".to_string(),
+            ))),
+            vec![Coin::new("cheq".to_string(), "100".to_string())],
+            "proposer".to_string(),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = MsgSubmitProposal::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,53 @@
+use cosmrs::proto::cosmos::gov::v1beta1::MsgSubmitProposalResponse as ProtoMsgSubmitProposalResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// MsgSubmitProposalResponse defines the Msg/SubmitProposal response type.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgSubmitProposalResponse {
+    pub proposal_id: u64,
+}
+
+impl MsgSubmitProposalResponse {
+    pub fn new(
+        proposal_id: u64,
+    ) -> Self {
+        MsgSubmitProposalResponse {
+            proposal_id,
+        }
+    }
+}
+
+impl CheqdProto for MsgSubmitProposalResponse {
+    type Proto = ProtoMsgSubmitProposalResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            proposal_id: self.proposal_id,
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.proposal_id,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_submit_proposal_response() {
+        let msg = MsgSubmitProposalResponse::new(
+            1,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = MsgSubmitProposalResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,70 @@
+use cosmrs::proto::cosmos::gov::v1beta1::MsgVote as ProtoMsgVote;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::CheqdProtoMsg;
+
+/// MsgVote defines a message to cast a vote.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgVote {
+    pub proposal_id: u64,
+    pub voter: String,
+    pub option: i32,
+}
+
+impl MsgVote {
+    pub fn new(
+        proposal_id: u64,
+        voter: String,
+        option: i32,
+    ) -> Self {
+        MsgVote {
+            proposal_id,
+            voter,
+            option,
+        }
+    }
+}
+
+impl CheqdProto for MsgVote {
+    type Proto = ProtoMsgVote;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            proposal_id: self.proposal_id,
+            voter: self.voter.clone(),
+            option: self.option,
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.proposal_id,
+            proto.voter.clone(),
+            proto.option,
+        ))
+    }
+}
+
+impl CheqdProtoMsg for MsgVote {
+    const TYPE_URL: &'static str = "/cosmos.gov.v1beta1.MsgVote";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_vote() {
+        let msg = MsgVote::new(
+            1,
+            "voter".to_string(),
+            1,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = MsgVote::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,42 @@
+use cosmrs::proto::cosmos::gov::v1beta1::MsgVoteResponse as ProtoMsgVoteResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// MsgVoteResponse defines the Msg/Vote response type.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgVoteResponse {
+}
+
+impl MsgVoteResponse {
+    pub fn new() -> Self {
+        MsgVoteResponse {}
+    }
+}
+
+impl CheqdProto for MsgVoteResponse {
+    type Proto = ProtoMsgVoteResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {}
+    }
+
+    fn from_proto(_proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_vote_response() {
+        let msg = MsgVoteResponse::new();
+
+        let proto = msg.to_proto();
+        let decoded = MsgVoteResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,77 @@
+use cosmrs::proto::cosmos::gov::v1beta1::MsgVoteWeighted as ProtoMsgVoteWeighted;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::CheqdProtoMsg;
+use super::super::gov::WeightedVoteOption;
+
+/// MsgVoteWeighted defines a message to cast a vote split between several options.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgVoteWeighted {
+    pub proposal_id: u64,
+    pub voter: String,
+    pub options: Vec<WeightedVoteOption>,
+}
+
+impl MsgVoteWeighted {
+    pub fn new(
+        proposal_id: u64,
+        voter: String,
+        options: Vec<WeightedVoteOption>,
+    ) -> Self {
+        MsgVoteWeighted {
+            proposal_id,
+            voter,
+            options,
+        }
+    }
+}
+
+impl CheqdProto for MsgVoteWeighted {
+    type Proto = ProtoMsgVoteWeighted;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            proposal_id: self.proposal_id,
+            voter: self.voter.clone(),
+            options: self.options.iter().map(|p| p.to_proto()).collect(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let options = proto
+            .options
+            .iter()
+            .map(|p| WeightedVoteOption::from_proto(p))
+            .collect::<IndyResult<Vec<WeightedVoteOption>>>()?;
+
+        Ok(Self::new(
+            proto.proposal_id,
+            proto.voter.clone(),
+            options,
+        ))
+    }
+}
+
+impl CheqdProtoMsg for MsgVoteWeighted {
+    const TYPE_URL: &'static str = "/cosmos.gov.v1beta1.MsgVoteWeighted";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_vote_weighted() {
+        let msg = MsgVoteWeighted::new(
+            1,
+            "voter".to_string(),
+            vec![],
+        );
+
+        let proto = msg.to_proto();
+        let decoded = MsgVoteWeighted::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
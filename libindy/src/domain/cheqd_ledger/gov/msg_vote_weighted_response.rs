@@ -0,0 +1,42 @@
+use cosmrs::proto::cosmos::gov::v1beta1::MsgVoteWeightedResponse as ProtoMsgVoteWeightedResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// MsgVoteWeightedResponse defines the Msg/VoteWeighted response type.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgVoteWeightedResponse {
+}
+
+impl MsgVoteWeightedResponse {
+    pub fn new() -> Self {
+        MsgVoteWeightedResponse {}
+    }
+}
+
+impl CheqdProto for MsgVoteWeightedResponse {
+    type Proto = ProtoMsgVoteWeightedResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {}
+    }
+
+    fn from_proto(_proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_vote_weighted_response() {
+        let msg = MsgVoteWeightedResponse::new();
+
+        let proto = msg.to_proto();
+        let decoded = MsgVoteWeightedResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
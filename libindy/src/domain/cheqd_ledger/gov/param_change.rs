@@ -0,0 +1,66 @@
+use cosmrs::proto::cosmos::params::v1beta1::ParamChange as ProtoParamChange;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// ParamChange defines an individual parameter change, for use in
+/// ParameterChangeProposal.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ParamChange {
+    pub subspace: String,
+    pub key: String,
+    pub value: String,
+}
+
+impl ParamChange {
+    pub fn new(
+        subspace: String,
+        key: String,
+        value: String,
+    ) -> Self {
+        ParamChange {
+            subspace,
+            key,
+            value,
+        }
+    }
+}
+
+impl CheqdProto for ParamChange {
+    type Proto = ProtoParamChange;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            subspace: self.subspace.clone(),
+            key: self.key.clone(),
+            value: self.value.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.subspace.clone(),
+            proto.key.clone(),
+            proto.value.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_param_change() {
+        let msg = ParamChange::new(
+            "subspace".to_string(),
+            "key".to_string(),
+            "value".to_string(),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = ParamChange::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,72 @@
+use cosmrs::proto::cosmos::params::v1beta1::ParameterChangeProposal as ProtoParameterChangeProposal;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::gov::ParamChange;
+
+/// ParameterChangeProposal defines a proposal to change one or more parameters.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ParameterChangeProposal {
+    pub title: String,
+    pub description: String,
+    pub changes: Vec<ParamChange>,
+}
+
+impl ParameterChangeProposal {
+    pub fn new(
+        title: String,
+        description: String,
+        changes: Vec<ParamChange>,
+    ) -> Self {
+        ParameterChangeProposal {
+            title,
+            description,
+            changes,
+        }
+    }
+}
+
+impl CheqdProto for ParameterChangeProposal {
+    type Proto = ProtoParameterChangeProposal;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            changes: self.changes.iter().map(|p| p.to_proto()).collect(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let changes = proto
+            .changes
+            .iter()
+            .map(|p| ParamChange::from_proto(p))
+            .collect::<IndyResult<Vec<ParamChange>>>()?;
+
+        Ok(Self::new(
+            proto.title.clone(),
+            proto.description.clone(),
+            changes,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parameter_change_proposal() {
+        let msg = ParameterChangeProposal::new(
+            "title".to_string(),
+            "description".to_string(),
+            vec![],
+        );
+
+        let proto = msg.to_proto();
+        let decoded = ParameterChangeProposal::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,132 @@
+use cosmrs::proto::cosmos::gov::v1beta1::Proposal as ProtoProposal;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::bank::Coin;
+use super::super::base::Timestamp;
+use super::super::gov::{ProposalContent, TallyResult};
+
+/// Proposal defines the core field members of a governance proposal.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Proposal {
+    pub proposal_id: u64,
+    pub content: Option<ProposalContent>,
+    pub status: i32,
+    pub final_tally_result: Option<TallyResult>,
+    pub submit_time: Option<Timestamp>,
+    pub deposit_end_time: Option<Timestamp>,
+    pub total_deposit: Vec<Coin>,
+    pub voting_start_time: Option<Timestamp>,
+    pub voting_end_time: Option<Timestamp>,
+}
+
+impl Proposal {
+    pub fn new(
+        proposal_id: u64,
+        content: Option<ProposalContent>,
+        status: i32,
+        final_tally_result: Option<TallyResult>,
+        submit_time: Option<Timestamp>,
+        deposit_end_time: Option<Timestamp>,
+        total_deposit: Vec<Coin>,
+        voting_start_time: Option<Timestamp>,
+        voting_end_time: Option<Timestamp>,
+    ) -> Self {
+        Proposal {
+            proposal_id,
+            content,
+            status,
+            final_tally_result,
+            submit_time,
+            deposit_end_time,
+            total_deposit,
+            voting_start_time,
+            voting_end_time,
+        }
+    }
+}
+
+impl CheqdProto for Proposal {
+    type Proto = ProtoProposal;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            proposal_id: self.proposal_id,
+            content: self.content.as_ref().map(|p| p.to_proto()),
+            status: self.status,
+            final_tally_result: self.final_tally_result.as_ref().map(|p| p.to_proto()),
+            submit_time: self.submit_time.as_ref().map(|p| p.to_proto()),
+            deposit_end_time: self.deposit_end_time.as_ref().map(|p| p.to_proto()),
+            total_deposit: self.total_deposit.iter().map(|p| p.to_proto()).collect(),
+            voting_start_time: self.voting_start_time.as_ref().map(|p| p.to_proto()),
+            voting_end_time: self.voting_end_time.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let content = proto
+            .content
+            .as_ref()
+            .map(|p| ProposalContent::from_proto(p))
+            .transpose()?;
+        let final_tally_result = proto
+            .final_tally_result
+            .as_ref()
+            .map(|p| TallyResult::from_proto(p))
+            .transpose()?;
+        let total_deposit = proto
+            .total_deposit
+            .iter()
+            .map(|p| Coin::from_proto(p))
+            .collect::<IndyResult<Vec<Coin>>>()?;
+        let timestamp = |t: &Option<prost_types::Timestamp>| {
+            t.as_ref().map(|p| Timestamp::from_proto(p)).transpose()
+        };
+
+        Ok(Self::new(
+            proto.proposal_id,
+            content,
+            proto.status,
+            final_tally_result,
+            timestamp(&proto.submit_time)?,
+            timestamp(&proto.deposit_end_time)?,
+            total_deposit,
+            timestamp(&proto.voting_start_time)?,
+            timestamp(&proto.voting_end_time)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::TextProposal;
+
+    #[test]
+    fn test_proposal() {
+        let msg = Proposal::new(
+            1,
+            Some(ProposalContent::Text(TextProposal::new(
+                "title".to_string(),
+                "description".to_string(),
+            ))),
+            2,
+            Some(TallyResult::new(
+                "10".to_string(),
+                "0".to_string(),
+                "1".to_string(),
+                "0".to_string(),
+            )),
+            Some(Timestamp::new(1, 0)),
+            Some(Timestamp::new(2, 0)),
+            vec![Coin::new("cheq".to_string(), "100".to_string())],
+            Some(Timestamp::new(2, 0)),
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = Proposal::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
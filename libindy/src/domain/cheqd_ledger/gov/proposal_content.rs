@@ -0,0 +1,97 @@
+use indy_api_types::errors::{IndyErrorKind, IndyResult, IndyError};
+use prost_types::Any;
+
+use super::super::CheqdProto;
+use super::super::prost_ext::ProstMessageExt;
+use super::super::gov::{ParameterChangeProposal, TextProposal};
+
+const TEXT_PROPOSAL_TYPE_URL: &str = "/cosmos.gov.v1beta1.TextProposal";
+const PARAMETER_CHANGE_PROPOSAL_TYPE_URL: &str = "/cosmos.params.v1beta1.ParameterChangeProposal";
+
+/// Content of a governance proposal. On the wire it is packed into `Any` by its type url.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum ProposalContent {
+    Text(TextProposal),
+    ParameterChange(ParameterChangeProposal),
+}
+
+impl CheqdProto for ProposalContent {
+    type Proto = Any;
+
+    fn to_proto(&self) -> Self::Proto {
+        match self {
+            ProposalContent::Text(content) => {
+                content.to_proto().to_any(TEXT_PROPOSAL_TYPE_URL)
+            }
+            ProposalContent::ParameterChange(content) => {
+                content.to_proto().to_any(PARAMETER_CHANGE_PROPOSAL_TYPE_URL)
+            }
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        match proto.type_url.as_str() {
+            TEXT_PROPOSAL_TYPE_URL => {
+                Ok(ProposalContent::Text(TextProposal::from_proto_bytes(&proto.value)?))
+            }
+            PARAMETER_CHANGE_PROPOSAL_TYPE_URL => {
+                Ok(ProposalContent::ParameterChange(ParameterChangeProposal::from_proto_bytes(&proto.value)?))
+            }
+            type_url => Err(IndyError::from_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Unsupported proposal content type: {}", type_url),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::ParamChange;
+
+    #[test]
+    fn test_proposal_content_text() {
+        let content = ProposalContent::Text(TextProposal::new(
+            "title".to_string(),
+            "description".to_string(),
+        ));
+
+        let proto = content.to_proto();
+        assert_eq!(TEXT_PROPOSAL_TYPE_URL, proto.type_url);
+
+        let decoded = ProposalContent::from_proto(&proto).unwrap();
+
+        assert_eq!(content, decoded);
+    }
+
+    #[test]
+    fn test_proposal_content_parameter_change() {
+        let content = ProposalContent::ParameterChange(ParameterChangeProposal::new(
+            "title".to_string(),
+            "description".to_string(),
+            vec![ParamChange::new(
+                "staking".to_string(),
+                "MaxValidators".to_string(),
+                "105".to_string(),
+            )],
+        ));
+
+        let proto = content.to_proto();
+        let decoded = ProposalContent::from_proto(&proto).unwrap();
+
+        assert_eq!(content, decoded);
+    }
+
+    #[test]
+    fn test_proposal_content_unknown_type() {
+        let proto = Any {
+            type_url: "/cosmos.upgrade.v1beta1.SoftwareUpgradeProposal".to_string(),
+            value: vec![],
+        };
+
+        let err = ProposalContent::from_proto(&proto).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+}
@@ -0,0 +1,60 @@
+use cosmrs::proto::cosmos::gov::v1beta1::QueryProposalResponse as ProtoQueryProposalResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::gov::Proposal;
+
+/// QueryProposalResponse is the response type for the Query/Proposal RPC method.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QueryProposalResponse {
+    pub proposal: Option<Proposal>,
+}
+
+impl QueryProposalResponse {
+    pub fn new(
+        proposal: Option<Proposal>,
+    ) -> Self {
+        QueryProposalResponse {
+            proposal,
+        }
+    }
+}
+
+impl CheqdProto for QueryProposalResponse {
+    type Proto = ProtoQueryProposalResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            proposal: self.proposal.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let proposal = proto
+            .proposal
+            .as_ref()
+            .map(|p| Proposal::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            proposal,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query_proposal_response() {
+        let msg = QueryProposalResponse::new(
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = QueryProposalResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,78 @@
+use cosmrs::proto::cosmos::gov::v1beta1::QueryProposalsRequest as ProtoQueryProposalsRequest;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::base::query::PageRequest;
+
+/// QueryProposalsRequest is the request type for the Query/Proposals RPC method.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QueryProposalsRequest {
+    pub proposal_status: i32,
+    pub voter: String,
+    pub depositor: String,
+    pub pagination: Option<PageRequest>,
+}
+
+impl QueryProposalsRequest {
+    pub fn new(
+        proposal_status: i32,
+        voter: String,
+        depositor: String,
+        pagination: Option<PageRequest>,
+    ) -> Self {
+        QueryProposalsRequest {
+            proposal_status,
+            voter,
+            depositor,
+            pagination,
+        }
+    }
+}
+
+impl CheqdProto for QueryProposalsRequest {
+    type Proto = ProtoQueryProposalsRequest;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            proposal_status: self.proposal_status,
+            voter: self.voter.clone(),
+            depositor: self.depositor.clone(),
+            pagination: self.pagination.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let pagination = proto
+            .pagination
+            .as_ref()
+            .map(|p| PageRequest::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            proto.proposal_status,
+            proto.voter.clone(),
+            proto.depositor.clone(),
+            pagination,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query_proposals_request() {
+        let msg = QueryProposalsRequest::new(
+            1,
+            "voter".to_string(),
+            "depositor".to_string(),
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = QueryProposalsRequest::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,72 @@
+use cosmrs::proto::cosmos::gov::v1beta1::QueryProposalsResponse as ProtoQueryProposalsResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::gov::Proposal;
+use super::super::base::query::PageResponse;
+
+/// QueryProposalsResponse is the response type for the Query/Proposals RPC method.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QueryProposalsResponse {
+    pub proposals: Vec<Proposal>,
+    pub pagination: Option<PageResponse>,
+}
+
+impl QueryProposalsResponse {
+    pub fn new(
+        proposals: Vec<Proposal>,
+        pagination: Option<PageResponse>,
+    ) -> Self {
+        QueryProposalsResponse {
+            proposals,
+            pagination,
+        }
+    }
+}
+
+impl CheqdProto for QueryProposalsResponse {
+    type Proto = ProtoQueryProposalsResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            proposals: self.proposals.iter().map(|p| p.to_proto()).collect(),
+            pagination: self.pagination.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let proposals = proto
+            .proposals
+            .iter()
+            .map(|p| Proposal::from_proto(p))
+            .collect::<IndyResult<Vec<Proposal>>>()?;
+        let pagination = proto
+            .pagination
+            .as_ref()
+            .map(|p| PageResponse::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            proposals,
+            pagination,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query_proposals_response() {
+        let msg = QueryProposalsResponse::new(
+            vec![],
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = QueryProposalsResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
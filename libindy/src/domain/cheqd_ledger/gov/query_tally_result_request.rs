@@ -0,0 +1,53 @@
+use cosmrs::proto::cosmos::gov::v1beta1::QueryTallyResultRequest as ProtoQueryTallyResultRequest;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// QueryTallyResultRequest is the request type for the Query/Tally RPC method.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QueryTallyResultRequest {
+    pub proposal_id: u64,
+}
+
+impl QueryTallyResultRequest {
+    pub fn new(
+        proposal_id: u64,
+    ) -> Self {
+        QueryTallyResultRequest {
+            proposal_id,
+        }
+    }
+}
+
+impl CheqdProto for QueryTallyResultRequest {
+    type Proto = ProtoQueryTallyResultRequest;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            proposal_id: self.proposal_id,
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.proposal_id,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query_tally_result_request() {
+        let msg = QueryTallyResultRequest::new(
+            1,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = QueryTallyResultRequest::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
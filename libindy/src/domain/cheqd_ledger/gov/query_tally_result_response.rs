@@ -0,0 +1,60 @@
+use cosmrs::proto::cosmos::gov::v1beta1::QueryTallyResultResponse as ProtoQueryTallyResultResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::gov::TallyResult;
+
+/// QueryTallyResultResponse is the response type for the Query/Tally RPC method.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QueryTallyResultResponse {
+    pub tally: Option<TallyResult>,
+}
+
+impl QueryTallyResultResponse {
+    pub fn new(
+        tally: Option<TallyResult>,
+    ) -> Self {
+        QueryTallyResultResponse {
+            tally,
+        }
+    }
+}
+
+impl CheqdProto for QueryTallyResultResponse {
+    type Proto = ProtoQueryTallyResultResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            tally: self.tally.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let tally = proto
+            .tally
+            .as_ref()
+            .map(|p| TallyResult::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            tally,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query_tally_result_response() {
+        let msg = QueryTallyResultResponse::new(
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = QueryTallyResultResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,60 @@
+use cosmrs::proto::cosmos::gov::v1beta1::QueryVoteResponse as ProtoQueryVoteResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::gov::Vote;
+
+/// QueryVoteResponse is the response type for the Query/Vote RPC method.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QueryVoteResponse {
+    pub vote: Option<Vote>,
+}
+
+impl QueryVoteResponse {
+    pub fn new(
+        vote: Option<Vote>,
+    ) -> Self {
+        QueryVoteResponse {
+            vote,
+        }
+    }
+}
+
+impl CheqdProto for QueryVoteResponse {
+    type Proto = ProtoQueryVoteResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            vote: self.vote.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let vote = proto
+            .vote
+            .as_ref()
+            .map(|p| Vote::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            vote,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query_vote_response() {
+        let msg = QueryVoteResponse::new(
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = QueryVoteResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,71 @@
+use cosmrs::proto::cosmos::gov::v1beta1::TallyResult as ProtoTallyResult;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// TallyResult defines a standard tally for a governance proposal.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct TallyResult {
+    pub yes: String,
+    pub abstain: String,
+    pub no: String,
+    pub no_with_veto: String,
+}
+
+impl TallyResult {
+    pub fn new(
+        yes: String,
+        abstain: String,
+        no: String,
+        no_with_veto: String,
+    ) -> Self {
+        TallyResult {
+            yes,
+            abstain,
+            no,
+            no_with_veto,
+        }
+    }
+}
+
+impl CheqdProto for TallyResult {
+    type Proto = ProtoTallyResult;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            yes: self.yes.clone(),
+            abstain: self.abstain.clone(),
+            no: self.no.clone(),
+            no_with_veto: self.no_with_veto.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.yes.clone(),
+            proto.abstain.clone(),
+            proto.no.clone(),
+            proto.no_with_veto.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tally_result() {
+        let msg = TallyResult::new(
+            "yes".to_string(),
+            "abstain".to_string(),
+            "no".to_string(),
+            "no_with_veto".to_string(),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = TallyResult::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,60 @@
+use cosmrs::proto::cosmos::gov::v1beta1::TextProposal as ProtoTextProposal;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// TextProposal defines a standard text proposal whose changes need to be
+/// manually updated in case of approval.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct TextProposal {
+    pub title: String,
+    pub description: String,
+}
+
+impl TextProposal {
+    pub fn new(
+        title: String,
+        description: String,
+    ) -> Self {
+        TextProposal {
+            title,
+            description,
+        }
+    }
+}
+
+impl CheqdProto for TextProposal {
+    type Proto = ProtoTextProposal;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            title: self.title.clone(),
+            description: self.description.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.title.clone(),
+            proto.description.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_text_proposal() {
+        let msg = TextProposal::new(
+            "title".to_string(),
+            "description".to_string(),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = TextProposal::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,79 @@
+use cosmrs::proto::cosmos::gov::v1beta1::Vote as ProtoVote;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::gov::WeightedVoteOption;
+
+/// Vote defines a vote on a governance proposal.
+/// A Vote consists of a proposal ID, the voter, and the vote option.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Vote {
+    pub proposal_id: u64,
+    pub voter: String,
+    pub option: i32,
+    pub options: Vec<WeightedVoteOption>,
+}
+
+impl Vote {
+    pub fn new(
+        proposal_id: u64,
+        voter: String,
+        option: i32,
+        options: Vec<WeightedVoteOption>,
+    ) -> Self {
+        Vote {
+            proposal_id,
+            voter,
+            option,
+            options,
+        }
+    }
+}
+
+impl CheqdProto for Vote {
+    type Proto = ProtoVote;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            proposal_id: self.proposal_id,
+            voter: self.voter.clone(),
+            option: self.option,
+            options: self.options.iter().map(|p| p.to_proto()).collect(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let options = proto
+            .options
+            .iter()
+            .map(|p| WeightedVoteOption::from_proto(p))
+            .collect::<IndyResult<Vec<WeightedVoteOption>>>()?;
+
+        Ok(Self::new(
+            proto.proposal_id,
+            proto.voter.clone(),
+            proto.option,
+            options,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_vote() {
+        let msg = Vote::new(
+            1,
+            "voter".to_string(),
+            1,
+            vec![],
+        );
+
+        let proto = msg.to_proto();
+        let decoded = Vote::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,7 @@
+/// Vote option with its weight as passed by the caller when building MsgVoteWeighted,
+/// e.g. `{"option": "yes", "weight": "0.7"}`.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct WeightedVote {
+    pub option: String,
+    pub weight: String,
+}
@@ -0,0 +1,59 @@
+use cosmrs::proto::cosmos::gov::v1beta1::WeightedVoteOption as ProtoWeightedVoteOption;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// WeightedVoteOption defines a unit of vote for vote split.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct WeightedVoteOption {
+    pub option: i32,
+    pub weight: String,
+}
+
+impl WeightedVoteOption {
+    pub fn new(
+        option: i32,
+        weight: String,
+    ) -> Self {
+        WeightedVoteOption {
+            option,
+            weight,
+        }
+    }
+}
+
+impl CheqdProto for WeightedVoteOption {
+    type Proto = ProtoWeightedVoteOption;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            option: self.option,
+            weight: self.weight.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.option,
+            proto.weight.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_weighted_vote_option() {
+        let msg = WeightedVoteOption::new(
+            1,
+            "weight".to_string(),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = WeightedVoteOption::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
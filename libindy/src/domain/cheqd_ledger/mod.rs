@@ -1,6 +1,8 @@
 use std::fmt::Debug;
 
+use cosmrs::tx::Msg;
 use indy_api_types::errors::IndyResult;
+use prost_types::Any;
 
 use prost_ext::ProstMessageExt;
 
@@ -12,6 +14,12 @@ pub mod bank;
 pub mod auth;
 pub mod base;
 pub mod crypto;
+pub mod payments;
+pub mod staking;
+pub mod distribution;
+pub mod gov;
+pub mod feegrant;
+pub mod authz;
 
 pub trait CheqdProto: Eq + Debug + Sized {
     type Proto: prost::Message + Default;
@@ -28,3 +36,17 @@ pub trait CheqdProto: Eq + Debug + Sized {
         Ok(Self::from_proto(&proto)?)
     }
 }
+
+/// Message of a cosmos module which has no `cosmrs::tx::MsgProto` implementation
+/// and is packed into `Any` by its type url.
+pub trait CheqdProtoMsg: CheqdProto {
+    const TYPE_URL: &'static str;
+
+    fn to_msg(&self) -> IndyResult<Msg> {
+        let any = Any {
+            type_url: Self::TYPE_URL.to_string(),
+            value: self.to_proto_bytes()?,
+        };
+        Ok(any.into())
+    }
+}
@@ -0,0 +1,61 @@
+//! Structures used by the built-in `cheqd` payment method
+
+/// Source returned by `indy_parse_get_payment_sources_response`.
+/// Cheqd is account based so every address has a single source holding its balance.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct CheqdPaymentSource {
+    pub source: String,
+    #[serde(rename = "paymentAddress")]
+    pub payment_address: String,
+    pub amount: u64,
+    pub extra: String,
+}
+
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct CheqdPaymentOutput {
+    pub recipient: String,
+    pub amount: u64,
+}
+
+/// `extra` of `indy_build_payment_req`: the pool and the sender account state the transaction is built for.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct CheqdPaymentExtra {
+    pub pool_alias: String,
+    pub account_number: u64,
+    pub sequence_number: u64,
+    pub max_gas: u64,
+    pub max_coin_amount: u64,
+    pub denom: Option<String>,
+    #[serde(default)]
+    pub timeout_height: u64,
+    #[serde(default)]
+    pub memo: String,
+}
+
+/// Payment request: hex-encoded transaction signed by the sender's key,
+/// to be sent with `indy_cheqd_pool_broadcast_tx_commit` to `pool_alias`.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct CheqdPaymentRequest {
+    pub pool_alias: String,
+    pub tx: String,
+}
+
+impl CheqdPaymentRequest {
+    pub fn new(pool_alias: String, tx: String) -> Self {
+        CheqdPaymentRequest {
+            pool_alias,
+            tx,
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct CheqdPaymentReceipt {
+    pub receipt: String,
+    pub height: u64,
+}
+
+#[derive(Eq, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct CheqdPaymentAddressConfig {
+    pub mnemonic: Option<String>,
+}
@@ -1,8 +1,10 @@
 use indy_api_types::errors::{IndyResult, IndyResultExt, IndyErrorKind};
 use prost::Message;
+use prost_types::Any;
 
 pub trait ProstMessageExt {
     fn to_bytes(&self) -> IndyResult<Vec<u8>>;
+    fn to_any(&self, type_url: &str) -> Any;
     fn from_bytes(bytes: &[u8]) -> IndyResult<Self>
         where
             Self: Sized;
@@ -21,6 +23,17 @@ impl<T> ProstMessageExt for T
         Ok(bytes)
     }
 
+    fn to_any(&self, type_url: &str) -> Any {
+        let mut value = Vec::with_capacity(self.encoded_len());
+        // Encoding can only fail on insufficient capacity and the buffer is reserved above
+        Message::encode(self, &mut value).ok();
+
+        Any {
+            type_url: type_url.to_string(),
+            value,
+        }
+    }
+
     fn from_bytes(bytes: &[u8]) -> IndyResult<Self>
         where
             Self: Sized,
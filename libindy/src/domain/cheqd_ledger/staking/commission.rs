@@ -0,0 +1,72 @@
+use cosmrs::proto::cosmos::staking::v1beta1::Commission as ProtoCommission;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::base::Timestamp;
+use super::super::staking::CommissionRates;
+
+/// Commission defines commission parameters for a given validator.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Commission {
+    pub commission_rates: Option<CommissionRates>,
+    pub update_time: Option<Timestamp>,
+}
+
+impl Commission {
+    pub fn new(
+        commission_rates: Option<CommissionRates>,
+        update_time: Option<Timestamp>,
+    ) -> Self {
+        Commission {
+            commission_rates,
+            update_time,
+        }
+    }
+}
+
+impl CheqdProto for Commission {
+    type Proto = ProtoCommission;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            commission_rates: self.commission_rates.as_ref().map(|p| p.to_proto()),
+            update_time: self.update_time.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let commission_rates = proto
+            .commission_rates
+            .as_ref()
+            .map(|p| CommissionRates::from_proto(p))
+            .transpose()?;
+        let update_time = proto
+            .update_time
+            .as_ref()
+            .map(|p| Timestamp::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            commission_rates,
+            update_time,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_commission() {
+        let msg = Commission::new(
+            None,
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = Commission::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,65 @@
+use cosmrs::proto::cosmos::staking::v1beta1::CommissionRates as ProtoCommissionRates;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// CommissionRates defines the initial commission rates to be used for creating a validator.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct CommissionRates {
+    pub rate: String,
+    pub max_rate: String,
+    pub max_change_rate: String,
+}
+
+impl CommissionRates {
+    pub fn new(
+        rate: String,
+        max_rate: String,
+        max_change_rate: String,
+    ) -> Self {
+        CommissionRates {
+            rate,
+            max_rate,
+            max_change_rate,
+        }
+    }
+}
+
+impl CheqdProto for CommissionRates {
+    type Proto = ProtoCommissionRates;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            rate: self.rate.clone(),
+            max_rate: self.max_rate.clone(),
+            max_change_rate: self.max_change_rate.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.rate.clone(),
+            proto.max_rate.clone(),
+            proto.max_change_rate.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_commission_rates() {
+        let msg = CommissionRates::new(
+            "rate".to_string(),
+            "max_rate".to_string(),
+            "max_change_rate".to_string(),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = CommissionRates::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
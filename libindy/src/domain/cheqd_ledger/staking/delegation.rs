@@ -0,0 +1,66 @@
+use cosmrs::proto::cosmos::staking::v1beta1::Delegation as ProtoDelegation;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// Delegation represents the bond with tokens held by an account. It is
+/// owned by one delegator, and is associated with the voting power of one validator.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Delegation {
+    pub delegator_address: String,
+    pub validator_address: String,
+    pub shares: String,
+}
+
+impl Delegation {
+    pub fn new(
+        delegator_address: String,
+        validator_address: String,
+        shares: String,
+    ) -> Self {
+        Delegation {
+            delegator_address,
+            validator_address,
+            shares,
+        }
+    }
+}
+
+impl CheqdProto for Delegation {
+    type Proto = ProtoDelegation;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            delegator_address: self.delegator_address.clone(),
+            validator_address: self.validator_address.clone(),
+            shares: self.shares.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.delegator_address.clone(),
+            proto.validator_address.clone(),
+            proto.shares.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_delegation() {
+        let msg = Delegation::new(
+            "delegator_address".to_string(),
+            "validator_address".to_string(),
+            "shares".to_string(),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = Delegation::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
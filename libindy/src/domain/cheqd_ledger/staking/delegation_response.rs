@@ -0,0 +1,73 @@
+use cosmrs::proto::cosmos::staking::v1beta1::DelegationResponse as ProtoDelegationResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::bank::Coin;
+use super::super::staking::Delegation;
+
+/// DelegationResponse is equivalent to Delegation except that it contains a balance
+/// in addition to shares which is more suitable for client responses.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DelegationResponse {
+    pub delegation: Option<Delegation>,
+    pub balance: Option<Coin>,
+}
+
+impl DelegationResponse {
+    pub fn new(
+        delegation: Option<Delegation>,
+        balance: Option<Coin>,
+    ) -> Self {
+        DelegationResponse {
+            delegation,
+            balance,
+        }
+    }
+}
+
+impl CheqdProto for DelegationResponse {
+    type Proto = ProtoDelegationResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            delegation: self.delegation.as_ref().map(|p| p.to_proto()),
+            balance: self.balance.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let delegation = proto
+            .delegation
+            .as_ref()
+            .map(|p| Delegation::from_proto(p))
+            .transpose()?;
+        let balance = proto
+            .balance
+            .as_ref()
+            .map(|p| Coin::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            delegation,
+            balance,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_delegation_response() {
+        let msg = DelegationResponse::new(
+            None,
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = DelegationResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,77 @@
+use cosmrs::proto::cosmos::staking::v1beta1::Description as ProtoDescription;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// Description defines a validator description.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Description {
+    pub moniker: String,
+    pub identity: String,
+    pub website: String,
+    pub security_contact: String,
+    pub details: String,
+}
+
+impl Description {
+    pub fn new(
+        moniker: String,
+        identity: String,
+        website: String,
+        security_contact: String,
+        details: String,
+    ) -> Self {
+        Description {
+            moniker,
+            identity,
+            website,
+            security_contact,
+            details,
+        }
+    }
+}
+
+impl CheqdProto for Description {
+    type Proto = ProtoDescription;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            moniker: self.moniker.clone(),
+            identity: self.identity.clone(),
+            website: self.website.clone(),
+            security_contact: self.security_contact.clone(),
+            details: self.details.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.moniker.clone(),
+            proto.identity.clone(),
+            proto.website.clone(),
+            proto.security_contact.clone(),
+            proto.details.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_description() {
+        let msg = Description::new(
+            "moniker".to_string(),
+            "identity".to_string(),
+            "website".to_string(),
+            "security_contact".to_string(),
+            "details".to_string(),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = Description::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
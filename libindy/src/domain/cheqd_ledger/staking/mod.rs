@@ -0,0 +1,39 @@
+//! Staking module related models
+
+pub use msg_delegate::MsgDelegate;
+pub use msg_delegate_response::MsgDelegateResponse;
+pub use msg_undelegate::MsgUndelegate;
+pub use msg_undelegate_response::MsgUndelegateResponse;
+pub use msg_begin_redelegate::MsgBeginRedelegate;
+pub use msg_begin_redelegate_response::MsgBeginRedelegateResponse;
+pub use delegation::Delegation;
+pub use delegation_response::DelegationResponse;
+pub use description::Description;
+pub use commission_rates::CommissionRates;
+pub use commission::Commission;
+pub use query_delegation_request::QueryDelegationRequest;
+pub use query_delegation_response::QueryDelegationResponse;
+pub use query_delegator_delegations_request::QueryDelegatorDelegationsRequest;
+pub use query_delegator_delegations_response::QueryDelegatorDelegationsResponse;
+pub use query_validators_request::QueryValidatorsRequest;
+pub use query_validators_response::QueryValidatorsResponse;
+pub use validator::Validator;
+
+mod msg_delegate;
+mod msg_delegate_response;
+mod msg_undelegate;
+mod msg_undelegate_response;
+mod msg_begin_redelegate;
+mod msg_begin_redelegate_response;
+mod delegation;
+mod delegation_response;
+mod description;
+mod commission_rates;
+mod commission;
+mod query_delegation_request;
+mod query_delegation_response;
+mod query_delegator_delegations_request;
+mod query_delegator_delegations_response;
+mod query_validators_request;
+mod query_validators_response;
+mod validator;
@@ -0,0 +1,85 @@
+use cosmrs::proto::cosmos::staking::v1beta1::MsgBeginRedelegate as ProtoMsgBeginRedelegate;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::CheqdProtoMsg;
+use super::super::bank::Coin;
+
+/// MsgBeginRedelegate defines a SDK message for performing a redelegation
+/// of coins from a delegator and source validator to a destination validator.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgBeginRedelegate {
+    pub delegator_address: String,
+    pub validator_src_address: String,
+    pub validator_dst_address: String,
+    pub amount: Option<Coin>,
+}
+
+impl MsgBeginRedelegate {
+    pub fn new(
+        delegator_address: String,
+        validator_src_address: String,
+        validator_dst_address: String,
+        amount: Option<Coin>,
+    ) -> Self {
+        MsgBeginRedelegate {
+            delegator_address,
+            validator_src_address,
+            validator_dst_address,
+            amount,
+        }
+    }
+}
+
+impl CheqdProto for MsgBeginRedelegate {
+    type Proto = ProtoMsgBeginRedelegate;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            delegator_address: self.delegator_address.clone(),
+            validator_src_address: self.validator_src_address.clone(),
+            validator_dst_address: self.validator_dst_address.clone(),
+            amount: self.amount.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let amount = proto
+            .amount
+            .as_ref()
+            .map(|p| Coin::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            proto.delegator_address.clone(),
+            proto.validator_src_address.clone(),
+            proto.validator_dst_address.clone(),
+            amount,
+        ))
+    }
+}
+
+impl CheqdProtoMsg for MsgBeginRedelegate {
+    const TYPE_URL: &'static str = "/cosmos.staking.v1beta1.MsgBeginRedelegate";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::environment;
+
+    #[test]
+    fn test_msg_begin_redelegate() {
+        let msg = MsgBeginRedelegate::new(
+            "cosmos1fknpjldck6n3v2wu86arpz8xjnfc60f99ylcjd".to_string(),
+            "cosmosvaloper1fknpjldck6n3v2wu86arpz8xjnfc60f99jqh9xs".to_string(),
+            "cosmosvaloper1wp7ncvhxh0lgzl7v4e8dz6hmw0ez9kuxlyvpyh".to_string(),
+            Some(Coin::new(environment::cheqd_denom(), "100".to_string())),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = MsgBeginRedelegate::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
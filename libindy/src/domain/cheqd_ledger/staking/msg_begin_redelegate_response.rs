@@ -0,0 +1,60 @@
+use cosmrs::proto::cosmos::staking::v1beta1::MsgBeginRedelegateResponse as ProtoMsgBeginRedelegateResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::base::Timestamp;
+
+/// MsgBeginRedelegateResponse defines the Msg/BeginRedelegate response type.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgBeginRedelegateResponse {
+    pub completion_time: Option<Timestamp>,
+}
+
+impl MsgBeginRedelegateResponse {
+    pub fn new(
+        completion_time: Option<Timestamp>,
+    ) -> Self {
+        MsgBeginRedelegateResponse {
+            completion_time,
+        }
+    }
+}
+
+impl CheqdProto for MsgBeginRedelegateResponse {
+    type Proto = ProtoMsgBeginRedelegateResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            completion_time: self.completion_time.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let completion_time = proto
+            .completion_time
+            .as_ref()
+            .map(|p| Timestamp::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            completion_time,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_begin_redelegate_response() {
+        let msg = MsgBeginRedelegateResponse::new(
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = MsgBeginRedelegateResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
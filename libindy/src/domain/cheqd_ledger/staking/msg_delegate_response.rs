@@ -0,0 +1,42 @@
+use cosmrs::proto::cosmos::staking::v1beta1::MsgDelegateResponse as ProtoMsgDelegateResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// MsgDelegateResponse defines the Msg/Delegate response type.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgDelegateResponse {
+}
+
+impl MsgDelegateResponse {
+    pub fn new() -> Self {
+        MsgDelegateResponse {}
+    }
+}
+
+impl CheqdProto for MsgDelegateResponse {
+    type Proto = ProtoMsgDelegateResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {}
+    }
+
+    fn from_proto(_proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_delegate_response() {
+        let msg = MsgDelegateResponse::new();
+
+        let proto = msg.to_proto();
+        let decoded = MsgDelegateResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,79 @@
+use cosmrs::proto::cosmos::staking::v1beta1::MsgUndelegate as ProtoMsgUndelegate;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::CheqdProtoMsg;
+use super::super::bank::Coin;
+
+/// MsgUndelegate defines a SDK message for performing an undelegation from a
+/// delegate and a validator.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgUndelegate {
+    pub delegator_address: String,
+    pub validator_address: String,
+    pub amount: Option<Coin>,
+}
+
+impl MsgUndelegate {
+    pub fn new(
+        delegator_address: String,
+        validator_address: String,
+        amount: Option<Coin>,
+    ) -> Self {
+        MsgUndelegate {
+            delegator_address,
+            validator_address,
+            amount,
+        }
+    }
+}
+
+impl CheqdProto for MsgUndelegate {
+    type Proto = ProtoMsgUndelegate;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            delegator_address: self.delegator_address.clone(),
+            validator_address: self.validator_address.clone(),
+            amount: self.amount.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let amount = proto
+            .amount
+            .as_ref()
+            .map(|p| Coin::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            proto.delegator_address.clone(),
+            proto.validator_address.clone(),
+            amount,
+        ))
+    }
+}
+
+impl CheqdProtoMsg for MsgUndelegate {
+    const TYPE_URL: &'static str = "/cosmos.staking.v1beta1.MsgUndelegate";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::environment;
+
+    #[test]
+    fn test_msg_undelegate() {
+        let msg = MsgUndelegate::new(
+            "cosmos1fknpjldck6n3v2wu86arpz8xjnfc60f99ylcjd".to_string(),
+            "cosmosvaloper1fknpjldck6n3v2wu86arpz8xjnfc60f99jqh9xs".to_string(),
+            Some(Coin::new(environment::cheqd_denom(), "100".to_string())),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = MsgUndelegate::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
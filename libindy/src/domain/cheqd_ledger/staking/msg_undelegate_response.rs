@@ -0,0 +1,60 @@
+use cosmrs::proto::cosmos::staking::v1beta1::MsgUndelegateResponse as ProtoMsgUndelegateResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::base::Timestamp;
+
+/// MsgUndelegateResponse defines the Msg/Undelegate response type.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MsgUndelegateResponse {
+    pub completion_time: Option<Timestamp>,
+}
+
+impl MsgUndelegateResponse {
+    pub fn new(
+        completion_time: Option<Timestamp>,
+    ) -> Self {
+        MsgUndelegateResponse {
+            completion_time,
+        }
+    }
+}
+
+impl CheqdProto for MsgUndelegateResponse {
+    type Proto = ProtoMsgUndelegateResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            completion_time: self.completion_time.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let completion_time = proto
+            .completion_time
+            .as_ref()
+            .map(|p| Timestamp::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            completion_time,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_msg_undelegate_response() {
+        let msg = MsgUndelegateResponse::new(
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = MsgUndelegateResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
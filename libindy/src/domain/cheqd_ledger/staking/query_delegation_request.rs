@@ -0,0 +1,59 @@
+use cosmrs::proto::cosmos::staking::v1beta1::QueryDelegationRequest as ProtoQueryDelegationRequest;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// QueryDelegationRequest is request type for the Query/Delegation RPC method.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QueryDelegationRequest {
+    pub delegator_addr: String,
+    pub validator_addr: String,
+}
+
+impl QueryDelegationRequest {
+    pub fn new(
+        delegator_addr: String,
+        validator_addr: String,
+    ) -> Self {
+        QueryDelegationRequest {
+            delegator_addr,
+            validator_addr,
+        }
+    }
+}
+
+impl CheqdProto for QueryDelegationRequest {
+    type Proto = ProtoQueryDelegationRequest;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            delegator_addr: self.delegator_addr.clone(),
+            validator_addr: self.validator_addr.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(
+            proto.delegator_addr.clone(),
+            proto.validator_addr.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query_delegation_request() {
+        let msg = QueryDelegationRequest::new(
+            "delegator_addr".to_string(),
+            "validator_addr".to_string(),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = QueryDelegationRequest::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,60 @@
+use cosmrs::proto::cosmos::staking::v1beta1::QueryDelegationResponse as ProtoQueryDelegationResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::staking::DelegationResponse;
+
+/// QueryDelegationResponse is response type for the Query/Delegation RPC method.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QueryDelegationResponse {
+    pub delegation_response: Option<DelegationResponse>,
+}
+
+impl QueryDelegationResponse {
+    pub fn new(
+        delegation_response: Option<DelegationResponse>,
+    ) -> Self {
+        QueryDelegationResponse {
+            delegation_response,
+        }
+    }
+}
+
+impl CheqdProto for QueryDelegationResponse {
+    type Proto = ProtoQueryDelegationResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            delegation_response: self.delegation_response.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let delegation_response = proto
+            .delegation_response
+            .as_ref()
+            .map(|p| DelegationResponse::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            delegation_response,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query_delegation_response() {
+        let msg = QueryDelegationResponse::new(
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = QueryDelegationResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
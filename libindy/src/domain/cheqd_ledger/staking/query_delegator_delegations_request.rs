@@ -0,0 +1,67 @@
+use cosmrs::proto::cosmos::staking::v1beta1::QueryDelegatorDelegationsRequest as ProtoQueryDelegatorDelegationsRequest;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::base::query::PageRequest;
+
+/// QueryDelegatorDelegationsRequest is request type for the
+/// Query/DelegatorDelegations RPC method.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QueryDelegatorDelegationsRequest {
+    pub delegator_addr: String,
+    pub pagination: Option<PageRequest>,
+}
+
+impl QueryDelegatorDelegationsRequest {
+    pub fn new(
+        delegator_addr: String,
+        pagination: Option<PageRequest>,
+    ) -> Self {
+        QueryDelegatorDelegationsRequest {
+            delegator_addr,
+            pagination,
+        }
+    }
+}
+
+impl CheqdProto for QueryDelegatorDelegationsRequest {
+    type Proto = ProtoQueryDelegatorDelegationsRequest;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            delegator_addr: self.delegator_addr.clone(),
+            pagination: self.pagination.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let pagination = proto
+            .pagination
+            .as_ref()
+            .map(|p| PageRequest::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            proto.delegator_addr.clone(),
+            pagination,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query_delegator_delegations_request() {
+        let msg = QueryDelegatorDelegationsRequest::new(
+            "delegator_addr".to_string(),
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = QueryDelegatorDelegationsRequest::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,73 @@
+use cosmrs::proto::cosmos::staking::v1beta1::QueryDelegatorDelegationsResponse as ProtoQueryDelegatorDelegationsResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::base::query::PageResponse;
+use super::super::staking::DelegationResponse;
+
+/// QueryDelegatorDelegationsResponse is response type for the
+/// Query/DelegatorDelegations RPC method.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QueryDelegatorDelegationsResponse {
+    pub delegation_responses: Vec<DelegationResponse>,
+    pub pagination: Option<PageResponse>,
+}
+
+impl QueryDelegatorDelegationsResponse {
+    pub fn new(
+        delegation_responses: Vec<DelegationResponse>,
+        pagination: Option<PageResponse>,
+    ) -> Self {
+        QueryDelegatorDelegationsResponse {
+            delegation_responses,
+            pagination,
+        }
+    }
+}
+
+impl CheqdProto for QueryDelegatorDelegationsResponse {
+    type Proto = ProtoQueryDelegatorDelegationsResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            delegation_responses: self.delegation_responses.iter().map(|p| p.to_proto()).collect(),
+            pagination: self.pagination.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let delegation_responses = proto
+            .delegation_responses
+            .iter()
+            .map(|p| DelegationResponse::from_proto(p))
+            .collect::<IndyResult<Vec<DelegationResponse>>>()?;
+        let pagination = proto
+            .pagination
+            .as_ref()
+            .map(|p| PageResponse::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            delegation_responses,
+            pagination,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query_delegator_delegations_response() {
+        let msg = QueryDelegatorDelegationsResponse::new(
+            vec![],
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = QueryDelegatorDelegationsResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
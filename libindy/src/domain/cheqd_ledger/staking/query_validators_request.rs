@@ -0,0 +1,66 @@
+use cosmrs::proto::cosmos::staking::v1beta1::QueryValidatorsRequest as ProtoQueryValidatorsRequest;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::base::query::PageRequest;
+
+/// QueryValidatorsRequest is request type for Query/Validators RPC method.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QueryValidatorsRequest {
+    pub status: String,
+    pub pagination: Option<PageRequest>,
+}
+
+impl QueryValidatorsRequest {
+    pub fn new(
+        status: String,
+        pagination: Option<PageRequest>,
+    ) -> Self {
+        QueryValidatorsRequest {
+            status,
+            pagination,
+        }
+    }
+}
+
+impl CheqdProto for QueryValidatorsRequest {
+    type Proto = ProtoQueryValidatorsRequest;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            status: self.status.clone(),
+            pagination: self.pagination.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let pagination = proto
+            .pagination
+            .as_ref()
+            .map(|p| PageRequest::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            proto.status.clone(),
+            pagination,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query_validators_request() {
+        let msg = QueryValidatorsRequest::new(
+            "status".to_string(),
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = QueryValidatorsRequest::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -0,0 +1,72 @@
+use cosmrs::proto::cosmos::staking::v1beta1::QueryValidatorsResponse as ProtoQueryValidatorsResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::base::query::PageResponse;
+use super::super::staking::Validator;
+
+/// QueryValidatorsResponse is response type for the Query/Validators RPC method
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QueryValidatorsResponse {
+    pub validators: Vec<Validator>,
+    pub pagination: Option<PageResponse>,
+}
+
+impl QueryValidatorsResponse {
+    pub fn new(
+        validators: Vec<Validator>,
+        pagination: Option<PageResponse>,
+    ) -> Self {
+        QueryValidatorsResponse {
+            validators,
+            pagination,
+        }
+    }
+}
+
+impl CheqdProto for QueryValidatorsResponse {
+    type Proto = ProtoQueryValidatorsResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            validators: self.validators.iter().map(|p| p.to_proto()).collect(),
+            pagination: self.pagination.as_ref().map(|p| p.to_proto()),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let validators = proto
+            .validators
+            .iter()
+            .map(|p| Validator::from_proto(p))
+            .collect::<IndyResult<Vec<Validator>>>()?;
+        let pagination = proto
+            .pagination
+            .as_ref()
+            .map(|p| PageResponse::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            validators,
+            pagination,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query_validators_response() {
+        let msg = QueryValidatorsResponse::new(
+            vec![],
+            None,
+        );
+
+        let proto = msg.to_proto();
+        let decoded = QueryValidatorsResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
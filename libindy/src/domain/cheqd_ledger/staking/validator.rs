@@ -0,0 +1,146 @@
+use cosmrs::proto::cosmos::staking::v1beta1::Validator as ProtoValidator;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::super::base::Timestamp;
+use super::super::staking::{Commission, Description};
+
+/// Validator defines a validator, together with the total amount of the
+/// Validator's bond shares and their exchange rate to coins.
+///
+/// Consensus public key is not exposed: it is packed into `Any` and is not needed by clients.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Validator {
+    pub operator_address: String,
+    pub jailed: bool,
+    pub status: i32,
+    pub tokens: String,
+    pub delegator_shares: String,
+    pub description: Option<Description>,
+    pub unbonding_height: i64,
+    pub unbonding_time: Option<Timestamp>,
+    pub commission: Option<Commission>,
+    pub min_self_delegation: String,
+}
+
+impl Validator {
+    pub fn new(
+        operator_address: String,
+        jailed: bool,
+        status: i32,
+        tokens: String,
+        delegator_shares: String,
+        description: Option<Description>,
+        unbonding_height: i64,
+        unbonding_time: Option<Timestamp>,
+        commission: Option<Commission>,
+        min_self_delegation: String,
+    ) -> Self {
+        Validator {
+            operator_address,
+            jailed,
+            status,
+            tokens,
+            delegator_shares,
+            description,
+            unbonding_height,
+            unbonding_time,
+            commission,
+            min_self_delegation,
+        }
+    }
+}
+
+impl CheqdProto for Validator {
+    type Proto = ProtoValidator;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            operator_address: self.operator_address.clone(),
+            consensus_pubkey: None,
+            jailed: self.jailed,
+            status: self.status,
+            tokens: self.tokens.clone(),
+            delegator_shares: self.delegator_shares.clone(),
+            description: self.description.as_ref().map(|p| p.to_proto()),
+            unbonding_height: self.unbonding_height,
+            unbonding_time: self.unbonding_time.as_ref().map(|p| p.to_proto()),
+            commission: self.commission.as_ref().map(|p| p.to_proto()),
+            min_self_delegation: self.min_self_delegation.clone(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let description = proto
+            .description
+            .as_ref()
+            .map(|p| Description::from_proto(p))
+            .transpose()?;
+        let unbonding_time = proto
+            .unbonding_time
+            .as_ref()
+            .map(|p| Timestamp::from_proto(p))
+            .transpose()?;
+        let commission = proto
+            .commission
+            .as_ref()
+            .map(|p| Commission::from_proto(p))
+            .transpose()?;
+
+        Ok(Self::new(
+            proto.operator_address.clone(),
+            proto.jailed,
+            proto.status,
+            proto.tokens.clone(),
+            proto.delegator_shares.clone(),
+            description,
+            proto.unbonding_height,
+            unbonding_time,
+            commission,
+            proto.min_self_delegation.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::CommissionRates;
+
+    #[test]
+    fn test_validator() {
+        let description = Description::new(
+            "moniker".to_string(),
+            "identity".to_string(),
+            "website".to_string(),
+            "security_contact".to_string(),
+            "details".to_string(),
+        );
+        let commission = Commission::new(
+            Some(CommissionRates::new(
+                "0.1".to_string(),
+                "0.2".to_string(),
+                "0.01".to_string(),
+            )),
+            Some(Timestamp::new(1, 0)),
+        );
+
+        let msg = Validator::new(
+            "cosmosvaloper1fknpjldck6n3v2wu86arpz8xjnfc60f99jqh9xs".to_string(),
+            false,
+            3,
+            "1000".to_string(),
+            "1000.000000000000000000".to_string(),
+            Some(description),
+            0,
+            None,
+            Some(commission),
+            "1".to_string(),
+        );
+
+        let proto = msg.to_proto();
+        let decoded = Validator::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
@@ -12,8 +12,10 @@ impl Validatable for DidMethod {
     fn validate(&self) -> Result<(), String> {
         lazy_static! {
             static ref REGEX_METHOD_NAME: Regex = Regex::new("^[a-z0-9]+$").unwrap();
+            // did:indy method carries namespace of the network: indy:sovrin or indy:sovrin:staging
+            static ref REGEX_INDY_METHOD_NAME: Regex = Regex::new("^indy:[a-z0-9_-]+(:[a-z0-9_-]+)?$").unwrap();
         }
-        if !REGEX_METHOD_NAME.is_match(&self.0) {
+        if !REGEX_METHOD_NAME.is_match(&self.0) && !REGEX_INDY_METHOD_NAME.is_match(&self.0) {
             return Err(format!(
                 "Invalid default name: {}. It does not match the DID method name format.",
                 self.0
@@ -102,10 +104,16 @@ impl DidValue {
     pub fn is_abbreviatable(&self) -> bool {
         match self.get_method() {
             Some(ref method) if method.starts_with("sov") => true,
+            Some(ref method) if qualifier::indy_method_namespace(method).is_some() => true,
             Some(_) => false,
             None => true,
         }
     }
+
+    /// Namespace of the Indy network for did:indy DIDs.
+    pub fn indy_namespace(&self) -> Option<String> {
+        qualifier::indy_namespace(&self.0)
+    }
 }
 
 impl Validatable for DidValue {
@@ -1,10 +1,12 @@
 use serde_json::Value;
 use std::ops::Not;
 
-use super::constants::{AUTH_RULE, AUTH_RULES, GET_AUTH_RULE};
+use indy_api_types::validation::Validatable;
+
+use super::constants::{txn_name_to_code, AUTH_RULE, AUTH_RULES, GET_AUTH_RULE};
 
 #[allow(non_camel_case_types)]
-#[derive(Deserialize, Debug, Serialize, PartialEq)]
+#[derive(Deserialize, Debug, Serialize, PartialEq, Clone)]
 pub enum AuthAction {
     ADD,
     EDIT
@@ -247,4 +249,74 @@ impl AuthRulesOperation {
     pub fn new(rules: AuthRules) -> AuthRulesOperation {
         AuthRulesOperation { _type: AUTH_RULES.to_string(), rules }
     }
-}
\ No newline at end of file
+}
+
+/**
+   The action to evaluate auth rules for
+    # parameters
+   auth_type - The type of the transaction (code or name)
+   auth_action - The type of the action (ADD or EDIT)
+   field - The field the action is applied to
+   old_value - The old value of the field (for EDIT action)
+   new_value - The new value of the field
+   owner - The DID of the owner of the object (the request author by default)
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct AuthRuleAction {
+    pub auth_type: String,
+    pub auth_action: AuthAction,
+    pub field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub old_value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+}
+
+impl Validatable for AuthRuleAction {
+    fn validate(&self) -> Result<(), String> {
+        if txn_name_to_code(&self.auth_type).is_none() {
+            return Err(format!("Unsupported `auth_type`: {}", self.auth_type));
+        }
+
+        if self.field.is_empty() {
+            return Err(String::from("Empty `field` has been passed"));
+        }
+
+        Ok(())
+    }
+}
+
+/**
+   Result of the evaluation of auth rules for a request
+    # parameters
+   satisfied - Whether the request meets the constraint
+   forbidden - Whether the action is forbidden
+   missing_signatures - Role constraints which are not met yet.
+        For OR combinations the alternative requiring the least signatures is reported.
+   missing_endorsement - Whether the request names an Endorser which hasn't signed it or has no role allowing to endorse
+*/
+#[derive(Serialize, PartialEq, Debug)]
+pub struct AuthRuleEvaluation {
+    pub satisfied: bool,
+    pub forbidden: bool,
+    pub missing_signatures: Vec<MissingSignatures>,
+    pub missing_endorsement: bool,
+}
+
+/**
+   Signatures required to meet a role constraint
+    # parameters
+   role - The role which signers must have
+   sig_count - The number of missing signatures
+   need_to_be_owner - Whether a signer must be an owner of the object
+   off_ledger_signature - Whether signatures of DIDs unknown for the ledger are accepted
+*/
+#[derive(Serialize, PartialEq, Debug, Clone)]
+pub struct MissingSignatures {
+    pub role: Option<String>,
+    pub sig_count: u32,
+    pub need_to_be_owner: bool,
+    pub off_ledger_signature: bool,
+}
@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use indy_api_types::validation::Validatable;
 
 use super::constants::{GET_TXN_AUTHR_AGRMT, GET_TXN_AUTHR_AGRMT_AML, TXN_AUTHR_AGRMT, TXN_AUTHR_AGRMT_AML, DISABLE_ALL_TXN_AUTHR_AGRMTS};
+use super::request::TxnAuthrAgrmtAcceptanceData;
+use super::response::ReplyType;
 
 #[derive(Serialize, PartialEq, Debug)]
 pub struct TxnAuthorAgreementOperation {
@@ -85,6 +87,34 @@ impl GetTxnAuthorAgreementOperation {
     }
 }
 
+#[derive(Deserialize, Debug)]
+pub struct GetTxnAuthorAgreementResult {
+    pub data: Option<GetTxnAuthorAgreementResultData>,
+}
+
+impl ReplyType for GetTxnAuthorAgreementResult {
+    fn get_type<'a>() -> &'a str {
+        GET_TXN_AUTHR_AGRMT
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetTxnAuthorAgreementResultData {
+    pub digest: String,
+    pub version: String,
+    #[serde(default)]
+    pub retirement_ts: Option<u64>,
+}
+
+/// Transaction author agreement acceptance set for the opened pool.
+#[derive(Debug, Clone)]
+pub struct PoolTxnAuthorAgreement {
+    pub acceptance: TxnAuthrAgrmtAcceptanceData,
+    /// Digest of the agreement in force on the ledger (`None` if there is no agreement)
+    /// and the time (in sec) when it was read from the ledger.
+    pub ledger_digest: Option<(Option<String>, i64)>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct AcceptanceMechanisms(pub HashMap<String, ::serde_json::Value>);
 
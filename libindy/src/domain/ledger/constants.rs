@@ -6,6 +6,8 @@ pub const TXN_AUTHR_AGRMT_AML: &str = "5";
 pub const GET_TXN_AUTHR_AGRMT: &str = "6";
 pub const GET_TXN_AUTHR_AGRMT_AML: &str = "7";
 pub const DISABLE_ALL_TXN_AUTHR_AGRMTS: &str = "8";
+pub const LEDGERS_FREEZE: &str = "9";
+pub const GET_FROZEN_LEDGERS: &str = "10";
 pub const ATTRIB: &str = "100";
 pub const SCHEMA: &str = "101";
 pub const CRED_DEF: &str = "102";
@@ -25,12 +27,26 @@ pub const GET_VALIDATOR_INFO: &str = "119";
 pub const AUTH_RULE: &str = "120";
 pub const GET_AUTH_RULE: &str = "121";
 pub const AUTH_RULES: &str = "122";
-pub const GET_DDO: &str = "120";//TODO change number
+pub const SET_JSON_LD_CONTEXT: &str = "200";
+pub const SET_RICH_SCHEMA: &str = "201";
+pub const SET_RICH_SCHEMA_ENCODING: &str = "202";
+pub const SET_RICH_SCHEMA_MAPPING: &str = "203";
+pub const SET_RICH_SCHEMA_CRED_DEF: &str = "204";
+pub const SET_RICH_SCHEMA_PRES_DEF: &str = "205";
+pub const GET_RICH_SCHEMA_OBJECT_BY_ID: &str = "300";
+pub const GET_RICH_SCHEMA_OBJECT_BY_METADATA: &str = "301";
 
-pub const REQUESTS: [&str; 25] = [NODE, NYM, GET_TXN, ATTRIB, SCHEMA, CRED_DEF, GET_ATTR, GET_NYM, GET_SCHEMA,
+pub const REQUESTS: [&str; 34] = [NODE, NYM, GET_TXN, ATTRIB, SCHEMA, CRED_DEF, GET_ATTR, GET_NYM, GET_SCHEMA,
     GET_CRED_DEF, POOL_UPGRADE, POOL_RESTART, POOL_CONFIG, REVOC_REG_DEF, REVOC_REG_ENTRY, GET_REVOC_REG_DEF,
-    GET_REVOC_REG, GET_REVOC_REG_DELTA, GET_VALIDATOR_INFO, AUTH_RULE, GET_DDO, TXN_AUTHR_AGRMT, TXN_AUTHR_AGRMT_AML,
-    GET_TXN_AUTHR_AGRMT, GET_TXN_AUTHR_AGRMT_AML];
+    GET_REVOC_REG, GET_REVOC_REG_DELTA, GET_VALIDATOR_INFO, AUTH_RULE, TXN_AUTHR_AGRMT, TXN_AUTHR_AGRMT_AML,
+    GET_TXN_AUTHR_AGRMT, GET_TXN_AUTHR_AGRMT_AML, LEDGERS_FREEZE, GET_FROZEN_LEDGERS, SET_JSON_LD_CONTEXT,
+    SET_RICH_SCHEMA, SET_RICH_SCHEMA_ENCODING, SET_RICH_SCHEMA_MAPPING, SET_RICH_SCHEMA_CRED_DEF,
+    SET_RICH_SCHEMA_PRES_DEF, GET_RICH_SCHEMA_OBJECT_BY_ID, GET_RICH_SCHEMA_OBJECT_BY_METADATA];
+
+/// Write requests to the domain ledger. Transaction author agreement acceptance applies to them.
+pub const DOMAIN_LEDGER_WRITE_REQUESTS: [&str; 12] = [NYM, ATTRIB, SCHEMA, CRED_DEF, REVOC_REG_DEF, REVOC_REG_ENTRY,
+    SET_JSON_LD_CONTEXT, SET_RICH_SCHEMA, SET_RICH_SCHEMA_ENCODING, SET_RICH_SCHEMA_MAPPING, SET_RICH_SCHEMA_CRED_DEF,
+    SET_RICH_SCHEMA_PRES_DEF];
 
 pub const TRUSTEE: &str = "0";
 pub const STEWARD: &str = "2";
@@ -66,11 +82,20 @@ pub fn txn_name_to_code(txn: &str) -> Option<&str> {
         "GET_REVOC_REG_DELTA" => Some(GET_REVOC_REG_DELTA),
         "GET_VALIDATOR_INFO" => Some(GET_VALIDATOR_INFO),
         "AUTH_RULE" => Some(AUTH_RULE),
-        "GET_DDO" => Some(GET_DDO),
         "TXN_AUTHR_AGRMT" => Some(TXN_AUTHR_AGRMT),
         "TXN_AUTHR_AGRMT_AML" => Some(TXN_AUTHR_AGRMT_AML),
         "GET_TXN_AUTHR_AGRMT" => Some(GET_TXN_AUTHR_AGRMT),
         "GET_TXN_AUTHR_AGRMT_AML" => Some(GET_TXN_AUTHR_AGRMT_AML),
+        "LEDGERS_FREEZE" => Some(LEDGERS_FREEZE),
+        "GET_FROZEN_LEDGERS" => Some(GET_FROZEN_LEDGERS),
+        "SET_JSON_LD_CONTEXT" => Some(SET_JSON_LD_CONTEXT),
+        "SET_RICH_SCHEMA" => Some(SET_RICH_SCHEMA),
+        "SET_RICH_SCHEMA_ENCODING" => Some(SET_RICH_SCHEMA_ENCODING),
+        "SET_RICH_SCHEMA_MAPPING" => Some(SET_RICH_SCHEMA_MAPPING),
+        "SET_RICH_SCHEMA_CRED_DEF" => Some(SET_RICH_SCHEMA_CRED_DEF),
+        "SET_RICH_SCHEMA_PRES_DEF" => Some(SET_RICH_SCHEMA_PRES_DEF),
+        "GET_RICH_SCHEMA_OBJECT_BY_ID" => Some(GET_RICH_SCHEMA_OBJECT_BY_ID),
+        "GET_RICH_SCHEMA_OBJECT_BY_METADATA" => Some(GET_RICH_SCHEMA_OBJECT_BY_METADATA),
         val => Some(val)
     }
 }
\ No newline at end of file
@@ -2,7 +2,6 @@ use super::constants::{CRED_DEF, GET_CRED_DEF};
 use super::response::{GetReplyResultV1, ReplyType};
 use super::super::anoncreds::credential_definition::{CredentialDefinitionData, CredentialDefinitionV1, SignatureType, CredentialDefinitionId};
 use super::super::anoncreds::schema::SchemaId;
-use super::super::ledger::request::ProtocolVersion;
 use super::super::crypto::did::ShortDidValue;
 
 #[derive(Serialize, Debug)]
@@ -18,17 +17,33 @@ pub struct CredDefOperation {
 }
 
 impl CredDefOperation {
-    pub fn new(data: CredentialDefinitionV1) -> CredDefOperation {
+    pub fn new(data: CredentialDefinitionV1, protocol_version: usize) -> CredDefOperation {
         CredDefOperation {
             _ref: data.schema_id.0.parse::<i32>().unwrap_or(0),
             signature_type: data.signature_type.to_str().to_string(),
             data: data.value,
-            tag: if ProtocolVersion::is_node_1_3() { None } else { Some(data.tag.clone()) },
+            tag: if protocol_version == 1 { None } else { Some(data.tag.clone()) },
             _type: CRED_DEF.to_string()
         }
     }
 }
 
+/// Drops credential definition tags from CRED_DEF and GET_CRED_DEF operations
+/// sent to node 1.3 pools (protocol version 1) as they don't support them.
+pub fn apply_protocol_version(operation: &mut serde_json::Value, protocol_version: usize) {
+    if protocol_version != 1 {
+        return;
+    }
+
+    if let Some(operation) = operation.as_object_mut() {
+        let type_ = operation.get("type").and_then(serde_json::Value::as_str);
+
+        if type_ == Some(CRED_DEF) || type_ == Some(GET_CRED_DEF) {
+            operation.remove("tag");
+        }
+    }
+}
+
 #[derive(Serialize, PartialEq, Debug)]
 pub struct GetCredDefOperation {
     #[serde(rename = "type")]
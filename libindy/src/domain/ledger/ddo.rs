@@ -1,18 +1,184 @@
-use super::constants::GET_DDO;
-use super::super::crypto::did::ShortDidValue;
+use serde_json::Value;
 
-#[derive(Serialize, PartialEq, Debug)]
-pub struct GetDdoOperation {
+use super::attrib::Endpoint;
+use super::response::ResponseMetadata;
+
+pub const DID_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+pub const ED25519_2018_CONTEXT: &str = "https://w3id.org/security/suites/ed25519-2018/v1";
+pub const X25519_2019_CONTEXT: &str = "https://w3id.org/security/suites/x25519-2019/v1";
+pub const DIDCOMM_V2_CONTEXT: &str = "https://didcomm.org/messaging/contexts/v2";
+pub const DID_RESOLUTION_CONTEXT: &str = "https://w3id.org/did-resolution/v1";
+pub const DID_LD_JSON: &str = "application/did+ld+json";
+
+pub const ED25519_VERIFICATION_KEY_2018: &str = "Ed25519VerificationKey2018";
+pub const X25519_KEY_AGREEMENT_KEY_2019: &str = "X25519KeyAgreementKey2019";
+
+pub const VERKEY_FRAGMENT: &str = "verkey";
+pub const KEY_AGREEMENT_FRAGMENT: &str = "key-agreement-1";
+
+pub const SERVICE_ENDPOINT: &str = "endpoint";
+pub const SERVICE_DID_COMMUNICATION: &str = "did-communication";
+pub const SERVICE_DIDCOMM: &str = "DIDComm";
+pub const DIDCOMM_V2_SERVICE_FRAGMENT: &str = "didcomm-1";
+
+pub const ACCEPT_DIDCOMM_AIP2: &str = "didcomm/aip2;env=rfc19";
+pub const ACCEPT_DIDCOMM_V2: &str = "didcomm/v2";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+    pub id: String,
     #[serde(rename = "type")]
-    pub _type: String,
-    pub dest: ShortDidValue
+    pub type_: String,
+    pub controller: String,
+    pub public_key_base58: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Service {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub service_endpoint: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recipient_keys: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub routing_keys: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accept: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    pub id: String,
+    pub verification_method: Vec<VerificationMethod>,
+    pub authentication: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_agreement: Vec<VerificationMethod>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub service: Vec<Service>,
 }
 
-impl GetDdoOperation {
-    pub fn new(dest: ShortDidValue) -> GetDdoOperation {
-        GetDdoOperation {
-            _type: GET_DDO.to_string(),
-            dest
+impl DidDocument {
+    pub fn new(id: &str, verkey: &str) -> DidDocument {
+        let verkey_id = DidDocument::fragment(id, VERKEY_FRAGMENT);
+
+        DidDocument {
+            context: vec![DID_CONTEXT.to_string(), ED25519_2018_CONTEXT.to_string()],
+            id: id.to_string(),
+            verification_method: vec![VerificationMethod {
+                id: verkey_id.clone(),
+                type_: ED25519_VERIFICATION_KEY_2018.to_string(),
+                controller: id.to_string(),
+                public_key_base58: verkey.to_string(),
+            }],
+            authentication: vec![verkey_id],
+            key_agreement: Vec::new(),
+            service: Vec::new(),
+        }
+    }
+
+    pub fn fragment(id: &str, fragment: &str) -> String {
+        format!("{}#{}", id, fragment)
+    }
+
+    pub fn add_context(&mut self, context: &str) {
+        if !self.context.iter().any(|c| c == context) {
+            self.context.push(context.to_string());
         }
     }
-}
\ No newline at end of file
+}
+
+/// Endpoint ATTRIB in the format defined by did:indy method specification.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidCommEndpoint {
+    pub endpoint: String,
+    pub types: Option<Vec<String>>,
+    #[serde(default)]
+    pub routing_keys: Vec<String>,
+}
+
+impl DidCommEndpoint {
+    pub fn types(&self) -> Vec<String> {
+        self.types
+            .clone()
+            .unwrap_or_else(|| vec![SERVICE_ENDPOINT.to_string(), SERVICE_DID_COMMUNICATION.to_string()])
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum EndpointData {
+    DidComm(DidCommEndpoint),
+    // {"ha": "ip:port", "verkey": ...} set by indy_set_endpoint_for_did style agents
+    Legacy(Endpoint),
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct EndpointAttrib {
+    pub endpoint: EndpointData,
+}
+
+/// Part of node reply state proof which lets the caller re-check the resolved data.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StateProofMetadata {
+    #[serde(alias = "root_hash")]
+    pub root_hash: String,
+    #[serde(alias = "multi_signature")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multi_signature: Option<Value>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerObjectMetadata {
+    #[serde(flatten)]
+    pub response_metadata: ResponseMetadata,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_proof: Option<StateProofMetadata>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocumentMetadata {
+    pub nym: LedgerObjectMetadata,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<LedgerObjectMetadata>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionMetadata {
+    pub content_type: String,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionResult {
+    #[serde(rename = "@context")]
+    pub context: String,
+    pub did_document: Value,
+    pub did_resolution_metadata: DidResolutionMetadata,
+    pub did_document_metadata: DidDocumentMetadata,
+}
+
+impl DidResolutionResult {
+    pub fn new(did_document: Value, did_document_metadata: DidDocumentMetadata) -> DidResolutionResult {
+        DidResolutionResult {
+            context: DID_RESOLUTION_CONTEXT.to_string(),
+            did_document,
+            did_resolution_metadata: DidResolutionMetadata {
+                content_type: DID_LD_JSON.to_string(),
+            },
+            did_document_metadata,
+        }
+    }
+}
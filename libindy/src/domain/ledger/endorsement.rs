@@ -0,0 +1,130 @@
+use indy_api_types::validation::Validatable;
+use serde_json::Value;
+
+use super::{
+    auth_rule::{AuthRuleAction, AuthRuleEvaluation},
+    nym::NymData,
+};
+use crate::domain::crypto::did::DidValue;
+
+/// Auth rule data used to check that an endorsed request meets the ledger constraints.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EndorsementAuthRule {
+    pub get_auth_rule_response: String,
+    pub action: AuthRuleAction,
+    #[serde(default)]
+    pub signers: Vec<NymData>,
+}
+
+impl Validatable for EndorsementAuthRule {
+    fn validate(&self) -> Result<(), String> {
+        self.action.validate()
+    }
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct EndorsementEnvelopeOptions {
+    #[serde(default)]
+    pub required_signers: Vec<DidValue>,
+    pub auth_rule: Option<EndorsementAuthRule>,
+}
+
+impl Validatable for EndorsementEnvelopeOptions {
+    fn validate(&self) -> Result<(), String> {
+        for did in self.required_signers.iter() {
+            did.validate()?;
+        }
+
+        if let Some(ref auth_rule) = self.auth_rule {
+            auth_rule.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Request waiting for signatures of its author and endorser.
+/// The same structure is passed between parties and stored in their wallets until submitted.
+/// `required_signers` is `None` if neither an auth rule nor explicit signers determine them.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PendingEndorsement {
+    pub id: String,
+    pub request: Value,
+    pub endorser: String,
+    pub required_signers: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_rule: Option<EndorsementAuthRule>,
+}
+
+impl PendingEndorsement {
+    pub fn id(identifier: &str, req_id: u64) -> String {
+        format!("{}:{}", identifier, req_id)
+    }
+
+    pub fn signers(&self) -> Vec<&str> {
+        let mut signers: Vec<&str> = self.request["signatures"]
+            .as_object()
+            .map(|signatures| signatures.keys().map(String::as_str).collect())
+            .unwrap_or_default();
+
+        if let (Some(_), Some(identifier)) = (
+            self.request["signature"].as_str(),
+            self.request["identifier"].as_str(),
+        ) {
+            signers.push(identifier);
+        }
+
+        signers
+    }
+
+    pub fn missing_signers(&self) -> Vec<String> {
+        let signers = self.signers();
+
+        self.required_signers
+            .iter()
+            .flatten()
+            .filter(|did| !signers.contains(&did.as_str()))
+            .cloned()
+            .collect()
+    }
+}
+
+impl Validatable for PendingEndorsement {
+    fn validate(&self) -> Result<(), String> {
+        if !self.request.is_object() {
+            return Err(String::from("`request` must be a json object"));
+        }
+
+        if self.request["endorser"].as_str() != Some(self.endorser.as_str()) {
+            return Err(String::from("`request` is not prepared for the envelope endorser"));
+        }
+
+        let expected_id = match (
+            self.request["identifier"].as_str(),
+            self.request["reqId"].as_u64(),
+        ) {
+            (Some(identifier), Some(req_id)) => PendingEndorsement::id(identifier, req_id),
+            _ => return Err(String::from("`request` must contain `identifier` and `reqId`")),
+        };
+
+        if self.id != expected_id {
+            return Err(String::from("`id` doesn't match the request"));
+        }
+
+        if let Some(ref auth_rule) = self.auth_rule {
+            auth_rule.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct EndorsementStatus {
+    pub complete: bool,
+    pub required_signers_determined: bool,
+    pub missing_signers: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_rule_evaluation: Option<AuthRuleEvaluation>,
+}
+
@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use super::constants::{GET_FROZEN_LEDGERS, LEDGERS_FREEZE};
+use super::response::{GetReplyResultV0, ReplyType};
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct LedgersFreezeOperation {
+    #[serde(rename = "type")]
+    _type: String,
+    ledgers_ids: Vec<u64>,
+}
+
+impl LedgersFreezeOperation {
+    pub fn new(ledgers_ids: Vec<u64>) -> LedgersFreezeOperation {
+        LedgersFreezeOperation {
+            _type: LEDGERS_FREEZE.to_string(),
+            ledgers_ids,
+        }
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct GetFrozenLedgersOperation {
+    #[serde(rename = "type")]
+    _type: String,
+}
+
+impl GetFrozenLedgersOperation {
+    pub fn new() -> GetFrozenLedgersOperation {
+        GetFrozenLedgersOperation {
+            _type: GET_FROZEN_LEDGERS.to_string(),
+        }
+    }
+}
+
+/// Last state of the ledger captured at the moment of freezing.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct FrozenLedger {
+    pub ledger: String,
+    pub state: String,
+    pub seq_no: u64,
+}
+
+/// Frozen ledgers keyed by ledger id.
+pub type FrozenLedgers = HashMap<String, FrozenLedger>;
+
+pub type GetFrozenLedgersReplyResult = GetReplyResultV0<FrozenLedgers>;
+
+impl ReplyType for GetFrozenLedgersReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_FROZEN_LEDGERS
+    }
+}
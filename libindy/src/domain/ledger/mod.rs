@@ -14,3 +14,6 @@ pub mod validator_info;
 pub mod constants;
 pub mod auth_rule;
 pub mod author_agreement;
+pub mod ledgers_freeze;
+pub mod rich_schema;
+pub mod endorsement;
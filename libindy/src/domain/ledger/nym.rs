@@ -61,7 +61,9 @@ pub struct GetNymResultDataV0 {
     pub identifier: Option<ShortDidValue>,
     pub dest: ShortDidValue,
     pub role: Option<String>,
-    pub verkey: Option<String>
+    pub verkey: Option<String>,
+    #[serde(rename = "diddocContent")]
+    pub diddoc_content: Option<String>,
 }
 
 #[derive(Deserialize, Eq, PartialEq, Debug)]
@@ -70,10 +72,12 @@ pub struct GetNymResultDataV1 {
     pub id: String,
     pub did: ShortDidValue,
     pub verkey: Option<String>,
-    pub role: Option<String>
+    pub role: Option<String>,
+    #[serde(rename = "diddocContent")]
+    pub diddoc_content: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone)]
 pub struct NymData {
     pub did: ShortDidValue,
     pub verkey: Option<String>,
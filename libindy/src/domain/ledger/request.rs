@@ -7,6 +7,7 @@ use std::{
     sync::atomic::{AtomicUsize, Ordering},
 };
 
+use indy_api_types::validation::Validatable;
 use lazy_static::lazy_static;
 
 use super::super::crypto::did::{DidValue, ShortDidValue};
@@ -33,7 +34,7 @@ impl ProtocolVersion {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct TxnAuthrAgrmtAcceptanceData {
     pub mechanism: String,
@@ -99,3 +100,55 @@ impl<T: serde::Serialize> Request<T> {
         .map_err(|err| format!("Cannot serialize Request: {:?}", err))
     }
 }
+
+pub const DEFAULT_SUBMIT_CONCURRENCY: usize = 10;
+
+#[derive(Deserialize, Debug)]
+pub struct SubmitRequestsOptions {
+    #[serde(default = "SubmitRequestsOptions::default_max_concurrency")]
+    pub max_concurrency: usize,
+    #[serde(default)]
+    pub stop_on_failure: bool,
+}
+
+impl Validatable for SubmitRequestsOptions {
+    fn validate(&self) -> Result<(), String> {
+        if self.max_concurrency == 0 {
+            return Err(String::from("`max_concurrency` must be greater than 0"));
+        }
+        Ok(())
+    }
+}
+
+impl Default for SubmitRequestsOptions {
+    fn default() -> Self {
+        SubmitRequestsOptions {
+            max_concurrency: SubmitRequestsOptions::default_max_concurrency(),
+            stop_on_failure: false,
+        }
+    }
+}
+
+impl SubmitRequestsOptions {
+    fn default_max_concurrency() -> usize {
+        DEFAULT_SUBMIT_CONCURRENCY
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum SubmitRequestResult {
+    Reply { reply: serde_json::Value },
+    Rejected { reply: serde_json::Value },
+    Error { error_code: i32, message: String },
+    Skipped,
+}
+
+impl SubmitRequestResult {
+    pub fn is_failure(&self) -> bool {
+        match self {
+            SubmitRequestResult::Reply { .. } | SubmitRequestResult::Skipped => false,
+            SubmitRequestResult::Rejected { .. } | SubmitRequestResult::Error { .. } => true,
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use indy_api_types::validation::Validatable;
+use serde_json::Value;
+
+use super::constants::{
+    GET_RICH_SCHEMA_OBJECT_BY_ID, GET_RICH_SCHEMA_OBJECT_BY_METADATA, SET_JSON_LD_CONTEXT,
+    SET_RICH_SCHEMA, SET_RICH_SCHEMA_CRED_DEF, SET_RICH_SCHEMA_ENCODING, SET_RICH_SCHEMA_MAPPING,
+    SET_RICH_SCHEMA_PRES_DEF,
+};
+use super::response::{GetReplyResultV0, ReplyType};
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum RichSchemaType {
+    Ctx,
+    Sch,
+    Enc,
+    Map,
+    Cdf,
+    Pdf,
+}
+
+impl RichSchemaType {
+    pub fn from_str(rs_type: &str) -> Result<RichSchemaType, String> {
+        serde_json::from_value(Value::String(rs_type.to_string())).map_err(|_| {
+            format!(
+                "Invalid rich schema type: {}. Expected one of: ctx, sch, enc, map, cdf, pdf",
+                rs_type
+            )
+        })
+    }
+
+    pub fn txn_type(&self) -> &'static str {
+        match self {
+            RichSchemaType::Ctx => SET_JSON_LD_CONTEXT,
+            RichSchemaType::Sch => SET_RICH_SCHEMA,
+            RichSchemaType::Enc => SET_RICH_SCHEMA_ENCODING,
+            RichSchemaType::Map => SET_RICH_SCHEMA_MAPPING,
+            RichSchemaType::Cdf => SET_RICH_SCHEMA_CRED_DEF,
+            RichSchemaType::Pdf => SET_RICH_SCHEMA_PRES_DEF,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RichSchema {
+    pub id: String,
+    pub content: String,
+    pub rs_name: String,
+    pub rs_version: String,
+    pub rs_type: RichSchemaType,
+    pub ver: String,
+}
+
+impl RichSchema {
+    pub fn new(
+        id: String,
+        content: String,
+        rs_name: String,
+        rs_version: String,
+        rs_type: RichSchemaType,
+        ver: String,
+    ) -> RichSchema {
+        RichSchema {
+            id,
+            content,
+            rs_name,
+            rs_version,
+            rs_type,
+            ver,
+        }
+    }
+}
+
+impl Validatable for RichSchema {
+    fn validate(&self) -> Result<(), String> {
+        if self.id.is_empty() {
+            return Err("Rich schema id is empty".to_string());
+        }
+
+        if self.rs_name.is_empty() || self.rs_version.is_empty() {
+            return Err("Rich schema name and version must be specified".to_string());
+        }
+
+        let content: Value = serde_json::from_str(&self.content)
+            .map_err(|err| format!("Rich schema content is invalid json: {}", err))?;
+
+        if !content.is_object() {
+            return Err("Rich schema content must be a json object".to_string());
+        }
+
+        match self.rs_type {
+            RichSchemaType::Ctx => {
+                if content["@context"].is_null() {
+                    return Err("JSON-LD context content must contain `@context`".to_string());
+                }
+            }
+            RichSchemaType::Sch | RichSchemaType::Map => {
+                if content["@id"].as_str() != Some(self.id.as_str()) {
+                    return Err(format!(
+                        "Rich schema content `@id` must be equal to rich schema id: {}",
+                        self.id
+                    ));
+                }
+
+                if content["@type"].is_null() {
+                    return Err("Rich schema content must contain `@type`".to_string());
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RichSchemaOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub id: String,
+    pub content: String,
+    pub rs_name: String,
+    pub rs_version: String,
+    pub rs_type: RichSchemaType,
+    pub ver: String,
+}
+
+impl RichSchemaOperation {
+    pub fn new(rich_schema: RichSchema) -> RichSchemaOperation {
+        RichSchemaOperation {
+            _type: rich_schema.rs_type.txn_type().to_string(),
+            id: rich_schema.id,
+            content: rich_schema.content,
+            rs_name: rich_schema.rs_name,
+            rs_version: rich_schema.rs_version,
+            rs_type: rich_schema.rs_type,
+            ver: rich_schema.ver,
+        }
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct GetRichSchemaObjectByIdOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub id: String,
+}
+
+impl GetRichSchemaObjectByIdOperation {
+    pub fn new(id: String) -> GetRichSchemaObjectByIdOperation {
+        GetRichSchemaObjectByIdOperation {
+            _type: GET_RICH_SCHEMA_OBJECT_BY_ID.to_string(),
+            id,
+        }
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRichSchemaObjectByMetadataOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub rs_type: RichSchemaType,
+    pub rs_name: String,
+    pub rs_version: String,
+}
+
+impl GetRichSchemaObjectByMetadataOperation {
+    pub fn new(
+        rs_type: RichSchemaType,
+        rs_name: String,
+        rs_version: String,
+    ) -> GetRichSchemaObjectByMetadataOperation {
+        GetRichSchemaObjectByMetadataOperation {
+            _type: GET_RICH_SCHEMA_OBJECT_BY_METADATA.to_string(),
+            rs_type,
+            rs_name,
+            rs_version,
+        }
+    }
+}
+
+/// Rich schema object as it is stored in the ledger state.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RichSchemaObject {
+    pub id: String,
+    pub content: String,
+    pub rs_name: String,
+    pub rs_version: String,
+    pub rs_type: RichSchemaType,
+    pub ver: String,
+    pub from: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endorser: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(transparent)]
+pub struct GetRichSchemaObjectByIdReplyResult(pub GetReplyResultV0<RichSchemaObject>);
+
+impl ReplyType for GetRichSchemaObjectByIdReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_RICH_SCHEMA_OBJECT_BY_ID
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(transparent)]
+pub struct GetRichSchemaObjectByMetadataReplyResult(pub GetReplyResultV0<RichSchemaObject>);
+
+impl ReplyType for GetRichSchemaObjectByMetadataReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_RICH_SCHEMA_OBJECT_BY_METADATA
+    }
+}
+
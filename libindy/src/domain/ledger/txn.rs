@@ -1,3 +1,5 @@
+use indy_api_types::validation::Validatable;
+
 use super::constants::GET_TXN;
 
 #[derive(Serialize, PartialEq, Debug)]
@@ -35,3 +37,49 @@ impl LedgerType {
         }
     }
 }
+
+pub const DEFAULT_DOWNLOAD_BATCH_SIZE: usize = 100;
+
+#[derive(Deserialize, Debug)]
+pub struct DownloadLedgerConfig {
+    #[serde(default = "DownloadLedgerConfig::default_batch_size")]
+    pub batch_size: usize,
+    pub to: Option<u64>,
+}
+
+impl Validatable for DownloadLedgerConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.batch_size == 0 {
+            return Err(String::from("`batch_size` must be greater than 0"));
+        }
+        if self.to == Some(0) {
+            return Err(String::from("`to` must be greater than 0"));
+        }
+        Ok(())
+    }
+}
+
+impl Default for DownloadLedgerConfig {
+    fn default() -> Self {
+        DownloadLedgerConfig {
+            batch_size: DownloadLedgerConfig::default_batch_size(),
+            to: None,
+        }
+    }
+}
+
+impl DownloadLedgerConfig {
+    fn default_batch_size() -> usize {
+        DEFAULT_DOWNLOAD_BATCH_SIZE
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerDownloadStatus {
+    pub ledger_id: i32,
+    pub ledger_size: u64,
+    pub txns_count: u64,
+    pub root_hash: String,
+    pub path: String,
+}
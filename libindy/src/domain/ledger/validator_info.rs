@@ -1,4 +1,7 @@
+use std::collections::BTreeMap;
+
 use super::constants::GET_VALIDATOR_INFO;
+use super::response::{GetReplyResultV0, ReplyType};
 
 #[derive(Serialize, PartialEq, Debug)]
 pub struct GetValidatorInfoOperation {
@@ -14,3 +17,358 @@ impl GetValidatorInfoOperation {
     }
 }
 
+
+pub type GetValidatorInfoReplyResult = GetReplyResultV0<ValidatorInfoData>;
+
+impl ReplyType for GetValidatorInfoReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_VALIDATOR_INFO
+    }
+}
+
+/// Validator info reported by a node. Only the parts libindy summarizes are typed,
+/// everything is optional as the report differs between Indy Node versions.
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct ValidatorInfoData {
+    pub timestamp: Option<u64>,
+    #[serde(rename = "Pool_info")]
+    pub pool_info: ValidatorPoolInfo,
+    #[serde(rename = "Node_info")]
+    pub node_info: ValidatorNodeInfo,
+    #[serde(rename = "Software")]
+    pub software: ValidatorSoftwareInfo,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct ValidatorPoolInfo {
+    /// Either node names or `[name, replica number]` pairs.
+    #[serde(rename = "Unreachable_nodes")]
+    pub unreachable_nodes: Vec<serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct ValidatorNodeInfo {
+    #[serde(rename = "Name")]
+    pub name: Option<String>,
+    #[serde(rename = "Mode")]
+    pub mode: Option<String>,
+    #[serde(rename = "Metrics")]
+    pub metrics: ValidatorMetrics,
+    #[serde(rename = "View_change_status")]
+    pub view_change_status: ValidatorViewChangeStatus,
+    #[serde(rename = "Catchup_status")]
+    pub catchup_status: ValidatorCatchupStatus,
+    #[serde(rename = "Freshness_status")]
+    pub freshness_status: BTreeMap<String, ValidatorFreshnessStatus>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct ValidatorMetrics {
+    pub uptime: Option<u64>,
+    #[serde(rename = "transaction-count")]
+    pub transaction_count: BTreeMap<String, u64>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct ValidatorViewChangeStatus {
+    #[serde(rename = "View_No")]
+    pub view_no: Option<u64>,
+    #[serde(rename = "VC_in_progress")]
+    pub vc_in_progress: Option<bool>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct ValidatorCatchupStatus {
+    #[serde(rename = "Ledger_statuses")]
+    pub ledger_statuses: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct ValidatorFreshnessStatus {
+    #[serde(rename = "Last_updated_time")]
+    pub last_updated_time: Option<String>,
+    #[serde(rename = "Has_write_consensus")]
+    pub has_write_consensus: Option<bool>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct ValidatorSoftwareInfo {
+    #[serde(rename = "indy-node")]
+    pub indy_node: Option<String>,
+    pub sovrin: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeValidatorInfoStatus {
+    Ok,
+    Timeout,
+    Error,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LedgerFreshness {
+    pub last_updated_time: Option<String>,
+    pub has_write_consensus: Option<bool>,
+}
+
+/// Validator info of a single node with ledgers keyed by name (pool, domain, config, audit).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NodeValidatorInfo {
+    pub node: String,
+    pub status: NodeValidatorInfoStatus,
+    pub error: Option<String>,
+    pub mode: Option<String>,
+    pub indy_node_version: Option<String>,
+    pub sovrin_version: Option<String>,
+    pub uptime: Option<u64>,
+    pub timestamp: Option<u64>,
+    pub view_no: Option<u64>,
+    pub view_change_in_progress: Option<bool>,
+    pub catchup_status: BTreeMap<String, String>,
+    pub ledger_sizes: BTreeMap<String, u64>,
+    pub freshness: BTreeMap<String, LedgerFreshness>,
+    pub unreachable_nodes: Vec<String>,
+}
+
+impl NodeValidatorInfo {
+    pub fn from_data(node: &str, data: ValidatorInfoData) -> NodeValidatorInfo {
+        let node_info = data.node_info;
+
+        NodeValidatorInfo {
+            node: node.to_string(),
+            status: NodeValidatorInfoStatus::Ok,
+            error: None,
+            mode: node_info.mode,
+            indy_node_version: data.software.indy_node,
+            sovrin_version: data.software.sovrin,
+            uptime: node_info.metrics.uptime,
+            timestamp: data.timestamp,
+            view_no: node_info.view_change_status.view_no,
+            view_change_in_progress: node_info.view_change_status.vc_in_progress,
+            catchup_status: node_info
+                .catchup_status
+                .ledger_statuses
+                .into_iter()
+                .map(|(ledger, status)| (ledger_name(&ledger), status))
+                .collect(),
+            ledger_sizes: node_info
+                .metrics
+                .transaction_count
+                .into_iter()
+                .map(|(ledger, size)| (ledger_name(&ledger), size))
+                .collect(),
+            freshness: node_info
+                .freshness_status
+                .into_iter()
+                .map(|(ledger, status)| {
+                    (
+                        ledger_name(&ledger),
+                        LedgerFreshness {
+                            last_updated_time: status.last_updated_time,
+                            has_write_consensus: status.has_write_consensus,
+                        },
+                    )
+                })
+                .collect(),
+            unreachable_nodes: data
+                .pool_info
+                .unreachable_nodes
+                .iter()
+                .filter_map(|node| match node {
+                    serde_json::Value::String(name) => Some(name.to_string()),
+                    serde_json::Value::Array(node) => node.get(0).and_then(|name| name.as_str()).map(String::from),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn failed(node: &str, status: NodeValidatorInfoStatus, error: Option<String>) -> NodeValidatorInfo {
+        NodeValidatorInfo {
+            node: node.to_string(),
+            status,
+            error,
+            mode: None,
+            indy_node_version: None,
+            sovrin_version: None,
+            uptime: None,
+            timestamp: None,
+            view_no: None,
+            view_change_in_progress: None,
+            catchup_status: BTreeMap::new(),
+            ledger_sizes: BTreeMap::new(),
+            freshness: BTreeMap::new(),
+            unreachable_nodes: Vec::new(),
+        }
+    }
+}
+
+/// Ledger names are used instead of ids and metric names reported by nodes.
+fn ledger_name(ledger: &str) -> String {
+    match ledger {
+        "0" | "pool" => "pool",
+        "1" | "ledger" | "domain" => "domain",
+        "2" | "config" => "config",
+        "3" | "audit" => "audit",
+        ledger => ledger,
+    }
+    .to_string()
+}
+
+/// Pool-wide view on validator info: values are grouped with the nodes reported them.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ValidatorInfoSummary {
+    pub nodes_count: usize,
+    pub replied_nodes: Vec<String>,
+    pub timeout_nodes: Vec<String>,
+    pub failed_nodes: Vec<String>,
+    pub indy_node_versions: BTreeMap<String, Vec<String>>,
+    pub view_numbers: BTreeMap<u64, Vec<String>>,
+    pub ledger_sizes: BTreeMap<String, BTreeMap<u64, Vec<String>>>,
+    pub unreachable_nodes: BTreeMap<String, Vec<String>>,
+    pub inconsistencies: Vec<String>,
+}
+
+impl ValidatorInfoSummary {
+    pub fn build(nodes: &[NodeValidatorInfo]) -> ValidatorInfoSummary {
+        let mut summary = ValidatorInfoSummary {
+            nodes_count: nodes.len(),
+            replied_nodes: Vec::new(),
+            timeout_nodes: Vec::new(),
+            failed_nodes: Vec::new(),
+            indy_node_versions: BTreeMap::new(),
+            view_numbers: BTreeMap::new(),
+            ledger_sizes: BTreeMap::new(),
+            unreachable_nodes: BTreeMap::new(),
+            inconsistencies: Vec::new(),
+        };
+
+        let mut node_inconsistencies = Vec::new();
+
+        for node in nodes {
+            let alias = node.node.clone();
+
+            match node.status {
+                NodeValidatorInfoStatus::Ok => summary.replied_nodes.push(alias.clone()),
+                NodeValidatorInfoStatus::Timeout => {
+                    node_inconsistencies.push(format!("{} didn't reply in time", alias));
+                    summary.timeout_nodes.push(alias);
+                    continue;
+                }
+                NodeValidatorInfoStatus::Error => {
+                    node_inconsistencies.push(format!(
+                        "{} replied with error: {}",
+                        alias,
+                        node.error.as_ref().map(String::as_str).unwrap_or("unknown")
+                    ));
+                    summary.failed_nodes.push(alias);
+                    continue;
+                }
+            }
+
+            if let Some(ref version) = node.indy_node_version {
+                summary.indy_node_versions.entry(version.clone()).or_insert_with(Vec::new).push(alias.clone());
+            }
+
+            if let Some(view_no) = node.view_no {
+                summary.view_numbers.entry(view_no).or_insert_with(Vec::new).push(alias.clone());
+            }
+
+            if node.view_change_in_progress == Some(true) {
+                node_inconsistencies.push(format!("View change is in progress on {}", alias));
+            }
+
+            for (ledger, size) in node.ledger_sizes.iter() {
+                summary
+                    .ledger_sizes
+                    .entry(ledger.clone())
+                    .or_insert_with(BTreeMap::new)
+                    .entry(*size)
+                    .or_insert_with(Vec::new)
+                    .push(alias.clone());
+            }
+
+            for (ledger, status) in node.catchup_status.iter() {
+                if status != "synced" {
+                    node_inconsistencies.push(format!("{} ledger of {} is not synced: {}", ledger, alias, status));
+                }
+            }
+
+            for (ledger, freshness) in node.freshness.iter() {
+                if freshness.has_write_consensus == Some(false) {
+                    node_inconsistencies.push(format!("{} ledger of {} has no write consensus", ledger, alias));
+                }
+            }
+
+            for unreachable_node in node.unreachable_nodes.iter() {
+                summary
+                    .unreachable_nodes
+                    .entry(unreachable_node.clone())
+                    .or_insert_with(Vec::new)
+                    .push(alias.clone());
+            }
+        }
+
+        if summary.indy_node_versions.len() > 1 {
+            summary.inconsistencies.push(format!(
+                "Nodes run different indy-node versions: {}",
+                _format_groups(&summary.indy_node_versions)
+            ));
+        }
+
+        if summary.view_numbers.len() > 1 {
+            summary
+                .inconsistencies
+                .push(format!("Nodes are in different views: {}", _format_groups(&summary.view_numbers)));
+        }
+
+        for (ledger, sizes) in summary.ledger_sizes.iter() {
+            if sizes.len() > 1 {
+                summary
+                    .inconsistencies
+                    .push(format!("Nodes have different {} ledger sizes: {}", ledger, _format_groups(sizes)));
+            }
+        }
+
+        for (node, reporters) in summary.unreachable_nodes.iter() {
+            summary
+                .inconsistencies
+                .push(format!("{} is unreachable from {}", node, reporters.join(", ")));
+        }
+
+        summary.inconsistencies.extend(node_inconsistencies);
+
+        summary
+    }
+}
+
+fn _format_groups<K: ::std::fmt::Display>(groups: &BTreeMap<K, Vec<String>>) -> String {
+    groups
+        .iter()
+        .map(|(value, nodes)| format!("{} ({})", value, nodes.join(", ")))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Validator info of all the nodes replied to GET_VALIDATOR_INFO request.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ValidatorInfo {
+    pub nodes: Vec<NodeValidatorInfo>,
+    pub summary: ValidatorInfoSummary,
+}
+
+impl ValidatorInfo {
+    pub fn new(nodes: Vec<NodeValidatorInfo>) -> ValidatorInfo {
+        let summary = ValidatorInfoSummary::build(&nodes);
+        ValidatorInfo { nodes, summary }
+    }
+}
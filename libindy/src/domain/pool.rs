@@ -1,4 +1,6 @@
 use indy_api_types::validation::Validatable;
+use lazy_static::lazy_static;
+use regex::Regex;
 
 pub const POOL_CON_ACTIVE_TO: i64 = 5;
 pub const POOL_ACK_TIMEOUT: i64 = 20;
@@ -6,16 +8,114 @@ pub const POOL_REPLY_TIMEOUT: i64 = 60;
 pub const MAX_REQ_PER_POOL_CON: usize = 5;
 pub const NUMBER_READ_NODES: u8 = 2;
 
+lazy_static! {
+    static ref NAMESPACE_REGEX: Regex = Regex::new("^[a-z0-9_-]+(:[a-z0-9_-]+)?$").unwrap();
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PoolConfig {
-    pub genesis_txn: String
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub genesis_txn: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub genesis_txn_data: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub genesis_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub genesis_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<PoolSnapshot>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot_verkey: Option<String>,
+}
+
+/// Where genesis transactions of the pool come from.
+#[derive(Debug)]
+pub enum GenesisSource<'a> {
+    File(&'a str),
+    Data(&'a str),
+    Url(&'a str),
 }
 
 impl PoolConfig {
     pub fn default_for_name(name: &str) -> PoolConfig {
         let mut txn = name.to_string();
         txn += ".txn";
-        PoolConfig { genesis_txn: txn }
+        PoolConfig {
+            genesis_txn: Some(txn),
+            genesis_txn_data: None,
+            genesis_url: None,
+            genesis_hash: None,
+            namespace: None,
+            snapshot: None,
+            snapshot_verkey: None,
+        }
+    }
+
+    pub fn genesis_source(&self) -> Option<GenesisSource> {
+        if let Some(ref genesis_txn) = self.genesis_txn {
+            Some(GenesisSource::File(genesis_txn))
+        } else if let Some(ref genesis_txn_data) = self.genesis_txn_data {
+            Some(GenesisSource::Data(genesis_txn_data))
+        } else if let Some(ref genesis_url) = self.genesis_url {
+            Some(GenesisSource::Url(genesis_url))
+        } else {
+            None
+        }
+    }
+}
+
+impl Validatable for PoolConfig {
+    fn validate(&self) -> Result<(), String> {
+        let sources = [&self.genesis_txn, &self.genesis_txn_data, &self.genesis_url]
+            .iter()
+            .filter(|source| source.is_some())
+            .count();
+
+        if sources > 1 {
+            return Err(String::from(
+                "Only one of `genesis_txn`, `genesis_txn_data` and `genesis_url` can be specified",
+            ));
+        }
+
+        if self.genesis_url.is_some() && self.genesis_hash.is_none() {
+            return Err(String::from(
+                "`genesis_hash` must be specified for genesis transactions fetched from `genesis_url`",
+            ));
+        }
+
+        if let Some(ref genesis_hash) = self.genesis_hash {
+            if genesis_hash.len() != 64 || !genesis_hash.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(format!(
+                    "Invalid `genesis_hash` {}: hex encoded SHA-256 digest expected",
+                    genesis_hash
+                ));
+            }
+        }
+
+        if let Some(ref snapshot) = self.snapshot {
+            if snapshot.txns.len() != snapshot.ledger_size {
+                return Err(format!(
+                    "Invalid `snapshot`: {} transactions expected, {} found",
+                    snapshot.ledger_size,
+                    snapshot.txns.len()
+                ));
+            }
+        }
+
+        if self.snapshot_verkey.is_some() && self.snapshot.is_none() {
+            return Err(String::from("`snapshot_verkey` can be specified only with `snapshot`"));
+        }
+
+        match self.namespace {
+            Some(ref namespace) if !NAMESPACE_REGEX.is_match(namespace) => Err(format!(
+                "Invalid `namespace` {}: did:indy namespace must match {}",
+                namespace,
+                NAMESPACE_REGEX.as_str()
+            )),
+            _ => Ok(()),
+        }
     }
 }
 
@@ -33,6 +133,24 @@ pub struct PoolOpenConfig {
     pub preordered_nodes: Vec<String>,
     #[serde(default = "PoolOpenConfig::default_number_read_nodes")]
     pub number_read_nodes: u8,
+    #[serde(default)]
+    pub read_nodes_selection: ReadNodesSelection,
+}
+
+/// How nodes for read requests are chosen.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadNodesSelection {
+    /// Nodes are shuffled (`preordered_nodes` go first).
+    Random,
+    /// Nodes are ranked by observed latency and success rate (`preordered_nodes` still go first).
+    Adaptive,
+}
+
+impl Default for ReadNodesSelection {
+    fn default() -> Self {
+        ReadNodesSelection::Random
+    }
 }
 
 impl Validatable for PoolOpenConfig {
@@ -65,6 +183,7 @@ impl Default for PoolOpenConfig {
             conn_active_timeout: PoolOpenConfig::default_conn_active_timeout(),
             preordered_nodes: PoolOpenConfig::default_preordered_nodes(),
             number_read_nodes: PoolOpenConfig::default_number_read_nodes(),
+            read_nodes_selection: ReadNodesSelection::default(),
         }
     }
 }
@@ -92,3 +211,132 @@ impl PoolOpenConfig {
 
     fn default_number_read_nodes() -> u8 { NUMBER_READ_NODES }
 }
+
+/// Configuration of the in-process simulated pool.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SimulatedPoolConfig {
+    #[serde(default = "SimulatedPoolConfig::default_nodes_count")]
+    pub nodes_count: usize,
+    #[serde(default)]
+    pub nyms: Vec<SimulatedNym>,
+}
+
+/// NYM written to the domain ledger of the simulated pool at its genesis.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SimulatedNym {
+    pub did: String,
+    pub verkey: String,
+    #[serde(default)]
+    pub role: Option<String>,
+}
+
+impl SimulatedPoolConfig {
+    pub const MAX_NODES_COUNT: usize = 25;
+
+    fn default_nodes_count() -> usize {
+        4
+    }
+}
+
+impl Default for SimulatedPoolConfig {
+    fn default() -> Self {
+        SimulatedPoolConfig {
+            nodes_count: SimulatedPoolConfig::default_nodes_count(),
+            nyms: Vec::new(),
+        }
+    }
+}
+
+impl Validatable for SimulatedPoolConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.nodes_count == 0 || self.nodes_count > SimulatedPoolConfig::MAX_NODES_COUNT {
+            return Err(format!(
+                "`nodes_count` must be between 1 and {}",
+                SimulatedPoolConfig::MAX_NODES_COUNT
+            ));
+        }
+
+        for nym in &self.nyms {
+            if nym.did.is_empty() || nym.verkey.is_empty() {
+                return Err(String::from("`did` and `verkey` must be specified for every nym"));
+            }
+
+            match nym.role.as_ref().map(String::as_str) {
+                None | Some("TRUSTEE") | Some("STEWARD") | Some("ENDORSER") | Some("TRUST_ANCHOR")
+                | Some("NETWORK_MONITOR") => {}
+                Some(role) => return Err(format!("Invalid role of nym {}: {}", nym.did, role)),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct NodeStatus {
+    pub alias: String,
+    pub address: String,
+    pub last_response_time: Option<i64>,
+    pub latency: Option<f64>,
+    pub failures: u64,
+    pub in_read_set: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PoolStatus {
+    pub ledger_size: usize,
+    pub merkle_root: String,
+    pub protocol_version: usize,
+    pub nodes: Vec<NodeStatus>,
+}
+
+/// Pool ledger transactions exported from the local copy of the pool ledger.
+/// Imported with the pool config to start catch-up from the snapshot instead of genesis.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PoolSnapshot {
+    pub ledger_size: usize,
+    pub root_hash: String,
+    pub txns: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<PoolSnapshotSignature>,
+}
+
+impl PoolSnapshot {
+    /// Data signed by the exporter. Transactions are covered by the root hash.
+    pub fn signed_data(&self) -> Vec<u8> {
+        format!("{}:{}", self.ledger_size, self.root_hash).into_bytes()
+    }
+}
+
+/// Base58 encoded signature of the snapshot and verkey of the key it was made with.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PoolSnapshotSignature {
+    pub verkey: String,
+    pub signature: String,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct PoolSnapshotExportConfig {
+    pub signer_verkey: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StateProofVerification {
+    pub root_hash: String,
+    pub participants: Vec<String>,
+    pub timestamp: Option<u64>,
+    pub multi_signature_valid: bool,
+    pub proof_valid: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplyVerification {
+    pub verified: bool,
+    pub state_proof_found: bool,
+    pub state_proofs: Vec<StateProofVerification>,
+    pub last_signed_time: Option<u64>,
+    pub nodes_count: usize,
+    pub f: usize,
+}
@@ -221,9 +221,16 @@ impl Locator {
                     wallet_service.clone(),
                     crypto_service.clone(),
                     ledger_service.clone(),
+                    cheqd_ledger_service.clone(),
+                    cheqd_keys_service.clone(),
+                    cheqd_pool_service.clone(),
                 );
 
-                let pool_controller = PoolController::new(pool_service.clone());
+                let pool_controller = PoolController::new(
+                    pool_service.clone(),
+                    wallet_service.clone(),
+                    crypto_service.clone(),
+                );
 
                 let cheqd_ledger_controller = CheqdLedgerController::new(cheqd_ledger_service.clone(), cheqd_pool_service.clone());
 
@@ -343,7 +350,11 @@ impl Locator {
                     ledger_service.clone(),
                 );
 
-                let pool_controller = PoolController::new(pool_service.clone());
+                let pool_controller = PoolController::new(
+                    pool_service.clone(),
+                    wallet_service.clone(),
+                    crypto_service.clone(),
+                );
 
                 let did_controller = DidController::new(
                     wallet_service.clone(),
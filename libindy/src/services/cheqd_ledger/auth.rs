@@ -17,7 +17,7 @@ impl CheqdLedgerService {
         &self,
         chain_id: &str,
         sender_public_key: &str,
-        msg: Msg,
+        msgs: Vec<Msg>,
         account_number: u64,
         sequence_number: u64,
         max_gas: u64,
@@ -25,15 +25,16 @@ impl CheqdLedgerService {
         max_coin_denom: &str,
         timeout_height: u64,
         memo: &str,
+        fee_granter: Option<&str>,
     ) -> IndyResult<SignDoc> {
         let timeout_height: Height = timeout_height.try_into()?;
 
-        let tx_body = tx::Body::new(vec![msg], memo, timeout_height);
+        let tx_body = tx::Body::new(msgs, memo, timeout_height);
 
         let signer_info = Self::build_signer_info(sender_public_key, sequence_number)?;
 
         let auth_info =
-            Self::build_auth_info(max_gas, max_coin_amount, max_coin_denom, signer_info)?;
+            Self::build_auth_info(max_gas, max_coin_amount, max_coin_denom, fee_granter, signer_info)?;
 
         let chain_id = chain_id.try_into()?;
 
@@ -46,6 +47,7 @@ impl CheqdLedgerService {
         max_gas: u64,
         max_coin: u64,
         max_coin_denom: &str,
+        fee_granter: Option<&str>,
         signer_info: SignerInfo,
     ) -> IndyResult<AuthInfo> {
         let amount = Coin {
@@ -53,7 +55,11 @@ impl CheqdLedgerService {
             amount: max_coin.into(),
         };
 
-        let auth_info = signer_info.auth_info(Fee::from_amount_and_gas(amount, max_gas));
+        let mut fee = Fee::from_amount_and_gas(amount, max_gas);
+        // Fee is deducted from the granter's account if it has granted an allowance to the signer
+        fee.granter = fee_granter.map(AccountId::from_str).transpose()?;
+
+        let auth_info = signer_info.auth_info(fee);
 
         Ok(auth_info)
     }
@@ -0,0 +1,141 @@
+use cosmrs::rpc::endpoint::broadcast::tx_commit::Response;
+use cosmrs::tx::Msg;
+use indy_api_types::errors::{IndyErrorKind, IndyResult, IndyError};
+use log_derive::logfn;
+use prost_types::Any as ProtoAny;
+
+use crate::domain::cheqd_ledger::{CheqdProto, CheqdProtoMsg};
+use crate::domain::cheqd_ledger::authz::{
+    GenericAuthorization, Grant, MsgExec, MsgExecResponse, MsgGrant, MsgGrantResponse, MsgRevoke,
+    MsgRevokeResponse,
+};
+use crate::domain::cheqd_ledger::base::{Any, Timestamp};
+use crate::services::CheqdLedgerService;
+
+impl CheqdLedgerService {
+    /// Allows `grantee` to execute messages of `msg_type_url` type on behalf of `granter`
+    /// until `expiration` (unix timestamp in seconds).
+    #[logfn(Info)]
+    pub(crate) fn authz_build_msg_grant(
+        &self,
+        granter: &str,
+        grantee: &str,
+        msg_type_url: &str,
+        expiration: i64,
+    ) -> IndyResult<Msg> {
+        let grant = Grant::new(
+            Some(GenericAuthorization::new(msg_type_url.to_string())),
+            Some(Timestamp::new(expiration, 0)),
+        );
+
+        let msg_grant = MsgGrant::new(
+            granter.to_string(),
+            grantee.to_string(),
+            Some(grant),
+        );
+
+        Ok(msg_grant.to_msg()?)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn authz_parse_msg_grant_resp(
+        &self,
+        resp: &Response,
+    ) -> IndyResult<MsgGrantResponse> {
+        self.parse_msg_resp(resp)
+    }
+
+    /// Wraps messages signed on behalf of the granters so that `grantee` can submit them.
+    #[logfn(Info)]
+    pub(crate) fn authz_build_msg_exec(
+        &self,
+        grantee: &str,
+        msgs: Vec<Msg>,
+    ) -> IndyResult<Msg> {
+        if msgs.is_empty() {
+            return Err(IndyError::from_msg(
+                IndyErrorKind::InvalidStructure,
+                "MsgExec must contain at least one message",
+            ));
+        }
+
+        let msgs = msgs
+            .into_iter()
+            .map(|msg| {
+                let proto: ProtoAny = msg.into();
+                Any::from_proto(&proto)
+            })
+            .collect::<IndyResult<Vec<Any>>>()?;
+
+        let msg_exec = MsgExec::new(
+            grantee.to_string(),
+            msgs,
+        );
+
+        Ok(msg_exec.to_msg()?)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn authz_parse_msg_exec_resp(
+        &self,
+        resp: &Response,
+    ) -> IndyResult<MsgExecResponse> {
+        self.parse_msg_resp(resp)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn authz_build_msg_revoke(
+        &self,
+        granter: &str,
+        grantee: &str,
+        msg_type_url: &str,
+    ) -> IndyResult<Msg> {
+        let msg_revoke = MsgRevoke::new(
+            granter.to_string(),
+            grantee.to_string(),
+            msg_type_url.to_string(),
+        );
+
+        Ok(msg_revoke.to_msg()?)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn authz_parse_msg_revoke_resp(
+        &self,
+        resp: &Response,
+    ) -> IndyResult<MsgRevokeResponse> {
+        self.parse_msg_resp(resp)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const GRANTER: &str = "cosmos1fknpjldck6n3v2wu86arpz8xjnfc60f99ylcjd";
+    const GRANTEE: &str = "cosmos1ma0t2kx2m7v4rdcgeaye4jgyv0p9q8fpd8gvyc";
+
+    #[test]
+    fn authz_build_msg_exec_wraps_messages() {
+        let service = CheqdLedgerService::new();
+
+        let inner = service.authz_build_msg_revoke(GRANTER, GRANTEE, "/cosmos.bank.v1beta1.MsgSend").unwrap();
+        let msg = service.authz_build_msg_exec(GRANTEE, vec![inner]).unwrap();
+
+        let proto: ProtoAny = msg.into();
+        assert_eq!(MsgExec::TYPE_URL, proto.type_url);
+
+        let msg_exec = MsgExec::from_proto_bytes(&proto.value).unwrap();
+        assert_eq!(GRANTEE, msg_exec.grantee);
+        assert_eq!(1, msg_exec.msgs.len());
+        assert_eq!(MsgRevoke::TYPE_URL, msg_exec.msgs[0].type_url);
+    }
+
+    #[test]
+    fn authz_build_msg_exec_fails_for_no_messages() {
+        let service = CheqdLedgerService::new();
+
+        let err = service.authz_build_msg_exec(GRANTEE, vec![]).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+}
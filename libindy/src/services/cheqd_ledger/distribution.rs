@@ -0,0 +1,60 @@
+use std::str::FromStr;
+
+use cosmrs::rpc::endpoint::abci_query;
+use cosmrs::rpc::endpoint::broadcast::tx_commit::Response;
+use cosmrs::tx::Msg;
+use indy_api_types::errors::IndyResult;
+use log_derive::logfn;
+
+use crate::domain::cheqd_ledger::{CheqdProto, CheqdProtoMsg};
+use crate::domain::cheqd_ledger::distribution::{
+    MsgWithdrawDelegatorReward, MsgWithdrawDelegatorRewardResponse,
+    QueryDelegationTotalRewardsRequest, QueryDelegationTotalRewardsResponse,
+};
+use crate::services::CheqdLedgerService;
+
+impl CheqdLedgerService {
+    #[logfn(Info)]
+    pub(crate) fn distribution_build_msg_withdraw_delegator_reward(
+        &self,
+        delegator_address: &str,
+        validator_address: &str,
+    ) -> IndyResult<Msg> {
+        let msg = MsgWithdrawDelegatorReward::new(
+            delegator_address.to_string(),
+            validator_address.to_string(),
+        );
+
+        Ok(msg.to_msg()?)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn distribution_parse_msg_withdraw_delegator_reward_resp(
+        &self,
+        resp: &Response,
+    ) -> IndyResult<MsgWithdrawDelegatorRewardResponse> {
+        self.parse_msg_resp(resp)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn distribution_build_query_delegation_total_rewards(
+        &self,
+        delegator_address: String,
+    ) -> IndyResult<abci_query::Request> {
+        let query_data = QueryDelegationTotalRewardsRequest::new(delegator_address);
+        let path = format!("/cosmos.distribution.v1beta1.Query/DelegationTotalRewards");
+        let path = cosmrs::tendermint::abci::Path::from_str(&path)?;
+        let req =
+            abci_query::Request::new(Some(path), query_data.to_proto_bytes()?, None, true);
+        Ok(req)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn distribution_parse_query_delegation_total_rewards_resp(
+        &self,
+        resp: &abci_query::Response,
+    ) -> IndyResult<QueryDelegationTotalRewardsResponse> {
+        let result = QueryDelegationTotalRewardsResponse::from_proto_bytes(&resp.response.value)?;
+        return Ok(result);
+    }
+}
@@ -0,0 +1,68 @@
+use cosmrs::rpc::endpoint::broadcast::tx_commit::Response;
+use cosmrs::tx::Msg;
+use indy_api_types::errors::IndyResult;
+use log_derive::logfn;
+
+use crate::domain::cheqd_ledger::CheqdProtoMsg;
+use crate::domain::cheqd_ledger::bank::Coin;
+use crate::domain::cheqd_ledger::base::Timestamp;
+use crate::domain::cheqd_ledger::feegrant::{
+    BasicAllowance, MsgGrantAllowance, MsgGrantAllowanceResponse, MsgRevokeAllowance,
+    MsgRevokeAllowanceResponse,
+};
+use crate::services::CheqdLedgerService;
+
+impl CheqdLedgerService {
+    /// Without `spend_limit` the grantee can spend any amount of the granter's coins on fees.
+    #[logfn(Info)]
+    pub(crate) fn feegrant_build_msg_grant_allowance(
+        &self,
+        granter: &str,
+        grantee: &str,
+        spend_limit: Option<(&str, &str)>,
+        expiration: Option<i64>,
+    ) -> IndyResult<Msg> {
+        let spend_limit = spend_limit
+            .map(|(amount, denom)| vec![Coin::new(denom.to_string(), amount.to_string())])
+            .unwrap_or_default();
+        let expiration = expiration.map(|seconds| Timestamp::new(seconds, 0));
+
+        let msg_grant_allowance = MsgGrantAllowance::new(
+            granter.to_string(),
+            grantee.to_string(),
+            Some(BasicAllowance::new(spend_limit, expiration)),
+        );
+
+        Ok(msg_grant_allowance.to_msg()?)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn feegrant_parse_msg_grant_allowance_resp(
+        &self,
+        resp: &Response,
+    ) -> IndyResult<MsgGrantAllowanceResponse> {
+        self.parse_msg_resp(resp)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn feegrant_build_msg_revoke_allowance(
+        &self,
+        granter: &str,
+        grantee: &str,
+    ) -> IndyResult<Msg> {
+        let msg_revoke_allowance = MsgRevokeAllowance::new(
+            granter.to_string(),
+            grantee.to_string(),
+        );
+
+        Ok(msg_revoke_allowance.to_msg()?)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn feegrant_parse_msg_revoke_allowance_resp(
+        &self,
+        resp: &Response,
+    ) -> IndyResult<MsgRevokeAllowanceResponse> {
+        self.parse_msg_resp(resp)
+    }
+}
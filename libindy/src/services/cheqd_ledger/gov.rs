@@ -0,0 +1,356 @@
+use std::str::FromStr;
+
+use cosmrs::AccountId;
+use cosmrs::proto::cosmos::gov::v1beta1::{ProposalStatus, VoteOption};
+use cosmrs::rpc::endpoint::abci_query;
+use cosmrs::rpc::endpoint::broadcast::tx_commit::Response;
+use cosmrs::tx::Msg;
+use indy_api_types::errors::{IndyErrorKind, IndyResult, IndyError};
+use log_derive::logfn;
+
+use crate::domain::cheqd_ledger::{CheqdProto, CheqdProtoMsg};
+use crate::domain::cheqd_ledger::bank::Coin;
+use crate::domain::cheqd_ledger::base::query::PageRequest;
+use crate::domain::cheqd_ledger::gov::{
+    MsgDeposit, MsgDepositResponse, MsgSubmitProposal, MsgSubmitProposalResponse, MsgVote,
+    MsgVoteResponse, MsgVoteWeighted, MsgVoteWeightedResponse, ParamChange,
+    ParameterChangeProposal, Proposal, ProposalContent, QueryProposalResponse,
+    QueryProposalsRequest, QueryProposalsResponse, QueryTallyResultRequest,
+    QueryTallyResultResponse, QueryVoteResponse, TextProposal, Vote, WeightedVote,
+    WeightedVoteOption,
+};
+use crate::services::CheqdLedgerService;
+use crate::utils::cheqd_crypto::check_proofs;
+
+// Prefixes of the gov module store keys
+const GOV_PROPOSALS_PREFIX: u8 = 0x00;
+const GOV_VOTES_PREFIX: u8 = 0x20;
+
+impl CheqdLedgerService {
+    #[logfn(Info)]
+    pub(crate) fn gov_build_msg_submit_text_proposal(
+        &self,
+        title: &str,
+        description: &str,
+        proposer: &str,
+        deposit_amount: &str,
+        deposit_denom: &str,
+    ) -> IndyResult<Msg> {
+        let content = ProposalContent::Text(TextProposal::new(
+            title.to_string(),
+            description.to_string(),
+        ));
+
+        self.gov_build_msg_submit_proposal(content, proposer, deposit_amount, deposit_denom)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn gov_build_msg_submit_param_change_proposal(
+        &self,
+        title: &str,
+        description: &str,
+        changes: Vec<ParamChange>,
+        proposer: &str,
+        deposit_amount: &str,
+        deposit_denom: &str,
+    ) -> IndyResult<Msg> {
+        if changes.is_empty() {
+            return Err(IndyError::from_msg(
+                IndyErrorKind::InvalidStructure,
+                "Parameter change proposal must contain at least one change",
+            ));
+        }
+
+        let content = ProposalContent::ParameterChange(ParameterChangeProposal::new(
+            title.to_string(),
+            description.to_string(),
+            changes,
+        ));
+
+        self.gov_build_msg_submit_proposal(content, proposer, deposit_amount, deposit_denom)
+    }
+
+    fn gov_build_msg_submit_proposal(
+        &self,
+        content: ProposalContent,
+        proposer: &str,
+        deposit_amount: &str,
+        deposit_denom: &str,
+    ) -> IndyResult<Msg> {
+        let msg_submit_proposal = MsgSubmitProposal::new(
+            Some(content),
+            vec![Coin::new(deposit_denom.to_string(), deposit_amount.to_string())],
+            proposer.to_string(),
+        );
+
+        Ok(msg_submit_proposal.to_msg()?)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn gov_parse_msg_submit_proposal_resp(
+        &self,
+        resp: &Response,
+    ) -> IndyResult<MsgSubmitProposalResponse> {
+        self.parse_msg_resp(resp)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn gov_build_msg_deposit(
+        &self,
+        proposal_id: u64,
+        depositor: &str,
+        amount: &str,
+        denom: &str,
+    ) -> IndyResult<Msg> {
+        let msg_deposit = MsgDeposit::new(
+            proposal_id,
+            depositor.to_string(),
+            vec![Coin::new(denom.to_string(), amount.to_string())],
+        );
+
+        Ok(msg_deposit.to_msg()?)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn gov_parse_msg_deposit_resp(
+        &self,
+        resp: &Response,
+    ) -> IndyResult<MsgDepositResponse> {
+        self.parse_msg_resp(resp)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn gov_build_msg_vote(
+        &self,
+        proposal_id: u64,
+        voter: &str,
+        option: &str,
+    ) -> IndyResult<Msg> {
+        let msg_vote = MsgVote::new(
+            proposal_id,
+            voter.to_string(),
+            parse_vote_option(option)?,
+        );
+
+        Ok(msg_vote.to_msg()?)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn gov_parse_msg_vote_resp(
+        &self,
+        resp: &Response,
+    ) -> IndyResult<MsgVoteResponse> {
+        self.parse_msg_resp(resp)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn gov_build_msg_vote_weighted(
+        &self,
+        proposal_id: u64,
+        voter: &str,
+        options: Vec<WeightedVote>,
+    ) -> IndyResult<Msg> {
+        if options.is_empty() {
+            return Err(IndyError::from_msg(
+                IndyErrorKind::InvalidStructure,
+                "Weighted vote must contain at least one option",
+            ));
+        }
+
+        let options = options
+            .into_iter()
+            .map(|vote| Ok(WeightedVoteOption::new(parse_vote_option(&vote.option)?, vote.weight)))
+            .collect::<IndyResult<Vec<WeightedVoteOption>>>()?;
+
+        let msg_vote_weighted = MsgVoteWeighted::new(
+            proposal_id,
+            voter.to_string(),
+            options,
+        );
+
+        Ok(msg_vote_weighted.to_msg()?)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn gov_parse_msg_vote_weighted_resp(
+        &self,
+        resp: &Response,
+    ) -> IndyResult<MsgVoteWeightedResponse> {
+        self.parse_msg_resp(resp)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn gov_build_query_proposal(
+        &self,
+        proposal_id: u64,
+    ) -> IndyResult<abci_query::Request> {
+        // Store key of the proposal: 0x00 | proposal id
+        let mut query_data = vec!(GOV_PROPOSALS_PREFIX);
+        query_data.extend_from_slice(&proposal_id.to_be_bytes());
+
+        let path = cosmrs::tendermint::abci::Path::from_str("/store/gov/key")?;
+        let req = abci_query::Request::new(Some(path), query_data, None, true);
+        Ok(req)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn gov_parse_query_proposal_resp(
+        &self,
+        resp: &abci_query::Response,
+    ) -> IndyResult<QueryProposalResponse> {
+        let result = if !resp.response.value.is_empty() {
+            Some(Proposal::from_proto_bytes(&resp.response.value)?)
+        } else { None };
+        check_proofs(resp.clone())?;
+        Ok(QueryProposalResponse::new(result))
+    }
+
+    /// Lists proposals through the gRPC query. The result is not covered by a state proof,
+    /// use `gov_build_query_proposal` to get a verified proposal.
+    #[logfn(Info)]
+    pub(crate) fn gov_build_query_proposals(
+        &self,
+        status: Option<&str>,
+        pagination: Option<PageRequest>,
+    ) -> IndyResult<abci_query::Request> {
+        let status = status.map(parse_proposal_status).transpose()?.unwrap_or_default();
+        let query_data = QueryProposalsRequest::new(status, String::new(), String::new(), pagination);
+        let path = format!("/cosmos.gov.v1beta1.Query/Proposals");
+        let path = cosmrs::tendermint::abci::Path::from_str(&path)?;
+        let req =
+            abci_query::Request::new(Some(path), query_data.to_proto_bytes()?, None, true);
+        Ok(req)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn gov_parse_query_proposals_resp(
+        &self,
+        resp: &abci_query::Response,
+    ) -> IndyResult<QueryProposalsResponse> {
+        let result = QueryProposalsResponse::from_proto_bytes(&resp.response.value)?;
+        return Ok(result);
+    }
+
+    /// Tally of a proposal in the voting period is computed by the node on request
+    /// and isn't stored in the state, so it can't be checked by a state proof.
+    /// Final tally of a finished proposal is a part of the proven proposal itself.
+    #[logfn(Info)]
+    pub(crate) fn gov_build_query_tally_result(
+        &self,
+        proposal_id: u64,
+    ) -> IndyResult<abci_query::Request> {
+        let query_data = QueryTallyResultRequest::new(proposal_id);
+        let path = format!("/cosmos.gov.v1beta1.Query/TallyResult");
+        let path = cosmrs::tendermint::abci::Path::from_str(&path)?;
+        let req =
+            abci_query::Request::new(Some(path), query_data.to_proto_bytes()?, None, true);
+        Ok(req)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn gov_parse_query_tally_result_resp(
+        &self,
+        resp: &abci_query::Response,
+    ) -> IndyResult<QueryTallyResultResponse> {
+        let result = QueryTallyResultResponse::from_proto_bytes(&resp.response.value)?;
+        return Ok(result);
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn gov_build_query_vote(
+        &self,
+        proposal_id: u64,
+        voter: &str,
+    ) -> IndyResult<abci_query::Request> {
+        let voter = AccountId::from_str(voter)?.to_bytes();
+
+        // Store key of the vote: 0x20 | proposal id | length prefixed voter address
+        let mut query_data = vec!(GOV_VOTES_PREFIX);
+        query_data.extend_from_slice(&proposal_id.to_be_bytes());
+        query_data.push(voter.len() as u8);
+        query_data.extend_from_slice(&voter);
+
+        let path = cosmrs::tendermint::abci::Path::from_str("/store/gov/key")?;
+        let req = abci_query::Request::new(Some(path), query_data, None, true);
+        Ok(req)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn gov_parse_query_vote_resp(
+        &self,
+        resp: &abci_query::Response,
+    ) -> IndyResult<QueryVoteResponse> {
+        let result = if !resp.response.value.is_empty() {
+            Some(Vote::from_proto_bytes(&resp.response.value)?)
+        } else { None };
+        check_proofs(resp.clone())?;
+        Ok(QueryVoteResponse::new(result))
+    }
+}
+
+/// Accepts both short (`yes`, `no_with_veto`) and proto (`VOTE_OPTION_YES`) names of the option.
+fn parse_vote_option(option: &str) -> IndyResult<i32> {
+    let option = match option.to_lowercase().trim_start_matches("vote_option_") {
+        "yes" => VoteOption::Yes,
+        "abstain" => VoteOption::Abstain,
+        "no" => VoteOption::No,
+        "no_with_veto" => VoteOption::NoWithVeto,
+        _ => return Err(IndyError::from_msg(
+            IndyErrorKind::InvalidStructure,
+            format!("Unknown vote option: {}", option),
+        ))
+    };
+
+    Ok(option as i32)
+}
+
+/// Accepts both short (`voting_period`) and proto (`PROPOSAL_STATUS_VOTING_PERIOD`) names of the status.
+fn parse_proposal_status(status: &str) -> IndyResult<i32> {
+    let status = match status.to_lowercase().trim_start_matches("proposal_status_") {
+        "deposit_period" => ProposalStatus::DepositPeriod,
+        "voting_period" => ProposalStatus::VotingPeriod,
+        "passed" => ProposalStatus::Passed,
+        "rejected" => ProposalStatus::Rejected,
+        "failed" => ProposalStatus::Failed,
+        _ => return Err(IndyError::from_msg(
+            IndyErrorKind::InvalidStructure,
+            format!("Unknown proposal status: {}", status),
+        ))
+    };
+
+    Ok(status as i32)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const VOTER: &str = "cosmos1fknpjldck6n3v2wu86arpz8xjnfc60f99ylcjd";
+
+    #[test]
+    fn parse_vote_option_works() {
+        assert_eq!(VoteOption::Yes as i32, parse_vote_option("yes").unwrap());
+        assert_eq!(VoteOption::NoWithVeto as i32, parse_vote_option("VOTE_OPTION_NO_WITH_VETO").unwrap());
+        assert_eq!(IndyErrorKind::InvalidStructure, parse_vote_option("maybe").unwrap_err().kind());
+    }
+
+    #[test]
+    fn gov_build_query_vote_works() {
+        let service = CheqdLedgerService::new();
+
+        let req = service.gov_build_query_vote(1, VOTER).unwrap();
+
+        assert_eq!(GOV_VOTES_PREFIX, req.data[0]);
+        assert_eq!(1u64.to_be_bytes(), req.data[1..9]);
+        assert_eq!(20, req.data[9]);
+        assert_eq!(30, req.data.len());
+    }
+
+    #[test]
+    fn gov_build_msg_vote_weighted_fails_for_empty_options() {
+        let service = CheqdLedgerService::new();
+
+        let err = service.gov_build_msg_vote_weighted(1, VOTER, vec![]).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+}
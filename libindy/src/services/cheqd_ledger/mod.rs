@@ -12,6 +12,14 @@ use crate::domain::cheqd_ledger::CheqdProto;
 mod auth;
 mod cheqd;
 mod bank;
+mod payments;
+mod staking;
+mod distribution;
+mod gov;
+mod feegrant;
+mod authz;
+
+pub(crate) use payments::CHEQD_PAYMENT_METHOD;
 
 pub(crate) struct CheqdLedgerService {}
 
@@ -0,0 +1,299 @@
+use std::convert::TryInto;
+use std::str::FromStr;
+
+use cosmrs::AccountId;
+use cosmrs::rpc::endpoint::abci_query;
+use cosmrs::rpc::endpoint::broadcast::tx_commit::Response;
+use cosmrs::tx::{Msg, MsgType};
+use indy_api_types::errors::{IndyErrorKind, IndyResult, IndyResultExt, IndyError};
+use log_derive::logfn;
+
+use crate::domain::cheqd_ledger::CheqdProto;
+use crate::domain::cheqd_ledger::bank::{Coin, MsgSend};
+use crate::domain::cheqd_ledger::payments::{
+    CheqdPaymentOutput, CheqdPaymentReceipt, CheqdPaymentSource,
+};
+use crate::services::CheqdLedgerService;
+use crate::utils::cheqd_crypto::check_proofs;
+use crate::utils::environment;
+
+pub(crate) const CHEQD_PAYMENT_METHOD: &str = "cheqd";
+const CHEQD_ACCOUNT_PREFIX: &str = "cosmos";
+const BANK_BALANCES_PREFIX: u8 = 0x02;
+const ACCOUNT_ADDRESS_LEN: usize = 20;
+
+impl CheqdLedgerService {
+    pub(crate) fn payment_address_from_account_id(&self, account_id: &str) -> String {
+        format!("pay:{}:{}", CHEQD_PAYMENT_METHOD, account_id)
+    }
+
+    pub(crate) fn payment_source_from_account_id(&self, account_id: &str) -> String {
+        format!("src:{}:{}", CHEQD_PAYMENT_METHOD, account_id)
+    }
+
+    /// Extracts the cosmos account id from `pay:cheqd:<account_id>` or `src:cheqd:<account_id>`.
+    pub(crate) fn payment_account_id(&self, address: &str) -> IndyResult<String> {
+        let parts: Vec<&str> = address.split(':').collect();
+
+        match parts.as_slice() {
+            [prefix, method, account_id]
+            if (*prefix == "pay" || *prefix == "src") && *method == CHEQD_PAYMENT_METHOD => {
+                AccountId::from_str(account_id)?;
+                Ok(account_id.to_string())
+            }
+            _ => Err(IndyError::from_msg(
+                IndyErrorKind::IncompatiblePaymentMethods,
+                format!("{:?} is not a cheqd payment address", address),
+            ))
+        }
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn payment_build_get_sources_request(
+        &self,
+        payment_address: &str,
+        denom: Option<&str>,
+    ) -> IndyResult<abci_query::Request> {
+        let account_id = self.payment_account_id(payment_address)?;
+        let account_id = AccountId::from_str(&account_id)?;
+        let denom = denom.map(String::from).unwrap_or_else(environment::cheqd_denom);
+
+        let address = account_id.to_bytes();
+
+        // Store key of the account balance: 0x02 | length prefixed address | denom
+        let mut query_data = vec!(BANK_BALANCES_PREFIX);
+        query_data.push(address.len() as u8);
+        query_data.extend_from_slice(&address);
+        query_data.extend_from_slice(denom.as_bytes());
+
+        let path = cosmrs::tendermint::abci::Path::from_str("/store/bank/key")?;
+        let req = abci_query::Request::new(Some(path), query_data, None, true);
+        Ok(req)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn payment_parse_get_sources_response(
+        &self,
+        resp: &abci_query::Response,
+    ) -> IndyResult<Vec<CheqdPaymentSource>> {
+        let (account_id, _) = parse_balance_key(&resp.response.key)?;
+
+        // An empty balance must be proven by the non-existence proof of the balance key
+        check_proofs(resp.clone())?;
+
+        if resp.response.value.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let coin = Coin::from_proto_bytes(&resp.response.value)?;
+        let amount = coin.amount.parse::<u64>()
+            .to_indy(IndyErrorKind::InvalidStructure, "Balance amount is not a valid number")?;
+
+        let source = CheqdPaymentSource {
+            source: self.payment_source_from_account_id(&account_id),
+            payment_address: self.payment_address_from_account_id(&account_id),
+            amount,
+            extra: coin.denom,
+        };
+
+        Ok(vec![source])
+    }
+
+    /// Builds one MsgSend for every output except the change output that points back to the sender.
+    /// Returns the sender account id together with the messages.
+    #[logfn(Info)]
+    pub(crate) fn payment_build_transfer_msgs(
+        &self,
+        inputs: &[String],
+        outputs: &[CheqdPaymentOutput],
+        denom: &str,
+    ) -> IndyResult<(String, Vec<Msg>)> {
+        let sender = match inputs {
+            [input] => self.payment_account_id(input)?,
+            _ => return Err(IndyError::from_msg(
+                IndyErrorKind::InvalidStructure,
+                "Cheqd payment method is account based and expects exactly one input",
+            ))
+        };
+
+        let mut msgs = Vec::new();
+
+        for output in outputs {
+            let recipient = self.payment_account_id(&output.recipient)?;
+
+            if recipient == sender {
+                continue;
+            }
+
+            let msg_send = MsgSend::new(
+                sender.clone(),
+                recipient,
+                vec![Coin::new(denom.to_string(), output.amount.to_string())],
+            );
+            msgs.push(msg_send.to_proto().to_msg()?);
+        }
+
+        if msgs.is_empty() {
+            return Err(IndyError::from_msg(
+                IndyErrorKind::InvalidStructure,
+                "Cheqd payment request doesn't transfer anything to another address",
+            ));
+        }
+
+        Ok((sender, msgs))
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn payment_parse_payment_response(
+        &self,
+        resp: &Response,
+    ) -> IndyResult<Vec<CheqdPaymentReceipt>> {
+        let _ = self.bank_parse_msg_send_resp(resp)?;
+
+        let receipt = CheqdPaymentReceipt {
+            receipt: format!("rcpt:{}:{}", CHEQD_PAYMENT_METHOD, resp.hash),
+            height: resp.height.value(),
+        };
+
+        Ok(vec![receipt])
+    }
+}
+
+/// Splits a bank balance store key `0x02 | length prefixed address | denom` into account id and denom.
+fn parse_balance_key(key: &[u8]) -> IndyResult<(String, String)> {
+    if key.len() < 2 || key[0] != BANK_BALANCES_PREFIX || key.len() <= 2 + key[1] as usize {
+        return Err(IndyError::from_msg(
+            IndyErrorKind::InvalidStructure,
+            "Response doesn't contain a key of the bank balance store",
+        ));
+    }
+
+    let address_end = 2 + key[1] as usize;
+    let address_bytes: [u8; ACCOUNT_ADDRESS_LEN] = key[2..address_end]
+        .try_into()
+        .to_indy(IndyErrorKind::InvalidStructure, "Invalid account address in the response key")?;
+    let account_id = AccountId::new(CHEQD_ACCOUNT_PREFIX, address_bytes)?.to_string();
+
+    let denom = String::from_utf8(key[address_end..].to_vec())
+        .to_indy(IndyErrorKind::InvalidStructure, "Invalid denom in the response key")?;
+
+    Ok((account_id, denom))
+}
+
+#[cfg(test)]
+mod test {
+    use indy_utils::crypto::base64;
+
+    use super::*;
+
+    const ACCOUNT_ID: &str = "cosmos1fknpjldck6n3v2wu86arpz8xjnfc60f99ylcjd";
+
+    #[test]
+    fn payment_account_id_works() {
+        let service = CheqdLedgerService::new();
+
+        let address = service.payment_address_from_account_id(ACCOUNT_ID);
+        assert_eq!(format!("pay:cheqd:{}", ACCOUNT_ID), address);
+        assert_eq!(ACCOUNT_ID, service.payment_account_id(&address).unwrap());
+
+        let source = service.payment_source_from_account_id(ACCOUNT_ID);
+        assert_eq!(ACCOUNT_ID, service.payment_account_id(&source).unwrap());
+    }
+
+    #[test]
+    fn payment_account_id_fails_for_other_method() {
+        let service = CheqdLedgerService::new();
+
+        let err = service.payment_account_id("pay:null:zR3GN9lfbCVtHjp").unwrap_err();
+        assert_eq!(IndyErrorKind::IncompatiblePaymentMethods, err.kind());
+    }
+
+    #[test]
+    fn payment_build_get_sources_request_works() {
+        let service = CheqdLedgerService::new();
+        let address = service.payment_address_from_account_id(ACCOUNT_ID);
+
+        let req = service.payment_build_get_sources_request(&address, Some("ncheq")).unwrap();
+
+        assert_eq!(BANK_BALANCES_PREFIX, req.data[0]);
+        assert_eq!(ACCOUNT_ADDRESS_LEN as u8, req.data[1]);
+        assert_eq!(b"ncheq", &req.data[2 + ACCOUNT_ADDRESS_LEN..]);
+        assert_eq!((ACCOUNT_ID.to_string(), "ncheq".to_string()), parse_balance_key(&req.data).unwrap());
+    }
+
+    #[test]
+    fn parse_balance_key_fails_for_wrong_length_prefix() {
+        let service = CheqdLedgerService::new();
+        let address = service.payment_address_from_account_id(ACCOUNT_ID);
+
+        let mut key = service.payment_build_get_sources_request(&address, Some("ncheq")).unwrap().data;
+        key[1] = 19;
+
+        let err = parse_balance_key(&key).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+
+        let err = parse_balance_key(&key[..2 + ACCOUNT_ADDRESS_LEN]).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+
+    #[test]
+    fn payment_parse_get_sources_response_fails_for_empty_balance_without_proof() {
+        let service = CheqdLedgerService::new();
+        let address = service.payment_address_from_account_id(ACCOUNT_ID);
+
+        let key = service.payment_build_get_sources_request(&address, Some("ncheq")).unwrap().data;
+        let resp = json!({
+            "response": {
+                "code": 0, "log": "", "info": "", "index": "0",
+                "key": base64::encode(&key),
+                "value": "",
+                "proof": null,
+                "height": "5",
+                "codespace": ""
+            }
+        });
+        let resp: abci_query::Response = serde_json::from_value(resp).unwrap();
+
+        let err = service.payment_parse_get_sources_response(&resp).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+
+    #[test]
+    fn payment_build_transfer_msgs_skips_change_output() {
+        let service = CheqdLedgerService::new();
+
+        let inputs = vec![service.payment_source_from_account_id(ACCOUNT_ID)];
+        let outputs = vec![
+            CheqdPaymentOutput {
+                recipient: service.payment_address_from_account_id("cosmos1ma0t2kx2m7v4rdcgeaye4jgyv0p9q8fpd8gvyc"),
+                amount: 10,
+            },
+            CheqdPaymentOutput {
+                recipient: service.payment_address_from_account_id(ACCOUNT_ID),
+                amount: 90,
+            },
+        ];
+
+        let (sender, msgs) = service.payment_build_transfer_msgs(&inputs, &outputs, "ncheq").unwrap();
+
+        assert_eq!(ACCOUNT_ID, sender);
+        assert_eq!(1, msgs.len());
+    }
+
+    #[test]
+    fn payment_build_transfer_msgs_fails_for_several_inputs() {
+        let service = CheqdLedgerService::new();
+
+        let inputs = vec![
+            service.payment_source_from_account_id(ACCOUNT_ID),
+            service.payment_source_from_account_id("cosmos1ma0t2kx2m7v4rdcgeaye4jgyv0p9q8fpd8gvyc"),
+        ];
+        let outputs = vec![CheqdPaymentOutput {
+            recipient: service.payment_address_from_account_id("cosmos1ma0t2kx2m7v4rdcgeaye4jgyv0p9q8fpd8gvyc"),
+            amount: 10,
+        }];
+
+        let err = service.payment_build_transfer_msgs(&inputs, &outputs, "ncheq").unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+}
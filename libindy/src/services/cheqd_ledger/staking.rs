@@ -0,0 +1,166 @@
+use std::str::FromStr;
+
+use cosmrs::rpc::endpoint::abci_query;
+use cosmrs::rpc::endpoint::broadcast::tx_commit::Response;
+use cosmrs::tx::Msg;
+use indy_api_types::errors::IndyResult;
+use log_derive::logfn;
+
+use crate::domain::cheqd_ledger::{CheqdProto, CheqdProtoMsg};
+use crate::domain::cheqd_ledger::bank::Coin;
+use crate::domain::cheqd_ledger::base::query::PageRequest;
+use crate::domain::cheqd_ledger::staking::{
+    MsgBeginRedelegate, MsgBeginRedelegateResponse, MsgDelegate, MsgDelegateResponse,
+    MsgUndelegate, MsgUndelegateResponse, QueryDelegationRequest, QueryDelegationResponse,
+    QueryDelegatorDelegationsRequest, QueryDelegatorDelegationsResponse, QueryValidatorsRequest,
+    QueryValidatorsResponse,
+};
+use crate::services::CheqdLedgerService;
+
+impl CheqdLedgerService {
+    #[logfn(Info)]
+    pub(crate) fn staking_build_msg_delegate(
+        &self,
+        delegator_address: &str,
+        validator_address: &str,
+        amount: &str,
+        denom: &str,
+    ) -> IndyResult<Msg> {
+        let msg_delegate = MsgDelegate::new(
+            delegator_address.to_string(),
+            validator_address.to_string(),
+            Some(Coin::new(denom.to_string(), amount.to_string())),
+        );
+
+        Ok(msg_delegate.to_msg()?)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn staking_parse_msg_delegate_resp(
+        &self,
+        resp: &Response,
+    ) -> IndyResult<MsgDelegateResponse> {
+        self.parse_msg_resp(resp)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn staking_build_msg_undelegate(
+        &self,
+        delegator_address: &str,
+        validator_address: &str,
+        amount: &str,
+        denom: &str,
+    ) -> IndyResult<Msg> {
+        let msg_undelegate = MsgUndelegate::new(
+            delegator_address.to_string(),
+            validator_address.to_string(),
+            Some(Coin::new(denom.to_string(), amount.to_string())),
+        );
+
+        Ok(msg_undelegate.to_msg()?)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn staking_parse_msg_undelegate_resp(
+        &self,
+        resp: &Response,
+    ) -> IndyResult<MsgUndelegateResponse> {
+        self.parse_msg_resp(resp)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn staking_build_msg_begin_redelegate(
+        &self,
+        delegator_address: &str,
+        validator_src_address: &str,
+        validator_dst_address: &str,
+        amount: &str,
+        denom: &str,
+    ) -> IndyResult<Msg> {
+        let msg_begin_redelegate = MsgBeginRedelegate::new(
+            delegator_address.to_string(),
+            validator_src_address.to_string(),
+            validator_dst_address.to_string(),
+            Some(Coin::new(denom.to_string(), amount.to_string())),
+        );
+
+        Ok(msg_begin_redelegate.to_msg()?)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn staking_parse_msg_begin_redelegate_resp(
+        &self,
+        resp: &Response,
+    ) -> IndyResult<MsgBeginRedelegateResponse> {
+        self.parse_msg_resp(resp)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn staking_build_query_delegation(
+        &self,
+        delegator_address: String,
+        validator_address: String,
+    ) -> IndyResult<abci_query::Request> {
+        let query_data = QueryDelegationRequest::new(delegator_address, validator_address);
+        let path = format!("/cosmos.staking.v1beta1.Query/Delegation");
+        let path = cosmrs::tendermint::abci::Path::from_str(&path)?;
+        let req =
+            abci_query::Request::new(Some(path), query_data.to_proto_bytes()?, None, true);
+        Ok(req)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn staking_parse_query_delegation_resp(
+        &self,
+        resp: &abci_query::Response,
+    ) -> IndyResult<QueryDelegationResponse> {
+        let result = QueryDelegationResponse::from_proto_bytes(&resp.response.value)?;
+        return Ok(result);
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn staking_build_query_delegator_delegations(
+        &self,
+        delegator_address: String,
+        pagination: Option<PageRequest>,
+    ) -> IndyResult<abci_query::Request> {
+        let query_data = QueryDelegatorDelegationsRequest::new(delegator_address, pagination);
+        let path = format!("/cosmos.staking.v1beta1.Query/DelegatorDelegations");
+        let path = cosmrs::tendermint::abci::Path::from_str(&path)?;
+        let req =
+            abci_query::Request::new(Some(path), query_data.to_proto_bytes()?, None, true);
+        Ok(req)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn staking_parse_query_delegator_delegations_resp(
+        &self,
+        resp: &abci_query::Response,
+    ) -> IndyResult<QueryDelegatorDelegationsResponse> {
+        let result = QueryDelegatorDelegationsResponse::from_proto_bytes(&resp.response.value)?;
+        return Ok(result);
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn staking_build_query_validators(
+        &self,
+        status: String,
+        pagination: Option<PageRequest>,
+    ) -> IndyResult<abci_query::Request> {
+        let query_data = QueryValidatorsRequest::new(status, pagination);
+        let path = format!("/cosmos.staking.v1beta1.Query/Validators");
+        let path = cosmrs::tendermint::abci::Path::from_str(&path)?;
+        let req =
+            abci_query::Request::new(Some(path), query_data.to_proto_bytes()?, None, true);
+        Ok(req)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn staking_parse_query_validators_resp(
+        &self,
+        resp: &abci_query::Response,
+    ) -> IndyResult<QueryValidatorsResponse> {
+        let result = QueryValidatorsResponse::from_proto_bytes(&resp.response.value)?;
+        return Ok(result);
+    }
+}
@@ -1,5 +1,6 @@
 //! Pool service for Tendermint back-end
 
+use std::convert::TryFrom;
 use std::fs;
 use std::io::Write;
 
@@ -14,6 +15,7 @@ use cosmrs::rpc;
 use cosmrs::rpc::{Request, Response};
 use cosmrs::rpc::endpoint::broadcast;
 use cosmrs::tendermint::abci;
+use cosmrs::tendermint::block;
 use cosmrs::tx::Raw;
 use indy_api_types::errors::{IndyErrorKind, IndyResult, IndyResultExt};
 use indy_api_types::errors::*;
@@ -193,6 +195,96 @@ impl CheqdPoolService {
         Ok(resp)
     }
 
+    pub(crate) async fn status(
+        &self,
+        pool_alias: &str,
+    ) -> IndyResult<rpc::endpoint::status::Response> {
+        let pool = self.get_config(pool_alias).await?;
+        let req = rpc::endpoint::status::Request;
+        let resp = self.send_req(req, pool.rpc_address.as_str()).await?;
+        Ok(resp)
+    }
+
+    pub(crate) async fn block(
+        &self,
+        pool_alias: &str,
+        height: Option<u64>,
+    ) -> IndyResult<rpc::endpoint::block::Response> {
+        let pool = self.get_config(pool_alias).await?;
+        let req = rpc::endpoint::block::Request {
+            height: Self::build_height(height)?,
+        };
+        let resp = self.send_req(req, pool.rpc_address.as_str()).await?;
+        Ok(resp)
+    }
+
+    pub(crate) async fn block_results(
+        &self,
+        pool_alias: &str,
+        height: Option<u64>,
+    ) -> IndyResult<rpc::endpoint::block_results::Response> {
+        let pool = self.get_config(pool_alias).await?;
+        let req = rpc::endpoint::block_results::Request {
+            height: Self::build_height(height)?,
+        };
+        let resp = self.send_req(req, pool.rpc_address.as_str()).await?;
+        Ok(resp)
+    }
+
+    pub(crate) async fn validators(
+        &self,
+        pool_alias: &str,
+        height: Option<u64>,
+        page: Option<u64>,
+        per_page: Option<u64>,
+    ) -> IndyResult<rpc::endpoint::validators::Response> {
+        let pool = self.get_config(pool_alias).await?;
+
+        let page = page
+            .map(|page| usize::try_from(page).to_indy(
+                IndyErrorKind::InvalidStructure,
+                format!("Invalid page number: {}", page),
+            ))
+            .transpose()?;
+
+        let per_page = per_page
+            .map(|per_page| u8::try_from(per_page).to_indy(
+                IndyErrorKind::InvalidStructure,
+                format!("Invalid number of validators per page: {}", per_page),
+            ))
+            .transpose()?;
+
+        let req = rpc::endpoint::validators::Request::new(
+            Self::build_height(height)?,
+            page.map(rpc::PageNumber::from),
+            per_page.map(rpc::PerPage::from),
+        );
+        let resp = self.send_req(req, pool.rpc_address.as_str()).await?;
+        Ok(resp)
+    }
+
+    pub(crate) async fn net_info(
+        &self,
+        pool_alias: &str,
+    ) -> IndyResult<rpc::endpoint::net_info::Response> {
+        let pool = self.get_config(pool_alias).await?;
+        let req = rpc::endpoint::net_info::Request;
+        let resp = self.send_req(req, pool.rpc_address.as_str()).await?;
+        Ok(resp)
+    }
+
+    // `None` asks the node for the latest height
+    fn build_height(height: Option<u64>) -> IndyResult<Option<block::Height>> {
+        height
+            .map(|height| {
+                block::Height::try_from(height).to_indy(
+                    IndyErrorKind::InvalidStructure,
+                    format!("Invalid block height: {}", height),
+                )
+            })
+            .transpose()
+    }
+
     async fn send_req<R>(&self, req: R, rpc_address: &str) -> IndyResult<R::Response>
         where
             R: Request,
@@ -235,3 +327,19 @@ mod send_req {
         });
     }
 }
+
+#[cfg(test)]
+mod build_height {
+    use crate::CheqdPoolService;
+
+    #[test]
+    fn build_height_works() {
+        assert_eq!(None, CheqdPoolService::build_height(None).unwrap());
+        assert_eq!(10u64, CheqdPoolService::build_height(Some(10)).unwrap().unwrap().value());
+    }
+
+    #[test]
+    fn build_height_rejects_overflow() {
+        assert!(CheqdPoolService::build_height(Some(u64::MAX)).is_err());
+    }
+}
@@ -0,0 +1,568 @@
+//! Local evaluation of ledger auth rules against a prepared request.
+//!
+//! Signatures are not verified here: a DID is considered as a signer if it is listed
+//! in the request signatures. Signer roles are taken from the NYMs passed by the caller,
+//! signers without a NYM are treated as unknown for the ledger.
+
+use std::collections::HashMap;
+
+use indy_api_types::errors::prelude::*;
+use serde_json::Value;
+
+use crate::domain::ledger::{
+    auth_rule::{
+        AuthAction, AuthRule, AuthRuleAction, AuthRuleEvaluation, Constraint, MissingSignatures,
+        RoleConstraint,
+    },
+    constants::{txn_name_to_code, ENDORSER, STEWARD, TRUSTEE},
+    nym::NymData,
+    request::Request,
+};
+
+const ANY_VALUE: &str = "*";
+
+enum Outcome {
+    Satisfied,
+    Missing(Vec<MissingSignatures>),
+    Forbidden,
+}
+
+struct Signer {
+    on_ledger: bool,
+    role: Option<String>,
+}
+
+pub(crate) fn evaluate(
+    rules: &[AuthRule],
+    action: &AuthRuleAction,
+    request: &Request<Value>,
+    nyms: &[NymData],
+) -> IndyResult<AuthRuleEvaluation> {
+    let rule = _get_rule(rules, action)?;
+
+    Ok(_evaluate_rule(rule, action, request, &_request_signers(request), nyms))
+}
+
+/// DIDs which must sign the request to meet the rule: its author, its Endorser (if any)
+/// and the smallest set of DIDs with passed NYMs needed in addition to them.
+/// Returns `None` if the rule can't be met even if all of these DIDs sign.
+pub(crate) fn required_signers(
+    rules: &[AuthRule],
+    action: &AuthRuleAction,
+    request: &Request<Value>,
+    nyms: &[NymData],
+) -> IndyResult<Option<Vec<String>>> {
+    let rule = _get_rule(rules, action)?;
+
+    let mut signers: Vec<String> = Vec::new();
+
+    for did in request.identifier.iter().chain(request.endorser.iter()) {
+        if !signers.contains(&did.0) {
+            signers.push(did.0.clone());
+        }
+    }
+
+    let mandatory = signers.len();
+
+    for nym in nyms {
+        if !signers.contains(&nym.did.0) {
+            signers.push(nym.did.0.clone());
+        }
+    }
+
+    if !_evaluate_rule(rule, action, request, &signers, nyms).satisfied {
+        return Ok(None);
+    }
+
+    let mut i = mandatory;
+
+    while i < signers.len() {
+        let mut reduced = signers.clone();
+        reduced.remove(i);
+
+        if _evaluate_rule(rule, action, request, &reduced, nyms).satisfied {
+            signers = reduced;
+        } else {
+            i += 1;
+        }
+    }
+
+    Ok(Some(signers))
+}
+
+fn _get_rule<'a>(rules: &'a [AuthRule], action: &AuthRuleAction) -> IndyResult<&'a AuthRule> {
+    _find_rule(rules, action).ok_or_else(|| {
+        err_msg(
+            IndyErrorKind::LedgerItemNotFound,
+            format!("No auth rule found for the action {:?}", action),
+        )
+    })
+}
+
+fn _evaluate_rule(
+    rule: &AuthRule,
+    action: &AuthRuleAction,
+    request: &Request<Value>,
+    signer_dids: &[String],
+    nyms: &[NymData],
+) -> AuthRuleEvaluation {
+    let signers: HashMap<String, Signer> = signer_dids
+        .iter()
+        .cloned()
+        .map(|did| {
+            let nym = nyms.iter().find(|nym| nym.did.0 == did);
+
+            let signer = Signer {
+                on_ledger: nym.is_some(),
+                role: nym
+                    .and_then(|nym| nym.role.clone())
+                    .filter(|role| !role.is_empty()),
+            };
+
+            (did, signer)
+        })
+        .collect();
+
+    let owner = action
+        .owner
+        .clone()
+        .or_else(|| request.identifier.as_ref().map(|did| did.0.clone()));
+
+    let missing_endorsement = match request.endorser {
+        Some(ref endorser) => !signers
+            .get(&endorser.0)
+            .map(|signer| {
+                signer.on_ledger
+                    && [TRUSTEE, STEWARD, ENDORSER]
+                        .contains(&signer.role.as_deref().unwrap_or_default())
+            })
+            .unwrap_or(false),
+        None => false,
+    };
+
+    let (forbidden, missing_signatures) =
+        match _evaluate_constraint(&rule.constraint, &signers, owner.as_deref()) {
+            Outcome::Satisfied => (false, Vec::new()),
+            Outcome::Missing(missing) => (false, missing),
+            Outcome::Forbidden => (true, Vec::new()),
+        };
+
+    AuthRuleEvaluation {
+        satisfied: !forbidden && missing_signatures.is_empty() && !missing_endorsement,
+        forbidden,
+        missing_signatures,
+        missing_endorsement,
+    }
+}
+
+/// Finds the most specific rule for the action: rules with less wildcards win.
+fn _find_rule<'a>(rules: &'a [AuthRule], action: &AuthRuleAction) -> Option<&'a AuthRule> {
+    let auth_type = txn_name_to_code(&action.auth_type).unwrap_or(&action.auth_type);
+
+    let auth_action = match action.auth_action {
+        AuthAction::ADD => "ADD",
+        AuthAction::EDIT => "EDIT",
+    };
+
+    rules
+        .iter()
+        .filter(|rule| {
+            rule.auth_type == auth_type
+                && rule.auth_action == auth_action
+                && _value_matches(Some(&rule.field), Some(&action.field))
+                && (action.auth_action == AuthAction::ADD
+                    || _value_matches(rule.old_value.as_ref(), action.old_value.as_ref()))
+                && _value_matches(rule.new_value.as_ref(), action.new_value.as_ref())
+        })
+        .min_by_key(|rule| {
+            [Some(&rule.field), rule.old_value.as_ref(), rule.new_value.as_ref()]
+                .iter()
+                .filter(|value| value.map(String::as_str) == Some(ANY_VALUE))
+                .count()
+        })
+}
+
+fn _value_matches(rule_value: Option<&String>, value: Option<&String>) -> bool {
+    match rule_value.map(String::as_str) {
+        Some(ANY_VALUE) => true,
+        rule_value => rule_value.unwrap_or_default() == value.map(String::as_str).unwrap_or_default(),
+    }
+}
+
+fn _request_signers(request: &Request<Value>) -> Vec<String> {
+    let mut signers: Vec<String> = request
+        .signatures
+        .as_ref()
+        .map(|signatures| signatures.keys().cloned().collect())
+        .unwrap_or_default();
+
+    if let (Some(_), Some(identifier)) = (request.signature.as_ref(), request.identifier.as_ref()) {
+        signers.push(identifier.0.clone());
+    }
+
+    signers
+}
+
+fn _evaluate_constraint(
+    constraint: &Constraint,
+    signers: &HashMap<String, Signer>,
+    owner: Option<&str>,
+) -> Outcome {
+    match constraint {
+        Constraint::ForbiddenConstraint(_) => Outcome::Forbidden,
+        Constraint::RoleConstraint(constraint) => _evaluate_role_constraint(constraint, signers, owner),
+        Constraint::AndConstraint(combination) => {
+            let mut missing = Vec::new();
+
+            for constraint in combination.auth_constraints.iter() {
+                match _evaluate_constraint(constraint, signers, owner) {
+                    Outcome::Satisfied => {}
+                    Outcome::Missing(constraint_missing) => missing.extend(constraint_missing),
+                    Outcome::Forbidden => return Outcome::Forbidden,
+                }
+            }
+
+            if missing.is_empty() {
+                Outcome::Satisfied
+            } else {
+                Outcome::Missing(missing)
+            }
+        }
+        Constraint::OrConstraint(combination) => {
+            let mut best: Option<Vec<MissingSignatures>> = None;
+
+            for constraint in combination.auth_constraints.iter() {
+                match _evaluate_constraint(constraint, signers, owner) {
+                    Outcome::Satisfied => return Outcome::Satisfied,
+                    Outcome::Missing(missing) => {
+                        if best
+                            .as_ref()
+                            .map(|best| _sig_count(&missing) < _sig_count(best))
+                            .unwrap_or(true)
+                        {
+                            best = Some(missing);
+                        }
+                    }
+                    Outcome::Forbidden => {}
+                }
+            }
+
+            best.map(Outcome::Missing).unwrap_or(Outcome::Forbidden)
+        }
+    }
+}
+
+fn _evaluate_role_constraint(
+    constraint: &RoleConstraint,
+    signers: &HashMap<String, Signer>,
+    owner: Option<&str>,
+) -> Outcome {
+    let signed = signers
+        .iter()
+        .filter(|(did, signer)| {
+            (signer.on_ledger || constraint.off_ledger_signature)
+                && _role_matches(constraint.role.as_deref(), signer.role.as_deref())
+                && (!constraint.need_to_be_owner || Some(did.as_str()) == owner)
+        })
+        .count() as u32;
+
+    if signed >= constraint.sig_count {
+        Outcome::Satisfied
+    } else {
+        Outcome::Missing(vec![MissingSignatures {
+            role: constraint.role.clone(),
+            sig_count: constraint.sig_count - signed,
+            need_to_be_owner: constraint.need_to_be_owner,
+            off_ledger_signature: constraint.off_ledger_signature,
+        }])
+    }
+}
+
+fn _role_matches(constraint_role: Option<&str>, signer_role: Option<&str>) -> bool {
+    match constraint_role {
+        Some(ANY_VALUE) => true,
+        None | Some("") => signer_role.is_none(),
+        constraint_role => constraint_role == signer_role,
+    }
+}
+
+fn _sig_count(missing: &[MissingSignatures]) -> u32 {
+    missing.iter().map(|missing| missing.sig_count).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::crypto::did::ShortDidValue;
+    use crate::domain::ledger::auth_rule::{CombinationConstraint, ForbiddenConstraint};
+    use crate::domain::ledger::constants::NYM;
+
+    use super::*;
+
+    const AUTHOR: &str = "V4SGRU86Z58d6TV7PBUe6f";
+    const TRUSTEE_DID: &str = "Th7MpTaRZVRYnPiabds81Y";
+    const ENDORSER_DID: &str = "CnEDk9HrMnmiHXEV1WFgbV";
+
+    fn _role(role: &str, sig_count: u32) -> Constraint {
+        Constraint::RoleConstraint(RoleConstraint {
+            sig_count,
+            role: Some(role.to_string()),
+            metadata: None,
+            need_to_be_owner: false,
+            off_ledger_signature: false,
+        })
+    }
+
+    fn _rule(field: &str, new_value: Option<&str>, constraint: Constraint) -> AuthRule {
+        AuthRule {
+            auth_type: NYM.to_string(),
+            auth_action: "ADD".to_string(),
+            field: field.to_string(),
+            old_value: None,
+            new_value: new_value.map(String::from),
+            constraint,
+        }
+    }
+
+    fn _action(new_value: Option<&str>) -> AuthRuleAction {
+        AuthRuleAction {
+            auth_type: "NYM".to_string(),
+            auth_action: AuthAction::ADD,
+            field: "role".to_string(),
+            old_value: None,
+            new_value: new_value.map(String::from),
+            owner: None,
+        }
+    }
+
+    fn _request(signers: &[&str], endorser: Option<&str>) -> Request<Value> {
+        let mut request: Request<Value> =
+            Request::new(1, ShortDidValue(AUTHOR.to_string()), json!({"type": NYM}), 2);
+        request.signatures = Some(
+            signers
+                .iter()
+                .map(|did| (did.to_string(), "signature".to_string()))
+                .collect(),
+        );
+        request.endorser = endorser.map(|did| ShortDidValue(did.to_string()));
+        request
+    }
+
+    fn _nym(did: &str, role: Option<&str>) -> NymData {
+        NymData {
+            did: ShortDidValue(did.to_string()),
+            verkey: None,
+            role: role.map(String::from),
+        }
+    }
+
+    #[test]
+    fn evaluate_works_for_satisfied_role_constraint() {
+        let rules = vec![_rule("role", Some("101"), _role(TRUSTEE, 1))];
+
+        let res = evaluate(
+            &rules,
+            &_action(Some("101")),
+            &_request(&[TRUSTEE_DID], None),
+            &[_nym(TRUSTEE_DID, Some(TRUSTEE))],
+        )
+        .unwrap();
+
+        assert!(res.satisfied);
+        assert!(res.missing_signatures.is_empty());
+    }
+
+    #[test]
+    fn evaluate_works_for_missing_signatures() {
+        let rules = vec![_rule("role", Some("101"), _role(TRUSTEE, 2))];
+
+        let res = evaluate(
+            &rules,
+            &_action(Some("101")),
+            &_request(&[TRUSTEE_DID], None),
+            &[_nym(TRUSTEE_DID, Some(TRUSTEE))],
+        )
+        .unwrap();
+
+        assert!(!res.satisfied);
+        assert_eq!(1, res.missing_signatures.len());
+        assert_eq!(1, res.missing_signatures[0].sig_count);
+        assert_eq!(Some(TRUSTEE.to_string()), res.missing_signatures[0].role);
+    }
+
+    #[test]
+    fn evaluate_works_for_or_constraint() {
+        let constraint = Constraint::OrConstraint(CombinationConstraint {
+            auth_constraints: vec![_role(TRUSTEE, 3), _role(STEWARD, 1)],
+        });
+        let rules = vec![_rule("role", Some("101"), constraint)];
+
+        let res = evaluate(&rules, &_action(Some("101")), &_request(&[], None), &[]).unwrap();
+
+        assert!(!res.satisfied);
+        assert_eq!(1, res.missing_signatures.len());
+        assert_eq!(Some(STEWARD.to_string()), res.missing_signatures[0].role);
+    }
+
+    #[test]
+    fn evaluate_works_for_and_constraint() {
+        let constraint = Constraint::AndConstraint(CombinationConstraint {
+            auth_constraints: vec![_role(TRUSTEE, 1), _role(STEWARD, 1)],
+        });
+        let rules = vec![_rule("role", Some("101"), constraint)];
+
+        let res = evaluate(
+            &rules,
+            &_action(Some("101")),
+            &_request(&[TRUSTEE_DID], None),
+            &[_nym(TRUSTEE_DID, Some(TRUSTEE))],
+        )
+        .unwrap();
+
+        assert!(!res.satisfied);
+        assert_eq!(1, res.missing_signatures.len());
+        assert_eq!(Some(STEWARD.to_string()), res.missing_signatures[0].role);
+    }
+
+    #[test]
+    fn evaluate_works_for_forbidden_constraint() {
+        let rules = vec![_rule(
+            "role",
+            Some("101"),
+            Constraint::ForbiddenConstraint(ForbiddenConstraint {}),
+        )];
+
+        let res = evaluate(&rules, &_action(Some("101")), &_request(&[], None), &[]).unwrap();
+
+        assert!(!res.satisfied);
+        assert!(res.forbidden);
+    }
+
+    #[test]
+    fn evaluate_works_for_off_ledger_signature() {
+        let constraint = Constraint::RoleConstraint(RoleConstraint {
+            sig_count: 1,
+            role: Some(ANY_VALUE.to_string()),
+            metadata: None,
+            need_to_be_owner: false,
+            off_ledger_signature: true,
+        });
+        let rules = vec![_rule("role", Some(""), constraint)];
+
+        let res = evaluate(&rules, &_action(None), &_request(&[AUTHOR], None), &[]).unwrap();
+
+        assert!(res.satisfied);
+    }
+
+    #[test]
+    fn evaluate_works_for_need_to_be_owner() {
+        let constraint = Constraint::RoleConstraint(RoleConstraint {
+            sig_count: 1,
+            role: Some(ANY_VALUE.to_string()),
+            metadata: None,
+            need_to_be_owner: true,
+            off_ledger_signature: false,
+        });
+        let rules = vec![_rule("role", Some(""), constraint)];
+
+        let res = evaluate(
+            &rules,
+            &_action(None),
+            &_request(&[TRUSTEE_DID], None),
+            &[_nym(TRUSTEE_DID, Some(TRUSTEE))],
+        )
+        .unwrap();
+
+        assert!(!res.satisfied);
+        assert!(res.missing_signatures[0].need_to_be_owner);
+    }
+
+    #[test]
+    fn evaluate_works_for_missing_endorsement() {
+        let rules = vec![_rule("role", Some(""), _role(ANY_VALUE, 1))];
+
+        let res = evaluate(
+            &rules,
+            &_action(None),
+            &_request(&[AUTHOR], Some(ENDORSER_DID)),
+            &[_nym(AUTHOR, None), _nym(ENDORSER_DID, Some(ENDORSER))],
+        )
+        .unwrap();
+
+        assert!(!res.satisfied);
+        assert!(res.missing_signatures.is_empty());
+        assert!(res.missing_endorsement);
+    }
+
+    #[test]
+    fn evaluate_works_for_most_specific_rule() {
+        let rules = vec![
+            _rule("role", Some(ANY_VALUE), _role(TRUSTEE, 1)),
+            _rule("role", Some("101"), _role(STEWARD, 1)),
+        ];
+
+        let res = evaluate(&rules, &_action(Some("101")), &_request(&[], None), &[]).unwrap();
+
+        assert_eq!(Some(STEWARD.to_string()), res.missing_signatures[0].role);
+    }
+
+    #[test]
+    fn required_signers_works_for_author_only() {
+        let rules = vec![_rule("role", Some(""), _role(ANY_VALUE, 1))];
+
+        let res = required_signers(
+            &rules,
+            &_action(None),
+            &_request(&[], None),
+            &[_nym(AUTHOR, None), _nym(TRUSTEE_DID, Some(TRUSTEE))],
+        )
+        .unwrap();
+
+        assert_eq!(Some(vec![AUTHOR.to_string()]), res);
+    }
+
+    #[test]
+    fn required_signers_works_for_additional_signers() {
+        let rules = vec![_rule("role", Some("101"), _role(TRUSTEE, 1))];
+
+        let res = required_signers(
+            &rules,
+            &_action(Some("101")),
+            &_request(&[], Some(ENDORSER_DID)),
+            &[
+                _nym(AUTHOR, None),
+                _nym(ENDORSER_DID, Some(ENDORSER)),
+                _nym(TRUSTEE_DID, Some(TRUSTEE)),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            Some(vec![AUTHOR.to_string(), ENDORSER_DID.to_string(), TRUSTEE_DID.to_string()]),
+            res
+        );
+    }
+
+    #[test]
+    fn required_signers_works_for_unreachable_rule() {
+        let rules = vec![_rule("role", Some("101"), _role(TRUSTEE, 2))];
+
+        let res = required_signers(
+            &rules,
+            &_action(Some("101")),
+            &_request(&[], None),
+            &[_nym(AUTHOR, None), _nym(TRUSTEE_DID, Some(TRUSTEE))],
+        )
+        .unwrap();
+
+        assert_eq!(None, res);
+    }
+
+    #[test]
+    fn evaluate_works_for_no_rule() {
+        let rules = vec![_rule("verkey", None, _role(TRUSTEE, 1))];
+
+        let res = evaluate(&rules, &_action(Some("101")), &_request(&[], None), &[]);
+
+        assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+    }
+}
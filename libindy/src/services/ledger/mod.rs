@@ -1,9 +1,11 @@
+mod auth_rule_evaluator;
 pub mod merkletree;
 
 use hex::FromHex;
 use indy_api_types::errors::prelude::*;
-use indy_utils::crypto::hash::hash as openssl_hash;
+use indy_utils::crypto::{ed25519_box, ed25519_sign, hash::hash as openssl_hash};
 use log_derive::logfn;
+use rust_base58::{FromBase58, ToBase58};
 use serde::de::DeserializeOwned;
 use serde_json::{self, Value};
 use ursa::cl::RevocationRegistryDelta as CryproRevocationRegistryDelta;
@@ -22,15 +24,25 @@ use crate::domain::{
     },
     crypto::did::DidValue,
     ledger::{
-        attrib::{AttribOperation, GetAttribOperation},
+        attrib::{AttribOperation, GetAttrReplyResult, GetAttribOperation},
         auth_rule::*,
         author_agreement::*,
         constants::{
-            txn_name_to_code, ENDORSER, GET_VALIDATOR_INFO, NETWORK_MONITOR, POOL_RESTART, ROLES,
-            ROLE_REMOVE, STEWARD, TRUSTEE,
+            txn_name_to_code, ENDORSER, GET_RICH_SCHEMA_OBJECT_BY_METADATA, GET_VALIDATOR_INFO,
+            NETWORK_MONITOR, POOL_RESTART, ROLES, ROLE_REMOVE, STEWARD, TRUSTEE,
         },
         cred_def::{CredDefOperation, GetCredDefOperation, GetCredDefReplyResult},
-        ddo::GetDdoOperation,
+        ddo::{
+            DidDocument, EndpointAttrib, EndpointData, Service, StateProofMetadata,
+            VerificationMethod, ACCEPT_DIDCOMM_AIP2, ACCEPT_DIDCOMM_V2, DIDCOMM_V2_CONTEXT,
+            DIDCOMM_V2_SERVICE_FRAGMENT, KEY_AGREEMENT_FRAGMENT, SERVICE_DIDCOMM,
+            SERVICE_DID_COMMUNICATION, SERVICE_ENDPOINT, VERKEY_FRAGMENT, X25519_2019_CONTEXT,
+            X25519_KEY_AGREEMENT_KEY_2019,
+        },
+        ledgers_freeze::{
+            FrozenLedgers, GetFrozenLedgersOperation, GetFrozenLedgersReplyResult,
+            LedgersFreezeOperation,
+        },
         node::{NodeOperation, NodeOperationData},
         nym::{GetNymOperation, GetNymReplyResult, GetNymResultDataV0, NymData, NymOperation},
         pool::{PoolConfigOperation, PoolRestartOperation, PoolUpgradeOperation, Schedule},
@@ -41,15 +53,25 @@ use crate::domain::{
             GetRevocRegReplyResult, RevRegEntryOperation,
         },
         rev_reg_def::{GetRevRegDefOperation, GetRevocRegDefReplyResult, RevRegDefOperation},
+        rich_schema::{
+            GetRichSchemaObjectByIdOperation, GetRichSchemaObjectByIdReplyResult,
+            GetRichSchemaObjectByMetadataOperation, GetRichSchemaObjectByMetadataReplyResult,
+            RichSchema, RichSchemaOperation, RichSchemaType,
+        },
         schema::{
             GetSchemaOperation, GetSchemaOperationData, GetSchemaReplyResult, SchemaOperation,
             SchemaOperationData,
         },
         txn::{GetTxnOperation, LedgerType},
-        validator_info::GetValidatorInfoOperation,
+        validator_info::{
+            GetValidatorInfoOperation, GetValidatorInfoReplyResult, NodeValidatorInfo,
+            NodeValidatorInfoStatus, ValidatorInfo,
+        },
     },
 };
 
+use crate::utils::crypto::verkey_builder::{build_full_verkey, split_verkey};
+
 macro_rules! build_result {
         ($operation:ident, $submitter_did:expr) => ({
             let operation = $operation::new();
@@ -165,13 +187,253 @@ impl LedgerService {
         Ok(res)
     }
 
+    /// DID document of Indy DID is built from NYM data, so DDO request is GET_NYM request.
     #[logfn(Info)]
     pub(crate) fn build_get_ddo_request(
         &self,
         identifier: Option<&DidValue>,
         dest: &DidValue,
     ) -> IndyResult<String> {
-        build_result!(GetDdoOperation, identifier, dest.to_short())
+        self.build_get_nym_request(identifier, dest)
+    }
+
+    /// Builds base DID document from GET_NYM reply.
+    /// Returns also `diddocContent` of the NYM if it is set.
+    #[logfn(Info)]
+    pub(crate) fn parse_get_nym_response_to_did_document(
+        &self,
+        did: &DidValue,
+        get_nym_response: &str,
+    ) -> IndyResult<(DidDocument, Option<Value>)> {
+        let reply: Reply<GetNymReplyResult> = LedgerService::parse_response(get_nym_response)?;
+
+        let (dest, verkey, diddoc_content) = match reply.result() {
+            GetNymReplyResult::GetNymReplyResultV0(res) => {
+                let data: GetNymResultDataV0 = res
+                    .data
+                    .ok_or(IndyError::from_msg(
+                        IndyErrorKind::LedgerItemNotFound,
+                        format!("Nym not found"),
+                    ))
+                    .and_then(|data| {
+                        serde_json::from_str(&data).map_err(|err| {
+                            IndyError::from_msg(
+                                IndyErrorKind::InvalidState,
+                                format!("Cannot parse GET_NYM response: {}", err),
+                            )
+                        })
+                    })?;
+
+                (data.dest, data.verkey, data.diddoc_content)
+            }
+            GetNymReplyResult::GetNymReplyResultV1(res) => (
+                res.txn.data.did,
+                res.txn.data.verkey,
+                res.txn.data.diddoc_content,
+            ),
+        };
+
+        // Legacy unqualified Indy DIDs are resolved as did:sov
+        let id = dest.qualify(Some(did.get_method().unwrap_or_else(|| "sov".to_string())));
+
+        let verkey = build_full_verkey(&dest.0, verkey.as_deref())?;
+        let (verkey, _) = split_verkey(&verkey);
+
+        let diddoc_content = diddoc_content
+            .map(|content| {
+                serde_json::from_str::<Value>(&content)
+                    .to_indy(IndyErrorKind::InvalidTransaction, "Invalid NYM diddocContent json")
+            })
+            .transpose()?;
+
+        Ok((DidDocument::new(&id.0, verkey), diddoc_content))
+    }
+
+    /// Adds services of endpoint ATTRIB to DID document.
+    /// Returns false if there is no endpoint set for the DID.
+    #[logfn(Info)]
+    pub(crate) fn add_endpoint_services(
+        &self,
+        did_doc: &mut DidDocument,
+        get_attrib_response: &str,
+    ) -> IndyResult<bool> {
+        let reply: Reply<GetAttrReplyResult> =
+            match LedgerService::parse_response(get_attrib_response) {
+                Ok(reply) => reply,
+                Err(err) if err.kind() == IndyErrorKind::LedgerItemNotFound => return Ok(false),
+                Err(err) => return Err(err),
+            };
+
+        let raw = match reply.result() {
+            GetAttrReplyResult::GetAttrReplyResultV0(res) => res.data,
+            GetAttrReplyResult::GetAttrReplyResultV1(res) => res.txn.data.raw,
+        };
+
+        let endpoint = match serde_json::from_str::<EndpointAttrib>(&raw) {
+            Ok(attrib) => attrib.endpoint,
+            Err(err) => {
+                warn!("Endpoint ATTRIB is skipped as it has unknown format: {}", err);
+                return Ok(false);
+            }
+        };
+
+        let verkey_id = DidDocument::fragment(&did_doc.id, VERKEY_FRAGMENT);
+
+        match endpoint {
+            EndpointData::Legacy(endpoint) => did_doc.service.push(Service {
+                id: DidDocument::fragment(&did_doc.id, SERVICE_DID_COMMUNICATION),
+                type_: SERVICE_DID_COMMUNICATION.to_string(),
+                service_endpoint: endpoint.ha,
+                priority: Some(0),
+                recipient_keys: vec![verkey_id],
+                routing_keys: Vec::new(),
+                accept: vec![ACCEPT_DIDCOMM_AIP2.to_string()],
+            }),
+            EndpointData::DidComm(endpoint) => {
+                for type_ in endpoint.types() {
+                    match type_.as_str() {
+                        SERVICE_ENDPOINT => did_doc.service.push(Service {
+                            id: DidDocument::fragment(&did_doc.id, SERVICE_ENDPOINT),
+                            type_: SERVICE_ENDPOINT.to_string(),
+                            service_endpoint: endpoint.endpoint.clone(),
+                            priority: None,
+                            recipient_keys: Vec::new(),
+                            routing_keys: Vec::new(),
+                            accept: Vec::new(),
+                        }),
+                        SERVICE_DID_COMMUNICATION => did_doc.service.push(Service {
+                            id: DidDocument::fragment(&did_doc.id, SERVICE_DID_COMMUNICATION),
+                            type_: SERVICE_DID_COMMUNICATION.to_string(),
+                            service_endpoint: endpoint.endpoint.clone(),
+                            priority: Some(0),
+                            recipient_keys: vec![verkey_id.clone()],
+                            routing_keys: endpoint.routing_keys.clone(),
+                            accept: vec![ACCEPT_DIDCOMM_AIP2.to_string()],
+                        }),
+                        SERVICE_DIDCOMM => {
+                            let key_agreement_id =
+                                DidDocument::fragment(&did_doc.id, KEY_AGREEMENT_FRAGMENT);
+
+                            let x25519_key = LedgerService::_verkey_to_x25519(
+                                &did_doc.verification_method[0].public_key_base58,
+                            )?;
+
+                            did_doc.key_agreement.push(VerificationMethod {
+                                id: key_agreement_id.clone(),
+                                type_: X25519_KEY_AGREEMENT_KEY_2019.to_string(),
+                                controller: did_doc.id.clone(),
+                                public_key_base58: x25519_key,
+                            });
+                            did_doc.add_context(X25519_2019_CONTEXT);
+                            did_doc.add_context(DIDCOMM_V2_CONTEXT);
+
+                            did_doc.service.push(Service {
+                                id: DidDocument::fragment(&did_doc.id, DIDCOMM_V2_SERVICE_FRAGMENT),
+                                type_: SERVICE_DIDCOMM.to_string(),
+                                service_endpoint: endpoint.endpoint.clone(),
+                                priority: None,
+                                recipient_keys: vec![key_agreement_id],
+                                routing_keys: endpoint.routing_keys.clone(),
+                                accept: vec![
+                                    ACCEPT_DIDCOMM_V2.to_string(),
+                                    ACCEPT_DIDCOMM_AIP2.to_string(),
+                                ],
+                            });
+                        }
+                        type_ => warn!("Endpoint type {:?} is not supported and skipped", type_),
+                    }
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Merges NYM `diddocContent` into base DID document.
+    /// Arrays are appended to the ones of base document, other properties are added.
+    #[logfn(Info)]
+    pub(crate) fn merge_diddoc_content(
+        &self,
+        did_doc: &DidDocument,
+        diddoc_content: Value,
+    ) -> IndyResult<Value> {
+        let mut did_doc = serde_json::to_value(did_doc)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize DID document")?;
+
+        let diddoc_content = match diddoc_content {
+            Value::Object(content) => content,
+            _ => {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidTransaction,
+                    "NYM diddocContent must be json object",
+                ))
+            }
+        };
+
+        for (key, value) in diddoc_content {
+            match (key.as_str(), value) {
+                ("id", ref value) if value != &did_doc["id"] => {
+                    return Err(err_msg(
+                        IndyErrorKind::InvalidTransaction,
+                        "NYM diddocContent must not override DID document id",
+                    ))
+                }
+                ("id", _) => {}
+                ("@context", Value::String(context)) => {
+                    LedgerService::_append_unique(&mut did_doc["@context"], vec![Value::String(context)])
+                }
+                ("@context", Value::Array(contexts)) => {
+                    LedgerService::_append_unique(&mut did_doc["@context"], contexts)
+                }
+                (_, Value::Array(values)) if did_doc[&key].is_array() => {
+                    LedgerService::_append_unique(&mut did_doc[&key], values)
+                }
+                (_, value) => {
+                    did_doc[&key] = value;
+                }
+            }
+        }
+
+        Ok(did_doc)
+    }
+
+    /// Extracts state proof root hash and multi signature from node reply.
+    #[logfn(Info)]
+    pub(crate) fn parse_state_proof_metadata(
+        &self,
+        response: &str,
+    ) -> IndyResult<Option<StateProofMetadata>> {
+        let message: Value = serde_json::from_str(response).to_indy(
+            IndyErrorKind::InvalidTransaction,
+            "Response is invalid json",
+        )?;
+
+        let state_proof = &message["result"]["state_proof"];
+
+        if state_proof.is_null() {
+            return Ok(None);
+        }
+
+        serde_json::from_value(state_proof.clone())
+            .map(Some)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Invalid state proof in response")
+    }
+
+    fn _verkey_to_x25519(verkey: &str) -> IndyResult<String> {
+        let verkey = ed25519_sign::PublicKey::from_slice(&verkey.from_base58()?)?;
+        let key = ed25519_sign::vk_to_curve25519(&verkey)?;
+
+        Ok(key[..ed25519_box::PUBLICKEYBYTES].to_base58())
+    }
+
+    fn _append_unique(target: &mut Value, values: Vec<Value>) {
+        if let Value::Array(target) = target {
+            for value in values {
+                if !target.contains(&value) {
+                    target.push(value);
+                }
+            }
+        }
     }
 
     #[logfn(Info)]
@@ -247,6 +509,7 @@ impl LedgerService {
         &self,
         identifier: &DidValue,
         cred_def: CredentialDefinitionV1,
+        protocol_version: usize,
     ) -> IndyResult<String> {
         let cred_def: CredentialDefinitionV1 = CredentialDefinitionV1 {
             id: cred_def.id.to_unqualified(),
@@ -255,7 +518,7 @@ impl LedgerService {
             tag: cred_def.tag,
             value: cred_def.value,
         };
-        build_result!(CredDefOperation, Some(identifier), cred_def)
+        build_result!(CredDefOperation, Some(identifier), cred_def, protocol_version)
     }
 
     #[logfn(Info)]
@@ -311,18 +574,22 @@ impl LedgerService {
         ledger_type: Option<&str>,
         seq_no: i32,
     ) -> IndyResult<String> {
-        let ledger_id = match ledger_type {
+        let ledger_id = self.parse_ledger_id(ledger_type)?;
+
+        build_result!(GetTxnOperation, identifier, seq_no, ledger_id)
+    }
+
+    pub(crate) fn parse_ledger_id(&self, ledger_type: Option<&str>) -> IndyResult<i32> {
+        match ledger_type {
             Some(type_) => serde_json::from_str::<LedgerType>(&format!(r#""{}""#, type_))
                 .map(|type_| type_.to_id())
                 .or_else(|_| type_.parse::<i32>())
                 .to_indy(
                     IndyErrorKind::InvalidStructure,
                     format!("Invalid Ledger type: {}", type_),
-                )?,
-            None => LedgerType::DOMAIN.to_id(),
-        };
-
-        build_result!(GetTxnOperation, identifier, seq_no, ledger_id)
+                ),
+            None => Ok(LedgerType::DOMAIN.to_id()),
+        }
     }
 
     #[logfn(Info)]
@@ -806,6 +1073,178 @@ impl LedgerService {
         )
     }
 
+    #[logfn(Info)]
+    pub(crate) fn build_ledgers_freeze_request(
+        &self,
+        identifier: &DidValue,
+        ledgers_ids: Vec<u64>,
+    ) -> IndyResult<String> {
+        build_result!(LedgersFreezeOperation, Some(identifier), ledgers_ids)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn build_get_frozen_ledgers_request(
+        &self,
+        identifier: Option<&DidValue>,
+    ) -> IndyResult<String> {
+        build_result!(GetFrozenLedgersOperation, identifier)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn parse_get_frozen_ledgers_response(&self, response: &str) -> IndyResult<String> {
+        let reply: Reply<GetFrozenLedgersReplyResult> = LedgerService::parse_response(response)?;
+
+        // Empty state means that no ledger has been frozen yet
+        let frozen_ledgers: FrozenLedgers = reply.result().data.unwrap_or_default();
+
+        let res = serde_json::to_string(&frozen_ledgers).map_err(|err| {
+            IndyError::from_msg(
+                IndyErrorKind::InvalidState,
+                format!("Cannot serialize frozen ledgers: {}", err),
+            )
+        })?;
+
+        Ok(res)
+    }
+
+    /// Parses replies of the nodes to GET_VALIDATOR_INFO request: either a json object
+    /// mapping node aliases to their replies (or "timeout") or a single node reply.
+    #[logfn(Info)]
+    pub(crate) fn parse_get_validator_info_response(&self, response: &str) -> IndyResult<String> {
+        let message: Value = serde_json::from_str(response).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Response is invalid json",
+        )?;
+
+        let nodes = match message {
+            Value::Object(ref replies) if !replies.contains_key("op") => replies
+                .iter()
+                .map(|(node, reply)| match reply.as_str() {
+                    Some("timeout") => {
+                        NodeValidatorInfo::failed(node, NodeValidatorInfoStatus::Timeout, None)
+                    }
+                    Some(reply) => LedgerService::_parse_node_validator_info(node, reply),
+                    None => NodeValidatorInfo::failed(
+                        node,
+                        NodeValidatorInfoStatus::Error,
+                        Some(String::from("Reply is not a string")),
+                    ),
+                })
+                .collect(),
+            Value::Object(_) => {
+                let reply: Reply<GetValidatorInfoReplyResult> =
+                    LedgerService::parse_response(response)?;
+
+                let data = reply.result().data.ok_or_else(|| {
+                    err_msg(IndyErrorKind::InvalidTransaction, "Validator info is missed in the reply")
+                })?;
+
+                let node = data.node_info.name.clone().unwrap_or_default();
+                vec![NodeValidatorInfo::from_data(&node, data)]
+            }
+            _ => {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidStructure,
+                    "Response is not a json object",
+                ))
+            }
+        };
+
+        let res = serde_json::to_string(&ValidatorInfo::new(nodes)).map_err(|err| {
+            IndyError::from_msg(
+                IndyErrorKind::InvalidState,
+                format!("Cannot serialize validator info: {}", err),
+            )
+        })?;
+
+        Ok(res)
+    }
+
+    fn _parse_node_validator_info(node: &str, reply: &str) -> NodeValidatorInfo {
+        let data = LedgerService::parse_response::<GetValidatorInfoReplyResult>(reply)
+            .and_then(|reply| {
+                reply.result().data.ok_or_else(|| {
+                    err_msg(IndyErrorKind::InvalidTransaction, "Validator info is missed in the reply")
+                })
+            });
+
+        match data {
+            Ok(data) => NodeValidatorInfo::from_data(node, data),
+            Err(err) => NodeValidatorInfo::failed(
+                node,
+                NodeValidatorInfoStatus::Error,
+                Some(err.to_string()),
+            ),
+        }
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn build_rich_schema_request(
+        &self,
+        identifier: &DidValue,
+        rich_schema: RichSchema,
+    ) -> IndyResult<String> {
+        build_result!(RichSchemaOperation, Some(identifier), rich_schema)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn build_get_rich_schema_object_by_id_request(
+        &self,
+        identifier: Option<&DidValue>,
+        id: &str,
+    ) -> IndyResult<String> {
+        build_result!(GetRichSchemaObjectByIdOperation, identifier, id.to_string())
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn build_get_rich_schema_object_by_metadata_request(
+        &self,
+        identifier: Option<&DidValue>,
+        rs_type: RichSchemaType,
+        rs_name: &str,
+        rs_version: &str,
+    ) -> IndyResult<String> {
+        build_result!(
+            GetRichSchemaObjectByMetadataOperation,
+            identifier,
+            rs_type,
+            rs_name.to_string(),
+            rs_version.to_string()
+        )
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn parse_get_rich_schema_object_response(&self, response: &str) -> IndyResult<String> {
+        let message: Value = serde_json::from_str(response).to_indy(
+            IndyErrorKind::InvalidTransaction,
+            "Response is invalid json",
+        )?;
+
+        let rich_schema_object = if message["result"]["type"].as_str()
+            == Some(GET_RICH_SCHEMA_OBJECT_BY_METADATA)
+        {
+            LedgerService::parse_response::<GetRichSchemaObjectByMetadataReplyResult>(response)?
+                .result()
+                .0
+                .data
+        } else {
+            LedgerService::parse_response::<GetRichSchemaObjectByIdReplyResult>(response)?
+                .result()
+                .0
+                .data
+        }
+        .ok_or_else(|| err_msg(IndyErrorKind::LedgerItemNotFound, "Rich schema object not found"))?;
+
+        let res = serde_json::to_string(&rich_schema_object).map_err(|err| {
+            IndyError::from_msg(
+                IndyErrorKind::InvalidState,
+                format!("Cannot serialize rich schema object: {}", err),
+            )
+        })?;
+
+        Ok(res)
+    }
+
     #[logfn(Info)]
     pub(crate) fn parse_response<T>(response: &str) -> IndyResult<Reply<T>>
     where
@@ -922,7 +1361,6 @@ impl LedgerService {
         Ok(())
     }
 
-    #[allow(dead_code)] // FIXME [async] TODO check why unused
     pub(crate) fn parse_get_auth_rule_response(&self, response: &str) -> IndyResult<Vec<AuthRule>> {
         trace!("parse_get_auth_rule_response >>> response: {:?}", response);
 
@@ -940,6 +1378,42 @@ impl LedgerService {
 
         Ok(res)
     }
+
+    #[logfn(Info)]
+    pub(crate) fn evaluate_auth_rule(
+        &self,
+        get_auth_rule_response: &str,
+        action: &AuthRuleAction,
+        request_json: &str,
+        signers: &[NymData],
+    ) -> IndyResult<AuthRuleEvaluation> {
+        let rules = self.parse_get_auth_rule_response(get_auth_rule_response)?;
+
+        let request: Request<Value> = serde_json::from_str(request_json).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Request is invalid json",
+        )?;
+
+        auth_rule_evaluator::evaluate(&rules, action, &request, signers)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn auth_rule_required_signers(
+        &self,
+        get_auth_rule_response: &str,
+        action: &AuthRuleAction,
+        request: &Value,
+        signers: &[NymData],
+    ) -> IndyResult<Option<Vec<String>>> {
+        let rules = self.parse_get_auth_rule_response(get_auth_rule_response)?;
+
+        let request: Request<Value> = serde_json::from_value(request.clone()).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Request is invalid json",
+        )?;
+
+        auth_rule_evaluator::required_signers(&rules, action, &request, signers)
+    }
 }
 
 #[cfg(test)]
@@ -948,7 +1422,7 @@ mod tests {
 
     use crate::domain::{
         anoncreds::schema::AttributeNames,
-        ledger::{constants::*, node::Services, request::ProtocolVersion},
+        ledger::{constants::*, cred_def, node::Services, request::ProtocolVersion},
     };
 
     const IDENTIFIER: &str = "NcYxiDXkpYi6ov5FcYDi1e";
@@ -1068,7 +1542,7 @@ mod tests {
         let ledger_service = LedgerService::new();
 
         let expected_result = json!({
-            "type": GET_DDO,
+            "type": GET_NYM,
             "dest": DEST
         });
 
@@ -1228,6 +1702,42 @@ mod tests {
         check_request(&request, expected_result);
     }
 
+    #[test]
+    fn apply_cred_def_protocol_version_drops_tag_for_protocol_version_1() {
+        let ledger_service = LedgerService::new();
+
+        let id = CredentialDefinitionId::new(
+            &identifier(),
+            &SchemaId("1".to_string()),
+            "signature_type",
+            "tag",
+        );
+
+        let request = ledger_service
+            .build_get_cred_def_request(Some(&identifier()), &id)
+            .unwrap();
+        let mut request: serde_json::Value = serde_json::from_str(&request).unwrap();
+
+        cred_def::apply_protocol_version(&mut request["operation"], 2);
+        assert_eq!("tag", request["operation"]["tag"]);
+
+        cred_def::apply_protocol_version(&mut request["operation"], 1);
+        assert!(request["operation"].get("tag").is_none());
+
+        let mut operation = json!({"type": CRED_DEF, "ref": 1, "signature_type": "CL", "data": {}, "tag": "tag"});
+        cred_def::apply_protocol_version(&mut operation, 1);
+        assert!(operation.get("tag").is_none());
+    }
+
+    #[test]
+    fn apply_cred_def_protocol_version_keeps_other_operations() {
+        let mut operation = json!({"type": GET_SCHEMA, "dest": IDENTIFIER, "tag": "tag"});
+
+        cred_def::apply_protocol_version(&mut operation, 1);
+
+        assert_eq!("tag", operation["tag"]);
+    }
+
     #[test]
     fn build_node_request_works() {
         let ledger_service = LedgerService::new();
@@ -1801,6 +2311,622 @@ mod tests {
         }
     }
 
+    mod did_document {
+        use super::*;
+
+        const ROOT_HASH: &str = "5BU5Rc3sRtTJB6tVprGiTSqiRaa9o6ei11MjH4Vu16ms";
+
+        fn get_nym_response(diddoc_content: Option<serde_json::Value>) -> String {
+            let mut data = json!({
+                "dest": DEST,
+                "identifier": IDENTIFIER,
+                "role": null,
+                "seqNo": 10,
+                "txnTime": 1600000000,
+                "verkey": VERKEY,
+            });
+
+            if let Some(diddoc_content) = diddoc_content {
+                data["diddocContent"] = json!(diddoc_content.to_string());
+            }
+
+            json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_NYM,
+                    "identifier": IDENTIFIER,
+                    "reqId": 1,
+                    "dest": DEST,
+                    "data": data.to_string(),
+                    "seqNo": 10,
+                    "txnTime": 1600000000,
+                    "state_proof": {
+                        "root_hash": ROOT_HASH,
+                        "proof_nodes": "+QHl+FGAgICg0he/hjc9t/tPFzmCrb2T+nHnN0cRwqPKqZEc3pw2iCaAoAsA80p3oFwfl4dDaKkNI8z8weRsSaS9Y8n3HoardRzxgICAgICAgICAgID4naAgLCAiUEPoqKaQ6OQ1zRYPuIcgEJnBoHCe5Wx8xA==",
+                        "multi_signature": {
+                            "value": {"ledger_id": 1, "pool_state_root_hash": "7o9...", "state_root_hash": ROOT_HASH, "timestamp": 1600000000, "txn_root_hash": "9Gv..."},
+                            "signature": "QZ...",
+                            "participants": ["Node1", "Node2", "Node3"]
+                        }
+                    }
+                }
+            }).to_string()
+        }
+
+        fn get_attrib_response(data: Option<serde_json::Value>) -> String {
+            json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_ATTR,
+                    "identifier": IDENTIFIER,
+                    "reqId": 2,
+                    "dest": DEST,
+                    "raw": "endpoint",
+                    "data": data.map(|data| data.to_string()),
+                    "seqNo": 11,
+                    "txnTime": 1600000100
+                }
+            }).to_string()
+        }
+
+        fn did_document(did: &DidValue) -> DidDocument {
+            LedgerService::new()
+                .parse_get_nym_response_to_did_document(did, &get_nym_response(None))
+                .unwrap()
+                .0
+        }
+
+        #[test]
+        fn parse_get_nym_response_to_did_document_works() {
+            let did = DidValue(format!("did:indy:sovrin:{}", DEST));
+
+            let (did_doc, diddoc_content) = LedgerService::new()
+                .parse_get_nym_response_to_did_document(&did, &get_nym_response(None))
+                .unwrap();
+
+            assert_eq!(did.0, did_doc.id);
+            assert_eq!(format!("{}#verkey", did.0), did_doc.verification_method[0].id);
+            assert_eq!(VERKEY, did_doc.verification_method[0].public_key_base58);
+            assert_eq!(vec![format!("{}#verkey", did.0)], did_doc.authentication);
+            assert!(did_doc.service.is_empty());
+            assert!(diddoc_content.is_none());
+        }
+
+        #[test]
+        fn parse_get_nym_response_to_did_document_works_for_unqualified_did() {
+            let did_doc = did_document(&dest());
+            assert_eq!(format!("did:sov:{}", DEST), did_doc.id);
+        }
+
+        #[test]
+        fn parse_get_nym_response_to_did_document_works_for_not_found() {
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_NYM,
+                    "identifier": IDENTIFIER,
+                    "reqId": 1,
+                    "dest": DEST,
+                    "data": null,
+                    "seqNo": null,
+                    "txnTime": null
+                }
+            }).to_string();
+
+            let res = LedgerService::new().parse_get_nym_response_to_did_document(&dest(), &response);
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+
+        #[test]
+        fn add_endpoint_services_works_for_legacy_endpoint() {
+            let mut did_doc = did_document(&dest());
+
+            let response = get_attrib_response(Some(json!({"endpoint": {"ha": "127.0.0.1:5555", "verkey": VERKEY}})));
+
+            assert!(LedgerService::new().add_endpoint_services(&mut did_doc, &response).unwrap());
+
+            assert_eq!(1, did_doc.service.len());
+            assert_eq!(SERVICE_DID_COMMUNICATION, did_doc.service[0].type_);
+            assert_eq!("127.0.0.1:5555", did_doc.service[0].service_endpoint);
+            assert_eq!(vec![format!("{}#verkey", did_doc.id)], did_doc.service[0].recipient_keys);
+        }
+
+        #[test]
+        fn add_endpoint_services_works_for_default_types() {
+            let mut did_doc = did_document(&dest());
+
+            let response = get_attrib_response(Some(json!({"endpoint": {"endpoint": "https://agent.example.com"}})));
+
+            assert!(LedgerService::new().add_endpoint_services(&mut did_doc, &response).unwrap());
+
+            let types: Vec<&str> = did_doc.service.iter().map(|service| service.type_.as_str()).collect();
+            assert_eq!(vec![SERVICE_ENDPOINT, SERVICE_DID_COMMUNICATION], types);
+            assert!(did_doc.key_agreement.is_empty());
+        }
+
+        #[test]
+        fn add_endpoint_services_works_for_didcomm() {
+            let mut did_doc = did_document(&dest());
+
+            let response = get_attrib_response(Some(json!({
+                "endpoint": {
+                    "endpoint": "https://agent.example.com",
+                    "types": ["DIDComm"],
+                    "routingKeys": ["did:sov:HR6vs6GEZ8rHaVgjg2WodM#key-agreement-1"]
+                }
+            })));
+
+            assert!(LedgerService::new().add_endpoint_services(&mut did_doc, &response).unwrap());
+
+            assert_eq!(1, did_doc.key_agreement.len());
+            assert_eq!(X25519_KEY_AGREEMENT_KEY_2019, did_doc.key_agreement[0].type_);
+            assert_ne!(VERKEY, did_doc.key_agreement[0].public_key_base58);
+            assert!(did_doc.context.contains(&X25519_2019_CONTEXT.to_string()));
+
+            assert_eq!(1, did_doc.service.len());
+            assert_eq!(SERVICE_DIDCOMM, did_doc.service[0].type_);
+            assert_eq!(vec![did_doc.key_agreement[0].id.clone()], did_doc.service[0].recipient_keys);
+            assert_eq!(1, did_doc.service[0].routing_keys.len());
+        }
+
+        #[test]
+        fn add_endpoint_services_works_for_no_endpoint() {
+            let mut did_doc = did_document(&dest());
+
+            let response = get_attrib_response(None);
+
+            assert!(!LedgerService::new().add_endpoint_services(&mut did_doc, &response).unwrap());
+            assert!(did_doc.service.is_empty());
+        }
+
+        #[test]
+        fn merge_diddoc_content_works() {
+            let did = DidValue(format!("did:indy:sovrin:{}", DEST));
+            let diddoc_content = json!({
+                "@context": ["https://identity.foundation/didcomm-messaging/service-endpoint/v1"],
+                "service": [{
+                    "id": format!("{}#didcomm", did.0),
+                    "type": "DIDCommMessaging",
+                    "serviceEndpoint": "https://agent.example.com"
+                }],
+                "alsoKnownAs": ["did:web:example.com"]
+            });
+
+            let (did_doc, content) = LedgerService::new()
+                .parse_get_nym_response_to_did_document(&did, &get_nym_response(Some(diddoc_content)))
+                .unwrap();
+
+            let did_doc = LedgerService::new().merge_diddoc_content(&did_doc, content.unwrap()).unwrap();
+
+            assert_eq!(3, did_doc["@context"].as_array().unwrap().len());
+            assert_eq!(1, did_doc["verificationMethod"].as_array().unwrap().len());
+            assert_eq!("DIDCommMessaging", did_doc["service"][0]["type"]);
+            assert_eq!(json!(["did:web:example.com"]), did_doc["alsoKnownAs"]);
+        }
+
+        #[test]
+        fn merge_diddoc_content_works_for_other_id() {
+            let did_doc = did_document(&dest());
+
+            let res = LedgerService::new().merge_diddoc_content(&did_doc, json!({"id": "did:sov:other"}));
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+
+        #[test]
+        fn parse_state_proof_metadata_works() {
+            let state_proof = LedgerService::new()
+                .parse_state_proof_metadata(&get_nym_response(None))
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(ROOT_HASH, state_proof.root_hash);
+            assert!(state_proof.multi_signature.is_some());
+        }
+
+        #[test]
+        fn parse_state_proof_metadata_works_for_no_state_proof() {
+            let state_proof = LedgerService::new()
+                .parse_state_proof_metadata(&get_attrib_response(None))
+                .unwrap();
+
+            assert!(state_proof.is_none());
+        }
+    }
+
+    mod validator_info {
+        use super::*;
+
+        fn _validator_info_reply(node: &str, version: &str, domain_size: u64, unreachable: &[&str]) -> String {
+            json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_VALIDATOR_INFO,
+                    "identifier": IDENTIFIER,
+                    "reqId": 1,
+                    "data": {
+                        "timestamp": 1613736202,
+                        "Pool_info": {
+                            "Unreachable_nodes": unreachable.iter().map(|node| json!([node, null])).collect::<Vec<Value>>(),
+                        },
+                        "Node_info": {
+                            "Name": node,
+                            "Mode": "participating",
+                            "Metrics": {
+                                "uptime": 3600,
+                                "transaction-count": {"ledger": domain_size, "pool": 4, "config": 0, "audit": 10},
+                            },
+                            "View_change_status": {"View_No": 0, "VC_in_progress": false},
+                            "Catchup_status": {"Ledger_statuses": {"0": "synced", "1": "synced", "2": "synced", "3": "synced"}},
+                            "Freshness_status": {"1": {"Last_updated_time": "2021-02-19 12:03:22+00:00", "Has_write_consensus": true}},
+                        },
+                        "Software": {"indy-node": version, "sovrin": "unknown"},
+                    }
+                }
+            })
+            .to_string()
+        }
+
+        #[test]
+        fn parse_get_validator_info_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "Node1": _validator_info_reply("Node1", "1.12.4", 10, &[]),
+                "Node2": _validator_info_reply("Node2", "1.12.4", 10, &[]),
+            })
+            .to_string();
+
+            let validator_info = ledger_service
+                .parse_get_validator_info_response(&response)
+                .unwrap();
+            let validator_info: ValidatorInfo = serde_json::from_str(&validator_info).unwrap();
+
+            assert_eq!(2, validator_info.nodes.len());
+
+            let node = &validator_info.nodes[0];
+            assert_eq!("Node1", node.node);
+            assert_eq!(NodeValidatorInfoStatus::Ok, node.status);
+            assert_eq!(Some("1.12.4".to_string()), node.indy_node_version);
+            assert_eq!(Some(3600), node.uptime);
+            assert_eq!(Some(0), node.view_no);
+            assert_eq!(Some(&10), node.ledger_sizes.get("domain"));
+            assert_eq!(Some(&"synced".to_string()), node.catchup_status.get("domain"));
+            assert_eq!(Some(true), node.freshness["domain"].has_write_consensus);
+
+            assert_eq!(vec!["Node1", "Node2"], validator_info.summary.replied_nodes);
+            assert!(validator_info.summary.inconsistencies.is_empty());
+        }
+
+        #[test]
+        fn parse_get_validator_info_response_works_for_inconsistencies() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "Node1": _validator_info_reply("Node1", "1.12.4", 10, &["Node4"]),
+                "Node2": _validator_info_reply("Node2", "1.12.3", 12, &["Node4"]),
+                "Node3": r#"{"op":"REQNACK","reqId":1,"reason":"client request invalid"}"#,
+                "Node4": "timeout",
+            })
+            .to_string();
+
+            let validator_info = ledger_service
+                .parse_get_validator_info_response(&response)
+                .unwrap();
+            let validator_info: ValidatorInfo = serde_json::from_str(&validator_info).unwrap();
+            let summary = validator_info.summary;
+
+            assert_eq!(4, summary.nodes_count);
+            assert_eq!(vec!["Node1", "Node2"], summary.replied_nodes);
+            assert_eq!(vec!["Node3"], summary.failed_nodes);
+            assert_eq!(vec!["Node4"], summary.timeout_nodes);
+            assert_eq!(2, summary.indy_node_versions.len());
+            assert_eq!(2, summary.ledger_sizes["domain"].len());
+            assert_eq!(vec!["Node1", "Node2"], summary.unreachable_nodes["Node4"]);
+
+            assert_eq!(
+                vec![
+                    "Nodes run different indy-node versions: 1.12.3 (Node2), 1.12.4 (Node1)",
+                    "Nodes have different domain ledger sizes: 10 (Node1), 12 (Node2)",
+                    "Node4 is unreachable from Node1, Node2",
+                ],
+                summary.inconsistencies[0..3].to_vec()
+            );
+            assert_eq!(5, summary.inconsistencies.len());
+        }
+
+        #[test]
+        fn parse_get_validator_info_response_works_for_single_reply() {
+            let ledger_service = LedgerService::new();
+
+            let validator_info = ledger_service
+                .parse_get_validator_info_response(&_validator_info_reply("Node1", "1.12.4", 10, &[]))
+                .unwrap();
+            let validator_info: ValidatorInfo = serde_json::from_str(&validator_info).unwrap();
+
+            assert_eq!(1, validator_info.nodes.len());
+            assert_eq!("Node1", validator_info.nodes[0].node);
+        }
+
+        #[test]
+        fn parse_get_validator_info_response_works_for_invalid_json() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.parse_get_validator_info_response("[]");
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+    }
+
+    mod ledgers_freeze {
+        use super::*;
+
+        #[test]
+        fn build_ledgers_freeze_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": LEDGERS_FREEZE,
+                "ledgers_ids": [0, 1, 28],
+            });
+
+            let request = ledger_service
+                .build_ledgers_freeze_request(&identifier(), vec![0, 1, 28])
+                .unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_get_frozen_ledgers_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_FROZEN_LEDGERS,
+            });
+
+            let request = ledger_service
+                .build_get_frozen_ledgers_request(Some(&identifier()))
+                .unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn parse_get_frozen_ledgers_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_FROZEN_LEDGERS,
+                    "seqNo": 5,
+                    "txnTime": 1613736202,
+                    "data": {
+                        "28": {
+                            "ledger": "GKot5hBsd81kMupNCXHaqbhv3huEbxAFMLnpcX2hniwn",
+                            "state": "DqQ7G4fgDHBfdfVLrE6DCdYyyED1fY5oKLh5iLNV6Lsk",
+                            "seq_no": 12
+                        }
+                    }
+                }
+            })
+            .to_string();
+
+            let frozen_ledgers = ledger_service
+                .parse_get_frozen_ledgers_response(&response)
+                .unwrap();
+            let frozen_ledgers: FrozenLedgers = serde_json::from_str(&frozen_ledgers).unwrap();
+
+            assert_eq!(1, frozen_ledgers.len());
+            assert_eq!(12, frozen_ledgers["28"].seq_no);
+        }
+
+        #[test]
+        fn parse_get_frozen_ledgers_response_works_for_no_frozen_ledgers() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_FROZEN_LEDGERS,
+                    "seqNo": null,
+                    "txnTime": null,
+                    "data": null
+                }
+            })
+            .to_string();
+
+            let frozen_ledgers = ledger_service
+                .parse_get_frozen_ledgers_response(&response)
+                .unwrap();
+
+            assert_eq!("{}", frozen_ledgers);
+        }
+    }
+
+    mod rich_schema {
+        use super::*;
+
+        use indy_api_types::validation::Validatable;
+
+        const RS_ID: &str = "did:sov:8a9eb2T5x9SzFyRoWXRgUh";
+        const RS_NAME: &str = "SimpleRichSchema";
+        const RS_VERSION: &str = "1.0";
+        const DEFAULT_RICH_SCHEMA_VERSION: &str = "1";
+
+        fn _rich_schema(rs_type: RichSchemaType, content: serde_json::Value) -> RichSchema {
+            RichSchema::new(
+                RS_ID.to_string(),
+                content.to_string(),
+                RS_NAME.to_string(),
+                RS_VERSION.to_string(),
+                rs_type,
+                DEFAULT_RICH_SCHEMA_VERSION.to_string(),
+            )
+        }
+
+        fn _schema_content() -> serde_json::Value {
+            json!({"@id": RS_ID, "@type": "rdfs:Class", "@context": "ctx:sov:2f9F8ZmxuvDqRiqqY29x6dx9oU4qwFTkPbDpWtwGbdUsrCD"})
+        }
+
+        #[test]
+        fn build_rich_schema_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": SET_RICH_SCHEMA,
+                "id": RS_ID,
+                "content": _schema_content().to_string(),
+                "rsName": RS_NAME,
+                "rsVersion": RS_VERSION,
+                "rsType": "sch",
+                "ver": "1",
+            });
+
+            let request = ledger_service
+                .build_rich_schema_request(
+                    &identifier(),
+                    _rich_schema(RichSchemaType::Sch, _schema_content()),
+                )
+                .unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_rich_schema_request_works_for_txn_types() {
+            let ledger_service = LedgerService::new();
+
+            for (rs_type, txn_type) in &[
+                (RichSchemaType::Ctx, SET_JSON_LD_CONTEXT),
+                (RichSchemaType::Enc, SET_RICH_SCHEMA_ENCODING),
+                (RichSchemaType::Map, SET_RICH_SCHEMA_MAPPING),
+                (RichSchemaType::Cdf, SET_RICH_SCHEMA_CRED_DEF),
+                (RichSchemaType::Pdf, SET_RICH_SCHEMA_PRES_DEF),
+            ] {
+                let request = ledger_service
+                    .build_rich_schema_request(&identifier(), _rich_schema(*rs_type, json!({})))
+                    .unwrap();
+
+                let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+                assert_eq!(json!(txn_type), request["operation"]["type"]);
+            }
+        }
+
+        #[test]
+        fn build_get_rich_schema_object_by_id_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_RICH_SCHEMA_OBJECT_BY_ID,
+                "id": RS_ID,
+            });
+
+            let request = ledger_service
+                .build_get_rich_schema_object_by_id_request(Some(&identifier()), RS_ID)
+                .unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_get_rich_schema_object_by_metadata_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_RICH_SCHEMA_OBJECT_BY_METADATA,
+                "rsType": "sch",
+                "rsName": RS_NAME,
+                "rsVersion": RS_VERSION,
+            });
+
+            let request = ledger_service
+                .build_get_rich_schema_object_by_metadata_request(
+                    Some(&identifier()),
+                    RichSchemaType::Sch,
+                    RS_NAME,
+                    RS_VERSION,
+                )
+                .unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn parse_get_rich_schema_object_response_works() {
+            let ledger_service = LedgerService::new();
+
+            for txn_type in &[GET_RICH_SCHEMA_OBJECT_BY_ID, GET_RICH_SCHEMA_OBJECT_BY_METADATA] {
+                let response = json!({
+                    "op": "REPLY",
+                    "result": {
+                        "type": txn_type,
+                        "seqNo": 10,
+                        "txnTime": 1613736202,
+                        "data": {
+                            "id": RS_ID,
+                            "content": _schema_content().to_string(),
+                            "rsName": RS_NAME,
+                            "rsVersion": RS_VERSION,
+                            "rsType": "sch",
+                            "ver": "1",
+                            "from": IDENTIFIER
+                        }
+                    }
+                })
+                .to_string();
+
+                let rich_schema_object = ledger_service
+                    .parse_get_rich_schema_object_response(&response)
+                    .unwrap();
+                let rich_schema_object: serde_json::Value =
+                    serde_json::from_str(&rich_schema_object).unwrap();
+
+                assert_eq!(json!(RS_ID), rich_schema_object["id"]);
+                assert_eq!(json!("sch"), rich_schema_object["rsType"]);
+            }
+        }
+
+        #[test]
+        fn parse_get_rich_schema_object_response_works_for_not_found() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_RICH_SCHEMA_OBJECT_BY_ID,
+                    "seqNo": null,
+                    "txnTime": null,
+                    "data": null
+                }
+            })
+            .to_string();
+
+            let res = ledger_service.parse_get_rich_schema_object_response(&response);
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+
+        #[test]
+        fn rich_schema_validate_works() {
+            _rich_schema(RichSchemaType::Sch, _schema_content())
+                .validate()
+                .unwrap();
+
+            _rich_schema(RichSchemaType::Ctx, json!({"@context": {"name": "http://schema.org/name"}}))
+                .validate()
+                .unwrap();
+        }
+
+        #[test]
+        fn rich_schema_validate_works_for_invalid_content() {
+            _rich_schema(RichSchemaType::Sch, json!({"@id": "other", "@type": "rdfs:Class"}))
+                .validate()
+                .unwrap_err();
+
+            _rich_schema(RichSchemaType::Ctx, json!({"name": "http://schema.org/name"}))
+                .validate()
+                .unwrap_err();
+
+            let mut rich_schema = _rich_schema(RichSchemaType::Enc, json!({}));
+            rich_schema.content = "not a json".to_string();
+            rich_schema.validate().unwrap_err();
+        }
+    }
+
     #[test]
     fn datetime_to_date() {
         assert_eq!(0, LedgerService::datetime_to_date_timestamp(0));
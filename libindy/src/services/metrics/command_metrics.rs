@@ -74,6 +74,7 @@ pub enum CommandMetric {
     LedgerCommandSignAndSubmitRequest,
     // LedgerCommand
     LedgerCommandSubmitRequest,
+    LedgerCommandSubmitRequests,
     LedgerCommandSubmitAck,
     LedgerCommandSubmitAction,
     LedgerCommandSignRequest,
@@ -92,6 +93,7 @@ pub enum CommandMetric {
     LedgerCommandParseGetCredDefResponse,
     LedgerCommandBuildNodeRequest,
     LedgerCommandBuildGetValidatorInfoRequest,
+    LedgerCommandParseGetValidatorInfoResponse,
     LedgerCommandBuildGetTxnRequest,
     LedgerCommandBuildPoolConfigRequest,
     LedgerCommandBuildPoolRestartRequest,
@@ -109,6 +111,7 @@ pub enum CommandMetric {
     LedgerCommandBuildAuthRuleRequest,
     LedgerCommandBuildAuthRulesRequest,
     LedgerCommandBuildGetAuthRuleRequest,
+    LedgerCommandEvaluateAuthRule,
     LedgerCommandGetSchema,
     LedgerCommandGetCredDef,
     LedgerCommandBuildTxnAuthorAgreementRequest,
@@ -117,7 +120,22 @@ pub enum CommandMetric {
     LedgerCommandBuildAcceptanceMechanismRequests,
     LedgerCommandBuildGetAcceptanceMechanismsRequest,
     LedgerCommandAppendTxnAuthorAgreementAcceptanceToRequest,
+    LedgerCommandSetPoolTxnAuthorAgreementAcceptance,
     LedgerCommandAppendRequestEndorser,
+    LedgerCommandBuildEndorsementEnvelope,
+    LedgerCommandSignEndorsementEnvelope,
+    LedgerCommandCheckEndorsementEnvelope,
+    LedgerCommandSubmitEndorsementEnvelope,
+    LedgerCommandListPendingEndorsements,
+    LedgerCommandDeletePendingEndorsement,
+    LedgerCommandBuildLedgersFreezeRequest,
+    LedgerCommandBuildGetFrozenLedgersRequest,
+    LedgerCommandParseGetFrozenLedgersResponse,
+    LedgerCommandBuildRichSchemaRequest,
+    LedgerCommandBuildGetRichSchemaObjectByIdRequest,
+    LedgerCommandBuildGetRichSchemaObjectByMetadataRequest,
+    LedgerCommandParseGetRichSchemaObjectResponse,
+    LedgerCommandDownloadLedger,
     // CheqdLedger - Cheqd Module
     CheqdLedgerCommandBuildMsgCreateNym,
     CheqdLedgerCommandBuildMsgUpdateNym,
@@ -131,6 +149,7 @@ pub enum CommandMetric {
     CheqdLedgerCommandParseQueryAllNymResp,
     // CheqdLedger - Auth Module
     CheqdLedgerCommandBuildTx,
+    CheqdLedgerCommandBuildTxWithFeeGranter,
     CheqdLedgerCommandBuildQueryCosmosAuthAccount,
     CheqdLedgerCommandParseQueryCosmosAuthAccountResp,
     // CheqdLedger - Bank Module
@@ -138,6 +157,54 @@ pub enum CommandMetric {
     CheqdLedgerCommandParseMsgSendResp,
     CheqdLedgerCommandBuildQueryBalance,
     CheqdLedgerCommandParseQueryBalanceResp,
+    // CheqdLedger - Staking Module
+    CheqdLedgerCommandBuildMsgDelegate,
+    CheqdLedgerCommandParseMsgDelegateResp,
+    CheqdLedgerCommandBuildMsgUndelegate,
+    CheqdLedgerCommandParseMsgUndelegateResp,
+    CheqdLedgerCommandBuildMsgBeginRedelegate,
+    CheqdLedgerCommandParseMsgBeginRedelegateResp,
+    CheqdLedgerCommandBuildQueryDelegation,
+    CheqdLedgerCommandParseQueryDelegationResp,
+    CheqdLedgerCommandBuildQueryDelegatorDelegations,
+    CheqdLedgerCommandParseQueryDelegatorDelegationsResp,
+    CheqdLedgerCommandBuildQueryValidators,
+    CheqdLedgerCommandParseQueryValidatorsResp,
+    // CheqdLedger - Distribution Module
+    CheqdLedgerCommandBuildMsgWithdrawDelegatorReward,
+    CheqdLedgerCommandParseMsgWithdrawDelegatorRewardResp,
+    CheqdLedgerCommandBuildQueryDelegationTotalRewards,
+    CheqdLedgerCommandParseQueryDelegationTotalRewardsResp,
+    // CheqdLedger - Gov Module
+    CheqdLedgerCommandBuildMsgSubmitTextProposal,
+    CheqdLedgerCommandBuildMsgSubmitParamChangeProposal,
+    CheqdLedgerCommandParseMsgSubmitProposalResp,
+    CheqdLedgerCommandBuildMsgDeposit,
+    CheqdLedgerCommandParseMsgDepositResp,
+    CheqdLedgerCommandBuildMsgVote,
+    CheqdLedgerCommandParseMsgVoteResp,
+    CheqdLedgerCommandBuildMsgVoteWeighted,
+    CheqdLedgerCommandParseMsgVoteWeightedResp,
+    CheqdLedgerCommandBuildQueryProposal,
+    CheqdLedgerCommandParseQueryProposalResp,
+    CheqdLedgerCommandBuildQueryProposals,
+    CheqdLedgerCommandParseQueryProposalsResp,
+    CheqdLedgerCommandBuildQueryTallyResult,
+    CheqdLedgerCommandParseQueryTallyResultResp,
+    CheqdLedgerCommandBuildQueryVote,
+    CheqdLedgerCommandParseQueryVoteResp,
+    // CheqdLedger - Feegrant Module
+    CheqdLedgerCommandBuildMsgGrantAllowance,
+    CheqdLedgerCommandParseMsgGrantAllowanceResp,
+    CheqdLedgerCommandBuildMsgRevokeAllowance,
+    CheqdLedgerCommandParseMsgRevokeAllowanceResp,
+    // CheqdLedger - Authz Module
+    CheqdLedgerCommandBuildMsgGrant,
+    CheqdLedgerCommandParseMsgGrantResp,
+    CheqdLedgerCommandBuildMsgExec,
+    CheqdLedgerCommandParseMsgExecResp,
+    CheqdLedgerCommandBuildMsgRevoke,
+    CheqdLedgerCommandParseMsgRevokeResp,
     // CheqdPool
     CheqdPoolCommandAdd,
     CheqdPoolCommandGetConfig,
@@ -145,6 +212,11 @@ pub enum CommandMetric {
     CheqdPoolCommandBroadcastTxCommit,
     CheqdPoolCommandAbciQuery,
     CheqdPoolCommandAbciInfo,
+    CheqdPoolCommandStatus,
+    CheqdPoolCommandBlock,
+    CheqdPoolCommandBlockResults,
+    CheqdPoolCommandValidators,
+    CheqdPoolCommandNetInfo,
     // CheqdKeys
     CheqdKeysAddRandom,
     CheqdKeysAddFromMnemonic,
@@ -162,6 +234,12 @@ pub enum CommandMetric {
     PoolCommandRefresh,
     PoolCommandRefreshAck,
     PoolCommandSetProtocolVersion,
+    PoolCommandGetPoolHandleForNamespace,
+    PoolCommandGetStatus,
+    PoolCommandUpdateGenesis,
+    PoolCommandVerifyLedgerReply,
+    PoolCommandCreateSimulated,
+    PoolCommandExportSnapshot,
     // DidCommand
     DidCommandCreateAndStoreMyDid,
     DidCommandReplaceKeysStart,
@@ -179,6 +257,7 @@ pub enum CommandMetric {
     DidCommandGetNymAck,
     DidCommandGetAttribAck,
     DidCommandQualifyDid,
+    DidCommandResolveDid,
     // WalletCommand
     WalletCommandRegisterWalletType,
     WalletCommandCreate,
@@ -202,6 +281,7 @@ pub enum CommandMetric {
     NonSecretsCommandAddRecordTags,
     NonSecretsCommandDeleteRecordTags,
     NonSecretsCommandDeleteRecord,
+    NonSecretsCommandBatch,
     NonSecretsCommandGetRecord,
     NonSecretsCommandOpenSearch,
     NonSecretsCommandFetchSearchNextRecords,
@@ -246,6 +326,12 @@ pub enum CommandMetric {
     CacheCommandGetCredDef,
     CacheCommandPurgeSchemaCache,
     CacheCommandPurgeCredDefCache,
+    CacheCommandGetNym,
+    CacheCommandGetRevocRegDef,
+    CacheCommandGetRevocRegDelta,
+    CacheCommandPurgeNymCache,
+    CacheCommandPurgeRevocRegDefCache,
+    CacheCommandPurgeRevocRegDeltaCache,
     // MetricsCommand
     MetricsCommandCollectMetrics,
     // Exit
@@ -23,11 +23,15 @@ pub(crate) use ledger::LedgerService;
 pub(crate) use metrics::MetricsService;
 pub(crate) use metrics::command_metrics::CommandMetric;
 pub(crate) use payments::{PaymentsService, PaymentsMethodCBs, RequesterInfo, Fees};
-pub(crate) use pool::PoolService;
+pub(crate) use pool::{parse_get_txn_data, CustomStateProofParser, LedgerMirror, PoolService};
+pub use pool::{
+    KeyValueSimpleData, KeyValueSimpleDataVerificationType, KeyValuesInSP, KeyValuesSubTrieData,
+    NumericalSuffixAscendingNoGapsData, ParsedSP, StateProofParser,
+};
 #[cfg(feature = "cheqd")]
 pub(crate) use cheqd_keys::CheqdKeysService;
 #[cfg(feature = "cheqd")]
-pub(crate) use cheqd_ledger::CheqdLedgerService;
+pub(crate) use cheqd_ledger::{CheqdLedgerService, CHEQD_PAYMENT_METHOD};
 #[cfg(feature = "cheqd")]
 pub(crate) use cheqd_pool::CheqdPoolService;
 pub(crate) use wallet::WalletService;
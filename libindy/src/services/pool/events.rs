@@ -5,9 +5,10 @@ use crate::domain::ledger::constants;
 use indy_api_types::errors::prelude::*;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
 use crate::services::pool::types::*;
+use crate::services::pool::PoolService;
 use indy_api_types::CommandHandle;
 
-pub const REQUESTS_FOR_STATE_PROOFS: [&str; 11] = [
+pub const REQUESTS_FOR_STATE_PROOFS: [&str; 14] = [
     constants::GET_NYM,
     constants::GET_TXN_AUTHR_AGRMT,
     constants::GET_TXN_AUTHR_AGRMT_AML,
@@ -19,6 +20,9 @@ pub const REQUESTS_FOR_STATE_PROOFS: [&str; 11] = [
     constants::GET_REVOC_REG_DELTA,
     constants::GET_AUTH_RULE,
     constants::GET_TXN,
+    constants::GET_FROZEN_LEDGERS,
+    constants::GET_RICH_SCHEMA_OBJECT_BY_ID,
+    constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA,
 ];
 
 const REQUEST_FOR_FULL: [&str; 2] = [
@@ -66,6 +70,14 @@ pub enum NetworkerEvent {
         String, //req_id
         Option<String>, //node_alias
     ),
+    NodeReplied(
+        String, //req_id
+        String, //node_alias
+    ),
+    NodeTimedOut(
+        String, //req_id
+        String, //node_alias
+    ),
     Timeout,
 }
 
@@ -245,9 +257,8 @@ impl Into<Option<RequestEvent>> for PoolEvent {
                         let key = super::state_proof::parse_key_from_request_for_builtin_sp(&req);
                         let timestamps = _parse_timestamp_from_req_for_builtin_sp(req, &op);
                         Some(RequestEvent::CustomSingleRequest(msg, req_id.clone(), key, timestamps))
-                    // FIXME: Do we need custom parsers???
-                    // } else if PoolService::get_sp_parser(&op.as_str()).is_some() {
-                    //     Some(RequestEvent::CustomSingleRequest(msg, req_id.clone(), None, (None, None)))
+                    } else if PoolService::get_sp_parser(&op.as_str()).is_some() {
+                        Some(RequestEvent::CustomSingleRequest(msg, req_id.clone(), None, (None, None)))
                     } else {
                         Some(RequestEvent::CustomConsensusRequest(msg, req_id.clone()))
                     }
@@ -0,0 +1,478 @@
+use std::fs;
+use std::io::{self, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+
+use indy_api_types::errors::prelude::*;
+use indy_utils::crypto::hash::Hash;
+use rust_base58::{FromBase58, ToBase58};
+use serde_json;
+use serde_json::Value as SJsonValue;
+
+use crate::services::ledger::merkletree::merkletree::MerkleTree;
+use crate::utils::environment;
+
+use super::state_proof::calculate_turns;
+
+const LEDGERS_DIR: &str = "ledgers";
+const LEDGER_EXT: &str = "txn";
+
+/// Local copy of a pool ledger downloaded with GET_TXN requests.
+///
+/// Transactions are stored one json per line in `<pool_path>/ledgers/<ledger_id>.txn`
+/// in the same form they are hashed into the ledger merkle tree:
+/// {"txn": .., "txnMetadata": .., "ver": .., "reqSignature": ..}
+pub(crate) struct LedgerMirror {
+    path: PathBuf,
+    // Length of the file part holding the stored transactions
+    len: u64,
+    merkle_tree: MerkleTree,
+}
+
+impl LedgerMirror {
+    pub(crate) fn open(pool_name: &str, ledger_id: i32) -> IndyResult<LedgerMirror> {
+        let path = _get_mirror_path(pool_name, ledger_id);
+
+        let mut merkle_tree = MerkleTree::from_vec(Vec::new())?;
+        let mut len = 0;
+
+        if path.exists() {
+            trace!("Restoring ledger copy from {:?}", path);
+
+            let data = fs::read(&path)
+                .to_indy(IndyErrorKind::IOError, "Can't read from ledger copy file")?;
+
+            // The last line may be torn by an interrupted write, it is dropped and downloaded again
+            while let Some(pos) = data[len..].iter().position(|b| *b == b'\n') {
+                let end = len + pos + 1;
+                let line = &data[len..end];
+
+                if !line.iter().all(u8::is_ascii_whitespace) {
+                    match serde_json::from_slice::<SJsonValue>(line) {
+                        Ok(txn) => merkle_tree.append(_txn_to_leaf(&txn)?)?,
+                        Err(_) if end == data.len() => break,
+                        Err(err) => return Err(err).to_indy(IndyErrorKind::InvalidState, "Malformed ledger copy file"),
+                    }
+                }
+
+                len = end;
+            }
+
+            if len < data.len() {
+                warn!("Dropping incomplete last line of ledger copy {:?}", path);
+
+                let file = fs::OpenOptions::new()
+                    .write(true)
+                    .open(&path)
+                    .to_indy(IndyErrorKind::IOError, "Can't open ledger copy file")?;
+
+                file.set_len(len as u64)
+                    .and_then(|_| file.sync_all())
+                    .to_indy(IndyErrorKind::IOError, "Can't truncate ledger copy file")?;
+            }
+        }
+
+        Ok(LedgerMirror { path, len: len as u64, merkle_tree })
+    }
+
+    pub(crate) fn count(&self) -> u64 {
+        self.merkle_tree.count() as u64
+    }
+
+    pub(crate) fn root_hash(&self) -> String {
+        self.merkle_tree.root_hash().to_base58()
+    }
+
+    pub(crate) fn path(&self) -> String {
+        self.path.to_string_lossy().into_owned()
+    }
+
+    /// Verifies the batch of GET_TXN reply data (starting with the next seq_no) and stores it.
+    ///
+    /// Every transaction is checked against the audit path of the reply and the local
+    /// copy extended with the batch must match the ledger prefix proven by the last one.
+    /// Nothing is stored if any check fails.
+    pub(crate) fn append_batch(&mut self, batch: &[SJsonValue]) -> IndyResult<()> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        let mut merkle_tree = self.merkle_tree.clone();
+        let mut txns = Vec::with_capacity(batch.len());
+        let mut prefix_root_hash = Vec::new();
+
+        for data in batch {
+            let seq_no = merkle_tree.count() as u64 + 1;
+
+            if let Some(txn_seq_no) = data["txnMetadata"]["seqNo"].as_u64() {
+                if txn_seq_no != seq_no {
+                    return Err(err_msg(IndyErrorKind::InvalidState,
+                                       format!("Unexpected transaction seqNo {}, expected {}", txn_seq_no, seq_no)));
+                }
+            }
+
+            let txn = _txn_from_get_txn_data(data);
+            let leaf = _txn_to_leaf(&txn)?;
+
+            prefix_root_hash = _verify_audit_path(&Hash::hash_leaf(&leaf)?, seq_no, data)?;
+
+            merkle_tree.append(leaf)?;
+            txns.push(txn);
+        }
+
+        if merkle_tree.root_hash() != &prefix_root_hash {
+            return Err(err_msg(IndyErrorKind::InvalidState,
+                               "Local ledger copy is not consistent with the pool ledger"));
+        }
+
+        self._store(&txns)?;
+        self.merkle_tree = merkle_tree;
+
+        Ok(())
+    }
+
+    /// Writes the batch after the stored transactions and syncs it to disk.
+    /// A partially written batch is cut off, so the file always holds whole batches only.
+    fn _store(&mut self, txns: &[SJsonValue]) -> IndyResult<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)
+                .to_indy(IndyErrorKind::IOError, "Can't create ledger copy directory")?;
+        }
+
+        let mut data = Vec::new();
+
+        for txn in txns {
+            writeln!(data, "{}", txn)
+                .to_indy(IndyErrorKind::IOError, "Can't write to ledger copy file")?;
+        }
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .open(&self.path)
+            .to_indy(IndyErrorKind::IOError, "Can't open ledger copy file")?;
+
+        if let Err(err) = _write_at(&mut file, self.len, &data) {
+            let _ = file.set_len(self.len).and_then(|_| file.sync_all());
+            return Err(err).to_indy(IndyErrorKind::IOError, "Can't write to ledger copy file");
+        }
+
+        self.len += data.len() as u64;
+
+        Ok(())
+    }
+}
+
+/// Returns `data` of GET_TXN reply or None if the requested transaction doesn't exist.
+pub(crate) fn parse_get_txn_data(reply: &str) -> IndyResult<Option<SJsonValue>> {
+    let reply: SJsonValue = serde_json::from_str(reply)
+        .to_indy(IndyErrorKind::InvalidTransaction, "Response is invalid json")?;
+
+    if reply["op"].as_str() != Some("REPLY") {
+        return Err(err_msg(IndyErrorKind::InvalidTransaction,
+                           format!("Unexpected GET_TXN response: {}", reply)));
+    }
+
+    match reply["result"]["data"] {
+        SJsonValue::Null => Ok(None),
+        SJsonValue::String(ref data) => serde_json::from_str(data)
+            .map(Some)
+            .to_indy(IndyErrorKind::InvalidTransaction, "GET_TXN response data is invalid json"),
+        ref data => Ok(Some(data.clone())),
+    }
+}
+
+fn _write_at(file: &mut fs::File, offset: u64, data: &[u8]) -> io::Result<()> {
+    file.seek(SeekFrom::Start(offset))?;
+    file.write_all(data)?;
+    file.set_len(offset + data.len() as u64)?;
+    file.sync_all()
+}
+
+fn _get_mirror_path(pool_name: &str, ledger_id: i32) -> PathBuf {
+    let mut path = environment::pool_path(pool_name);
+    path.push(LEDGERS_DIR);
+    path.push(ledger_id.to_string());
+    path.set_extension(LEDGER_EXT);
+    path
+}
+
+fn _txn_from_get_txn_data(data: &SJsonValue) -> SJsonValue {
+    let mut txn = json!({});
+
+    for field in &["txn", "txnMetadata", "ver", "reqSignature"] {
+        if !data[field].is_null() {
+            txn[field] = data[field].clone();
+        }
+    }
+
+    txn
+}
+
+fn _txn_to_leaf(txn: &SJsonValue) -> IndyResult<Vec<u8>> {
+    rmp_serde::to_vec(txn)
+        .to_indy(IndyErrorKind::InvalidState, "Can't encode transaction as message pack")
+}
+
+/// Checks the audit path of GET_TXN reply data and returns the root hash
+/// of the ledger prefix ending with the transaction (built from the left siblings only).
+fn _verify_audit_path(leaf_hash: &[u8], seq_no: u64, data: &SJsonValue) -> IndyResult<Vec<u8>> {
+    let ledger_size = data["ledgerSize"].as_u64()
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidTransaction, "GET_TXN response has no ledgerSize"))?;
+
+    let root_hash = data["rootHash"].as_str()
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidTransaction, "GET_TXN response has no rootHash"))?
+        .from_base58()
+        .map_err(|_| err_msg(IndyErrorKind::InvalidTransaction, "Can't decode GET_TXN rootHash"))?;
+
+    let audit_path = data["auditPath"].as_array()
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidTransaction, "GET_TXN response has no auditPath"))?
+        .iter()
+        .map(|hash| hash.as_str()
+            .and_then(|hash| hash.from_base58().ok())
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidTransaction, "Can't decode GET_TXN auditPath")))
+        .collect::<IndyResult<Vec<Vec<u8>>>>()?;
+
+    if seq_no == 0 || seq_no > ledger_size {
+        return Err(err_msg(IndyErrorKind::InvalidState,
+                           format!("Transaction {} is out of the ledger of size {}", seq_no, ledger_size)));
+    }
+
+    let turns = calculate_turns(ledger_size, seq_no - 1);
+
+    if turns.len() != audit_path.len() {
+        return Err(err_msg(IndyErrorKind::InvalidState,
+                           format!("Audit proof verification failed for transaction {}", seq_no)));
+    }
+
+    let mut hash = leaf_hash.to_vec();
+    let mut prefix_hash = leaf_hash.to_vec();
+
+    for (node, turn_right) in audit_path.iter().zip(turns) {
+        if turn_right {
+            hash = Hash::hash_nodes(&hash, node)?;
+        } else {
+            hash = Hash::hash_nodes(node, &hash)?;
+            prefix_hash = Hash::hash_nodes(node, &prefix_hash)?;
+        }
+    }
+
+    if hash != root_hash {
+        return Err(err_msg(IndyErrorKind::InvalidState,
+                           format!("Audit proof verification failed for transaction {}", seq_no)));
+    }
+
+    Ok(prefix_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::test;
+
+    use super::*;
+
+    fn _txns(count: u64) -> Vec<SJsonValue> {
+        (1..=count)
+            .map(|seq_no| json!({
+                "txn": {"type": "1", "data": {"dest": format!("did{}", seq_no)}},
+                "txnMetadata": {"seqNo": seq_no},
+                "ver": "1",
+                "reqSignature": {}
+            }))
+            .collect()
+    }
+
+    // Builds GET_TXN reply data for every transaction against the ledger of all given txns.
+    fn _get_txn_data(txns: &[SJsonValue]) -> Vec<SJsonValue> {
+        let leaves = txns.iter().map(|txn| _txn_to_leaf(txn).unwrap()).collect::<Vec<_>>();
+        let ledger = MerkleTree::from_vec(leaves.clone()).unwrap();
+        let ledger_size = leaves.len() as u64;
+
+        txns.iter().enumerate()
+            .map(|(idx, txn)| {
+                let audit_path = _audit_path(&leaves, idx, leaves.len())
+                    .iter()
+                    .map(|hash| hash.to_base58())
+                    .collect::<Vec<_>>();
+
+                let mut data = txn.clone();
+                data["auditPath"] = json!(audit_path);
+                data["rootHash"] = json!(ledger.root_hash().to_base58());
+                data["ledgerSize"] = json!(ledger_size);
+                data
+            })
+            .collect()
+    }
+
+    // RFC 6962 audit path (leaf to root order).
+    fn _audit_path(leaves: &[Vec<u8>], idx: usize, size: usize) -> Vec<Vec<u8>> {
+        if size <= 1 {
+            return vec![];
+        }
+        let k = size.next_power_of_two() / 2;
+        if idx < k {
+            let mut path = _audit_path(&leaves[..k], idx, k);
+            path.push(_subtree_hash(&leaves[k..size]));
+            path
+        } else {
+            let mut path = _audit_path(&leaves[k..size], idx - k, size - k);
+            path.push(_subtree_hash(&leaves[..k]));
+            path
+        }
+    }
+
+    fn _subtree_hash(leaves: &[Vec<u8>]) -> Vec<u8> {
+        MerkleTree::from_vec(leaves.to_vec()).unwrap().root_hash().clone()
+    }
+
+    #[test]
+    fn ledger_mirror_append_batch_works() {
+        test::cleanup_storage("ledger_mirror_append_batch_works");
+
+        let txns = _txns(7);
+        let data = _get_txn_data(&txns);
+
+        let mut mirror = LedgerMirror::open("ledger_mirror_append_batch_works", 1).unwrap();
+        assert_eq!(0, mirror.count());
+
+        mirror.append_batch(&data[..3]).unwrap();
+        mirror.append_batch(&data[3..]).unwrap();
+
+        assert_eq!(7, mirror.count());
+        assert_eq!(data[0]["rootHash"].as_str().unwrap(), mirror.root_hash());
+
+        test::cleanup_storage("ledger_mirror_append_batch_works");
+    }
+
+    #[test]
+    fn ledger_mirror_open_works_for_resume() {
+        test::cleanup_storage("ledger_mirror_open_works_for_resume");
+
+        let txns = _txns(5);
+        let data = _get_txn_data(&txns);
+
+        {
+            let mut mirror = LedgerMirror::open("ledger_mirror_open_works_for_resume", 1).unwrap();
+            mirror.append_batch(&data[..2]).unwrap();
+        }
+
+        let mut mirror = LedgerMirror::open("ledger_mirror_open_works_for_resume", 1).unwrap();
+        assert_eq!(2, mirror.count());
+
+        mirror.append_batch(&data[2..]).unwrap();
+        assert_eq!(data[0]["rootHash"].as_str().unwrap(), mirror.root_hash());
+
+        test::cleanup_storage("ledger_mirror_open_works_for_resume");
+    }
+
+    #[test]
+    fn ledger_mirror_open_works_for_torn_last_line() {
+        test::cleanup_storage("ledger_mirror_open_works_for_torn_last_line");
+
+        let txns = _txns(5);
+        let data = _get_txn_data(&txns);
+
+        let path = {
+            let mut mirror = LedgerMirror::open("ledger_mirror_open_works_for_torn_last_line", 1).unwrap();
+            mirror.append_batch(&data[..2]).unwrap();
+            mirror.path()
+        };
+
+        let stored_len = fs::metadata(&path).unwrap().len();
+
+        let torn_txn = txns[2].to_string();
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        write!(file, "{}\n{}", torn_txn, &torn_txn[..torn_txn.len() / 2]).unwrap();
+        drop(file);
+
+        {
+            let mirror = LedgerMirror::open("ledger_mirror_open_works_for_torn_last_line", 1).unwrap();
+            assert_eq!(3, mirror.count());
+        }
+
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        writeln!(file, "{}", &torn_txn[..torn_txn.len() / 2]).unwrap();
+        drop(file);
+
+        let mut mirror = LedgerMirror::open("ledger_mirror_open_works_for_torn_last_line", 1).unwrap();
+        assert_eq!(3, mirror.count());
+        assert_eq!(stored_len + torn_txn.len() as u64 + 1, fs::metadata(&path).unwrap().len());
+
+        mirror.append_batch(&data[3..]).unwrap();
+        assert_eq!(5, mirror.count());
+        assert_eq!(data[0]["rootHash"].as_str().unwrap(), mirror.root_hash());
+
+        let mirror = LedgerMirror::open("ledger_mirror_open_works_for_torn_last_line", 1).unwrap();
+        assert_eq!(5, mirror.count());
+
+        test::cleanup_storage("ledger_mirror_open_works_for_torn_last_line");
+    }
+
+    #[test]
+    fn ledger_mirror_open_works_for_malformed_line() {
+        test::cleanup_storage("ledger_mirror_open_works_for_malformed_line");
+
+        let txns = _txns(2);
+        let data = _get_txn_data(&txns);
+
+        let path = {
+            let mut mirror = LedgerMirror::open("ledger_mirror_open_works_for_malformed_line", 1).unwrap();
+            mirror.append_batch(&data[..1]).unwrap();
+            mirror.path()
+        };
+
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        writeln!(file, "malformed").unwrap();
+        writeln!(file, "{}", txns[1]).unwrap();
+        drop(file);
+
+        let res = LedgerMirror::open("ledger_mirror_open_works_for_malformed_line", 1);
+        assert_kind!(IndyErrorKind::InvalidState, res);
+
+        test::cleanup_storage("ledger_mirror_open_works_for_malformed_line");
+    }
+
+    #[test]
+    fn ledger_mirror_append_batch_works_for_invalid_audit_path() {
+        test::cleanup_storage("ledger_mirror_append_batch_works_for_invalid_audit_path");
+
+        let txns = _txns(4);
+        let mut data = _get_txn_data(&txns);
+        data[1]["txn"]["data"]["dest"] = json!("other");
+
+        let mut mirror = LedgerMirror::open("ledger_mirror_append_batch_works_for_invalid_audit_path", 1).unwrap();
+
+        let res = mirror.append_batch(&data);
+        assert_kind!(IndyErrorKind::InvalidState, res);
+        assert_eq!(0, mirror.count());
+
+        test::cleanup_storage("ledger_mirror_append_batch_works_for_invalid_audit_path");
+    }
+
+    #[test]
+    fn ledger_mirror_append_batch_works_for_skipped_txn() {
+        test::cleanup_storage("ledger_mirror_append_batch_works_for_skipped_txn");
+
+        let txns = _txns(4);
+        let data = _get_txn_data(&txns);
+
+        let mut mirror = LedgerMirror::open("ledger_mirror_append_batch_works_for_skipped_txn", 1).unwrap();
+
+        let res = mirror.append_batch(&data[1..]);
+        assert_kind!(IndyErrorKind::InvalidState, res);
+        assert_eq!(0, mirror.count());
+
+        test::cleanup_storage("ledger_mirror_append_batch_works_for_skipped_txn");
+    }
+
+    #[test]
+    fn parse_get_txn_data_works() {
+        let reply = json!({"op": "REPLY", "result": {"data": {"txn": {}}}}).to_string();
+        assert_eq!(Some(json!({"txn": {}})), parse_get_txn_data(&reply).unwrap());
+
+        let reply = json!({"op": "REPLY", "result": {"data": null}}).to_string();
+        assert_eq!(None, parse_get_txn_data(&reply).unwrap());
+
+        let reply = json!({"op": "REQNACK", "reason": "some"}).to_string();
+        assert_kind!(IndyErrorKind::InvalidTransaction, parse_get_txn_data(&reply));
+    }
+}
@@ -6,8 +6,11 @@ use std::path::PathBuf;
 use serde_json;
 use serde_json::Value as SJsonValue;
 
-use crate::domain::ledger::request::ProtocolVersion;
+use failure::Context;
 use indy_api_types::errors::prelude::*;
+use indy_utils::crypto::ed25519_sign;
+use rust_base58::{FromBase58, ToBase58};
+use crate::domain::pool::PoolSnapshot;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
 use crate::services::pool::types::{NodeTransaction, NodeTransactionV0, NodeTransactionV1};
 use crate::utils::environment;
@@ -52,6 +55,123 @@ pub fn drop_cache(pool_name: &str) -> IndyResult<()> {
     }
 }
 
+/// Removes stored pool ledger transactions, if any, so the tree is restored from genesis next time.
+pub fn clear_cache(pool_name: &str) -> IndyResult<()> {
+    let p = get_pool_stored_path(pool_name, false);
+    if p.exists() {
+        fs::remove_file(p)
+            .to_indy(IndyErrorKind::IOError, "Can't remove pool ledger cache file")?;
+    }
+    Ok(())
+}
+
+/// Exports transactions of the local copy of the pool ledger:
+/// caught up ones if pool was opened before and genesis ones otherwise.
+pub fn export_snapshot(pool_name: &str) -> IndyResult<PoolSnapshot> {
+    let merkle_tree = create(pool_name)?;
+
+    let txns = merkle_tree
+        .iter()
+        .map(|txn| {
+            rmp_serde::decode::from_slice(txn.as_slice())
+                .to_indy(IndyErrorKind::InvalidState, "MerkleTree contains invalid item")
+        })
+        .collect::<IndyResult<Vec<SJsonValue>>>()?;
+
+    Ok(PoolSnapshot {
+        ledger_size: merkle_tree.count(),
+        root_hash: merkle_tree.root_hash().to_base58(),
+        txns,
+        signature: None,
+    })
+}
+
+/// Checks that snapshot transactions extend genesis transactions of the pool
+/// and match snapshot root hash, and that the snapshot signature (if any) is valid.
+/// If `verkey` is passed, the snapshot must be signed with it.
+/// Returns the transactions in the pool ledger cache format.
+pub fn check_snapshot(genesis: &MerkleTree, snapshot: &PoolSnapshot, verkey: Option<&str>) -> IndyResult<Vec<Vec<u8>>> {
+    _check_snapshot_signature(snapshot, verkey)?;
+
+    let txns = snapshot
+        .txns
+        .iter()
+        .map(|txn| {
+            rmp_serde::encode::to_vec_named(txn)
+                .to_indy(IndyErrorKind::InvalidStructure, "Can't encode snapshot txn as message pack")
+        })
+        .collect::<IndyResult<Vec<Vec<u8>>>>()?;
+
+    if txns.len() != snapshot.ledger_size {
+        return Err(err_msg(IndyErrorKind::InvalidStructure,
+                           format!("Snapshot size mismatch: expected {}, actual {}", snapshot.ledger_size, txns.len())));
+    }
+
+    if txns.len() < genesis.count() || genesis.iter().zip(txns.iter()).any(|(genesis_txn, txn)| genesis_txn != txn) {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Snapshot doesn't start with pool genesis transactions"));
+    }
+
+    let mut mt = MerkleTree::from_vec(Vec::new())?;
+
+    for txn in txns.iter() {
+        mt.append(txn.clone())?;
+    }
+
+    let root_hash = mt.root_hash().to_base58();
+
+    if root_hash != snapshot.root_hash {
+        return Err(err_msg(IndyErrorKind::InvalidStructure,
+                           format!("Snapshot root hash mismatch: expected {}, actual {}", snapshot.root_hash, root_hash)));
+    }
+
+    Ok(txns)
+}
+
+fn _check_snapshot_signature(snapshot: &PoolSnapshot, verkey: Option<&str>) -> IndyResult<()> {
+    let signature = match (&snapshot.signature, verkey) {
+        (Some(signature), Some(verkey)) if signature.verkey != verkey => {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Snapshot is signed with unexpected verkey: expected {}, actual {}", verkey, signature.verkey)));
+        }
+        (Some(signature), _) => signature,
+        (None, Some(_)) => return Err(err_msg(IndyErrorKind::InvalidStructure, "Snapshot isn't signed")),
+        (None, None) => return Ok(()),
+    };
+
+    let vk = signature.verkey
+        .from_base58()
+        .map_err(Context::new)
+        .to_indy(IndyErrorKind::InvalidStructure, "Can't decode snapshot signer verkey")?;
+
+    let sig = signature.signature
+        .from_base58()
+        .map_err(Context::new)
+        .to_indy(IndyErrorKind::InvalidStructure, "Can't decode snapshot signature")?;
+
+    let valid = ed25519_sign::verify(
+        &ed25519_sign::PublicKey::from_slice(&vk)?,
+        &snapshot.signed_data(),
+        &ed25519_sign::Signature::from_slice(&sig)?,
+    )?;
+
+    if !valid {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Snapshot signature is invalid"));
+    }
+
+    Ok(())
+}
+
+/// Replaces the pool ledger cache with checked snapshot transactions,
+/// so the next opening catches up from them.
+pub fn store_snapshot(pool_name: &str, txns: &[Vec<u8>]) -> IndyResult<()> {
+    let p = get_pool_stored_path(pool_name, false);
+
+    let mut file = fs::File::create(p)
+        .to_indy(IndyErrorKind::IOError, "Can't create pool ledger cache file")?;
+
+    _dump_vec_to_file(txns, &mut file)
+}
+
 fn _from_cache(file_name: &PathBuf) -> IndyResult<MerkleTree> {
     let mut mt = MerkleTree::from_vec(Vec::new())?;
 
@@ -189,6 +309,34 @@ fn _parse_txn_from_json(txn: &str) -> IndyResult<Vec<u8>> {
         .to_indy(IndyErrorKind::InvalidState, "Can't encode genesis txn as message pack")
 }
 
+/// Detects protocol version of the pool from the format of its pool ledger transactions:
+/// 1 for Indy Node 1.3 (flat) transactions and 2 for Indy Node 1.4 and greater ones.
+/// Returns `None` for an empty ledger.
+pub fn detect_protocol_version(merkle_tree: &MerkleTree) -> IndyResult<Option<usize>> {
+    let mut protocol_version = None;
+
+    for gen_txn in merkle_tree {
+        let gen_txn: NodeTransaction = rmp_serde::decode::from_slice(gen_txn.as_slice())
+            .to_indy(IndyErrorKind::InvalidState, "MerkleTree contains invalid item")?;
+
+        let txn_protocol_version = match gen_txn {
+            NodeTransaction::NodeTransactionV0(_) => 1,
+            NodeTransaction::NodeTransactionV1(_) => 2,
+        };
+
+        match protocol_version {
+            Some(version) if version != txn_protocol_version => {
+                return Err(err_msg(IndyErrorKind::PoolIncompatibleProtocolVersion,
+                                   format!("Pool Genesis Transactions mix versions {} and {}",
+                                           NodeTransactionV0::VERSION, NodeTransactionV1::VERSION)));
+            }
+            _ => protocol_version = Some(txn_protocol_version),
+        }
+    }
+
+    Ok(protocol_version)
+}
+
 pub fn build_node_state(merkle_tree: &MerkleTree) -> IndyResult<HashMap<String, NodeTransactionV1>> {
     let mut gen_tnxs: HashMap<String, NodeTransactionV1> = HashMap::new();
 
@@ -196,27 +344,9 @@ pub fn build_node_state(merkle_tree: &MerkleTree) -> IndyResult<HashMap<String,
         let gen_txn: NodeTransaction = rmp_serde::decode::from_slice(gen_txn.as_slice())
             .to_indy(IndyErrorKind::InvalidState, "MerkleTree contains invalid item")?;
 
-        let protocol_version = ProtocolVersion::get();
-
         let mut gen_txn = match gen_txn {
-            NodeTransaction::NodeTransactionV0(txn) => {
-                if protocol_version != 1 {
-                    return Err(err_msg(IndyErrorKind::PoolIncompatibleProtocolVersion,
-                                format!("Libindy PROTOCOL_VERSION is {} but Pool Genesis Transactions are of version {}.\
-                                         Call indy_set_protocol_version(1) to set correct PROTOCOL_VERSION",
-                                        protocol_version, NodeTransactionV0::VERSION)));
-                }
-                NodeTransactionV1::from(txn)
-            }
-            NodeTransaction::NodeTransactionV1(txn) => {
-                if protocol_version != 2 {
-                    return Err(err_msg(IndyErrorKind::PoolIncompatibleProtocolVersion,
-                                       format!("Libindy PROTOCOL_VERSION is {} but Pool Genesis Transactions are of version {}.\
-                                                Call indy_set_protocol_version(2) to set correct PROTOCOL_VERSION",
-                                               protocol_version, NodeTransactionV1::VERSION)));
-                }
-                txn
-            }
+            NodeTransaction::NodeTransactionV0(txn) => NodeTransactionV1::from(txn),
+            NodeTransaction::NodeTransactionV1(txn) => txn,
         };
 
         if gen_tnxs.contains_key(&gen_txn.txn.data.dest) {
@@ -232,6 +362,17 @@ pub fn from_file(txn_file: &str) -> IndyResult<MerkleTree> {
     _from_genesis(&PathBuf::from(txn_file))
 }
 
+pub fn from_data(txns: &str) -> IndyResult<MerkleTree> {
+    let mut mt = MerkleTree::from_vec(Vec::new())?;
+
+    for line in txns.lines() {
+        if line.trim().is_empty() { continue; };
+        mt.append(_parse_txn_from_json(line)?)?;
+    }
+
+    Ok(mt)
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -261,21 +402,22 @@ mod tests {
     }
 
     #[test]
-    fn pool_worker_build_node_state_works_for_new_txns_format_and_1_protocol_version() {
-        test::cleanup_storage("pool_worker_build_node_state_works_for_new_txns_format_and_1_protocol_version");
+    fn pool_worker_build_node_state_detects_protocol_version_2_for_new_txns_format_despite_global_1() {
+        test::cleanup_storage("pool_worker_build_node_state_detects_protocol_version_2_for_new_txns_format_despite_global_1");
 
         _set_protocol_version(1);
 
         let node_txns = test::gen_txns();
         let txns_src = node_txns[0..(2 as usize)].join("\n");
 
-        _write_genesis_txns("pool_worker_build_node_state_works_for_new_txns_format_and_1_protocol_version", &txns_src);
+        _write_genesis_txns("pool_worker_build_node_state_detects_protocol_version_2_for_new_txns_format_despite_global_1", &txns_src);
 
-        let merkle_tree = super::create("pool_worker_build_node_state_works_for_new_txns_format_and_1_protocol_version").unwrap();
-        let res = super::build_node_state(&merkle_tree);
-        assert_kind!(IndyErrorKind::PoolIncompatibleProtocolVersion, res);
+        let merkle_tree = super::create("pool_worker_build_node_state_detects_protocol_version_2_for_new_txns_format_despite_global_1").unwrap();
+        let node_state = super::build_node_state(&merkle_tree).unwrap();
+        assert_eq!(2, node_state.len());
+        assert_eq!(Some(2), super::detect_protocol_version(&merkle_tree).unwrap());
 
-        test::cleanup_storage("pool_worker_build_node_state_works_for_new_txns_format_and_1_protocol_version");
+        test::cleanup_storage("pool_worker_build_node_state_detects_protocol_version_2_for_new_txns_format_despite_global_1");
     }
 
     #[test]
@@ -328,6 +470,43 @@ mod tests {
         test::cleanup_storage("pool_worker_restore_merkle_tree_works_from_genesis_txns");
     }
 
+    #[test]
+    fn pool_worker_from_data_works() {
+        let node_txns = test::gen_txns();
+        let txns_src = format!("{}\n{}\n\n",
+                               node_txns[0].replace(environment::test_pool_ip().as_str(), "10.0.0.2"),
+                               node_txns[1].replace(environment::test_pool_ip().as_str(), "10.0.0.2"));
+
+        let merkle_tree = super::from_data(&txns_src).unwrap();
+
+        assert_eq!(merkle_tree.count(), 2);
+        assert_eq!(merkle_tree.root_hash_hex(), "c715aef44aaacab8746c9a505ba106b5554fe6d29ec7f0a2abc9d7723fdea523");
+    }
+
+    #[test]
+    fn pool_worker_from_data_works_for_invalid_txn() {
+        let res = super::from_data("{\"invalid\"");
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn pool_worker_clear_cache_works() {
+        test::cleanup_storage("pool_worker_clear_cache_works");
+
+        let node_txns = test::gen_txns();
+        _write_genesis_txns("pool_worker_clear_cache_works", &node_txns[0..2].join("\n"));
+
+        super::clear_cache("pool_worker_clear_cache_works").unwrap();
+
+        super::dump_new_txns("pool_worker_clear_cache_works", &[]).unwrap();
+        assert!(get_pool_stored_path("pool_worker_clear_cache_works", false).exists());
+
+        super::clear_cache("pool_worker_clear_cache_works").unwrap();
+        assert!(!get_pool_stored_path("pool_worker_clear_cache_works", false).exists());
+
+        test::cleanup_storage("pool_worker_clear_cache_works");
+    }
+
     #[test]
     fn pool_worker_build_node_state_works_for_old_format() {
         test::cleanup_storage("pool_worker_build_node_state_works_for_old_format");
@@ -387,19 +566,36 @@ mod tests {
     }
 
     #[test]
-    fn pool_worker_build_node_state_works_for_old_txns_format_and_2_protocol_version() {
-        test::cleanup_storage("pool_worker_build_node_state_works_for_old_txns_format_and_2_protocol_version");
+    fn pool_worker_build_node_state_detects_protocol_version_1_for_old_txns_format_despite_global_2() {
+        test::cleanup_storage("pool_worker_build_node_state_detects_protocol_version_1_for_old_txns_format_despite_global_2");
 
         _set_protocol_version(TEST_PROTOCOL_VERSION);
 
         let txns_src = format!("{}\n{}\n", NODE1_OLD, NODE2_OLD);
 
-        _write_genesis_txns("pool_worker_build_node_state_works_for_old_txns_format_and_2_protocol_version", &txns_src);
+        _write_genesis_txns("pool_worker_build_node_state_detects_protocol_version_1_for_old_txns_format_despite_global_2", &txns_src);
+
+        let merkle_tree = super::create("pool_worker_build_node_state_detects_protocol_version_1_for_old_txns_format_despite_global_2").unwrap();
+        let node_state = super::build_node_state(&merkle_tree).unwrap();
+        assert_eq!(2, node_state.len());
+        assert_eq!(Some(1), super::detect_protocol_version(&merkle_tree).unwrap());
+
+        test::cleanup_storage("pool_worker_build_node_state_detects_protocol_version_1_for_old_txns_format_despite_global_2");
+    }
 
-        let merkle_tree = super::create("pool_worker_build_node_state_works_for_old_txns_format_and_2_protocol_version").unwrap();
-        let res = super::build_node_state(&merkle_tree);
+    #[test]
+    fn detect_protocol_version_works_for_mixed_txns_formats() {
+        let node_txns = test::gen_txns();
+        let txns_src = format!("{}\n{}\n", NODE1_OLD, node_txns[1]);
+
+        let merkle_tree = super::from_data(&txns_src).unwrap();
+        let res = super::detect_protocol_version(&merkle_tree);
         assert_kind!(IndyErrorKind::PoolIncompatibleProtocolVersion, res);
+    }
 
-        test::cleanup_storage("pool_worker_build_node_state_works_for_old_txns_format_and_2_protocol_version");
+    #[test]
+    fn detect_protocol_version_works_for_empty_ledger() {
+        let merkle_tree = MerkleTree::from_vec(Vec::new()).unwrap();
+        assert_eq!(None, super::detect_protocol_version(&merkle_tree).unwrap());
     }
 }
\ No newline at end of file
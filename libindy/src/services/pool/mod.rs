@@ -1,46 +1,71 @@
 use std::{
     collections::{HashMap, HashSet},
-    fs, io,
+    ffi::{CStr, CString},
+    fs,
     io::Write,
-    sync::Arc,
+    sync::{Arc, RwLock},
 };
 
 use byteorder::{ByteOrder, LittleEndian};
 use futures::{channel::oneshot, lock::Mutex};
-use indy_api_types::{errors::*, CommandHandle, PoolHandle};
+use http_client::{
+    h1::H1Client,
+    http_types::{Method, Request as HttpRequest, Url},
+    HttpClient,
+};
+use indy_api_types::{errors::*, CommandHandle, ErrorCode, PoolHandle};
 use indy_utils::{next_command_handle, next_pool_handle};
 use lazy_static::lazy_static;
+use rust_base58::{FromBase58, ToBase58};
 use serde::de::DeserializeOwned;
 use serde_json;
-use ursa::bls::VerKey;
+use sha2::{Digest, Sha256};
+use ursa::bls::{Generator, VerKey};
 use zmq::Socket;
 
-//use crate::api::ledger::{CustomFree, CustomTransactionParser}; FIXME: !!!
 use crate::{
+    api::ledger::{CustomFree, CustomTransactionParser},
     domain::{
-        ledger::response::{Message, Reply, ResponseMetadata},
-        pool::{PoolConfig, PoolOpenConfig},
+        ledger::{
+            author_agreement::PoolTxnAuthorAgreement,
+            request::TxnAuthrAgrmtAcceptanceData,
+            response::{Message, Reply, ResponseMetadata},
+        },
+        pool::{
+            GenesisSource, PoolConfig, PoolOpenConfig, PoolSnapshot, PoolStatus,
+            ReplyVerification, SimulatedPoolConfig, StateProofVerification,
+        },
     },
     utils::environment,
 };
 
+pub(crate) use self::ledger_mirror::{parse_get_txn_data, LedgerMirror};
+pub use self::types::{
+    KeyValueSimpleData, KeyValueSimpleDataVerificationType, KeyValuesInSP, KeyValuesSubTrieData,
+    NumericalSuffixAscendingNoGapsData, ParsedSP,
+};
+
 use self::{
     events::{COMMAND_CONNECT, COMMAND_EXIT, COMMAND_REFRESH},
     pool::{Pool, ZMQPool},
+    simulator::SimulatedPool,
 };
 
 mod catchup;
 mod commander;
 mod events;
+mod ledger_mirror;
 mod merkle_tree_factory;
 mod networker;
+mod node_stats;
 mod pool;
 mod request_handler;
+mod simulator;
 mod state_proof;
 mod types;
 
 lazy_static! {
-    //static ref REGISTERED_SP_PARSERS: Mutex<HashMap<String, (CustomTransactionParser, CustomFree)>> = Mutex::new(HashMap::new()); FXIME: !!!
+    static ref REGISTERED_SP_PARSERS: RwLock<HashMap<String, Arc<dyn StateProofParser>>> = RwLock::new(HashMap::new());
     static ref POOL_HANDLE_SENDERS: Arc<Mutex<HashMap<PoolHandle, oneshot::Sender<IndyResult<PoolHandle>>>>> = Arc::new(Mutex::new(HashMap::new()));
     static ref SUBMIT_SENDERS: Arc<Mutex<HashMap<PoolHandle, oneshot::Sender<IndyResult<String>>>>> = Arc::new(Mutex::new(HashMap::new()));
     static ref CLOSE_SENDERS: Arc<Mutex<HashMap<PoolHandle, oneshot::Sender<IndyResult<()>>>>> = Arc::new(Mutex::new(HashMap::new()));
@@ -48,9 +73,67 @@ lazy_static! {
 
 type Nodes = HashMap<String, Option<VerKey>>;
 
+/// Extracts state proofs from replies for transaction types which libindy doesn't know about.
+/// Parsed state proofs are checked the same way as ones of built-in types.
+pub trait StateProofParser: Send + Sync {
+    fn parse(&self, reply_from_node: &str) -> IndyResult<Vec<ParsedSP>>;
+}
+
+/// State proof parser plugged through `indy_register_transaction_parser_for_sp`.
+pub(crate) struct CustomStateProofParser {
+    parser: CustomTransactionParser,
+    free: CustomFree,
+}
+
+impl CustomStateProofParser {
+    pub(crate) fn new(parser: CustomTransactionParser, free: CustomFree) -> CustomStateProofParser {
+        CustomStateProofParser { parser, free }
+    }
+}
+
+impl StateProofParser for CustomStateProofParser {
+    fn parse(&self, reply_from_node: &str) -> IndyResult<Vec<ParsedSP>> {
+        let reply_from_node = CString::new(reply_from_node)
+            .to_indy(IndyErrorKind::InvalidStructure, "Reply from node contains null byte")?;
+
+        let mut parsed_sp = ::std::ptr::null();
+        let err = (self.parser)(reply_from_node.as_ptr(), &mut parsed_sp);
+
+        if err != ErrorCode::Success {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Plugged state proof parser returned error {:?}", err),
+            ));
+        }
+
+        if parsed_sp.is_null() {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                "Plugged state proof parser returned null",
+            ));
+        }
+
+        let res = unsafe { CStr::from_ptr(parsed_sp) }
+            .to_str()
+            .to_indy(IndyErrorKind::InvalidStructure, "Plugged state proof parser returned invalid UTF-8 string")
+            .and_then(|parsed_sp| {
+                serde_json::from_str::<Vec<ParsedSP>>(parsed_sp)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Plugged state proof parser returned invalid ParsedSP json")
+            });
+
+        let err = (self.free)(parsed_sp);
+        trace!("CustomStateProofParser::parse: plugin free res {:?}", err);
+
+        res
+    }
+}
+
 pub(crate) struct PoolService {
     open_pools: Mutex<HashMap<PoolHandle, Arc<ZMQPool>>>,
     pending_pools: Mutex<HashSet<String>>,
+    namespaces: Mutex<HashMap<String, PoolHandle>>,
+    taa_acceptances: Mutex<HashMap<PoolHandle, PoolTxnAuthorAgreement>>,
+    simulated_pools: Mutex<HashMap<String, Arc<SimulatedPool>>>,
 }
 
 impl PoolService {
@@ -58,15 +141,18 @@ impl PoolService {
         PoolService {
             open_pools: Mutex::new(HashMap::new()),
             pending_pools: Mutex::new(HashSet::new()),
+            namespaces: Mutex::new(HashMap::new()),
+            taa_acceptances: Mutex::new(HashMap::new()),
+            simulated_pools: Mutex::new(HashMap::new()),
         }
     }
 
-    pub(crate) fn create(&self, name: &str, config: Option<PoolConfig>) -> IndyResult<()> {
+    pub(crate) async fn create(&self, name: &str, config: Option<PoolConfig>) -> IndyResult<()> {
         //TODO: initialize all state machines
         trace!("PoolService::create {} with config {:?}", name, config);
 
         let mut path = environment::pool_path(name);
-        let pool_config = config.unwrap_or_else(|| PoolConfig::default_for_name(name));
+        let mut pool_config = config.unwrap_or_else(|| PoolConfig::default_for_name(name));
 
         if path.as_path().exists() {
             return Err(err_msg(
@@ -78,9 +164,177 @@ impl PoolService {
             ));
         }
 
-        // check that we can build MerkeleTree from genesis transaction file
+        let genesis_txns = self._get_genesis_txns(&pool_config).await?;
+        let snapshot_txns = self._check_snapshot(&pool_config, &genesis_txns)?;
+
+        fs::create_dir_all(path.as_path())
+            .to_indy(IndyErrorKind::IOError, "Can't create pool config directory")?;
+
+        path.push(name);
+        path.set_extension("txn");
+
+        // fs::copy also copies attributes of the file
+        // and copying permissions can be problem for some cases
+        fs::write(path.as_path(), &genesis_txns).to_indy(
+            IndyErrorKind::IOError,
+            format!("Can't create genesis txn file {:?}", path.as_path()),
+        )?;
+
+        if let Some(snapshot_txns) = snapshot_txns {
+            merkle_tree_factory::store_snapshot(name, &snapshot_txns)?;
+        }
+
+        // inline genesis transactions and snapshot are already stored in the pool directory
+        pool_config.genesis_txn_data = None;
+        pool_config.snapshot = None;
+        pool_config.snapshot_verkey = None;
+
+        self._write_config(name, &pool_config)?;
+
+        // TODO probably create another one file pool.json with pool description,
+        // but now there is no info to save (except name witch equal to directory)
+        Ok(())
+    }
+
+    /// Starts in-process simulated pool and creates pool ledger configuration for it.
+    /// Pools opened with this configuration talk to the simulated nodes instead of the network
+    /// until the configuration is deleted. Returns genesis transactions of the simulated pool.
+    pub(crate) async fn create_simulated(&self, name: &str, config: SimulatedPoolConfig) -> IndyResult<String> {
+        trace!("PoolService::create_simulated {} with config {:?}", name, config);
+
+        let simulated_pool = SimulatedPool::start(&config)?;
+        let genesis_txns = simulated_pool.genesis_txns().to_string();
+
+        self.create(
+            name,
+            Some(PoolConfig {
+                genesis_txn: None,
+                genesis_txn_data: Some(genesis_txns.clone()),
+                genesis_url: None,
+                genesis_hash: None,
+                namespace: None,
+                snapshot: None,
+                snapshot_verkey: None,
+            }),
+        )
+        .await?;
+
+        self.simulated_pools
+            .lock()
+            .await
+            .insert(name.to_string(), Arc::new(simulated_pool));
+
+        Ok(genesis_txns)
+    }
+
+    /// Replaces genesis transactions of the created pool ledger configuration.
+    /// Pool ledger transactions caught up before are dropped,
+    /// so the next opening starts from the new genesis transactions.
+    pub(crate) async fn update_genesis(&self, name: &str, config: PoolConfig) -> IndyResult<()> {
+        trace!("PoolService::update_genesis {} with config {:?}", name, config);
+
+        if self._is_open(name).await {
+            return Err(err_msg(
+                IndyErrorKind::InvalidState,
+                "Can't update pool genesis transactions - pool is open now",
+            ));
+        }
+
+        let path = environment::pool_path(name);
+
+        if !path.as_path().exists() {
+            return Err(err_msg(
+                IndyErrorKind::PoolNotCreated,
+                format!("Pool is not created for name: {:?}", name),
+            ));
+        }
+
+        // genesis hash pinned before stays in force unless the new one is given explicitly
+        let stored_config = self._read_config(name)?;
+
+        let config = PoolConfig {
+            genesis_hash: config.genesis_hash.or_else(|| {
+                stored_config
+                    .as_ref()
+                    .and_then(|config| config.genesis_hash.clone())
+            }),
+            namespace: config.namespace.or_else(|| {
+                stored_config
+                    .as_ref()
+                    .and_then(|config| config.namespace.clone())
+            }),
+            ..config
+        };
+
+        let genesis_txns = self._get_genesis_txns(&config).await?;
+        let snapshot_txns = self._check_snapshot(&config, &genesis_txns)?;
+
+        // the same path merkle_tree_factory restores the tree from
+        let mut genesis_path = path;
+        genesis_path.push(name);
+        genesis_path.set_extension("txn");
+
+        let tmp_path = genesis_path.with_file_name(format!("{}.txn.tmp", name));
+
+        fs::write(tmp_path.as_path(), &genesis_txns)
+            .to_indy(IndyErrorKind::IOError, "Can't write genesis txn file")?;
+
+        fs::rename(tmp_path.as_path(), genesis_path.as_path())
+            .to_indy(IndyErrorKind::IOError, "Can't replace genesis txn file")?;
+
+        merkle_tree_factory::clear_cache(name)?;
+
+        if let Some(snapshot_txns) = snapshot_txns {
+            merkle_tree_factory::store_snapshot(name, &snapshot_txns)?;
+        }
+
+        self._write_config(
+            name,
+            &PoolConfig {
+                genesis_txn_data: None,
+                snapshot: None,
+                snapshot_verkey: None,
+                ..config
+            },
+        )
+    }
+
+    /// Reads genesis transactions from the source set in the config
+    /// and checks them against expected hash.
+    async fn _get_genesis_txns(&self, config: &PoolConfig) -> IndyResult<String> {
+        let source = config.genesis_source().ok_or_else(|| {
+            err_msg(
+                IndyErrorKind::InvalidStructure,
+                "One of `genesis_txn`, `genesis_txn_data` and `genesis_url` must be specified",
+            )
+        })?;
+
+        let genesis_txns = match source {
+            GenesisSource::File(genesis_txn) => fs::read_to_string(genesis_txn).to_indy(
+                IndyErrorKind::IOError,
+                format!("Can't read genesis txn file {:?}", genesis_txn),
+            )?,
+            GenesisSource::Data(genesis_txn_data) => genesis_txn_data.to_string(),
+            GenesisSource::Url(genesis_url) => _fetch_genesis_txns(genesis_url).await?,
+        };
+
+        if let Some(ref genesis_hash) = config.genesis_hash {
+            let actual_hash = hex::encode(Sha256::digest(genesis_txns.as_bytes()));
+
+            if !actual_hash.eq_ignore_ascii_case(genesis_hash) {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidStructure,
+                    format!(
+                        "Genesis transactions hash mismatch: expected {}, actual {}",
+                        genesis_hash, actual_hash
+                    ),
+                ));
+            }
+        }
+
+        // check that we can build MerkeleTree from genesis transactions
         //TODO: move parse to correct place
-        let mt = merkle_tree_factory::from_file(&pool_config.genesis_txn)?;
+        let mt = merkle_tree_factory::from_data(&genesis_txns)?;
 
         if mt.count() == 0 {
             return Err(err_msg(
@@ -89,37 +343,39 @@ impl PoolService {
             ));
         }
 
-        fs::create_dir_all(path.as_path())
-            .to_indy(IndyErrorKind::IOError, "Can't create pool config directory")?;
-
-        path.push(name);
-        path.set_extension("txn");
-
-        {
-            // fs::copy also copies attributes of the file
-            // and copying permissions can be problem for some cases
+        Ok(genesis_txns)
+    }
 
-            let mut gt_fin = fs::File::open(&pool_config.genesis_txn).to_indy(
-                IndyErrorKind::IOError,
-                format!("Can't open genesis txn file {:?}", &pool_config.genesis_txn),
-            )?;
+    /// Checks pool ledger snapshot set in the config against genesis transactions.
+    /// Nodes check the snapshot again on opening: it is only the starting point of catch-up.
+    fn _check_snapshot(&self, config: &PoolConfig, genesis_txns: &str) -> IndyResult<Option<Vec<Vec<u8>>>> {
+        match config.snapshot {
+            Some(ref snapshot) => {
+                let genesis = merkle_tree_factory::from_data(genesis_txns)?;
+                merkle_tree_factory::check_snapshot(&genesis, snapshot, config.snapshot_verkey.as_deref())
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
 
-            let mut gt_fout = fs::File::create(path.as_path()).to_indy(
-                IndyErrorKind::IOError,
-                format!("Can't create genesis txn file {:?}", path.as_path()),
-            )?;
+    /// Exports transactions of the local copy of the pool ledger, so other machines
+    /// can create the pool config with them and skip catch-up from genesis.
+    pub(crate) async fn export_snapshot(&self, name: &str) -> IndyResult<PoolSnapshot> {
+        trace!("PoolService::export_snapshot {}", name);
 
-            io::copy(&mut gt_fin, &mut gt_fout).to_indy(
-                IndyErrorKind::IOError,
-                format!(
-                    "Can't copy genesis txn file from {:?} to {:?}",
-                    &pool_config.genesis_txn,
-                    path.as_path()
-                ),
-            )?;
+        if !environment::pool_path(name).exists() {
+            return Err(err_msg(
+                IndyErrorKind::PoolNotCreated,
+                format!("Pool is not created for name: {:?}", name),
+            ));
         }
 
-        path.pop();
+        merkle_tree_factory::export_snapshot(name)
+    }
+
+    fn _write_config(&self, name: &str, config: &PoolConfig) -> IndyResult<()> {
+        let mut path = environment::pool_path(name);
         path.push("config");
         path.set_extension("json");
 
@@ -127,29 +383,26 @@ impl PoolService {
             .to_indy(IndyErrorKind::IOError, "Can't create pool config file")?;
 
         f.write_all({
-            serde_json::to_string(&pool_config)
+            serde_json::to_string(config)
                 .to_indy(IndyErrorKind::InvalidState, "Can't serialize pool config")?
                 .as_bytes()
         })
         .to_indy(IndyErrorKind::IOError, "Can't write to pool config file")?;
 
         f.flush()
-            .to_indy(IndyErrorKind::IOError, "Can't write to pool config file")?;
-
-        // TODO probably create another one file pool.json with pool description,
-        // but now there is no info to save (except name witch equal to directory)
-        Ok(())
+            .to_indy(IndyErrorKind::IOError, "Can't write to pool config file")
     }
 
-    pub(crate) async fn delete(&self, name: &str) -> IndyResult<()> {
-        if self
-            .open_pools
+    async fn _is_open(&self, name: &str) -> bool {
+        self.open_pools
             .lock()
             .await
             .values()
-            .find(|pool| pool.pool.lock().unwrap().get_name().eq(name))
-            .is_some()
-        {
+            .any(|pool| pool.pool.lock().unwrap().get_name().eq(name))
+    }
+
+    pub(crate) async fn delete(&self, name: &str) -> IndyResult<()> {
+        if self._is_open(name).await {
             return Err(err_msg(
                 IndyErrorKind::InvalidState,
                 "Can't delete pool config - pool is open now",
@@ -159,7 +412,11 @@ impl PoolService {
         let path = environment::pool_path(name);
 
         fs::remove_dir_all(path)
-            .to_indy(IndyErrorKind::IOError, "Can't delete pool config directory")
+            .to_indy(IndyErrorKind::IOError, "Can't delete pool config directory")?;
+
+        self.simulated_pools.lock().await.remove(name);
+
+        Ok(())
     }
 
     pub(crate) async fn open(
@@ -189,11 +446,27 @@ impl PoolService {
             ));
         }
 
+        let namespace = self._read_namespace(&name)?;
+        let taa_acceptance = self._read_taa_acceptance(&name)?;
+
+        if let Some(ref namespace) = namespace {
+            if self.namespaces.lock().await.contains_key(namespace) {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidState,
+                    format!("Pool for namespace \"{}\" is already opened", namespace),
+                ));
+            }
+        }
+
         let config = config.unwrap_or_default();
 
         let pool_handle: PoolHandle = next_pool_handle();
         let mut new_pool = Pool::new(&name, pool_handle, config);
 
+        if let Some(simulated_pool) = self.simulated_pools.lock().await.get(&name) {
+            new_pool.set_transport(simulated_pool.transport());
+        }
+
         let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets(&format!("pool_{}", name));
 
         new_pool.work(recv_cmd_sock);
@@ -209,6 +482,20 @@ impl PoolService {
         self.pending_pools.lock().await.remove(&name);
 
         if res.is_ok() {
+            if let Some(namespace) = namespace {
+                self.namespaces.lock().await.insert(namespace, pool_handle);
+            }
+
+            if let Some(acceptance) = taa_acceptance {
+                self.taa_acceptances.lock().await.insert(
+                    pool_handle,
+                    PoolTxnAuthorAgreement {
+                        acceptance,
+                        ledger_digest: None,
+                    },
+                );
+            }
+
             self.open_pools.lock().await.insert(
                 new_pool.get_id(),
                 Arc::new(ZMQPool::new(new_pool, send_cmd_sock)),
@@ -218,6 +505,106 @@ impl PoolService {
         res
     }
 
+    /// Returns handle of the opened pool registered for did:indy namespace.
+    pub(crate) async fn get_pool_handle_for_namespace(&self, namespace: &str) -> IndyResult<PoolHandle> {
+        self.namespaces
+            .lock()
+            .await
+            .get(namespace)
+            .cloned()
+            .ok_or_else(|| {
+                err_msg(
+                    IndyErrorKind::InvalidPoolHandle,
+                    format!("No opened pool for namespace \"{}\"", namespace),
+                )
+            })
+    }
+
+    /// Sets transaction author agreement acceptance to append to write requests sent to the opened pool.
+    /// The acceptance is stored in the pool directory, so it is restored on the next opening.
+    pub(crate) async fn set_taa_acceptance(
+        &self,
+        handle: PoolHandle,
+        acceptance: TxnAuthrAgrmtAcceptanceData,
+    ) -> IndyResult<()> {
+        let name = self.get_pool_name(handle).await?;
+
+        self._write_taa_acceptance(&name, &acceptance)?;
+
+        self.taa_acceptances.lock().await.insert(
+            handle,
+            PoolTxnAuthorAgreement {
+                acceptance,
+                ledger_digest: None,
+            },
+        );
+
+        Ok(())
+    }
+
+    pub(crate) async fn get_taa_acceptance(&self, handle: PoolHandle) -> Option<PoolTxnAuthorAgreement> {
+        self.taa_acceptances.lock().await.get(&handle).cloned()
+    }
+
+    /// Remembers the digest of the agreement in force on the ledger.
+    /// `None` forgets the known digest, so it is read from the ledger again.
+    pub(crate) async fn set_ledger_taa_digest(&self, handle: PoolHandle, digest: Option<Option<String>>) {
+        if let Some(taa) = self.taa_acceptances.lock().await.get_mut(&handle) {
+            taa.ledger_digest = digest.map(|digest| (digest, time::get_time().sec));
+        }
+    }
+
+    fn _write_taa_acceptance(&self, name: &str, acceptance: &TxnAuthrAgrmtAcceptanceData) -> IndyResult<()> {
+        let mut path = environment::pool_path(name);
+        path.push("taa_acceptance");
+        path.set_extension("json");
+
+        let acceptance = serde_json::to_string(acceptance)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize TAA acceptance")?;
+
+        fs::write(path.as_path(), acceptance)
+            .to_indy(IndyErrorKind::IOError, "Can't write TAA acceptance file")
+    }
+
+    fn _read_taa_acceptance(&self, name: &str) -> IndyResult<Option<TxnAuthrAgrmtAcceptanceData>> {
+        let mut path = environment::pool_path(name);
+        path.push("taa_acceptance");
+        path.set_extension("json");
+
+        if !path.as_path().exists() {
+            return Ok(None);
+        }
+
+        let acceptance = fs::read_to_string(path.as_path())
+            .to_indy(IndyErrorKind::IOError, "Can't read TAA acceptance file")?;
+
+        serde_json::from_str(&acceptance)
+            .to_indy(IndyErrorKind::InvalidState, "Invalid TAA acceptance file")
+            .map(Some)
+    }
+
+    fn _read_namespace(&self, name: &str) -> IndyResult<Option<String>> {
+        Ok(self._read_config(name)?.and_then(|config| config.namespace))
+    }
+
+    fn _read_config(&self, name: &str) -> IndyResult<Option<PoolConfig>> {
+        let mut path = environment::pool_path(name);
+        path.push("config");
+        path.set_extension("json");
+
+        if !path.as_path().exists() {
+            return Ok(None);
+        }
+
+        let config = fs::read_to_string(path.as_path())
+            .to_indy(IndyErrorKind::IOError, "Can't read pool config file")?;
+
+        let config: PoolConfig = serde_json::from_str(&config)
+            .to_indy(IndyErrorKind::InvalidState, "Can't deserialize pool config")?;
+
+        Ok(Some(config))
+    }
+
     //#[logfn(trace)] FIXME:
     pub(crate) async fn open_ack(pool_hanlde: PoolHandle, result: IndyResult<()>) {
         let sender: futures::channel::oneshot::Sender<IndyResult<PoolHandle>> = POOL_HANDLE_SENDERS
@@ -235,6 +622,62 @@ impl PoolService {
         sender.send(result.map(|()| String::new())).unwrap(); //FIXME
     }
 
+    pub(crate) async fn get_pool_name(&self, handle: PoolHandle) -> IndyResult<String> {
+        self.open_pools
+            .lock()
+            .await
+            .get(&handle)
+            .map(|pool| pool.pool.lock().unwrap().get_name().to_string())
+            .ok_or_else(|| {
+                err_msg(
+                    IndyErrorKind::InvalidPoolHandle,
+                    format!("No pool with requested handle {:?}", handle),
+                )
+            })
+    }
+
+    /// Returns protocol version detected for the opened pool.
+    pub(crate) async fn get_protocol_version(&self, handle: PoolHandle) -> IndyResult<usize> {
+        self.open_pools
+            .lock()
+            .await
+            .get(&handle)
+            .map(|pool| pool.pool.lock().unwrap().get_protocol_version())
+            .ok_or_else(|| {
+                err_msg(
+                    IndyErrorKind::InvalidPoolHandle,
+                    format!("No pool with requested handle {:?}", handle),
+                )
+            })
+    }
+
+    pub(crate) async fn get_status(&self, handle: PoolHandle) -> IndyResult<PoolStatus> {
+        let (name, nodes, protocol_version) = self
+            .open_pools
+            .lock()
+            .await
+            .get(&handle)
+            .map(|pool| {
+                let pool = pool.pool.lock().unwrap();
+                (pool.get_name().to_string(), pool.get_nodes_status(), pool.get_protocol_version())
+            })
+            .ok_or_else(|| {
+                err_msg(
+                    IndyErrorKind::InvalidPoolHandle,
+                    format!("No pool with requested handle {:?}", handle),
+                )
+            })?;
+
+        let merkle_tree = merkle_tree_factory::create(&name)?;
+
+        Ok(PoolStatus {
+            ledger_size: merkle_tree.count(),
+            merkle_root: merkle_tree.root_hash().to_base58(),
+            protocol_version,
+            nodes,
+        })
+    }
+
     pub(crate) async fn send_tx(&self, handle: PoolHandle, msg: &str) -> IndyResult<String> {
         self.send_action(handle, msg, None, None).await
     }
@@ -289,34 +732,39 @@ impl PoolService {
         sender.send(result).unwrap(); //FIXME
     }
 
-    // FIXME: !!!
-
-    // pub fn register_sp_parser(txn_type: &str,
-    //                           parser: CustomTransactionParser, free: CustomFree) -> IndyResult<()> {
-    //     if events::REQUESTS_FOR_STATE_PROOFS.contains(&txn_type) {
-    //         return Err(err_msg(IndyErrorKind::InvalidStructure,
-    //                            format!("Try to override StateProof parser for default TXN_TYPE {}", txn_type)));
-    //     }
-
-    //     REGISTERED_SP_PARSERS.lock()
-    //         .map(|mut map| {
-    //             map.insert(txn_type.to_owned(), (parser, free));
-    //         })
-    //         .unwrap(); // FIXME: Can we avoid unwrap?
+    pub(crate) fn register_sp_parser(txn_type: &str, parser: Arc<dyn StateProofParser>) -> IndyResult<()> {
+        if events::REQUESTS_FOR_STATE_PROOFS.contains(&txn_type) {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Try to override StateProof parser for default TXN_TYPE {}", txn_type),
+            ));
+        }
 
-    //     Ok(())
-    // }
+        REGISTERED_SP_PARSERS
+            .write()
+            .map_err(|_| err_msg(IndyErrorKind::InvalidState, "State proof parsers registry is poisoned"))?
+            .insert(txn_type.to_owned(), parser);
 
-    // FIXME:!!!
+        Ok(())
+    }
 
-    // pub fn get_sp_parser(txn_type: &str) -> Option<(CustomTransactionParser, CustomFree)> {
-    //     let parsers = REGISTERED_SP_PARSERS.lock().unwrap(); // FIXME: Can we avoid unwrap here?
-    //     parsers.get(txn_type).map(Clone::clone)
-    // }
+    pub(crate) fn get_sp_parser(txn_type: &str) -> Option<Arc<dyn StateProofParser>> {
+        REGISTERED_SP_PARSERS
+            .read()
+            .ok()
+            .and_then(|parsers| parsers.get(txn_type).cloned())
+    }
 
     pub(crate) async fn close(&self, handle: PoolHandle) -> IndyResult<()> {
         let pool = self.open_pools.lock().await.remove(&handle);
 
+        self.namespaces
+            .lock()
+            .await
+            .retain(|_, pool_handle| *pool_handle != handle);
+
+        self.taa_acceptances.lock().await.remove(&handle);
+
         let (sender, receiver) = oneshot::channel::<IndyResult<()>>();
         CLOSE_SENDERS.lock().await.insert(handle, sender);
 
@@ -440,12 +888,130 @@ impl PoolService {
         let c = Self::parse_response_metadata(response);
         c.ok().and_then(|resp| resp.last_txn_time)
     }
+
+    /// Verifies state proofs and BLS multi-signatures of a saved reply without connecting to the pool.
+    /// Node keys are taken from the stored pool ledger of `pool_name` or from `genesis_txns`.
+    pub(crate) fn verify_ledger_reply(
+        &self,
+        pool_name: Option<&str>,
+        genesis_txns: Option<&str>,
+        request: &str,
+        reply: &str,
+    ) -> IndyResult<ReplyVerification> {
+        let merkle = match (pool_name, genesis_txns) {
+            (Some(pool_name), None) => merkle_tree_factory::create(pool_name)?,
+            (None, Some(genesis_txns)) => merkle_tree_factory::from_data(genesis_txns)?,
+            _ => {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidStructure,
+                    "Exactly one of `pool_name` and `genesis_txns` must be specified",
+                ))
+            }
+        };
+
+        let (nodes, _) = pool::_get_nodes_and_remotes(&merkle)?;
+        let f = pool::_get_f(nodes.len());
+
+        let request: serde_json::Value = serde_json::from_str(request)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is malformed json")?;
+
+        let reply_json: serde_json::Value = serde_json::from_str(reply)
+            .to_indy(IndyErrorKind::InvalidStructure, "Reply is malformed json")?;
+
+        let result = &reply_json["result"];
+
+        if !result.is_object() {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                "Reply doesn't contain result",
+            ));
+        }
+
+        let sp_key = state_proof::parse_key_from_request_for_builtin_sp(&request);
+
+        let parsed_sps =
+            state_proof::parse_generic_reply_for_proof_checking(result, reply, sp_key.as_deref());
+
+        let generator = Generator::from_bytes(&request_handler::DEFAULT_GENERATOR.from_base58()?)?;
+
+        let state_proofs: Vec<StateProofVerification> = parsed_sps
+            .iter()
+            .flatten()
+            .map(|parsed_sp| StateProofVerification {
+                root_hash: parsed_sp.root_hash.clone(),
+                participants: parsed_sp.multi_signature["participants"]
+                    .as_array()
+                    .map(|participants| {
+                        participants
+                            .iter()
+                            .filter_map(|participant| participant.as_str().map(String::from))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                timestamp: parsed_sp.multi_signature["value"]["timestamp"].as_u64(),
+                multi_signature_valid: state_proof::verify_sp_multi_signature(
+                    parsed_sp, &nodes, f, &generator,
+                ),
+                proof_valid: state_proof::verify_sp_proof(parsed_sp),
+            })
+            .collect();
+
+        let state_proof_found = parsed_sps.is_some();
+
+        Ok(ReplyVerification {
+            verified: state_proof_found
+                && !state_proofs.is_empty()
+                && state_proofs
+                    .iter()
+                    .all(|sp| sp.multi_signature_valid && sp.proof_valid),
+            state_proof_found,
+            state_proofs,
+            last_signed_time: Self::get_last_signed_time(reply),
+            nodes_count: nodes.len(),
+            f,
+        })
+    }
 }
 
 lazy_static! {
     static ref THRESHOLD: std::sync::RwLock<u64> = std::sync::RwLock::new(600);
 }
 
+async fn _fetch_genesis_txns(genesis_url: &str) -> IndyResult<String> {
+    let url = Url::parse(genesis_url).to_indy(
+        IndyErrorKind::InvalidStructure,
+        format!("Invalid genesis url {:?}", genesis_url),
+    )?;
+
+    let mut resp = H1Client::new()
+        .send(HttpRequest::new(Method::Get, url))
+        .await
+        .map_err(|err| {
+            err_msg(
+                IndyErrorKind::IOError,
+                format!("Can't fetch genesis transactions from {:?}: {}", genesis_url, err),
+            )
+        })?;
+
+    if !resp.status().is_success() {
+        return Err(err_msg(
+            IndyErrorKind::IOError,
+            format!(
+                "Can't fetch genesis transactions from {:?}: status {}",
+                genesis_url,
+                resp.status()
+            ),
+        ));
+    }
+
+    resp.body_string().await.map_err(|err| {
+        err_msg(
+            IndyErrorKind::IOError,
+            format!("Can't read genesis transactions from {:?}: {}", genesis_url, err),
+        )
+    })
+}
+
 fn _handle_response_message_type<T>(message: Message<T>) -> IndyResult<Reply<T>>
 where
     T: DeserializeOwned + ::std::fmt::Debug,
@@ -577,9 +1143,16 @@ pub mod tests {
     mod pool_service {
         use super::*;
 
+        use std::iter::FromIterator;
         use std::path;
 
         use indy_api_types::INVALID_POOL_HANDLE;
+        use indy_utils::crypto::ed25519_sign;
+        use libc::c_char;
+
+        use crate::domain::ledger::constants;
+        use crate::domain::pool::PoolSnapshotSignature;
+        use crate::services::pool::catchup::{check_nodes_responses_on_status, CatchupProgress};
 
         #[test]
         fn pool_service_new_works() {
@@ -627,13 +1200,13 @@ pub mod tests {
         }
 
         #[async_std::test]
-        async fn pool_service_refresh_works() {
-            test::cleanup_storage("pool_service_refresh_works");
+        async fn pool_service_close_works_for_namespace() {
+            test::cleanup_storage("pool_service_close_works_for_namespace");
 
             let ps = PoolService::new();
             let pool_id = next_pool_handle();
             let (send_cmd_sock, recv_cmd_sock) =
-                pool_create_pair_of_sockets("pool_service_refresh_works");
+                pool_create_pair_of_sockets("pool_service_close_works_for_namespace");
 
             ps.open_pools.lock().await.insert(
                 pool_id,
@@ -642,12 +1215,44 @@ pub mod tests {
                     send_cmd_sock,
                 )),
             );
+            ps.namespaces.lock().await.insert("sovrin".to_string(), pool_id);
+
+            assert_eq!(pool_id, ps.get_pool_handle_for_namespace("sovrin").await.unwrap());
 
             let pool_mock = thread::spawn(move || {
-                assert_eq!(
-                    1,
-                    zmq::poll(&mut [recv_cmd_sock.as_poll_item(zmq::POLLIN)], 10_000).unwrap()
-                );
+                recv_cmd_sock.recv_multipart(0).unwrap();
+                block_on(PoolService::close_ack(pool_id, Ok(())));
+            });
+
+            block_on(ps.close(pool_id)).unwrap();
+            pool_mock.join().unwrap();
+
+            let err = ps.get_pool_handle_for_namespace("sovrin").await.unwrap_err();
+            assert_eq!(IndyErrorKind::InvalidPoolHandle, err.kind());
+        }
+
+        #[async_std::test]
+        async fn pool_service_refresh_works() {
+            test::cleanup_storage("pool_service_refresh_works");
+
+            let ps = PoolService::new();
+            let pool_id = next_pool_handle();
+            let (send_cmd_sock, recv_cmd_sock) =
+                pool_create_pair_of_sockets("pool_service_refresh_works");
+
+            ps.open_pools.lock().await.insert(
+                pool_id,
+                Arc::new(ZMQPool::new(
+                    Pool::new("", pool_id, PoolOpenConfig::default()),
+                    send_cmd_sock,
+                )),
+            );
+
+            let pool_mock = thread::spawn(move || {
+                assert_eq!(
+                    1,
+                    zmq::poll(&mut [recv_cmd_sock.as_poll_item(zmq::POLLIN)], 10_000).unwrap()
+                );
                 let recv = recv_cmd_sock.recv_multipart(zmq::DONTWAIT).unwrap();
                 assert_eq!(recv.len(), 3);
                 assert_eq!(COMMAND_REFRESH, String::from_utf8(recv[0].clone()).unwrap());
@@ -700,6 +1305,544 @@ pub mod tests {
             test::cleanup_storage("pool_service_delete_works_for_opened");
         }
 
+        fn _genesis_config(genesis_txn_data: &str, genesis_hash: Option<&str>) -> PoolConfig {
+            PoolConfig {
+                genesis_txn: None,
+                genesis_txn_data: Some(genesis_txn_data.to_string()),
+                genesis_url: None,
+                genesis_hash: genesis_hash.map(String::from),
+                namespace: None,
+                snapshot: None,
+                snapshot_verkey: None,
+            }
+        }
+
+        fn _genesis_txns(count: usize) -> String {
+            test::gen_txns()[0..count].join("\n")
+        }
+
+        fn _read_genesis_txns(pool_name: &str) -> String {
+            let mut path = environment::pool_path(pool_name);
+            path.push(pool_name);
+            path.set_extension("txn");
+            fs::read_to_string(path).unwrap()
+        }
+
+        #[async_std::test]
+        async fn pool_service_create_works_for_genesis_txn_data() {
+            test::cleanup_storage("pool_service_create_works_for_genesis_txn_data");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_create_works_for_genesis_txn_data";
+            let txns = _genesis_txns(4);
+
+            ps.create(pool_name, Some(_genesis_config(&txns, None))).await.unwrap();
+
+            assert_eq!(txns, _read_genesis_txns(pool_name));
+            assert!(ps._read_config(pool_name).unwrap().unwrap().genesis_txn_data.is_none());
+
+            test::cleanup_storage("pool_service_create_works_for_genesis_txn_data");
+        }
+
+        #[async_std::test]
+        async fn pool_service_create_works_for_genesis_hash() {
+            test::cleanup_storage("pool_service_create_works_for_genesis_hash");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_create_works_for_genesis_hash";
+            let txns = _genesis_txns(4);
+            let hash = hex::encode(Sha256::digest(txns.as_bytes()));
+
+            ps.create(pool_name, Some(_genesis_config(&txns, Some(&hash)))).await.unwrap();
+
+            assert_eq!(Some(hash), ps._read_config(pool_name).unwrap().unwrap().genesis_hash);
+
+            test::cleanup_storage("pool_service_create_works_for_genesis_hash");
+        }
+
+        #[async_std::test]
+        async fn pool_service_create_works_for_genesis_hash_mismatch() {
+            test::cleanup_storage("pool_service_create_works_for_genesis_hash_mismatch");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_create_works_for_genesis_hash_mismatch";
+            let hash = hex::encode(Sha256::digest(b"other"));
+
+            let res = ps.create(pool_name, Some(_genesis_config(&_genesis_txns(4), Some(&hash)))).await;
+
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+            assert!(!environment::pool_path(pool_name).exists());
+
+            test::cleanup_storage("pool_service_create_works_for_genesis_hash_mismatch");
+        }
+
+        #[async_std::test]
+        async fn pool_service_create_works_for_invalid_genesis_url() {
+            test::cleanup_storage("pool_service_create_works_for_invalid_genesis_url");
+
+            let ps = PoolService::new();
+            let config = PoolConfig {
+                genesis_txn: None,
+                genesis_txn_data: None,
+                genesis_url: Some("not an url".to_string()),
+                genesis_hash: Some(hex::encode(Sha256::digest(b"other"))),
+                namespace: None,
+                snapshot: None,
+                snapshot_verkey: None,
+            };
+
+            let res = ps.create("pool_service_create_works_for_invalid_genesis_url", Some(config)).await;
+
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+
+            test::cleanup_storage("pool_service_create_works_for_invalid_genesis_url");
+        }
+
+        #[async_std::test]
+        async fn pool_service_update_genesis_works() {
+            test::cleanup_storage("pool_service_update_genesis_works");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_update_genesis_works";
+            let config = PoolConfig {
+                namespace: Some("sovrin".to_string()),
+                .._genesis_config(&_genesis_txns(2), None)
+            };
+
+            ps.create(pool_name, Some(config)).await.unwrap();
+
+            let txns = _genesis_txns(4);
+            ps.update_genesis(pool_name, _genesis_config(&txns, None)).await.unwrap();
+
+            assert_eq!(txns, _read_genesis_txns(pool_name));
+            assert_eq!(4, merkle_tree_factory::create(pool_name).unwrap().count());
+            assert_eq!(Some("sovrin".to_string()), ps._read_namespace(pool_name).unwrap());
+
+            let mut tmp_path = environment::pool_path(pool_name);
+            tmp_path.push(format!("{}.txn.tmp", pool_name));
+            assert!(!tmp_path.exists());
+
+            test::cleanup_storage("pool_service_update_genesis_works");
+        }
+
+        #[async_std::test]
+        async fn pool_service_update_genesis_works_for_pinned_genesis_hash() {
+            test::cleanup_storage("pool_service_update_genesis_works_for_pinned_genesis_hash");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_update_genesis_works_for_pinned_genesis_hash";
+            let txns = _genesis_txns(2);
+            let hash = hex::encode(Sha256::digest(txns.as_bytes()));
+
+            ps.create(pool_name, Some(_genesis_config(&txns, Some(&hash)))).await.unwrap();
+
+            let res = ps.update_genesis(pool_name, _genesis_config(&_genesis_txns(4), None)).await;
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+            assert_eq!(txns, _read_genesis_txns(pool_name));
+
+            ps.update_genesis(pool_name, _genesis_config(&txns, None)).await.unwrap();
+
+            let stored_config = ps._read_config(pool_name).unwrap().unwrap();
+            assert_eq!(Some(hash), stored_config.genesis_hash);
+
+            test::cleanup_storage("pool_service_update_genesis_works_for_pinned_genesis_hash");
+        }
+
+        #[async_std::test]
+        async fn pool_service_update_genesis_works_for_new_genesis_hash() {
+            test::cleanup_storage("pool_service_update_genesis_works_for_new_genesis_hash");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_update_genesis_works_for_new_genesis_hash";
+            let txns = _genesis_txns(2);
+            let hash = hex::encode(Sha256::digest(txns.as_bytes()));
+
+            ps.create(pool_name, Some(_genesis_config(&txns, Some(&hash)))).await.unwrap();
+
+            let new_txns = _genesis_txns(4);
+            let new_hash = hex::encode(Sha256::digest(new_txns.as_bytes()));
+
+            ps.update_genesis(pool_name, _genesis_config(&new_txns, Some(&new_hash)))
+                .await
+                .unwrap();
+
+            assert_eq!(new_txns, _read_genesis_txns(pool_name));
+
+            let stored_config = ps._read_config(pool_name).unwrap().unwrap();
+            assert_eq!(Some(new_hash), stored_config.genesis_hash);
+
+            test::cleanup_storage("pool_service_update_genesis_works_for_new_genesis_hash");
+        }
+
+        #[async_std::test]
+        async fn pool_service_export_snapshot_works() {
+            test::cleanup_storage("pool_service_export_snapshot_works");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_export_snapshot_works";
+
+            ps.create(pool_name, Some(_genesis_config(&_genesis_txns(4), None))).await.unwrap();
+
+            let snapshot = ps.export_snapshot(pool_name).await.unwrap();
+            let merkle_tree = merkle_tree_factory::from_data(&_genesis_txns(4)).unwrap();
+
+            assert_eq!(4, snapshot.ledger_size);
+            assert_eq!(4, snapshot.txns.len());
+            assert_eq!(merkle_tree.root_hash().to_base58(), snapshot.root_hash);
+
+            test::cleanup_storage("pool_service_export_snapshot_works");
+        }
+
+        #[async_std::test]
+        async fn pool_service_export_snapshot_works_for_not_created() {
+            let ps = PoolService::new();
+
+            let res = ps.export_snapshot("pool_service_export_snapshot_works_for_not_created").await;
+            assert_eq!(IndyErrorKind::PoolNotCreated, res.unwrap_err().kind());
+        }
+
+        fn _snapshot(count: usize) -> PoolSnapshot {
+            let merkle_tree = merkle_tree_factory::from_data(&_genesis_txns(count)).unwrap();
+
+            PoolSnapshot {
+                ledger_size: count,
+                root_hash: merkle_tree.root_hash().to_base58(),
+                txns: test::gen_txns()[0..count]
+                    .iter()
+                    .map(|txn| serde_json::from_str(txn).unwrap())
+                    .collect(),
+                signature: None,
+            }
+        }
+
+        fn _signed_snapshot(count: usize) -> (PoolSnapshot, String) {
+            let (vk, sk) = ed25519_sign::create_key_pair_for_signature(None).unwrap();
+            let mut snapshot = _snapshot(count);
+
+            let signature = ed25519_sign::sign(&sk, &snapshot.signed_data()).unwrap();
+
+            snapshot.signature = Some(PoolSnapshotSignature {
+                verkey: vk[..].to_base58(),
+                signature: signature[..].to_base58(),
+            });
+
+            (snapshot, vk[..].to_base58())
+        }
+
+        #[async_std::test]
+        async fn pool_service_create_works_for_snapshot() {
+            test::cleanup_storage("pool_service_create_works_for_snapshot");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_create_works_for_snapshot";
+            let config = PoolConfig {
+                snapshot: Some(_snapshot(4)),
+                .._genesis_config(&_genesis_txns(2), None)
+            };
+
+            ps.create(pool_name, Some(config)).await.unwrap();
+
+            assert_eq!(_genesis_txns(2), _read_genesis_txns(pool_name));
+            assert_eq!(4, merkle_tree_factory::create(pool_name).unwrap().count());
+            assert_eq!(_snapshot(4), ps.export_snapshot(pool_name).await.unwrap());
+            assert!(ps._read_config(pool_name).unwrap().unwrap().snapshot.is_none());
+
+            test::cleanup_storage("pool_service_create_works_for_snapshot");
+        }
+
+        #[async_std::test]
+        async fn pool_service_create_works_for_snapshot_not_extending_genesis() {
+            test::cleanup_storage("pool_service_create_works_for_snapshot_not_extending_genesis");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_create_works_for_snapshot_not_extending_genesis";
+            let config = PoolConfig {
+                snapshot: Some(_snapshot(2)),
+                .._genesis_config(&_genesis_txns(4), None)
+            };
+
+            let res = ps.create(pool_name, Some(config)).await;
+
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+            assert!(!environment::pool_path(pool_name).exists());
+
+            test::cleanup_storage("pool_service_create_works_for_snapshot_not_extending_genesis");
+        }
+
+        #[async_std::test]
+        async fn pool_service_create_works_for_snapshot_root_hash_mismatch() {
+            test::cleanup_storage("pool_service_create_works_for_snapshot_root_hash_mismatch");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_create_works_for_snapshot_root_hash_mismatch";
+            let config = PoolConfig {
+                snapshot: Some(PoolSnapshot {
+                    root_hash: _snapshot(3).root_hash,
+                    .._snapshot(4)
+                }),
+                .._genesis_config(&_genesis_txns(2), None)
+            };
+
+            let res = ps.create(pool_name, Some(config)).await;
+
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+            assert!(!environment::pool_path(pool_name).exists());
+
+            test::cleanup_storage("pool_service_create_works_for_snapshot_root_hash_mismatch");
+        }
+
+        #[async_std::test]
+        async fn pool_service_catchup_restarts_from_genesis_for_snapshot_diverging_from_pool() {
+            test::cleanup_storage("pool_service_catchup_restarts_from_genesis_for_snapshot_diverging_from_pool");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_catchup_restarts_from_genesis_for_snapshot_diverging_from_pool";
+
+            // The snapshot is consistent in itself, so it can be imported, but nodes have other transactions
+            let mut txns = _snapshot(4).txns;
+            txns[3]["txn"]["data"]["data"]["alias"] = json!("Node5");
+
+            let tampered_txns = txns.iter().map(|txn| txn.to_string()).collect::<Vec<String>>().join("\n");
+
+            let config = PoolConfig {
+                snapshot: Some(PoolSnapshot {
+                    ledger_size: 4,
+                    root_hash: merkle_tree_factory::from_data(&tampered_txns).unwrap().root_hash().to_base58(),
+                    txns,
+                    signature: None,
+                }),
+                .._genesis_config(&_genesis_txns(2), None)
+            };
+
+            ps.create(pool_name, Some(config)).await.unwrap();
+
+            let merkle_tree = merkle_tree_factory::create(pool_name).unwrap();
+            assert_eq!(4, merkle_tree.count());
+
+            let pool_root_hash = merkle_tree_factory::from_data(&_genesis_txns(4)).unwrap().root_hash().to_base58();
+
+            let mut votes = HashMap::new();
+            votes.insert((pool_root_hash, 4, None), HashSet::from_iter(vec!["n1".to_string()]));
+
+            let progress = check_nodes_responses_on_status(&votes, &merkle_tree, 1, 0, pool_name).unwrap();
+
+            match progress {
+                CatchupProgress::ShouldBeStarted(_, target_mt_size, merkle_tree) => {
+                    assert_eq!(4, target_mt_size);
+                    assert_eq!(2, merkle_tree.count());
+                }
+                _ => panic!("Catch-up from genesis expected"),
+            }
+
+            assert_eq!(2, merkle_tree_factory::create(pool_name).unwrap().count());
+
+            test::cleanup_storage("pool_service_catchup_restarts_from_genesis_for_snapshot_diverging_from_pool");
+        }
+
+        #[async_std::test]
+        async fn pool_service_create_works_for_signed_snapshot() {
+            test::cleanup_storage("pool_service_create_works_for_signed_snapshot");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_create_works_for_signed_snapshot";
+            let (snapshot, verkey) = _signed_snapshot(4);
+            let config = PoolConfig {
+                snapshot: Some(snapshot),
+                snapshot_verkey: Some(verkey),
+                .._genesis_config(&_genesis_txns(2), None)
+            };
+
+            ps.create(pool_name, Some(config)).await.unwrap();
+
+            assert_eq!(4, merkle_tree_factory::create(pool_name).unwrap().count());
+            assert!(ps._read_config(pool_name).unwrap().unwrap().snapshot_verkey.is_none());
+
+            test::cleanup_storage("pool_service_create_works_for_signed_snapshot");
+        }
+
+        #[async_std::test]
+        async fn pool_service_create_works_for_tampered_signed_snapshot() {
+            test::cleanup_storage("pool_service_create_works_for_tampered_signed_snapshot");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_create_works_for_tampered_signed_snapshot";
+            let (snapshot, verkey) = _signed_snapshot(4);
+            let config = PoolConfig {
+                snapshot: Some(PoolSnapshot {
+                    signature: snapshot.signature,
+                    .._snapshot(3)
+                }),
+                snapshot_verkey: Some(verkey),
+                .._genesis_config(&_genesis_txns(2), None)
+            };
+
+            let res = ps.create(pool_name, Some(config)).await;
+
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+            assert!(!environment::pool_path(pool_name).exists());
+
+            test::cleanup_storage("pool_service_create_works_for_tampered_signed_snapshot");
+        }
+
+        #[async_std::test]
+        async fn pool_service_create_works_for_snapshot_signed_with_other_key() {
+            test::cleanup_storage("pool_service_create_works_for_snapshot_signed_with_other_key");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_create_works_for_snapshot_signed_with_other_key";
+            let (snapshot, _) = _signed_snapshot(4);
+            let (_, other_verkey) = _signed_snapshot(4);
+            let config = PoolConfig {
+                snapshot: Some(snapshot),
+                snapshot_verkey: Some(other_verkey),
+                .._genesis_config(&_genesis_txns(2), None)
+            };
+
+            let res = ps.create(pool_name, Some(config)).await;
+
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+
+            test::cleanup_storage("pool_service_create_works_for_snapshot_signed_with_other_key");
+        }
+
+        #[async_std::test]
+        async fn pool_service_create_works_for_unsigned_snapshot_and_verkey() {
+            test::cleanup_storage("pool_service_create_works_for_unsigned_snapshot_and_verkey");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_create_works_for_unsigned_snapshot_and_verkey";
+            let (_, verkey) = _signed_snapshot(4);
+            let config = PoolConfig {
+                snapshot: Some(_snapshot(4)),
+                snapshot_verkey: Some(verkey),
+                .._genesis_config(&_genesis_txns(2), None)
+            };
+
+            let res = ps.create(pool_name, Some(config)).await;
+
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+
+            test::cleanup_storage("pool_service_create_works_for_unsigned_snapshot_and_verkey");
+        }
+
+        #[async_std::test]
+        async fn pool_service_update_genesis_works_for_snapshot() {
+            test::cleanup_storage("pool_service_update_genesis_works_for_snapshot");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_update_genesis_works_for_snapshot";
+
+            ps.create(pool_name, Some(_genesis_config(&_genesis_txns(2), None))).await.unwrap();
+
+            let config = PoolConfig {
+                snapshot: Some(_snapshot(4)),
+                .._genesis_config(&_genesis_txns(2), None)
+            };
+
+            ps.update_genesis(pool_name, config).await.unwrap();
+
+            assert_eq!(4, merkle_tree_factory::create(pool_name).unwrap().count());
+
+            test::cleanup_storage("pool_service_update_genesis_works_for_snapshot");
+        }
+
+        fn _get_nym_request() -> String {
+            json!({
+                "reqId": 1,
+                "identifier": "Th7MpTaRZVRYnPiabds81Y",
+                "operation": {"type": "105", "dest": "V4SGRU86Z58d6TV7PBUe6f"},
+                "protocolVersion": 2
+            })
+            .to_string()
+        }
+
+        #[test]
+        fn pool_service_verify_ledger_reply_works_for_reply_without_state_proof() {
+            let ps = PoolService::new();
+            let reply = json!({
+                "op": "REPLY",
+                "result": {"type": "105", "dest": "V4SGRU86Z58d6TV7PBUe6f", "data": null, "seqNo": null, "txnTime": null}
+            })
+            .to_string();
+
+            let res = ps
+                .verify_ledger_reply(None, Some(&_genesis_txns(4)), &_get_nym_request(), &reply)
+                .unwrap();
+
+            assert!(!res.verified);
+            assert!(!res.state_proof_found);
+            assert!(res.state_proofs.is_empty());
+            assert_eq!(4, res.nodes_count);
+            assert_eq!(1, res.f);
+        }
+
+        #[test]
+        fn pool_service_verify_ledger_reply_works_for_no_pool_source() {
+            let ps = PoolService::new();
+
+            let res = ps.verify_ledger_reply(None, None, &_get_nym_request(), "{}");
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+
+            let res = ps.verify_ledger_reply(Some("pool"), Some(&_genesis_txns(4)), &_get_nym_request(), "{}");
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn pool_service_verify_ledger_reply_works_for_not_created_pool() {
+            test::cleanup_storage("pool_service_verify_ledger_reply_works_for_not_created_pool");
+
+            let ps = PoolService::new();
+
+            let res = ps.verify_ledger_reply(
+                Some("pool_service_verify_ledger_reply_works_for_not_created_pool"),
+                None,
+                &_get_nym_request(),
+                "{}",
+            );
+
+            assert_eq!(IndyErrorKind::PoolNotCreated, res.unwrap_err().kind());
+        }
+
+        #[async_std::test]
+        async fn pool_service_update_genesis_works_for_not_created() {
+            test::cleanup_storage("pool_service_update_genesis_works_for_not_created");
+
+            let ps = PoolService::new();
+
+            let res = ps
+                .update_genesis("pool_service_update_genesis_works_for_not_created", _genesis_config(&_genesis_txns(4), None))
+                .await;
+
+            assert_eq!(IndyErrorKind::PoolNotCreated, res.unwrap_err().kind());
+        }
+
+        #[async_std::test]
+        async fn pool_service_update_genesis_works_for_opened() {
+            test::cleanup_storage("pool_service_update_genesis_works_for_opened");
+
+            let (send_cmd_sock, _recv_cmd_sock) =
+                pool_create_pair_of_sockets("pool_service_update_genesis_works_for_opened");
+            let ps = PoolService::new();
+            let pool_name = "pool_service_update_genesis_works_for_opened";
+            let txns = _genesis_txns(2);
+
+            ps.create(pool_name, Some(_genesis_config(&txns, None))).await.unwrap();
+
+            let pool_id = next_pool_handle();
+            let pool = Pool::new(pool_name, pool_id, PoolOpenConfig::default());
+            ps.open_pools
+                .lock()
+                .await
+                .insert(pool_id, Arc::new(ZMQPool::new(pool, send_cmd_sock)));
+
+            let res = ps.update_genesis(pool_name, _genesis_config(&_genesis_txns(4), None)).await;
+
+            assert_eq!(IndyErrorKind::InvalidState, res.unwrap_err().kind());
+            assert_eq!(txns, _read_genesis_txns(pool_name));
+
+            test::cleanup_storage("pool_service_update_genesis_works_for_opened");
+        }
+
         #[async_std::test]
         async fn pool_send_tx_works() {
             test::cleanup_storage("pool_send_tx_works");
@@ -767,6 +1910,53 @@ pub mod tests {
             assert_eq!(IndyErrorKind::InvalidPoolHandle, res.unwrap_err().kind());
         }
 
+        #[async_std::test]
+        async fn pool_get_status_works_for_invalid_handle() {
+            test::cleanup_storage("pool_get_status_works_for_invalid_handle");
+            let ps = PoolService::new();
+            let res = ps.get_status(INVALID_POOL_HANDLE).await;
+            assert_eq!(IndyErrorKind::InvalidPoolHandle, res.unwrap_err().kind());
+        }
+
+        #[async_std::test]
+        async fn pool_service_set_taa_acceptance_works_for_invalid_handle() {
+            let ps = PoolService::new();
+
+            let acceptance = TxnAuthrAgrmtAcceptanceData {
+                mechanism: "acceptance type 1".to_string(),
+                taa_digest: "050e52a57837fff904d3d059c8a123e3a04177042bf467db2b2c27abd8045d5e".to_string(),
+                time: 123379200,
+            };
+
+            let res = ps.set_taa_acceptance(INVALID_POOL_HANDLE, acceptance).await;
+
+            assert_eq!(IndyErrorKind::InvalidPoolHandle, res.unwrap_err().kind());
+            assert!(ps.get_taa_acceptance(INVALID_POOL_HANDLE).await.is_none());
+        }
+
+        #[async_std::test]
+        async fn pool_service_taa_acceptance_is_stored_with_pool_config() {
+            test::cleanup_storage("pool_service_taa_acceptance_is_stored_with_pool_config");
+
+            let ps = PoolService::new();
+            let pool_name = "pool_service_taa_acceptance_is_stored_with_pool_config";
+
+            ps.create(pool_name, Some(_genesis_config(&_genesis_txns(4), None))).await.unwrap();
+            assert!(ps._read_taa_acceptance(pool_name).unwrap().is_none());
+
+            let acceptance = TxnAuthrAgrmtAcceptanceData {
+                mechanism: "acceptance type 1".to_string(),
+                taa_digest: "050e52a57837fff904d3d059c8a123e3a04177042bf467db2b2c27abd8045d5e".to_string(),
+                time: 123379200,
+            };
+
+            ps._write_taa_acceptance(pool_name, &acceptance).unwrap();
+
+            assert_eq!(Some(acceptance), PoolService::new()._read_taa_acceptance(pool_name).unwrap());
+
+            test::cleanup_storage("pool_service_taa_acceptance_is_stored_with_pool_config");
+        }
+
         #[async_std::test]
         async fn pool_send_action_works() {
             test::cleanup_storage("pool_send_action_works");
@@ -825,48 +2015,54 @@ pub mod tests {
             assert_eq!(IndyErrorKind::InvalidPoolHandle, res.unwrap_err().kind());
         }
 
-        // FIXME:
-        // #[test]
-        // fn pool_register_sp_parser_works() {
-        //     test::cleanup_storage("pool_register_sp_parser_works");
-        //     REGISTERED_SP_PARSERS.lock().unwrap().clear();
-        //     extern "C" fn test_sp(
-        //         _reply_from_node: *const c_char,
-        //         _parsed_sp: *mut *const c_char,
-        //     ) -> ErrorCode {
-        //         ErrorCode::Success
-        //     }
-        //     extern "C" fn test_free(_data: *const c_char) -> ErrorCode {
-        //         ErrorCode::Success
-        //     }
-        //     PoolService::register_sp_parser("test", test_sp, test_free).unwrap();
-        // }
-
-        // FIMXE:
-        // #[test]
-        // fn pool_get_sp_parser_works() {
-        //     test::cleanup_storage("pool_get_sp_parser_works");
-        //     REGISTERED_SP_PARSERS.lock().unwrap().clear();
-        //     extern "C" fn test_sp(
-        //         _reply_from_node: *const c_char,
-        //         _parsed_sp: *mut *const c_char,
-        //     ) -> ErrorCode {
-        //         ErrorCode::Success
-        //     }
-        //     extern "C" fn test_free(_data: *const c_char) -> ErrorCode {
-        //         ErrorCode::Success
-        //     }
-        //     PoolService::register_sp_parser("test", test_sp, test_free).unwrap();
-        //     PoolService::get_sp_parser("test").unwrap();
-        // }
-
-        // FIXME:
-        // #[test]
-        // fn pool_get_sp_parser_works_for_invalid_name() {
-        //     test::cleanup_storage("pool_get_sp_parser_works_for_invalid_name");
-        //     REGISTERED_SP_PARSERS.lock().unwrap().clear();
-        //     assert_eq!(None, PoolService::get_sp_parser("test"));
-        // }
+        extern "C" fn test_sp(_reply_from_node: *const c_char, parsed_sp: *mut *const c_char) -> ErrorCode {
+            let sp = CString::new("[]").unwrap();
+            unsafe { *parsed_sp = sp.into_raw(); }
+            ErrorCode::Success
+        }
+
+        extern "C" fn test_sp_err(_reply_from_node: *const c_char, _parsed_sp: *mut *const c_char) -> ErrorCode {
+            ErrorCode::CommonInvalidStructure
+        }
+
+        extern "C" fn test_free(data: *const c_char) -> ErrorCode {
+            unsafe { CString::from_raw(data as *mut c_char); }
+            ErrorCode::Success
+        }
+
+        #[test]
+        fn pool_register_sp_parser_works() {
+            let parser = Arc::new(CustomStateProofParser::new(test_sp, test_free));
+            PoolService::register_sp_parser("pool_register_sp_parser_works", parser).unwrap();
+        }
+
+        #[test]
+        fn pool_register_sp_parser_works_for_builtin_type() {
+            let parser = Arc::new(CustomStateProofParser::new(test_sp, test_free));
+            let res = PoolService::register_sp_parser(constants::GET_NYM, parser);
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn pool_get_sp_parser_works() {
+            let parser = Arc::new(CustomStateProofParser::new(test_sp, test_free));
+            PoolService::register_sp_parser("pool_get_sp_parser_works", parser).unwrap();
+
+            let parser = PoolService::get_sp_parser("pool_get_sp_parser_works").unwrap();
+            assert!(parser.parse("{}").unwrap().is_empty());
+        }
+
+        #[test]
+        fn pool_get_sp_parser_works_for_invalid_name() {
+            assert!(PoolService::get_sp_parser("pool_get_sp_parser_works_for_invalid_name").is_none());
+        }
+
+        #[test]
+        fn custom_sp_parser_parse_works_for_parser_error() {
+            let parser = CustomStateProofParser::new(test_sp_err, test_free);
+            let res = parser.parse("{}");
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+        }
     }
 
     #[async_std::test]
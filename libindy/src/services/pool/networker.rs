@@ -1,4 +1,5 @@
-use std::sync::Mutex;
+use std::cmp::Ordering;
+use std::sync::{Arc, Mutex};
 use std::collections::{BTreeMap, HashMap, HashSet};
 
 use rand::thread_rng;
@@ -6,7 +7,9 @@ use rand::prelude::SliceRandom;
 use time::Tm;
 
 use indy_api_types::errors::prelude::*;
+use crate::domain::pool::{ReadNodesSelection, NUMBER_READ_NODES};
 use crate::services::pool::events::*;
+use crate::services::pool::node_stats::NodesStats;
 use crate::services::pool::types::*;
 use indy_utils::sequence;
 use indy_utils::crypto::base64;
@@ -22,6 +25,35 @@ pub trait Networker {
     fn process_event(&mut self, pe: Option<NetworkerEvent>) -> Option<RequestEvent>;
     fn get_timeout(&self) -> ((String, String), i64);
     fn get_poll_items(&self) -> Vec<PollItem>;
+    fn set_stats(&mut self, stats: Arc<Mutex<NodesStats>>);
+    fn set_transport(&mut self, transport: Arc<dyn Transport>);
+}
+
+/// Opens the sockets the networker uses to talk to pool nodes.
+///
+/// Returned socket must behave as a DEALER: every message sent is one request to the node,
+/// every message received is one node reply.
+pub trait Transport: Send + Sync {
+    fn connect(&self, node: &RemoteNode, key_pair: &zmq::CurveKeyPair) -> IndyResult<ZSocket>;
+}
+
+/// Default transport: CurveZMQ connection to the node client address.
+pub struct ZMQTransport {
+    ctx: zmq::Context,
+}
+
+impl Default for ZMQTransport {
+    fn default() -> ZMQTransport {
+        ZMQTransport {
+            ctx: zmq::Context::new(),
+        }
+    }
+}
+
+impl Transport for ZMQTransport {
+    fn connect(&self, node: &RemoteNode, key_pair: &zmq::CurveKeyPair) -> IndyResult<ZSocket> {
+        node.connect(&self.ctx, key_pair)
+    }
 }
 
 pub struct ZMQNetworker {
@@ -31,6 +63,8 @@ pub struct ZMQNetworker {
     active_timeout: i64,
     conn_limit: usize,
     preordered_nodes: Vec<String>,
+    stats: Arc<Mutex<NodesStats>>,
+    transport: Arc<dyn Transport>,
 }
 
 impl Networker for ZMQNetworker {
@@ -42,6 +76,8 @@ impl Networker for ZMQNetworker {
             active_timeout,
             conn_limit,
             preordered_nodes,
+            stats: Arc::new(Mutex::new(NodesStats::new(NUMBER_READ_NODES, ReadNodesSelection::Random))),
+            transport: Arc::new(ZMQTransport::default()),
         }
     }
 
@@ -84,7 +120,9 @@ impl Networker for ZMQNetworker {
                     None => {
                         trace!("send request in new conn");
                         let pc_id = sequence::get_next_id();
-                        let mut pc = PoolConnection::new(self.nodes.clone(), self.active_timeout, self.preordered_nodes.clone());
+                        let scores = self.stats.lock().unwrap().get_scores();
+                        let mut pc = PoolConnection::with_transport(self.nodes.clone(), self.active_timeout, self.preordered_nodes.clone(), scores.as_ref(), self.transport.clone());
+                        self.stats.lock().unwrap().update_read_order(&pc.nodes);
                         pc.send_request(pe).expect("FIXME");
                         self.pool_connections.insert(pc_id, pc);
                         self.req_id_mappings.insert(req_id.clone(), pc_id);
@@ -94,6 +132,7 @@ impl Networker for ZMQNetworker {
             }
             Some(NetworkerEvent::NodesStateUpdated(nodes)) => {
                 trace!("ZMQNetworker::process_event: nodes_updated {:?}", nodes);
+                self.stats.lock().unwrap().update_nodes(&nodes);
                 self.nodes = nodes;
                 None
             }
@@ -137,6 +176,18 @@ impl Networker for ZMQNetworker {
 
                 None
             }
+            Some(NetworkerEvent::NodeReplied(req_id, node_alias)) => {
+                let latency = self.req_id_mappings.get(&req_id)
+                    .and_then(|idx| self.pool_connections.get(idx))
+                    .and_then(|pc| pc.take_sent_time(&req_id, &node_alias))
+                    .map(|sent| (time::now() - sent).num_milliseconds());
+                self.stats.lock().unwrap().record_reply(&node_alias, latency);
+                None
+            }
+            Some(NetworkerEvent::NodeTimedOut(_, node_alias)) => {
+                self.stats.lock().unwrap().record_failure(&node_alias);
+                None
+            }
             Some(NetworkerEvent::Timeout) => {
                 let pc_to_delete: Vec<i32> = self.pool_connections.iter()
                     .filter(|(_, v)| v.is_orphaned())
@@ -163,26 +214,45 @@ impl Networker for ZMQNetworker {
         self.pool_connections.iter()
             .flat_map(|(_, pool)| pool.get_poll_items()).collect()
     }
+
+    fn set_stats(&mut self, stats: Arc<Mutex<NodesStats>>) {
+        self.stats = stats;
+    }
+
+    fn set_transport(&mut self, transport: Arc<dyn Transport>) {
+        self.transport = transport;
+    }
 }
 
 pub struct PoolConnection {
     nodes: Vec<RemoteNode>,
     sockets: Vec<Option<ZSocket>>,
-    ctx: zmq::Context,
+    transport: Arc<dyn Transport>,
     key_pair: zmq::CurveKeyPair,
     resend: Mutex<HashMap<String, (usize, String)>>,
     timeouts: Mutex<HashMap<(String, String), Tm>>,
+    sent: Mutex<HashMap<(String, String), Tm>>,
     time_created: time::Tm,
     req_cnt: usize,
     active_timeout: i64,
 }
 
 impl PoolConnection {
-    fn new(mut nodes: Vec<RemoteNode>, active_timeout: i64, preordered_nodes: Vec<String>) -> Self {
+    #[cfg(test)]
+    fn new(nodes: Vec<RemoteNode>, active_timeout: i64, preordered_nodes: Vec<String>, scores: Option<&HashMap<String, f64>>) -> Self {
+        PoolConnection::with_transport(nodes, active_timeout, preordered_nodes, scores, Arc::new(ZMQTransport::default()))
+    }
+
+    fn with_transport(mut nodes: Vec<RemoteNode>, active_timeout: i64, preordered_nodes: Vec<String>, scores: Option<&HashMap<String, f64>>, transport: Arc<dyn Transport>) -> Self {
         trace!("PoolConnection::new: from nodes {:?}", nodes);
 
         nodes.shuffle(&mut thread_rng());
 
+        if let Some(scores) = scores {
+            let score = |node: &RemoteNode| scores.get(&node.name).copied().unwrap_or(0.0);
+            nodes.sort_by(|node_1, node_2| score(node_1).partial_cmp(&score(node_2)).unwrap_or(Ordering::Equal));
+        }
+
         if !preordered_nodes.is_empty() {
             nodes.sort_by_key(|node: &RemoteNode| -> usize {
                 preordered_nodes.iter()
@@ -198,11 +268,12 @@ impl PoolConnection {
         PoolConnection {
             nodes,
             sockets,
-            ctx: zmq::Context::new(),
+            transport,
             key_pair: zmq::CurveKeyPair::new().expect("FIXME"),
             resend: Mutex::new(HashMap::new()),
             time_created: time::now(),
             timeouts: Mutex::new(HashMap::new()),
+            sent: Mutex::new(HashMap::new()),
             req_cnt: 0,
             active_timeout,
         }
@@ -299,16 +370,23 @@ impl PoolConnection {
     fn clean_timeout(&self, req_id: &str, node_alias: Option<String>) {
         match node_alias {
             Some(node_alias) => {
-                self.timeouts.lock().unwrap().remove(&(req_id.to_string(), node_alias));
+                let key = (req_id.to_string(), node_alias);
+                self.timeouts.lock().unwrap().remove(&key);
+                self.sent.lock().unwrap().remove(&key);
             }
             None => {
                 let keys_to_remove: Vec<(String, String)> = self.timeouts.lock().unwrap().keys()
                     .cloned().filter(|&(ref req_id_timeout, _)| req_id == req_id_timeout).collect();
                 keys_to_remove.iter().for_each(|key| { self.timeouts.lock().unwrap().remove(key); });
+                self.sent.lock().unwrap().retain(|(req_id_sent, _), _| req_id != req_id_sent);
             }
         }
     }
 
+    fn take_sent_time(&self, req_id: &str, node_alias: &str) -> Option<Tm> {
+        self.sent.lock().unwrap().remove(&(req_id.to_string(), node_alias.to_string()))
+    }
+
     fn has_active_requests(&self) -> bool {
         !self.timeouts.lock().unwrap().is_empty()
     }
@@ -323,7 +401,9 @@ impl PoolConnection {
             let s = self._get_socket(idx)?;
             s.send(&req, zmq::DONTWAIT)?;
         }
-        self.timeouts.lock().unwrap().insert((req_id, self.nodes[idx].name.clone()), time::now() + Duration::seconds(timeout));
+        let key = (req_id, self.nodes[idx].name.clone());
+        self.sent.lock().unwrap().insert(key.clone(), time::now());
+        self.timeouts.lock().unwrap().insert(key, time::now() + Duration::seconds(timeout));
         trace!("_send_msg_to_one_node <<");
         Ok(())
     }
@@ -331,7 +411,7 @@ impl PoolConnection {
     fn _get_socket(&mut self, idx: usize) -> IndyResult<&ZSocket> {
         if self.sockets[idx].is_none() {
             debug!("_get_socket: open new socket for node {}", idx);
-            let s: ZSocket = self.nodes[idx].connect(&self.ctx, &self.key_pair)?;
+            let s: ZSocket = self.transport.connect(&self.nodes[idx], &self.key_pair)?;
             self.sockets[idx] = Some(s)
         }
         Ok(self.sockets[idx].as_ref().unwrap())
@@ -382,6 +462,10 @@ impl Networker for MockNetworker {
     fn get_poll_items(&self) -> Vec<PollItem> {
         unimplemented!()
     }
+
+    fn set_stats(&mut self, _stats: Arc<Mutex<NodesStats>>) {}
+
+    fn set_transport(&mut self, _transport: Arc<dyn Transport>) {}
 }
 
 
@@ -516,6 +600,34 @@ pub mod networker_tests {
             }
         }
 
+        #[test]
+        fn networker_process_send_one_request_event_works_for_adaptive_selection() {
+            let mut txn_1 = nodes_emulator::node();
+            let handle_1 = nodes_emulator::start(&mut txn_1);
+            let rn_1 = _remote_node(&txn_1);
+
+            let mut txn_2 = nodes_emulator::node_2();
+            let handle_2 = nodes_emulator::start(&mut txn_2);
+            let rn_2 = _remote_node(&txn_2);
+
+            let stats = Arc::new(Mutex::new(NodesStats::new(1, ReadNodesSelection::Adaptive)));
+
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            networker.set_stats(stats.clone());
+
+            networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn_1.clone(), rn_2.clone()])));
+            stats.lock().unwrap().record_reply(&rn_1.name, Some(500));
+            stats.lock().unwrap().record_reply(&rn_2.name, Some(50));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+
+            assert_eq!(MESSAGE.to_string(), nodes_emulator::next(&handle_2).unwrap());
+            assert!(nodes_emulator::next(&handle_1).is_none());
+
+            let statuses = stats.lock().unwrap().get_nodes_status();
+            assert!(statuses.iter().find(|status| status.alias == txn_2.txn.data.data.alias).unwrap().in_read_set);
+            assert!(!statuses.iter().find(|status| status.alias == txn_1.txn.data.data.alias).unwrap().in_read_set);
+        }
+
         #[test]
         fn networker_process_send_all_request_event_works_for_list_nodes() {
             let mut txn_1 = nodes_emulator::node();
@@ -615,7 +727,7 @@ pub mod networker_tests {
         fn networker_process_timeout_event_works() {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
-            let conn = PoolConnection::new(vec![rn.clone()], POOL_CON_ACTIVE_TO, vec![]);
+            let conn = PoolConnection::new(vec![rn.clone()], POOL_CON_ACTIVE_TO, vec![], None);
 
             let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
@@ -645,6 +757,45 @@ pub mod networker_tests {
             assert!(networker.pool_connections.is_empty());
         }
 
+        #[test]
+        fn networker_process_node_replied_event_works() {
+            let mut txn = nodes_emulator::node();
+            let handle = nodes_emulator::start(&mut txn);
+            let rn = _remote_node(&txn);
+            let alias = txn.txn.data.data.alias.clone();
+
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+            assert_eq!(MESSAGE.to_string(), nodes_emulator::next(&handle).unwrap());
+
+            networker.process_event(Some(NetworkerEvent::NodeReplied(REQ_ID.to_string(), alias.clone())));
+
+            let statuses = networker.stats.lock().unwrap().get_nodes_status();
+            assert_eq!(1, statuses.len());
+            assert_eq!(alias, statuses[0].alias);
+            assert!(statuses[0].latency.is_some());
+            assert!(statuses[0].last_response_time.is_some());
+            assert!(statuses[0].in_read_set);
+        }
+
+        #[test]
+        fn networker_process_node_timed_out_event_works() {
+            let txn = nodes_emulator::node();
+            let rn = _remote_node(&txn);
+            let alias = txn.txn.data.data.alias.clone();
+
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+
+            networker.process_event(Some(NetworkerEvent::NodeTimedOut(REQ_ID.to_string(), alias)));
+
+            let statuses = networker.stats.lock().unwrap().get_nodes_status();
+            assert_eq!(1, statuses[0].failures);
+            assert!(statuses[0].latency.is_none());
+        }
+
         #[test]
         fn networker_process_second_request_after_cleaning_timeout_works() {
             let txn = nodes_emulator::node();
@@ -737,7 +888,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+            PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], None);
         }
 
         #[test]
@@ -753,7 +904,7 @@ pub mod networker_tests {
                 nodes.push(_remote_node(&txn));
             }
 
-            let pc = PoolConnection::new(nodes, POOL_CON_ACTIVE_TO, vec![]);
+            let pc = PoolConnection::new(nodes, POOL_CON_ACTIVE_TO, vec![], None);
 
             let act_names: Vec<String> = pc.nodes.iter().map(|n| n.name.to_string()).collect();
 
@@ -781,19 +932,40 @@ pub mod networker_tests {
 
             let pc = PoolConnection::new(vec![rn_1.clone(), rn_2.clone(), rn_3.clone(), rn_4.clone(), rn_5.clone()],
                                          POOL_CON_ACTIVE_TO,
-                                         vec![rn_2.name.clone(), rn_1.name.clone(), rn_5.name.clone()]);
+                                         vec![rn_2.name.clone(), rn_1.name.clone(), rn_5.name.clone()], None);
 
             assert_eq!(rn_2.name, pc.nodes[0].name);
             assert_eq!(rn_1.name, pc.nodes[1].name);
             assert_eq!(rn_5.name, pc.nodes[2].name);
         }
 
+        #[test]
+        fn pool_connection_new_works_for_scores() {
+            let mut txn = nodes_emulator::node();
+
+            let mut nodes: Vec<RemoteNode> = Vec::new();
+            let mut scores: HashMap<String, f64> = HashMap::new();
+
+            for i in 0..10 {
+                txn.txn.data.data.alias = format!("Node{}", i);
+                nodes.push(_remote_node(&txn));
+                scores.insert(txn.txn.data.data.alias.clone(), (10 - i) as f64);
+            }
+            scores.remove("Node5");
+
+            let pc = PoolConnection::new(nodes, POOL_CON_ACTIVE_TO, vec!["Node8".to_string()], Some(&scores));
+
+            let act_names: Vec<String> = pc.nodes.iter().map(|n| n.name.to_string()).collect();
+
+            assert_eq!(vec!["Node8", "Node5", "Node9", "Node7", "Node6", "Node4", "Node3", "Node2", "Node1", "Node0"], act_names);
+        }
+
         #[test]
         fn pool_connection_is_active_works() {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], None);
 
             assert!(conn.is_active());
 
@@ -807,7 +979,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], None);
 
             assert!(!conn.has_active_requests());
 
@@ -821,7 +993,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], None);
 
             let ((req_id, node_alias), timeout) = conn.get_timeout();
             assert_eq!(req_id, "".to_string());
@@ -842,7 +1014,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], None);
 
             conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
 
@@ -862,7 +1034,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], None);
 
             conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
 
@@ -878,7 +1050,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], None);
 
             let _socket = conn._get_socket(0).unwrap();
         }
@@ -889,7 +1061,7 @@ pub mod networker_tests {
             let mut rn = _remote_node(&txn);
             rn.zaddr = "invalid_address".to_string();
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], None);
 
             let res = conn._get_socket(0);
             assert_kind!(IndyErrorKind::IOError, res);
@@ -901,7 +1073,7 @@ pub mod networker_tests {
             let handle = nodes_emulator::start(&mut txn);
             let rn = _remote_node(&txn);
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], None);
 
             conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
             conn.send_request(Some(NetworkerEvent::SendOneRequest("msg2".to_string(), "12".to_string(), POOL_ACK_TIMEOUT))).unwrap();
@@ -921,7 +1093,7 @@ pub mod networker_tests {
             let handle_2 = nodes_emulator::start(&mut txn_2);
             let rn_2 = _remote_node(&txn_2);
 
-            let mut conn = PoolConnection::new(vec![rn_1, rn_2], POOL_CON_ACTIVE_TO, vec!["n1".to_string(), "n2".to_string()]);
+            let mut conn = PoolConnection::new(vec![rn_1, rn_2], POOL_CON_ACTIVE_TO, vec!["n1".to_string(), "n2".to_string()], None);
 
             conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
 
@@ -941,7 +1113,7 @@ pub mod networker_tests {
             let handle_2 = nodes_emulator::start(&mut txn_2);
             let rn_2 = _remote_node(&txn_2);
 
-            let mut conn = PoolConnection::new(vec![rn_1, rn_2], POOL_CON_ACTIVE_TO, vec![]);
+            let mut conn = PoolConnection::new(vec![rn_1, rn_2], POOL_CON_ACTIVE_TO, vec![], None);
 
             conn.send_request(Some(NetworkerEvent::SendAllRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None))).unwrap();
 
@@ -957,7 +1129,7 @@ pub mod networker_tests {
             let handle = nodes_emulator::start(&mut txn);
             let rn = _remote_node(&txn);
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], None);
 
             conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
 
@@ -978,7 +1150,7 @@ pub mod networker_tests {
             let handle_2 = nodes_emulator::start(&mut txn_2);
             let rn_2 = _remote_node(&txn_2);
 
-            let mut conn = PoolConnection::new(vec![rn_1, rn_2], POOL_CON_ACTIVE_TO, vec![]);
+            let mut conn = PoolConnection::new(vec![rn_1, rn_2], POOL_CON_ACTIVE_TO, vec![], None);
 
             conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
 
@@ -996,7 +1168,7 @@ pub mod networker_tests {
             let mut rn = _remote_node(&txn);
             rn.zaddr = "invalid_address".to_string();
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], None);
 
             let res = conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
             assert_kind!(IndyErrorKind::IOError, res);
@@ -0,0 +1,298 @@
+use std::collections::HashMap;
+
+use crate::domain::pool::{NodeStatus, ReadNodesSelection, POOL_ACK_TIMEOUT};
+use crate::services::pool::types::RemoteNode;
+
+/// Weight of the newest sample in the rolling (exponentially weighted) node latency.
+const LATENCY_SMOOTHING: f64 = 0.2;
+
+/// Latency (in milliseconds) assumed for ranking of a node which failed without ever replying.
+const UNRESPONSIVE_NODE_LATENCY: f64 = (POOL_ACK_TIMEOUT * 1000) as f64;
+
+#[derive(Debug, Default)]
+struct NodeStats {
+    last_response_time: Option<i64>,
+    latency: Option<f64>,
+    replies: u64,
+    failures: u64,
+}
+
+impl NodeStats {
+    /// Expected latency penalized by the failure rate. Lower is better.
+    fn score(&self) -> f64 {
+        let latency = self.latency.unwrap_or(if self.failures == 0 { 0.0 } else { UNRESPONSIVE_NODE_LATENCY });
+        let success_rate = (self.replies + 1) as f64 / (self.replies + self.failures + 1) as f64;
+        latency / success_rate
+    }
+}
+
+/// Health of the pool nodes as observed by the networker and the ranking of nodes for
+/// read requests derived from it.
+///
+/// Shared between the pool worker thread, which records node replies and timeouts,
+/// and `PoolService`, which reports it to the caller.
+#[derive(Debug)]
+pub struct NodesStats {
+    nodes: Vec<(String, String)>,
+    stats: HashMap<String, NodeStats>,
+    read_order: Vec<String>,
+    number_read_nodes: usize,
+    read_nodes_selection: ReadNodesSelection,
+}
+
+impl NodesStats {
+    pub fn new(number_read_nodes: u8, read_nodes_selection: ReadNodesSelection) -> NodesStats {
+        NodesStats {
+            nodes: Vec::new(),
+            stats: HashMap::new(),
+            read_order: Vec::new(),
+            number_read_nodes: number_read_nodes as usize,
+            read_nodes_selection,
+        }
+    }
+
+    pub fn update_nodes(&mut self, nodes: &[RemoteNode]) {
+        self.nodes = nodes
+            .iter()
+            .map(|node| (node.name.clone(), node.zaddr.clone()))
+            .collect();
+
+        let nodes = &self.nodes;
+        self.stats
+            .retain(|alias, _| nodes.iter().any(|(name, _)| name == alias));
+        self.read_order.retain(|alias| nodes.iter().any(|(name, _)| name == alias));
+    }
+
+    /// Remembers the order in which the newest pool connection addresses nodes.
+    /// Read requests are sent to the first `number_read_nodes` of them.
+    pub fn update_read_order(&mut self, nodes: &[RemoteNode]) {
+        self.read_order = nodes.iter().map(|node| node.name.clone()).collect();
+    }
+
+    /// `latency` is the time in milliseconds between sending a request to the node and its first reply.
+    pub fn record_reply(&mut self, node_alias: &str, latency: Option<i64>) {
+        let stats = self.stats.entry(node_alias.to_string()).or_default();
+
+        stats.last_response_time = Some(time::get_time().sec);
+
+        if let Some(sample) = latency {
+            let sample = sample as f64;
+
+            stats.latency = Some(match stats.latency {
+                Some(latency) => latency + LATENCY_SMOOTHING * (sample - latency),
+                None => sample,
+            });
+            stats.replies += 1;
+        }
+    }
+
+    pub fn record_failure(&mut self, node_alias: &str) {
+        self.stats.entry(node_alias.to_string()).or_default().failures += 1;
+    }
+
+    /// Scores of the nodes to rank them for read requests (lower is better).
+    /// Nodes without any observations aren't scored, so they are tried first.
+    /// Returns `None` if nodes must not be ranked.
+    pub fn get_scores(&self) -> Option<HashMap<String, f64>> {
+        match self.read_nodes_selection {
+            ReadNodesSelection::Random => None,
+            ReadNodesSelection::Adaptive => Some(
+                self.stats
+                    .iter()
+                    .map(|(alias, stats)| (alias.clone(), stats.score()))
+                    .collect(),
+            ),
+        }
+    }
+
+    pub fn get_nodes_status(&self) -> Vec<NodeStatus> {
+        let read_set: Vec<&String> = self
+            .read_order
+            .iter()
+            .take(self.number_read_nodes)
+            .collect();
+
+        self.nodes
+            .iter()
+            .map(|(alias, address)| {
+                let stats = self.stats.get(alias);
+
+                NodeStatus {
+                    alias: alias.clone(),
+                    address: address.clone(),
+                    last_response_time: stats.and_then(|stats| stats.last_response_time),
+                    latency: stats.and_then(|stats| stats.latency),
+                    failures: stats.map(|stats| stats.failures).unwrap_or(0),
+                    in_read_set: read_set.contains(&alias),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _remote_node(name: &str) -> RemoteNode {
+        RemoteNode {
+            name: name.to_string(),
+            public_key: vec![],
+            zaddr: format!("tcp://127.0.0.1:{}", name.len()),
+            is_blacklisted: false,
+        }
+    }
+
+    fn _nodes() -> Vec<RemoteNode> {
+        vec![_remote_node("Node1"), _remote_node("Node2"), _remote_node("Node3")]
+    }
+
+    fn _status<'a>(statuses: &'a [NodeStatus], alias: &str) -> &'a NodeStatus {
+        statuses.iter().find(|status| status.alias == alias).unwrap()
+    }
+
+    #[test]
+    fn nodes_stats_get_nodes_status_works_for_no_requests() {
+        let mut stats = NodesStats::new(2, ReadNodesSelection::Random);
+        stats.update_nodes(&_nodes());
+
+        let statuses = stats.get_nodes_status();
+
+        assert_eq!(3, statuses.len());
+        assert_eq!("Node1", statuses[0].alias);
+        assert_eq!("tcp://127.0.0.1:5", statuses[0].address);
+        assert!(statuses.iter().all(|status| status.latency.is_none()
+            && status.last_response_time.is_none()
+            && status.failures == 0
+            && !status.in_read_set));
+    }
+
+    #[test]
+    fn nodes_stats_record_reply_works() {
+        let mut stats = NodesStats::new(2, ReadNodesSelection::Random);
+        stats.update_nodes(&_nodes());
+
+        stats.record_reply("Node1", Some(100));
+
+        let statuses = stats.get_nodes_status();
+        let status = _status(&statuses, "Node1");
+
+        assert!(status.last_response_time.is_some());
+        assert_eq!(Some(100.0), status.latency);
+        assert_eq!(0, status.failures);
+        assert!(_status(&statuses, "Node2").latency.is_none());
+    }
+
+    #[test]
+    fn nodes_stats_record_reply_works_for_latency_smoothing() {
+        let mut stats = NodesStats::new(2, ReadNodesSelection::Random);
+        stats.update_nodes(&_nodes());
+
+        stats.record_reply("Node1", Some(100));
+        stats.record_reply("Node1", Some(200));
+
+        assert_eq!(Some(120.0), _status(&stats.get_nodes_status(), "Node1").latency);
+    }
+
+    #[test]
+    fn nodes_stats_record_reply_works_for_unknown_latency() {
+        let mut stats = NodesStats::new(2, ReadNodesSelection::Random);
+        stats.update_nodes(&_nodes());
+
+        stats.record_reply("Node1", Some(100));
+        stats.record_reply("Node1", None);
+
+        let statuses = stats.get_nodes_status();
+        let status = _status(&statuses, "Node1");
+
+        assert!(status.last_response_time.is_some());
+        assert_eq!(Some(100.0), status.latency);
+    }
+
+    #[test]
+    fn nodes_stats_record_failure_works() {
+        let mut stats = NodesStats::new(2, ReadNodesSelection::Random);
+        stats.update_nodes(&_nodes());
+
+        stats.record_failure("Node2");
+        stats.record_failure("Node2");
+
+        let statuses = stats.get_nodes_status();
+        let status = _status(&statuses, "Node2");
+
+        assert_eq!(2, status.failures);
+        assert!(status.latency.is_none());
+        assert!(status.last_response_time.is_none());
+    }
+
+    #[test]
+    fn nodes_stats_read_set_works() {
+        let mut stats = NodesStats::new(2, ReadNodesSelection::Random);
+        stats.update_nodes(&_nodes());
+
+        stats.update_read_order(&[_remote_node("Node3"), _remote_node("Node1"), _remote_node("Node2")]);
+
+        let statuses = stats.get_nodes_status();
+
+        assert!(_status(&statuses, "Node3").in_read_set);
+        assert!(_status(&statuses, "Node1").in_read_set);
+        assert!(!_status(&statuses, "Node2").in_read_set);
+    }
+
+    #[test]
+    fn nodes_stats_update_nodes_works_for_removed_node() {
+        let mut stats = NodesStats::new(2, ReadNodesSelection::Random);
+        stats.update_nodes(&_nodes());
+        stats.update_read_order(&_nodes());
+        stats.record_failure("Node1");
+
+        stats.update_nodes(&[_remote_node("Node2"), _remote_node("Node3")]);
+
+        let statuses = stats.get_nodes_status();
+
+        assert_eq!(2, statuses.len());
+        assert!(stats.stats.get("Node1").is_none());
+        assert!(_status(&statuses, "Node2").in_read_set);
+        assert!(!_status(&statuses, "Node3").in_read_set);
+    }
+
+    #[test]
+    fn nodes_stats_get_scores_works_for_random_selection() {
+        let mut stats = NodesStats::new(2, ReadNodesSelection::Random);
+        stats.update_nodes(&_nodes());
+        stats.record_reply("Node1", Some(100));
+
+        assert!(stats.get_scores().is_none());
+    }
+
+    #[test]
+    fn nodes_stats_get_scores_works_for_adaptive_selection() {
+        let mut stats = NodesStats::new(2, ReadNodesSelection::Adaptive);
+        stats.update_nodes(&_nodes());
+
+        stats.record_reply("Node1", Some(300));
+        stats.record_reply("Node2", Some(100));
+        stats.record_reply("Node3", Some(100));
+        stats.record_failure("Node3");
+
+        let scores = stats.get_scores().unwrap();
+
+        assert_eq!(300.0, scores["Node1"]);
+        assert_eq!(100.0, scores["Node2"]);
+        assert_eq!(150.0, scores["Node3"]);
+    }
+
+    #[test]
+    fn nodes_stats_get_scores_works_for_unresponsive_node() {
+        let mut stats = NodesStats::new(2, ReadNodesSelection::Adaptive);
+        stats.update_nodes(&_nodes());
+
+        stats.record_reply("Node1", Some(10_000));
+        stats.record_failure("Node2");
+
+        let scores = stats.get_scores().unwrap();
+
+        assert!(scores["Node2"] > scores["Node1"]);
+        assert!(!scores.contains_key("Node3"));
+    }
+}
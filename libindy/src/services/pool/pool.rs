@@ -8,16 +8,17 @@ use std::thread::JoinHandle;
 use failure::Context;
 
 use crate::domain::ledger::request::ProtocolVersion;
-use crate::domain::pool::PoolOpenConfig;
+use crate::domain::pool::{NodeStatus, PoolOpenConfig, ReadNodesSelection};
 use indy_api_types::errors::prelude::*;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
 use crate::services::pool::commander::Commander;
 use crate::services::pool::events::*;
 use crate::services::pool::{merkle_tree_factory, Nodes, PoolService};
-use crate::services::pool::networker::{Networker, ZMQNetworker};
+use crate::services::pool::networker::{Networker, Transport, ZMQNetworker};
+use crate::services::pool::node_stats::NodesStats;
 use crate::services::pool::request_handler::{RequestHandler, RequestHandlerImpl};
 use rust_base58::{FromBase58, ToBase58};
-use crate::services::pool::types::{LedgerStatus, RemoteNode};
+use crate::services::pool::types::{LedgerStatus, Message, RemoteNode};
 use indy_utils::crypto::ed25519_sign;
 
 use ursa::bls::VerKey;
@@ -30,9 +31,54 @@ struct PoolSM<T: Networker, R: RequestHandler<T>> {
     timeout: i64,
     extended_timeout: i64,
     number_read_nodes: u8,
+    read_nodes_selection: ReadNodesSelection,
+    protocol_version: Arc<Mutex<PoolProtocolVersion>>,
     state: PoolState<T, R>,
 }
 
+/// Protocol version of the pool detected on open and refresh.
+/// Version reported by more than f nodes in their LEDGER_STATUS replies takes precedence
+/// over the one implied by the format of the pool ledger transactions.
+#[derive(Debug, Default)]
+pub(super) struct PoolProtocolVersion {
+    genesis: Option<usize>,
+    nodes: HashMap<String, usize>,
+    detected: Option<usize>,
+}
+
+impl PoolProtocolVersion {
+    fn reset(&mut self, genesis: Option<usize>) {
+        self.genesis = genesis;
+        self.nodes.clear();
+    }
+
+    fn node_reported(&mut self, node_alias: &str, version: usize) {
+        self.nodes.insert(node_alias.to_string(), version);
+    }
+
+    fn resolve(&mut self, f: usize) {
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+
+        for version in self.nodes.values() {
+            *counts.entry(*version).or_insert(0) += 1;
+        }
+
+        let reported = counts
+            .into_iter()
+            .filter(|(_, count)| *count > f)
+            .max_by_key(|(_, count)| *count)
+            .map(|(version, _)| version);
+
+        self.detected = reported.or(self.genesis).or(self.detected);
+    }
+
+    /// Version to use for the pool. Falls back to the global one set by indy_set_protocol_version
+    /// until the version is detected.
+    pub(super) fn get(&self) -> usize {
+        self.detected.or(self.genesis).unwrap_or_else(ProtocolVersion::get)
+    }
+}
+
 /// Transitions of pool state
 /// Initialization -> GettingCatchupTarget, Active, Terminated, Closed
 /// GettingCatchupTarget -> SyncCatchup, Active, Terminated, Closed
@@ -80,21 +126,23 @@ struct TerminatedState<T: Networker> {
 struct ClosedState {}
 
 impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
-    pub fn new(networker: Arc<Mutex<T>>, pname: &str, id: PoolHandle, timeout: i64, extended_timeout: i64, number_read_nodes: u8) -> PoolSM<T, R> {
+    pub fn new(networker: Arc<Mutex<T>>, pname: &str, id: PoolHandle, timeout: i64, extended_timeout: i64, number_read_nodes: u8, read_nodes_selection: ReadNodesSelection) -> PoolSM<T, R> {
         PoolSM {
             pool_name: pname.to_string(),
             id,
             timeout,
             extended_timeout,
             number_read_nodes,
+            read_nodes_selection,
+            protocol_version: Arc::new(Mutex::new(PoolProtocolVersion::default())),
             state: PoolState::Initialization(InitializationState {
                 networker
             }),
         }
     }
 
-    pub fn step(pool_name: String, id: PoolHandle, timeout: i64, extended_timeout: i64, number_read_nodes: u8, state: PoolState<T, R>) -> Self {
-        PoolSM { pool_name, id, timeout, extended_timeout, number_read_nodes, state }
+    pub fn step(pool_name: String, id: PoolHandle, timeout: i64, extended_timeout: i64, number_read_nodes: u8, read_nodes_selection: ReadNodesSelection, protocol_version: Arc<Mutex<PoolProtocolVersion>>, state: PoolState<T, R>) -> Self {
+        PoolSM { pool_name, id, timeout, extended_timeout, number_read_nodes, read_nodes_selection, protocol_version, state }
     }
 }
 
@@ -263,7 +311,7 @@ impl<T: Networker> From<TerminatedState<T>> for ClosedState {
 
 impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
     pub fn handle_event(self, pe: PoolEvent) -> Self {
-        let PoolSM { pool_name, id, state, timeout, extended_timeout, number_read_nodes } = self;
+        let PoolSM { pool_name, id, state, timeout, extended_timeout, number_read_nodes, read_nodes_selection, protocol_version } = self;
         let state = match state {
             PoolState::Initialization(state) => match pe {
                 PoolEvent::CheckCache(cmd_id) => {
@@ -273,7 +321,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         //                        PoolWrapper::Active(pool.into())
                         unimplemented!()
                     } else {
-                        match _get_request_handler_with_ledger_status_sent(state.networker.clone(), &pool_name, timeout, extended_timeout, number_read_nodes) {
+                        match _get_request_handler_with_ledger_status_sent(state.networker.clone(), &pool_name, timeout, extended_timeout, number_read_nodes, read_nodes_selection, &protocol_version) {
                             Ok(request_handler) => PoolState::GettingCatchupTarget((request_handler, cmd_id, state).into()),
                             Err(err) => {
                                 futures::executor::block_on(PoolService::open_ack(id, Err(err)));
@@ -289,6 +337,12 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                 _ => PoolState::Initialization(state)
             }
             PoolState::GettingCatchupTarget(mut state) => {
+                if let PoolEvent::NodeReply(ref reply, ref node_alias) = pe {
+                    if let Ok(Message::LedgerStatus(ref ls)) = Message::from_raw_str(reply) {
+                        protocol_version.lock().unwrap().node_reported(node_alias, ls.protocolVersion.unwrap_or(1));
+                    }
+                }
+
                 let pe = state.request_handler.process_event(pe.clone().into()).unwrap_or(pe);
                 match pe {
                     PoolEvent::Close(cmd_id) => {
@@ -302,8 +356,8 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     PoolEvent::CatchupRestart(merkle_tree) => {
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle_tree) {
                             state.networker.lock().unwrap().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
-                            state.request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &[], &nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
-                            let ls = _ledger_status(&merkle_tree);
+                            state.request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &[], &nodes, &pool_name, timeout, extended_timeout, number_read_nodes, read_nodes_selection);
+                            let ls = _ledger_status(&merkle_tree, protocol_version.lock().unwrap().get());
                             state.request_handler.process_event(Some(RequestEvent::LedgerStatus(ls, None, Some(merkle_tree))));
                             PoolState::GettingCatchupTarget(state)
                         } else {
@@ -313,7 +367,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     PoolEvent::CatchupTargetFound(target_mt_root, target_mt_size, merkle_tree) => {
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle_tree) {
                             state.networker.lock().unwrap().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
-                            let mut request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &[], &nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
+                            let mut request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &[], &nodes, &pool_name, timeout, extended_timeout, number_read_nodes, read_nodes_selection);
                             request_handler.process_event(Some(RequestEvent::CatchupReq(merkle_tree, target_mt_size, target_mt_root)));
                             PoolState::SyncCatchup((request_handler, state).into())
                         } else {
@@ -322,6 +376,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     }
                     PoolEvent::Synced(merkle) => {
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle) {
+                            protocol_version.lock().unwrap().resolve(_get_f(nodes.len()));
                             state.networker.lock().unwrap().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
                             _send_open_refresh_ack(state.cmd_id, id, state.refresh, Ok(()));
                             PoolState::Active((state, nodes).into())
@@ -339,7 +394,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         PoolState::Closed(state.into())
                     }
                     PoolEvent::Refresh(cmd_id) => {
-                        if let Ok(request_handler) = _get_request_handler_with_ledger_status_sent(state.networker.clone(), &pool_name, timeout, extended_timeout, number_read_nodes) {
+                        if let Ok(request_handler) = _get_request_handler_with_ledger_status_sent(state.networker.clone(), &pool_name, timeout, extended_timeout, number_read_nodes, read_nodes_selection, &protocol_version) {
                             PoolState::GettingCatchupTarget((state, request_handler, cmd_id).into())
                         } else {
                             PoolState::Terminated(state)
@@ -365,7 +420,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         PoolState::Closed(state.into())
                     }
                     PoolEvent::Refresh(cmd_id) => {
-                        if let Ok(request_handler) = _get_request_handler_with_ledger_status_sent(state.networker.clone(), &pool_name, timeout, extended_timeout, number_read_nodes) {
+                        if let Ok(request_handler) = _get_request_handler_with_ledger_status_sent(state.networker.clone(), &pool_name, timeout, extended_timeout, number_read_nodes, read_nodes_selection, &protocol_version) {
                             PoolState::GettingCatchupTarget((state, request_handler, cmd_id).into())
                         } else {
                             PoolState::Terminated(state.into())
@@ -376,7 +431,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         let re: Option<RequestEvent> = pe.into();
                         match re.as_ref().map(|r| r.get_req_id()) {
                             Some(req_id) => {
-                                let mut request_handler = R::new(state.networker.clone(), _get_f(state.nodes.len()), &[cmd_id], &state.nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
+                                let mut request_handler = R::new(state.networker.clone(), _get_f(state.nodes.len()), &[cmd_id], &state.nodes, &pool_name, timeout, extended_timeout, number_read_nodes, read_nodes_selection);
                                 request_handler.process_event(re);
                                 state.request_handlers.insert(req_id.to_string(), request_handler); //FIXME check already exists
                             }
@@ -392,6 +447,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         let re: Option<RequestEvent> = pe.into();
                         match re.as_ref().map(|r| r.get_req_id()) {
                             Some(req_id) => {
+                                state.networker.lock().unwrap().process_event(Some(NetworkerEvent::NodeReplied(req_id.clone(), node)));
                                 let remove = if let Some(rh) = state.request_handlers.get_mut(&req_id) {
                                     rh.process_event(re);
                                     rh.is_terminal()
@@ -409,6 +465,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     }
                     PoolEvent::Timeout(req_id, node_alias) => {
                         if let Some(rh) = state.request_handlers.get_mut(&req_id) {
+                            state.networker.lock().unwrap().process_event(Some(NetworkerEvent::NodeTimedOut(req_id.clone(), node_alias)));
                             rh.process_event(pe.into());
                         } else if "".eq(&req_id) {
                             state.networker.lock().unwrap().process_event(Some(NetworkerEvent::Timeout));
@@ -430,6 +487,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     PoolEvent::NodesBlacklisted => PoolState::Terminated(state.into()),
                     PoolEvent::Synced(merkle) => {
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle).map_err(map_err_err!()) {
+                            protocol_version.lock().unwrap().resolve(_get_f(nodes.len()));
                             state.networker.lock().unwrap().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
                             _send_open_refresh_ack(state.cmd_id, id, state.refresh, Ok(()));
                             PoolState::Active((state, nodes).into())
@@ -441,7 +499,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                 }
             }
         };
-        PoolSM::step(pool_name, id, timeout, extended_timeout, number_read_nodes, state)
+        PoolSM::step(pool_name, id, timeout, extended_timeout, number_read_nodes, read_nodes_selection, protocol_version, state)
     }
 
     pub fn is_terminal(&self) -> bool {
@@ -474,6 +532,10 @@ pub struct Pool<S: Networker, R: RequestHandler<S>> {
     conn_limit: usize,
     preordered_nodes: Vec<String>,
     number_read_nodes: u8,
+    read_nodes_selection: ReadNodesSelection,
+    stats: Arc<Mutex<NodesStats>>,
+    transport: Option<Arc<dyn Transport>>,
+    protocol_version: Arc<Mutex<PoolProtocolVersion>>,
 }
 
 impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
@@ -491,9 +553,17 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
             conn_limit: config.conn_limit,
             preordered_nodes: config.preordered_nodes,
             number_read_nodes: config.number_read_nodes,
+            read_nodes_selection: config.read_nodes_selection,
+            stats: Arc::new(Mutex::new(NodesStats::new(config.number_read_nodes, config.read_nodes_selection))),
+            transport: None,
+            protocol_version: Arc::new(Mutex::new(PoolProtocolVersion::default())),
         }
     }
 
+    pub fn set_transport(&mut self, transport: Arc<dyn Transport>) {
+        self.transport = Some(transport);
+    }
+
     pub fn work(&mut self, cmd_socket: zmq::Socket) {
         let name = self.name.as_str().to_string();
         let id = self.id;
@@ -503,12 +573,20 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
         let conn_limit = self.conn_limit;
         let preordered_nodes = self.preordered_nodes.clone();
         let number_read_nodes = self.number_read_nodes;
+        let read_nodes_selection = self.read_nodes_selection;
+        let stats = self.stats.clone();
+        let transport = self.transport.clone();
+        let protocol_version = self.protocol_version.clone();
         self.worker = Some(thread::spawn(move || {
             let mut pool_thread: PoolThread<S, R> = PoolThread::new(cmd_socket, name, id,
                                                                     timeout, extended_timeout,
                                                                     active_timeout, conn_limit,
                                                                     preordered_nodes,
-                                                                    number_read_nodes);
+                                                                    number_read_nodes,
+                                                                    read_nodes_selection,
+                                                                    stats,
+                                                                    transport,
+                                                                    protocol_version);
             pool_thread.work();
         }));
     }
@@ -520,6 +598,14 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
     pub fn get_id(&self) -> PoolHandle {
         self.id
     }
+
+    pub fn get_nodes_status(&self) -> Vec<NodeStatus> {
+        self.stats.lock().unwrap().get_nodes_status()
+    }
+
+    pub fn get_protocol_version(&self) -> usize {
+        self.protocol_version.lock().unwrap().get()
+    }
 }
 
 struct PoolThread<S: Networker, R: RequestHandler<S>> {
@@ -530,11 +616,19 @@ struct PoolThread<S: Networker, R: RequestHandler<S>> {
 }
 
 impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
-    pub fn new(cmd_socket: zmq::Socket, name: String, id: PoolHandle, timeout: i64, extended_timeout: i64, active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>, number_read_nodes: u8) -> Self {
-        let networker = Arc::new(Mutex::new(S::new(active_timeout, conn_limit, preordered_nodes)));
+    pub fn new(cmd_socket: zmq::Socket, name: String, id: PoolHandle, timeout: i64, extended_timeout: i64, active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>, number_read_nodes: u8, read_nodes_selection: ReadNodesSelection, stats: Arc<Mutex<NodesStats>>, transport: Option<Arc<dyn Transport>>, protocol_version: Arc<Mutex<PoolProtocolVersion>>) -> Self {
+        let mut networker = S::new(active_timeout, conn_limit, preordered_nodes);
+        networker.set_stats(stats);
+        if let Some(transport) = transport {
+            networker.set_transport(transport);
+        }
+        let networker = Arc::new(Mutex::new(networker));
        
+        let mut pool_sm = PoolSM::new(networker.clone(), &name, id, timeout, extended_timeout, number_read_nodes, read_nodes_selection);
+        pool_sm.protocol_version = protocol_version;
+
         PoolThread {
-            pool_sm: Some(PoolSM::new(networker.clone(), &name, id, timeout, extended_timeout, number_read_nodes)),
+            pool_sm: Some(pool_sm),
             events: VecDeque::new(),
             commander: Commander::new(cmd_socket),
             networker,
@@ -599,7 +693,7 @@ impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
     }
 }
 
-fn _get_f(cnt: usize) -> usize {
+pub(super) fn _get_f(cnt: usize) -> usize {
     if cnt < 4 {
         return 0;
     }
@@ -611,7 +705,9 @@ fn _get_request_handler_with_ledger_status_sent<T: Networker, R: RequestHandler<
     pool_name: &str,
     timeout: i64,
     extended_timeout: i64,
-    number_read_nodes: u8) -> IndyResult<R>
+    number_read_nodes: u8,
+    read_nodes_selection: ReadNodesSelection,
+    protocol_version: &Mutex<PoolProtocolVersion>) -> IndyResult<R>
 {
     let mut merkle = merkle_tree_factory::create(pool_name)?;
 
@@ -628,15 +724,18 @@ fn _get_request_handler_with_ledger_status_sent<T: Networker, R: RequestHandler<
         }
     };
     networker.lock().unwrap().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
-    let mut request_handler = R::new(networker.clone(), _get_f(nodes.len()), &[], &nodes, pool_name, timeout, extended_timeout, number_read_nodes);
-    let ls = _ledger_status(&merkle);
+    let protocol_version = {
+        let mut protocol_version = protocol_version.lock().unwrap();
+        protocol_version.reset(merkle_tree_factory::detect_protocol_version(&merkle)?);
+        protocol_version.get()
+    };
+    let mut request_handler = R::new(networker.clone(), _get_f(nodes.len()), &[], &nodes, pool_name, timeout, extended_timeout, number_read_nodes, read_nodes_selection);
+    let ls = _ledger_status(&merkle, protocol_version);
     request_handler.process_event(Some(RequestEvent::LedgerStatus(ls, None, Some(merkle))));
     Ok(request_handler)
 }
 
-fn _ledger_status(merkle: &MerkleTree) -> LedgerStatus {
-    let protocol_version = ProtocolVersion::get();
-
+fn _ledger_status(merkle: &MerkleTree, protocol_version: usize) -> LedgerStatus {
     LedgerStatus {
         txnSeqNo: merkle.count(),
         merkleRoot: merkle.root_hash().as_slice().to_base58(),
@@ -647,7 +746,7 @@ fn _ledger_status(merkle: &MerkleTree) -> LedgerStatus {
     }
 }
 
-fn _get_nodes_and_remotes(merkle: &MerkleTree) -> IndyResult<(Nodes, Vec<RemoteNode>)> {
+pub(super) fn _get_nodes_and_remotes(merkle: &MerkleTree) -> IndyResult<(Nodes, Vec<RemoteNode>)> {
     let nodes = merkle_tree_factory::build_node_state(merkle)?;
 
     Ok(nodes.iter().map(|(_, txn)| {
@@ -794,6 +893,12 @@ mod tests {
             let p: Pool<MockNetworker, MockRequestHandler> = Pool::new(name, id, PoolOpenConfig::default());
             assert_eq!(id, p.get_id());
         }
+
+        #[test]
+        pub fn pool_get_nodes_status_works_for_not_synced_pool() {
+            let p: Pool<MockNetworker, MockRequestHandler> = Pool::new("pool_get_nodes_status_works_for_not_synced_pool", next_pool_handle(), PoolOpenConfig::default());
+            assert!(p.get_nodes_status().is_empty());
+        }
     }
 
     mod pool_sm {
@@ -809,7 +914,7 @@ mod tests {
 
         #[test]
         pub fn pool_wrapper_new_initialization_works() {
-            let _p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "name", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+            let _p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "name", next_pool_handle(), 0, 0, NUMBER_READ_NODES, ReadNodesSelection::Random);
         }
 
         #[test]
@@ -819,7 +924,7 @@ mod tests {
             ProtocolVersion::set(2);
             _write_genesis_txns("pool_wrapper_check_cache_works");
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_check_cache_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_check_cache_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             assert_match!(PoolState::GettingCatchupTarget(_), p.state);
@@ -832,7 +937,7 @@ mod tests {
             let (pool_handle, _recv) = fake_pool_handle_for_poolsm().await;
             let p: PoolSM<MockNetworker, MockRequestHandler> =
                 PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))),
-                            "pool_wrapper_check_cache_works_for_no_pool_created", pool_handle, 0, 0, NUMBER_READ_NODES);
+                            "pool_wrapper_check_cache_works_for_no_pool_created", pool_handle, 0, 0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             assert_match!(PoolState::Terminated(_), p.state);
@@ -841,7 +946,7 @@ mod tests {
         #[async_std::test]
         pub async fn pool_wrapper_terminated_close_works() {
             let (pool_handle, _recv) = fake_pool_handle_for_poolsm().await;
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_terminated_close_works", pool_handle, 0, 0, NUMBER_READ_NODES);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_terminated_close_works", pool_handle, 0, 0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let (cmd_id, _receiver): (CommandHandle, _) = fake_pool_handle_for_close_cmd().await;
@@ -853,7 +958,7 @@ mod tests {
         pub async fn pool_wrapper_terminated_refresh_works() {
             test::cleanup_pool("pool_wrapper_terminated_refresh_works");
             let (pool_handle, _recv) = fake_pool_handle_for_poolsm().await;
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_terminated_refresh_works", pool_handle, 0, 0, NUMBER_READ_NODES);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_terminated_refresh_works", pool_handle, 0, 0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
 
@@ -877,6 +982,7 @@ mod tests {
                 timeout: 0,
                 extended_timeout: 0,
                 number_read_nodes: NUMBER_READ_NODES,
+                read_nodes_selection: ReadNodesSelection::Random,
             };
 
             let p = p.handle_event(PoolEvent::Timeout("".to_string(), "".to_string()));
@@ -893,7 +999,7 @@ mod tests {
 
         #[async_std::test]
         pub async fn pool_wrapper_close_works_from_initialization() {
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_cloe_works_from_initialization", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_cloe_works_from_initialization", next_pool_handle(), 0, 0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let (cmd_id, _receiver): (CommandHandle, _) = fake_pool_handle_for_close_cmd().await;
             let p = p.handle_event(PoolEvent::Close(cmd_id));
             assert_match!(PoolState::Closed(_), p.state);
@@ -907,7 +1013,7 @@ mod tests {
             _write_genesis_txns("pool_wrapper_close_works_from_getting_catchup_target");
 
             let p: PoolSM<MockNetworker, MockRequestHandler> =
-                PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_close_works_from_getting_catchup_target", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+                PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_close_works_from_getting_catchup_target", next_pool_handle(), 0, 0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let (cmd_id, _receiver): (CommandHandle, _) = fake_pool_handle_for_close_cmd().await;
@@ -927,7 +1033,7 @@ mod tests {
             let (pool_handle, _recv) = fake_pool_handle_for_poolsm().await;
             
             let p: PoolSM<MockNetworker, MockRequestHandler> =
-                PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_catchup_target_not_found_works", pool_handle, 0, 0, NUMBER_READ_NODES);
+                PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_catchup_target_not_found_works", pool_handle, 0, 0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::CatchupTargetNotFound(err_msg(IndyErrorKind::PoolTimeout, "Pool timeout")));
@@ -946,7 +1052,7 @@ mod tests {
             let (pool_handle, _recv) = fake_pool_handle_for_poolsm().await;
             
             let p: PoolSM<MockNetworker, MockRequestHandler> =
-                PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_getting_catchup_target_synced_works", pool_handle, 0, 0, NUMBER_READ_NODES);
+                PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_getting_catchup_target_synced_works", pool_handle, 0, 0, NUMBER_READ_NODES, ReadNodesSelection::Random);
            
                 let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
@@ -970,11 +1076,10 @@ mod tests {
                 "pool_wrapper_getting_catchup_target_synced_works_for_node_state_error",
                 next_pool_handle(),
                 0,
-                0, NUMBER_READ_NODES);
+                0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
-            ProtocolVersion::set(1);
-            let p = p.handle_event(PoolEvent::Synced(merkle_tree_factory::create("pool_wrapper_getting_catchup_target_synced_works_for_node_state_error").unwrap()));
+            let p = p.handle_event(PoolEvent::Synced(_invalid_merkle_tree()));
             assert_match!(PoolState::Terminated(_), p.state);
 
             test::cleanup_storage("pool_wrapper_getting_catchup_target_synced_works_for_node_state_error");
@@ -997,7 +1102,7 @@ mod tests {
                 "pool_wrapper_getting_catchup_target_catchup_target_found_works",
                 next_pool_handle(),
                 0,
-                0, NUMBER_READ_NODES);
+                0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
@@ -1013,18 +1118,16 @@ mod tests {
             ProtocolVersion::set(2);
             _write_genesis_txns("pool_wrapper_getting_catchup_target_catchup_target_found_works_for_node_state_error");
 
-            let mt = merkle_tree_factory::create("pool_wrapper_getting_catchup_target_catchup_target_found_works_for_node_state_error").unwrap();
-
             let p: PoolSM<MockNetworker, MockRequestHandler> =
                 PoolSM::new(Arc::new(Mutex::new(
                     MockNetworker::new(0, 0, vec![]))),
                             "pool_wrapper_getting_catchup_target_catchup_target_found_works_for_node_state_error",
                             next_pool_handle(),
                             0,
-                            0, NUMBER_READ_NODES);
+                            0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
-            ProtocolVersion::set(1);
+            let mt = _invalid_merkle_tree();
             let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
             assert_match!(PoolState::Terminated(_), p.state);
 
@@ -1049,7 +1152,7 @@ mod tests {
                             "pool_wrapper_sync_catchup_close_works",
                             next_pool_handle(),
                             0,
-                            0, NUMBER_READ_NODES);
+                            0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
@@ -1079,7 +1182,7 @@ mod tests {
                 "pool_wrapper_sync_catchup_synced_works",
                 pool_handle,
                 0,
-                0, NUMBER_READ_NODES);
+                0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
@@ -1107,12 +1210,11 @@ mod tests {
                 "pool_wrapper_sync_catchup_synced_works_for_node_state_error",
                 next_pool_handle(),
                 0,
-                0, NUMBER_READ_NODES);
+                0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
-            ProtocolVersion::set(1);
-            let p = p.handle_event(PoolEvent::Synced(merkle_tree_factory::create("pool_wrapper_sync_catchup_synced_works_for_node_state_error").unwrap()));
+            let p = p.handle_event(PoolEvent::Synced(_invalid_merkle_tree()));
             assert_match!(PoolState::Terminated(_), p.state);
 
             test::cleanup_storage("pool_wrapper_sync_catchup_synced_works_for_node_state_error");
@@ -1140,7 +1242,7 @@ mod tests {
                                                                            "pool_wrapper_active_send_request_works",
                                                                            pool_handle,
                                                                            0,
-                                                                           0, NUMBER_READ_NODES);
+                                                                           0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
@@ -1182,7 +1284,7 @@ mod tests {
                             "pool_wrapper_active_send_request_works_for_no_req_id",
                             pool_handle,
                             0,
-                            0, NUMBER_READ_NODES);
+                            0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
@@ -1237,7 +1339,7 @@ mod tests {
                 "pool_wrapper_active_node_reply_works",
                 pool_handle,
                 0,
-                0, NUMBER_READ_NODES);
+                0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
@@ -1249,6 +1351,7 @@ mod tests {
             match p.state {
                 PoolState::Active(state) => {
                     assert_eq!(state.request_handlers.len(), 0);
+                    assert_match!(Some(Some(NetworkerEvent::NodeReplied(_, _))), state.networker.lock().unwrap().events.last());
                 }
                 _ => assert!(false)
             };
@@ -1277,7 +1380,7 @@ mod tests {
                                        0,
                                        vec![]))),
                             "pool_wrapper_sends_requests_to_two_nodes",
-                            pool_handle, 0, 0, NUMBER_READ_NODES);
+                            pool_handle, 0, 0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
@@ -1330,7 +1433,7 @@ mod tests {
                                                                            "pool_wrapper_active_node_reply_works_for_no_request",
                                                                            pool_handle,
                                                                            0,
-                                                                           0, NUMBER_READ_NODES);
+                                                                           0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
@@ -1375,7 +1478,7 @@ mod tests {
                             "pool_wrapper_active_node_reply_works_for_invalid_reply",
                             pool_handle,
                             0,
-                            0, NUMBER_READ_NODES);
+                            0, NUMBER_READ_NODES, ReadNodesSelection::Random);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
@@ -1402,6 +1505,10 @@ mod tests {
             f.flush().unwrap();
             f.sync_all().unwrap();
         }
+
+        fn _invalid_merkle_tree() -> MerkleTree {
+            MerkleTree::from_vec(vec![b"invalid".to_vec()]).unwrap()
+        }
     }
 
     mod other {
@@ -1418,5 +1525,48 @@ mod tests {
             assert_eq!(_get_f(6), 1);
             assert_eq!(_get_f(7), 2);
         }
+
+        #[test]
+        fn pool_protocol_version_works_for_genesis_txns() {
+            let mut protocol_version = PoolProtocolVersion::default();
+
+            protocol_version.reset(Some(1));
+            protocol_version.resolve(1);
+
+            assert_eq!(1, protocol_version.get());
+        }
+
+        #[test]
+        fn pool_protocol_version_works_for_nodes_replies() {
+            let mut protocol_version = PoolProtocolVersion::default();
+
+            protocol_version.reset(Some(1));
+            protocol_version.node_reported("Node1", 2);
+            protocol_version.node_reported("Node2", 2);
+            protocol_version.node_reported("Node3", 1);
+            protocol_version.resolve(1);
+
+            assert_eq!(2, protocol_version.get());
+        }
+
+        #[test]
+        fn pool_protocol_version_works_for_not_enough_nodes_replies() {
+            let mut protocol_version = PoolProtocolVersion::default();
+
+            protocol_version.reset(Some(1));
+            protocol_version.node_reported("Node1", 2);
+            protocol_version.resolve(1);
+
+            assert_eq!(1, protocol_version.get());
+        }
+
+        #[test]
+        fn pool_protocol_version_works_for_not_detected() {
+            ProtocolVersion::set(2);
+
+            let protocol_version = PoolProtocolVersion::default();
+
+            assert_eq!(2, protocol_version.get());
+        }
     }
 }
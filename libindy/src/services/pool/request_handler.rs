@@ -11,6 +11,7 @@ use serde_json::Value as SJsonValue;
 use self::super::THRESHOLD;
 
 use indy_api_types::errors::prelude::*;
+use crate::domain::pool::ReadNodesSelection;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
 use crate::services::pool::catchup::{build_catchup_req, CatchupProgress, check_cons_proofs, check_nodes_responses_on_status};
 use crate::services::pool::events::NetworkerEvent;
@@ -39,6 +40,7 @@ struct RequestSM<T: Networker> {
     timeout: i64,
     extended_timeout: i64,
     number_read_nodes: u8,
+    read_nodes_selection: ReadNodesSelection,
     state: RequestState<T>,
 }
 
@@ -71,7 +73,8 @@ impl<T: Networker> RequestSM<T> {
                f: usize,
                cmd_ids: &[CommandHandle],
                nodes: &Nodes,
-               pool_name: &str, timeout: i64, extended_timeout: i64, number_read_nodes: u8) -> Self {
+               pool_name: &str, timeout: i64, extended_timeout: i64, number_read_nodes: u8,
+               read_nodes_selection: ReadNodesSelection) -> Self {
         let generator: Generator = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap();
         RequestSM {
             f,
@@ -82,6 +85,7 @@ impl<T: Networker> RequestSM<T> {
             timeout,
             extended_timeout,
             number_read_nodes,
+            read_nodes_selection,
             state: RequestState::Start(StartState {
                 networker
             }),
@@ -96,6 +100,7 @@ impl<T: Networker> RequestSM<T> {
                 timeout: i64,
                 extended_timeout: i64,
                 number_read_nodes: u8,
+                read_nodes_selection: ReadNodesSelection,
                 state: RequestState<T>) -> Self {
         RequestSM {
             f,
@@ -106,6 +111,7 @@ impl<T: Networker> RequestSM<T> {
             timeout,
             extended_timeout,
             number_read_nodes,
+            read_nodes_selection,
             state,
         }
     }
@@ -143,6 +149,7 @@ struct SingleState<T: Networker> {
     networker: Arc<Mutex<T>>,
     sp_key: Option<Vec<u8>>,
     timestamps: (Option<u64>, Option<u64>),
+    sent_cnt: usize,
 }
 
 struct FullState<T: Networker> {
@@ -153,8 +160,8 @@ struct FullState<T: Networker> {
 
 struct FinishState {}
 
-impl<T: Networker> From<(StartState<T>, Option<Vec<u8>>, (Option<u64>, Option<u64>))> for SingleState<T> {
-    fn from((state, sp_key, timestamps): (StartState<T>, Option<Vec<u8>>, (Option<u64>, Option<u64>))) -> Self {
+impl<T: Networker> From<(StartState<T>, Option<Vec<u8>>, (Option<u64>, Option<u64>), usize)> for SingleState<T> {
+    fn from((state, sp_key, timestamps, sent_cnt): (StartState<T>, Option<Vec<u8>>, (Option<u64>, Option<u64>), usize)) -> Self {
         SingleState {
             denied_nodes: HashSet::new(),
             replies: HashMap::new(),
@@ -162,6 +169,7 @@ impl<T: Networker> From<(StartState<T>, Option<Vec<u8>>, (Option<u64>, Option<u6
             networker: state.networker.clone(),
             sp_key,
             timestamps,
+            sent_cnt,
         }
     }
 }
@@ -247,7 +255,7 @@ impl Hash for NodeResponse {
 
 impl<T: Networker> RequestSM<T> {
     fn handle_event(self, re: RequestEvent) -> (Self, Option<PoolEvent>) {
-        let RequestSM { state, f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout, number_read_nodes } = self;
+        let RequestSM { state, f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout, number_read_nodes, read_nodes_selection } = self;
         let (state, event) = match state {
             RequestState::Start(state) => {
                 match re {
@@ -282,7 +290,7 @@ impl<T: Networker> RequestSM<T> {
                             state.networker.lock().unwrap().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
                         }
 
-                        (RequestState::Single((state, sp_key, timestamps).into()), None)
+                        (RequestState::Single((state, sp_key, timestamps, number_read_nodes as usize).into()), None)
                     }
                     RequestEvent::CustomFullRequest(msg, req_id, local_timeout, nodes_to_send) => {
                         let timeout = local_timeout.map(|to| to as i64).unwrap_or(extended_timeout);
@@ -409,11 +417,11 @@ impl<T: Networker> RequestSM<T> {
                                 _send_ok_replies(&cmd_ids, if cnt > f { &soonest } else { &raw_msg });
                                 (RequestState::finish(), None)
                             } else {
-                                (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout), None)
+                                (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout, read_nodes_selection), None)
                             }
                         } else {
                             state.denied_nodes.insert(node_alias.clone());
-                            (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout), None)
+                            (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout, read_nodes_selection), None)
                         }
                     }
                     RequestEvent::ReqACK(_, _, node_alias, req_id) => {
@@ -422,7 +430,7 @@ impl<T: Networker> RequestSM<T> {
                     }
                     RequestEvent::Timeout(req_id, node_alias) => {
                         state.timeout_nodes.insert(node_alias.clone());
-                        (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout), None)
+                        (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout, read_nodes_selection), None)
                     }
                     RequestEvent::Terminate => {
                         _finish_request(&cmd_ids);
@@ -506,7 +514,7 @@ impl<T: Networker> RequestSM<T> {
             }
             RequestState::Finish(state) => (RequestState::Finish(state), None)
         };
-        (RequestSM::step(f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout, number_read_nodes, state), event)
+        (RequestSM::step(f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout, number_read_nodes, read_nodes_selection, state), event)
     }
 
     fn is_terminal(&self) -> bool {
@@ -609,7 +617,8 @@ impl<T: Networker> RequestSM<T> {
 }
 
 pub trait RequestHandler<T: Networker> {
-    fn new(networker: Arc<Mutex<T>>, f: usize, cmd_ids: &[CommandHandle], nodes: &Nodes, pool_name: &str, timeout: i64, extended_timeout: i64, number_read_nodes: u8) -> Self;
+    fn new(networker: Arc<Mutex<T>>, f: usize, cmd_ids: &[CommandHandle], nodes: &Nodes, pool_name: &str, timeout: i64, extended_timeout: i64, number_read_nodes: u8,
+           read_nodes_selection: ReadNodesSelection) -> Self;
     fn process_event(&mut self, ore: Option<RequestEvent>) -> Option<PoolEvent>;
     fn is_terminal(&self) -> bool;
 }
@@ -619,9 +628,10 @@ pub struct RequestHandlerImpl<T: Networker> {
 }
 
 impl<T: Networker> RequestHandler<T> for RequestHandlerImpl<T> {
-    fn new(networker: Arc<Mutex<T>>, f: usize, cmd_ids: &[CommandHandle], nodes: &Nodes, pool_name: &str, timeout: i64, extended_timeout: i64, number_read_nodes: u8) -> Self {
+    fn new(networker: Arc<Mutex<T>>, f: usize, cmd_ids: &[CommandHandle], nodes: &Nodes, pool_name: &str, timeout: i64, extended_timeout: i64, number_read_nodes: u8,
+           read_nodes_selection: ReadNodesSelection) -> Self {
         RequestHandlerImpl {
-            request_wrapper: Some(RequestSM::new(networker, f, cmd_ids, nodes, pool_name, timeout, extended_timeout, number_read_nodes)),
+            request_wrapper: Some(RequestSM::new(networker, f, cmd_ids, nodes, pool_name, timeout, extended_timeout, number_read_nodes, read_nodes_selection)),
         }
     }
 
@@ -651,10 +661,18 @@ impl<T: Networker> SingleState<T> {
             < total_nodes_cnt
     }
 
-    fn try_to_continue(self, req_id: String, node_alias: String, cmd_ids: &[CommandHandle], nodes_cnt: usize, timeout: i64) -> RequestState<T> {
+    fn try_to_continue(mut self, req_id: String, node_alias: String, cmd_ids: &[CommandHandle], nodes_cnt: usize, timeout: i64,
+                       read_nodes_selection: ReadNodesSelection) -> RequestState<T> {
         if self.is_consensus_reachable(nodes_cnt) {
-            self.networker.lock().unwrap().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
-            self.networker.lock().unwrap().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
+            let resend_cnt = match read_nodes_selection {
+                ReadNodesSelection::Random => 2,
+                // The best performing nodes failed to give a provable reply, so fall back to consensus of the whole pool at once
+                ReadNodesSelection::Adaptive => nodes_cnt.saturating_sub(self.sent_cnt),
+            };
+            for _ in 0..resend_cnt {
+                self.networker.lock().unwrap().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
+            }
+            self.sent_cnt += resend_cnt;
             self.networker.lock().unwrap().process_event(Some(NetworkerEvent::CleanTimeout(req_id, Some(node_alias))));
             RequestState::Single(self)
         } else {
@@ -859,7 +877,8 @@ pub mod tests {
     pub struct MockRequestHandler {}
 
     impl<T: Networker> RequestHandler<T> for MockRequestHandler {
-        fn new(_networker: Arc<Mutex<T>>, _f: usize, _cmd_ids: &[CommandHandle], _nodes: &Nodes, _pool_name: &str, _timeout: i64, _extended_timeout: i64, _number_read_nodes: u8) -> Self {
+        fn new(_networker: Arc<Mutex<T>>, _f: usize, _cmd_ids: &[CommandHandle], _nodes: &Nodes, _pool_name: &str, _timeout: i64, _extended_timeout: i64, _number_read_nodes: u8,
+               _read_nodes_selection: ReadNodesSelection) -> Self {
             MockRequestHandler {}
         }
 
@@ -921,6 +940,11 @@ pub mod tests {
     }
 
     fn _request_handler(pool_name: &str, f: usize, nodes_cnt: usize) -> RequestHandlerImpl<MockNetworker> {
+        _request_handler_with_read_nodes_selection(pool_name, f, nodes_cnt, ReadNodesSelection::Random)
+    }
+
+    fn _request_handler_with_read_nodes_selection(pool_name: &str, f: usize, nodes_cnt: usize,
+                                                  read_nodes_selection: ReadNodesSelection) -> RequestHandlerImpl<MockNetworker> {
         let networker = Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![])));
 
         let mut default_nodes: Nodes = HashMap::new();
@@ -940,7 +964,8 @@ pub mod tests {
                                 pool_name,
                                 0,
                                 0,
-                                NUMBER_READ_NODES)
+                                NUMBER_READ_NODES,
+                                read_nodes_selection)
     }
 
     // required because of dumping txns to cache
@@ -1225,8 +1250,38 @@ pub mod tests {
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
 
-        // TODO: FIXME: Re-check if required
-        #[allow(dead_code)]
+        fn _resend_cnt(request_handler: RequestHandlerImpl<MockNetworker>) -> usize {
+            match request_handler.request_wrapper.unwrap().state {
+                RequestState::Single(state) => state.networker.lock().unwrap().events.iter()
+                    .filter(|event| if let Some(NetworkerEvent::Resend(_, _)) = event { true } else { false })
+                    .count(),
+                _ => panic!("Unexpected request state"),
+            }
+        }
+
+        #[test]
+        fn request_handler_process_reply_event_from_single_state_works_for_no_state_proof_and_random_selection() {
+            let mut request_handler = _request_handler_with_read_nodes_selection("request_handler_process_reply_event_from_single_state_works_for_no_state_proof_and_random_selection",
+                                                                                 1, 4, ReadNodesSelection::Random);
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE_2.to_string())));
+
+            assert_eq!(NUMBER_READ_NODES as usize - 1 + 2 + 2, _resend_cnt(request_handler));
+        }
+
+        #[test]
+        fn request_handler_process_reply_event_from_single_state_works_for_no_state_proof_and_adaptive_selection() {
+            let mut request_handler = _request_handler_with_read_nodes_selection("request_handler_process_reply_event_from_single_state_works_for_no_state_proof_and_adaptive_selection",
+                                                                                 1, 4, ReadNodesSelection::Adaptive);
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE_2.to_string())));
+
+            // the rest of the pool is requested at once after the first unproven reply
+            assert_eq!(4 - 1, _resend_cnt(request_handler));
+        }
+
         fn correct_state_proof_reply(timestamp: u64) -> String {
             json!({
                 "result": {
@@ -1244,51 +1299,45 @@ pub mod tests {
             }).to_string()
         }
 
-        // FIXME: !!!
-        // #[test]
-        // fn request_handler_process_reply_event_from_single_state_works_for_state_proof() {
-        //     set_freshness_threshold(600);
-        //     add_state_proof_parser();
-        //     let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_state_proof", 1, 2);
-        //     request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
-        //     request_handler.process_event(Some(
-        //         RequestEvent::Reply(Reply::default(), correct_state_proof_reply(_get_cur_time() - 300), NODE.to_string(), REQ_ID.to_string()))
-        //     );
-        //     assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
-        // }
-
-        // FIMXE: !!!
-        // #[test]
-        // fn request_handler_process_reply_event_from_single_state_works_for_state_proof_from_future() {
-        //     set_freshness_threshold(600);
-        //     add_state_proof_parser();
-        //     let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_state_proof_from_future", 1, 2);
-        //     request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
-        //     request_handler.process_event(
-        //         Some(RequestEvent::Reply(Reply::default(), correct_state_proof_reply(_get_cur_time() + 300), NODE.to_string(), REQ_ID.to_string()))
-        //     );
-        //     assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
-        // }
+        #[test]
+        fn request_handler_process_reply_event_from_single_state_works_for_state_proof() {
+            add_state_proof_parser();
+            let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_state_proof", 1, 2);
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(
+                RequestEvent::Reply(Reply::default(), correct_state_proof_reply(_get_cur_time() - 300), NODE.to_string(), REQ_ID.to_string()))
+            );
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
 
-        // FIXME: !!!
-        // fn add_state_proof_parser() {
-        //     use crate::services::pool::REGISTERED_SP_PARSERS;
-        //     use indy_api_types::ErrorCode;
-        //     use libc::c_char;
-        //     use std::ffi::CString;
+        #[test]
+        fn request_handler_process_reply_event_from_single_state_works_for_state_proof_from_future() {
+            add_state_proof_parser();
+            let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_state_proof_from_future", 1, 2);
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(
+                Some(RequestEvent::Reply(Reply::default(), correct_state_proof_reply(_get_cur_time() + 300), NODE.to_string(), REQ_ID.to_string()))
+            );
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
 
-        //     REGISTERED_SP_PARSERS.lock().unwrap().clear();
+        fn add_state_proof_parser() {
+            use crate::services::pool::CustomStateProofParser;
+            use indy_api_types::ErrorCode;
+            use libc::c_char;
+            use std::ffi::CString;
 
-        //     extern fn test_sp(_reply_from_node: *const c_char, parsed_sp: *mut *const c_char) -> ErrorCode {
-        //         let sp: CString = CString::new("[]").unwrap();
-        //         unsafe { *parsed_sp = sp.into_raw(); }
-        //         ErrorCode::Success
-        //     }
-        //     extern fn test_free(_data: *const c_char) -> ErrorCode {
-        //         ErrorCode::Success
-        //     }
-        //     PoolService::register_sp_parser("test", test_sp, test_free).unwrap();
-        // }
+            extern fn test_sp(_reply_from_node: *const c_char, parsed_sp: *mut *const c_char) -> ErrorCode {
+                let sp: CString = CString::new("[]").unwrap();
+                unsafe { *parsed_sp = sp.into_raw(); }
+                ErrorCode::Success
+            }
+            extern fn test_free(data: *const c_char) -> ErrorCode {
+                unsafe { CString::from_raw(data as *mut c_char); }
+                ErrorCode::Success
+            }
+            PoolService::register_sp_parser("test", Arc::new(CustomStateProofParser::new(test_sp, test_free))).unwrap();
+        }
 
         // FIXME:
         // #[test]
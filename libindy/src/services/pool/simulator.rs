@@ -0,0 +1,1216 @@
+//! In-process simulated pool of Indy nodes.
+//!
+//! Nodes of the simulated pool are ROUTER sockets bound to `inproc` endpoints and served
+//! by one worker thread. All nodes share one ledger, so they answer identically:
+//! pool ledger catch-up, reads of NYM, ATTRIB, SCHEMA and CRED_DEF with state proofs
+//! signed by BLS keys of all nodes, and signed writes of these transactions.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use indy_api_types::errors::prelude::*;
+use indy_utils::crypto::{base64, ed25519_sign};
+use indy_utils::crypto::hash::hash as openssl_hash;
+use rust_base58::{FromBase58, ToBase58};
+use serde_json::Value as SJsonValue;
+use ursa::bls::{Bls, Generator, MultiSignature, ProofOfPossession, SignKey, VerKey};
+use zmq::Socket as ZSocket;
+
+use crate::domain::ledger::constants;
+use crate::domain::pool::SimulatedPoolConfig;
+use crate::services::ledger::merkletree::merkletree::MerkleTree;
+use crate::utils::crypto::signature_serializer::serialize_signature;
+
+use super::events::{COMMAND_EXIT, REQUESTS_FOR_STATE_PROOFS};
+use super::networker::Transport;
+use super::request_handler::DEFAULT_GENERATOR;
+use super::state_proof;
+use super::types::{CatchupRep, CatchupReq, ConsistencyProof, LedgerStatus, Message, RemoteNode};
+
+const POOL_LEDGER_ID: usize = 0;
+const DOMAIN_LEDGER_ID: usize = 1;
+const PROTOCOL_VERSION: usize = 2;
+const CONTROL_ENDPOINT: &str = "inproc://simulated-pool-control";
+const WRITE_REQUESTS: [&str; 4] = [constants::NYM, constants::ATTRIB, constants::SCHEMA, constants::CRED_DEF];
+
+/// Running simulated pool. Nodes are stopped when it is dropped.
+pub(crate) struct SimulatedPool {
+    genesis_txns: String,
+    transport: Arc<SimulatedTransport>,
+    control: Mutex<ZSocket>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl SimulatedPool {
+    pub(crate) fn start(config: &SimulatedPoolConfig) -> IndyResult<SimulatedPool> {
+        trace!("SimulatedPool::start > config {:?}", config);
+
+        let ctx = zmq::Context::new();
+        let ledger = SimulatedLedger::new(config)?;
+
+        let mut sockets = Vec::with_capacity(ledger.nodes.len());
+        let mut endpoints = HashMap::with_capacity(ledger.nodes.len());
+
+        for (alias, _) in ledger.nodes.iter() {
+            let endpoint = format!("inproc://simulated-node-{}", alias);
+            let socket = ctx.socket(zmq::SocketType::ROUTER)?;
+            socket.set_linger(0)?;
+            socket.bind(&endpoint)?;
+            endpoints.insert(alias.clone(), endpoint);
+            sockets.push(socket);
+        }
+
+        let control = ctx.socket(zmq::SocketType::PAIR)?;
+        let worker_control = ctx.socket(zmq::SocketType::PAIR)?;
+        worker_control.bind(CONTROL_ENDPOINT)?;
+        control.connect(CONTROL_ENDPOINT)?;
+
+        let genesis_txns = ledger.genesis_txns();
+
+        let worker = thread::spawn(move || {
+            SimulatedPoolWorker { sockets, control: worker_control, ledger }.work();
+        });
+
+        trace!("SimulatedPool::start <");
+
+        Ok(SimulatedPool {
+            genesis_txns,
+            transport: Arc::new(SimulatedTransport { ctx, endpoints }),
+            control: Mutex::new(control),
+            worker: Some(worker),
+        })
+    }
+
+    pub(crate) fn genesis_txns(&self) -> &str {
+        &self.genesis_txns
+    }
+
+    pub(crate) fn transport(&self) -> Arc<dyn Transport> {
+        self.transport.clone()
+    }
+}
+
+impl Drop for SimulatedPool {
+    fn drop(&mut self) {
+        if let Err(err) = self.control.lock().unwrap().send(COMMAND_EXIT.as_bytes(), zmq::DONTWAIT) {
+            warn!("Can't send exit command to simulated pool worker thread {}", err);
+        }
+
+        if let Some(worker) = self.worker.take() {
+            if worker.join().is_err() {
+                warn!("Simulated pool worker thread panicked");
+            }
+        }
+    }
+}
+
+/// Connects pool connections to the nodes of the simulated pool instead of their network addresses.
+pub(crate) struct SimulatedTransport {
+    ctx: zmq::Context,
+    endpoints: HashMap<String, String>,
+}
+
+impl Transport for SimulatedTransport {
+    fn connect(&self, node: &RemoteNode, key_pair: &zmq::CurveKeyPair) -> IndyResult<ZSocket> {
+        let endpoint = self.endpoints.get(&node.name).ok_or_else(|| {
+            err_msg(
+                IndyErrorKind::InvalidState,
+                format!("Node {} doesn't belong to the simulated pool", node.name),
+            )
+        })?;
+
+        let s = self.ctx.socket(zmq::SocketType::DEALER)?;
+        s.set_identity(base64::encode(&key_pair.public_key).as_bytes())?;
+        s.set_linger(0)?;
+        s.connect(endpoint)?;
+        Ok(s)
+    }
+}
+
+struct SimulatedPoolWorker {
+    sockets: Vec<ZSocket>,
+    control: ZSocket,
+    ledger: SimulatedLedger,
+}
+
+impl SimulatedPoolWorker {
+    fn work(&mut self) {
+        loop {
+            let readable: Vec<bool> = {
+                let mut poll_items: Vec<zmq::PollItem> = self.sockets
+                    .iter()
+                    .map(|socket| socket.as_poll_item(zmq::POLLIN))
+                    .collect();
+                poll_items.push(self.control.as_poll_item(zmq::POLLIN));
+
+                if let Err(err) = zmq::poll(&mut poll_items, -1) {
+                    error!("Simulated pool poll failed {:?}", err);
+                    return;
+                }
+
+                poll_items.iter().map(zmq::PollItem::is_readable).collect()
+            };
+
+            if readable[self.sockets.len()] {
+                trace!("Simulated pool worker exit");
+                return;
+            }
+
+            for idx in (0..self.sockets.len()).filter(|idx| readable[*idx]) {
+                while let Ok(parts) = self.sockets[idx].recv_multipart(zmq::DONTWAIT) {
+                    if parts.len() != 2 {
+                        warn!("Simulated node received unexpected message parts {:?}", parts);
+                        continue;
+                    }
+
+                    let msg = String::from_utf8_lossy(&parts[1]).to_string();
+
+                    for reply in self.ledger.process(&msg) {
+                        if let Err(err) = self.sockets[idx].send_multipart(&[parts[0].as_slice(), reply.as_bytes()], zmq::DONTWAIT) {
+                            warn!("Simulated node can't send reply {:?}", err);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct NymRecord {
+    identifier: String,
+    verkey: Option<String>,
+    role: Option<String>,
+}
+
+/// Ledgers and state shared by all nodes of the simulated pool.
+struct SimulatedLedger {
+    nodes: Vec<(String, SignKey)>,
+    pool_txns: Vec<SJsonValue>,
+    pool_leaves: Vec<Vec<u8>>,
+    pool_tree: MerkleTree,
+    domain_txns: Vec<SJsonValue>,
+    domain_tree: MerkleTree,
+    state: BTreeMap<Vec<u8>, String>,
+    read_results: HashMap<Vec<u8>, SJsonValue>,
+    nyms: HashMap<String, NymRecord>,
+    write_results: HashMap<(String, u64), SJsonValue>,
+}
+
+impl SimulatedLedger {
+    fn new(config: &SimulatedPoolConfig) -> IndyResult<SimulatedLedger> {
+        let gen = Generator::from_bytes(
+            &DEFAULT_GENERATOR
+                .from_base58()
+                .map_err(|_| err_msg(IndyErrorKind::InvalidState, "Invalid default BLS generator"))?,
+        )
+        .to_indy(IndyErrorKind::InvalidState, "Invalid default BLS generator")?;
+
+        let mut nodes = Vec::with_capacity(config.nodes_count);
+        let mut pool_txns = Vec::with_capacity(config.nodes_count);
+
+        for idx in 1..=config.nodes_count {
+            let alias = format!("Node{}", idx);
+            let seed = format!("{:0>32}", alias);
+
+            let (verkey, _) = ed25519_sign::create_key_pair_for_signature(Some(
+                &ed25519_sign::Seed::from_slice(seed.as_bytes())?,
+            ))?;
+
+            let bls_sign_key = SignKey::new(Some(seed.as_bytes()))
+                .to_indy(IndyErrorKind::InvalidState, "Can't create BLS sign key of simulated node")?;
+            let bls_verkey = VerKey::new(&gen, &bls_sign_key)
+                .to_indy(IndyErrorKind::InvalidState, "Can't create BLS verkey of simulated node")?;
+            let bls_pop = ProofOfPossession::new(&bls_verkey, &bls_sign_key)
+                .to_indy(IndyErrorKind::InvalidState, "Can't create BLS key proof of possession of simulated node")?;
+
+            pool_txns.push(json!({
+                "reqSignature": {},
+                "txn": {
+                    "data": {
+                        "data": {
+                            "alias": alias,
+                            "blskey": bls_verkey.as_bytes().to_base58(),
+                            "blskey_pop": bls_pop.as_bytes().to_base58(),
+                            "client_ip": "127.0.0.1",
+                            "client_port": 9700 + 2 * idx,
+                            "node_ip": "127.0.0.1",
+                            "node_port": 9699 + 2 * idx,
+                            "services": ["VALIDATOR"]
+                        },
+                        "dest": verkey[..].to_base58()
+                    },
+                    "metadata": {
+                        "from": verkey[..16].to_base58()
+                    },
+                    "type": constants::NODE
+                },
+                "txnMetadata": {
+                    "seqNo": idx,
+                    "txnId": hex::encode(openssl_hash(alias.as_bytes())?)
+                },
+                "ver": "1"
+            }));
+
+            nodes.push((alias, bls_sign_key));
+        }
+
+        let pool_leaves = pool_txns
+            .iter()
+            .map(_txn_leaf)
+            .collect::<IndyResult<Vec<Vec<u8>>>>()?;
+
+        let mut ledger = SimulatedLedger {
+            nodes,
+            pool_txns,
+            pool_tree: MerkleTree::from_vec(pool_leaves.clone())?,
+            pool_leaves,
+            domain_txns: Vec::new(),
+            domain_tree: MerkleTree::from_vec(Vec::new())?,
+            state: BTreeMap::new(),
+            read_results: HashMap::new(),
+            nyms: HashMap::new(),
+            write_results: HashMap::new(),
+        };
+
+        for nym in config.nyms.iter() {
+            let operation = json!({
+                "type": constants::NYM,
+                "dest": nym.did,
+                "verkey": nym.verkey,
+                "role": nym.role.as_ref().map(|role| _role_code(role)),
+            });
+
+            let txn = json!({
+                "txn": {
+                    "type": constants::NYM,
+                    "data": _txn_data(&operation),
+                    "metadata": {}
+                },
+                "txnMetadata": {},
+                "reqSignature": {},
+                "ver": "1"
+            });
+
+            ledger._apply_nym(&nym.did, &operation, txn)?;
+        }
+
+        Ok(ledger)
+    }
+
+    fn genesis_txns(&self) -> String {
+        self.pool_txns
+            .iter()
+            .map(SJsonValue::to_string)
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn process(&mut self, msg: &str) -> Vec<String> {
+        trace!("SimulatedLedger::process > msg {:?}", msg);
+
+        if msg == "pi" {
+            return vec!["po".to_string()];
+        }
+
+        let msg: SJsonValue = match serde_json::from_str(msg) {
+            Ok(msg) => msg,
+            Err(err) => {
+                warn!("Simulated node received malformed message {:?}", err);
+                return vec![];
+            }
+        };
+
+        let res = match (msg["op"].as_str(), msg["operation"]["type"].as_str()) {
+            (Some("LEDGER_STATUS"), _) => serde_json::from_value(msg)
+                .to_indy(IndyErrorKind::InvalidStructure, "Invalid LEDGER_STATUS")
+                .and_then(|status| self._process_ledger_status(status))
+                .map(|msg| vec![msg]),
+            (Some("CATCHUP_REQ"), _) => serde_json::from_value(msg)
+                .to_indy(IndyErrorKind::InvalidStructure, "Invalid CATCHUP_REQ")
+                .and_then(|req| self._process_catchup_req(req))
+                .map(|msg| vec![msg]),
+            (None, Some(type_)) if WRITE_REQUESTS.contains(&type_) => self._process_write(&msg),
+            (None, Some(type_)) if type_ == constants::GET_TXN => Ok(vec![self._process_get_txn(&msg)]),
+            (None, Some(type_)) if REQUESTS_FOR_STATE_PROOFS.contains(&type_) => self._process_read(&msg).map(|msg| vec![msg]),
+            (None, Some(type_)) => Ok(vec![_request_outcome(
+                "REQNACK",
+                &msg,
+                Some(&format!("Simulated pool doesn't support transactions of type {}", type_)),
+            )]),
+            _ => {
+                warn!("Simulated node received unexpected message {:?}", msg);
+                Ok(vec![])
+            }
+        };
+
+        res.unwrap_or_else(|err| {
+            error!("Simulated node can't process message {:?}", err);
+            vec![]
+        })
+    }
+
+    fn _process_ledger_status(&self, status: LedgerStatus) -> IndyResult<String> {
+        let size = self.pool_txns.len();
+
+        let msg = if status.ledgerId as usize == POOL_LEDGER_ID && status.txnSeqNo > 0 && status.txnSeqNo < size {
+            Message::ConsistencyProof(ConsistencyProof {
+                seqNoStart: status.txnSeqNo,
+                seqNoEnd: size,
+                ledgerId: POOL_LEDGER_ID,
+                hashes: _consistency_proof(&self.pool_leaves, status.txnSeqNo)?
+                    .iter()
+                    .map(|hash| hash.to_base58())
+                    .collect(),
+                oldMerkleRoot: _tree_hash(&self.pool_leaves[..status.txnSeqNo])?.to_base58(),
+                newMerkleRoot: self.pool_tree.root_hash().to_base58(),
+            })
+        } else {
+            Message::LedgerStatus(LedgerStatus {
+                txnSeqNo: size,
+                merkleRoot: self.pool_tree.root_hash().to_base58(),
+                ledgerId: POOL_LEDGER_ID as u8,
+                ppSeqNo: None,
+                viewNo: None,
+                protocolVersion: Some(PROTOCOL_VERSION),
+            })
+        };
+
+        serde_json::to_string(&msg).to_indy(IndyErrorKind::InvalidState, "Can't serialize simulated node message")
+    }
+
+    fn _process_catchup_req(&self, req: CatchupReq) -> IndyResult<String> {
+        let size = self.pool_txns.len();
+        let end = ::std::cmp::min(req.seqNoEnd, size);
+        let till = ::std::cmp::min(req.catchupTill, size);
+
+        let txns = (::std::cmp::max(req.seqNoStart, 1)..=end)
+            .map(|seq_no| (seq_no.to_string(), self.pool_txns[seq_no - 1].clone()))
+            .collect();
+
+        let cons_proof = if end > 0 && end < till {
+            _consistency_proof(&self.pool_leaves[..till], end)?
+                .iter()
+                .map(|hash| hash.to_base58())
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        serde_json::to_string(&Message::CatchupRep(CatchupRep {
+            ledgerId: POOL_LEDGER_ID,
+            consProof: cons_proof,
+            txns,
+        }))
+        .to_indy(IndyErrorKind::InvalidState, "Can't serialize simulated node message")
+    }
+
+    fn _process_read(&self, request: &SJsonValue) -> IndyResult<String> {
+        let mut result = request["operation"].clone();
+        result["identifier"] = request["identifier"].clone();
+        result["reqId"] = request["reqId"].clone();
+
+        match state_proof::parse_key_from_request_for_builtin_sp(request) {
+            Some(key) => {
+                match self.read_results.get(&key) {
+                    Some(found) => _merge(&mut result, found),
+                    None => _merge(&mut result, &json!({"data": null, "seqNo": null, "txnTime": null})),
+                }
+                result["state_proof"] = self._state_proof()?;
+            }
+            None => _merge(&mut result, &json!({"data": null, "seqNo": null, "txnTime": null})),
+        }
+
+        Ok(_reply(result))
+    }
+
+    fn _process_get_txn(&self, request: &SJsonValue) -> String {
+        let txns: &[SJsonValue] = match request["operation"]["ledgerId"].as_u64().map(|id| id as usize) {
+            Some(POOL_LEDGER_ID) => &self.pool_txns,
+            Some(DOMAIN_LEDGER_ID) | None => &self.domain_txns,
+            _ => &[],
+        };
+
+        let seq_no = request["operation"]["data"].as_u64();
+
+        let data = seq_no
+            .filter(|seq_no| *seq_no > 0)
+            .and_then(|seq_no| txns.get(seq_no as usize - 1))
+            .cloned()
+            .unwrap_or(SJsonValue::Null);
+
+        _reply(json!({
+            "type": constants::GET_TXN,
+            "identifier": request["identifier"],
+            "reqId": request["reqId"],
+            "seqNo": seq_no,
+            "data": data,
+        }))
+    }
+
+    fn _process_write(&mut self, request: &SJsonValue) -> IndyResult<Vec<String>> {
+        let (identifier, req_id) = match (request["identifier"].as_str(), request["reqId"].as_u64()) {
+            (Some(identifier), Some(req_id)) => (identifier.to_string(), req_id),
+            _ => return Ok(vec![_request_outcome("REQNACK", request, Some("Request must contain identifier and reqId"))]),
+        };
+
+        let ack = _request_outcome("REQACK", request, None);
+
+        if let Some(result) = self.write_results.get(&(identifier.clone(), req_id)) {
+            return Ok(vec![ack, _reply(result.clone())]);
+        }
+
+        let signers = match self._verify_signatures(request)? {
+            Ok(signers) => signers,
+            Err(reason) => return Ok(vec![_request_outcome("REQNACK", request, Some(&reason))]),
+        };
+
+        match self._apply_write(request, &identifier, &signers)? {
+            Ok(result) => {
+                self.write_results.insert((identifier, req_id), result.clone());
+                Ok(vec![ack, _reply(result)])
+            }
+            Err(reason) => Ok(vec![ack, _request_outcome("REJECT", request, Some(&reason))]),
+        }
+    }
+
+    /// Returns DIDs signed the request or the reason to reject it.
+    fn _verify_signatures(&self, request: &SJsonValue) -> IndyResult<Result<Vec<String>, String>> {
+        let mut signatures: Vec<(&str, &str)> = Vec::new();
+
+        if let (Some(identifier), Some(signature)) = (request["identifier"].as_str(), request["signature"].as_str()) {
+            signatures.push((identifier, signature));
+        }
+
+        if let Some(multi_signatures) = request["signatures"].as_object() {
+            for (did, signature) in multi_signatures {
+                if let Some(signature) = signature.as_str() {
+                    signatures.push((did, signature));
+                }
+            }
+        }
+
+        if signatures.is_empty() {
+            return Ok(Err("MissingSignature()".to_string()));
+        }
+
+        let serialized = serialize_signature(request.clone())?;
+
+        for (did, signature) in signatures.iter() {
+            let verkey = match self.nyms.get(*did).and_then(|nym| nym.verkey.as_ref()) {
+                Some(verkey) => verkey,
+                None => return Ok(Err(format!("CouldNotAuthenticate('Can not find verkey for {}')", did))),
+            };
+
+            let verkey = if verkey.starts_with('~') {
+                let mut full_verkey = did.from_base58().unwrap_or_default();
+                full_verkey.extend(verkey[1..].from_base58().unwrap_or_default());
+                full_verkey
+            } else {
+                verkey.from_base58().unwrap_or_default()
+            };
+
+            let valid = match (ed25519_sign::PublicKey::from_slice(&verkey),
+                               signature.from_base58().map(|signature| ed25519_sign::Signature::from_slice(&signature))) {
+                (Ok(verkey), Ok(Ok(signature))) => ed25519_sign::verify(&verkey, serialized.as_bytes(), &signature)?,
+                _ => false,
+            };
+
+            if !valid {
+                return Ok(Err(format!("InsufficientCorrectSignatures(0, 1): invalid signature of {}", did)));
+            }
+        }
+
+        let signers: Vec<String> = signatures.iter().map(|(did, _)| did.to_string()).collect();
+
+        if let Some(endorser) = request["endorser"].as_str() {
+            if !signers.iter().any(|signer| signer == endorser) {
+                return Ok(Err(format!("MissingSignature(): endorser {} must sign the request", endorser)));
+            }
+        }
+
+        Ok(Ok(signers))
+    }
+
+    /// Returns reply result of the written transaction or the reason to reject it.
+    fn _apply_write(&mut self, request: &SJsonValue, identifier: &str, signers: &[String]) -> IndyResult<Result<SJsonValue, String>> {
+        let operation = &request["operation"];
+        let type_ = operation["type"].as_str().unwrap_or_default();
+
+        let endorser = request["endorser"].as_str();
+        let author_roles: Vec<Option<&str>> = ::std::iter::once(identifier)
+            .chain(endorser)
+            .filter(|did| signers.iter().any(|signer| signer == did))
+            .map(|did| self.nyms.get(did).and_then(|nym| nym.role.as_ref().map(String::as_str)))
+            .collect();
+        let authorized = |roles: &[&str]| author_roles.iter().any(|role| role.map(|role| roles.contains(&role)).unwrap_or(false));
+
+        let privileged = [constants::TRUSTEE, constants::STEWARD, constants::ENDORSER];
+
+        let check = match type_ {
+            constants::NYM => {
+                let dest = unwrap_opt_or_return!(operation["dest"].as_str(), Ok(Err("NYM without dest".to_string())));
+                let existing = self.nyms.get(dest);
+                let new_role = operation["role"].as_str().filter(|role| !role.is_empty());
+                let role_changed = operation.get("role").is_some()
+                    && existing.map(|nym| nym.role.as_ref().map(String::as_str) != new_role).unwrap_or(new_role.is_some());
+                let role_authorized = match new_role {
+                    Some(constants::ENDORSER) | Some(constants::NETWORK_MONITOR) => authorized(&[constants::TRUSTEE, constants::STEWARD]),
+                    _ => authorized(&[constants::TRUSTEE]),
+                };
+
+                match existing {
+                    None if !authorized(&privileged) => Err(format!("{} is not authorized to create NYM", identifier)),
+                    Some(_) if identifier != dest && !authorized(&[constants::TRUSTEE]) =>
+                        Err(format!("{} is not authorized to update NYM {}", identifier, dest)),
+                    _ if role_changed && !role_authorized => Err(format!("{} is not authorized to set role {:?}", identifier, new_role)),
+                    _ => Ok(()),
+                }
+            }
+            constants::ATTRIB => {
+                let dest = operation["dest"].as_str().unwrap_or_default();
+                match self.nyms.get(dest) {
+                    Some(nym) if identifier == dest || identifier == nym.identifier => Ok(()),
+                    Some(_) => Err(format!("{} is not authorized to add attribute of {}", identifier, dest)),
+                    None => Err(format!("Unknown NYM {}", dest)),
+                }
+            }
+            constants::SCHEMA | constants::CRED_DEF if !authorized(&privileged) =>
+                Err(format!("{} is not authorized to write transaction of type {}", identifier, type_)),
+            constants::CRED_DEF => {
+                let schema = operation["ref"].as_u64()
+                    .filter(|seq_no| *seq_no > 0)
+                    .and_then(|seq_no| self.domain_txns.get(seq_no as usize - 1));
+
+                match schema {
+                    Some(schema) if schema["txn"]["type"].as_str() == Some(constants::SCHEMA) => Ok(()),
+                    _ => Err(format!("Schema with seqNo {:?} is not found", operation["ref"])),
+                }
+            }
+            _ => Ok(()),
+        };
+
+        if let Err(reason) = check {
+            return Ok(Err(reason));
+        }
+
+        let mut metadata = json!({"from": identifier, "reqId": request["reqId"]});
+        if let Some(endorser) = endorser {
+            metadata["endorser"] = json!(endorser);
+        }
+        if !request["taaAcceptance"].is_null() {
+            metadata["taaAcceptance"] = request["taaAcceptance"].clone();
+        }
+
+        let signature_values: Vec<SJsonValue> = signers
+            .iter()
+            .map(|did| {
+                let value = if request["signature"].is_string() && did == identifier {
+                    request["signature"].clone()
+                } else {
+                    request["signatures"][did].clone()
+                };
+                json!({"from": did, "value": value})
+            })
+            .collect();
+
+        let txn = json!({
+            "txn": {
+                "type": type_,
+                "data": _txn_data(operation),
+                "metadata": metadata,
+                "protocolVersion": request["protocolVersion"]
+            },
+            "txnMetadata": {},
+            "reqSignature": {
+                "type": if signature_values.len() > 1 { "ED25519_MULTI" } else { "ED25519" },
+                "values": signature_values
+            },
+            "ver": "1"
+        });
+
+        let written = match type_ {
+            constants::NYM => self._apply_nym(identifier, operation, txn)?,
+            constants::ATTRIB => self._apply_attrib(operation, txn)?,
+            constants::SCHEMA => self._apply_schema(identifier, operation, txn)?,
+            _ => self._apply_cred_def(identifier, operation, txn)?,
+        };
+
+        Ok(written)
+    }
+
+    fn _apply_nym(&mut self, identifier: &str, operation: &SJsonValue, txn: SJsonValue) -> IndyResult<Result<SJsonValue, String>> {
+        let dest = operation["dest"].as_str().unwrap_or_default().to_string();
+
+        let (txn, seq_no, txn_time) = self._append_txn(txn)?;
+
+        let nym = self.nyms.entry(dest.clone()).or_insert_with(|| NymRecord {
+            identifier: identifier.to_string(),
+            verkey: None,
+            role: None,
+        });
+
+        if let Some(verkey) = operation["verkey"].as_str() {
+            nym.verkey = Some(verkey.to_string());
+        }
+
+        if let Some(role) = operation.get("role") {
+            nym.role = role.as_str().filter(|role| !role.is_empty()).map(String::from);
+        }
+
+        let data = json!({
+            "dest": dest,
+            "identifier": nym.identifier,
+            "role": nym.role,
+            "verkey": nym.verkey,
+            "seqNo": seq_no,
+            "txnTime": txn_time,
+        });
+
+        self._put_state(
+            json!({"type": constants::GET_NYM, "dest": dest}),
+            json!({"data": data.to_string(), "seqNo": seq_no, "txnTime": txn_time}),
+        )?;
+
+        Ok(Ok(txn))
+    }
+
+    fn _apply_attrib(&mut self, operation: &SJsonValue, txn: SJsonValue) -> IndyResult<Result<SJsonValue, String>> {
+        let (get_operation, data) = if let Some(raw) = operation["raw"].as_str() {
+            let raw_json: SJsonValue = unwrap_or_return!(serde_json::from_str(raw), Ok(Err("Invalid raw attribute".to_string())));
+            let name = unwrap_opt_or_return!(raw_json.as_object().and_then(|raw| raw.keys().next()), Ok(Err("Empty raw attribute".to_string())));
+            (json!({"raw": name}), raw.to_string())
+        } else if let Some(hash) = operation["hash"].as_str() {
+            (json!({"hash": hash}), hash.to_string())
+        } else if let Some(enc) = operation["enc"].as_str() {
+            (json!({"enc": enc}), enc.to_string())
+        } else {
+            return Ok(Err("ATTRIB must contain one of raw, hash and enc".to_string()));
+        };
+
+        let (txn, seq_no, txn_time) = self._append_txn(txn)?;
+
+        let mut get_operation = get_operation;
+        get_operation["type"] = json!(constants::GET_ATTR);
+        get_operation["dest"] = operation["dest"].clone();
+
+        self._put_state(get_operation, json!({"data": data, "seqNo": seq_no, "txnTime": txn_time}))?;
+
+        Ok(Ok(txn))
+    }
+
+    fn _apply_schema(&mut self, identifier: &str, operation: &SJsonValue, txn: SJsonValue) -> IndyResult<Result<SJsonValue, String>> {
+        let get_operation = json!({
+            "type": constants::GET_SCHEMA,
+            "dest": identifier,
+            "data": {
+                "name": operation["data"]["name"],
+                "version": operation["data"]["version"],
+            }
+        });
+
+        let request = json!({"operation": get_operation, "protocolVersion": PROTOCOL_VERSION});
+
+        let exists = state_proof::parse_key_from_request_for_builtin_sp(&request)
+            .map(|key| self.state.contains_key(&key))
+            .unwrap_or(false);
+
+        if exists {
+            return Ok(Err(format!("Schema {} {} of {} already exists", operation["data"]["name"], operation["data"]["version"], identifier)));
+        }
+
+        let (txn, seq_no, txn_time) = self._append_txn(txn)?;
+
+        self._put_state(get_operation, json!({"data": operation["data"], "seqNo": seq_no, "txnTime": txn_time}))?;
+
+        Ok(Ok(txn))
+    }
+
+    fn _apply_cred_def(&mut self, identifier: &str, operation: &SJsonValue, txn: SJsonValue) -> IndyResult<Result<SJsonValue, String>> {
+        let (txn, seq_no, txn_time) = self._append_txn(txn)?;
+
+        self._put_state(
+            json!({
+                "type": constants::GET_CRED_DEF,
+                "ref": operation["ref"],
+                "signature_type": operation["signature_type"],
+                "origin": identifier,
+                "tag": operation["tag"],
+            }),
+            json!({"data": operation["data"], "seqNo": seq_no, "txnTime": txn_time}),
+        )?;
+
+        Ok(Ok(txn))
+    }
+
+    /// Appends the transaction to the domain ledger and returns it with seqNo and txnTime set.
+    fn _append_txn(&mut self, mut txn: SJsonValue) -> IndyResult<(SJsonValue, u64, u64)> {
+        let seq_no = self.domain_txns.len() as u64 + 1;
+        let txn_time = _now();
+
+        txn["txnMetadata"] = json!({"seqNo": seq_no, "txnTime": txn_time});
+
+        self.domain_tree.append(_txn_leaf(&txn)?)?;
+        self.domain_txns.push(txn.clone());
+
+        txn["rootHash"] = json!(self.domain_tree.root_hash().to_base58());
+
+        Ok((txn, seq_no, txn_time))
+    }
+
+    /// Stores the value proven for reads done with `get_operation`
+    /// and the reply result fields returned for them.
+    fn _put_state(&mut self, get_operation: SJsonValue, found: SJsonValue) -> IndyResult<()> {
+        let mut result = get_operation.clone();
+        _merge(&mut result, &found);
+
+        let request = json!({"operation": get_operation, "protocolVersion": PROTOCOL_VERSION});
+
+        match state_proof::parse_key_value_for_builtin_sp(&request, &result) {
+            Some((key, Some(value))) => {
+                self.state.insert(key.clone(), value);
+                self.read_results.insert(key, found);
+                Ok(())
+            }
+            _ => Err(err_msg(IndyErrorKind::InvalidState, "Can't calculate state key-value for simulated transaction")),
+        }
+    }
+
+    fn _state_proof(&self) -> IndyResult<SJsonValue> {
+        let (root_hash, proof_nodes) = state_proof::build_state_proof(&self.state);
+
+        let value = json!({
+            "ledger_id": DOMAIN_LEDGER_ID,
+            "pool_state_root_hash": self.pool_tree.root_hash().to_base58(),
+            "state_root_hash": root_hash,
+            "timestamp": _now(),
+            "txn_root_hash": self.domain_tree.root_hash().to_base58(),
+        });
+
+        let message = rmp_serde::to_vec_named(&value)
+            .to_indy(IndyErrorKind::InvalidState, "Can't encode simulated multi signature value")?;
+
+        let signatures = self.nodes
+            .iter()
+            .map(|(_, sign_key)| Bls::sign(&message, sign_key))
+            .collect::<Result<Vec<_>, _>>()
+            .to_indy(IndyErrorKind::InvalidState, "Can't sign simulated state proof")?;
+
+        let signature = MultiSignature::new(&signatures.iter().collect::<Vec<_>>())
+            .to_indy(IndyErrorKind::InvalidState, "Can't sign simulated state proof")?;
+
+        Ok(json!({
+            "root_hash": root_hash,
+            "proof_nodes": proof_nodes,
+            "multi_signature": {
+                "signature": signature.as_bytes().to_base58(),
+                "participants": self.nodes.iter().map(|(alias, _)| alias).collect::<Vec<_>>(),
+                "value": value,
+            }
+        }))
+    }
+}
+
+fn _role_code(role: &str) -> &str {
+    match role {
+        "TRUSTEE" => constants::TRUSTEE,
+        "STEWARD" => constants::STEWARD,
+        "ENDORSER" | "TRUST_ANCHOR" => constants::ENDORSER,
+        "NETWORK_MONITOR" => constants::NETWORK_MONITOR,
+        role => role,
+    }
+}
+
+fn _txn_data(operation: &SJsonValue) -> SJsonValue {
+    let mut data = operation.clone();
+    if let Some(data) = data.as_object_mut() {
+        data.remove("type");
+    }
+    data
+}
+
+fn _txn_leaf(txn: &SJsonValue) -> IndyResult<Vec<u8>> {
+    rmp_serde::to_vec_named(txn).to_indy(IndyErrorKind::InvalidState, "Can't encode simulated txn as message pack")
+}
+
+fn _merge(target: &mut SJsonValue, source: &SJsonValue) {
+    if let (Some(target), Some(source)) = (target.as_object_mut(), source.as_object()) {
+        for (key, value) in source {
+            target.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+fn _reply(result: SJsonValue) -> String {
+    json!({"op": "REPLY", "result": result}).to_string()
+}
+
+fn _request_outcome(op: &str, request: &SJsonValue, reason: Option<&str>) -> String {
+    let mut msg = json!({
+        "op": op,
+        "identifier": request["identifier"],
+        "reqId": request["reqId"],
+    });
+
+    if let Some(reason) = reason {
+        msg["reason"] = json!(reason);
+    }
+
+    msg.to_string()
+}
+
+fn _now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since_epoch| since_epoch.as_secs())
+        .unwrap_or(0)
+}
+
+fn _tree_hash(leaves: &[Vec<u8>]) -> IndyResult<Vec<u8>> {
+    Ok(MerkleTree::from_vec(leaves.to_vec())?.root_hash().clone())
+}
+
+/// RFC 6962 consistency proof between the first `old_size` leaves and all `leaves`.
+fn _consistency_proof(leaves: &[Vec<u8>], old_size: usize) -> IndyResult<Vec<Vec<u8>>> {
+    _consistency_subproof(leaves, old_size, true)
+}
+
+fn _consistency_subproof(leaves: &[Vec<u8>], old_size: usize, complete: bool) -> IndyResult<Vec<Vec<u8>>> {
+    let size = leaves.len();
+
+    if old_size == size {
+        return Ok(if complete { Vec::new() } else { vec![_tree_hash(leaves)?] });
+    }
+
+    let mut split = 1;
+    while split * 2 < size {
+        split *= 2;
+    }
+
+    let proof = if old_size <= split {
+        let mut proof = _consistency_subproof(&leaves[..split], old_size, complete)?;
+        proof.push(_tree_hash(&leaves[split..])?);
+        proof
+    } else {
+        let mut proof = _consistency_subproof(&leaves[split..], old_size - split, false)?;
+        proof.push(_tree_hash(&leaves[..split])?);
+        proof
+    };
+
+    Ok(proof)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::pool::{register_state_proof_parser, ParsedSP, StateProofParser};
+    use crate::domain::ledger::request::ProtocolVersion;
+    use crate::domain::pool::SimulatedNym;
+    use crate::services::pool::catchup::check_cons_proofs;
+    use crate::services::pool::merkle_tree_factory;
+    use crate::services::pool::pool::_get_nodes_and_remotes;
+
+    use super::*;
+
+    const TRUSTEE_SEED: &str = "000000000000000000000000Trustee1";
+
+    struct Identity {
+        did: String,
+        verkey: String,
+        sign_key: ed25519_sign::SecretKey,
+    }
+
+    fn _identity(seed: &str) -> Identity {
+        let (verkey, sign_key) = ed25519_sign::create_key_pair_for_signature(Some(
+            &ed25519_sign::Seed::from_slice(seed.as_bytes()).unwrap(),
+        )).unwrap();
+
+        Identity {
+            did: verkey[..16].to_base58(),
+            verkey: verkey[..].to_base58(),
+            sign_key,
+        }
+    }
+
+    fn _ledger(trustee: &Identity) -> SimulatedLedger {
+        SimulatedLedger::new(&SimulatedPoolConfig {
+            nodes_count: 4,
+            nyms: vec![SimulatedNym {
+                did: trustee.did.clone(),
+                verkey: trustee.verkey.clone(),
+                role: Some("TRUSTEE".to_string()),
+            }],
+        }).unwrap()
+    }
+
+    fn _signed_request(signer: &Identity, req_id: u64, operation: SJsonValue) -> String {
+        let mut request = json!({
+            "identifier": signer.did,
+            "reqId": req_id,
+            "operation": operation,
+            "protocolVersion": 2,
+        });
+
+        let serialized = serialize_signature(request.clone()).unwrap();
+        let signature = ed25519_sign::sign(&signer.sign_key, serialized.as_bytes()).unwrap();
+        request["signature"] = json!(signature[..].to_base58());
+        request.to_string()
+    }
+
+    fn _single_reply(replies: Vec<String>, op: &str) -> SJsonValue {
+        let reply: SJsonValue = serde_json::from_str(replies.last().unwrap()).unwrap();
+        assert_eq!(op, reply["op"].as_str().unwrap());
+        reply
+    }
+
+    #[test]
+    fn simulated_ledger_genesis_txns_works() {
+        ProtocolVersion::set(2);
+
+        let ledger = _ledger(&_identity(TRUSTEE_SEED));
+
+        let merkle = merkle_tree_factory::from_data(&ledger.genesis_txns()).unwrap();
+        let (nodes, remotes) = _get_nodes_and_remotes(&merkle).unwrap();
+
+        assert_eq!(4, nodes.len());
+        assert_eq!(4, remotes.len());
+        assert!(nodes.values().all(Option::is_some));
+        assert_eq!(ledger.pool_tree.root_hash(), merkle.root_hash());
+    }
+
+    #[test]
+    fn simulated_ledger_process_works_for_ping() {
+        let mut ledger = _ledger(&_identity(TRUSTEE_SEED));
+
+        assert_eq!(vec!["po".to_string()], ledger.process("pi"));
+    }
+
+    #[test]
+    fn simulated_ledger_process_works_for_actual_ledger_status() {
+        let mut ledger = _ledger(&_identity(TRUSTEE_SEED));
+
+        let status = json!({
+            "op": "LEDGER_STATUS",
+            "txnSeqNo": 4,
+            "merkleRoot": ledger.pool_tree.root_hash().to_base58(),
+            "ledgerId": 0,
+            "ppSeqNo": null,
+            "viewNo": null,
+            "protocolVersion": 2,
+        }).to_string();
+
+        let reply = _single_reply(ledger.process(&status), "LEDGER_STATUS");
+        assert_eq!(4, reply["txnSeqNo"].as_u64().unwrap());
+    }
+
+    #[test]
+    fn simulated_ledger_process_works_for_catchup() {
+        let mut ledger = _ledger(&_identity(TRUSTEE_SEED));
+
+        let status = json!({
+            "op": "LEDGER_STATUS",
+            "txnSeqNo": 2,
+            "merkleRoot": _tree_hash(&ledger.pool_leaves[..2]).unwrap().to_base58(),
+            "ledgerId": 0,
+            "ppSeqNo": null,
+            "viewNo": null,
+        }).to_string();
+
+        let proof = _single_reply(ledger.process(&status), "CONSISTENCY_PROOF");
+        let proof: ConsistencyProof = serde_json::from_value(proof).unwrap();
+
+        let old_tree = MerkleTree::from_vec(ledger.pool_leaves[..2].to_vec()).unwrap();
+        let target_root = proof.newMerkleRoot.from_base58().unwrap();
+        check_cons_proofs(&old_tree, &proof.hashes, &target_root, proof.seqNoEnd).unwrap();
+
+        let req = json!({
+            "op": "CATCHUP_REQ",
+            "ledgerId": 0,
+            "seqNoStart": 3,
+            "seqNoEnd": 3,
+            "catchupTill": 4,
+        }).to_string();
+
+        let rep = _single_reply(ledger.process(&req), "CATCHUP_REP");
+        let rep: CatchupRep = serde_json::from_value(rep).unwrap();
+
+        let mut tree = old_tree;
+        tree.append(_txn_leaf(&rep.txns["3"]).unwrap()).unwrap();
+        check_cons_proofs(&tree, &rep.consProof, &target_root, 4).unwrap();
+    }
+
+    #[test]
+    fn simulated_ledger_process_works_for_nym_write_and_read() {
+        ProtocolVersion::set(2);
+
+        let trustee = _identity(TRUSTEE_SEED);
+        let new_identity = _identity("00000000000000000000000000000My1");
+        let mut ledger = _ledger(&trustee);
+
+        let nym = _signed_request(&trustee, 1, json!({
+            "type": constants::NYM,
+            "dest": new_identity.did,
+            "verkey": new_identity.verkey,
+        }));
+
+        let replies = ledger.process(&nym);
+        assert_eq!(2, replies.len());
+        let reply = _single_reply(replies, "REPLY");
+        assert_eq!(2, reply["result"]["txnMetadata"]["seqNo"].as_u64().unwrap());
+        assert_eq!(trustee.did, reply["result"]["txn"]["metadata"]["from"].as_str().unwrap());
+
+        let get_nym = json!({
+            "identifier": new_identity.did,
+            "reqId": 2,
+            "operation": {"type": constants::GET_NYM, "dest": new_identity.did},
+            "protocolVersion": 2,
+        });
+
+        let reply = _single_reply(ledger.process(&get_nym.to_string()), "REPLY");
+        let data: SJsonValue = serde_json::from_str(reply["result"]["data"].as_str().unwrap()).unwrap();
+        assert_eq!(new_identity.verkey, data["verkey"].as_str().unwrap());
+
+        let merkle = merkle_tree_factory::from_data(&ledger.genesis_txns()).unwrap();
+        let (nodes, _) = _get_nodes_and_remotes(&merkle).unwrap();
+        let gen = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap();
+
+        let key = state_proof::parse_key_from_request_for_builtin_sp(&get_nym).unwrap();
+        let parsed_sps = state_proof::parse_generic_reply_for_proof_checking(&reply["result"], &reply.to_string(), Some(&key)).unwrap();
+        assert!(state_proof::verify_parsed_sp(parsed_sps, &nodes, 1, &gen));
+    }
+
+    // Parses replies of a custom type which carry GET_NYM state proofs
+    struct CustomGetNymParser {
+        key: Vec<u8>,
+        root_hash: Option<String>,
+    }
+
+    impl StateProofParser for CustomGetNymParser {
+        fn parse(&self, reply_from_node: &str) -> IndyResult<Vec<ParsedSP>> {
+            let mut reply: SJsonValue = serde_json::from_str(reply_from_node)
+                .to_indy(IndyErrorKind::InvalidStructure, "Reply is invalid json")?;
+            reply["result"]["type"] = json!(constants::GET_NYM);
+
+            let mut parsed_sps = state_proof::parse_generic_reply_for_proof_checking(&reply["result"], reply_from_node, Some(&self.key))
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Reply has no state proof"))?;
+
+            if let Some(ref root_hash) = self.root_hash {
+                parsed_sps.iter_mut().for_each(|parsed_sp| parsed_sp.root_hash = root_hash.clone());
+            }
+
+            Ok(parsed_sps)
+        }
+    }
+
+    #[test]
+    fn simulated_ledger_state_proof_works_for_registered_rust_parser() {
+        ProtocolVersion::set(2);
+
+        let trustee = _identity(TRUSTEE_SEED);
+        let new_identity = _identity("00000000000000000000000000000My1");
+        let mut ledger = _ledger(&trustee);
+
+        let nym = _signed_request(&trustee, 1, json!({
+            "type": constants::NYM,
+            "dest": new_identity.did,
+            "verkey": new_identity.verkey,
+        }));
+        ledger.process(&nym);
+
+        let get_nym = json!({
+            "identifier": new_identity.did,
+            "reqId": 2,
+            "operation": {"type": constants::GET_NYM, "dest": new_identity.did},
+            "protocolVersion": 2,
+        });
+
+        let mut reply = _single_reply(ledger.process(&get_nym.to_string()), "REPLY");
+        reply["result"]["type"] = json!("simulated_custom_get_nym");
+
+        let merkle = merkle_tree_factory::from_data(&ledger.genesis_txns()).unwrap();
+        let (nodes, _) = _get_nodes_and_remotes(&merkle).unwrap();
+        let gen = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap();
+        let key = state_proof::parse_key_from_request_for_builtin_sp(&get_nym).unwrap();
+
+        let parser = CustomGetNymParser { key: key.clone(), root_hash: None };
+        register_state_proof_parser("simulated_custom_get_nym", Arc::new(parser)).unwrap();
+
+        let parsed_sps = state_proof::parse_generic_reply_for_proof_checking(&reply["result"], &reply.to_string(), None).unwrap();
+        assert!(state_proof::verify_parsed_sp(parsed_sps, &nodes, 1, &gen));
+
+        // Parser output which doesn't match the signed root hash is rejected
+        let parser = CustomGetNymParser { key, root_hash: Some("11111111111111111111111111111111".to_string()) };
+        register_state_proof_parser("simulated_custom_get_nym", Arc::new(parser)).unwrap();
+
+        let parsed_sps = state_proof::parse_generic_reply_for_proof_checking(&reply["result"], &reply.to_string(), None).unwrap();
+        assert!(!state_proof::verify_parsed_sp(parsed_sps, &nodes, 1, &gen));
+    }
+
+    #[test]
+    fn simulated_ledger_process_works_for_repeated_write() {
+        let trustee = _identity(TRUSTEE_SEED);
+        let new_identity = _identity("00000000000000000000000000000My1");
+        let mut ledger = _ledger(&trustee);
+
+        let nym = _signed_request(&trustee, 1, json!({
+            "type": constants::NYM,
+            "dest": new_identity.did,
+        }));
+
+        let first = ledger.process(&nym);
+        let second = ledger.process(&nym);
+
+        assert_eq!(first, second);
+        assert_eq!(2, ledger.domain_txns.len());
+    }
+
+    #[test]
+    fn simulated_ledger_process_works_for_invalid_signature() {
+        let trustee = _identity(TRUSTEE_SEED);
+        let mut ledger = _ledger(&trustee);
+
+        let mut nym: SJsonValue = serde_json::from_str(&_signed_request(&trustee, 1, json!({
+            "type": constants::NYM,
+            "dest": "VsKV7grR1BUE29mG2Fm2kX",
+        }))).unwrap();
+        nym["operation"]["dest"] = json!("2PRyVHmkXQnQzJQKxHxnXC");
+
+        _single_reply(ledger.process(&nym.to_string()), "REQNACK");
+        assert_eq!(1, ledger.domain_txns.len());
+    }
+
+    #[test]
+    fn simulated_ledger_process_works_for_unauthorized_write() {
+        let trustee = _identity(TRUSTEE_SEED);
+        let new_identity = _identity("00000000000000000000000000000My1");
+        let mut ledger = _ledger(&trustee);
+
+        let nym = _signed_request(&trustee, 1, json!({
+            "type": constants::NYM,
+            "dest": new_identity.did,
+            "verkey": new_identity.verkey,
+        }));
+        ledger.process(&nym);
+
+        let schema = _signed_request(&new_identity, 2, json!({
+            "type": constants::SCHEMA,
+            "data": {"name": "gvt", "version": "1.0", "attr_names": ["name"]},
+        }));
+
+        _single_reply(ledger.process(&schema), "REJECT");
+        assert_eq!(2, ledger.domain_txns.len());
+    }
+
+    #[test]
+    fn simulated_ledger_process_works_for_unsupported_request() {
+        let mut ledger = _ledger(&_identity(TRUSTEE_SEED));
+
+        let request = json!({
+            "identifier": "V4SGRU86Z58d6TV7PBUe6f",
+            "reqId": 1,
+            "operation": {"type": "999"},
+        }).to_string();
+
+        _single_reply(ledger.process(&request), "REQNACK");
+    }
+}
@@ -1,7 +1,7 @@
 extern crate log_derive;
 extern crate rmp_serde;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use indy_utils::crypto::hash::{Hash};
 use rust_base58::ToBase58;
 
@@ -21,11 +21,11 @@ use self::log_derive::logfn;
 use ursa::bls::{Bls, Generator, MultiSignature, VerKey};
 use self::node::{Node, TrieDB};
 use rust_base58::FromBase58;
-use crate::services::pool::Nodes;
+use crate::services::pool::{Nodes, PoolService};
 
 mod node;
 
-pub fn parse_generic_reply_for_proof_checking(json_msg: &SJsonValue, _raw_msg: &str, sp_key: Option<&[u8]>) -> Option<Vec<ParsedSP>> {
+pub fn parse_generic_reply_for_proof_checking(json_msg: &SJsonValue, raw_msg: &str, sp_key: Option<&[u8]>) -> Option<Vec<ParsedSP>> {
     let type_ = if let Some(type_) = json_msg["type"].as_str() {
         trace!("TransactionHandler::parse_generic_reply_for_proof_checking: type_: {:?}", type_);
         type_
@@ -42,31 +42,13 @@ pub fn parse_generic_reply_for_proof_checking(json_msg: &SJsonValue, _raw_msg: &
             warn!("parse_generic_reply_for_proof_checking: can't get key in sp for built-in type");
             None
         }
-    // FIXME: Do we need custom parsers???
-    // } else if let Some((parser, free)) = PoolService::get_sp_parser(type_) {
-    //     trace!("TransactionHandler::parse_generic_reply_for_proof_checking: plugged: parser {:?}, free {:?}",
-    //            parser, free);
-
-    //     let msg = CString::new(raw_msg).ok()?;
-    //     let mut parsed_c_str = ::std::ptr::null();
-    //     let err = parser(msg.as_ptr(), &mut parsed_c_str);
-    //     if err != ErrorCode::Success {
-    //         debug!("TransactionHandler::parse_generic_reply_for_proof_checking: <<< plugin return err {:?}", err);
-    //         return None;
-    //     }
-    //     let c_str = if parsed_c_str.is_null() { None } else { Some(unsafe { CStr::from_ptr(parsed_c_str) }) };
-    //     let parsed_sps = c_str
-    //         .and_then(|c_str| c_str.to_str().map_err(map_err_trace!()).ok())
-    //         .and_then(|c_str|
-    //             serde_json::from_str::<Vec<ParsedSP>>(c_str)
-    //                 .map_err(|err|
-    //                     debug!("TransactionHandler::parse_generic_reply_for_proof_checking: <<< can't parse plugin response {}", err))
-    //                 .ok());
-
-    //     let err = free(parsed_c_str);
-    //     trace!("TransactionHandler::parse_generic_reply_for_proof_checking: plugin free res {:?}", err);
-
-    //     parsed_sps
+    } else if let Some(parser) = PoolService::get_sp_parser(type_) {
+        trace!("TransactionHandler::parse_generic_reply_for_proof_checking: plugged");
+
+        parser
+            .parse(raw_msg)
+            .map_err(|err| debug!("TransactionHandler::parse_generic_reply_for_proof_checking: <<< plugged parser failed {:?}", err))
+            .ok()
     } else {
         trace!("TransactionHandler::parse_generic_reply_for_proof_checking: <<< type not supported");
         None
@@ -77,66 +59,75 @@ pub fn verify_parsed_sp(parsed_sps: Vec<ParsedSP>,
                         nodes: &Nodes,
                         f: usize,
                         gen: &Generator) -> bool {
-    for parsed_sp in parsed_sps {
-        if parsed_sp.multi_signature["value"]["state_root_hash"].as_str().ne(
-            &Some(&parsed_sp.root_hash)) && parsed_sp.multi_signature["value"]["txn_root_hash"].as_str().ne(
-            &Some(&parsed_sp.root_hash)) {
-            error!("Given signature is not for current root hash, aborting");
-            return false;
-        }
+    parsed_sps
+        .iter()
+        .all(|parsed_sp| verify_sp_multi_signature(parsed_sp, nodes, f, gen) && verify_sp_proof(parsed_sp))
+}
 
-        let data_to_check_proof_signature =
-            _parse_reply_for_proof_signature_checking(&parsed_sp.multi_signature);
-        let (signature, participants, value) = unwrap_opt_or_return!(data_to_check_proof_signature, false);
-        if !_verify_proof_signature(signature,
-                                    participants.as_slice(),
-                                    &value,
-                                    nodes, f, gen)
-            .map_err(|err| warn!("{:?}", err)).unwrap_or(false) {
-            return false;
-        }
+/// Checks that BLS multi-signature of the state proof is made for its root hash by enough pool nodes.
+pub fn verify_sp_multi_signature(parsed_sp: &ParsedSP,
+                                 nodes: &Nodes,
+                                 f: usize,
+                                 gen: &Generator) -> bool {
+    if parsed_sp.multi_signature["value"]["state_root_hash"].as_str().ne(
+        &Some(&parsed_sp.root_hash)) && parsed_sp.multi_signature["value"]["txn_root_hash"].as_str().ne(
+        &Some(&parsed_sp.root_hash)) {
+        error!("Given signature is not for current root hash, aborting");
+        return false;
+    }
 
-        let proof_nodes = unwrap_or_return!(base64::decode(&parsed_sp.proof_nodes), false);
-        let root_hash = unwrap_or_return!(parsed_sp.root_hash.from_base58(), false);
-        match parsed_sp.kvs_to_verify {
-            KeyValuesInSP::Simple(kvs) => {
-                match kvs.verification_type {
-                    KeyValueSimpleDataVerificationType::Simple => {
-                        for (k, v) in kvs.kvs {
-                            let key = unwrap_or_return!(base64::decode(&k), false);
-                            if !_verify_proof(proof_nodes.as_slice(),
-                                              root_hash.as_slice(),
-                                              &key,
-                                              v.as_ref().map(String::as_str)) {
-                                return false;
-                            }
-                        }
-                    }
-                    KeyValueSimpleDataVerificationType::NumericalSuffixAscendingNoGaps(data) => {
-                        if !_verify_proof_range(proof_nodes.as_slice(),
-                                                root_hash.as_slice(),
-                                                data.prefix.as_str(),
-                                                data.from,
-                                                data.next,
-                                                &kvs.kvs) {
+    let data_to_check_proof_signature =
+        _parse_reply_for_proof_signature_checking(&parsed_sp.multi_signature);
+    let (signature, participants, value) = unwrap_opt_or_return!(data_to_check_proof_signature, false);
+    _verify_proof_signature(signature,
+                            participants.as_slice(),
+                            &value,
+                            nodes, f, gen)
+        .map_err(|err| warn!("{:?}", err)).unwrap_or(false)
+}
+
+/// Checks key-values of the state proof against its root hash.
+pub fn verify_sp_proof(parsed_sp: &ParsedSP) -> bool {
+    let proof_nodes = unwrap_or_return!(base64::decode(&parsed_sp.proof_nodes), false);
+    let root_hash = unwrap_or_return!(parsed_sp.root_hash.from_base58(), false);
+    match parsed_sp.kvs_to_verify {
+        KeyValuesInSP::Simple(ref kvs) => {
+            match kvs.verification_type {
+                KeyValueSimpleDataVerificationType::Simple => {
+                    for (k, v) in kvs.kvs.iter() {
+                        let key = unwrap_or_return!(base64::decode(k), false);
+                        if !_verify_proof(proof_nodes.as_slice(),
+                                          root_hash.as_slice(),
+                                          &key,
+                                          v.as_ref().map(String::as_str)) {
                             return false;
                         }
                     }
-                    KeyValueSimpleDataVerificationType::MerkleTree(length) => {
-                        if !_verify_merkle_tree(proof_nodes.as_slice(),
-                                                root_hash.as_slice(),
-                                                &kvs.kvs,
-                                                length){
-                            return false;
-                        }
+                }
+                KeyValueSimpleDataVerificationType::NumericalSuffixAscendingNoGaps(ref data) => {
+                    if !_verify_proof_range(proof_nodes.as_slice(),
+                                            root_hash.as_slice(),
+                                            data.prefix.as_str(),
+                                            data.from,
+                                            data.next,
+                                            &kvs.kvs) {
+                        return false;
+                    }
+                }
+                KeyValueSimpleDataVerificationType::MerkleTree(length) => {
+                    if !_verify_merkle_tree(proof_nodes.as_slice(),
+                                            root_hash.as_slice(),
+                                            &kvs.kvs,
+                                            length){
+                        return false;
                     }
                 }
             }
-            //TODO IS-713 support KeyValuesInSP::SubTrie
-            kvs => {
-                warn!("Unsupported parsed state proof format for key-values {:?} ", kvs);
-                return false;
-            }
+        }
+        //TODO IS-713 support KeyValuesInSP::SubTrie
+        ref kvs => {
+            warn!("Unsupported parsed state proof format for key-values {:?} ", kvs);
+            return false;
         }
     }
 
@@ -146,6 +137,10 @@ pub fn verify_parsed_sp(parsed_sps: Vec<ParsedSP>,
 #[logfn(Trace)]
 pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Vec<u8>> {
     let type_ = json_msg["operation"]["type"].as_str()?;
+    // keys are built for the protocol version the request is sent with
+    let is_node_1_3 = json_msg["protocolVersion"].as_u64()
+        .map(|protocol_version| protocol_version == 1)
+        .unwrap_or_else(ProtocolVersion::is_node_1_3);
     let json_msg = &json_msg["operation"];
     let key_suffix: String = match type_ {
         constants::GET_ATTR => {
@@ -154,7 +149,7 @@ pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Ve
                 .or_else(|| json_msg["hash"].as_str()) {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: GET_ATTR attr_name {:?}", attr_name);
 
-                let marker = if ProtocolVersion::is_node_1_3() { '\x01' } else { '1' };
+                let marker = if is_node_1_3 { '\x01' } else { '1' };
                 let hash = openssl_hash(attr_name.as_bytes()).ok()?;
                 format!(":{}:{}", marker, hex::encode(hash))
             } else {
@@ -166,8 +161,8 @@ pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Ve
             if let (Some(sign_type), Some(sch_seq_no)) = (json_msg["signature_type"].as_str(),
                                                           json_msg["ref"].as_u64()) {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: GET_CRED_DEF sign_type {:?}, sch_seq_no: {:?}", sign_type, sch_seq_no);
-                let marker = if ProtocolVersion::is_node_1_3() { '\x03' } else { '3' };
-                let tag = if ProtocolVersion::is_node_1_3() { None } else { json_msg["tag"].as_str() };
+                let marker = if is_node_1_3 { '\x03' } else { '3' };
+                let tag = if is_node_1_3 { None } else { json_msg["tag"].as_str() };
                 let tag = tag.map(|t| format!(":{}", t)).unwrap_or_else(|| "".to_owned());
                 format!(":{}:{}:{}{}", marker, sign_type, sch_seq_no, tag)
             } else {
@@ -183,7 +178,7 @@ pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Ve
             if let (Some(name), Some(ver)) = (json_msg["data"]["name"].as_str(),
                                               json_msg["data"]["version"].as_str()) {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: GET_SCHEMA name {:?}, ver: {:?}", name, ver);
-                let marker = if ProtocolVersion::is_node_1_3() { '\x02' } else { '2' };
+                let marker = if is_node_1_3 { '\x02' } else { '2' };
                 format!(":{}:{}:{}", marker, name, ver)
             } else {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< GET_SCHEMA No key suffix");
@@ -194,7 +189,7 @@ pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Ve
             //{MARKER}:{REVOC_REG_DEF_ID} MARKER = 6
             if let Some(revoc_reg_def_id) = json_msg["revocRegDefId"].as_str() {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: GET_REVOC_REG revoc_reg_def_id {:?}", revoc_reg_def_id);
-                let marker = if ProtocolVersion::is_node_1_3() { '\x06' } else { '6' };
+                let marker = if is_node_1_3 { '\x06' } else { '6' };
                 format!("{}:{}", marker, revoc_reg_def_id)
             } else {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< GET_REVOC_REG No key suffix");
@@ -220,7 +215,7 @@ pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Ve
             //{MARKER}:{REVOC_REG_DEF_ID} MARKER = 5
             if let Some(revoc_reg_def_id) = json_msg["revocRegDefId"].as_str() {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: GET_REVOC_REG_DELTA revoc_reg_def_id {:?}", revoc_reg_def_id);
-                let marker = if ProtocolVersion::is_node_1_3() { '\x05' } else { '5' };
+                let marker = if is_node_1_3 { '\x05' } else { '5' };
                 format!("{}:{}", marker, revoc_reg_def_id)
             } else {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< GET_REVOC_REG_DELTA No key suffix");
@@ -232,7 +227,7 @@ pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Ve
             //{MARKER}:{REVOC_REG_DEF_ID} MARKER = 6 for both
             if let Some(revoc_reg_def_id) = json_msg["revocRegDefId"].as_str() {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: GET_REVOC_REG_DELTA revoc_reg_def_id {:?}", revoc_reg_def_id);
-                let marker = if ProtocolVersion::is_node_1_3() { '\x06' } else { '6' };
+                let marker = if is_node_1_3 { '\x06' } else { '6' };
                 format!("{}:{}", marker, revoc_reg_def_id)
             } else {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< GET_REVOC_REG_DELTA No key suffix");
@@ -257,6 +252,28 @@ pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Ve
                 "3:latest".to_owned()
             }
         }
+        constants::GET_FROZEN_LEDGERS => {
+            //{MARKER}:FROZEN_LEDGERS MARKER = 4
+            "4:FROZEN_LEDGERS".to_owned()
+        }
+        constants::GET_RICH_SCHEMA_OBJECT_BY_ID => {
+            if let Some(id) = json_msg["id"].as_str() {
+                id.to_owned()
+            } else {
+                debug!("parse_key_from_request_for_builtin_sp: <<< GET_RICH_SCHEMA_OBJECT_BY_ID has no id");
+                return None;
+            }
+        }
+        constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA => {
+            // secondary key: {rsType}:{rsName}:{rsVersion}
+            match (json_msg["rsType"].as_str(), json_msg["rsName"].as_str(), json_msg["rsVersion"].as_str()) {
+                (Some(rs_type), Some(rs_name), Some(rs_version)) => format!("{}:{}:{}", rs_type, rs_name, rs_version),
+                _ => {
+                    debug!("parse_key_from_request_for_builtin_sp: <<< GET_RICH_SCHEMA_OBJECT_BY_METADATA has no metadata");
+                    return None;
+                }
+            }
+        }
         constants::GET_TXN => {
             if let Some(seq_no) = json_msg["data"].as_u64() {
                 format!("{}", seq_no)
@@ -281,7 +298,8 @@ pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Ve
                 return None;
             }
         }
-        constants::GET_REVOC_REG | constants::GET_REVOC_REG_DELTA | constants::GET_TXN_AUTHR_AGRMT | constants::GET_TXN_AUTHR_AGRMT_AML | constants::GET_AUTH_RULE => {
+        constants::GET_REVOC_REG | constants::GET_REVOC_REG_DELTA | constants::GET_TXN_AUTHR_AGRMT | constants::GET_TXN_AUTHR_AGRMT_AML | constants::GET_AUTH_RULE | constants::GET_FROZEN_LEDGERS
+        | constants::GET_RICH_SCHEMA_OBJECT_BY_ID | constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA => {
             Vec::new()
         }
         constants::GET_REVOC_REG_DEF => {
@@ -310,45 +328,45 @@ pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Ve
     Some(key)
 }
 
+/// Returns the state key and the value the built-in state proof of the reply result is checked against.
+pub(super) fn parse_key_value_for_builtin_sp(request: &SJsonValue, reply_result: &SJsonValue) -> Option<(Vec<u8>, Option<String>)> {
+    let key = parse_key_from_request_for_builtin_sp(request)?;
+    let type_ = reply_result["type"].as_str()?;
+    let (data, parsed_data) = _parse_reply_data(reply_result)?;
+
+    _parse_reply_for_proof_value(reply_result, data.as_ref().map(String::as_str), &parsed_data, type_, &key)
+        .map_err(|err| debug!("parse_key_value_for_builtin_sp: <<< {:?}", err))
+        .ok()
+        .map(|value| (key, value))
+}
+
+/// Builds Patricia Merkle Trie for the state key-values.
+/// Returns base58 root hash and base64 proof nodes in the form nodes send them in `state_proof`.
+pub(super) fn build_state_proof(kvs: &BTreeMap<Vec<u8>, String>) -> (String, String) {
+    let (root_hash, proof_nodes) = Node::build_trie(kvs);
+    (root_hash.to_base58(), base64::encode(&proof_nodes))
+}
+
 fn _parse_reply_for_builtin_sp(json_msg: &SJsonValue, type_: &str, key: &[u8]) -> Option<Vec<ParsedSP>> {
     trace!("TransactionHandler::parse_reply_for_builtin_sp: >>> json_msg: {:?}", json_msg);
 
     assert!(REQUESTS_FOR_STATE_PROOFS.contains(&type_));
 
-    // TODO: FIXME: It is a workaround for Node's problem. Node returns some transactions as strings and some as objects.
-    // If node returns marshaled json it can contain spaces and it can cause invalid hash.
-    // So we have to save the original string too.
-    // See https://jira.hyperledger.org/browse/INDY-699
-    let (data, parsed_data): (Option<String>, SJsonValue) = match json_msg["data"] {
-        SJsonValue::Null => {
-            trace!("TransactionHandler::parse_reply_for_builtin_sp: Data is null");
-            (None, SJsonValue::Null)
-        }
-        SJsonValue::String(ref str) => {
-            trace!("TransactionHandler::parse_reply_for_builtin_sp: Data is string");
-            if let Ok(parsed_data) = serde_json::from_str(str) {
-                (Some(str.to_owned()), parsed_data)
-            } else {
-                trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< Data field is invalid json");
-                return None;
-            }
-        }
-        SJsonValue::Object(ref map) => {
-            trace!("TransactionHandler::parse_reply_for_builtin_sp: Data is object");
-            (Some(json_msg["data"].to_string()), SJsonValue::from(map.clone()))
-        }
-        SJsonValue::Array(ref array) => {
-            trace!("TransactionHandler::parse_reply_for_builtin_sp: Data is array");
-            (Some(json_msg["data"].to_string()), SJsonValue::from(array.clone()))
-        }
-        _ => {
-            trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< Data field is invalid type");
-            return None;
-        }
-    };
+    let (data, parsed_data) = _parse_reply_data(json_msg)?;
 
     trace!("TransactionHandler::parse_reply_for_builtin_sp: data: {:?}, parsed_data: {:?}", data, parsed_data);
 
+    // Found rich schema objects are proven against the primary key (the object id),
+    // the secondary metadata key is only used to prove absence.
+    let primary_key;
+    let key = match (type_, parsed_data["id"].as_str()) {
+        (constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA, Some(id)) => {
+            primary_key = id.as_bytes().to_vec();
+            primary_key.as_slice()
+        }
+        _ => key
+    };
+
     let mut state_proofs = vec![];
 
     match _parse_reply_for_sp(json_msg, data.as_ref().map(String::as_str), &parsed_data, type_, key) {
@@ -381,6 +399,42 @@ fn _parse_reply_for_builtin_sp(json_msg: &SJsonValue, type_: &str, key: &[u8]) -
     Some(state_proofs)
 }
 
+fn _parse_reply_data(json_msg: &SJsonValue) -> Option<(Option<String>, SJsonValue)> {
+    // TODO: FIXME: It is a workaround for Node's problem. Node returns some transactions as strings and some as objects.
+    // If node returns marshaled json it can contain spaces and it can cause invalid hash.
+    // So we have to save the original string too.
+    // See https://jira.hyperledger.org/browse/INDY-699
+    let res = match json_msg["data"] {
+        SJsonValue::Null => {
+            trace!("TransactionHandler::parse_reply_for_builtin_sp: Data is null");
+            (None, SJsonValue::Null)
+        }
+        SJsonValue::String(ref str) => {
+            trace!("TransactionHandler::parse_reply_for_builtin_sp: Data is string");
+            if let Ok(parsed_data) = serde_json::from_str(str) {
+                (Some(str.to_owned()), parsed_data)
+            } else {
+                trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< Data field is invalid json");
+                return None;
+            }
+        }
+        SJsonValue::Object(ref map) => {
+            trace!("TransactionHandler::parse_reply_for_builtin_sp: Data is object");
+            (Some(json_msg["data"].to_string()), SJsonValue::from(map.clone()))
+        }
+        SJsonValue::Array(ref array) => {
+            trace!("TransactionHandler::parse_reply_for_builtin_sp: Data is array");
+            (Some(json_msg["data"].to_string()), SJsonValue::from(array.clone()))
+        }
+        _ => {
+            trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< Data field is invalid type");
+            return None;
+        }
+    };
+
+    Some(res)
+}
+
 fn _parse_reply_for_sp(json_msg: &SJsonValue, data: Option<&str>, parsed_data: &SJsonValue, xtype: &str, sp_key: &[u8]) -> Result<ParsedSP, String> {
     trace!("TransactionHandler::_parse_reply_for_sp: data: {:?}, parsed_data: {:?}", data, parsed_data);
 
@@ -549,7 +603,7 @@ fn _verify_merkle_tree(proof_nodes: &[u8], root_hash: &[u8], kvs: &[(String, Opt
         }
     };
 
-    let turns = _calculate_turns(length, seq_no - 1);
+    let turns = calculate_turns(length, seq_no - 1);
     trace!("_verify_merkle_tree >> turns: {:?}", turns);
 
     if hashes.len() != turns.len() {
@@ -604,7 +658,7 @@ fn _verify_merkle_tree(proof_nodes: &[u8], root_hash: &[u8], kvs: &[(String, Opt
 
 // true is right
 // false is left
-fn _calculate_turns(length: u64, idx: u64) -> Vec<bool> {
+pub(super) fn calculate_turns(length: u64, idx: u64) -> Vec<bool> {
     let mut idx = idx;
     let mut length = length;
     let mut result: Vec<bool> = vec![];
@@ -766,7 +820,6 @@ fn _parse_reply_for_proof_value(json_msg: &SJsonValue, data: Option<&str>, parse
         }
 
         match xtype {
-            //TODO constants::GET_DDO => support DDO
             constants::GET_TXN => {
                 value = json!({});
                 if parsed_data["txn"].is_null() && parsed_data["txnMetadata"].is_null() &&
@@ -814,7 +867,8 @@ fn _parse_reply_for_proof_value(json_msg: &SJsonValue, data: Option<&str>, parse
             constants::GET_ATTR => {
                 value["val"] = SJsonValue::String(hex::encode(openssl_hash(data.as_bytes()).map_err(|err| err.to_string())?));
             }
-            constants::GET_CRED_DEF | constants::GET_REVOC_REG_DEF | constants::GET_REVOC_REG | constants::GET_TXN_AUTHR_AGRMT_AML => {
+            constants::GET_CRED_DEF | constants::GET_REVOC_REG_DEF | constants::GET_REVOC_REG | constants::GET_TXN_AUTHR_AGRMT_AML | constants::GET_FROZEN_LEDGERS
+            | constants::GET_RICH_SCHEMA_OBJECT_BY_ID | constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA => {
                 value["val"] = parsed_data.clone();
             }
             constants::GET_AUTH_RULE => {
@@ -992,6 +1046,55 @@ mod tests {
         assert!(!_verify_merkle_tree(node_bytes.as_bytes(), root_hash.as_slice(), kvs.as_slice(), 5));
     }
 
+    #[test]
+    fn build_state_proof_works() {
+        let mut kvs: BTreeMap<Vec<u8>, String> = BTreeMap::new();
+        kvs.insert(b"33".to_vec(), "v1".to_string());
+        kvs.insert(b"34".to_vec(), "v2".to_string());
+        kvs.insert(b"3C".to_vec(), "v3".to_string());
+        kvs.insert(b"4".to_vec(), "v4".to_string());
+        kvs.insert(b"D".to_vec(), "v5asdfasdf".to_string());
+        kvs.insert(b"DE".to_vec(), "v6fdsfdfs".to_string());
+
+        let (root_hash, proof_nodes) = build_state_proof(&kvs);
+        let root_hash = root_hash.from_base58().unwrap();
+        let proof_nodes = base64::decode(&proof_nodes).unwrap();
+
+        for (key, value) in kvs.iter() {
+            assert!(_verify_proof(&proof_nodes, &root_hash, key, Some(value)));
+        }
+        assert!(!_verify_proof(&proof_nodes, &root_hash, b"33", Some("v2")));
+        assert!(_verify_proof(&proof_nodes, &root_hash, b"35", None));
+        assert!(!_verify_proof(&proof_nodes, &root_hash, b"35", Some("v1")));
+    }
+
+    #[test]
+    fn build_state_proof_works_for_empty_state() {
+        let (root_hash, proof_nodes) = build_state_proof(&BTreeMap::new());
+        let root_hash = root_hash.from_base58().unwrap();
+        let proof_nodes = base64::decode(&proof_nodes).unwrap();
+
+        assert!(_verify_proof(&proof_nodes, &root_hash, b"33", None));
+    }
+
+    #[test]
+    fn parse_key_value_for_builtin_sp_works_for_get_nym() {
+        let request = json!({"operation": {"type": constants::GET_NYM, "dest": "VsKV7grR1BUE29mG2Fm2kX"}});
+        let result = json!({
+            "type": constants::GET_NYM,
+            "dest": "VsKV7grR1BUE29mG2Fm2kX",
+            "seqNo": 2,
+            "txnTime": 1234,
+            "data": r#"{"dest":"VsKV7grR1BUE29mG2Fm2kX","identifier":"V4SGRU86Z58d6TV7PBUe6f","role":null,"verkey":"~CoRER63DVYnWZtK8uAzNbx"}"#,
+        });
+
+        let (key, value) = parse_key_value_for_builtin_sp(&request, &result).unwrap();
+
+        assert_eq!(key, openssl_hash(b"VsKV7grR1BUE29mG2Fm2kX").unwrap());
+        let value: SJsonValue = serde_json::from_str(&value.unwrap()).unwrap();
+        assert_eq!(value, json!({"identifier": "V4SGRU86Z58d6TV7PBUe6f", "role": null, "verkey": "~CoRER63DVYnWZtK8uAzNbx", "seqNo": 2, "txnTime": 1234}));
+    }
+
     #[test]
     fn state_proof_nodes_parse_and_get_works() {
         /*
@@ -1481,6 +1584,67 @@ mod tests {
         assert_eq!(_verify_proof(proofs.as_slice(), &[0x00], "".as_bytes(), None), false);
     }
 
+    #[test]
+    fn parse_key_from_request_for_builtin_sp_works_for_get_frozen_ledgers() {
+        let request = json!({
+            "operation": {
+                "type": constants::GET_FROZEN_LEDGERS,
+            }
+        });
+
+        let key = parse_key_from_request_for_builtin_sp(&request).unwrap();
+
+        assert_eq!(b"4:FROZEN_LEDGERS".to_vec(), key);
+    }
+
+    #[test]
+    fn parse_key_from_request_for_builtin_sp_works_for_request_protocol_version() {
+        let request = |protocol_version: usize| json!({
+            "protocolVersion": protocol_version,
+            "operation": {
+                "type": constants::GET_SCHEMA,
+                "dest": "V4SGRU86Z58d6TV7PBUe6f",
+                "data": {"name": "gvt", "version": "1.0"},
+            }
+        });
+
+        let key_v1 = parse_key_from_request_for_builtin_sp(&request(1)).unwrap();
+        let key_v2 = parse_key_from_request_for_builtin_sp(&request(2)).unwrap();
+
+        assert_eq!(b"V4SGRU86Z58d6TV7PBUe6f:\x02:gvt:1.0".to_vec(), key_v1);
+        assert_eq!(b"V4SGRU86Z58d6TV7PBUe6f:2:gvt:1.0".to_vec(), key_v2);
+    }
+
+    #[test]
+    fn parse_key_from_request_for_builtin_sp_works_for_get_rich_schema_object_by_id() {
+        let request = json!({
+            "operation": {
+                "type": constants::GET_RICH_SCHEMA_OBJECT_BY_ID,
+                "id": "did:sov:8a9eb2T5x9SzFyRoWXRgUh",
+            }
+        });
+
+        let key = parse_key_from_request_for_builtin_sp(&request).unwrap();
+
+        assert_eq!(b"did:sov:8a9eb2T5x9SzFyRoWXRgUh".to_vec(), key);
+    }
+
+    #[test]
+    fn parse_key_from_request_for_builtin_sp_works_for_get_rich_schema_object_by_metadata() {
+        let request = json!({
+            "operation": {
+                "type": constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA,
+                "rsType": "sch",
+                "rsName": "SimpleRichSchema",
+                "rsVersion": "1.0",
+            }
+        });
+
+        let key = parse_key_from_request_for_builtin_sp(&request).unwrap();
+
+        assert_eq!(b"sch:SimpleRichSchema:1.0".to_vec(), key);
+    }
+
     #[test]
     fn transaction_handler_parse_generic_reply_for_proof_checking_works_for_get_txn() {
         let json_msg = &json!({
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use rlp::{DecoderError as RlpDecoderError, Prototype as RlpPrototype,
           RlpStream,
@@ -393,6 +393,101 @@ impl Node {
         String::from_utf8(res).to_indy(IndyErrorKind::InvalidStructure, "Patricia Merkle Trie contains malformed utf8 string")
     }
 
+    /// Builds Patricia Merkle Trie for the key-values.
+    /// Returns root hash of the trie and RLP list of all its nodes (child nodes are referenced by hash).
+    pub fn build_trie(kvs: &BTreeMap<Vec<u8>, String>) -> (Vec<u8>, Vec<u8>) {
+        let entries: Vec<(Vec<u8>, Vec<u8>)> = kvs.iter()
+            .map(|(key, value)| (Node::path_to_nibbles(key), Node::_encode_value(value)))
+            .collect();
+
+        let mut nodes: Vec<Node> = Vec::new();
+
+        let root = if entries.is_empty() {
+            Node::Blank
+        } else {
+            Node::_build_node(&entries, 0, &mut nodes)
+        };
+
+        let root_hash = root.get_hash().to_vec();
+        nodes.push(root);
+
+        (root_hash, rlp::encode_list(&nodes).to_vec())
+    }
+
+    fn _build_node(entries: &[(Vec<u8>, Vec<u8>)], depth: usize, nodes: &mut Vec<Node>) -> Node {
+        if entries.len() == 1 {
+            let (ref path, ref value) = entries[0];
+            return Node::Leaf(Leaf {
+                path: Node::_encode_path(&path[depth..], true),
+                value: value.clone(),
+            });
+        }
+
+        let first = &entries[0].0[depth..];
+        let common_len = entries[1..].iter()
+            .map(|(path, _)| first.iter().zip(&path[depth..]).take_while(|(a, b)| a == b).count())
+            .min()
+            .unwrap_or(0);
+
+        if common_len > 0 {
+            let next = Node::_build_node(entries, depth + common_len, nodes);
+            return Node::Extension(Extension {
+                path: Node::_encode_path(&first[..common_len], false),
+                next: Box::new(Node::_store(next, nodes)),
+            });
+        }
+
+        // entries are sorted, so the key ending on this node (if any) goes first
+        let (value, rest) = if entries[0].0.len() == depth {
+            (Some(entries[0].1.clone()), &entries[1..])
+        } else {
+            (None, entries)
+        };
+
+        let mut children: [Option<Box<Node>>; Node::RADIX] = Default::default();
+        let mut start = 0;
+        while start < rest.len() {
+            let nibble = rest[start].0[depth];
+            let end = start + rest[start..].iter().take_while(|(path, _)| path[depth] == nibble).count();
+            let child = Node::_build_node(&rest[start..end], depth + 1, nodes);
+            children[nibble as usize] = Some(Box::new(Node::_store(child, nodes)));
+            start = end;
+        }
+
+        Node::Full(FullNode { nodes: children, value })
+    }
+
+    fn _store(node: Node, nodes: &mut Vec<Node>) -> Node {
+        let hash = node.get_hash().to_vec();
+        nodes.push(node);
+        Node::Hash(hash)
+    }
+
+    fn _encode_value(value: &str) -> Vec<u8> {
+        let mut stream = RlpStream::new_list(1);
+        stream.append(&value.as_bytes().to_vec());
+        stream.out().to_vec()
+    }
+
+    fn _encode_path(nibbles: &[u8], is_leaf: bool) -> Vec<u8> {
+        let flags = if is_leaf { Node::IS_LEAF_MASK } else { 0x00 };
+        let mut path: Vec<u8> = Vec::with_capacity(nibbles.len() / 2 + 1);
+
+        let nibbles = if nibbles.len() % 2 == 1 {
+            path.push(flags | Node::IS_PATH_ODD_MASK | nibbles[0]);
+            &nibbles[1..]
+        } else {
+            path.push(flags);
+            nibbles
+        };
+
+        for pair in nibbles.chunks(2) {
+            path.push((pair[0] << 4) | pair[1]);
+        }
+
+        path
+    }
+
     fn parse_path(path: &[u8]) -> (bool, Vec<u8>) {
         let is_leaf: bool = path[0] & Node::IS_LEAF_MASK == Node::IS_LEAF_MASK;
         let is_odd: bool = path[0] & Node::IS_PATH_ODD_MASK == Node::IS_PATH_ODD_MASK;
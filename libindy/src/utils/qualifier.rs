@@ -1,8 +1,16 @@
 use lazy_static::lazy_static;
 use regex::Regex;
 
+pub const INDY_METHOD: &str = "indy";
+
 lazy_static! {
     pub static ref REGEX: Regex = Regex::new("^[a-z0-9]+:([a-z0-9]+):(.*)$").unwrap();
+
+    // did:indy:<namespace>:<id> optionally followed by an object path (/anoncreds/v0/...)
+    // Namespace may contain one sub-namespace: did:indy:sovrin:staging:<id>
+    pub static ref INDY_REGEX: Regex = Regex::new(
+        "^did:indy:(?P<namespace>[a-z0-9_-]+(?::[a-z0-9_-]+)?):(?P<id>[1-9A-HJ-NP-Za-km-z]+)(?P<path>/.*)?$"
+    ).unwrap();
 }
 
 pub fn qualify(entity: &str, prefix: &str, method: &str) -> String {
@@ -10,6 +18,10 @@ pub fn qualify(entity: &str, prefix: &str, method: &str) -> String {
 }
 
 pub fn to_unqualified(entity: &str) -> String {
+    if let Some(caps) = INDY_REGEX.captures(entity) {
+        return caps["id"].to_string();
+    }
+
     match REGEX.captures(entity) {
         None => entity.to_string(),
         Some(caps) => caps
@@ -19,7 +31,13 @@ pub fn to_unqualified(entity: &str) -> String {
     }
 }
 
+/// Returns DID method of the entity.
+/// For did:indy identifiers the namespace is a part of the method: `indy:<namespace>`.
 pub fn method(entity: &str) -> Option<String> {
+    if let Some(namespace) = indy_namespace(entity) {
+        return Some(indy_method(&namespace));
+    }
+
     match REGEX.captures(entity) {
         None => None,
         Some(caps) => caps.get(1).map(|m| m.as_str().to_string()),
@@ -30,6 +48,32 @@ pub fn is_fully_qualified(entity: &str) -> bool {
     REGEX.is_match(&entity)
 }
 
+/// Returns namespace of the Indy network for did:indy identifiers.
+pub fn indy_namespace(entity: &str) -> Option<String> {
+    INDY_REGEX
+        .captures(entity)
+        .map(|caps| caps["namespace"].to_string())
+}
+
+pub fn is_indy_qualified(entity: &str) -> bool {
+    INDY_REGEX.is_match(entity)
+}
+
+pub fn indy_method(namespace: &str) -> String {
+    format!("{}:{}", INDY_METHOD, namespace)
+}
+
+/// Returns namespace if method is did:indy method (`indy:<namespace>`).
+pub fn indy_method_namespace(method: &str) -> Option<&str> {
+    let prefix = format!("{}:", INDY_METHOD);
+
+    if method.starts_with(&prefix) && method.len() > prefix.len() {
+        Some(&method[prefix.len()..])
+    } else {
+        None
+    }
+}
+
 macro_rules! qualifiable_type (($newtype:ident) => (
 
     #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
@@ -48,7 +92,56 @@ macro_rules! qualifiable_type (($newtype:ident) => (
 
         #[allow(dead_code)]
         pub fn is_fully_qualified(&self) -> bool {
-            self.0.starts_with($newtype::PREFIX) && qualifier::is_fully_qualified(&self.0)
+            qualifier::is_indy_qualified(&self.0) ||
+                (self.0.starts_with($newtype::PREFIX) && qualifier::is_fully_qualified(&self.0))
         }
     }
 ));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DID: &str = "NcYxiDXkpYi6ov5FcYDi1e";
+    const DID_SOV: &str = "did:sov:NcYxiDXkpYi6ov5FcYDi1e";
+    const DID_INDY: &str = "did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e";
+    const DID_INDY_SUB_NAMESPACE: &str = "did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e";
+    const SCHEMA_ID_INDY: &str = "did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e/anoncreds/v0/SCHEMA/gvt/1.0";
+
+    #[test]
+    fn to_unqualified_works() {
+        assert_eq!(DID, to_unqualified(DID));
+        assert_eq!(DID, to_unqualified(DID_SOV));
+        assert_eq!(DID, to_unqualified(DID_INDY));
+        assert_eq!(DID, to_unqualified(DID_INDY_SUB_NAMESPACE));
+    }
+
+    #[test]
+    fn method_works() {
+        assert_eq!(None, method(DID));
+        assert_eq!(Some("sov".to_string()), method(DID_SOV));
+        assert_eq!(Some("indy:sovrin".to_string()), method(DID_INDY));
+        assert_eq!(Some("indy:sovrin:staging".to_string()), method(DID_INDY_SUB_NAMESPACE));
+        assert_eq!(Some("indy:sovrin".to_string()), method(SCHEMA_ID_INDY));
+    }
+
+    #[test]
+    fn qualify_works_for_indy_method() {
+        assert_eq!(DID_INDY, qualify(DID, "did", &indy_method("sovrin")));
+    }
+
+    #[test]
+    fn indy_namespace_works() {
+        assert_eq!(None, indy_namespace(DID_SOV));
+        assert_eq!(Some("sovrin".to_string()), indy_namespace(DID_INDY));
+        assert_eq!(Some("sovrin:staging".to_string()), indy_namespace(DID_INDY_SUB_NAMESPACE));
+        assert_eq!(Some("sovrin".to_string()), indy_namespace(SCHEMA_ID_INDY));
+    }
+
+    #[test]
+    fn indy_method_namespace_works() {
+        assert_eq!(None, indy_method_namespace("sov"));
+        assert_eq!(None, indy_method_namespace("indy"));
+        assert_eq!(Some("sovrin:staging"), indy_method_namespace("indy:sovrin:staging"));
+    }
+}
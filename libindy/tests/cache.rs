@@ -20,9 +20,12 @@ use utils::{
     domain::{
         anoncreds::{
             credential_definition::{CredentialDefinition, CredentialDefinitionV1},
+            revocation_registry_definition::RevocationRegistryDefinitionV1,
+            revocation_registry_delta::RevocationRegistryDeltaV1,
             schema::{SchemaId, SchemaV1},
         },
         crypto::did::DidValue,
+        ledger::nym::NymData,
     },
     Setup,
 };
@@ -472,4 +475,263 @@ mod high_cases {
                 .unwrap();
         }
     }
+
+    mod nym_cache {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_nym_cache_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let options_json = json!({}).to_string();
+            let nym_json1 = get_nym_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_TRUSTEE,
+                DID_TRUSTEE,
+                &options_json,
+            )
+            .unwrap();
+            let nym: NymData = serde_json::from_str(&nym_json1).unwrap();
+            assert_eq!(DID_TRUSTEE, nym.did.0);
+
+            // now retrieve it from cache
+            let options_json = json!({"noUpdate": true}).to_string();
+            let nym_json2 = get_nym_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_TRUSTEE,
+                DID_TRUSTEE,
+                &options_json,
+            )
+            .unwrap();
+
+            assert_eq!(nym_json1, nym_json2);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_nym_only_cache_no_cached_data() {
+            let setup = Setup::wallet_and_pool();
+
+            let options_json = json!({"noUpdate": true}).to_string();
+
+            let res = get_nym_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_TRUSTEE,
+                DID_TRUSTEE,
+                &options_json,
+            );
+
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_nym_cache_works_after_purge() {
+            let setup = Setup::wallet_and_pool();
+
+            get_nym_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_TRUSTEE,
+                DID_TRUSTEE,
+                "{}",
+            )
+            .unwrap();
+
+            purge_nym_cache(setup.wallet_handle, "{}").unwrap();
+
+            let res = get_nym_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_TRUSTEE,
+                DID_TRUSTEE,
+                &json!({"noUpdate": true}).to_string(),
+            );
+
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_purge_nym_cache_no_options() {
+            let setup = Setup::wallet();
+            purge_nym_cache(setup.wallet_handle, "{}").unwrap();
+        }
+
+        #[test]
+        fn indy_purge_nym_cache_older_than_1000_seconds() {
+            let setup = Setup::wallet();
+            purge_nym_cache(setup.wallet_handle, &json!({"maxAge": 1000}).to_string()).unwrap();
+        }
+    }
+
+    mod revoc_reg_def_cache {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_revoc_reg_def_cache_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_id) = utils::ledger::post_entities();
+
+            let options_json = json!({}).to_string();
+            let revoc_reg_def_json1 = get_revoc_reg_def_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                &options_json,
+            )
+            .unwrap();
+            let _revoc_reg_def: RevocationRegistryDefinitionV1 =
+                serde_json::from_str(&revoc_reg_def_json1).unwrap();
+
+            // now retrieve it from cache
+            let options_json = json!({"noUpdate": true}).to_string();
+            let revoc_reg_def_json2 = get_revoc_reg_def_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                &options_json,
+            )
+            .unwrap();
+
+            assert_eq!(revoc_reg_def_json1, revoc_reg_def_json2);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_revoc_reg_def_only_cache_no_cached_data() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_id) = utils::ledger::post_entities();
+
+            let options_json = json!({"noUpdate": true}).to_string();
+
+            let res = get_revoc_reg_def_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                &options_json,
+            );
+
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_purge_revoc_reg_def_cache_no_options() {
+            let setup = Setup::wallet();
+            purge_revoc_reg_def_cache(setup.wallet_handle, "{}").unwrap();
+        }
+    }
+
+    mod revoc_reg_delta_cache {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_revoc_reg_delta_cache_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_id) = utils::ledger::post_entities();
+
+            let to = time::get_time().sec as u64 + 300;
+
+            let (id1, revoc_reg_delta_json1, timestamp1) = get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                to,
+                "{}",
+            )
+            .unwrap();
+            let _revoc_reg_delta: RevocationRegistryDeltaV1 =
+                serde_json::from_str(&revoc_reg_delta_json1).unwrap();
+
+            // now retrieve it from cache
+            let (id2, revoc_reg_delta_json2, timestamp2) = get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                to,
+                &json!({"noUpdate": true}).to_string(),
+            )
+            .unwrap();
+
+            assert_eq!(id1, id2);
+            assert_eq!(revoc_reg_delta_json1, revoc_reg_delta_json2);
+            assert_eq!(timestamp1, timestamp2);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_revoc_reg_delta_cache_works_for_extended_interval() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_id) = utils::ledger::post_entities();
+
+            let to = time::get_time().sec as u64 + 300;
+
+            get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                to,
+                "{}",
+            )
+            .unwrap();
+
+            // cached delta is extended by fetching only the missing interval
+            let (_, revoc_reg_delta_json, _) = get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                to + 100,
+                "{}",
+            )
+            .unwrap();
+            let _revoc_reg_delta: RevocationRegistryDeltaV1 =
+                serde_json::from_str(&revoc_reg_delta_json).unwrap();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_revoc_reg_delta_only_cache_no_cached_data() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_id) = utils::ledger::post_entities();
+
+            let res = get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                time::get_time().sec as u64,
+                &json!({"noUpdate": true}).to_string(),
+            );
+
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_purge_revoc_reg_delta_cache_no_options() {
+            let setup = Setup::wallet();
+            purge_revoc_reg_delta_cache(setup.wallet_handle, "{}").unwrap();
+        }
+    }
 }
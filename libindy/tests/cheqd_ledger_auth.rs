@@ -49,6 +49,47 @@ mod high_cases {
             println!("Tx: {:?}", tx);
             assert_ne!(tx.len(), 0);
         }
+
+        #[test]
+        fn test_build_tx_with_fee_granter() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            let (account_number, account_sequence) = setup.get_base_account_number_and_sequence(&setup.account_id).unwrap();
+            let (granter, _) = cheqd_setup::CheqdSetup::create_key(setup.wallet_handle, "bob", "bob").unwrap();
+
+            // Message
+            let msg = cheqd_ledger::cheqd::build_msg_create_nym(
+                "test-did",
+                &setup.account_id,
+                "test-verkey",
+                "test-alias",
+                "test-role",
+            ).unwrap();
+
+            // Tx
+            let timeout_height = setup.get_timeout_height();
+            let tx = cheqd_ledger::auth::build_tx(
+                &setup.pool_alias, &setup.pub_key, &msg, account_number, account_sequence, 300000, 0, "cheq", timeout_height, "memo",
+            ).unwrap();
+            let tx_with_fee_granter = cheqd_ledger::auth::build_tx_with_fee_granter(
+                &setup.pool_alias, &setup.pub_key, &msg, account_number, account_sequence, 300000, 0, "cheq", timeout_height, "memo", &granter,
+            ).unwrap();
+
+            println!("Tx: {:?}", tx_with_fee_granter);
+            assert_ne!(tx, tx_with_fee_granter);
+        }
+
+        #[test]
+        fn test_build_tx_with_fee_granter_for_invalid_granter() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            let msg = cheqd_ledger::bank::build_msg_send(&setup.account_id, &setup.account_id, "100", &setup.denom).unwrap();
+
+            let res = cheqd_ledger::auth::build_tx_with_fee_granter(
+                &setup.pool_alias, &setup.pub_key, &msg, 0, 0, 300000, 0, "cheq", 0, "memo", "invalid-granter",
+            );
+            assert!(res.is_err());
+        }
     }
 
     #[cfg(test)]
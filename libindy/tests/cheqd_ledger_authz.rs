@@ -0,0 +1,155 @@
+#[macro_use]
+extern crate derivative;
+
+#[macro_use]
+extern crate serde_derive;
+
+#[macro_use]
+extern crate serde_json;
+
+#[macro_use]
+mod utils;
+
+#[cfg(feature = "cheqd")]
+use utils::{cheqd_keys, cheqd_ledger, cheqd_pool, cheqd_setup};
+#[cfg(feature = "cheqd")]
+use serde_json::Value;
+
+#[cfg(feature = "cheqd")]
+mod high_cases {
+    use super::*;
+
+    #[cfg(test)]
+    mod feegrant {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_cheqd_pool")]
+        fn test_grant_and_revoke_allowance() {
+            let setup = cheqd_setup::CheqdSetup::new();
+            let (grantee, _) = cheqd_setup::CheqdSetup::create_key(setup.wallet_handle, "bob", "bob").unwrap();
+
+            ///// Grant
+
+            let msg = cheqd_ledger::feegrant::build_msg_grant_allowance(
+                &setup.account_id,
+                &grantee,
+                Some("1000"),
+                Some(&setup.denom),
+                -1,
+            ).unwrap();
+
+            let resp = setup.build_and_sign_and_broadcast_tx(&msg).unwrap();
+            let tx_resp_parsed = cheqd_ledger::feegrant::parse_msg_grant_allowance_resp(&resp).unwrap();
+            println!("Tx resp: {:?}", tx_resp_parsed);
+
+            ///// Revoke
+
+            let msg = cheqd_ledger::feegrant::build_msg_revoke_allowance(&setup.account_id, &grantee).unwrap();
+
+            let resp = setup.build_and_sign_and_broadcast_tx(&msg).unwrap();
+            let tx_resp_parsed = cheqd_ledger::feegrant::parse_msg_revoke_allowance_resp(&resp).unwrap();
+            println!("Tx resp: {:?}", tx_resp_parsed);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_cheqd_pool")]
+        fn test_tx_paid_by_fee_granter() {
+            let setup = cheqd_setup::CheqdSetup::new();
+            let amount_for_transfer = 100;
+            let fee = 10;
+
+            ///// Create grantee account
+
+            let grantee_alias = "grantee_alias";
+            let grantee_response = cheqd_keys::add_random(setup.wallet_handle, grantee_alias).unwrap();
+            let grantee_response: Value = serde_json::from_str(&grantee_response).unwrap();
+            let grantee = grantee_response["account_id"].as_str().unwrap().to_string();
+            let grantee_pub_key = grantee_response["pub_key"].as_str().unwrap().to_string();
+
+            let msg = cheqd_ledger::bank::build_msg_send(&setup.account_id, &grantee, "1000", &setup.denom).unwrap();
+            setup.build_and_sign_and_broadcast_tx(&msg).unwrap();
+
+            ///// Grant
+
+            let msg = cheqd_ledger::feegrant::build_msg_grant_allowance(
+                &setup.account_id,
+                &grantee,
+                Some("1000"),
+                Some(&setup.denom),
+                -1,
+            ).unwrap();
+            setup.build_and_sign_and_broadcast_tx(&msg).unwrap();
+
+            let granter_balance = setup.get_balance(&setup.account_id).unwrap();
+            let grantee_balance = setup.get_balance(&grantee).unwrap();
+
+            ///// Send coins back to granter with the fee paid by granter
+
+            let msg = cheqd_ledger::bank::build_msg_send(
+                &grantee,
+                &setup.account_id,
+                &amount_for_transfer.to_string(),
+                &setup.denom,
+            ).unwrap();
+
+            let (account_number, account_sequence) = setup.get_base_account_number_and_sequence(&grantee).unwrap();
+            let tx = cheqd_ledger::auth::build_tx_with_fee_granter(
+                &setup.pool_alias, &grantee_pub_key, &msg, account_number, account_sequence, 300000, fee, &setup.denom, setup.get_timeout_height(), "memo", &setup.account_id,
+            ).unwrap();
+
+            let signed = cheqd_keys::sign(setup.wallet_handle, grantee_alias, &tx).unwrap();
+            let resp = cheqd_pool::broadcast_tx_commit(&setup.pool_alias, &signed).unwrap();
+            let tx_resp_parsed = cheqd_ledger::bank::parse_msg_send_resp(&resp).unwrap();
+            println!("Tx resp: {:?}", tx_resp_parsed);
+
+            ///// Grantee pays only the transferred amount, the fee is deducted from granter
+
+            assert_eq!(grantee_balance - amount_for_transfer, setup.get_balance(&grantee).unwrap());
+            assert_eq!(granter_balance + amount_for_transfer - fee, setup.get_balance(&setup.account_id).unwrap());
+        }
+    }
+
+    #[cfg(test)]
+    mod authz {
+        use super::*;
+
+        const MSG_SEND_TYPE_URL: &str = "/cosmos.bank.v1beta1.MsgSend";
+
+        #[test]
+        #[cfg(feature = "local_nodes_cheqd_pool")]
+        fn test_grant_and_revoke() {
+            let setup = cheqd_setup::CheqdSetup::new();
+            let (grantee, _) = cheqd_setup::CheqdSetup::create_key(setup.wallet_handle, "bob", "bob").unwrap();
+
+            ///// Grant
+
+            let msg = cheqd_ledger::authz::build_msg_grant(
+                &setup.account_id,
+                &grantee,
+                MSG_SEND_TYPE_URL,
+                4102444800,
+            ).unwrap();
+
+            let resp = setup.build_and_sign_and_broadcast_tx(&msg).unwrap();
+            let tx_resp_parsed = cheqd_ledger::authz::parse_msg_grant_resp(&resp).unwrap();
+            println!("Tx resp: {:?}", tx_resp_parsed);
+
+            ///// Revoke
+
+            let msg = cheqd_ledger::authz::build_msg_revoke(&setup.account_id, &grantee, MSG_SEND_TYPE_URL).unwrap();
+
+            let resp = setup.build_and_sign_and_broadcast_tx(&msg).unwrap();
+            let tx_resp_parsed = cheqd_ledger::authz::parse_msg_revoke_resp(&resp).unwrap();
+            println!("Tx resp: {:?}", tx_resp_parsed);
+        }
+
+        #[test]
+        fn test_build_msg_exec() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            let msg = cheqd_ledger::bank::build_msg_send(&setup.account_id, &setup.account_id, "100", &setup.denom).unwrap();
+            cheqd_ledger::authz::build_msg_exec(&setup.account_id, &msg).unwrap();
+        }
+    }
+}
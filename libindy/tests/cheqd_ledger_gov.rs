@@ -0,0 +1,87 @@
+#[macro_use]
+extern crate derivative;
+
+#[macro_use]
+extern crate serde_derive;
+
+#[macro_use]
+extern crate serde_json;
+
+#[macro_use]
+mod utils;
+
+#[cfg(feature = "cheqd")]
+use utils::{cheqd_ledger, cheqd_pool, cheqd_setup};
+use serde_json::Value;
+
+#[cfg(feature = "cheqd")]
+mod high_cases {
+    use super::*;
+
+    #[cfg(test)]
+    mod proposal {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_cheqd_pool")]
+        fn test_submit_proposal_and_vote() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            ///// Submit proposal
+
+            let msg = cheqd_ledger::gov::build_msg_submit_text_proposal(
+                "title",
+                "description",
+                &setup.account_id,
+                "10000000",
+                &setup.denom,
+            ).unwrap();
+
+            let resp = setup.build_and_sign_and_broadcast_tx(&msg).unwrap();
+            let tx_resp_parsed = cheqd_ledger::gov::parse_msg_submit_proposal_resp(&resp).unwrap();
+            println!("Tx resp: {:?}", tx_resp_parsed);
+
+            let tx_resp_parsed: Value = serde_json::from_str(&tx_resp_parsed).unwrap();
+            let proposal_id = tx_resp_parsed["proposal_id"].as_u64().unwrap();
+
+            ///// Query proposal
+
+            let query = cheqd_ledger::gov::build_query_proposal(proposal_id).unwrap();
+            let query_resp = cheqd_pool::abci_query(&setup.pool_alias, &query).unwrap();
+            let query_resp = cheqd_ledger::gov::parse_query_proposal_resp(&query_resp).unwrap();
+            println!("Query response: {:?}", query_resp);
+
+            let proposal: Value = serde_json::from_str(&query_resp).unwrap();
+            assert_eq!(proposal_id, proposal["proposal"]["proposal_id"].as_u64().unwrap());
+
+            ///// Vote
+
+            let msg = cheqd_ledger::gov::build_msg_vote_weighted(
+                proposal_id,
+                &setup.account_id,
+                r#"[{"option": "yes", "weight": "0.7"}, {"option": "abstain", "weight": "0.3"}]"#,
+            ).unwrap();
+
+            let resp = setup.build_and_sign_and_broadcast_tx(&msg).unwrap();
+            let tx_resp_parsed = cheqd_ledger::gov::parse_msg_vote_weighted_resp(&resp).unwrap();
+            println!("Tx resp: {:?}", tx_resp_parsed);
+
+            ///// Query vote
+
+            let query = cheqd_ledger::gov::build_query_vote(proposal_id, &setup.account_id).unwrap();
+            let query_resp = cheqd_pool::abci_query(&setup.pool_alias, &query).unwrap();
+            let query_resp = cheqd_ledger::gov::parse_query_vote_resp(&query_resp).unwrap();
+            println!("Query response: {:?}", query_resp);
+
+            let vote: Value = serde_json::from_str(&query_resp).unwrap();
+            assert_eq!(2, vote["vote"]["options"].as_array().unwrap().len());
+
+            ///// Query tally
+
+            let query = cheqd_ledger::gov::build_query_tally_result(proposal_id).unwrap();
+            let query_resp = cheqd_pool::abci_query(&setup.pool_alias, &query).unwrap();
+            let query_resp = cheqd_ledger::gov::parse_query_tally_result_resp(&query_resp).unwrap();
+            println!("Query response: {:?}", query_resp);
+        }
+    }
+}
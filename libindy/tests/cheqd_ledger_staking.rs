@@ -0,0 +1,106 @@
+#[macro_use]
+extern crate derivative;
+
+#[macro_use]
+extern crate serde_derive;
+
+#[macro_use]
+extern crate serde_json;
+
+#[macro_use]
+mod utils;
+
+#[cfg(feature = "cheqd")]
+use utils::{cheqd_ledger, cheqd_pool, cheqd_setup};
+use serde_json::Value;
+
+#[cfg(feature = "cheqd")]
+mod high_cases {
+    use super::*;
+
+    #[cfg(test)]
+    mod delegation {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_cheqd_pool")]
+        fn test_delegate_and_withdraw_rewards() {
+            let setup = cheqd_setup::CheqdSetup::new();
+            let amount_for_delegation = "100";
+
+            ///// Query bonded validators
+
+            let query = cheqd_ledger::staking::build_query_validators(Some("BOND_STATUS_BONDED"), None).unwrap();
+            let query_resp = cheqd_pool::abci_query(&setup.pool_alias, &query).unwrap();
+            let query_resp = cheqd_ledger::staking::parse_query_validators_resp(&query_resp).unwrap();
+            println!("Query response: {:?}", query_resp);
+
+            let validators: Value = serde_json::from_str(&query_resp).unwrap();
+            let validator = validators["validators"][0]["operator_address"].as_str().unwrap().to_string();
+
+            ///// Delegate
+
+            let msg = cheqd_ledger::staking::build_msg_delegate(
+                &setup.account_id,
+                &validator,
+                amount_for_delegation,
+                &setup.denom,
+            ).unwrap();
+
+            let resp = setup.build_and_sign_and_broadcast_tx(&msg).unwrap();
+            let tx_resp_parsed = cheqd_ledger::staking::parse_msg_delegate_resp(&resp).unwrap();
+            println!("Tx resp: {:?}", tx_resp_parsed);
+
+            ///// Query delegation
+
+            let query = cheqd_ledger::staking::build_query_delegation(&setup.account_id, &validator).unwrap();
+            let query_resp = cheqd_pool::abci_query(&setup.pool_alias, &query).unwrap();
+            let query_resp = cheqd_ledger::staking::parse_query_delegation_resp(&query_resp).unwrap();
+            println!("Query response: {:?}", query_resp);
+
+            let delegation: Value = serde_json::from_str(&query_resp).unwrap();
+            assert_eq!(validator, delegation["delegation_response"]["delegation"]["validator_address"].as_str().unwrap());
+
+            ///// Query delegator delegations
+
+            let query = cheqd_ledger::staking::build_query_delegator_delegations(&setup.account_id, None).unwrap();
+            let query_resp = cheqd_pool::abci_query(&setup.pool_alias, &query).unwrap();
+            let query_resp = cheqd_ledger::staking::parse_query_delegator_delegations_resp(&query_resp).unwrap();
+            println!("Query response: {:?}", query_resp);
+
+            let delegations: Value = serde_json::from_str(&query_resp).unwrap();
+            assert!(!delegations["delegation_responses"].as_array().unwrap().is_empty());
+
+            ///// Query pending rewards
+
+            let query = cheqd_ledger::distribution::build_query_delegation_total_rewards(&setup.account_id).unwrap();
+            let query_resp = cheqd_pool::abci_query(&setup.pool_alias, &query).unwrap();
+            let query_resp = cheqd_ledger::distribution::parse_query_delegation_total_rewards_resp(&query_resp).unwrap();
+            println!("Query response: {:?}", query_resp);
+
+            ///// Withdraw rewards
+
+            let msg = cheqd_ledger::distribution::build_msg_withdraw_delegator_reward(
+                &setup.account_id,
+                &validator,
+            ).unwrap();
+
+            let resp = setup.build_and_sign_and_broadcast_tx(&msg).unwrap();
+            let tx_resp_parsed = cheqd_ledger::distribution::parse_msg_withdraw_delegator_reward_resp(&resp).unwrap();
+            println!("Tx resp: {:?}", tx_resp_parsed);
+
+            ///// Undelegate
+
+            let msg = cheqd_ledger::staking::build_msg_undelegate(
+                &setup.account_id,
+                &validator,
+                amount_for_delegation,
+                &setup.denom,
+            ).unwrap();
+
+            let resp = setup.build_and_sign_and_broadcast_tx(&msg).unwrap();
+            let tx_resp_parsed = cheqd_ledger::staking::parse_msg_undelegate_resp(&resp).unwrap();
+            println!("Tx resp: {:?}", tx_resp_parsed);
+        }
+    }
+}
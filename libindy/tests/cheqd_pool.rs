@@ -11,6 +11,7 @@ mod utils;
 use utils::{cheqd_keys, cheqd_pool, cheqd_setup, cheqd_ledger};
 use utils::test;
 use serde_json::Value;
+use indyrs::ErrorCode;
 
 #[cfg(feature = "cheqd")]
 mod high_cases {
@@ -182,4 +183,94 @@ mod high_cases {
             assert!(true);
         }
     }
+
+    #[cfg(test)]
+    mod status {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_cheqd_pool")]
+        fn test_status() {
+            let setup = cheqd_setup::CheqdSetup::new();
+            let resp = cheqd_pool::status(&setup.pool_alias).unwrap();
+            println!("Status response: {:?}", resp);
+
+            let resp: Value = serde_json::from_str(&resp).unwrap();
+            assert!(resp["sync_info"]["latest_block_height"].is_string());
+        }
+    }
+
+    #[cfg(test)]
+    mod block {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_cheqd_pool")]
+        fn test_block() {
+            let setup = cheqd_setup::CheqdSetup::new();
+            let resp = cheqd_pool::block(&setup.pool_alias, 1).unwrap();
+            println!("Block response: {:?}", resp);
+
+            let resp: Value = serde_json::from_str(&resp).unwrap();
+            assert_eq!("1", resp["block"]["header"]["height"].as_str().unwrap());
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_cheqd_pool")]
+        fn test_latest_block_results() {
+            let setup = cheqd_setup::CheqdSetup::new();
+            let resp = cheqd_pool::block_results(&setup.pool_alias, -1).unwrap();
+            println!("Block results response: {:?}", resp);
+        }
+
+        #[test]
+        fn test_block_for_invalid_height() {
+            let res = cheqd_pool::block("test_pool", -2);
+            assert_code!(ErrorCode::CommonInvalidParam3, res);
+
+            let res = cheqd_pool::block_results("test_pool", -2);
+            assert_code!(ErrorCode::CommonInvalidParam3, res);
+        }
+    }
+
+    #[cfg(test)]
+    mod validators {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_cheqd_pool")]
+        fn test_validators() {
+            let setup = cheqd_setup::CheqdSetup::new();
+            let resp = cheqd_pool::validators(&setup.pool_alias, -1, -1, -1).unwrap();
+            println!("Validators response: {:?}", resp);
+
+            let resp: Value = serde_json::from_str(&resp).unwrap();
+            assert!(!resp["validators"].as_array().unwrap().is_empty());
+        }
+
+        #[test]
+        fn test_validators_for_invalid_params() {
+            let res = cheqd_pool::validators("test_pool", -2, -1, -1);
+            assert_code!(ErrorCode::CommonInvalidParam3, res);
+
+            let res = cheqd_pool::validators("test_pool", -1, -2, -1);
+            assert_code!(ErrorCode::CommonInvalidParam4, res);
+
+            let res = cheqd_pool::validators("test_pool", -1, -1, -2);
+            assert_code!(ErrorCode::CommonInvalidParam5, res);
+        }
+    }
+
+    #[cfg(test)]
+    mod net_info {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_cheqd_pool")]
+        fn test_net_info() {
+            let setup = cheqd_setup::CheqdSetup::new();
+            let resp = cheqd_pool::net_info(&setup.pool_alias).unwrap();
+            println!("Net info response: {:?}", resp);
+        }
+    }
 }
@@ -266,6 +266,76 @@ mod high_cases {
         }
     }
 
+    mod resolve_did {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_resolve_did_works() {
+            let setup = Setup::new_identity();
+
+            let attrib_data = json!({
+                "endpoint": {
+                    "endpoint": "https://agent.example.com",
+                    "types": ["endpoint", "did-communication", "DIDComm"]
+                }
+            }).to_string();
+            let attrib_request = ledger::build_attrib_request(
+                &setup.did,
+                &setup.did,
+                None,
+                Some(&attrib_data),
+                None,
+            )
+            .unwrap();
+            ledger::sign_and_submit_request(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &attrib_request,
+            )
+            .unwrap();
+
+            thread::sleep(std::time::Duration::from_secs(1));
+
+            let resolution_result = did::resolve_did(setup.pool_handle, &setup.did).unwrap();
+            let resolution_result: serde_json::Value = serde_json::from_str(&resolution_result).unwrap();
+
+            let did_doc = &resolution_result["didDocument"];
+            let id = format!("did:sov:{}", setup.did);
+            assert_eq!(json!(id), did_doc["id"]);
+            assert_eq!(json!(setup.verkey), did_doc["verificationMethod"][0]["publicKeyBase58"]);
+            assert_eq!(json!([format!("{}#verkey", id)]), did_doc["authentication"]);
+            assert_eq!(3, did_doc["service"].as_array().unwrap().len());
+            assert_eq!(1, did_doc["keyAgreement"].as_array().unwrap().len());
+
+            let metadata = &resolution_result["didDocumentMetadata"];
+            assert!(metadata["nym"]["seqNo"].is_u64());
+            assert!(metadata["endpoint"]["seqNo"].is_u64());
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_resolve_did_works_for_did_without_endpoint() {
+            let setup = Setup::new_identity();
+
+            let resolution_result = did::resolve_did(setup.pool_handle, &setup.did).unwrap();
+            let resolution_result: serde_json::Value = serde_json::from_str(&resolution_result).unwrap();
+
+            assert!(resolution_result["didDocument"]["service"].is_null());
+            assert!(resolution_result["didDocumentMetadata"]["endpoint"].is_null());
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_resolve_did_works_for_unknown_did() {
+            let setup = Setup::pool();
+
+            let res = did::resolve_did(setup.pool_handle, DID_MY1);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+    }
+
     mod set_did_metadata {
         use super::*;
 
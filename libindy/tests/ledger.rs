@@ -251,6 +251,87 @@ mod high_cases {
         }
     }
 
+    mod submit_requests {
+        use super::*;
+
+        #[cfg(feature = "local_nodes_pool")]
+        fn _nym_requests(setup: &Setup, count: usize) -> Vec<serde_json::Value> {
+            (0..count)
+                .map(|_| {
+                    let (did, verkey) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+                    let request =
+                        ledger::build_nym_request(&setup.did, &did, Some(&verkey), None, None).unwrap();
+                    let request = ledger::sign_request(setup.wallet_handle, &setup.did, &request).unwrap();
+                    serde_json::from_str(&request).unwrap()
+                })
+                .collect()
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_requests_works() {
+            let setup = Setup::trustee();
+
+            let requests = _nym_requests(&setup, 3);
+
+            let results = ledger::submit_requests(
+                setup.pool_handle,
+                &json!(requests).to_string(),
+                Some(r#"{"max_concurrency": 2}"#),
+            )
+            .unwrap();
+            let results: Vec<serde_json::Value> = serde_json::from_str(&results).unwrap();
+
+            assert_eq!(3, results.len());
+
+            for (request, result) in requests.iter().zip(results.iter()) {
+                assert_eq!("reply", result["status"].as_str().unwrap());
+                assert_eq!(request["reqId"], result["reply"]["result"]["txn"]["metadata"]["reqId"]);
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_requests_works_for_stop_on_failure() {
+            let setup = Setup::trustee();
+
+            let mut requests = _nym_requests(&setup, 3);
+            requests[0]["signature"] = json!("invalid");
+
+            let results = ledger::submit_requests(
+                setup.pool_handle,
+                &json!(requests).to_string(),
+                Some(r#"{"max_concurrency": 1, "stop_on_failure": true}"#),
+            )
+            .unwrap();
+            let results: Vec<serde_json::Value> = serde_json::from_str(&results).unwrap();
+
+            assert_eq!("rejected", results[0]["status"].as_str().unwrap());
+            assert_eq!(json!({"status": "skipped"}), results[1]);
+            assert_eq!(json!({"status": "skipped"}), results[2]);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_requests_works_for_failure_without_stop() {
+            let setup = Setup::trustee();
+
+            let mut requests = _nym_requests(&setup, 2);
+            requests[0]["signature"] = json!("invalid");
+
+            let results = ledger::submit_requests(
+                setup.pool_handle,
+                &json!(requests).to_string(),
+                None,
+            )
+            .unwrap();
+            let results: Vec<serde_json::Value> = serde_json::from_str(&results).unwrap();
+
+            assert_eq!("rejected", results[0]["status"].as_str().unwrap());
+            assert_eq!("reply", results[1]["status"].as_str().unwrap());
+        }
+    }
+
     mod sign_request {
         use super::*;
 
@@ -1078,6 +1159,42 @@ mod high_cases {
                 serde_json::from_str::<Reply<GetValidatorInfoResult>>(value).unwrap();
             }
         }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_parse_get_validator_info_response_works() {
+            let setup = Setup::trustee();
+
+            let get_validator_info_request =
+                ledger::build_get_validator_info_request(&setup.did).unwrap();
+
+            let get_validator_info_response = ledger::sign_and_submit_request(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &get_validator_info_request,
+            )
+            .unwrap();
+
+            let validator_info =
+                ledger::parse_get_validator_info_response(&get_validator_info_response).unwrap();
+            let validator_info: serde_json::Value = serde_json::from_str(&validator_info).unwrap();
+
+            assert_eq!(4, validator_info["nodes"].as_array().unwrap().len());
+            assert_eq!(4, validator_info["summary"]["replied_nodes"].as_array().unwrap().len());
+            assert!(validator_info["nodes"][0]["indy_node_version"].is_string());
+        }
+
+        #[test]
+        fn indy_parse_get_validator_info_response_works_for_timeout() {
+            let response = json!({"Node1": "timeout"}).to_string();
+
+            let validator_info = ledger::parse_get_validator_info_response(&response).unwrap();
+            let validator_info: serde_json::Value = serde_json::from_str(&validator_info).unwrap();
+
+            assert_eq!("timeout", validator_info["nodes"][0]["status"].as_str().unwrap());
+            assert_eq!(json!(["Node1"]), validator_info["summary"]["timeout_nodes"]);
+        }
     }
 
     mod get_txn_requests {
@@ -1190,6 +1307,42 @@ mod high_cases {
         }
     }
 
+    mod download_ledger {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_download_ledger_works() {
+            let setup = Setup::pool();
+
+            let status = ledger::download_ledger(setup.pool_handle, "POOL", None).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+
+            assert_eq!(json!(0), status["ledgerId"]);
+            assert_eq!(status["ledgerSize"], status["txnsCount"]);
+            assert!(status["txnsCount"].as_u64().unwrap() >= 4);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_download_ledger_works_for_resume() {
+            let setup = Setup::pool();
+
+            let status = ledger::download_ledger(
+                setup.pool_handle,
+                "POOL",
+                Some(r#"{"batch_size": 1, "to": 2}"#),
+            )
+            .unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(json!(2), status["txnsCount"]);
+
+            let status = ledger::download_ledger(setup.pool_handle, "POOL", None).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(status["ledgerSize"], status["txnsCount"]);
+        }
+    }
+
     mod pool_config {
         use super::*;
 
@@ -1822,27 +1975,35 @@ mod high_cases {
         }
     }
 
-    // FIXME: [async] re-check if needed
-    //    mod indy_register_transaction_parser_for_sp {
-    //        extern crate libc;
-    //
-    //        use super::*;
-    //
-    //        use self::libc::c_char;
-    //
-    //        #[test]
-    //        fn indy_register_transaction_parser_for_sp_works() {
-    //            Setup::empty();
-    //
-    //            extern fn parse(msg: *const c_char, parsed: *mut *const c_char) -> i32 {
-    //                unsafe { *parsed = msg; }
-    //                ErrorCode::Success as i32
-    //            }
-    //            extern fn free(_buf: *const c_char) -> i32 { ErrorCode::Success as i32 }
-    //
-    //            ledger::register_transaction_parser_for_sp("my_txn_type", parse, free).unwrap();
-    //        }
-    //    }
+    mod indy_register_transaction_parser_for_sp {
+        use super::*;
+
+        use libc::c_char;
+
+        extern "C" fn parse(_msg: *const c_char, parsed: *mut *const c_char) -> i32 {
+            unsafe { *parsed = b"[]\0".as_ptr() as *const c_char; }
+            ErrorCode::Success as i32
+        }
+
+        extern "C" fn free(_buf: *const c_char) -> i32 {
+            ErrorCode::Success as i32
+        }
+
+        #[test]
+        fn indy_register_transaction_parser_for_sp_works() {
+            Setup::empty();
+
+            utils::ledger::register_transaction_parser_for_sp("my_txn_type", parse, free).unwrap();
+        }
+
+        #[test]
+        fn indy_register_transaction_parser_for_sp_works_for_builtin_type() {
+            Setup::empty();
+
+            let res = utils::ledger::register_transaction_parser_for_sp(constants::GET_NYM, parse, free);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
 
     mod get_response_metadata {
         use super::*;
@@ -3009,6 +3170,160 @@ mod high_cases {
                 &default_constraint_action_2,
             );
         }
+
+        fn _get_auth_rule_response(constraint: serde_json::Value) -> String {
+            json!({
+                "op": "REPLY",
+                "result": {
+                    "type": constants::GET_AUTH_RULE,
+                    "data": [{
+                        "auth_type": constants::NYM,
+                        "auth_action": ADD_AUTH_ACTION,
+                        "field": FIELD,
+                        "old_value": null,
+                        "new_value": NEW_VALUE,
+                        "constraint": constraint,
+                    }],
+                },
+            })
+            .to_string()
+        }
+
+        fn _add_endorser_action() -> String {
+            json!({
+                "auth_type": "NYM",
+                "auth_action": ADD_AUTH_ACTION,
+                "field": FIELD,
+                "new_value": NEW_VALUE,
+            })
+            .to_string()
+        }
+
+        fn _signed_request(signers: &[&str]) -> String {
+            let mut request: serde_json::Value = serde_json::from_str(
+                &ledger::build_nym_request(DID_TRUSTEE, DID_MY1, None, None, Some("ENDORSER"))
+                    .unwrap(),
+            )
+            .unwrap();
+
+            request["signatures"] = signers
+                .iter()
+                .map(|did| (did.to_string(), json!("signature")))
+                .collect::<serde_json::Map<String, serde_json::Value>>()
+                .into();
+
+            request.to_string()
+        }
+
+        #[test]
+        fn indy_evaluate_auth_rule_works() {
+            let response = _get_auth_rule_response(json!({
+                "constraint_id": "OR",
+                "auth_constraints": [
+                    {"constraint_id": "ROLE", "role": "0", "sig_count": 1, "need_to_be_owner": false},
+                    {"constraint_id": "ROLE", "role": "2", "sig_count": 2, "need_to_be_owner": false},
+                ],
+            }));
+
+            let signers = json!([{"did": DID_TRUSTEE, "verkey": null, "role": "0"}]).to_string();
+
+            let evaluation = ledger::evaluate_auth_rule(
+                &response,
+                &_add_endorser_action(),
+                &_signed_request(&[DID_TRUSTEE]),
+                &signers,
+            )
+            .unwrap();
+
+            let evaluation: serde_json::Value = serde_json::from_str(&evaluation).unwrap();
+
+            assert_eq!(
+                json!({
+                    "satisfied": true,
+                    "forbidden": false,
+                    "missing_signatures": [],
+                    "missing_endorsement": false,
+                }),
+                evaluation
+            );
+        }
+
+        #[test]
+        fn indy_evaluate_auth_rule_works_for_missing_signatures() {
+            let response = _get_auth_rule_response(json!({
+                "constraint_id": "AND",
+                "auth_constraints": [
+                    {"constraint_id": "ROLE", "role": "0", "sig_count": 1, "need_to_be_owner": false},
+                    {"constraint_id": "ROLE", "role": "2", "sig_count": 2, "need_to_be_owner": false},
+                ],
+            }));
+
+            let signers = json!([{"did": DID_TRUSTEE, "verkey": null, "role": "0"}]).to_string();
+
+            let evaluation = ledger::evaluate_auth_rule(
+                &response,
+                &_add_endorser_action(),
+                &_signed_request(&[DID_TRUSTEE]),
+                &signers,
+            )
+            .unwrap();
+
+            let evaluation: serde_json::Value = serde_json::from_str(&evaluation).unwrap();
+
+            assert_eq!(false, evaluation["satisfied"]);
+            assert_eq!(
+                json!([{"role": "2", "sig_count": 2, "need_to_be_owner": false, "off_ledger_signature": false}]),
+                evaluation["missing_signatures"]
+            );
+        }
+
+        #[test]
+        fn indy_evaluate_auth_rule_works_for_unknown_action() {
+            let response = _get_auth_rule_response(json!({
+                "constraint_id": "ROLE", "role": "0", "sig_count": 1, "need_to_be_owner": false
+            }));
+
+            let action = json!({
+                "auth_type": "NYM",
+                "auth_action": EDIT_AUTH_ACTION,
+                "field": FIELD,
+                "old_value": VALUE,
+                "new_value": NEW_VALUE,
+            })
+            .to_string();
+
+            let res = ledger::evaluate_auth_rule(
+                &response,
+                &action,
+                &_signed_request(&[DID_TRUSTEE]),
+                "[]",
+            );
+
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_evaluate_auth_rule_works_for_invalid_action() {
+            let response = _get_auth_rule_response(json!({
+                "constraint_id": "ROLE", "role": "0", "sig_count": 1, "need_to_be_owner": false
+            }));
+
+            let action = json!({
+                "auth_type": "UNKNOWN",
+                "auth_action": ADD_AUTH_ACTION,
+                "field": FIELD,
+            })
+            .to_string();
+
+            let res = ledger::evaluate_auth_rule(
+                &response,
+                &action,
+                &_signed_request(&[DID_TRUSTEE]),
+                "[]",
+            );
+
+            assert_code!(ErrorCode::CommonInvalidParam3, res);
+        }
     }
 
     mod author_agreement {
@@ -3676,6 +3991,96 @@ mod high_cases {
             _disable_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
         }
 
+        #[test]
+        fn indy_pool_author_agreement_acceptance_works() {
+            let setup = Setup::trustee();
+
+            let (_, aml_label, _, _) = _set_aml(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            let (taa_text, taa_version, _, _) =
+                _set_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            ledger::set_pool_txn_author_agreement_acceptance(
+                setup.pool_handle,
+                Some(&taa_text),
+                Some(&taa_version),
+                None,
+                &aml_label,
+                time::get_time().sec as u64,
+            )
+            .unwrap();
+
+            let (did_, verkey_) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+
+            let nym_req =
+                ledger::build_nym_request(&setup.did, &did_, Some(&verkey_), None, None).unwrap();
+
+            let nym_resp = ledger::sign_and_submit_request(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &nym_req,
+            )
+            .unwrap();
+
+            pool::check_response_type(&nym_resp, ResponseType::REPLY);
+
+            _disable_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+        }
+
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_pool_author_agreement_acceptance_works_for_changed_taa() {
+            let setup = Setup::trustee();
+
+            let (_, aml_label, _, _) = _set_aml(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            let (_, _, taa_digest, _) = _set_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            ledger::set_pool_txn_author_agreement_acceptance(
+                setup.pool_handle,
+                None,
+                None,
+                Some(&taa_digest),
+                &aml_label,
+                time::get_time().sec as u64,
+            )
+            .unwrap();
+
+            _set_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            let (did_, verkey_) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+
+            let nym_req =
+                ledger::build_nym_request(&setup.did, &did_, Some(&verkey_), None, None).unwrap();
+
+            let res = ledger::sign_and_submit_request(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &nym_req,
+            );
+            assert_code!(ErrorCode::LedgerTxnAuthorAgreementChanged, res);
+
+            _disable_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+        }
+
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_pool_author_agreement_acceptance_works_for_invalid_pool_handle() {
+            Setup::empty();
+
+            let res = ledger::set_pool_txn_author_agreement_acceptance(
+                INVALID_POOL_HANDLE,
+                Some(TEXT),
+                Some(VERSION),
+                None,
+                ACCEPTANCE_MECH_TYPE,
+                TIME_OF_ACCEPTANCE,
+            );
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
         #[test]
         #[cfg(not(feature = "only_high_cases"))]
         fn indy_reset_author_agreement_works() {
@@ -4232,7 +4637,307 @@ mod high_cases {
             pool::check_response_type(&response, ResponseType::REQNACK);
         }
     }
-}
+
+    mod endorsement_envelope {
+        use super::*;
+
+        #[test]
+        fn indy_build_endorsement_envelope_works() {
+            let setup = Setup::did();
+
+            let envelope = ledger::build_endorsement_envelope(
+                setup.wallet_handle,
+                REQUEST,
+                DID_TRUSTEE,
+                Some(&json!({ "required_signers": [DID_MY1] }).to_string()),
+            )
+            .unwrap();
+            let envelope: serde_json::Value = serde_json::from_str(&envelope).unwrap();
+
+            let request: serde_json::Value = serde_json::from_str(REQUEST).unwrap();
+            let identifier = request["identifier"].as_str().unwrap();
+
+            assert_eq!(
+                format!("{}:{}", identifier, request["reqId"]),
+                envelope["id"].as_str().unwrap()
+            );
+            assert_eq!(DID_TRUSTEE, envelope["request"]["endorser"].as_str().unwrap());
+            assert_eq!(
+                json!([identifier, DID_TRUSTEE, DID_MY1]),
+                envelope["required_signers"]
+            );
+
+            let pending = ledger::list_pending_endorsements(setup.wallet_handle).unwrap();
+            let pending: Vec<serde_json::Value> = serde_json::from_str(&pending).unwrap();
+            assert_eq!(vec![envelope], pending);
+        }
+
+        #[test]
+        fn indy_build_endorsement_envelope_works_for_auth_rule() {
+            let setup = Setup::did();
+
+            let request =
+                ledger::build_nym_request(&setup.did, DID_MY1, None, None, Some("ENDORSER"))
+                    .unwrap();
+
+            let auth_rule = json!({
+                "get_auth_rule_response": json!({
+                    "op": "REPLY",
+                    "result": {
+                        "type": constants::GET_AUTH_RULE,
+                        "data": [{
+                            "auth_type": constants::NYM,
+                            "auth_action": "ADD",
+                            "field": "role",
+                            "old_value": null,
+                            "new_value": "101",
+                            "constraint": {"constraint_id": "ROLE", "role": "0", "sig_count": 1, "need_to_be_owner": false},
+                        }],
+                    },
+                })
+                .to_string(),
+                "action": {"auth_type": "NYM", "auth_action": "ADD", "field": "role", "new_value": "101"},
+                "signers": [
+                    {"did": DID_MY2, "verkey": null, "role": "101"},
+                    {"did": DID_TRUSTEE, "verkey": null, "role": "0"},
+                ],
+            });
+
+            let envelope = ledger::build_endorsement_envelope(
+                setup.wallet_handle,
+                &request,
+                DID_MY2,
+                Some(&json!({ "auth_rule": auth_rule }).to_string()),
+            )
+            .unwrap();
+            let envelope: serde_json::Value = serde_json::from_str(&envelope).unwrap();
+
+            assert_eq!(
+                json!([setup.did, DID_MY2, DID_TRUSTEE]),
+                envelope["required_signers"]
+            );
+        }
+
+        #[test]
+        fn indy_sign_endorsement_envelope_works() {
+            let setup = Setup::did();
+
+            let request = ledger::build_nym_request(&setup.did, DID_MY1, None, None, None).unwrap();
+
+            let envelope = ledger::build_endorsement_envelope(
+                setup.wallet_handle,
+                &request,
+                DID_TRUSTEE,
+                Some(&json!({ "required_signers": [setup.did] }).to_string()),
+            )
+            .unwrap();
+
+            let status = ledger::check_endorsement_envelope(&envelope).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(false, status["complete"].as_bool().unwrap());
+            assert_eq!(json!([setup.did, DID_TRUSTEE]), status["missing_signers"]);
+
+            let envelope =
+                ledger::sign_endorsement_envelope(setup.wallet_handle, &envelope, &setup.did)
+                    .unwrap();
+
+            let status = ledger::check_endorsement_envelope(&envelope).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(false, status["complete"].as_bool().unwrap());
+            assert_eq!(json!([DID_TRUSTEE]), status["missing_signers"]);
+
+            let pending = ledger::list_pending_endorsements(setup.wallet_handle).unwrap();
+            let pending: Vec<serde_json::Value> = serde_json::from_str(&pending).unwrap();
+            assert_eq!(
+                vec![serde_json::from_str::<serde_json::Value>(&envelope).unwrap()],
+                pending
+            );
+        }
+
+        #[test]
+        fn indy_delete_pending_endorsement_works() {
+            let setup = Setup::did();
+
+            let envelope =
+                ledger::build_endorsement_envelope(setup.wallet_handle, REQUEST, DID_TRUSTEE, None)
+                    .unwrap();
+            let envelope: serde_json::Value = serde_json::from_str(&envelope).unwrap();
+
+            ledger::delete_pending_endorsement(
+                setup.wallet_handle,
+                envelope["id"].as_str().unwrap(),
+            )
+            .unwrap();
+
+            let pending = ledger::list_pending_endorsements(setup.wallet_handle).unwrap();
+            assert_eq!("[]", pending);
+        }
+
+        #[test]
+        fn indy_submit_endorsement_envelope_works() {
+            let setup = Setup::endorser();
+
+            let (author_did, _) = did::create_store_and_publish_did(
+                setup.wallet_handle,
+                setup.pool_handle,
+                "TRUSTEE",
+                None,
+            )
+            .unwrap();
+
+            let request = ledger::build_schema_request(&author_did, SCHEMA_DATA).unwrap();
+
+            let envelope = ledger::build_endorsement_envelope(
+                setup.wallet_handle,
+                &request,
+                &setup.did,
+                Some(&json!({ "required_signers": [author_did] }).to_string()),
+            )
+            .unwrap();
+
+            let envelope =
+                ledger::sign_endorsement_envelope(setup.wallet_handle, &envelope, &author_did)
+                    .unwrap();
+            let envelope =
+                ledger::sign_endorsement_envelope(setup.wallet_handle, &envelope, &setup.did)
+                    .unwrap();
+
+            let status = ledger::check_endorsement_envelope(&envelope).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(true, status["complete"].as_bool().unwrap());
+
+            let response = ledger::submit_endorsement_envelope(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &envelope,
+            )
+            .unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+
+            let pending = ledger::list_pending_endorsements(setup.wallet_handle).unwrap();
+            assert_eq!("[]", pending);
+        }
+    }
+
+    mod ledgers_freeze {
+        use super::*;
+
+        #[test]
+        fn indy_build_ledgers_freeze_request() {
+            let expected_result = json!({
+                "type": constants::LEDGERS_FREEZE,
+                "ledgers_ids": [0, 1, 28],
+            });
+
+            let request = ledger::build_ledgers_freeze_request(IDENTIFIER, "[0, 1, 28]").unwrap();
+            check_request(&request, expected_result, IDENTIFIER);
+        }
+
+        #[test]
+        fn indy_build_get_frozen_ledgers_request() {
+            let expected_result = json!({
+                "type": constants::GET_FROZEN_LEDGERS,
+            });
+
+            let request = ledger::build_get_frozen_ledgers_request(Some(IDENTIFIER)).unwrap();
+            check_request(&request, expected_result, IDENTIFIER);
+        }
+
+        #[test]
+        fn indy_build_get_frozen_ledgers_request_for_default_submitter_did() {
+            let request = ledger::build_get_frozen_ledgers_request(None).unwrap();
+            check_default_identifier(&request);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_frozen_ledgers_request_works() {
+            let setup = Setup::pool();
+
+            let request = ledger::build_get_frozen_ledgers_request(None).unwrap();
+            let response = ledger::submit_request(setup.pool_handle, &request).unwrap();
+
+            let frozen_ledgers = ledger::parse_get_frozen_ledgers_response(&response).unwrap();
+            let _frozen_ledgers: serde_json::Map<String, serde_json::Value> =
+                serde_json::from_str(&frozen_ledgers).unwrap();
+        }
+    }
+
+    mod rich_schema {
+        use super::*;
+
+        const RS_ID: &str = "did:sov:8a9eb2T5x9SzFyRoWXRgUh";
+        const RS_NAME: &str = "SimpleRichSchema";
+        const RS_VERSION: &str = "1.0";
+
+        fn rs_content() -> String {
+            json!({"@id": RS_ID, "@type": "rdfs:Class"}).to_string()
+        }
+
+        #[test]
+        fn indy_build_rich_schema_request() {
+            let expected_result = json!({
+                "type": constants::SET_RICH_SCHEMA,
+                "id": RS_ID,
+                "content": rs_content(),
+                "rsName": RS_NAME,
+                "rsVersion": RS_VERSION,
+                "rsType": "sch",
+                "ver": "1",
+            });
+
+            let request = ledger::build_rich_schema_request(
+                IDENTIFIER,
+                RS_ID,
+                &rs_content(),
+                RS_NAME,
+                RS_VERSION,
+                "sch",
+                "1",
+            )
+            .unwrap();
+            check_request(&request, expected_result, IDENTIFIER);
+        }
+
+        #[test]
+        fn indy_build_get_rich_schema_object_by_id_request() {
+            let expected_result = json!({
+                "type": constants::GET_RICH_SCHEMA_OBJECT_BY_ID,
+                "id": RS_ID,
+            });
+
+            let request =
+                ledger::build_get_rich_schema_object_by_id_request(Some(IDENTIFIER), RS_ID)
+                    .unwrap();
+            check_request(&request, expected_result, IDENTIFIER);
+        }
+
+        #[test]
+        fn indy_build_get_rich_schema_object_by_metadata_request() {
+            let expected_result = json!({
+                "type": constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA,
+                "rsType": "sch",
+                "rsName": RS_NAME,
+                "rsVersion": RS_VERSION,
+            });
+
+            let request = ledger::build_get_rich_schema_object_by_metadata_request(
+                Some(IDENTIFIER),
+                "sch",
+                RS_NAME,
+                RS_VERSION,
+            )
+            .unwrap();
+            check_request(&request, expected_result, IDENTIFIER);
+        }
+
+        #[test]
+        fn indy_build_get_rich_schema_object_by_id_request_for_default_submitter_did() {
+            let request = ledger::build_get_rich_schema_object_by_id_request(None, RS_ID).unwrap();
+            check_default_identifier(&request);
+        }
+    }
+}
 
 #[cfg(not(feature = "only_high_cases"))]
 mod medium_cases {
@@ -4286,6 +4991,158 @@ mod medium_cases {
         }
     }
 
+    mod submit_requests {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_requests_works_for_invalid_options() {
+            let setup = Setup::pool();
+
+            let res = ledger::submit_requests(setup.pool_handle, "[]", Some(r#"{"max_concurrency": 0}"#));
+            assert_code!(ErrorCode::CommonInvalidParam4, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_requests_works_for_invalid_requests() {
+            let setup = Setup::pool();
+
+            let res = ledger::submit_requests(setup.pool_handle, r#"{"reqId": 1}"#, None);
+            assert_code!(ErrorCode::CommonInvalidParam3, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_requests_works_for_empty_batch() {
+            let setup = Setup::pool();
+
+            let results = ledger::submit_requests(setup.pool_handle, "[]", None).unwrap();
+            assert_eq!("[]", results);
+        }
+    }
+
+    mod endorsement_envelope {
+        use super::*;
+
+        #[test]
+        fn indy_build_endorsement_envelope_works_for_request_without_req_id() {
+            let setup = Setup::did();
+
+            let res = ledger::build_endorsement_envelope(
+                setup.wallet_handle,
+                &json!({ "identifier": setup.did, "operation": {} }).to_string(),
+                DID_TRUSTEE,
+                None,
+            );
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_endorsement_envelope_works_for_invalid_endorser() {
+            let setup = Setup::did();
+
+            let res = ledger::build_endorsement_envelope(
+                setup.wallet_handle,
+                REQUEST,
+                INVALID_IDENTIFIER,
+                None,
+            );
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_endorsement_envelope_works_for_removing_signatures() {
+            let setup = Setup::did();
+
+            let request = ledger::build_nym_request(&setup.did, DID_MY1, None, None, None).unwrap();
+            let request = ledger::sign_request(setup.wallet_handle, &setup.did, &request).unwrap();
+
+            let envelope =
+                ledger::build_endorsement_envelope(setup.wallet_handle, &request, DID_TRUSTEE, None)
+                    .unwrap();
+            let envelope: serde_json::Value = serde_json::from_str(&envelope).unwrap();
+
+            assert!(envelope["request"]["signature"].is_null());
+            assert!(envelope["request"]["signatures"].is_null());
+        }
+
+        #[test]
+        fn indy_build_endorsement_envelope_works_for_undetermined_signers() {
+            let setup = Setup::did();
+
+            let envelope =
+                ledger::build_endorsement_envelope(setup.wallet_handle, REQUEST, DID_TRUSTEE, None)
+                    .unwrap();
+            let parsed: serde_json::Value = serde_json::from_str(&envelope).unwrap();
+            assert!(parsed["required_signers"].is_null());
+
+            let status = ledger::check_endorsement_envelope(&envelope).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(false, status["complete"].as_bool().unwrap());
+            assert_eq!(false, status["required_signers_determined"].as_bool().unwrap());
+        }
+
+        #[test]
+        fn indy_sign_endorsement_envelope_works_for_unknown_signer() {
+            let setup = Setup::did();
+
+            let envelope =
+                ledger::build_endorsement_envelope(setup.wallet_handle, REQUEST, DID_TRUSTEE, None)
+                    .unwrap();
+
+            let res = ledger::sign_endorsement_envelope(setup.wallet_handle, &envelope, DID_MY1);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_check_endorsement_envelope_works_for_modified_endorser() {
+            let setup = Setup::did();
+
+            let envelope =
+                ledger::build_endorsement_envelope(setup.wallet_handle, REQUEST, DID_TRUSTEE, None)
+                    .unwrap();
+            let mut envelope: serde_json::Value = serde_json::from_str(&envelope).unwrap();
+            envelope["endorser"] = json!(DID_MY1);
+
+            let res = ledger::check_endorsement_envelope(&envelope.to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_delete_pending_endorsement_works_for_unknown_id() {
+            let setup = Setup::did();
+
+            ledger::delete_pending_endorsement(setup.wallet_handle, "unknown:1").unwrap();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_endorsement_envelope_works_for_missing_signatures() {
+            let setup = Setup::endorser();
+
+            let request = ledger::build_schema_request(&setup.did, SCHEMA_DATA).unwrap();
+
+            let envelope = ledger::build_endorsement_envelope(
+                setup.wallet_handle,
+                &request,
+                DID_TRUSTEE,
+                Some(&json!({ "required_signers": [setup.did] }).to_string()),
+            )
+            .unwrap();
+            let envelope =
+                ledger::sign_endorsement_envelope(setup.wallet_handle, &envelope, &setup.did)
+                    .unwrap();
+
+            let res = ledger::submit_endorsement_envelope(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &envelope,
+            );
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     mod submit_action {
         use super::*;
 
@@ -5441,6 +6298,26 @@ mod medium_cases {
         }
     }
 
+    mod download_ledger {
+        use super::*;
+
+        #[test]
+        fn indy_download_ledger_works_for_invalid_pool_handle() {
+            let res = ledger::download_ledger(INVALID_POOL_HANDLE, "DOMAIN", None);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
+        #[test]
+        fn indy_download_ledger_works_for_invalid_config() {
+            let res = ledger::download_ledger(
+                INVALID_POOL_HANDLE,
+                "DOMAIN",
+                Some(r#"{"batch_size": 0}"#),
+            );
+            assert_code!(ErrorCode::CommonInvalidParam4, res);
+        }
+    }
+
     mod get_txn_requests {
         use super::*;
 
@@ -5692,6 +6569,65 @@ mod medium_cases {
             assert!(response_metadata["lastSeqNo"].as_u64().is_none());
         }
     }
+
+    mod ledgers_freeze {
+        use super::*;
+
+        #[test]
+        fn indy_build_ledgers_freeze_request_works_for_invalid_ledgers_ids() {
+            let res = ledger::build_ledgers_freeze_request(IDENTIFIER, r#"["pool", "config"]"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_parse_get_frozen_ledgers_response_works_for_invalid_response() {
+            let res = ledger::parse_get_frozen_ledgers_response("{}");
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+    }
+
+    mod rich_schema {
+        use super::*;
+
+        #[test]
+        fn indy_build_rich_schema_request_works_for_invalid_rs_type() {
+            let res = ledger::build_rich_schema_request(
+                IDENTIFIER,
+                "did:sov:8a9eb2T5x9SzFyRoWXRgUh",
+                "{}",
+                "SimpleRichSchema",
+                "1.0",
+                "unknown",
+                "1",
+            );
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_rich_schema_request_works_for_invalid_content() {
+            let res = ledger::build_rich_schema_request(
+                IDENTIFIER,
+                "did:sov:8a9eb2T5x9SzFyRoWXRgUh",
+                "not a json",
+                "SimpleRichSchema",
+                "1.0",
+                "sch",
+                "1",
+            );
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_get_rich_schema_object_by_metadata_request_works_for_invalid_rs_type() {
+            let res = ledger::build_get_rich_schema_object_by_metadata_request(
+                None,
+                "unknown",
+                "SimpleRichSchema",
+                "1.0",
+            );
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
 }
 
 fn check_request(request: &str, expected_operation: serde_json::Value, expected_identifier: &str) {
@@ -245,6 +245,65 @@ mod high_cases {
         }
     }
 
+    mod wallet_batch {
+        use super::*;
+
+        #[test]
+        fn indy_wallet_batch_works() {
+            let setup = Setup::wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, Some(TAGS)).unwrap();
+            add_wallet_record(setup.wallet_handle, TYPE, ID_3, VALUE_3, None).unwrap();
+
+            let operations = json!([
+                {"op": "add", "type": TYPE, "id": ID_2, "value": VALUE_2, "tags": tags_2()},
+                {"op": "update_value", "type": TYPE, "id": ID, "value": VALUE_3},
+                {"op": "delete_tags", "type": TYPE, "id": ID, "tag_names": ["tagName1"]},
+                {"op": "delete", "type": TYPE, "id": ID_3},
+            ])
+            .to_string();
+
+            wallet_batch(setup.wallet_handle, &operations).unwrap();
+
+            check_record_field(setup.wallet_handle, TYPE, ID, "value", VALUE_3);
+            check_record_field(
+                setup.wallet_handle,
+                TYPE,
+                ID,
+                "tags",
+                r#"{"~tagName2":"5","~tagName3":"8"}"#,
+            );
+            check_record_field(setup.wallet_handle, TYPE, ID_2, "value", VALUE_2);
+            check_record_field(setup.wallet_handle, TYPE, ID_2, "tags", TAGS_2);
+
+            let res = get_wallet_record(setup.wallet_handle, TYPE, ID_3, OPTIONS_EMPTY);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_wallet_batch_works_for_rollback() {
+            let setup = Setup::wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, Some(TAGS)).unwrap();
+
+            let operations = json!([
+                {"op": "add", "type": TYPE, "id": ID_2, "value": VALUE_2},
+                {"op": "update_tags", "type": TYPE, "id": ID, "tags": tags_2()},
+                {"op": "update_value", "type": TYPE, "id": ID_3, "value": VALUE_3},
+            ])
+            .to_string();
+
+            let res = wallet_batch(setup.wallet_handle, &operations);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            check_record_field(setup.wallet_handle, TYPE, ID, "value", VALUE);
+            check_record_field(setup.wallet_handle, TYPE, ID, "tags", TAGS);
+
+            let res = get_wallet_record(setup.wallet_handle, TYPE, ID_2, OPTIONS_EMPTY);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+    }
+
     mod get_record {
         use super::*;
 
@@ -1326,6 +1385,62 @@ mod medium_cases {
         }
     }
 
+    mod wallet_batch {
+        use super::*;
+
+        #[test]
+        fn indy_wallet_batch_works_for_already_existing_record() {
+            let setup = Setup::wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
+
+            let operations = json!([
+                {"op": "update_value", "type": TYPE, "id": ID, "value": VALUE_2},
+                {"op": "add", "type": TYPE, "id": ID, "value": VALUE_3},
+            ])
+            .to_string();
+
+            let res = wallet_batch(setup.wallet_handle, &operations);
+            assert_code!(ErrorCode::WalletItemAlreadyExists, res);
+
+            check_record_field(setup.wallet_handle, TYPE, ID, "value", VALUE);
+        }
+
+        #[test]
+        fn indy_wallet_batch_works_for_invalid_operations() {
+            let setup = Setup::wallet();
+
+            let operations = json!([
+                {"op": "unknown", "type": TYPE, "id": ID},
+            ])
+            .to_string();
+
+            let res = wallet_batch(setup.wallet_handle, &operations);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_wallet_batch_works_for_invalid_type() {
+            let setup = Setup::wallet();
+
+            let operations = json!([
+                {"op": "delete", "type": FORBIDDEN_TYPE, "id": ID},
+            ])
+            .to_string();
+
+            let res = wallet_batch(setup.wallet_handle, &operations);
+            assert_code!(ErrorCode::WalletAccessFailed, res);
+        }
+
+        #[test]
+        fn indy_wallet_batch_works_for_invalid_handle() {
+            Setup::empty();
+
+            let res = wallet_batch(INVALID_WALLET_HANDLE, "[]");
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+    }
+
     mod get_record {
         use super::*;
 
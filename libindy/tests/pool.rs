@@ -51,6 +51,110 @@ mod high_cases {
 
             let _ = fs::remove_file(txn_file_path);
         }
+
+        #[test]
+        fn create_pool_ledger_config_works_for_genesis_txn_data() {
+            let setup = Setup::empty();
+
+            let pool_config = json!({
+                "genesis_txn_data": pool::genesis_txns(4),
+            }).to_string();
+
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+        }
+
+        #[test]
+        fn create_pool_ledger_config_works_for_genesis_hash() {
+            let setup = Setup::empty();
+
+            let genesis_txns = pool::genesis_txns(4);
+            let pool_config = json!({
+                "genesis_txn_data": genesis_txns,
+                "genesis_hash": pool::genesis_hash(&genesis_txns),
+            }).to_string();
+
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+        }
+    }
+
+    mod update_genesis {
+        use super::*;
+
+        #[test]
+        fn update_pool_ledger_genesis_works() {
+            let setup = Setup::empty();
+
+            let txn_file_path =
+                pool::create_genesis_txn_file_for_test_pool(&setup.name, Some(2), None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let genesis_txns = pool::genesis_txns(4);
+            let config = json!({
+                "genesis_txn_data": genesis_txns,
+                "genesis_hash": pool::genesis_hash(&genesis_txns),
+            }).to_string();
+
+            pool::update_genesis(&setup.name, &config).unwrap();
+
+            assert_eq!(genesis_txns, pool::read_genesis_txns(&setup.name));
+
+            let snapshot = pool::export_snapshot(&setup.name).unwrap();
+            let snapshot: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+            assert_eq!(4, snapshot["ledger_size"].as_u64().unwrap());
+        }
+
+        #[test]
+        fn update_pool_ledger_genesis_works_for_pinned_genesis_hash() {
+            let setup = Setup::empty();
+
+            let genesis_txns = pool::genesis_txns(2);
+            let pool_config = json!({
+                "genesis_txn_data": genesis_txns,
+                "genesis_hash": pool::genesis_hash(&genesis_txns),
+            }).to_string();
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let config = json!({
+                "genesis_txn_data": pool::genesis_txns(4),
+            }).to_string();
+
+            let res = pool::update_genesis(&setup.name, &config);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            assert_eq!(genesis_txns, pool::read_genesis_txns(&setup.name));
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn update_pool_ledger_genesis_works_for_reopened() {
+            let setup = Setup::empty();
+
+            let pool_handle = pool::create_and_open_pool_ledger(&setup.name).unwrap();
+            pool::close(pool_handle).unwrap();
+
+            let genesis_txns = pool::genesis_txns(4);
+            let config = json!({
+                "genesis_txn_data": genesis_txns,
+            }).to_string();
+
+            pool::update_genesis(&setup.name, &config).unwrap();
+
+            assert_eq!(genesis_txns, pool::read_genesis_txns(&setup.name));
+
+            let pool_handle = pool::open_pool_ledger(&setup.name, None).unwrap();
+            pool::close(pool_handle).unwrap();
+
+            assert_eq!(genesis_txns, pool::read_genesis_txns(&setup.name));
+
+            let snapshot = pool::export_snapshot(&setup.name).unwrap();
+            let snapshot: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+            let genesis: Vec<serde_json::Value> = genesis_txns
+                .lines()
+                .map(|txn| serde_json::from_str(txn).unwrap())
+                .collect();
+            assert_eq!(genesis[..], snapshot["txns"].as_array().unwrap()[..genesis.len()]);
+        }
     }
 
     mod open {
@@ -110,6 +214,34 @@ mod high_cases {
             pool::open_pool_ledger(&setup.name, None).unwrap();
         }
 
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn open_pool_ledger_works_for_adaptive_read_nodes_selection() {
+            let setup = Setup::empty();
+
+            let config = r#"{"read_nodes_selection": "adaptive"}"#;
+
+            let txn_file_path =
+                pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let pool_handle = pool::open_pool_ledger(&setup.name, Some(config)).unwrap();
+
+            for seq_no in 1..4 {
+                let request = utils::ledger::build_get_txn_request(None, seq_no, None).unwrap();
+                utils::ledger::submit_request(pool_handle, &request).unwrap();
+            }
+
+            let status = pool::get_status(pool_handle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+
+            let nodes = status["nodes"].as_array().unwrap();
+            assert_eq!(2, nodes.iter().filter(|node| node["in_read_set"].as_bool().unwrap()).count());
+
+            pool::close(pool_handle).unwrap();
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         pub fn open_pool_ledger_works_for_cached_txns() {
@@ -136,6 +268,237 @@ mod high_cases {
         }
     }
 
+    mod get_status {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_pool_status_works() {
+            let setup = Setup::pool();
+
+            let status = pool::get_status(setup.pool_handle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+
+            assert_eq!(4, status["ledger_size"].as_u64().unwrap());
+            assert!(!status["merkle_root"].as_str().unwrap().is_empty());
+            assert_eq!(2, status["protocol_version"].as_u64().unwrap());
+
+            let nodes = status["nodes"].as_array().unwrap();
+            assert_eq!(4, nodes.len());
+            assert!(nodes.iter().all(|node| node["alias"].is_string() && node["address"].is_string()));
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_pool_status_works_after_read_request() {
+            let setup = Setup::pool();
+
+            let request = utils::ledger::build_get_txn_request(None, 1, None).unwrap();
+            utils::ledger::submit_request(setup.pool_handle, &request).unwrap();
+
+            let status = pool::get_status(setup.pool_handle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+
+            let nodes = status["nodes"].as_array().unwrap();
+            assert_eq!(2, nodes.iter().filter(|node| node["in_read_set"].as_bool().unwrap()).count());
+            assert!(nodes.iter().any(|node| node["latency"].is_number() && node["last_response_time"].is_number()));
+        }
+    }
+
+    mod verify_ledger_reply {
+        use super::*;
+
+        #[cfg(feature = "local_nodes_pool")]
+        fn _get_nym_request_and_reply(pool_handle: indyrs::PoolHandle) -> (String, String) {
+            let did = utils::constants::DID_TRUSTEE;
+            let request = utils::ledger::build_get_nym_request(Some(did), did).unwrap();
+            let reply = utils::ledger::submit_request(pool_handle, &request).unwrap();
+            (request, reply)
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_verify_ledger_reply_works() {
+            let setup = Setup::pool();
+
+            let (request, reply) = _get_nym_request_and_reply(setup.pool_handle);
+            pool::close(setup.pool_handle).unwrap();
+
+            let verification = pool::verify_ledger_reply(Some(&setup.name), None, &request, &reply).unwrap();
+            let verification: serde_json::Value = serde_json::from_str(&verification).unwrap();
+
+            assert!(verification["verified"].as_bool().unwrap());
+            assert_eq!(4, verification["nodesCount"].as_u64().unwrap());
+
+            let state_proofs = verification["stateProofs"].as_array().unwrap();
+            assert_eq!(1, state_proofs.len());
+            assert!(state_proofs[0]["multiSignatureValid"].as_bool().unwrap());
+            assert!(state_proofs[0]["proofValid"].as_bool().unwrap());
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_verify_ledger_reply_works_for_genesis_txns() {
+            let setup = Setup::pool();
+
+            let (request, reply) = _get_nym_request_and_reply(setup.pool_handle);
+            let genesis_txns = pool::genesis_txns(4);
+
+            let verification = pool::verify_ledger_reply(None, Some(&genesis_txns), &request, &reply).unwrap();
+            let verification: serde_json::Value = serde_json::from_str(&verification).unwrap();
+
+            assert!(verification["verified"].as_bool().unwrap());
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_verify_ledger_reply_works_for_tampered_signature() {
+            let setup = Setup::pool();
+
+            let (request, reply) = _get_nym_request_and_reply(setup.pool_handle);
+
+            let mut reply: serde_json::Value = serde_json::from_str(&reply).unwrap();
+            reply["result"]["state_proof"]["multi_signature"]["signature"] = json!("QuVs9dE1nHxkA5bXhfYGdwCx3q5ewjPnthwv4jw1r3Cnnw9YSQxbDHdVH8cQyXqzbQbZgVbTqjFdmwcmxBMyoYQWnySmgS5gRKUvu6qY95rBBBUfHQx2XKyDs3LEwa7yCUqNNDwfGEybRcTGUTZY4DNYCeGQw9QXNJxjn1A2ggA7oxi");
+
+            let verification = pool::verify_ledger_reply(Some(&setup.name), None, &request, &reply.to_string()).unwrap();
+            let verification: serde_json::Value = serde_json::from_str(&verification).unwrap();
+
+            assert!(!verification["verified"].as_bool().unwrap());
+            assert!(!verification["stateProofs"][0]["multiSignatureValid"].as_bool().unwrap());
+            assert!(verification["stateProofs"][0]["proofValid"].as_bool().unwrap());
+        }
+    }
+
+    mod export_snapshot {
+        use super::*;
+
+        #[test]
+        fn indy_export_pool_snapshot_works() {
+            let setup = Setup::empty();
+
+            let pool_config = json!({
+                "genesis_txn_data": pool::genesis_txns(4),
+            }).to_string();
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let snapshot = pool::export_snapshot(&setup.name).unwrap();
+            let snapshot: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+
+            assert_eq!(4, snapshot["ledger_size"].as_u64().unwrap());
+            assert_eq!(4, snapshot["txns"].as_array().unwrap().len());
+            assert!(!snapshot["root_hash"].as_str().unwrap().is_empty());
+        }
+
+        #[test]
+        fn indy_export_pool_snapshot_works_for_signed_snapshot() {
+            let setup = Setup::key();
+
+            let pool_config = json!({
+                "genesis_txn_data": pool::genesis_txns(2),
+            }).to_string();
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let snapshot = pool::export_signed_snapshot(setup.wallet_handle, &setup.name, &setup.verkey).unwrap();
+            let snapshot: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+            assert_eq!(setup.verkey, snapshot["signature"]["verkey"].as_str().unwrap());
+
+            let imported_pool_name = format!("{}_imported", setup.name);
+            let pool_config = json!({
+                "genesis_txn_data": pool::genesis_txns(2),
+                "snapshot": snapshot,
+                "snapshot_verkey": setup.verkey,
+            }).to_string();
+            pool::create_pool_ledger_config(&imported_pool_name, Some(pool_config.as_str())).unwrap();
+
+            pool::delete(&imported_pool_name).unwrap();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_export_pool_snapshot_works_for_import() {
+            let setup = Setup::empty();
+
+            let pool_handle = pool::create_and_open_pool_ledger(&setup.name).unwrap();
+            pool::close(pool_handle).unwrap();
+
+            let snapshot = pool::export_snapshot(&setup.name).unwrap();
+            let snapshot: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+
+            let imported_pool_name = format!("{}_imported", setup.name);
+            let pool_config = json!({
+                "genesis_txn_data": pool::genesis_txns(4),
+                "snapshot": snapshot,
+            }).to_string();
+            pool::create_pool_ledger_config(&imported_pool_name, Some(pool_config.as_str())).unwrap();
+
+            let pool_handle = pool::open_pool_ledger(&imported_pool_name, None).unwrap();
+
+            let status = pool::get_status(pool_handle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(snapshot["ledger_size"], status["ledger_size"]);
+            assert_eq!(snapshot["root_hash"], status["merkle_root"]);
+
+            pool::close(pool_handle).unwrap();
+            pool::delete(&imported_pool_name).unwrap();
+        }
+    }
+
+    mod create_simulated_pool {
+        use super::*;
+
+        use utils::{did, ledger};
+        use utils::constants::{PROTOCOL_VERSION, TRUSTEE_SEED};
+
+        #[test]
+        fn indy_create_simulated_pool_works() {
+            let setup = Setup::wallet();
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+
+            let (trustee_did, trustee_verkey) = did::create_and_store_my_did(setup.wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let config = json!({
+                "nyms": [{"did": trustee_did, "verkey": trustee_verkey, "role": "TRUSTEE"}]
+            }).to_string();
+
+            let genesis_txns = pool::create_simulated_pool(&setup.name, Some(&config)).unwrap();
+            assert_eq!(4, genesis_txns.lines().count());
+
+            let pool_handle = pool::open_pool_ledger(&setup.name, None).unwrap();
+
+            let (my_did, my_verkey) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+            let nym_request = ledger::build_nym_request(&trustee_did, &my_did, Some(&my_verkey), None, None).unwrap();
+            let nym_response = ledger::sign_and_submit_request(pool_handle, setup.wallet_handle, &trustee_did, &nym_request).unwrap();
+            let nym_response: serde_json::Value = serde_json::from_str(&nym_response).unwrap();
+            assert_eq!("REPLY", nym_response["op"].as_str().unwrap());
+
+            let get_nym_request = ledger::build_get_nym_request(Some(&my_did), &my_did).unwrap();
+            let get_nym_response = ledger::submit_request(pool_handle, &get_nym_request).unwrap();
+            let nym = ledger::parse_get_nym_response(&get_nym_response).unwrap();
+            let nym: serde_json::Value = serde_json::from_str(&nym).unwrap();
+            assert_eq!(my_verkey, nym["verkey"].as_str().unwrap());
+
+            pool::close(pool_handle).unwrap();
+            pool::delete(&setup.name).unwrap();
+        }
+
+        #[test]
+        fn indy_create_simulated_pool_works_for_unauthorized_write() {
+            let setup = Setup::wallet();
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+
+            pool::create_simulated_pool(&setup.name, None).unwrap();
+            let pool_handle = pool::open_pool_ledger(&setup.name, None).unwrap();
+
+            let (my_did, my_verkey) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+            let nym_request = ledger::build_nym_request(&my_did, &my_did, Some(&my_verkey), None, None).unwrap();
+            let nym_response = ledger::sign_and_submit_request(pool_handle, setup.wallet_handle, &my_did, &nym_request).unwrap();
+            let nym_response: serde_json::Value = serde_json::from_str(&nym_response).unwrap();
+            assert_eq!("REQNACK", nym_response["op"].as_str().unwrap());
+
+            pool::close(pool_handle).unwrap();
+            pool::delete(&setup.name).unwrap();
+        }
+    }
+
     mod close {
         use super::*;
 
@@ -180,6 +543,33 @@ mod high_cases {
         }
     }
 
+    mod get_pool_handle_for_namespace {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_pool_handle_for_namespace_works() {
+            let setup = Setup::empty();
+
+            let txn_file_path =
+                pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = json!({
+                "genesis_txn": txn_file_path.to_string_lossy(),
+                "namespace": "sovrin:staging",
+            }).to_string();
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let pool_handle = pool::open_pool_ledger(&setup.name, None).unwrap();
+
+            assert_eq!(pool_handle, pool::get_pool_handle_for_namespace("sovrin:staging").unwrap());
+
+            pool::close(pool_handle).unwrap();
+
+            let res = pool::get_pool_handle_for_namespace("sovrin:staging");
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+    }
+
     mod set_protocol_version {
         use super::*;
 
@@ -227,6 +617,21 @@ mod medium_cases {
             assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
 
+        #[test]
+        fn create_pool_ledger_config_works_for_invalid_namespace() {
+            let setup = Setup::empty();
+
+            let txn_file_path =
+                pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = json!({
+                "genesis_txn": txn_file_path.to_string_lossy(),
+                "namespace": "Sovrin/Main",
+            }).to_string();
+
+            let res = pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str()));
+            assert_code!(ErrorCode::CommonInvalidParam3, res);
+        }
+
         #[test]
         fn create_pool_ledger_config_works_for_invalid_genesis_txn_path() {
             let setup = Setup::empty();
@@ -258,6 +663,101 @@ mod medium_cases {
             let pool_config = pool::pool_config_json(txn_file_path.as_path());
             pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
         }
+
+        #[test]
+        fn create_pool_ledger_config_works_for_several_genesis_sources() {
+            let setup = Setup::empty();
+
+            let txn_file_path =
+                pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = json!({
+                "genesis_txn": txn_file_path.to_string_lossy(),
+                "genesis_txn_data": pool::genesis_txns(4),
+            }).to_string();
+
+            let res = pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str()));
+            assert_code!(ErrorCode::CommonInvalidParam3, res);
+        }
+
+        #[test]
+        fn create_pool_ledger_config_works_for_genesis_url_without_hash() {
+            let setup = Setup::empty();
+
+            let pool_config = json!({
+                "genesis_url": "https://example.com/pool_transactions_genesis",
+            }).to_string();
+
+            let res = pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str()));
+            assert_code!(ErrorCode::CommonInvalidParam3, res);
+        }
+
+        #[test]
+        fn create_pool_ledger_config_works_for_invalid_genesis_hash() {
+            let setup = Setup::empty();
+
+            let pool_config = json!({
+                "genesis_txn_data": pool::genesis_txns(4),
+                "genesis_hash": "not a hash",
+            }).to_string();
+
+            let res = pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str()));
+            assert_code!(ErrorCode::CommonInvalidParam3, res);
+        }
+
+        #[test]
+        fn create_pool_ledger_config_works_for_genesis_hash_mismatch() {
+            let setup = Setup::empty();
+
+            let pool_config = json!({
+                "genesis_txn_data": pool::genesis_txns(4),
+                "genesis_hash": pool::genesis_hash(&pool::genesis_txns(2)),
+            }).to_string();
+
+            let res = pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str()));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod update_genesis {
+        use super::*;
+
+        #[test]
+        fn update_pool_ledger_genesis_works_for_not_created() {
+            let setup = Setup::empty();
+
+            let config = json!({
+                "genesis_txn_data": pool::genesis_txns(4),
+            }).to_string();
+
+            let res = pool::update_genesis(&setup.name, &config);
+            assert_code!(ErrorCode::PoolLedgerNotCreatedError, res);
+        }
+
+        #[test]
+        fn update_pool_ledger_genesis_works_for_no_genesis_source() {
+            let setup = Setup::empty();
+
+            let txn_file_path =
+                pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let res = pool::update_genesis(&setup.name, r#"{"namespace": "sovrin"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn update_pool_ledger_genesis_works_for_opened() {
+            let setup = Setup::pool();
+
+            let config = json!({
+                "genesis_txn_data": pool::genesis_txns(4),
+            }).to_string();
+
+            let res = pool::update_genesis(&setup.name, &config);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+        }
     }
 
     mod open {
@@ -355,7 +855,23 @@ mod medium_cases {
 
         #[test]
         #[cfg(feature = "local_nodes_pool")]
-        fn open_pool_ledger_works_for_incompatible_protocol_version() {
+        fn open_pool_ledger_works_for_unknown_read_nodes_selection() {
+            let setup = Setup::empty();
+
+            let config = r#"{"read_nodes_selection": "fastest"}"#;
+
+            let txn_file_path =
+                pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let res = pool::open_pool_ledger(&setup.name, Some(config));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn open_pool_ledger_works_for_other_protocol_version() {
             let setup = Setup::empty();
 
             pool::set_protocol_version(1).unwrap();
@@ -365,9 +881,13 @@ mod medium_cases {
             let pool_config = pool::pool_config_json(txn_file_path.as_path());
             pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
 
-            let res = pool::open_pool_ledger(&setup.name, None);
-            assert_code!(ErrorCode::PoolIncompatibleProtocolVersion, res);
+            let pool_handle = pool::open_pool_ledger(&setup.name, None).unwrap();
+
+            let status = pool::get_status(pool_handle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(PROTOCOL_VERSION as u64, status["protocol_version"].as_u64().unwrap());
 
+            pool::close(pool_handle).unwrap();
             pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
         }
 
@@ -504,6 +1024,161 @@ mod medium_cases {
         }
     }
 
+    mod get_status {
+        use super::*;
+
+        #[test]
+        fn indy_get_pool_status_works_for_invalid_handle() {
+            Setup::empty();
+
+            let res = pool::get_status(0);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+    }
+
+    mod verify_ledger_reply {
+        use super::*;
+
+        #[test]
+        fn indy_verify_ledger_reply_works_for_missed_pool_source() {
+            Setup::empty();
+
+            let request = ledger::build_get_nym_request(Some(IDENTIFIER), DEST).unwrap();
+
+            let res = pool::verify_ledger_reply(None, None, &request, "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_verify_ledger_reply_works_for_not_created_pool() {
+            let setup = Setup::empty();
+
+            let request = ledger::build_get_nym_request(Some(IDENTIFIER), DEST).unwrap();
+
+            let res = pool::verify_ledger_reply(Some(&setup.name), None, &request, "{}");
+            assert_code!(ErrorCode::PoolLedgerNotCreatedError, res);
+        }
+    }
+
+    mod export_snapshot {
+        use super::*;
+
+        #[test]
+        fn indy_export_pool_snapshot_works_for_not_created() {
+            let setup = Setup::empty();
+
+            let res = pool::export_snapshot(&setup.name);
+            assert_code!(ErrorCode::PoolLedgerNotCreatedError, res);
+        }
+
+        #[test]
+        fn indy_export_pool_snapshot_works_for_tampered_signed_snapshot() {
+            let setup = Setup::key();
+
+            let pool_config = json!({
+                "genesis_txn_data": pool::genesis_txns(4),
+            }).to_string();
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let snapshot = pool::export_signed_snapshot(setup.wallet_handle, &setup.name, &setup.verkey).unwrap();
+            let mut snapshot: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+            snapshot["txns"][3]["txn"]["data"]["data"]["alias"] = json!("Node5");
+
+            let imported_pool_name = format!("{}_imported", setup.name);
+            let pool_config = json!({
+                "genesis_txn_data": pool::genesis_txns(2),
+                "snapshot": snapshot,
+                "snapshot_verkey": setup.verkey,
+            }).to_string();
+
+            let res = pool::create_pool_ledger_config(&imported_pool_name, Some(pool_config.as_str()));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_export_pool_snapshot_works_for_unsigned_snapshot_and_verkey() {
+            let setup = Setup::key();
+
+            let pool_config = json!({
+                "genesis_txn_data": pool::genesis_txns(4),
+            }).to_string();
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let snapshot = pool::export_snapshot(&setup.name).unwrap();
+            let snapshot: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+
+            let imported_pool_name = format!("{}_imported", setup.name);
+            let pool_config = json!({
+                "genesis_txn_data": pool::genesis_txns(2),
+                "snapshot": snapshot,
+                "snapshot_verkey": setup.verkey,
+            }).to_string();
+
+            let res = pool::create_pool_ledger_config(&imported_pool_name, Some(pool_config.as_str()));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_export_pool_snapshot_works_for_unknown_signer() {
+            let setup = Setup::wallet();
+
+            let pool_config = json!({
+                "genesis_txn_data": pool::genesis_txns(4),
+            }).to_string();
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let res = pool::export_signed_snapshot(setup.wallet_handle, &setup.name, VERKEY_MY1);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_export_pool_snapshot_works_for_root_hash_mismatch() {
+            let setup = Setup::empty();
+
+            let pool_config = json!({
+                "genesis_txn_data": pool::genesis_txns(4),
+            }).to_string();
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let snapshot = pool::export_snapshot(&setup.name).unwrap();
+            let mut snapshot: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+            snapshot["root_hash"] = json!("11111111111111111111111111111111");
+
+            let imported_pool_name = format!("{}_imported", setup.name);
+            let pool_config = json!({
+                "genesis_txn_data": pool::genesis_txns(4),
+                "snapshot": snapshot,
+            }).to_string();
+
+            let res = pool::create_pool_ledger_config(&imported_pool_name, Some(pool_config.as_str()));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod create_simulated_pool {
+        use super::*;
+
+        #[test]
+        fn indy_create_simulated_pool_works_for_invalid_nodes_count() {
+            let setup = Setup::empty();
+
+            let res = pool::create_simulated_pool(&setup.name, Some(r#"{"nodes_count": 0}"#));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_create_simulated_pool_works_for_twice() {
+            let setup = Setup::empty();
+
+            pool::create_simulated_pool(&setup.name, None).unwrap();
+
+            let res = pool::create_simulated_pool(&setup.name, None);
+            assert_code!(ErrorCode::PoolLedgerConfigAlreadyExistsError, res);
+
+            pool::delete(&setup.name).unwrap();
+        }
+    }
+
     mod set_protocol_version {
         use super::*;
 
@@ -33,3 +33,65 @@ pub fn purge_cred_def_cache(
 ) -> Result<(), IndyError> {
     cache::purge_cred_def_cache(wallet_handle, options_json).wait()
 }
+
+pub fn get_nym_cache(
+    pool_handle: PoolHandle,
+    wallet_handle: WalletHandle,
+    submitter_did: &str,
+    did: &str,
+    options_json: &str,
+) -> Result<String, IndyError> {
+    cache::get_nym(pool_handle, wallet_handle, submitter_did, did, options_json).wait()
+}
+
+pub fn get_revoc_reg_def_cache(
+    pool_handle: PoolHandle,
+    wallet_handle: WalletHandle,
+    submitter_did: &str,
+    id: &str,
+    options_json: &str,
+) -> Result<String, IndyError> {
+    cache::get_revoc_reg_def(pool_handle, wallet_handle, submitter_did, id, options_json).wait()
+}
+
+pub fn get_revoc_reg_delta_cache(
+    pool_handle: PoolHandle,
+    wallet_handle: WalletHandle,
+    submitter_did: &str,
+    revoc_reg_def_id: &str,
+    from: Option<u64>,
+    to: u64,
+    options_json: &str,
+) -> Result<(String, String, u64), IndyError> {
+    cache::get_revoc_reg_delta(
+        pool_handle,
+        wallet_handle,
+        submitter_did,
+        revoc_reg_def_id,
+        from.map(|from| from as i64).unwrap_or(-1),
+        to as i64,
+        options_json,
+    )
+    .wait()
+}
+
+pub fn purge_nym_cache(
+    wallet_handle: WalletHandle,
+    options_json: &str,
+) -> Result<(), IndyError> {
+    cache::purge_nym_cache(wallet_handle, options_json).wait()
+}
+
+pub fn purge_revoc_reg_def_cache(
+    wallet_handle: WalletHandle,
+    options_json: &str,
+) -> Result<(), IndyError> {
+    cache::purge_revoc_reg_def_cache(wallet_handle, options_json).wait()
+}
+
+pub fn purge_revoc_reg_delta_cache(
+    wallet_handle: WalletHandle,
+    options_json: &str,
+) -> Result<(), IndyError> {
+    cache::purge_revoc_reg_delta_cache(wallet_handle, options_json).wait()
+}
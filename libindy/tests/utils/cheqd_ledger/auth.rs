@@ -26,6 +26,34 @@ pub fn build_tx(
     ).wait()
 }
 
+pub fn build_tx_with_fee_granter(
+    pool_alias: &str,
+    sender_public_key: &str,
+    msg: &[u8],
+    account_number: u64,
+    sequence_number: u64,
+    max_gas: u64,
+    max_coin_amount: u64,
+    max_coin_denom: &str,
+    timeout_height: u64,
+    memo: &str,
+    fee_granter: &str,
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::auth::build_tx_with_fee_granter(
+        pool_alias,
+        sender_public_key,
+        msg,
+        account_number,
+        sequence_number,
+        max_gas,
+        max_coin_amount,
+        max_coin_denom,
+        timeout_height,
+        memo,
+        fee_granter,
+    ).wait()
+}
+
 pub fn build_query_account(address: &str) -> Result<String, IndyError> {
     cheqd_ledger::auth::build_query_account(address).wait()
 }
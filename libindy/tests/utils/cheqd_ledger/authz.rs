@@ -0,0 +1,43 @@
+use indyrs::{future::Future, cheqd_ledger, IndyError};
+
+pub fn build_msg_grant(
+    granter: &str,
+    grantee: &str,
+    msg_type_url: &str,
+    expiration: i64,
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::authz::build_msg_grant(granter, grantee, msg_type_url, expiration).wait()
+}
+
+pub fn parse_msg_grant_resp(
+    commit_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::authz::parse_msg_grant_resp(commit_resp).wait()
+}
+
+pub fn build_msg_exec(
+    grantee: &str,
+    msg: &[u8],
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::authz::build_msg_exec(grantee, msg).wait()
+}
+
+pub fn parse_msg_exec_resp(
+    commit_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::authz::parse_msg_exec_resp(commit_resp).wait()
+}
+
+pub fn build_msg_revoke(
+    granter: &str,
+    grantee: &str,
+    msg_type_url: &str,
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::authz::build_msg_revoke(granter, grantee, msg_type_url).wait()
+}
+
+pub fn parse_msg_revoke_resp(
+    commit_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::authz::parse_msg_revoke_resp(commit_resp).wait()
+}
@@ -0,0 +1,26 @@
+use indyrs::{future::Future, cheqd_ledger, IndyError};
+
+pub fn build_msg_withdraw_delegator_reward(
+    delegator_address: &str,
+    validator_address: &str,
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::distribution::build_msg_withdraw_delegator_reward(delegator_address, validator_address).wait()
+}
+
+pub fn parse_msg_withdraw_delegator_reward_resp(
+    commit_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::distribution::parse_msg_withdraw_delegator_reward_resp(commit_resp).wait()
+}
+
+pub fn build_query_delegation_total_rewards(
+    delegator_address: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::distribution::build_query_delegation_total_rewards(delegator_address).wait()
+}
+
+pub fn parse_query_delegation_total_rewards_resp(
+    query_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::distribution::parse_query_delegation_total_rewards_resp(query_resp).wait()
+}
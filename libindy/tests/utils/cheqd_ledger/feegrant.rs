@@ -0,0 +1,30 @@
+use indyrs::{future::Future, cheqd_ledger, IndyError};
+
+pub fn build_msg_grant_allowance(
+    granter: &str,
+    grantee: &str,
+    spend_limit_amount: Option<&str>,
+    spend_limit_denom: Option<&str>,
+    expiration: i64,
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::feegrant::build_msg_grant_allowance(granter, grantee, spend_limit_amount, spend_limit_denom, expiration).wait()
+}
+
+pub fn parse_msg_grant_allowance_resp(
+    commit_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::feegrant::parse_msg_grant_allowance_resp(commit_resp).wait()
+}
+
+pub fn build_msg_revoke_allowance(
+    granter: &str,
+    grantee: &str,
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::feegrant::build_msg_revoke_allowance(granter, grantee).wait()
+}
+
+pub fn parse_msg_revoke_allowance_resp(
+    commit_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::feegrant::parse_msg_revoke_allowance_resp(commit_resp).wait()
+}
@@ -0,0 +1,121 @@
+use indyrs::{future::Future, cheqd_ledger, IndyError};
+
+pub fn build_msg_submit_text_proposal(
+    title: &str,
+    description: &str,
+    proposer: &str,
+    deposit_amount: &str,
+    deposit_denom: &str,
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::gov::build_msg_submit_text_proposal(title, description, proposer, deposit_amount, deposit_denom).wait()
+}
+
+pub fn build_msg_submit_param_change_proposal(
+    title: &str,
+    description: &str,
+    changes: &str,
+    proposer: &str,
+    deposit_amount: &str,
+    deposit_denom: &str,
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::gov::build_msg_submit_param_change_proposal(title, description, changes, proposer, deposit_amount, deposit_denom).wait()
+}
+
+pub fn parse_msg_submit_proposal_resp(
+    commit_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::gov::parse_msg_submit_proposal_resp(commit_resp).wait()
+}
+
+pub fn build_msg_deposit(
+    proposal_id: u64,
+    depositor: &str,
+    amount: &str,
+    denom: &str,
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::gov::build_msg_deposit(proposal_id, depositor, amount, denom).wait()
+}
+
+pub fn parse_msg_deposit_resp(
+    commit_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::gov::parse_msg_deposit_resp(commit_resp).wait()
+}
+
+pub fn build_msg_vote(
+    proposal_id: u64,
+    voter: &str,
+    option: &str,
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::gov::build_msg_vote(proposal_id, voter, option).wait()
+}
+
+pub fn parse_msg_vote_resp(
+    commit_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::gov::parse_msg_vote_resp(commit_resp).wait()
+}
+
+pub fn build_msg_vote_weighted(
+    proposal_id: u64,
+    voter: &str,
+    options: &str,
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::gov::build_msg_vote_weighted(proposal_id, voter, options).wait()
+}
+
+pub fn parse_msg_vote_weighted_resp(
+    commit_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::gov::parse_msg_vote_weighted_resp(commit_resp).wait()
+}
+
+pub fn build_query_proposal(
+    proposal_id: u64,
+) -> Result<String, IndyError> {
+    cheqd_ledger::gov::build_query_proposal(proposal_id).wait()
+}
+
+pub fn parse_query_proposal_resp(
+    query_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::gov::parse_query_proposal_resp(query_resp).wait()
+}
+
+pub fn build_query_proposals(
+    status: Option<&str>,
+    pagination: Option<&str>,
+) -> Result<String, IndyError> {
+    cheqd_ledger::gov::build_query_proposals(status, pagination).wait()
+}
+
+pub fn parse_query_proposals_resp(
+    query_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::gov::parse_query_proposals_resp(query_resp).wait()
+}
+
+pub fn build_query_tally_result(
+    proposal_id: u64,
+) -> Result<String, IndyError> {
+    cheqd_ledger::gov::build_query_tally_result(proposal_id).wait()
+}
+
+pub fn parse_query_tally_result_resp(
+    query_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::gov::parse_query_tally_result_resp(query_resp).wait()
+}
+
+pub fn build_query_vote(
+    proposal_id: u64,
+    voter: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::gov::build_query_vote(proposal_id, voter).wait()
+}
+
+pub fn parse_query_vote_resp(
+    query_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::gov::parse_query_vote_resp(query_resp).wait()
+}
@@ -1,3 +1,8 @@
 pub mod auth;
 pub mod cheqd;
 pub mod bank;
+pub mod staking;
+pub mod distribution;
+pub mod gov;
+pub mod feegrant;
+pub mod authz;
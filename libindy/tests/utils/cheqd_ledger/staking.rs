@@ -0,0 +1,86 @@
+use indyrs::{future::Future, cheqd_ledger, IndyError};
+
+pub fn build_msg_delegate(
+    delegator_address: &str,
+    validator_address: &str,
+    amount: &str,
+    denom: &str,
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::staking::build_msg_delegate(delegator_address, validator_address, amount, denom).wait()
+}
+
+pub fn parse_msg_delegate_resp(
+    commit_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::staking::parse_msg_delegate_resp(commit_resp).wait()
+}
+
+pub fn build_msg_undelegate(
+    delegator_address: &str,
+    validator_address: &str,
+    amount: &str,
+    denom: &str,
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::staking::build_msg_undelegate(delegator_address, validator_address, amount, denom).wait()
+}
+
+pub fn parse_msg_undelegate_resp(
+    commit_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::staking::parse_msg_undelegate_resp(commit_resp).wait()
+}
+
+pub fn build_msg_begin_redelegate(
+    delegator_address: &str,
+    validator_src_address: &str,
+    validator_dst_address: &str,
+    amount: &str,
+    denom: &str,
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::staking::build_msg_begin_redelegate(delegator_address, validator_src_address, validator_dst_address, amount, denom).wait()
+}
+
+pub fn parse_msg_begin_redelegate_resp(
+    commit_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::staking::parse_msg_begin_redelegate_resp(commit_resp).wait()
+}
+
+pub fn build_query_delegation(
+    delegator_address: &str,
+    validator_address: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::staking::build_query_delegation(delegator_address, validator_address).wait()
+}
+
+pub fn parse_query_delegation_resp(
+    query_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::staking::parse_query_delegation_resp(query_resp).wait()
+}
+
+pub fn build_query_delegator_delegations(
+    delegator_address: &str,
+    pagination: Option<&str>,
+) -> Result<String, IndyError> {
+    cheqd_ledger::staking::build_query_delegator_delegations(delegator_address, pagination).wait()
+}
+
+pub fn parse_query_delegator_delegations_resp(
+    query_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::staking::parse_query_delegator_delegations_resp(query_resp).wait()
+}
+
+pub fn build_query_validators(
+    status: Option<&str>,
+    pagination: Option<&str>,
+) -> Result<String, IndyError> {
+    cheqd_ledger::staking::build_query_validators(status, pagination).wait()
+}
+
+pub fn parse_query_validators_resp(
+    query_resp: &str,
+) -> Result<String, IndyError> {
+    cheqd_ledger::staking::parse_query_validators_resp(query_resp).wait()
+}
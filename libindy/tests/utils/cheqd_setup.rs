@@ -93,6 +93,18 @@ impl CheqdSetup {
         Ok((account_number, account_sequence))
     }
 
+    pub fn get_balance(&self, account_id: &str) -> Result<u64, IndyError> {
+        let query = cheqd_ledger::bank::bank_build_query_balance(account_id, &self.denom)?;
+        let resp = cheqd_pool::abci_query(&self.pool_alias, &query)?;
+        let resp = cheqd_ledger::bank::parse_query_balance_resp(&resp)?;
+        println!("Cheqd setup. Get balance: {:?}", resp);
+
+        let resp: Value = serde_json::from_str(&resp).unwrap();
+        let balance = resp["balance"]["amount"].as_str().unwrap().parse::<u64>().unwrap();
+
+        Ok(balance)
+    }
+
     pub fn build_and_sign_and_broadcast_tx(&self, msg: &[u8]) -> Result<String, IndyError> {
         // Get account info
         let (account_number, account_sequence) = self.get_base_account_number_and_sequence(&self.account_id)?;
@@ -135,6 +135,10 @@ pub fn get_endpoint_for_did(
     did::get_endpoint_for_did(wallet_handle, pool_handle, did).wait()
 }
 
+pub fn resolve_did(pool_handle: PoolHandle, did: &str) -> Result<String, IndyError> {
+    did::resolve_did(pool_handle, did).wait()
+}
+
 pub fn set_did_metadata(
     wallet_handle: WalletHandle,
     did: &str,
@@ -1,7 +1,12 @@
 use std::{mem, sync::Once};
 
 use indy_utils::crypto::hash::hash;
-use indyrs::{future::Future, ledger, IndyError, PoolHandle, WalletHandle};
+use indyrs::{
+    future::Future,
+    ledger,
+    ledger::{CustomFree, CustomTransactionParser},
+    IndyError, PoolHandle, WalletHandle,
+};
 use lazy_static::lazy_static;
 
 use crate::utils::{anoncreds, blob_storage, constants::*, did, pool, timeout, wallet};
@@ -40,6 +45,14 @@ pub fn submit_request(pool_handle: PoolHandle, request_json: &str) -> Result<Str
     ledger::submit_request(pool_handle, request_json).wait()
 }
 
+pub fn submit_requests(
+    pool_handle: PoolHandle,
+    requests_json: &str,
+    options_json: Option<&str>,
+) -> Result<String, IndyError> {
+    ledger::submit_requests(pool_handle, requests_json, options_json).wait()
+}
+
 pub fn submit_action(
     pool_handle: PoolHandle,
     request_json: &str,
@@ -49,6 +62,14 @@ pub fn submit_action(
     ledger::submit_action(pool_handle, request_json, nodes, timeout).wait()
 }
 
+pub fn download_ledger(
+    pool_handle: PoolHandle,
+    ledger_type: &str,
+    config: Option<&str>,
+) -> Result<String, IndyError> {
+    ledger::download_ledger(pool_handle, ledger_type, config).wait()
+}
+
 pub fn sign_request(
     wallet_handle: WalletHandle,
     submitter_did: &str,
@@ -184,6 +205,10 @@ pub fn build_get_validator_info_request(submitter_did: &str) -> Result<String, I
     ledger::build_get_validator_info_request(submitter_did).wait()
 }
 
+pub fn parse_get_validator_info_response(get_validator_info_response: &str) -> Result<String, IndyError> {
+    ledger::parse_get_validator_info_response(get_validator_info_response).wait()
+}
+
 pub fn build_get_txn_request(
     submitter_did: Option<&str>,
     data: i32,
@@ -308,22 +333,13 @@ pub fn parse_get_revoc_reg_delta_response(
     ledger::parse_get_revoc_reg_delta_response(get_revoc_reg_delta_response).wait()
 }
 
-//pub fn register_transaction_parser_for_sp(txn_type: &str, parse: CustomTransactionParser, free: CustomFree) -> Result<(), ErrorCode> {
-//    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
-//
-//    let txn_type = CString::new(txn_type).unwrap();
-//
-//    let err =
-//        unsafe {
-//            indy_register_transaction_parser_for_sp(command_handle,
-//                                                    txn_type.as_ptr(),
-//                                                    Some(parse),
-//                                                    Some(free),
-//                                                    cb)
-//        };
-//
-//    super::results::result_to_empty(err, receiver)
-//}
+pub fn register_transaction_parser_for_sp(
+    txn_type: &str,
+    parser: CustomTransactionParser,
+    free: CustomFree,
+) -> Result<(), IndyError> {
+    ledger::register_transaction_parser_for_sp(txn_type, parser, free).wait()
+}
 
 pub fn get_response_metadata(response: &str) -> Result<String, IndyError> {
     ledger::get_response_metadata(response).wait()
@@ -373,6 +389,16 @@ pub fn build_get_auth_rule_request(
     .wait()
 }
 
+pub fn evaluate_auth_rule(
+    get_auth_rule_response: &str,
+    action_json: &str,
+    request_json: &str,
+    signers_json: &str,
+) -> Result<String, IndyError> {
+    ledger::evaluate_auth_rule(get_auth_rule_response, action_json, request_json, signers_json)
+        .wait()
+}
+
 pub fn build_txn_author_agreement_request(
     submitter_did: &str,
     text: Option<&str>,
@@ -439,6 +465,25 @@ pub fn append_txn_author_agreement_acceptance_to_request(
     .wait()
 }
 
+pub fn set_pool_txn_author_agreement_acceptance(
+    pool_handle: PoolHandle,
+    text: Option<&str>,
+    version: Option<&str>,
+    taa_digest: Option<&str>,
+    acc_mech_type: &str,
+    time_of_acceptance: u64,
+) -> Result<(), IndyError> {
+    ledger::set_pool_txn_author_agreement_acceptance(
+        pool_handle,
+        text,
+        version,
+        taa_digest,
+        acc_mech_type,
+        time_of_acceptance,
+    )
+    .wait()
+}
+
 pub fn append_request_endorser(
     request_json: &str,
     endorser_did: &str,
@@ -446,6 +491,110 @@ pub fn append_request_endorser(
     ledger::append_request_endorser(request_json, endorser_did).wait()
 }
 
+pub fn build_endorsement_envelope(
+    wallet_handle: WalletHandle,
+    request_json: &str,
+    endorser_did: &str,
+    options_json: Option<&str>,
+) -> Result<String, IndyError> {
+    ledger::build_endorsement_envelope(wallet_handle, request_json, endorser_did, options_json)
+        .wait()
+}
+
+pub fn sign_endorsement_envelope(
+    wallet_handle: WalletHandle,
+    envelope_json: &str,
+    signer_did: &str,
+) -> Result<String, IndyError> {
+    ledger::sign_endorsement_envelope(wallet_handle, envelope_json, signer_did).wait()
+}
+
+pub fn check_endorsement_envelope(envelope_json: &str) -> Result<String, IndyError> {
+    ledger::check_endorsement_envelope(envelope_json).wait()
+}
+
+pub fn submit_endorsement_envelope(
+    pool_handle: PoolHandle,
+    wallet_handle: WalletHandle,
+    envelope_json: &str,
+) -> Result<String, IndyError> {
+    ledger::submit_endorsement_envelope(pool_handle, wallet_handle, envelope_json).wait()
+}
+
+pub fn list_pending_endorsements(wallet_handle: WalletHandle) -> Result<String, IndyError> {
+    ledger::list_pending_endorsements(wallet_handle).wait()
+}
+
+pub fn delete_pending_endorsement(wallet_handle: WalletHandle, id: &str) -> Result<(), IndyError> {
+    ledger::delete_pending_endorsement(wallet_handle, id).wait()
+}
+
+pub fn build_ledgers_freeze_request(
+    submitter_did: &str,
+    ledgers_ids: &str,
+) -> Result<String, IndyError> {
+    ledger::build_ledgers_freeze_request(submitter_did, ledgers_ids).wait()
+}
+
+pub fn build_get_frozen_ledgers_request(submitter_did: Option<&str>) -> Result<String, IndyError> {
+    ledger::build_get_frozen_ledgers_request(submitter_did).wait()
+}
+
+pub fn parse_get_frozen_ledgers_response(
+    get_frozen_ledgers_response: &str,
+) -> Result<String, IndyError> {
+    ledger::parse_get_frozen_ledgers_response(get_frozen_ledgers_response).wait()
+}
+
+pub fn build_rich_schema_request(
+    submitter_did: &str,
+    rs_id: &str,
+    rs_content: &str,
+    rs_name: &str,
+    rs_version: &str,
+    rs_type: &str,
+    ver: &str,
+) -> Result<String, IndyError> {
+    ledger::build_rich_schema_request(
+        submitter_did,
+        rs_id,
+        rs_content,
+        rs_name,
+        rs_version,
+        rs_type,
+        ver,
+    )
+    .wait()
+}
+
+pub fn build_get_rich_schema_object_by_id_request(
+    submitter_did: Option<&str>,
+    rs_id: &str,
+) -> Result<String, IndyError> {
+    ledger::build_get_rich_schema_object_by_id_request(submitter_did, rs_id).wait()
+}
+
+pub fn build_get_rich_schema_object_by_metadata_request(
+    submitter_did: Option<&str>,
+    rs_type: &str,
+    rs_name: &str,
+    rs_version: &str,
+) -> Result<String, IndyError> {
+    ledger::build_get_rich_schema_object_by_metadata_request(
+        submitter_did,
+        rs_type,
+        rs_name,
+        rs_version,
+    )
+    .wait()
+}
+
+pub fn parse_get_rich_schema_object_response(
+    get_rich_schema_object_response: &str,
+) -> Result<String, IndyError> {
+    ledger::parse_get_rich_schema_object_response(get_rich_schema_object_response).wait()
+}
+
 #[cfg(feature = "local_nodes_pool")]
 pub fn post_entities() -> (&'static str, &'static str, &'static str) {
     lazy_static! {
@@ -85,6 +85,10 @@ pub fn delete_wallet_record(
     wallet::delete_wallet_record(wallet_handle, type_, id).wait()
 }
 
+pub fn wallet_batch(wallet_handle: WalletHandle, operations_json: &str) -> Result<(), IndyError> {
+    wallet::wallet_batch(wallet_handle, operations_json).wait()
+}
+
 pub fn get_wallet_record(
     wallet_handle: WalletHandle,
     type_: &str,
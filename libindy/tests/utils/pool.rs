@@ -5,8 +5,9 @@ use std::{
 };
 
 use byteorder::{LittleEndian, WriteBytesExt};
-use indyrs::{future::Future, pool, ErrorCode, IndyError, PoolHandle};
+use indyrs::{future::Future, pool, ErrorCode, IndyError, PoolHandle, WalletHandle, INVALID_WALLET_HANDLE};
 use serde_json;
+use sha2::{Digest, Sha256};
 
 use crate::utils::{
     environment, test,
@@ -121,6 +122,22 @@ pub fn create_genesis_txn_file_for_test_pool_with_wrong_ips(
     create_genesis_txn_file(pool_name, txn_file_data.as_str(), txn_file_path)
 }
 
+pub fn genesis_txns(nodes_count: usize) -> String {
+    test::gen_txns()[0..nodes_count].join("\n")
+}
+
+pub fn genesis_hash(genesis_txns: &str) -> String {
+    hex::encode(Sha256::digest(genesis_txns.as_bytes()))
+}
+
+pub fn read_genesis_txns(pool_name: &str) -> String {
+    let mut txn_file_path = environment::pool_path(pool_name);
+    txn_file_path.push(pool_name);
+    txn_file_path.set_extension("txn");
+
+    fs::read_to_string(txn_file_path).unwrap()
+}
+
 // Note that to be config valid it assumes genesis txt file is already exists
 pub fn pool_config_json(txn_file_path: &Path) -> String {
     let config = PoolConfig {
@@ -138,6 +155,10 @@ pub fn create_pool_ledger_config(
 }
 
 #[cfg(feature = "local_nodes_pool")]
+pub fn create_simulated_pool(pool_name: &str, config: Option<&str>) -> Result<String, IndyError> {
+    pool::create_simulated_pool(pool_name, config).wait()
+}
+
 pub fn open_pool_ledger(pool_name: &str, config: Option<&str>) -> Result<PoolHandle, IndyError> {
     pool::open_pool_ledger(pool_name, config).wait()
 }
@@ -201,14 +222,44 @@ pub fn refresh(pool_handle: PoolHandle) -> Result<(), IndyError> {
     pool::refresh_pool_ledger(pool_handle).wait()
 }
 
+pub fn get_status(pool_handle: PoolHandle) -> Result<String, IndyError> {
+    pool::get_pool_status(pool_handle).wait()
+}
+
+pub fn verify_ledger_reply(
+    pool_name: Option<&str>,
+    genesis_txns: Option<&str>,
+    request_json: &str,
+    reply_json: &str,
+) -> Result<String, IndyError> {
+    pool::verify_ledger_reply(pool_name, genesis_txns, request_json, reply_json).wait()
+}
+
 pub fn close(pool_handle: PoolHandle) -> Result<(), IndyError> {
     pool::close_pool_ledger(pool_handle).wait()
 }
 
+pub fn get_pool_handle_for_namespace(namespace: &str) -> Result<PoolHandle, IndyError> {
+    pool::get_pool_handle_for_namespace(namespace).wait()
+}
+
 pub fn delete(pool_name: &str) -> Result<(), IndyError> {
     pool::delete_pool_ledger(pool_name).wait()
 }
 
+pub fn update_genesis(pool_name: &str, config: &str) -> Result<(), IndyError> {
+    pool::update_pool_ledger_genesis(pool_name, config).wait()
+}
+
+pub fn export_snapshot(pool_name: &str) -> Result<String, IndyError> {
+    pool::export_pool_snapshot(INVALID_WALLET_HANDLE, pool_name, None).wait()
+}
+
+pub fn export_signed_snapshot(wallet_handle: WalletHandle, pool_name: &str, signer_verkey: &str) -> Result<String, IndyError> {
+    let export_config = json!({ "signer_verkey": signer_verkey }).to_string();
+    pool::export_pool_snapshot(wallet_handle, pool_name, Some(&export_config)).wait()
+}
+
 pub fn set_protocol_version(protocol_version: usize) -> Result<(), IndyError> {
     pool::set_protocol_version(protocol_version).wait()
 }
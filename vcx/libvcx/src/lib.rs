@@ -36,6 +36,8 @@ extern crate rmp_serde;
 extern crate indy_sys;
 
 extern crate base64;
+#[cfg(feature = "cheqd")]
+extern crate hex;
 
 extern crate strum;
 #[macro_use]
@@ -46,6 +46,9 @@ pub static CONFIG_THREADPOOL_SIZE: &'static str = "threadpool_size";
 pub static CONFIG_WALLET_KEY_DERIVATION: &'static str = "wallet_key_derivation";
 pub static CONFIG_PROTOCOL_VERSION: &'static str = "protocol_version";
 pub static CONFIG_PAYMENT_METHOD: &'static str = "payment_method";
+pub static CONFIG_CHEQD_POOL_ALIAS: &'static str = "cheqd_pool_alias";
+pub static CONFIG_CHEQD_MAX_GAS: &'static str = "cheqd_max_gas";
+pub static CONFIG_CHEQD_MAX_FEE: &'static str = "cheqd_max_fee";
 pub static CONFIG_TXN_AUTHOR_AGREEMENT: &'static str = "author_agreement";
 pub static CONFIG_USE_LATEST_PROTOCOLS: &'static str = "use_latest_protocols";
 pub static CONFIG_POOL_CONFIG: &'static str = "pool_config";
@@ -77,6 +80,8 @@ pub static DEFAULT_PAYMENT_PLUGIN: &str = "libnullpay.so";
 pub static DEFAULT_PAYMENT_INIT_FUNCTION: &str = "nullpay_init";
 pub static DEFAULT_USE_LATEST_PROTOCOLS: &str = "false";
 pub static DEFAULT_PAYMENT_METHOD: &str = "null";
+pub static DEFAULT_CHEQD_MAX_GAS: u64 = 300000;
+pub static DEFAULT_CHEQD_MAX_FEE: u64 = 0;
 pub static DEFAULT_PROTOCOL_TYPE: &str = "1.0";
 pub static MAX_THREADPOOL_SIZE: usize = 128;
 pub static MOCK_DEFAULT_INDY_PROOF_VALIDATION: &str = "true";
@@ -359,6 +364,23 @@ pub fn get_payment_method() -> VcxResult<String> {
         .map_err(|_|VcxError::from_msg(VcxErrorKind::MissingPaymentMethod, "Payment Method is not set."))
 }
 
+pub fn get_cheqd_pool_alias() -> VcxResult<String> {
+    get_config_value(CONFIG_CHEQD_POOL_ALIAS)
+        .map_err(|_| VcxError::from_msg(VcxErrorKind::InvalidConfiguration, "Cheqd pool alias is not set."))
+}
+
+pub fn get_cheqd_max_gas() -> u64 {
+    get_config_value(CONFIG_CHEQD_MAX_GAS).ok()
+        .and_then(|max_gas| max_gas.parse().ok())
+        .unwrap_or(DEFAULT_CHEQD_MAX_GAS)
+}
+
+pub fn get_cheqd_max_fee() -> u64 {
+    get_config_value(CONFIG_CHEQD_MAX_FEE).ok()
+        .and_then(|max_fee| max_fee.parse().ok())
+        .unwrap_or(DEFAULT_CHEQD_MAX_FEE)
+}
+
 pub fn get_communication_method() -> VcxResult<String> {
     get_config_value(COMMUNICATION_METHOD)
 }
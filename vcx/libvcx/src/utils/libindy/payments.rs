@@ -1,5 +1,7 @@
 use futures::Future;
 use indy::payments;
+#[cfg(feature = "cheqd")]
+use indy::{cheqd_ledger, cheqd_pool};
 use serde_json::Value;
 
 use std::fmt;
@@ -11,6 +13,9 @@ use utils::constants::{SUBMIT_SCHEMA_RESPONSE, CREATE_TRANSFER_ACTION};
 use settings;
 use error::prelude::*;
 
+#[cfg(feature = "cheqd")]
+static CHEQD_PAYMENT_METHOD: &str = "cheqd";
+
 static DEFAULT_FEES: &str = r#"{"0":0, "1":0, "3":0, "100":0, "101":2, "102":42, "103":0, "104":0, "105":0, "107":0, "108":0, "109":0, "110":0, "111":0, "112":0, "113":2, "114":2, "115":0, "116":0, "117":0, "118":0, "119":0, "10001":0}"#;
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -143,6 +148,13 @@ pub fn get_address_info(address: &str) -> VcxResult<AddressInfo> {
         return Ok(AddressInfo { address: address.to_string(), balance: _address_balance(&utxo), utxo });
     }
 
+    #[cfg(feature = "cheqd")]
+    {
+        if _is_cheqd_payment_method() {
+            return _get_cheqd_address_info(address);
+        }
+    }
+
     let did = settings::get_config_value(settings::CONFIG_INSTITUTION_DID)?;
 
     let (txn, _) = payments::build_get_payment_sources_with_from_request(get_wallet_handle(), Some(&did), address, None)
@@ -320,6 +332,13 @@ pub fn pay_a_payee(price: u64, address: &str) -> VcxResult<(PaymentTxn, String)>
     trace!("pay_a_payee >>> price: {}, address {}", price, address);
     debug!("sending {} tokens to address {}", price, address);
 
+    #[cfg(feature = "cheqd")]
+    {
+        if !settings::indy_mocks_enabled() && _is_cheqd_payment_method() {
+            return _cheqd_pay_a_payee(price, address);
+        }
+    }
+
     let ledger_cost = get_action_price(CREATE_TRANSFER_ACTION, None)?;
     let (remainder, input, refund_address) = inputs(price + ledger_cost)?;
     let outputs = outputs(remainder, &refund_address, Some(address.to_string()), Some(price))?;
@@ -357,6 +376,104 @@ pub fn pay_a_payee(price: u64, address: &str) -> VcxResult<(PaymentTxn, String)>
     Ok((payment, result))
 }
 
+#[cfg(feature = "cheqd")]
+fn _is_cheqd_payment_method() -> bool {
+    settings::get_payment_method().map(|method| method == CHEQD_PAYMENT_METHOD).unwrap_or(false)
+}
+
+/// Cheqd balances are queried from the cheqd pool instead of the Indy one.
+#[cfg(feature = "cheqd")]
+fn _get_cheqd_address_info(address: &str) -> VcxResult<AddressInfo> {
+    let pool_alias = settings::get_cheqd_pool_alias()?;
+
+    let (request, payment_method) = payments::build_get_payment_sources_request(get_wallet_handle(), None, address)
+        .wait()?;
+
+    let response = cheqd_pool::abci_query(&pool_alias, &request).wait()?;
+
+    let response = payments::parse_get_payment_sources_response(&payment_method, &response)
+        .wait()?;
+
+    let utxo: Vec<UTXO> = ::serde_json::from_str(&response)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize payment sources response: {}", err)))?;
+
+    Ok(AddressInfo { address: address.to_string(), balance: _address_balance(&utxo), utxo })
+}
+
+/// Cheqd transfers are signed by libindy and broadcast to the cheqd pool.
+/// The fee is paid with gas, so no Indy auth rule price is added.
+#[cfg(feature = "cheqd")]
+fn _cheqd_pay_a_payee(price: u64, address: &str) -> VcxResult<(PaymentTxn, String)> {
+    let wallet_info: WalletInfo = get_wallet_token_info()?;
+    let (input, payer_address) = _cheqd_input(price, &wallet_info)?;
+    let outputs = outputs(0, &payer_address, Some(address.to_string()), Some(price))?;
+
+    let (inputs_json, outputs_json) = _serialize_inputs_and_outputs(&input, &outputs)?;
+
+    let pool_alias = settings::get_cheqd_pool_alias()?;
+    let (account_number, sequence_number) = _get_cheqd_account_state(&pool_alias, &payer_address)?;
+
+    let extra = json!({
+        "pool_alias": pool_alias,
+        "account_number": account_number,
+        "sequence_number": sequence_number,
+        "max_gas": settings::get_cheqd_max_gas(),
+        "max_coin_amount": settings::get_cheqd_max_fee(),
+    }).to_string();
+
+    let (request, payment_method) =
+        payments::build_payment_req(get_wallet_handle(), None, &inputs_json, &outputs_json, Some(&extra))
+            .wait()?;
+
+    let request: Value = ::serde_json::from_str(&request)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize cheqd payment request: {}", err)))?;
+    let tx = request["tx"].as_str()
+        .and_then(|tx| hex::decode(tx).ok())
+        .ok_or(VcxError::from_msg(VcxErrorKind::InvalidJson, "Cheqd payment request doesn't contain a valid transaction"))?;
+
+    let result = cheqd_pool::broadcast_tx_commit(&pool_alias, &tx).wait()?;
+
+    payments::parse_payment_response(&payment_method, &result).wait()?;
+
+    let payment = PaymentTxn::from_parts(input, outputs, price, false);
+    Ok((payment, result))
+}
+
+/// Cheqd is account based: the whole payment is made from one address which balance covers the price.
+/// Returns the single input together with the address which signs the transfer.
+#[cfg(feature = "cheqd")]
+fn _cheqd_input(cost: u64, wallet_info: &WalletInfo) -> VcxResult<(Vec<String>, String)> {
+    wallet_info.addresses.iter()
+        .filter(|address| address.balance >= cost)
+        .find_map(|address| {
+            address.utxo.iter()
+                .find_map(|utxo| utxo.source.clone())
+                .map(|source| (vec![source], address.address.clone()))
+        })
+        .ok_or_else(|| {
+            warn!("no single address in wallet has enough tokens to pay: cost: {}", cost);
+            VcxError::from_msg(VcxErrorKind::InsufficientTokenAmount, format!("No single address in wallet has enough tokens to pay: cost: {}", cost))
+        })
+}
+
+#[cfg(feature = "cheqd")]
+fn _get_cheqd_account_state(pool_alias: &str, payment_address: &str) -> VcxResult<(u64, u64)> {
+    let account_id = payment_address.rsplit(':').next().unwrap_or_default();
+
+    let request = cheqd_ledger::auth::build_query_account(account_id).wait()?;
+    let response = cheqd_pool::abci_query(pool_alias, &request).wait()?;
+    let response = cheqd_ledger::auth::parse_query_account_resp(&response).wait()?;
+
+    let response: Value = ::serde_json::from_str(&response)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize cheqd account: {}", err)))?;
+    let account = &response["account"]["base_account"];
+
+    match (account["account_number"].as_u64(), account["sequence"].as_u64()) {
+        (Some(account_number), Some(sequence)) => Ok((account_number, sequence)),
+        _ => Err(VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cheqd account {} not found", account_id)))
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct RequestInfo {
     pub price: u64,
@@ -723,6 +840,43 @@ pub mod tests {
         assert_eq!(inputs(7).err().unwrap().kind(), VcxErrorKind::InsufficientTokenAmount);
     }
 
+    #[cfg(feature = "cheqd")]
+    #[test]
+    fn test_cheqd_input_uses_single_address_covering_cost() {
+        let _setup = SetupDefaults::init();
+
+        let address_1 = "pay:cheqd:cosmos1fknpjldck6n3v2wu86arpz8xjnfc60f99ylcjd";
+        let address_2 = "pay:cheqd:cosmos1ma0t2kx2m7v4rdcgeaye4jgyv0p9q8fpd8gvyc";
+        let source_1 = "src:cheqd:cosmos1fknpjldck6n3v2wu86arpz8xjnfc60f99ylcjd";
+        let source_2 = "src:cheqd:cosmos1ma0t2kx2m7v4rdcgeaye4jgyv0p9q8fpd8gvyc";
+
+        let address_info = |address: &str, source: &str, amount: u64| AddressInfo {
+            address: address.to_string(),
+            balance: amount,
+            utxo: vec![UTXO { source: Some(source.to_string()), recipient: address.to_string(), amount, extra: Some("ncheq".to_string()) }],
+        };
+
+        let wallet_info = WalletInfo {
+            balance: 15,
+            balance_str: "15".to_string(),
+            addresses: vec![address_info(address_1, source_1, 5), address_info(address_2, source_2, 10)],
+        };
+
+        // First address covers the price
+        assert_eq!(_cheqd_input(5, &wallet_info).unwrap(), (vec![source_1.to_string()], address_1.to_string()));
+
+        // Only second address covers the price
+        assert_eq!(_cheqd_input(7, &wallet_info).unwrap(), (vec![source_2.to_string()], address_2.to_string()));
+
+        // Only payee output, no change output back to the payer
+        let (_, payer_address) = _cheqd_input(5, &wallet_info).unwrap();
+        let outputs = outputs(0, &payer_address, Some(address_2.to_string()), Some(5)).unwrap();
+        assert_eq!(outputs, vec![Output { source: None, recipient: address_2.to_string(), amount: 5, extra: None }]);
+
+        // Err - whole wallet covers the price but no single address does
+        assert_eq!(_cheqd_input(12, &wallet_info).err().unwrap().kind(), VcxErrorKind::InsufficientTokenAmount);
+    }
+
     #[test]
     fn test_gen_outputs_for_txn_fees() {
         let _setup = SetupMocks::init();
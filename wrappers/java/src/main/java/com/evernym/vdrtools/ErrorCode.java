@@ -230,6 +230,11 @@ public enum ErrorCode {
 	 */
 	LedgerNotFound(309),
 
+	/**
+	 * Transaction author agreement on the ledger differs from the one accepted for the pool.
+	 */
+	LedgerTxnAuthorAgreementChanged(310),
+
 	// Crypto errors
 
 	/**
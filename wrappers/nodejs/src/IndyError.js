@@ -44,6 +44,7 @@ var errors = {
   307: 'PoolLedgerTimeout',
   308: 'PoolIncompatibleProtocolVersion',
   309: 'LedgerNotFound',
+  310: 'LedgerTxnAuthorAgreementChanged',
   400: 'AnoncredsRevocationRegistryFullError',
   401: 'AnoncredsInvalidUserRevocId',
   404: 'AnoncredsMasterSecretDuplicateNameError',
@@ -131,6 +131,9 @@ class ErrorCode(IntEnum):
     # Item not found on ledger.
     LedgerNotFound = 309
 
+    # Transaction author agreement on the ledger differs from the one accepted for the pool.
+    LedgerTxnAuthorAgreementChanged = 310
+
     # Revocation registry is full and creation of new registry is necessary
     AnoncredsRevocationRegistryFullError = 400
 
@@ -320,6 +323,9 @@ class PoolIncompatibleProtocolVersion(IndyError):
 class LedgerNotFound(IndyError):
     """ Item not found on ledger. """
 
+class LedgerTxnAuthorAgreementChanged(IndyError):
+    """ Transaction author agreement on the ledger differs from the one accepted for the pool. """
+
 class AnoncredsRevocationRegistryFullError(IndyError):
     """ Revocation registry is full and creation of new registry is necessary """
 
@@ -410,6 +416,7 @@ def errorcode_to_exception(errorcode):
         ErrorCode.PoolLedgerTimeout: PoolLedgerTimeout,
         ErrorCode.PoolIncompatibleProtocolVersion: PoolIncompatibleProtocolVersion,
         ErrorCode.LedgerNotFound: LedgerNotFound,
+        ErrorCode.LedgerTxnAuthorAgreementChanged: LedgerTxnAuthorAgreementChanged,
         # Anoncreds Errors
         ErrorCode.AnoncredsRevocationRegistryFullError: AnoncredsRevocationRegistryFullError,
         ErrorCode.AnoncredsInvalidUserRevocId: AnoncredsInvalidUserRevocId,
@@ -19,6 +19,23 @@ extern "C" {
         cb: Option<ResponseSliceCB>,
     ) -> Error;
 
+    pub fn indy_cheqd_ledger_auth_build_tx_with_fee_granter(
+        command_handle: CommandHandle,
+        pool_alias: CString,
+        sender_public_key: CString,
+        msg_raw: BString,
+        msg_len: u32,
+        account_number: u64,
+        sequence_number: u64,
+        max_gas: u64,
+        max_coin_amount: u64,
+        max_coin_denom: CString,
+        timeout_height: u64,
+        memo: CString,
+        fee_granter: CString,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
     pub fn indy_cheqd_ledger_auth_build_query_account(
         command_handle: CommandHandle,
         address: CString,
@@ -0,0 +1,47 @@
+use crate::{BString, CString, CommandHandle, Error};
+use crate::{ResponseSliceCB, ResponseStringCB};
+
+extern "C" {
+    pub fn indy_cheqd_ledger_authz_build_msg_grant(
+        command_handle: CommandHandle,
+        granter: CString,
+        grantee: CString,
+        msg_type_url: CString,
+        expiration: i64,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_authz_parse_msg_grant_resp(
+        command_handle: CommandHandle,
+        commit_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_authz_build_msg_exec(
+        command_handle: CommandHandle,
+        grantee: CString,
+        msg_raw: BString,
+        msg_len: u32,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_authz_parse_msg_exec_resp(
+        command_handle: CommandHandle,
+        commit_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_authz_build_msg_revoke(
+        command_handle: CommandHandle,
+        granter: CString,
+        grantee: CString,
+        msg_type_url: CString,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_authz_parse_msg_revoke_resp(
+        command_handle: CommandHandle,
+        commit_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+}
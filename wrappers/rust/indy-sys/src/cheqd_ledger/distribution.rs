@@ -0,0 +1,29 @@
+use crate::{CString, CommandHandle, Error};
+use crate::{ResponseSliceCB, ResponseStringCB};
+
+extern "C" {
+    pub fn indy_cheqd_ledger_distribution_build_msg_withdraw_delegator_reward(
+        command_handle: CommandHandle,
+        delegator_address: CString,
+        validator_address: CString,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_distribution_parse_msg_withdraw_delegator_reward_resp(
+        command_handle: CommandHandle,
+        commit_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_distribution_build_query_delegation_total_rewards(
+        command_handle: CommandHandle,
+        delegator_address: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_distribution_parse_query_delegation_total_rewards_resp(
+        command_handle: CommandHandle,
+        query_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+}
@@ -0,0 +1,33 @@
+use crate::{CString, CommandHandle, Error};
+use crate::{ResponseSliceCB, ResponseStringCB};
+
+extern "C" {
+    pub fn indy_cheqd_ledger_feegrant_build_msg_grant_allowance(
+        command_handle: CommandHandle,
+        granter: CString,
+        grantee: CString,
+        spend_limit_amount: CString,
+        spend_limit_denom: CString,
+        expiration: i64,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_feegrant_parse_msg_grant_allowance_resp(
+        command_handle: CommandHandle,
+        commit_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_feegrant_build_msg_revoke_allowance(
+        command_handle: CommandHandle,
+        granter: CString,
+        grantee: CString,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_feegrant_parse_msg_revoke_allowance_resp(
+        command_handle: CommandHandle,
+        commit_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+}
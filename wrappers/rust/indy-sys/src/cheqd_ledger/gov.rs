@@ -0,0 +1,124 @@
+use crate::{CString, CommandHandle, Error};
+use crate::{ResponseSliceCB, ResponseStringCB};
+
+extern "C" {
+    pub fn indy_cheqd_ledger_gov_build_msg_submit_text_proposal(
+        command_handle: CommandHandle,
+        title: CString,
+        description: CString,
+        proposer: CString,
+        deposit_amount: CString,
+        deposit_denom: CString,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_gov_build_msg_submit_param_change_proposal(
+        command_handle: CommandHandle,
+        title: CString,
+        description: CString,
+        changes: CString,
+        proposer: CString,
+        deposit_amount: CString,
+        deposit_denom: CString,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_gov_parse_msg_submit_proposal_resp(
+        command_handle: CommandHandle,
+        commit_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_gov_build_msg_deposit(
+        command_handle: CommandHandle,
+        proposal_id: u64,
+        depositor: CString,
+        amount: CString,
+        denom: CString,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_gov_parse_msg_deposit_resp(
+        command_handle: CommandHandle,
+        commit_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_gov_build_msg_vote(
+        command_handle: CommandHandle,
+        proposal_id: u64,
+        voter: CString,
+        option: CString,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_gov_parse_msg_vote_resp(
+        command_handle: CommandHandle,
+        commit_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_gov_build_msg_vote_weighted(
+        command_handle: CommandHandle,
+        proposal_id: u64,
+        voter: CString,
+        options: CString,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_gov_parse_msg_vote_weighted_resp(
+        command_handle: CommandHandle,
+        commit_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_gov_build_query_proposal(
+        command_handle: CommandHandle,
+        proposal_id: u64,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_gov_parse_query_proposal_resp(
+        command_handle: CommandHandle,
+        query_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_gov_build_query_proposals(
+        command_handle: CommandHandle,
+        status: CString,
+        pagination: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_gov_parse_query_proposals_resp(
+        command_handle: CommandHandle,
+        query_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_gov_build_query_tally_result(
+        command_handle: CommandHandle,
+        proposal_id: u64,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_gov_parse_query_tally_result_resp(
+        command_handle: CommandHandle,
+        query_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_gov_build_query_vote(
+        command_handle: CommandHandle,
+        proposal_id: u64,
+        voter: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_gov_parse_query_vote_resp(
+        command_handle: CommandHandle,
+        query_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+}
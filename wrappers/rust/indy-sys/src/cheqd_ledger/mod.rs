@@ -4,3 +4,13 @@ pub mod auth;
 pub mod cheqd;
 #[cfg(feature = "cheqd")]
 pub mod bank;
+#[cfg(feature = "cheqd")]
+pub mod staking;
+#[cfg(feature = "cheqd")]
+pub mod distribution;
+#[cfg(feature = "cheqd")]
+pub mod gov;
+#[cfg(feature = "cheqd")]
+pub mod feegrant;
+#[cfg(feature = "cheqd")]
+pub mod authz;
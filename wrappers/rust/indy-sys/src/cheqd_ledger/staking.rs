@@ -0,0 +1,89 @@
+use crate::{CString, CommandHandle, Error};
+use crate::{ResponseSliceCB, ResponseStringCB};
+
+extern "C" {
+    pub fn indy_cheqd_ledger_staking_build_msg_delegate(
+        command_handle: CommandHandle,
+        delegator_address: CString,
+        validator_address: CString,
+        amount: CString,
+        denom: CString,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_staking_parse_msg_delegate_resp(
+        command_handle: CommandHandle,
+        commit_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_staking_build_msg_undelegate(
+        command_handle: CommandHandle,
+        delegator_address: CString,
+        validator_address: CString,
+        amount: CString,
+        denom: CString,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_staking_parse_msg_undelegate_resp(
+        command_handle: CommandHandle,
+        commit_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_staking_build_msg_begin_redelegate(
+        command_handle: CommandHandle,
+        delegator_address: CString,
+        validator_src_address: CString,
+        validator_dst_address: CString,
+        amount: CString,
+        denom: CString,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_staking_parse_msg_begin_redelegate_resp(
+        command_handle: CommandHandle,
+        commit_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_staking_build_query_delegation(
+        command_handle: CommandHandle,
+        delegator_address: CString,
+        validator_address: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_staking_parse_query_delegation_resp(
+        command_handle: CommandHandle,
+        query_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_staking_build_query_delegator_delegations(
+        command_handle: CommandHandle,
+        delegator_address: CString,
+        pagination: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_staking_parse_query_delegator_delegations_resp(
+        command_handle: CommandHandle,
+        query_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_staking_build_query_validators(
+        command_handle: CommandHandle,
+        status: CString,
+        pagination: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_staking_parse_query_validators_resp(
+        command_handle: CommandHandle,
+        query_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+}
@@ -42,4 +42,39 @@ extern "C" {
         pool_alias: CString,
         cb: Option<ResponseStringCB>,
     ) -> Error;
+
+    pub fn indy_cheqd_pool_status(
+        command_handle: CommandHandle,
+        pool_alias: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_pool_block(
+        command_handle: CommandHandle,
+        pool_alias: CString,
+        height: i64,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_pool_block_results(
+        command_handle: CommandHandle,
+        pool_alias: CString,
+        height: i64,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_pool_validators(
+        command_handle: CommandHandle,
+        pool_alias: CString,
+        height: i64,
+        page: i64,
+        per_page: i64,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_pool_net_info(
+        command_handle: CommandHandle,
+        pool_alias: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
 }
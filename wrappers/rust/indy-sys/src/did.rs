@@ -64,6 +64,13 @@ extern "C" {
         cb: Option<ResponseStringStringCB>,
     ) -> Error;
 
+    pub fn indy_resolve_did(
+        command_handle: CommandHandle,
+        pool_handle: PoolHandle,
+        did: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
     pub fn indy_set_did_metadata(
         command_handle: CommandHandle,
         wallet_handle: WalletHandle,
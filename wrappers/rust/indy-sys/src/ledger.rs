@@ -19,6 +19,14 @@ extern "C" {
         cb: Option<ResponseStringCB>,
     ) -> Error;
 
+    pub fn indy_submit_requests(
+        command_handle: CommandHandle,
+        pool_handle: PoolHandle,
+        requests_json: CString,
+        options_json: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
     pub fn indy_submit_action(
         command_handle: CommandHandle,
         pool_handle: PoolHandle,
@@ -28,6 +36,14 @@ extern "C" {
         cb: Option<ResponseStringCB>,
     ) -> Error;
 
+    pub fn indy_download_ledger(
+        command_handle: CommandHandle,
+        pool_handle: PoolHandle,
+        ledger_type: CString,
+        config: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
     pub fn indy_sign_request(
         command_handle: CommandHandle,
         wallet_handle: WalletHandle,
@@ -148,6 +164,12 @@ extern "C" {
         cb: Option<ResponseStringCB>,
     ) -> Error;
 
+    pub fn indy_parse_get_validator_info_response(
+        command_handle: CommandHandle,
+        get_validator_info_response: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
     pub fn indy_build_get_txn_request(
         command_handle: CommandHandle,
         submitter_did: CString,
@@ -246,11 +268,13 @@ extern "C" {
         cb: Option<ResponseStringStringU64CB>,
     ) -> Error;
 
-    //    pub fn indy_register_transaction_parser_for_sp(command_handle: CommandHandle,
-//                                                   txn_type: CString,
-//                                                   parser: Option<CustomTransactionParser>,
-//                                                   free: Option<CustomFree>,
-//                                                   cb: Option<ResponseEmptyCB>) -> Error;
+    pub fn indy_register_transaction_parser_for_sp(
+        command_handle: CommandHandle,
+        txn_type: CString,
+        parser: Option<CustomTransactionParser>,
+        free: Option<CustomFree>,
+        cb: Option<ResponseEmptyCB>,
+    ) -> Error;
 
     pub fn indy_get_response_metadata(
         command_handle: CommandHandle,
@@ -288,6 +312,15 @@ extern "C" {
         cb: Option<ResponseStringCB>,
     ) -> Error;
 
+    pub fn indy_evaluate_auth_rule(
+        command_handle: CommandHandle,
+        get_auth_rule_response: CString,
+        action_json: CString,
+        request_json: CString,
+        signers_json: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
     pub fn indy_build_txn_author_agreement_request(
         command_handle: CommandHandle,
         submitter_did: CString,
@@ -339,12 +372,120 @@ extern "C" {
         cb: Option<ResponseStringCB>,
     ) -> Error;
 
+    pub fn indy_set_pool_txn_author_agreement_acceptance(
+        command_handle: CommandHandle,
+        pool_handle: PoolHandle,
+        text: CString,
+        version: CString,
+        hash: CString,
+        acc_mech_type: CString,
+        time_of_acceptance: u64,
+        cb: Option<ResponseEmptyCB>,
+    ) -> Error;
+
     pub fn indy_append_request_endorser(
         command_handle: CommandHandle,
         request_json: CString,
         endorser_did: CString,
         cb: Option<ResponseStringCB>,
     ) -> Error;
+
+    pub fn indy_build_endorsement_envelope(
+        command_handle: CommandHandle,
+        wallet_handle: WalletHandle,
+        request_json: CString,
+        endorser_did: CString,
+        options_json: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_sign_endorsement_envelope(
+        command_handle: CommandHandle,
+        wallet_handle: WalletHandle,
+        envelope_json: CString,
+        signer_did: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_check_endorsement_envelope(
+        command_handle: CommandHandle,
+        envelope_json: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_submit_endorsement_envelope(
+        command_handle: CommandHandle,
+        pool_handle: PoolHandle,
+        wallet_handle: WalletHandle,
+        envelope_json: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_list_pending_endorsements(
+        command_handle: CommandHandle,
+        wallet_handle: WalletHandle,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_delete_pending_endorsement(
+        command_handle: CommandHandle,
+        wallet_handle: WalletHandle,
+        id: CString,
+        cb: Option<ResponseEmptyCB>,
+    ) -> Error;
+
+    pub fn indy_build_ledgers_freeze_request(
+        command_handle: CommandHandle,
+        submitter_did: CString,
+        ledgers_ids: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_build_get_frozen_ledgers_request(
+        command_handle: CommandHandle,
+        submitter_did: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_parse_get_frozen_ledgers_response(
+        command_handle: CommandHandle,
+        get_frozen_ledgers_response: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_build_rich_schema_request(
+        command_handle: CommandHandle,
+        submitter_did: CString,
+        rs_id: CString,
+        rs_content: CString,
+        rs_name: CString,
+        rs_version: CString,
+        rs_type: CString,
+        ver: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_build_get_rich_schema_object_by_id_request(
+        command_handle: CommandHandle,
+        submitter_did: CString,
+        rs_id: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_build_get_rich_schema_object_by_metadata_request(
+        command_handle: CommandHandle,
+        submitter_did: CString,
+        rs_type: CString,
+        rs_name: CString,
+        rs_version: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_parse_get_rich_schema_object_response(
+        command_handle: CommandHandle,
+        get_rich_schema_object_response: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
 }
 
 pub type CustomTransactionParser =
@@ -1,6 +1,6 @@
 use super::*;
 
-use crate::{CString, CommandHandle, Error, PoolHandle};
+use crate::{CString, CommandHandle, Error, PoolHandle, WalletHandle};
 
 extern "C" {
 
@@ -11,6 +11,13 @@ extern "C" {
         cb: Option<ResponseEmptyCB>,
     ) -> Error;
 
+    pub fn indy_create_simulated_pool(
+        command_handle: CommandHandle,
+        config_name: CString,
+        config: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
     pub fn indy_open_pool_ledger(
         command_handle: CommandHandle,
         config_name: CString,
@@ -24,6 +31,21 @@ extern "C" {
         cb: Option<ResponseEmptyCB>,
     ) -> Error;
 
+    pub fn indy_get_pool_status(
+        command_handle: CommandHandle,
+        handle: PoolHandle,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_verify_ledger_reply(
+        command_handle: CommandHandle,
+        pool_name: CString,
+        genesis_txns: CString,
+        request_json: CString,
+        reply_json: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
     pub fn indy_list_pools(command_handle: CommandHandle, cb: Option<ResponseStringCB>) -> Error;
 
     pub fn indy_close_pool_ledger(
@@ -38,9 +60,30 @@ extern "C" {
         cb: Option<ResponseEmptyCB>,
     ) -> Error;
 
+    pub fn indy_update_pool_ledger_genesis(
+        command_handle: CommandHandle,
+        config_name: CString,
+        config: CString,
+        cb: Option<ResponseEmptyCB>,
+    ) -> Error;
+
+    pub fn indy_export_pool_snapshot(
+        command_handle: CommandHandle,
+        wallet_handle: WalletHandle,
+        config_name: CString,
+        export_config: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
     pub fn indy_set_protocol_version(
         command_handle: CommandHandle,
         protocol_version: usize,
         cb: Option<ResponseEmptyCB>,
     ) -> Error;
+
+    pub fn indy_get_pool_handle_for_namespace(
+        command_handle: CommandHandle,
+        namespace: CString,
+        cb: Option<ResponseI32CB>,
+    ) -> Error;
 }
@@ -7,7 +7,7 @@ use std::ffi::CString;
 use crate::utils::callbacks::{ClosureHandler, ResultHandler};
 
 use crate::ffi::cache;
-use crate::ffi::{ResponseEmptyCB, ResponseStringCB};
+use crate::ffi::{ResponseEmptyCB, ResponseStringCB, ResponseStringStringU64CB};
 use crate::{WalletHandle, CommandHandle, PoolHandle};
 
 /// Get schema json data for specified schema id.
@@ -176,4 +176,236 @@ fn _purge_cred_def_cache(command_handle: CommandHandle, wallet_handle: WalletHan
     let options_json = c_str!(options_json);
 
     ErrorCode::from(unsafe { cache::indy_purge_cred_def_cache(command_handle, wallet_handle, options_json.as_ptr(), cb) })
-}
\ No newline at end of file
+}
+
+/// Gets NYM data json for specified DID.
+/// If data is present inside of cache, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `submitter_did` - DID of the submitter stored in secured Wallet.
+/// * `did` - DID to get NYM data for.
+/// * `options_json` -
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+///
+/// # Returns
+/// NYM data json as returned by parse_get_nym_response.
+pub fn get_nym(pool_handle: PoolHandle,
+               wallet_handle: WalletHandle,
+               submitter_did: &str,
+               did: &str,
+               options_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_nym(command_handle, pool_handle, wallet_handle, submitter_did, did, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_nym(command_handle: CommandHandle,
+            pool_handle: PoolHandle,
+            wallet_handle: WalletHandle,
+            submitter_did: &str,
+            did: &str,
+            options_json: &str,
+            cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let did = c_str!(did);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(
+        unsafe {
+            cache::indy_get_nym(command_handle, pool_handle, wallet_handle, submitter_did.as_ptr(), did.as_ptr(), options_json.as_ptr(), cb)
+        }
+    )
+}
+
+/// Gets revocation registry definition json data for specified revocation registry definition id.
+/// If data is present inside of cache, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `submitter_did` - DID of the submitter stored in secured Wallet.
+/// * `id` - identifier of revocation registry definition.
+/// * `options_json` -
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+///
+/// # Returns
+/// Revocation Registry Definition json.
+pub fn get_revoc_reg_def(pool_handle: PoolHandle,
+                         wallet_handle: WalletHandle,
+                         submitter_did: &str,
+                         id: &str,
+                         options_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_revoc_reg_def(command_handle, pool_handle, wallet_handle, submitter_did, id, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_revoc_reg_def(command_handle: CommandHandle,
+                      pool_handle: PoolHandle,
+                      wallet_handle: WalletHandle,
+                      submitter_did: &str,
+                      id: &str,
+                      options_json: &str,
+                      cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let id = c_str!(id);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(
+        unsafe {
+            cache::indy_get_revoc_reg_def(command_handle, pool_handle, wallet_handle, submitter_did.as_ptr(), id.as_ptr(), options_json.as_ptr(), cb)
+        }
+    )
+}
+
+/// Gets revocation registry delta for specified revocation registry definition id and interval.
+/// If data is present inside of cache, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `submitter_did` - DID of the submitter stored in secured Wallet.
+/// * `revoc_reg_def_id` - ID of the corresponding Revocation Registry Definition in ledger.
+/// * `from` - Requested time represented as a total number of seconds from Unix Epoch.
+///   Pass -1 to get the whole state till `to`.
+/// * `to` - Requested time represented as a total number of seconds from Unix Epoch.
+/// * `options_json` -
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+///
+/// # Returns
+/// Revocation Registry Definition Id, Revocation Registry Delta json and Timestamp.
+pub fn get_revoc_reg_delta(pool_handle: PoolHandle,
+                           wallet_handle: WalletHandle,
+                           submitter_did: &str,
+                           revoc_reg_def_id: &str,
+                           from: i64,
+                           to: i64,
+                           options_json: &str) -> Box<dyn Future<Item=(String, String, u64), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string_u64();
+
+    let err = _get_revoc_reg_delta(command_handle, pool_handle, wallet_handle, submitter_did, revoc_reg_def_id, from, to, options_json, cb);
+
+    ResultHandler::str_str_u64(command_handle, err, receiver)
+}
+
+fn _get_revoc_reg_delta(command_handle: CommandHandle,
+                        pool_handle: PoolHandle,
+                        wallet_handle: WalletHandle,
+                        submitter_did: &str,
+                        revoc_reg_def_id: &str,
+                        from: i64,
+                        to: i64,
+                        options_json: &str,
+                        cb: Option<ResponseStringStringU64CB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let revoc_reg_def_id = c_str!(revoc_reg_def_id);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(
+        unsafe {
+            cache::indy_get_revoc_reg_delta(command_handle, pool_handle, wallet_handle, submitter_did.as_ptr(), revoc_reg_def_id.as_ptr(), from, to, options_json.as_ptr(), cb)
+        }
+    )
+}
+
+/// Purge NYM cache.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `options_json` -
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+pub fn purge_nym_cache(wallet_handle: WalletHandle, options_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _purge_nym_cache(command_handle, wallet_handle, options_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _purge_nym_cache(command_handle: CommandHandle, wallet_handle: WalletHandle, options_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe { cache::indy_purge_nym_cache(command_handle, wallet_handle, options_json.as_ptr(), cb) })
+}
+
+/// Purge revocation registry definition cache.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `options_json` -
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+pub fn purge_revoc_reg_def_cache(wallet_handle: WalletHandle, options_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _purge_revoc_reg_def_cache(command_handle, wallet_handle, options_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _purge_revoc_reg_def_cache(command_handle: CommandHandle, wallet_handle: WalletHandle, options_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe { cache::indy_purge_revoc_reg_def_cache(command_handle, wallet_handle, options_json.as_ptr(), cb) })
+}
+
+/// Purge revocation registry delta cache.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `options_json` -
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+pub fn purge_revoc_reg_delta_cache(wallet_handle: WalletHandle, options_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _purge_revoc_reg_delta_cache(command_handle, wallet_handle, options_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _purge_revoc_reg_delta_cache(command_handle: CommandHandle, wallet_handle: WalletHandle, options_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe { cache::indy_purge_revoc_reg_delta_cache(command_handle, wallet_handle, options_json.as_ptr(), cb) })
+}
@@ -77,6 +77,81 @@ fn _build_tx(
     })
 }
 
+pub fn build_tx_with_fee_granter(
+    pool_alias: &str,
+    sender_public_key: &str,
+    msg: &[u8],
+    account_number: u64,
+    sequence_number: u64,
+    max_gas: u64,
+    max_coin_amount: u64,
+    max_coin_denom: &str,
+    timeout_height: u64,
+    memo: &str,
+    fee_granter: &str,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_tx_with_fee_granter(
+        command_handle,
+        pool_alias,
+        sender_public_key,
+        msg,
+        account_number,
+        sequence_number,
+        max_gas,
+        max_coin_amount,
+        max_coin_denom,
+        timeout_height,
+        memo,
+        fee_granter,
+        cb,
+    );
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_tx_with_fee_granter(
+    command_handle: CommandHandle,
+    pool_alias: &str,
+    sender_public_key: &str,
+    msg: &[u8],
+    account_number: u64,
+    sequence_number: u64,
+    max_gas: u64,
+    max_coin_amount: u64,
+    max_coin_denom: &str,
+    timeout_height: u64,
+    memo: &str,
+    fee_granter: &str,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let pool_alias = c_str!(pool_alias);
+    let sender_public_key = c_str!(sender_public_key);
+    let max_coin_denom = c_str!(max_coin_denom);
+    let memo = c_str!(memo);
+    let fee_granter = c_str!(fee_granter);
+
+    ErrorCode::from(unsafe {
+        auth::indy_cheqd_ledger_auth_build_tx_with_fee_granter(
+            command_handle,
+            pool_alias.as_ptr(),
+            sender_public_key.as_ptr(),
+            msg.as_ptr() as *const u8,
+            msg.len() as u32,
+            account_number,
+            sequence_number,
+            max_gas,
+            max_coin_amount,
+            max_coin_denom.as_ptr(),
+            timeout_height,
+            memo.as_ptr(),
+            fee_granter.as_ptr(),
+            cb,
+        )
+    })
+}
+
 pub fn build_query_account(address: &str) -> Box<dyn Future<Item = String, Error = IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
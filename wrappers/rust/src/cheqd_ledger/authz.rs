@@ -0,0 +1,190 @@
+use crate::{ErrorCode, IndyError};
+
+use std::ffi::CString;
+
+use futures::Future;
+
+use crate::ffi::cheqd_ledger;
+use crate::ffi::{ResponseSliceCB, ResponseStringCB};
+
+use crate::utils::callbacks::{ClosureHandler, ResultHandler};
+use crate::CommandHandle;
+
+pub fn build_msg_grant(
+    granter: &str,
+    grantee: &str,
+    msg_type_url: &str,
+    expiration: i64,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_msg_grant(command_handle, granter, grantee, msg_type_url, expiration, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_msg_grant(
+    command_handle: CommandHandle,
+    granter: &str,
+    grantee: &str,
+    msg_type_url: &str,
+    expiration: i64,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let granter = c_str!(granter);
+    let grantee = c_str!(grantee);
+    let msg_type_url = c_str!(msg_type_url);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::authz::indy_cheqd_ledger_authz_build_msg_grant(
+            command_handle,
+            granter.as_ptr(),
+            grantee.as_ptr(),
+            msg_type_url.as_ptr(),
+            expiration,
+            cb,
+        )
+    })
+}
+
+pub fn parse_msg_grant_resp(
+    commit_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_msg_grant_resp(command_handle, commit_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_msg_grant_resp(
+    command_handle: CommandHandle,
+    commit_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let commit_resp = c_str!(commit_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::authz::indy_cheqd_ledger_authz_parse_msg_grant_resp(
+            command_handle,
+            commit_resp.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_msg_exec(
+    grantee: &str,
+    msg: &[u8],
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_msg_exec(command_handle, grantee, msg, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_msg_exec(
+    command_handle: CommandHandle,
+    grantee: &str,
+    msg: &[u8],
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let grantee = c_str!(grantee);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::authz::indy_cheqd_ledger_authz_build_msg_exec(
+            command_handle,
+            grantee.as_ptr(),
+            msg.as_ptr() as *const u8,
+            msg.len() as u32,
+            cb,
+        )
+    })
+}
+
+pub fn parse_msg_exec_resp(
+    commit_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_msg_exec_resp(command_handle, commit_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_msg_exec_resp(
+    command_handle: CommandHandle,
+    commit_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let commit_resp = c_str!(commit_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::authz::indy_cheqd_ledger_authz_parse_msg_exec_resp(
+            command_handle,
+            commit_resp.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_msg_revoke(
+    granter: &str,
+    grantee: &str,
+    msg_type_url: &str,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_msg_revoke(command_handle, granter, grantee, msg_type_url, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_msg_revoke(
+    command_handle: CommandHandle,
+    granter: &str,
+    grantee: &str,
+    msg_type_url: &str,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let granter = c_str!(granter);
+    let grantee = c_str!(grantee);
+    let msg_type_url = c_str!(msg_type_url);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::authz::indy_cheqd_ledger_authz_build_msg_revoke(
+            command_handle,
+            granter.as_ptr(),
+            grantee.as_ptr(),
+            msg_type_url.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn parse_msg_revoke_resp(
+    commit_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_msg_revoke_resp(command_handle, commit_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_msg_revoke_resp(
+    command_handle: CommandHandle,
+    commit_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let commit_resp = c_str!(commit_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::authz::indy_cheqd_ledger_authz_parse_msg_revoke_resp(
+            command_handle,
+            commit_resp.as_ptr(),
+            cb,
+        )
+    })
+}
@@ -0,0 +1,119 @@
+use crate::{ErrorCode, IndyError};
+
+use std::ffi::CString;
+
+use futures::Future;
+
+use crate::ffi::cheqd_ledger;
+use crate::ffi::{ResponseSliceCB, ResponseStringCB};
+
+use crate::utils::callbacks::{ClosureHandler, ResultHandler};
+use crate::CommandHandle;
+
+pub fn build_msg_withdraw_delegator_reward(
+    delegator_address: &str,
+    validator_address: &str,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_msg_withdraw_delegator_reward(command_handle, delegator_address, validator_address, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_msg_withdraw_delegator_reward(
+    command_handle: CommandHandle,
+    delegator_address: &str,
+    validator_address: &str,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let delegator_address = c_str!(delegator_address);
+    let validator_address = c_str!(validator_address);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::distribution::indy_cheqd_ledger_distribution_build_msg_withdraw_delegator_reward(
+            command_handle,
+            delegator_address.as_ptr(),
+            validator_address.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn parse_msg_withdraw_delegator_reward_resp(
+    commit_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_msg_withdraw_delegator_reward_resp(command_handle, commit_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_msg_withdraw_delegator_reward_resp(
+    command_handle: CommandHandle,
+    commit_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let commit_resp = c_str!(commit_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::distribution::indy_cheqd_ledger_distribution_parse_msg_withdraw_delegator_reward_resp(
+            command_handle,
+            commit_resp.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_query_delegation_total_rewards(
+    delegator_address: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_query_delegation_total_rewards(command_handle, delegator_address, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_query_delegation_total_rewards(
+    command_handle: CommandHandle,
+    delegator_address: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let delegator_address = c_str!(delegator_address);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::distribution::indy_cheqd_ledger_distribution_build_query_delegation_total_rewards(
+            command_handle,
+            delegator_address.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn parse_query_delegation_total_rewards_resp(
+    query_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_query_delegation_total_rewards_resp(command_handle, query_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_query_delegation_total_rewards_resp(
+    command_handle: CommandHandle,
+    query_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let query_resp = c_str!(query_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::distribution::indy_cheqd_ledger_distribution_parse_query_delegation_total_rewards_resp(
+            command_handle,
+            query_resp.as_ptr(),
+            cb,
+        )
+    })
+}
@@ -0,0 +1,135 @@
+use crate::{ErrorCode, IndyError};
+
+use std::ffi::CString;
+use std::ptr::null;
+
+use futures::Future;
+
+use crate::ffi::cheqd_ledger;
+use crate::ffi::{ResponseSliceCB, ResponseStringCB};
+
+use crate::utils::callbacks::{ClosureHandler, ResultHandler};
+use crate::CommandHandle;
+
+pub fn build_msg_grant_allowance(
+    granter: &str,
+    grantee: &str,
+    spend_limit_amount: Option<&str>,
+    spend_limit_denom: Option<&str>,
+    expiration: i64,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_msg_grant_allowance(command_handle, granter, grantee, spend_limit_amount, spend_limit_denom, expiration, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_msg_grant_allowance(
+    command_handle: CommandHandle,
+    granter: &str,
+    grantee: &str,
+    spend_limit_amount: Option<&str>,
+    spend_limit_denom: Option<&str>,
+    expiration: i64,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let granter = c_str!(granter);
+    let grantee = c_str!(grantee);
+    let spend_limit_amount_str = opt_c_str!(spend_limit_amount);
+    let spend_limit_denom_str = opt_c_str!(spend_limit_denom);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::feegrant::indy_cheqd_ledger_feegrant_build_msg_grant_allowance(
+            command_handle,
+            granter.as_ptr(),
+            grantee.as_ptr(),
+            opt_c_ptr!(spend_limit_amount, spend_limit_amount_str),
+            opt_c_ptr!(spend_limit_denom, spend_limit_denom_str),
+            expiration,
+            cb,
+        )
+    })
+}
+
+pub fn parse_msg_grant_allowance_resp(
+    commit_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_msg_grant_allowance_resp(command_handle, commit_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_msg_grant_allowance_resp(
+    command_handle: CommandHandle,
+    commit_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let commit_resp = c_str!(commit_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::feegrant::indy_cheqd_ledger_feegrant_parse_msg_grant_allowance_resp(
+            command_handle,
+            commit_resp.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_msg_revoke_allowance(
+    granter: &str,
+    grantee: &str,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_msg_revoke_allowance(command_handle, granter, grantee, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_msg_revoke_allowance(
+    command_handle: CommandHandle,
+    granter: &str,
+    grantee: &str,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let granter = c_str!(granter);
+    let grantee = c_str!(grantee);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::feegrant::indy_cheqd_ledger_feegrant_build_msg_revoke_allowance(
+            command_handle,
+            granter.as_ptr(),
+            grantee.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn parse_msg_revoke_allowance_resp(
+    commit_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_msg_revoke_allowance_resp(command_handle, commit_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_msg_revoke_allowance_resp(
+    command_handle: CommandHandle,
+    commit_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let commit_resp = c_str!(commit_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::feegrant::indy_cheqd_ledger_feegrant_parse_msg_revoke_allowance_resp(
+            command_handle,
+            commit_resp.as_ptr(),
+            cb,
+        )
+    })
+}
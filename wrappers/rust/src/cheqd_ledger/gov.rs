@@ -0,0 +1,518 @@
+use crate::{ErrorCode, IndyError};
+
+use std::ffi::CString;
+use std::ptr::null;
+
+use futures::Future;
+
+use crate::ffi::cheqd_ledger;
+use crate::ffi::{ResponseSliceCB, ResponseStringCB};
+
+use crate::utils::callbacks::{ClosureHandler, ResultHandler};
+use crate::CommandHandle;
+
+pub fn build_msg_submit_text_proposal(
+    title: &str,
+    description: &str,
+    proposer: &str,
+    deposit_amount: &str,
+    deposit_denom: &str,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_msg_submit_text_proposal(command_handle, title, description, proposer, deposit_amount, deposit_denom, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_msg_submit_text_proposal(
+    command_handle: CommandHandle,
+    title: &str,
+    description: &str,
+    proposer: &str,
+    deposit_amount: &str,
+    deposit_denom: &str,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let title = c_str!(title);
+    let description = c_str!(description);
+    let proposer = c_str!(proposer);
+    let deposit_amount = c_str!(deposit_amount);
+    let deposit_denom = c_str!(deposit_denom);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_build_msg_submit_text_proposal(
+            command_handle,
+            title.as_ptr(),
+            description.as_ptr(),
+            proposer.as_ptr(),
+            deposit_amount.as_ptr(),
+            deposit_denom.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_msg_submit_param_change_proposal(
+    title: &str,
+    description: &str,
+    changes: &str,
+    proposer: &str,
+    deposit_amount: &str,
+    deposit_denom: &str,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_msg_submit_param_change_proposal(command_handle, title, description, changes, proposer, deposit_amount, deposit_denom, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_msg_submit_param_change_proposal(
+    command_handle: CommandHandle,
+    title: &str,
+    description: &str,
+    changes: &str,
+    proposer: &str,
+    deposit_amount: &str,
+    deposit_denom: &str,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let title = c_str!(title);
+    let description = c_str!(description);
+    let changes = c_str!(changes);
+    let proposer = c_str!(proposer);
+    let deposit_amount = c_str!(deposit_amount);
+    let deposit_denom = c_str!(deposit_denom);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_build_msg_submit_param_change_proposal(
+            command_handle,
+            title.as_ptr(),
+            description.as_ptr(),
+            changes.as_ptr(),
+            proposer.as_ptr(),
+            deposit_amount.as_ptr(),
+            deposit_denom.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn parse_msg_submit_proposal_resp(
+    commit_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_msg_submit_proposal_resp(command_handle, commit_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_msg_submit_proposal_resp(
+    command_handle: CommandHandle,
+    commit_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let commit_resp = c_str!(commit_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_parse_msg_submit_proposal_resp(
+            command_handle,
+            commit_resp.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_msg_deposit(
+    proposal_id: u64,
+    depositor: &str,
+    amount: &str,
+    denom: &str,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_msg_deposit(command_handle, proposal_id, depositor, amount, denom, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_msg_deposit(
+    command_handle: CommandHandle,
+    proposal_id: u64,
+    depositor: &str,
+    amount: &str,
+    denom: &str,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let depositor = c_str!(depositor);
+    let amount = c_str!(amount);
+    let denom = c_str!(denom);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_build_msg_deposit(
+            command_handle,
+            proposal_id,
+            depositor.as_ptr(),
+            amount.as_ptr(),
+            denom.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn parse_msg_deposit_resp(
+    commit_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_msg_deposit_resp(command_handle, commit_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_msg_deposit_resp(
+    command_handle: CommandHandle,
+    commit_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let commit_resp = c_str!(commit_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_parse_msg_deposit_resp(
+            command_handle,
+            commit_resp.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_msg_vote(
+    proposal_id: u64,
+    voter: &str,
+    option: &str,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_msg_vote(command_handle, proposal_id, voter, option, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_msg_vote(
+    command_handle: CommandHandle,
+    proposal_id: u64,
+    voter: &str,
+    option: &str,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let voter = c_str!(voter);
+    let option = c_str!(option);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_build_msg_vote(
+            command_handle,
+            proposal_id,
+            voter.as_ptr(),
+            option.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn parse_msg_vote_resp(
+    commit_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_msg_vote_resp(command_handle, commit_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_msg_vote_resp(
+    command_handle: CommandHandle,
+    commit_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let commit_resp = c_str!(commit_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_parse_msg_vote_resp(
+            command_handle,
+            commit_resp.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_msg_vote_weighted(
+    proposal_id: u64,
+    voter: &str,
+    options: &str,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_msg_vote_weighted(command_handle, proposal_id, voter, options, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_msg_vote_weighted(
+    command_handle: CommandHandle,
+    proposal_id: u64,
+    voter: &str,
+    options: &str,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let voter = c_str!(voter);
+    let options = c_str!(options);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_build_msg_vote_weighted(
+            command_handle,
+            proposal_id,
+            voter.as_ptr(),
+            options.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn parse_msg_vote_weighted_resp(
+    commit_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_msg_vote_weighted_resp(command_handle, commit_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_msg_vote_weighted_resp(
+    command_handle: CommandHandle,
+    commit_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let commit_resp = c_str!(commit_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_parse_msg_vote_weighted_resp(
+            command_handle,
+            commit_resp.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_query_proposal(
+    proposal_id: u64,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_query_proposal(command_handle, proposal_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_query_proposal(
+    command_handle: CommandHandle,
+    proposal_id: u64,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_build_query_proposal(
+            command_handle,
+            proposal_id,
+            cb,
+        )
+    })
+}
+
+pub fn parse_query_proposal_resp(
+    query_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_query_proposal_resp(command_handle, query_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_query_proposal_resp(
+    command_handle: CommandHandle,
+    query_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let query_resp = c_str!(query_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_parse_query_proposal_resp(
+            command_handle,
+            query_resp.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_query_proposals(
+    status: Option<&str>,
+    pagination: Option<&str>,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_query_proposals(command_handle, status, pagination, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_query_proposals(
+    command_handle: CommandHandle,
+    status: Option<&str>,
+    pagination: Option<&str>,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let status_str = opt_c_str!(status);
+    let pagination_str = opt_c_str!(pagination);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_build_query_proposals(
+            command_handle,
+            opt_c_ptr!(status, status_str),
+            opt_c_ptr!(pagination, pagination_str),
+            cb,
+        )
+    })
+}
+
+pub fn parse_query_proposals_resp(
+    query_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_query_proposals_resp(command_handle, query_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_query_proposals_resp(
+    command_handle: CommandHandle,
+    query_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let query_resp = c_str!(query_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_parse_query_proposals_resp(
+            command_handle,
+            query_resp.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_query_tally_result(
+    proposal_id: u64,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_query_tally_result(command_handle, proposal_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_query_tally_result(
+    command_handle: CommandHandle,
+    proposal_id: u64,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_build_query_tally_result(
+            command_handle,
+            proposal_id,
+            cb,
+        )
+    })
+}
+
+pub fn parse_query_tally_result_resp(
+    query_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_query_tally_result_resp(command_handle, query_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_query_tally_result_resp(
+    command_handle: CommandHandle,
+    query_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let query_resp = c_str!(query_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_parse_query_tally_result_resp(
+            command_handle,
+            query_resp.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_query_vote(
+    proposal_id: u64,
+    voter: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_query_vote(command_handle, proposal_id, voter, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_query_vote(
+    command_handle: CommandHandle,
+    proposal_id: u64,
+    voter: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let voter = c_str!(voter);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_build_query_vote(
+            command_handle,
+            proposal_id,
+            voter.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn parse_query_vote_resp(
+    query_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_query_vote_resp(command_handle, query_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_query_vote_resp(
+    command_handle: CommandHandle,
+    query_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let query_resp = c_str!(query_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::gov::indy_cheqd_ledger_gov_parse_query_vote_resp(
+            command_handle,
+            query_resp.as_ptr(),
+            cb,
+        )
+    })
+}
@@ -0,0 +1,376 @@
+use crate::{ErrorCode, IndyError};
+
+use std::ffi::CString;
+use std::ptr::null;
+
+use futures::Future;
+
+use crate::ffi::cheqd_ledger;
+use crate::ffi::{ResponseSliceCB, ResponseStringCB};
+
+use crate::utils::callbacks::{ClosureHandler, ResultHandler};
+use crate::CommandHandle;
+
+pub fn build_msg_delegate(
+    delegator_address: &str,
+    validator_address: &str,
+    amount: &str,
+    denom: &str,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_msg_delegate(command_handle, delegator_address, validator_address, amount, denom, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_msg_delegate(
+    command_handle: CommandHandle,
+    delegator_address: &str,
+    validator_address: &str,
+    amount: &str,
+    denom: &str,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let delegator_address = c_str!(delegator_address);
+    let validator_address = c_str!(validator_address);
+    let amount = c_str!(amount);
+    let denom = c_str!(denom);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::staking::indy_cheqd_ledger_staking_build_msg_delegate(
+            command_handle,
+            delegator_address.as_ptr(),
+            validator_address.as_ptr(),
+            amount.as_ptr(),
+            denom.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn parse_msg_delegate_resp(
+    commit_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_msg_delegate_resp(command_handle, commit_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_msg_delegate_resp(
+    command_handle: CommandHandle,
+    commit_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let commit_resp = c_str!(commit_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::staking::indy_cheqd_ledger_staking_parse_msg_delegate_resp(
+            command_handle,
+            commit_resp.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_msg_undelegate(
+    delegator_address: &str,
+    validator_address: &str,
+    amount: &str,
+    denom: &str,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_msg_undelegate(command_handle, delegator_address, validator_address, amount, denom, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_msg_undelegate(
+    command_handle: CommandHandle,
+    delegator_address: &str,
+    validator_address: &str,
+    amount: &str,
+    denom: &str,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let delegator_address = c_str!(delegator_address);
+    let validator_address = c_str!(validator_address);
+    let amount = c_str!(amount);
+    let denom = c_str!(denom);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::staking::indy_cheqd_ledger_staking_build_msg_undelegate(
+            command_handle,
+            delegator_address.as_ptr(),
+            validator_address.as_ptr(),
+            amount.as_ptr(),
+            denom.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn parse_msg_undelegate_resp(
+    commit_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_msg_undelegate_resp(command_handle, commit_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_msg_undelegate_resp(
+    command_handle: CommandHandle,
+    commit_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let commit_resp = c_str!(commit_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::staking::indy_cheqd_ledger_staking_parse_msg_undelegate_resp(
+            command_handle,
+            commit_resp.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_msg_begin_redelegate(
+    delegator_address: &str,
+    validator_src_address: &str,
+    validator_dst_address: &str,
+    amount: &str,
+    denom: &str,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_msg_begin_redelegate(command_handle, delegator_address, validator_src_address, validator_dst_address, amount, denom, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_msg_begin_redelegate(
+    command_handle: CommandHandle,
+    delegator_address: &str,
+    validator_src_address: &str,
+    validator_dst_address: &str,
+    amount: &str,
+    denom: &str,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let delegator_address = c_str!(delegator_address);
+    let validator_src_address = c_str!(validator_src_address);
+    let validator_dst_address = c_str!(validator_dst_address);
+    let amount = c_str!(amount);
+    let denom = c_str!(denom);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::staking::indy_cheqd_ledger_staking_build_msg_begin_redelegate(
+            command_handle,
+            delegator_address.as_ptr(),
+            validator_src_address.as_ptr(),
+            validator_dst_address.as_ptr(),
+            amount.as_ptr(),
+            denom.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn parse_msg_begin_redelegate_resp(
+    commit_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_msg_begin_redelegate_resp(command_handle, commit_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_msg_begin_redelegate_resp(
+    command_handle: CommandHandle,
+    commit_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let commit_resp = c_str!(commit_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::staking::indy_cheqd_ledger_staking_parse_msg_begin_redelegate_resp(
+            command_handle,
+            commit_resp.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_query_delegation(
+    delegator_address: &str,
+    validator_address: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_query_delegation(command_handle, delegator_address, validator_address, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_query_delegation(
+    command_handle: CommandHandle,
+    delegator_address: &str,
+    validator_address: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let delegator_address = c_str!(delegator_address);
+    let validator_address = c_str!(validator_address);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::staking::indy_cheqd_ledger_staking_build_query_delegation(
+            command_handle,
+            delegator_address.as_ptr(),
+            validator_address.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn parse_query_delegation_resp(
+    query_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_query_delegation_resp(command_handle, query_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_query_delegation_resp(
+    command_handle: CommandHandle,
+    query_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let query_resp = c_str!(query_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::staking::indy_cheqd_ledger_staking_parse_query_delegation_resp(
+            command_handle,
+            query_resp.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_query_delegator_delegations(
+    delegator_address: &str,
+    pagination: Option<&str>,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_query_delegator_delegations(command_handle, delegator_address, pagination, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_query_delegator_delegations(
+    command_handle: CommandHandle,
+    delegator_address: &str,
+    pagination: Option<&str>,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let delegator_address = c_str!(delegator_address);
+    let pagination_str = opt_c_str!(pagination);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::staking::indy_cheqd_ledger_staking_build_query_delegator_delegations(
+            command_handle,
+            delegator_address.as_ptr(),
+            opt_c_ptr!(pagination, pagination_str),
+            cb,
+        )
+    })
+}
+
+pub fn parse_query_delegator_delegations_resp(
+    query_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_query_delegator_delegations_resp(command_handle, query_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_query_delegator_delegations_resp(
+    command_handle: CommandHandle,
+    query_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let query_resp = c_str!(query_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::staking::indy_cheqd_ledger_staking_parse_query_delegator_delegations_resp(
+            command_handle,
+            query_resp.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn build_query_validators(
+    status: Option<&str>,
+    pagination: Option<&str>,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_query_validators(command_handle, status, pagination, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_query_validators(
+    command_handle: CommandHandle,
+    status: Option<&str>,
+    pagination: Option<&str>,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let status_str = opt_c_str!(status);
+    let pagination_str = opt_c_str!(pagination);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::staking::indy_cheqd_ledger_staking_build_query_validators(
+            command_handle,
+            opt_c_ptr!(status, status_str),
+            opt_c_ptr!(pagination, pagination_str),
+            cb,
+        )
+    })
+}
+
+pub fn parse_query_validators_resp(
+    query_resp: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_query_validators_resp(command_handle, query_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_query_validators_resp(
+    command_handle: CommandHandle,
+    query_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let query_resp = c_str!(query_resp);
+
+    ErrorCode::from(unsafe {
+        cheqd_ledger::staking::indy_cheqd_ledger_staking_parse_query_validators_resp(
+            command_handle,
+            query_resp.as_ptr(),
+            cb,
+        )
+    })
+}
@@ -164,3 +164,148 @@ fn _abci_info(
         )
     })
 }
+
+pub fn status(
+    pool_alias: &str,
+) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _status(command_handle, pool_alias, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _status(
+    command_handle: CommandHandle,
+    pool_alias: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let pool_alias = c_str!(pool_alias);
+
+    ErrorCode::from(unsafe {
+        cheqd_pool::indy_cheqd_pool_status(
+            command_handle,
+            pool_alias.as_ptr(),
+            cb,
+        )
+    })
+}
+
+pub fn block(
+    pool_alias: &str,
+    height: i64,
+) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _block(command_handle, pool_alias, height, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _block(
+    command_handle: CommandHandle,
+    pool_alias: &str,
+    height: i64,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let pool_alias = c_str!(pool_alias);
+
+    ErrorCode::from(unsafe {
+        cheqd_pool::indy_cheqd_pool_block(
+            command_handle,
+            pool_alias.as_ptr(),
+            height,
+            cb,
+        )
+    })
+}
+
+pub fn block_results(
+    pool_alias: &str,
+    height: i64,
+) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _block_results(command_handle, pool_alias, height, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _block_results(
+    command_handle: CommandHandle,
+    pool_alias: &str,
+    height: i64,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let pool_alias = c_str!(pool_alias);
+
+    ErrorCode::from(unsafe {
+        cheqd_pool::indy_cheqd_pool_block_results(
+            command_handle,
+            pool_alias.as_ptr(),
+            height,
+            cb,
+        )
+    })
+}
+
+pub fn validators(
+    pool_alias: &str,
+    height: i64,
+    page: i64,
+    per_page: i64,
+) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _validators(command_handle, pool_alias, height, page, per_page, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _validators(
+    command_handle: CommandHandle,
+    pool_alias: &str,
+    height: i64,
+    page: i64,
+    per_page: i64,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let pool_alias = c_str!(pool_alias);
+
+    ErrorCode::from(unsafe {
+        cheqd_pool::indy_cheqd_pool_validators(
+            command_handle,
+            pool_alias.as_ptr(),
+            height,
+            page,
+            per_page,
+            cb,
+        )
+    })
+}
+
+pub fn net_info(
+    pool_alias: &str,
+) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _net_info(command_handle, pool_alias, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _net_info(
+    command_handle: CommandHandle,
+    pool_alias: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let pool_alias = c_str!(pool_alias);
+
+    ErrorCode::from(unsafe {
+        cheqd_pool::indy_cheqd_pool_net_info(
+            command_handle,
+            pool_alias.as_ptr(),
+            cb,
+        )
+    })
+}
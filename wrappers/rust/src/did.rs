@@ -246,6 +246,28 @@ fn _get_endpoint_for_did(command_handle: CommandHandle, wallet_handle: WalletHan
     ErrorCode::from(unsafe { did::indy_get_endpoint_for_did(command_handle, wallet_handle, pool_handle, did.as_ptr(), cb) })
 }
 
+/// Resolves W3C DID document for the given Indy DID.
+///
+/// # Arguments
+/// * `pool_handle` - Pool handle (created by open_pool). Pass 0 to use the pool opened for the namespace of did:indy DID.
+/// * `did` - The DID to resolve.
+///
+/// # Returns
+/// * `resolution_result` - DID resolution result json with DID document and ledger metadata.
+pub fn resolve_did(pool_handle: PoolHandle, did: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _resolve_did(command_handle, pool_handle, did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _resolve_did(command_handle: CommandHandle, pool_handle: PoolHandle, did: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let did = c_str!(did);
+
+    ErrorCode::from(unsafe { did::indy_resolve_did(command_handle, pool_handle, did.as_ptr(), cb) })
+}
+
 /// Saves/replaces the meta information for the giving DID in the wallet.
 ///
 /// # Arguments
@@ -6,7 +6,9 @@ use std::ptr::null;
 use futures::Future;
 
 use crate::ffi::ledger;
-use crate::ffi::{ResponseStringCB,
+pub use crate::ffi::ledger::{CustomFree, CustomTransactionParser};
+use crate::ffi::{ResponseEmptyCB,
+          ResponseStringCB,
           ResponseStringStringCB,
           ResponseStringStringU64CB};
 
@@ -73,6 +75,35 @@ fn _submit_request(command_handle: CommandHandle, pool_handle: PoolHandle, reque
     ErrorCode::from(unsafe { ledger::indy_submit_request(command_handle, pool_handle, request_json.as_ptr(), cb) })
 }
 
+/// Publishes a batch of request messages to validator pool (no signing, like submit_request).
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `requests_json` - array of prepared request jsons.
+/// * `options_json` - (Optional) submit options json:
+/// {
+///     "max_concurrency": int (optional, 10 by default) - the number of requests sent at the same time,
+///     "stop_on_failure": bool (optional, false by default) - don't send the rest of requests after a failed one,
+/// }
+///
+/// # Returns
+/// Array of results in the order of requests: `{"status": "reply" | "rejected", "reply": <reply json>}`,
+/// `{"status": "error", "error_code": <int>, "message": <string>}` or `{"status": "skipped"}`.
+pub fn submit_requests(pool_handle: PoolHandle, requests_json: &str, options_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _submit_requests(command_handle, pool_handle, requests_json, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _submit_requests(command_handle: CommandHandle, pool_handle: PoolHandle, requests_json: &str, options_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let requests_json = c_str!(requests_json);
+    let options_json_str = opt_c_str!(options_json);
+
+    ErrorCode::from(unsafe { ledger::indy_submit_requests(command_handle, pool_handle, requests_json.as_ptr(), opt_c_ptr!(options_json, options_json_str), cb) })
+}
+
 pub fn submit_action(pool_handle: PoolHandle, request_json: &str, nodes: Option<&str>, wait_timeout: Option<i32>) -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
@@ -90,6 +121,48 @@ fn _submit_action(command_handle: CommandHandle, pool_handle: PoolHandle, reques
     })
 }
 
+/// Downloads transactions of the ledger into the local copy kept for the pool.
+///
+/// Transactions are requested in batches by GET_TXN requests starting right after the last
+/// locally stored one, so repeated calls resume the download incrementally.
+/// Every transaction is checked against the audit path and the multi-signed ledger root hash
+/// returned by the pool before it is stored.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `ledger_type` - type of the ledger to download: DOMAIN, POOL, CONFIG or any number.
+/// * `config` - (Optional) download config json:
+/// {
+///     "batch_size": <int> (Optional) number of transactions requested at once (100 by default),
+///     "to": <int> (Optional) seq_no of the last transaction to download (the whole ledger by default)
+/// }
+///
+/// # Returns
+/// Download status json:
+/// {
+///     "ledgerId": <int> id of the ledger,
+///     "ledgerSize": <int> size of the ledger reported by the pool,
+///     "txnsCount": <int> number of transactions in the local copy,
+///     "rootHash": <string> base58 merkle root of the local copy,
+///     "path": <string> path to the local copy
+/// }
+pub fn download_ledger(pool_handle: PoolHandle, ledger_type: &str, config: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _download_ledger(command_handle, pool_handle, ledger_type, config, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _download_ledger(command_handle: CommandHandle, pool_handle: PoolHandle, ledger_type: &str, config: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let ledger_type = c_str!(ledger_type);
+    let config_str = opt_c_str!(config);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_download_ledger(command_handle, pool_handle, ledger_type.as_ptr(), opt_c_ptr!(config, config_str), cb)
+    })
+}
+
 /// Signs request message.
 ///
 /// Adds submitter information to passed request json, signs it with submitter
@@ -609,6 +682,36 @@ fn _build_get_validator_info_request(command_handle: CommandHandle, submitter_di
     })
 }
 
+/// Parses replies of the nodes to GET_VALIDATOR_INFO request into typed validator info
+/// and summarizes it for the whole pool.
+///
+/// # Arguments
+/// * `get_validator_info_response` - response of GET_VALIDATOR_INFO request
+///     (json object mapping node aliases to their replies or a single node reply).
+///
+/// # Returns
+/// Validator info json:
+/// {
+///     "nodes": [{"node": string, "status": "ok"|"timeout"|"error", "indy_node_version": string, "uptime": int,
+///                "view_no": int, "catchup_status": {"<ledger>": string}, "ledger_sizes": {"<ledger>": int}, ...}],
+///     "summary": {"nodes_count": int, "replied_nodes": [string], "inconsistencies": [string], ...}
+/// }
+pub fn parse_get_validator_info_response(get_validator_info_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_validator_info_response(command_handle, get_validator_info_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_validator_info_response(command_handle: CommandHandle, get_validator_info_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_validator_info_response = c_str!(get_validator_info_response);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_parse_get_validator_info_response(command_handle, get_validator_info_response.as_ptr(), cb)
+    })
+}
+
 /// Builds a POOL_CONFIG request. Request to change Pool's configuration.
 ///
 /// # Arguments
@@ -994,6 +1097,29 @@ fn _parse_get_revoc_reg_delta_response(command_handle: CommandHandle, get_revoc_
     ErrorCode::from(unsafe { ledger::indy_parse_get_revoc_reg_delta_response(command_handle, get_revoc_reg_delta_response.as_ptr(), cb) })
 }
 
+/// Registers custom state proof parser for transactions of the given type.
+///
+/// Replies to requests of this type will be verified with state proofs returned by `parser`
+/// instead of waiting for f+1 equal replies from nodes.
+///
+/// # Arguments
+/// * `txn_type` - type of transaction to apply `parser` callback.
+/// * `parser` - callback to parse reply for state proof.
+/// * `free` - callback to deallocate memory allocated by `parser`.
+pub fn register_transaction_parser_for_sp(txn_type: &str, parser: CustomTransactionParser, free: CustomFree) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _register_transaction_parser_for_sp(command_handle, txn_type, parser, free, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _register_transaction_parser_for_sp(command_handle: CommandHandle, txn_type: &str, parser: CustomTransactionParser, free: CustomFree, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let txn_type = c_str!(txn_type);
+
+    ErrorCode::from(unsafe { ledger::indy_register_transaction_parser_for_sp(command_handle, txn_type.as_ptr(), Some(parser), Some(free), cb) })
+}
+
 /// Parse transaction response to fetch metadata.
 /// The important use case for this method is validation of Node's response freshens.
 ///
@@ -1202,6 +1328,61 @@ fn _build_get_auth_rule_request(command_handle: CommandHandle,
     })
 }
 
+/// Evaluates authentication rules locally to predict whether the ledger would accept a prepared write request.
+///
+/// Signatures are not verified, only their presence in the request is taken into account.
+///
+/// # Arguments
+/// * `get_auth_rule_response` - response of GET_AUTH_RULE request.
+/// * `action_json` - the action to evaluate rules for:
+///     {
+///         auth_type: ledger transaction alias or associated value,
+///         auth_action: type of an action. Can be either "ADD" or "EDIT",
+///         field: transaction field,
+///         old_value: (Optional) old value of a field (mandatory for EDIT action),
+///         new_value: (Optional) new value of a field,
+///         owner: (Optional) DID of the owner of the changed object (the request author by default),
+///     }
+/// * `request_json` - the prepared request.
+/// * `signers_json` - NYM data of the request signers and endorser as returned by `parse_get_nym_response`.
+///
+/// # Returns
+/// Evaluation result as json:
+/// {
+///     satisfied: bool,
+///     forbidden: bool,
+///     missing_signatures: [{role, sig_count, need_to_be_owner, off_ledger_signature}],
+///     missing_endorsement: bool,
+/// }
+pub fn evaluate_auth_rule(get_auth_rule_response: &str, action_json: &str, request_json: &str, signers_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _evaluate_auth_rule(command_handle, get_auth_rule_response, action_json, request_json, signers_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _evaluate_auth_rule(command_handle: CommandHandle,
+                       get_auth_rule_response: &str,
+                       action_json: &str,
+                       request_json: &str,
+                       signers_json: &str,
+                       cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_auth_rule_response = c_str!(get_auth_rule_response);
+    let action_json = c_str!(action_json);
+    let request_json = c_str!(request_json);
+    let signers_json = c_str!(signers_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_evaluate_auth_rule(command_handle,
+                                        get_auth_rule_response.as_ptr(),
+                                        action_json.as_ptr(),
+                                        request_json.as_ptr(),
+                                        signers_json.as_ptr(),
+                                        cb)
+    })
+}
+
 /// Builds a TXN_AUTHR_AGRMT request. Request to add a new version of Transaction Author Agreement to the ledger.
 ///
 /// # Arguments
@@ -1472,6 +1653,61 @@ fn _append_txn_author_agreement_acceptance_to_request(command_handle: CommandHan
     })
 }
 
+/// Sets transaction author agreement acceptance for the opened pool.
+///
+/// `sign_and_submit_request` appends the acceptance to domain ledger write requests sent to this pool.
+/// If the agreement on the ledger differs from the accepted one,
+/// `LedgerTxnAuthorAgreementChanged` error is returned and the new agreement must be accepted.
+/// The acceptance is stored with the pool ledger configuration and restored when the pool is opened again.
+///
+/// # Arguments
+/// * `pool_handle`: pool handle (created by open_pool_ledger).
+/// * `text` and `version`: (optional) raw data about TAA from ledger.
+///     These parameters should be passed together.
+///     These parameters are required if taa_digest parameter is omitted.
+/// * `taa_digest`: (optional) digest on text and version.
+///     Digest is sha256 hash calculated on concatenated strings: version || text.
+///     This parameter is required if text and version parameters are omitted.
+/// * `mechanism`: mechanism how user has accepted the TAA
+/// * `time`: UTC timestamp when user has accepted the TAA. Note that the time portion will be discarded to avoid a privacy risk.
+pub fn set_pool_txn_author_agreement_acceptance(pool_handle: PoolHandle,
+                                                text: Option<&str>,
+                                                version: Option<&str>,
+                                                taa_digest: Option<&str>,
+                                                mechanism: &str,
+                                                time: u64) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _set_pool_txn_author_agreement_acceptance(command_handle, pool_handle, text, version, taa_digest, mechanism, time, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _set_pool_txn_author_agreement_acceptance(command_handle: CommandHandle,
+                                             pool_handle: PoolHandle,
+                                             text: Option<&str>,
+                                             version: Option<&str>,
+                                             taa_digest: Option<&str>,
+                                             mechanism: &str,
+                                             time: u64,
+                                             cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let text_str = opt_c_str!(text);
+    let version_str = opt_c_str!(version);
+    let taa_digest_str = opt_c_str!(taa_digest);
+    let mechanism = c_str!(mechanism);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_set_pool_txn_author_agreement_acceptance(command_handle,
+                                                              pool_handle,
+                                                              opt_c_ptr!(text, text_str),
+                                                              opt_c_ptr!(version, version_str),
+                                                              opt_c_ptr!(taa_digest, taa_digest_str),
+                                                              mechanism.as_ptr(),
+                                                              time,
+                                                              cb)
+    })
+}
+
 /// Append Endorser to an existing request.
 ///
 /// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
@@ -1510,4 +1746,470 @@ fn _append_request_endorser(command_handle: CommandHandle,
                                              endorser_did.as_ptr(),
                                              cb)
     })
-}
\ No newline at end of file
+}
+
+/// Builds an endorsement envelope: a request prepared for the Endorser together with the list
+/// of DIDs which must sign it. The envelope is stored in the wallet as a pending endorsement.
+///
+/// Note: Signatures present in the request are removed as appending of the Endorser changes signed data.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open).
+/// * `request_json`: original request built by the transaction author.
+/// * `endorser_did`: DID of the Endorser that will submit the transaction.
+/// * `options_json`: (Optional) envelope options:
+/// {
+///     "required_signers": [string] - (Optional) DIDs which must sign the request in addition to the author and Endorser,
+///                                     or in addition to the ones required by `auth_rule`,
+///     "auth_rule": (Optional) auth rule the request must meet (see `evaluate_auth_rule`):
+///     {
+///         "get_auth_rule_response": string - response of GET_AUTH_RULE request,
+///         "action": json - the action to evaluate rules for,
+///         "signers": [json] - NYM data of the signers as returned by `parse_get_nym_response`,
+///     }
+/// }
+///
+/// # Returns
+/// Endorsement envelope json:
+/// {
+///     "id": string - identifier of the pending endorsement,
+///     "request": json - the request with Endorser field appended and signatures collected so far,
+///     "endorser": string - DID of the Endorser,
+///     "required_signers": [string] or null - DIDs which must sign the request. Derived from `auth_rule` if it is passed,
+///                           null if it isn't and no explicit signers are passed or the rule can't be met,
+///     "auth_rule": (Optional) json - auth rule the request must meet,
+/// }
+pub fn build_endorsement_envelope(wallet_handle: WalletHandle,
+                                  request_json: &str,
+                                  endorser_did: &str,
+                                  options_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_endorsement_envelope(command_handle, wallet_handle, request_json, endorser_did, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_endorsement_envelope(command_handle: CommandHandle,
+                               wallet_handle: WalletHandle,
+                               request_json: &str,
+                               endorser_did: &str,
+                               options_json: Option<&str>,
+                               cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+    let endorser_did = c_str!(endorser_did);
+    let options_json_str = opt_c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_endorsement_envelope(command_handle,
+                                                wallet_handle,
+                                                request_json.as_ptr(),
+                                                endorser_did.as_ptr(),
+                                                opt_c_ptr!(options_json, options_json_str),
+                                                cb)
+    })
+}
+
+/// Adds a signature to the request of an endorsement envelope (see `build_endorsement_envelope`).
+/// The signed envelope is stored (or updated) in the wallet as a pending endorsement.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open).
+/// * `envelope_json`: endorsement envelope.
+/// * `signer_did`: DID of the signer. The key of the DID must be in the wallet.
+///
+/// # Returns
+/// Endorsement envelope json with the signature added.
+pub fn sign_endorsement_envelope(wallet_handle: WalletHandle,
+                                 envelope_json: &str,
+                                 signer_did: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _sign_endorsement_envelope(command_handle, wallet_handle, envelope_json, signer_did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _sign_endorsement_envelope(command_handle: CommandHandle,
+                              wallet_handle: WalletHandle,
+                              envelope_json: &str,
+                              signer_did: &str,
+                              cb: Option<ResponseStringCB>) -> ErrorCode {
+    let envelope_json = c_str!(envelope_json);
+    let signer_did = c_str!(signer_did);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_sign_endorsement_envelope(command_handle,
+                                               wallet_handle,
+                                               envelope_json.as_ptr(),
+                                               signer_did.as_ptr(),
+                                               cb)
+    })
+}
+
+/// Checks whether an endorsement envelope collected all required signatures.
+/// If the envelope contains an auth rule, the request is also evaluated against it.
+///
+/// # Arguments
+/// * `envelope_json`: endorsement envelope.
+///
+/// # Returns
+/// Status json:
+/// {
+///     "complete": bool - whether the request can be submitted,
+///     "required_signers_determined": bool - false if the envelope doesn't know its required signers,
+///     "missing_signers": [string] - required DIDs which haven't signed the request yet,
+///     "auth_rule_evaluation": (Optional) json - result of the auth rule evaluation,
+/// }
+pub fn check_endorsement_envelope(envelope_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _check_endorsement_envelope(command_handle, envelope_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _check_endorsement_envelope(command_handle: CommandHandle, envelope_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let envelope_json = c_str!(envelope_json);
+
+    ErrorCode::from(unsafe { ledger::indy_check_endorsement_envelope(command_handle, envelope_json.as_ptr(), cb) })
+}
+
+/// Submits the request of a complete endorsement envelope to validator pool.
+/// The pending endorsement is removed from the wallet once the request is written.
+/// Envelopes with undetermined required signers are rejected.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `wallet_handle` - wallet handle (created by Wallet::open).
+/// * `envelope_json`: endorsement envelope.
+///
+/// # Returns
+/// Request result as json.
+pub fn submit_endorsement_envelope(pool_handle: PoolHandle,
+                                   wallet_handle: WalletHandle,
+                                   envelope_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _submit_endorsement_envelope(command_handle, pool_handle, wallet_handle, envelope_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _submit_endorsement_envelope(command_handle: CommandHandle,
+                                pool_handle: PoolHandle,
+                                wallet_handle: WalletHandle,
+                                envelope_json: &str,
+                                cb: Option<ResponseStringCB>) -> ErrorCode {
+    let envelope_json = c_str!(envelope_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_submit_endorsement_envelope(command_handle, pool_handle, wallet_handle, envelope_json.as_ptr(), cb)
+    })
+}
+
+/// Lists endorsement envelopes stored in the wallet which are not submitted yet.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open).
+///
+/// # Returns
+/// Array of endorsement envelopes (see `build_endorsement_envelope`).
+pub fn list_pending_endorsements(wallet_handle: WalletHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _list_pending_endorsements(command_handle, wallet_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _list_pending_endorsements(command_handle: CommandHandle, wallet_handle: WalletHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { ledger::indy_list_pending_endorsements(command_handle, wallet_handle, cb) })
+}
+
+/// Deletes a pending endorsement from the wallet.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open).
+/// * `id` - identifier of the pending endorsement (`id` field of the envelope).
+pub fn delete_pending_endorsement(wallet_handle: WalletHandle, id: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _delete_pending_endorsement(command_handle, wallet_handle, id, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _delete_pending_endorsement(command_handle: CommandHandle, wallet_handle: WalletHandle, id: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let id = c_str!(id);
+
+    ErrorCode::from(unsafe { ledger::indy_delete_pending_endorsement(command_handle, wallet_handle, id.as_ptr(), cb) })
+}
+
+/// Builds a LEDGERS_FREEZE request. Request to freeze list of ledgers.
+/// Frozen ledgers can not be written anymore but can still be read.
+///
+/// # Arguments
+/// * `submitter_did` - Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `append_request_endorser`)
+/// * `ledgers_ids` - list of ledgers IDs for freezing ledgers (json format).
+///     [0, 1, 28, ...]
+///
+/// # Returns
+/// Request result as json.
+pub fn build_ledgers_freeze_request(submitter_did: &str, ledgers_ids: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_ledgers_freeze_request(command_handle, submitter_did, ledgers_ids, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_ledgers_freeze_request(command_handle: CommandHandle,
+                                 submitter_did: &str,
+                                 ledgers_ids: &str,
+                                 cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let ledgers_ids = c_str!(ledgers_ids);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_ledgers_freeze_request(command_handle,
+                                                  submitter_did.as_ptr(),
+                                                  ledgers_ids.as_ptr(),
+                                                  cb)
+    })
+}
+
+/// Builds a GET_FROZEN_LEDGERS request. Request to get list of frozen ledgers.
+/// Frozen ledgers are defined by LEDGERS_FREEZE request.
+///
+/// # Arguments
+/// * `submitter_did` - (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+///
+/// # Returns
+/// Request result as json.
+pub fn build_get_frozen_ledgers_request(submitter_did: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_get_frozen_ledgers_request(command_handle, submitter_did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_get_frozen_ledgers_request(command_handle: CommandHandle,
+                                     submitter_did: Option<&str>,
+                                     cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_get_frozen_ledgers_request(command_handle,
+                                                      opt_c_ptr!(submitter_did, submitter_did_str),
+                                                      cb)
+    })
+}
+
+/// Parse a GET_FROZEN_LEDGERS response to get list of frozen ledgers.
+///
+/// # Arguments
+/// * `get_frozen_ledgers_response` - response of GET_FROZEN_LEDGERS request.
+///
+/// # Returns
+/// Frozen ledgers json (empty object if no ledger has been frozen):
+/// {
+///     "<ledger_id>": {
+///         ledger: root hash of the ledger at the moment of freezing,
+///         state: state root hash of the ledger at the moment of freezing,
+///         seq_no: last sequence number of the ledger at the moment of freezing
+///     },
+///     ...
+/// }
+pub fn parse_get_frozen_ledgers_response(get_frozen_ledgers_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_frozen_ledgers_response(command_handle, get_frozen_ledgers_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_frozen_ledgers_response(command_handle: CommandHandle,
+                                      get_frozen_ledgers_response: &str,
+                                      cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_frozen_ledgers_response = c_str!(get_frozen_ledgers_response);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_parse_get_frozen_ledgers_response(command_handle,
+                                                       get_frozen_ledgers_response.as_ptr(),
+                                                       cb)
+    })
+}
+
+/// Builds a request to store a rich schema object on the ledger.
+/// The transaction type is selected by `rs_type`:
+/// ctx -> SET_JSON_LD_CONTEXT, sch -> SET_RICH_SCHEMA, enc -> SET_RICH_SCHEMA_ENCODING,
+/// map -> SET_RICH_SCHEMA_MAPPING, cdf -> SET_RICH_SCHEMA_CRED_DEF, pdf -> SET_RICH_SCHEMA_PRES_DEF.
+///
+/// # Arguments
+/// * `submitter_did` - Identifier (DID) of the transaction author as base58-encoded string.
+/// * `rs_id` - identifier of the rich schema object (DID string).
+/// * `rs_content` - JSON-LD content of the rich schema object (json string).
+/// * `rs_name` - rich schema object name.
+/// * `rs_version` - rich schema object version.
+/// * `rs_type` - rich schema object type. One of: ctx, sch, enc, map, cdf, pdf.
+/// * `ver` - version of the rich schema object format (currently "1").
+///
+/// # Returns
+/// Request result as json.
+pub fn build_rich_schema_request(submitter_did: &str,
+                                 rs_id: &str,
+                                 rs_content: &str,
+                                 rs_name: &str,
+                                 rs_version: &str,
+                                 rs_type: &str,
+                                 ver: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_rich_schema_request(command_handle, submitter_did, rs_id, rs_content, rs_name, rs_version, rs_type, ver, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_rich_schema_request(command_handle: CommandHandle,
+                              submitter_did: &str,
+                              rs_id: &str,
+                              rs_content: &str,
+                              rs_name: &str,
+                              rs_version: &str,
+                              rs_type: &str,
+                              ver: &str,
+                              cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let rs_id = c_str!(rs_id);
+    let rs_content = c_str!(rs_content);
+    let rs_name = c_str!(rs_name);
+    let rs_version = c_str!(rs_version);
+    let rs_type = c_str!(rs_type);
+    let ver = c_str!(ver);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_rich_schema_request(command_handle,
+                                               submitter_did.as_ptr(),
+                                               rs_id.as_ptr(),
+                                               rs_content.as_ptr(),
+                                               rs_name.as_ptr(),
+                                               rs_version.as_ptr(),
+                                               rs_type.as_ptr(),
+                                               ver.as_ptr(),
+                                               cb)
+    })
+}
+
+/// Builds a GET_RICH_SCHEMA_OBJECT_BY_ID request. Request to get a rich schema object by its id.
+///
+/// # Arguments
+/// * `submitter_did` - (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// * `rs_id` - identifier of the rich schema object.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_get_rich_schema_object_by_id_request(submitter_did: Option<&str>, rs_id: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_get_rich_schema_object_by_id_request(command_handle, submitter_did, rs_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_get_rich_schema_object_by_id_request(command_handle: CommandHandle,
+                                               submitter_did: Option<&str>,
+                                               rs_id: &str,
+                                               cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let rs_id = c_str!(rs_id);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_get_rich_schema_object_by_id_request(command_handle,
+                                                                opt_c_ptr!(submitter_did, submitter_did_str),
+                                                                rs_id.as_ptr(),
+                                                                cb)
+    })
+}
+
+/// Builds a GET_RICH_SCHEMA_OBJECT_BY_METADATA request.
+/// Request to get a rich schema object by its type, name and version.
+///
+/// # Arguments
+/// * `submitter_did` - (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// * `rs_type` - rich schema object type. One of: ctx, sch, enc, map, cdf, pdf.
+/// * `rs_name` - rich schema object name.
+/// * `rs_version` - rich schema object version.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_get_rich_schema_object_by_metadata_request(submitter_did: Option<&str>,
+                                                        rs_type: &str,
+                                                        rs_name: &str,
+                                                        rs_version: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_get_rich_schema_object_by_metadata_request(command_handle, submitter_did, rs_type, rs_name, rs_version, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_get_rich_schema_object_by_metadata_request(command_handle: CommandHandle,
+                                                     submitter_did: Option<&str>,
+                                                     rs_type: &str,
+                                                     rs_name: &str,
+                                                     rs_version: &str,
+                                                     cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let rs_type = c_str!(rs_type);
+    let rs_name = c_str!(rs_name);
+    let rs_version = c_str!(rs_version);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_get_rich_schema_object_by_metadata_request(command_handle,
+                                                                      opt_c_ptr!(submitter_did, submitter_did_str),
+                                                                      rs_type.as_ptr(),
+                                                                      rs_name.as_ptr(),
+                                                                      rs_version.as_ptr(),
+                                                                      cb)
+    })
+}
+
+/// Parse a GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA response.
+///
+/// # Arguments
+/// * `get_rich_schema_object_response` - response of GET_RICH_SCHEMA_OBJECT_BY_* request.
+///
+/// # Returns
+/// Rich schema object json:
+/// {
+///     id: identifier of the rich schema object,
+///     content: JSON-LD content of the object,
+///     rsName: object name,
+///     rsVersion: object version,
+///     rsType: object type,
+///     ver: version of the object format,
+///     from: DID of the object author,
+///     endorser: (Optional) DID of the endorser
+/// }
+pub fn parse_get_rich_schema_object_response(get_rich_schema_object_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_rich_schema_object_response(command_handle, get_rich_schema_object_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_rich_schema_object_response(command_handle: CommandHandle,
+                                          get_rich_schema_object_response: &str,
+                                          cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_rich_schema_object_response = c_str!(get_rich_schema_object_response);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_parse_get_rich_schema_object_response(command_handle,
+                                                           get_rich_schema_object_response.as_ptr(),
+                                                           cb)
+    })
+}
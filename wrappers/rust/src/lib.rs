@@ -271,6 +271,10 @@ pub enum ErrorCode
     #[fail(display = "LedgerNotFound")]
     LedgerNotFound = 309,
 
+    // Transaction author agreement on the ledger differs from the one accepted for the pool.
+    #[fail(display = "LedgerTxnAuthorAgreementChanged")]
+    LedgerTxnAuthorAgreementChanged = 310,
+
     // Revocation registry is full and creation of new registry is necessary
     #[fail(display = "AnoncredsRevocationRegistryFullError")]
     AnoncredsRevocationRegistryFullError = 400,
@@ -11,7 +11,7 @@ use crate::ffi::{ResponseEmptyCB,
           ResponseI32CB};
 
 use futures::Future;
-use crate::{CommandHandle, PoolHandle};
+use crate::{CommandHandle, PoolHandle, WalletHandle};
 
 /// Creates a new local pool ledger configuration that can be used later to connect pool nodes.
 ///
@@ -19,7 +19,12 @@ use crate::{CommandHandle, PoolHandle};
 /// * `config_name` - Name of the pool ledger configuration.
 /// * `config`  (required)- Pool configuration json. Example:
 /// {
-///     "genesis_txn": string (required), A path to genesis transaction file.
+///     "genesis_txn": string (optional), A path to genesis transaction file.
+///     "genesis_txn_data": string (optional), Genesis transactions itself (content of genesis transaction file).
+///     "genesis_url": string (optional), URL to fetch genesis transaction file from. `genesis_hash` is required for this source.
+///     "genesis_hash": string (optional), hex encoded SHA-256 digest of genesis transaction file content.
+///     "snapshot": object (optional), pool ledger snapshot exported by `export_pool_snapshot` to catch up from.
+///     "snapshot_verkey": string (optional), verkey the snapshot must be signed with.
 /// }
 pub fn create_pool_ledger_config(pool_name: &str, pool_config: Option<&str>) -> Box<dyn Future<Item=(), Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
@@ -36,6 +41,36 @@ fn _create_pool_ledger_config(command_handle: CommandHandle, pool_name: &str, po
     ErrorCode::from(unsafe { pool::indy_create_pool_ledger_config(command_handle, pool_name.as_ptr(), opt_c_ptr!(pool_config, pool_config_str), cb) })
 }
 
+/// Starts in-process simulated pool of nodes and creates a local pool ledger configuration for it.
+///
+/// Pools opened with this configuration talk to the simulated nodes instead of the network
+/// until the configuration is deleted.
+///
+/// # Arguments
+/// * `config_name` - Name of the pool ledger configuration.
+/// * `config`  (optional)- Simulated pool configuration json. Example:
+/// {
+///     "nodes_count": int (optional), number of simulated nodes (4 by default).
+///     "nyms": array (optional), NYMs written at the pool genesis: [{"did": string, "verkey": string, "role": string (optional)}]
+/// }
+///
+/// # Returns
+/// Genesis transactions of the simulated pool
+pub fn create_simulated_pool(pool_name: &str, config: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _create_simulated_pool(command_handle, pool_name, config, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _create_simulated_pool(command_handle: CommandHandle, pool_name: &str, config: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let pool_name = c_str!(pool_name);
+    let config_str = opt_c_str!(config);
+
+    ErrorCode::from(unsafe { pool::indy_create_simulated_pool(command_handle, pool_name.as_ptr(), opt_c_ptr!(config, config_str), cb) })
+}
+
 /// Opens pool ledger and performs connecting to pool nodes.
 ///
 /// Pool ledger configuration with corresponded name must be previously created
@@ -93,6 +128,59 @@ fn _refresh_pool_ledger(command_handle: CommandHandle, pool_handle: PoolHandle,
     ErrorCode::from(unsafe { pool::indy_refresh_pool_ledger(command_handle, pool_handle, cb) })
 }
 
+/// Returns health of the pool nodes: alias, address, last response time, rolling latency,
+/// failures count and read set membership of every node, together with the size and
+/// merkle root of the local copy of the pool ledger.
+///
+/// # Arguments
+/// * `handle` - pool handle returned by open_ledger
+pub fn get_pool_status(pool_handle: PoolHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_pool_status(command_handle, pool_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_pool_status(command_handle: CommandHandle, pool_handle: PoolHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { pool::indy_get_pool_status(command_handle, pool_handle, cb) })
+}
+
+/// Verifies state proof and BLS multi-signature of a saved ledger reply without connecting to the pool.
+///
+/// # Arguments
+/// * `pool_name` - (Optional) name of the created pool ledger configuration to take node keys from.
+/// * `genesis_txns` - (Optional) pool genesis transactions to take node keys from.
+///                    Exactly one of `pool_name` and `genesis_txns` must be passed.
+/// * `request_json` - the request the reply was received for.
+/// * `reply_json` - the reply to verify.
+///
+/// # Returns
+/// Verification report json with the result of the multi-signature and proof checks of every state proof.
+pub fn verify_ledger_reply(pool_name: Option<&str>, genesis_txns: Option<&str>, request_json: &str, reply_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verify_ledger_reply(command_handle, pool_name, genesis_txns, request_json, reply_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verify_ledger_reply(command_handle: CommandHandle, pool_name: Option<&str>, genesis_txns: Option<&str>, request_json: &str, reply_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let pool_name_str = opt_c_str!(pool_name);
+    let genesis_txns_str = opt_c_str!(genesis_txns);
+    let request_json = c_str!(request_json);
+    let reply_json = c_str!(reply_json);
+
+    ErrorCode::from(unsafe {
+        pool::indy_verify_ledger_reply(command_handle,
+                                       opt_c_ptr!(pool_name, pool_name_str),
+                                       opt_c_ptr!(genesis_txns, genesis_txns_str),
+                                       request_json.as_ptr(),
+                                       reply_json.as_ptr(),
+                                       cb)
+    })
+}
+
 /// Lists names of created pool ledgers
 pub fn list_pools() -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
@@ -140,6 +228,64 @@ fn _delete_pool_ledger(command_handle: CommandHandle, pool_name: &str, cb: Optio
     ErrorCode::from(unsafe { pool::indy_delete_pool_ledger_config(command_handle, pool_name.as_ptr(), cb) })
 }
 
+/// Replaces genesis transactions of created pool ledger configuration.
+///
+/// Pool must not be opened.
+///
+/// # Arguments
+/// * `config_name` - Name of the pool ledger configuration.
+/// * `config` - Genesis transactions source json. Example:
+/// {
+///     "genesis_txn": string (optional), A path to genesis transaction file.
+///     "genesis_txn_data": string (optional), Genesis transactions itself (content of genesis transaction file).
+///     "genesis_url": string (optional), URL to fetch genesis transaction file from. `genesis_hash` is required for this source.
+///     "genesis_hash": string (optional), hex encoded SHA-256 digest of genesis transaction file content.
+///                     If omitted, the hash pinned before (if any) is checked and kept.
+///     "snapshot": object (optional), pool ledger snapshot exported by `export_pool_snapshot` to catch up from.
+///     "snapshot_verkey": string (optional), verkey the snapshot must be signed with.
+/// }
+pub fn update_pool_ledger_genesis(pool_name: &str, config: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _update_pool_ledger_genesis(command_handle, pool_name, config, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _update_pool_ledger_genesis(command_handle: CommandHandle, pool_name: &str, config: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let pool_name = c_str!(pool_name);
+    let config = c_str!(config);
+
+    ErrorCode::from(unsafe { pool::indy_update_pool_ledger_genesis(command_handle, pool_name.as_ptr(), config.as_ptr(), cb) })
+}
+
+/// Exports transactions of the local copy of the pool ledger as a snapshot.
+///
+/// Snapshot can be passed as `snapshot` of the pool config on other machines
+/// to skip catch-up from genesis transactions.
+///
+/// # Arguments
+/// * `wallet_handle` - Wallet holding the signer key. Not used if `signer_verkey` isn't set.
+/// * `config_name` - Name of the pool ledger configuration.
+/// * `export_config` - (Optional) Export configuration json: {"signer_verkey": string} - verkey of the key to sign the snapshot with.
+///
+/// # Returns
+/// Snapshot json: {"ledger_size": int, "root_hash": string, "txns": [object], "signature": Optional<{"verkey": string, "signature": string}>}
+pub fn export_pool_snapshot(wallet_handle: WalletHandle, pool_name: &str, export_config: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _export_pool_snapshot(command_handle, wallet_handle, pool_name, export_config, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _export_pool_snapshot(command_handle: CommandHandle, wallet_handle: WalletHandle, pool_name: &str, export_config: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let pool_name = c_str!(pool_name);
+    let export_config_str = opt_c_str!(export_config);
+
+    ErrorCode::from(unsafe { pool::indy_export_pool_snapshot(command_handle, wallet_handle, pool_name.as_ptr(), opt_c_ptr!(export_config, export_config_str), cb) })
+}
+
 /// Set PROTOCOL_VERSION to specific version.
 ///
 /// There is a global property PROTOCOL_VERSION that used in every request to the pool and
@@ -165,3 +311,21 @@ fn _set_protocol_version(command_handle: CommandHandle, protocol_version: usize,
       pool::indy_set_protocol_version(command_handle, protocol_version, cb)
     })
 }
+
+/// Returns handle of the opened pool ledger which serves given did:indy namespace.
+///
+/// # Arguments
+/// * `namespace` - did:indy namespace (e.g. "sovrin" or "sovrin:staging")
+pub fn get_pool_handle_for_namespace(namespace: &str) -> Box<dyn Future<Item=PoolHandle, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_handle();
+
+    let err = _get_pool_handle_for_namespace(command_handle, namespace, cb);
+
+    ResultHandler::handle(command_handle, err, receiver)
+}
+
+fn _get_pool_handle_for_namespace(command_handle: CommandHandle, namespace: &str, cb: Option<ResponseI32CB>) -> ErrorCode {
+    let namespace = c_str!(namespace);
+
+    ErrorCode::from(unsafe { pool::indy_get_pool_handle_for_namespace(command_handle, namespace.as_ptr(), cb) })
+}
@@ -499,6 +499,40 @@ fn _delete_wallet_record(command_handle: CommandHandle, wallet_handle: WalletHan
     })
 }
 
+/// Apply a list of non-secret record operations to the wallet atomically:
+/// either all operations are applied or, if any of them fails, none of them.
+///
+/// Supported by the default (SQLite) and MySQL wallet storages. Plugged storages
+/// have no transactions and return WalletStorageError without applying anything.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet)
+/// * `operations_json` - the list of operations applied in the given order as json:
+///   [
+///     {"op": "add", "type": <str>, "id": <str>, "value": <str>, "tags": <tags_json>}, // tags are optional
+///     {"op": "update_value", "type": <str>, "id": <str>, "value": <str>},
+///     {"op": "add_tags", "type": <str>, "id": <str>, "tags": <tags_json>},
+///     {"op": "update_tags", "type": <str>, "id": <str>, "tags": <tags_json>},
+///     {"op": "delete_tags", "type": <str>, "id": <str>, "tag_names": [<str>]},
+///     {"op": "delete", "type": <str>, "id": <str>}
+///   ]
+///   where tags_json has the same format as in add_wallet_record
+pub fn wallet_batch(wallet_handle: WalletHandle, operations_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _wallet_batch(command_handle, wallet_handle, operations_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _wallet_batch(command_handle: CommandHandle, wallet_handle: WalletHandle, operations_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let operations_json = c_str!(operations_json);
+
+    ErrorCode::from(unsafe {
+      non_secrets::indy_wallet_batch(command_handle, wallet_handle, operations_json.as_ptr(), cb)
+    })
+}
+
 /// Get an wallet record by id
 ///
 /// # Arguments